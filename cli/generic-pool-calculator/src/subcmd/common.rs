@@ -33,6 +33,24 @@ pub fn verify_manager(state: &CalculatorState, curr_manager: Pubkey) -> Result<(
     Ok(())
 }
 
+pub fn verify_pending_manager(
+    state: &CalculatorState,
+    pending_manager: Pubkey,
+) -> Result<(), Infallible> {
+    if state.pending_manager == Pubkey::default() {
+        eprintln!("No pending manager. Propose one with set-manager first");
+        std::process::exit(-1);
+    }
+    if state.pending_manager != pending_manager {
+        eprintln!(
+            "Wrong pending manager. Expected: {}. Got: {}",
+            state.pending_manager, pending_manager
+        );
+        std::process::exit(-1);
+    }
+    Ok(())
+}
+
 pub async fn lst_sol_common_account_metas(
     rpc: &RpcClient,
     arg: &SolValCalcArg,
@@ -1,8 +1,10 @@
 use clap::Subcommand;
 
+mod accept_manager;
 mod common;
 mod init;
 mod lst_to_sol;
+mod migrate_state;
 mod set_manager;
 mod sol_to_lst;
 mod update_last_upgrade_slot;
@@ -11,7 +13,8 @@ mod view;
 use init::InitArgs;
 
 use self::{
-    lst_to_sol::LstToSolArgs, set_manager::SetManagerArgs, sol_to_lst::SolToLstArgs,
+    accept_manager::AcceptManagerArgs, lst_to_sol::LstToSolArgs, migrate_state::MigrateStateArgs,
+    set_manager::SetManagerArgs, sol_to_lst::SolToLstArgs,
     update_last_upgrade_slot::UpdateLastUpgradeSlotArgs, view::ViewArgs,
 };
 
@@ -19,6 +22,8 @@ use self::{
 pub enum Subcmd {
     Init,
     SetManager(SetManagerArgs),
+    AcceptManager(AcceptManagerArgs),
+    MigrateState(MigrateStateArgs),
     UpdateLastUpgradeSlot(UpdateLastUpgradeSlotArgs),
     View(ViewArgs),
     SolToLst(SolToLstArgs),
@@ -30,6 +35,8 @@ impl Subcmd {
         match &args.subcmd {
             Self::Init => InitArgs::run(args).await,
             Self::SetManager(_) => SetManagerArgs::run(args).await,
+            Self::AcceptManager(_) => AcceptManagerArgs::run(args).await,
+            Self::MigrateState(_) => MigrateStateArgs::run(args).await,
             Self::UpdateLastUpgradeSlot(_) => UpdateLastUpgradeSlotArgs::run(args).await,
             Self::View(_) => ViewArgs::run(args).await,
             Self::SolToLst(_) => SolToLstArgs::run(args).await,
@@ -7,7 +7,9 @@ use sanctum_solana_cli_utils::{parse_signer, PubkeySrc};
 use super::{common::verify_manager, Subcmd};
 
 #[derive(Args, Debug)]
-#[command(long_about = "Sets the SOL value calculator program's manager")]
+#[command(
+    long_about = "Proposes a new manager for the SOL value calculator program. The new manager must then run accept-manager to take over."
+)]
 pub struct SetManagerArgs {
     #[arg(
         long,
@@ -16,7 +18,7 @@ pub struct SetManagerArgs {
     )]
     pub curr_manager: Option<String>,
 
-    #[arg(help = "The new program's manager to set. Can be a pubkey or signer.")]
+    #[arg(help = "The new program's manager to propose. Can be a pubkey or signer.")]
     pub new_manager: String,
 }
 
@@ -42,7 +44,7 @@ impl SetManagerArgs {
             .0;
         let state_data = rpc.get_account_data(&state_pda).await.unwrap();
         let state = try_calculator_state(&state_data).unwrap();
-        verify_manager(state, curr_manager.pubkey()).unwrap();
+        verify_manager(&state, curr_manager.pubkey()).unwrap();
 
         let ix = set_manager_ix_with_program_id(
             program_id,
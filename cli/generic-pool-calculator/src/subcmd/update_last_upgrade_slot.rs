@@ -59,7 +59,7 @@ impl UpdateLastUpgradeSlotArgs {
             .0;
         let state_data = rpc.get_account_data(&state_pda).await.unwrap();
         let state = try_calculator_state(&state_data).unwrap();
-        verify_manager(state, curr_manager.pubkey()).unwrap();
+        verify_manager(&state, curr_manager.pubkey()).unwrap();
 
         // programdata addr is BpfLoader PDA [program_addr]:
         // https://docs.rs/solana-program/latest/src/solana_program/bpf_loader_upgradeable.rs.html#211
@@ -4,7 +4,9 @@ use generic_pool_calculator_lib::{pda::CalculatorStateFindPdaArgs, utils::try_ca
 use super::Subcmd;
 
 #[derive(Args, Debug)]
-#[command(long_about = "Views the generic pool calculator's state (manager + last_upgrade_slot)")]
+#[command(
+    long_about = "Views the generic pool calculator's state (manager, last_upgrade_slot, pending_manager)"
+)]
 pub struct ViewArgs;
 
 impl ViewArgs {
@@ -4,7 +4,7 @@ use sanctum_solana_test_utils::{
     banks_rpc_server::BanksRpcServer, cli::TempCliConfig, ExtendedProgramTest,
 };
 use solana_program_test::{BanksClient, ProgramTest};
-use solana_sdk::{hash::Hash, signature::Keypair, signer::Signer};
+use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Keypair, signer::Signer};
 
 use super::GpcSplProgramTest;
 
@@ -34,6 +34,26 @@ pub async fn setup_with_payer_as_manager(
     (cmd, cfg, bc, payer, rbh)
 }
 
+pub async fn setup_with_payer_as_pending_manager(
+    last_upgrade_slot: u64,
+) -> (Command, TempCliConfig, BanksClient, Keypair, Hash) {
+    let payer = Keypair::new();
+    let pt = ProgramTest::default()
+        .add_spl_programs()
+        .add_system_account(payer.pubkey(), 1_000_000_000)
+        .add_mock_spl_calculator_state_with_pending_manager(
+            last_upgrade_slot,
+            Pubkey::new_unique(),
+            payer.pubkey(),
+        );
+    let (bc, _rng_payer, rbh) = pt.start().await;
+
+    let (port, _jh) = BanksRpcServer::spawn_random_unused(bc.clone()).await;
+    let cfg = TempCliConfig::from_keypair_and_local_port(&payer, port);
+    let cmd = base_cmd(&cfg);
+    (cmd, cfg, bc, payer, rbh)
+}
+
 pub async fn setup(pt: ProgramTest) -> (Command, TempCliConfig, BanksClient, Keypair, Hash) {
     let pt = pt.add_spl_programs();
     let (bc, payer, rbh) = pt.start().await;
@@ -53,6 +73,10 @@ pub trait TestGpcCmd {
 
     fn cmd_set_manager(&mut self) -> &mut Self;
 
+    fn cmd_accept_manager(&mut self) -> &mut Self;
+
+    fn cmd_migrate_state(&mut self) -> &mut Self;
+
     fn cmd_update_last_upgrade_slot(&mut self) -> &mut Self;
 
     fn cmd_view(&mut self) -> &mut Self;
@@ -75,6 +99,14 @@ impl TestGpcCmd for Command {
         self.arg("set-manager")
     }
 
+    fn cmd_accept_manager(&mut self) -> &mut Self {
+        self.arg("accept-manager")
+    }
+
+    fn cmd_migrate_state(&mut self) -> &mut Self {
+        self.arg("migrate-state")
+    }
+
     fn cmd_update_last_upgrade_slot(&mut self) -> &mut Self {
         self.arg("update-last-upgrade-slot")
     }
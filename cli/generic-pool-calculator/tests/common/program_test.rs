@@ -8,6 +8,13 @@ use solana_program_test::{processor, ProgramTest};
 pub trait GpcSplProgramTest {
     fn add_spl_programs(self) -> Self;
     fn add_mock_spl_calculator_state(self, last_upgrade_slot: u64, manager: Pubkey) -> Self;
+    fn add_mock_spl_calculator_state_with_pending_manager(
+        self,
+        last_upgrade_slot: u64,
+        manager: Pubkey,
+        pending_manager: Pubkey,
+    ) -> Self;
+    fn add_mock_legacy_spl_calculator_state(self, last_upgrade_slot: u64, manager: Pubkey) -> Self;
 }
 
 impl GpcSplProgramTest for ProgramTest {
@@ -25,9 +32,32 @@ impl GpcSplProgramTest for ProgramTest {
     }
 
     fn add_mock_spl_calculator_state(self, last_upgrade_slot: u64, manager: Pubkey) -> Self {
+        self.add_mock_spl_calculator_state_with_pending_manager(
+            last_upgrade_slot,
+            manager,
+            Pubkey::default(),
+        )
+    }
+
+    fn add_mock_spl_calculator_state_with_pending_manager(
+        self,
+        last_upgrade_slot: u64,
+        manager: Pubkey,
+        pending_manager: Pubkey,
+    ) -> Self {
         self.add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager,
             last_upgrade_slot,
+            pending_manager,
+            owner: spl_calculator_lib::program::ID,
+        })
+    }
+
+    fn add_mock_legacy_spl_calculator_state(self, last_upgrade_slot: u64, manager: Pubkey) -> Self {
+        self.add_mock_legacy_calculator_state(MockCalculatorStateAccountArgs {
+            manager,
+            last_upgrade_slot,
+            pending_manager: Pubkey::default(),
             owner: spl_calculator_lib::program::ID,
         })
     }
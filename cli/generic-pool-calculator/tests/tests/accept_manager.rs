@@ -0,0 +1,46 @@
+use generic_pool_calculator_lib::utils::try_calculator_state;
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, temp_keypair_file, ExtendedCommand},
+    ExtendedBanksClient,
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::common::{setup, setup_with_payer_as_pending_manager, GpcSplProgramTest, TestGpcCmd};
+
+async fn assert_accepted_manager(bc: &mut BanksClient, expected_manager: Pubkey) {
+    let state_data = bc
+        .get_account_data(spl_calculator_lib::program::SPL_CALCULATOR_STATE_ID)
+        .await;
+    let state = try_calculator_state(&state_data).unwrap();
+    assert_eq!(state.manager, expected_manager);
+    assert_eq!(state.pending_manager, Pubkey::default());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn accept_manager_success_payer_as_pending_manager() {
+    let (mut cmd, _cfg, mut bc, payer, _rbh) = setup_with_payer_as_pending_manager(0).await;
+    cmd.with_spl_calculator().cmd_accept_manager();
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_accepted_manager(&mut bc, payer.pubkey()).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn accept_manager_success_separate_pending_manager() {
+    let pending_manager = Keypair::new();
+    let pending_manager_keyfile = temp_keypair_file(&pending_manager);
+    let pt = ProgramTest::default().add_mock_spl_calculator_state_with_pending_manager(
+        0,
+        Pubkey::new_unique(),
+        pending_manager.pubkey(),
+    );
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(pt).await;
+    cmd.with_spl_calculator()
+        .cmd_accept_manager()
+        .arg("-p")
+        .arg(pending_manager_keyfile.path());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_accepted_manager(&mut bc, pending_manager.pubkey()).await;
+}
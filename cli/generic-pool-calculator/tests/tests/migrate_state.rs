@@ -0,0 +1,35 @@
+use generic_pool_calculator_lib::{utils::try_calculator_state, CALCULATOR_STATE_SIZE};
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, temp_keypair_file, ExtendedCommand},
+    ExtendedBanksClient,
+};
+use solana_program_test::ProgramTest;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::common::{setup, GpcSplProgramTest, TestGpcCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn migrate_state_success_separate_manager() {
+    const LAST_UPGRADE_SLOT: u64 = 69;
+
+    let manager = Keypair::new();
+    let manager_keyfile = temp_keypair_file(&manager);
+    let pt = ProgramTest::default()
+        .add_mock_legacy_spl_calculator_state(LAST_UPGRADE_SLOT, manager.pubkey());
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(pt).await;
+    cmd.with_spl_calculator()
+        .cmd_migrate_state()
+        .arg("-m")
+        .arg(manager_keyfile.path());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let state_data = bc
+        .get_account_data(spl_calculator_lib::program::SPL_CALCULATOR_STATE_ID)
+        .await;
+    assert_eq!(state_data.len(), CALCULATOR_STATE_SIZE);
+    let state = try_calculator_state(&state_data).unwrap();
+    assert_eq!(state.manager, manager.pubkey());
+    assert_eq!(state.last_upgrade_slot, LAST_UPGRADE_SLOT);
+    assert_eq!(state.pending_manager, Pubkey::default());
+}
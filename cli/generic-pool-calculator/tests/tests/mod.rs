@@ -1,4 +1,6 @@
+mod accept_manager;
 mod init;
+mod migrate_state;
 mod set_manager;
 mod update_last_upgrade_slot;
 mod view;
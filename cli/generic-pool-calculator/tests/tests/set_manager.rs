@@ -8,24 +8,29 @@ use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
 
 use crate::common::{setup, setup_with_payer_as_manager, GpcSplProgramTest, TestGpcCmd};
 
-async fn assert_new_manager(bc: &mut BanksClient, expected_new_manager: Pubkey) {
+async fn assert_pending_manager(
+    bc: &mut BanksClient,
+    expected_manager: Pubkey,
+    expected_pending_manager: Pubkey,
+) {
     let state_data = bc
         .get_account_data(spl_calculator_lib::program::SPL_CALCULATOR_STATE_ID)
         .await;
     let state = try_calculator_state(&state_data).unwrap();
-    assert_eq!(state.manager, expected_new_manager);
+    assert_eq!(state.manager, expected_manager);
+    assert_eq!(state.pending_manager, expected_pending_manager);
 }
 
 #[tokio::test(flavor = "multi_thread")]
 async fn set_manager_success_payer_as_manager_new_manager_pubkey() {
     let new_manager = Pubkey::new_unique();
-    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup_with_payer_as_manager(0).await;
+    let (mut cmd, _cfg, mut bc, payer, _rbh) = setup_with_payer_as_manager(0).await;
     cmd.with_spl_calculator()
         .cmd_set_manager()
         .arg(new_manager.to_string());
     let exec_res = cmd.exec_b64_txs(&mut bc).await;
     assert_all_txs_success_nonempty(&exec_res);
-    assert_new_manager(&mut bc, new_manager).await;
+    assert_pending_manager(&mut bc, payer.pubkey(), new_manager).await;
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -42,5 +47,5 @@ async fn set_manager_success_separate_manager_new_manager_pubkey() {
         .arg(new_manager.to_string());
     let exec_res = cmd.exec_b64_txs(&mut bc).await;
     assert_all_txs_success_nonempty(&exec_res);
-    assert_new_manager(&mut bc, new_manager).await;
+    assert_pending_manager(&mut bc, curr_manager.pubkey(), new_manager).await;
 }
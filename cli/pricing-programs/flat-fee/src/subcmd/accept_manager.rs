@@ -0,0 +1,61 @@
+use clap::Args;
+use flat_fee_interface::{accept_manager_ix_with_program_id, AcceptManagerKeys};
+use flat_fee_lib::{pda::ProgramStateFindPdaArgs, utils::try_program_state};
+use s_cli_utils::handle_tx_full;
+use sanctum_solana_cli_utils::parse_signer;
+
+use super::{common::verify_pending_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Accepts a manager transfer proposed with set-manager, making the pending manager the flat-fee pricing program's manager"
+)]
+pub struct AcceptManagerArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's pending manager signer. Defaults to config wallet if not set."
+    )]
+    pub pending_manager: Option<String>,
+}
+
+impl AcceptManagerArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { pending_manager } = match args.subcmd {
+            Subcmd::AcceptManager(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let pending_manager_signer = pending_manager.map(|s| parse_signer(&s).unwrap());
+        let pending_manager = pending_manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_data = rpc.get_account_data(&state_pda).await.unwrap();
+        let state = try_program_state(&state_data).unwrap();
+        verify_pending_manager(&state, pending_manager.pubkey()).unwrap();
+
+        let ix = accept_manager_ix_with_program_id(
+            program_id,
+            AcceptManagerKeys {
+                pending_manager: state.pending_manager,
+                state: state_pda,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), pending_manager.as_ref()],
+        )
+        .await;
+    }
+}
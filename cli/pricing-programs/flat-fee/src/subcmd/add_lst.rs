@@ -60,7 +60,7 @@ impl AddLstArgs {
             .0;
         let state_acc = rpc.get_account(&state_pda).await.unwrap();
         let state = try_program_state(&state_acc.data).unwrap();
-        verify_manager(state, manager.pubkey()).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
 
         let ix = add_lst_ix_with_program_id(
             program_id,
@@ -0,0 +1,98 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use flat_fee_interface::{add_pair_fee_ix_with_program_id, AddPairFeeIxArgs};
+use flat_fee_lib::{
+    account_resolvers::AddPairFeeFreeArgs, pda::ProgramStateFindPdaArgs, utils::try_program_state,
+};
+use s_cli_utils::handle_tx_full;
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Add a fee for swapping from an input LST to an output LST that overrides the sum of their LST fees"
+)]
+pub struct AddPairFeeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        help = "Input LST of the pair. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub input: LstArg,
+
+    #[arg(
+        help = "Output LST of the pair. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub output: LstArg,
+
+    #[arg(help = "Fee in bips to impose when swapping from input to output")]
+    pub fee_bps: i16,
+}
+
+impl AddPairFeeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            input,
+            output,
+            fee_bps,
+        } = match args.subcmd {
+            Subcmd::AddPairFee(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let ix = add_pair_fee_ix_with_program_id(
+            program_id,
+            AddPairFeeFreeArgs {
+                payer: payer.pubkey(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+                input_lst_mint: input.mint(),
+                output_lst_mint: output.mint(),
+            }
+            .resolve_for_prog(program_id)
+            .unwrap()
+            .0,
+            AddPairFeeIxArgs { fee_bps },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -21,6 +21,24 @@ pub fn verify_manager(state: &ProgramState, curr_manager: Pubkey) -> Result<(),
     Ok(())
 }
 
+pub fn verify_pending_manager(
+    state: &ProgramState,
+    pending_manager: Pubkey,
+) -> Result<(), Infallible> {
+    if state.pending_manager == Pubkey::default() {
+        eprintln!("No pending manager. Propose one with set-manager first");
+        std::process::exit(-1);
+    }
+    if state.pending_manager != pending_manager {
+        eprintln!(
+            "Wrong pending manager. Expected: {}. Got: {}",
+            state.pending_manager, pending_manager
+        );
+        std::process::exit(-1);
+    }
+    Ok(())
+}
+
 pub async fn handle_pricing_ix(rpc: &RpcClient, ix: Instruction, payer: &dyn Signer) {
     let tx = to_est_cu_sim_tx(&payer.pubkey(), &[ix], &[]).unwrap();
     let RpcSimulateTransactionResult {
@@ -0,0 +1,96 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use flat_fee_interface::migrate_lst_fee_ix_with_program_id;
+use flat_fee_lib::{
+    account_resolvers::MigrateLstFeeFreeArgs,
+    pda::{FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
+    program::LEGACY_FEE_ACCOUNT_SIZE,
+    utils::try_program_state,
+};
+use s_cli_utils::handle_tx_full;
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Migrates the FeeAccount of a given LST created before the LP deposit fee override and fee ramp fields were added to the current layout. The config wallet pays for the additional rent."
+)]
+pub struct MigrateLstFeeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        help = "Mint of the LST to migrate the FeeAccount of. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub lst_mint: LstArg,
+}
+
+impl MigrateLstFeeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { manager, lst_mint } = match args.subcmd {
+            Subcmd::MigrateLstFee(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let fee_acc = FeeAccountFindPdaArgs {
+            program_id,
+            lst_mint: lst_mint.mint(),
+        }
+        .get_fee_account_address_and_bump_seed()
+        .0;
+        let fee_acc_data = rpc.get_account_data(&fee_acc).await.unwrap();
+        if fee_acc_data.len() != LEGACY_FEE_ACCOUNT_SIZE {
+            eprintln!("FeeAccount already migrated");
+            return;
+        }
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let ix = migrate_lst_fee_ix_with_program_id(
+            program_id,
+            MigrateLstFeeFreeArgs {
+                payer: payer.pubkey(),
+                fee_acc,
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
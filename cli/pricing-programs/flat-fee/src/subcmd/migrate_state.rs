@@ -0,0 +1,70 @@
+use clap::Args;
+use flat_fee_interface::{migrate_state_ix_with_program_id, MigrateStateKeys};
+use flat_fee_lib::{
+    pda::ProgramStateFindPdaArgs, program::LEGACY_STATE_SIZE, utils::try_program_state,
+};
+use s_cli_utils::handle_tx_full;
+use sanctum_solana_cli_utils::parse_signer;
+use solana_sdk::system_program;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Migrates a ProgramState created before pending_manager was added to the current layout. The config wallet pays for the additional rent."
+)]
+pub struct MigrateStateArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+}
+
+impl MigrateStateArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { manager } = match args.subcmd {
+            Subcmd::MigrateState(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_data = rpc.get_account_data(&state_pda).await.unwrap();
+        if state_data.len() != LEGACY_STATE_SIZE {
+            eprintln!("ProgramState already migrated");
+            return;
+        }
+        let state = try_program_state(&state_data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let ix = migrate_state_ix_with_program_id(
+            program_id,
+            MigrateStateKeys {
+                manager: state.manager,
+                payer: payer.pubkey(),
+                state: state_pda,
+                system_program: system_program::ID,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -1,42 +1,72 @@
 use clap::Subcommand;
 
+mod accept_manager;
 mod add_lst;
+mod add_pair_fee;
 mod common;
 mod initialize;
+mod migrate_lst_fee;
+mod migrate_state;
 mod price_exact_in;
 mod price_exact_out;
 mod price_lp_tokens_to_mint;
 mod price_lp_tokens_to_redeem;
 mod remove_lst;
+mod remove_pair_fee;
+mod set_epoch_surcharge;
+mod set_lp_deposit_fee;
 mod set_lp_withdrawal_fee;
 mod set_lst_fee;
+mod set_lst_fee_ramp;
 mod set_manager;
+mod set_pair_fee;
 mod view;
 mod view_lst;
+mod view_pair_fee;
 
+use accept_manager::AcceptManagerArgs;
 use add_lst::AddLstArgs;
+use add_pair_fee::AddPairFeeArgs;
 use initialize::InitializeArgs;
+use migrate_lst_fee::MigrateLstFeeArgs;
+use migrate_state::MigrateStateArgs;
 use remove_lst::RemoveLstArgs;
+use remove_pair_fee::RemovePairFeeArgs;
+use set_epoch_surcharge::SetEpochSurchargeArgs;
+use set_lp_deposit_fee::SetLpDepositFeeArgs;
 use set_lp_withdrawal_fee::SetLpWithdrawalFeeArgs;
 use set_lst_fee::SetLstFeeArgs;
+use set_lst_fee_ramp::SetLstFeeRampArgs;
 use set_manager::SetManagerArgs;
+use set_pair_fee::SetPairFeeArgs;
 
 use self::{
     price_exact_in::PriceExactInArgs, price_exact_out::PriceExactOutArgs,
     price_lp_tokens_to_mint::PriceLpTokensToMintArgs,
     price_lp_tokens_to_redeem::PriceLpTokensToRedeemArgs, view::ViewArgs, view_lst::ViewLstArgs,
+    view_pair_fee::ViewPairFeeArgs,
 };
 
 #[derive(Debug, Subcommand)]
 pub enum Subcmd {
     Initialize,
     SetManager(SetManagerArgs),
+    AcceptManager(AcceptManagerArgs),
     AddLst(AddLstArgs),
     RemoveLst(RemoveLstArgs),
     SetLstFee(SetLstFeeArgs),
+    SetLstFeeRamp(SetLstFeeRampArgs),
+    AddPairFee(AddPairFeeArgs),
+    RemovePairFee(RemovePairFeeArgs),
+    SetPairFee(SetPairFeeArgs),
     SetLpWithdrawalFee(SetLpWithdrawalFeeArgs),
+    SetLpDepositFee(SetLpDepositFeeArgs),
+    SetEpochSurcharge(SetEpochSurchargeArgs),
+    MigrateState(MigrateStateArgs),
+    MigrateLstFee(MigrateLstFeeArgs),
     View(ViewArgs),
     ViewLst(ViewLstArgs),
+    ViewPairFee(ViewPairFeeArgs),
     PriceExactIn(PriceExactInArgs),
     PriceExactOut(PriceExactOutArgs),
     PriceLpTokensToMint(PriceLpTokensToMintArgs),
@@ -48,12 +78,22 @@ impl Subcmd {
         match &args.subcmd {
             Self::Initialize => InitializeArgs::run(args).await,
             Self::SetManager(_) => SetManagerArgs::run(args).await,
+            Self::AcceptManager(_) => AcceptManagerArgs::run(args).await,
             Self::AddLst(_) => AddLstArgs::run(args).await,
             Self::RemoveLst(_) => RemoveLstArgs::run(args).await,
             Self::SetLstFee(_) => SetLstFeeArgs::run(args).await,
+            Self::SetLstFeeRamp(_) => SetLstFeeRampArgs::run(args).await,
+            Self::AddPairFee(_) => AddPairFeeArgs::run(args).await,
+            Self::RemovePairFee(_) => RemovePairFeeArgs::run(args).await,
+            Self::SetPairFee(_) => SetPairFeeArgs::run(args).await,
             Self::SetLpWithdrawalFee(_) => SetLpWithdrawalFeeArgs::run(args).await,
+            Self::SetLpDepositFee(_) => SetLpDepositFeeArgs::run(args).await,
+            Self::SetEpochSurcharge(_) => SetEpochSurchargeArgs::run(args).await,
+            Self::MigrateState(_) => MigrateStateArgs::run(args).await,
+            Self::MigrateLstFee(_) => MigrateLstFeeArgs::run(args).await,
             Self::View(_) => ViewArgs::run(args).await,
             Self::ViewLst(_) => ViewLstArgs::run(args).await,
+            Self::ViewPairFee(_) => ViewPairFeeArgs::run(args).await,
             Self::PriceExactIn(_) => PriceExactInArgs::run(args).await,
             Self::PriceExactOut(_) => PriceExactOutArgs::run(args).await,
             Self::PriceLpTokensToMint(_) => PriceLpTokensToMintArgs::run(args).await,
@@ -2,9 +2,11 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
-use flat_fee_interface::{price_exact_in_ix_with_program_id, PriceExactInIxArgs, PriceExactInKeys};
-use flat_fee_lib::pda::FeeAccountFindPdaArgs;
-use solana_sdk::native_token::sol_to_lamports;
+use flat_fee_interface::{price_exact_in_ix_with_program_id, PriceExactInIxArgs};
+use flat_fee_lib::{account_resolvers::PriceExactInFreeArgs, pda::PairFeeAccountFindPdaArgs};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, instruction::AccountMeta, native_token::sol_to_lamports,
+};
 
 use crate::lst_arg::LstArg;
 
@@ -60,27 +62,14 @@ impl PriceExactInArgs {
         let program_id = args.program;
 
         let input_lst_mint = input.mint();
-        let input_fee_acc = FeeAccountFindPdaArgs {
-            program_id,
-            lst_mint: input_lst_mint,
-        }
-        .get_fee_account_address_and_bump_seed()
-        .0;
         let output_lst_mint = output.mint();
-        let output_fee_acc = FeeAccountFindPdaArgs {
-            program_id,
-            lst_mint: output_lst_mint,
-        }
-        .get_fee_account_address_and_bump_seed()
-        .0;
-        let ix = price_exact_in_ix_with_program_id(
+        let mut ix = price_exact_in_ix_with_program_id(
             program_id,
-            PriceExactInKeys {
+            PriceExactInFreeArgs {
                 input_lst_mint,
                 output_lst_mint,
-                input_fee_acc,
-                output_fee_acc,
-            },
+            }
+            .resolve_for_prog(program_id),
             PriceExactInIxArgs {
                 amount: sol_to_lamports(amount),
                 sol_value: sol_to_lamports(sol_value),
@@ -88,6 +77,24 @@ impl PriceExactInArgs {
         )
         .unwrap();
 
+        // PairFeeAccount is an optional trailing account, only pass it if it exists
+        let (pair_fee_acc, _bump) = PairFeeAccountFindPdaArgs {
+            program_id,
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .get_pair_fee_account_address_and_bump_seed();
+        let pair_fee_acc_exists = rpc
+            .get_account_with_commitment(&pair_fee_acc, CommitmentConfig::default())
+            .await
+            .unwrap()
+            .value
+            .is_some();
+        if pair_fee_acc_exists {
+            ix.accounts
+                .push(AccountMeta::new_readonly(pair_fee_acc, false));
+        }
+
         handle_pricing_ix(&rpc, ix, payer.as_ref()).await;
     }
 }
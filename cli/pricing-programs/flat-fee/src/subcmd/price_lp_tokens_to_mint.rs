@@ -2,9 +2,8 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
-use flat_fee_interface::{
-    price_lp_tokens_to_mint_ix_with_program_id, PriceLpTokensToMintIxArgs, PriceLpTokensToMintKeys,
-};
+use flat_fee_interface::{price_lp_tokens_to_mint_ix_with_program_id, PriceLpTokensToMintIxArgs};
+use flat_fee_lib::account_resolvers::PriceLpTokensToMintFreeArgs;
 use solana_sdk::native_token::sol_to_lamports;
 
 use crate::lst_arg::LstArg;
@@ -55,9 +54,10 @@ impl PriceLpTokensToMintArgs {
 
         let ix = price_lp_tokens_to_mint_ix_with_program_id(
             program_id,
-            PriceLpTokensToMintKeys {
+            PriceLpTokensToMintFreeArgs {
                 input_lst_mint: lst_mint.mint(),
-            },
+            }
+            .resolve_for_prog(program_id),
             PriceLpTokensToMintIxArgs {
                 amount: sol_to_lamports(amount),
                 sol_value: sol_to_lamports(sol_value),
@@ -56,7 +56,7 @@ impl RemoveLstArgs {
             .0;
         let state_acc = rpc.get_account(&state_pda).await.unwrap();
         let state = try_program_state(&state_acc.data).unwrap();
-        verify_manager(state, manager.pubkey()).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
 
         let refund_rent_to = PubkeySrc::parse(&refund_rent_to).unwrap();
 
@@ -0,0 +1,85 @@
+use clap::Args;
+use flat_fee_interface::{set_epoch_surcharge_ix_with_program_id, SetEpochSurchargeIxArgs};
+use flat_fee_lib::{
+    account_resolvers::SetEpochSurchargeFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state,
+};
+use s_cli_utils::handle_tx_full;
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Update the surcharge imposed on all fees during the first and last slots of every epoch"
+)]
+pub struct SetEpochSurchargeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(help = "Surcharge in bips to add to all fees within the epoch boundary window")]
+    pub epoch_surcharge_bps: u16,
+
+    #[arg(
+        help = "Number of slots at the start and end of every epoch to impose the surcharge in. 0 disables the surcharge"
+    )]
+    pub epoch_surcharge_slots: u16,
+}
+
+impl SetEpochSurchargeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            epoch_surcharge_bps,
+            epoch_surcharge_slots,
+        } = match args.subcmd {
+            Subcmd::SetEpochSurcharge(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let ix = set_epoch_surcharge_ix_with_program_id(
+            program_id,
+            SetEpochSurchargeFreeArgs {
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+            SetEpochSurchargeIxArgs {
+                epoch_surcharge_bps,
+                epoch_surcharge_slots,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
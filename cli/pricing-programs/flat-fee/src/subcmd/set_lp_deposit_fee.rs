@@ -0,0 +1,122 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use flat_fee_interface::{
+    set_lp_deposit_fee_ix_with_program_id, set_lst_lp_deposit_fee_ix_with_program_id,
+    SetLpDepositFeeIxArgs, SetLstLpDepositFeeIxArgs,
+};
+use flat_fee_lib::{
+    account_resolvers::{SetLpDepositFeeFreeArgs, SetLstLpDepositFeeByMintFreeArgs},
+    pda::ProgramStateFindPdaArgs,
+    utils::try_program_state,
+};
+use s_cli_utils::handle_tx_full;
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Update the fees imposed for minting LP tokens with LST, either globally or as an override for a given LST"
+)]
+pub struct SetLpDepositFeeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        long,
+        short,
+        help = "Mint of the LST to set the fee override for. Sets the global fee if not set. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub lst: Option<LstArg>,
+
+    #[arg(
+        long,
+        requires = "lst",
+        conflicts_with = "lp_deposit_fee_bps",
+        help = "Remove the fee override for the given LST, reverting to the global fee"
+    )]
+    pub clear: bool,
+
+    #[arg(
+        required_unless_present = "clear",
+        help = "Fee in bips to impose when minting LP tokens with LST"
+    )]
+    pub lp_deposit_fee_bps: Option<u16>,
+}
+
+impl SetLpDepositFeeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            lst,
+            clear: _,
+            lp_deposit_fee_bps,
+        } = match args.subcmd {
+            Subcmd::SetLpDepositFee(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let state_acc = KeyedAccount {
+            pubkey: state_pda,
+            account: state_acc,
+        };
+        let ix = match lst {
+            Some(lst) => set_lst_lp_deposit_fee_ix_with_program_id(
+                program_id,
+                SetLstLpDepositFeeByMintFreeArgs {
+                    lst_mint: lst.mint(),
+                    state_acc,
+                }
+                .resolve_for_prog(program_id)
+                .unwrap(),
+                // clap ensures lp_deposit_fee_bps is None if --clear is set
+                SetLstLpDepositFeeIxArgs { lp_deposit_fee_bps },
+            ),
+            None => set_lp_deposit_fee_ix_with_program_id(
+                program_id,
+                SetLpDepositFeeFreeArgs { state_acc }
+                    .resolve_for_prog(program_id)
+                    .unwrap(),
+                SetLpDepositFeeIxArgs {
+                    // clap ensures this is set if --clear is not set,
+                    // and --clear requires --lst
+                    lp_deposit_fee_bps: lp_deposit_fee_bps.unwrap(),
+                },
+            ),
+        }
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
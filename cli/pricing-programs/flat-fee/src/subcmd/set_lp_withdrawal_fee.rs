@@ -45,7 +45,7 @@ impl SetLpWithdrawalFeeArgs {
             .0;
         let state_acc = rpc.get_account(&state_pda).await.unwrap();
         let state = try_program_state(&state_acc.data).unwrap();
-        verify_manager(state, manager.pubkey()).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
 
         let ix = set_lp_withdrawal_fee_ix_with_program_id(
             program_id,
@@ -0,0 +1,114 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use flat_fee_interface::{set_lst_fee_ramp_ix_with_program_id, SetLstFeeRampIxArgs};
+use flat_fee_lib::{
+    account_resolvers::SetLstFeeRampByMintFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state,
+};
+use s_cli_utils::handle_tx_full;
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Linearly ramp the fees for given LST from its current fees to the target fees over a range of slots"
+)]
+pub struct SetLstFeeRampArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        long,
+        help = "Slot to start the ramp at. Defaults to the current slot if not set."
+    )]
+    pub start_slot: Option<u64>,
+
+    #[arg(long, help = "Slot at which the target fees take full effect")]
+    pub end_slot: u64,
+
+    #[arg(
+        help = "Mint of the LST to set fees for. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub lst_mint: LstArg,
+
+    #[arg(help = "Fee in bips to impose when the LST is used as input at the end of the ramp")]
+    pub target_input_fee_bps: i16,
+
+    #[arg(help = "Fee in bips to impose when the LST is used as output at the end of the ramp")]
+    pub target_output_fee_bps: i16,
+}
+
+impl SetLstFeeRampArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            start_slot,
+            end_slot,
+            lst_mint,
+            target_input_fee_bps,
+            target_output_fee_bps,
+        } = match args.subcmd {
+            Subcmd::SetLstFeeRamp(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let start_slot = match start_slot {
+            Some(s) => s,
+            None => rpc.get_slot().await.unwrap(),
+        };
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let ix = set_lst_fee_ramp_ix_with_program_id(
+            program_id,
+            SetLstFeeRampByMintFreeArgs {
+                lst_mint: lst_mint.mint(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+            SetLstFeeRampIxArgs {
+                target_input_fee_bps,
+                target_output_fee_bps,
+                start_slot,
+                end_slot,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use clap::Args;
 use flat_fee_lib::{
     pda::{FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
-    utils::{try_fee_account, try_program_state},
+    utils::{try_fee_account, try_pair_fee_account, try_program_state},
 };
 use sanctum_lst_list::SanctumLst;
 use solana_sdk::pubkey::Pubkey;
@@ -59,6 +59,13 @@ impl ViewArgs {
         });
 
         for (pk, acc) in program_accs {
+            // remaining accounts are either FeeAccounts or PairFeeAccounts
+            if let Ok(pair_fee) = try_pair_fee_account(&acc.data) {
+                println!("Pair {pk}:");
+                println!("{pair_fee:#?}");
+                println!();
+                continue;
+            }
             let symbol = pda_to_lst
                 .get(&pk)
                 .map_or_else(|| "Unknown LST", |SanctumLst { symbol, .. }| symbol);
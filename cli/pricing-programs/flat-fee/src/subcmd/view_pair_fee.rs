@@ -0,0 +1,47 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use flat_fee_lib::{pda::PairFeeAccountFindPdaArgs, utils::try_pair_fee_account};
+
+use crate::{lst_arg::LstArg, subcmd::Subcmd};
+
+#[derive(Args, Debug)]
+#[command(long_about = "View the current fee for a given LST pair")]
+pub struct ViewPairFeeArgs {
+    #[arg(
+        help = "Input LST of the pair. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub input: LstArg,
+
+    #[arg(
+        help = "Output LST of the pair. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub output: LstArg,
+}
+
+impl ViewPairFeeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { input, output } = match args.subcmd {
+            Subcmd::ViewPairFee(a) => a,
+            _ => unreachable!(),
+        };
+
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let pair_fee_account_pda = PairFeeAccountFindPdaArgs {
+            program_id,
+            input_lst_mint: input.mint(),
+            output_lst_mint: output.mint(),
+        }
+        .get_pair_fee_account_address_and_bump_seed()
+        .0;
+        let pair_fee_account_data = rpc.get_account_data(&pair_fee_account_pda).await.unwrap();
+        let pair_fee_account = try_pair_fee_account(&pair_fee_account_data).unwrap();
+
+        println!("{pair_fee_account:#?}");
+    }
+}
@@ -1,7 +1,10 @@
 use assert_cmd::Command;
 use cli_test_utils::TestCliCmd;
 use flat_fee_interface::ProgramState;
-use flat_fee_test_utils::{FlatFeeProgramTest, MockFeeAccount, MockFeeAccountArgs};
+use flat_fee_test_utils::{
+    FlatFeeProgramTest, MockFeeAccount, MockFeeAccountArgs, MockPairFeeAccount,
+    MockPairFeeAccountArgs,
+};
 use sanctum_solana_test_utils::{
     banks_rpc_server::BanksRpcServer,
     cli::TempCliConfig,
@@ -82,6 +85,17 @@ pub async fn setup(
     (cmd, cfg, bc, payer, rbh)
 }
 
+pub fn add_mock_pair_fee_accounts(
+    mut pt: ProgramTest,
+    mock_pair_fee_accounts: &[MockPairFeeAccountArgs],
+) -> ProgramTest {
+    for mpfa in mock_pair_fee_accounts {
+        let (acc, addr) = mpfa.to_pair_fee_account_and_addr(flat_fee_lib::program::ID);
+        pt.add_account(addr, MockPairFeeAccount(acc).into_account());
+    }
+    pt
+}
+
 pub fn cargo_bin() -> Command {
     Command::cargo_bin("flt").unwrap()
 }
@@ -99,17 +113,37 @@ pub trait TestCmd {
 
     fn cmd_set_manager(&mut self) -> &mut Self;
 
+    fn cmd_migrate_state(&mut self) -> &mut Self;
+
+    fn cmd_migrate_lst_fee(&mut self) -> &mut Self;
+
+    fn cmd_accept_manager(&mut self) -> &mut Self;
+
     fn cmd_set_lp_withdrawal_fee(&mut self) -> &mut Self;
 
+    fn cmd_set_lp_deposit_fee(&mut self) -> &mut Self;
+
+    fn cmd_set_epoch_surcharge(&mut self) -> &mut Self;
+
     fn cmd_add_lst(&mut self) -> &mut Self;
 
     fn cmd_remove_lst(&mut self) -> &mut Self;
 
     fn cmd_set_lst_fee(&mut self) -> &mut Self;
 
+    fn cmd_set_lst_fee_ramp(&mut self) -> &mut Self;
+
+    fn cmd_add_pair_fee(&mut self) -> &mut Self;
+
+    fn cmd_remove_pair_fee(&mut self) -> &mut Self;
+
+    fn cmd_set_pair_fee(&mut self) -> &mut Self;
+
     fn cmd_view(&mut self) -> &mut Self;
 
     fn cmd_view_lst(&mut self) -> &mut Self;
+
+    fn cmd_view_pair_fee(&mut self) -> &mut Self;
 }
 
 impl TestCmd for Command {
@@ -125,10 +159,30 @@ impl TestCmd for Command {
         self.arg("set-manager")
     }
 
+    fn cmd_migrate_state(&mut self) -> &mut Self {
+        self.arg("migrate-state")
+    }
+
+    fn cmd_migrate_lst_fee(&mut self) -> &mut Self {
+        self.arg("migrate-lst-fee")
+    }
+
+    fn cmd_accept_manager(&mut self) -> &mut Self {
+        self.arg("accept-manager")
+    }
+
     fn cmd_set_lp_withdrawal_fee(&mut self) -> &mut Self {
         self.arg("set-lp-withdrawal-fee")
     }
 
+    fn cmd_set_lp_deposit_fee(&mut self) -> &mut Self {
+        self.arg("set-lp-deposit-fee")
+    }
+
+    fn cmd_set_epoch_surcharge(&mut self) -> &mut Self {
+        self.arg("set-epoch-surcharge")
+    }
+
     fn cmd_add_lst(&mut self) -> &mut Self {
         self.arg("add-lst")
     }
@@ -141,6 +195,22 @@ impl TestCmd for Command {
         self.arg("set-lst-fee")
     }
 
+    fn cmd_set_lst_fee_ramp(&mut self) -> &mut Self {
+        self.arg("set-lst-fee-ramp")
+    }
+
+    fn cmd_add_pair_fee(&mut self) -> &mut Self {
+        self.arg("add-pair-fee")
+    }
+
+    fn cmd_remove_pair_fee(&mut self) -> &mut Self {
+        self.arg("remove-pair-fee")
+    }
+
+    fn cmd_set_pair_fee(&mut self) -> &mut Self {
+        self.arg("set-pair-fee")
+    }
+
     fn cmd_view(&mut self) -> &mut Self {
         self.arg("view")
     }
@@ -148,4 +218,8 @@ impl TestCmd for Command {
     fn cmd_view_lst(&mut self) -> &mut Self {
         self.arg("view-lst")
     }
+
+    fn cmd_view_pair_fee(&mut self) -> &mut Self {
+        self.arg("view-pair-fee")
+    }
 }
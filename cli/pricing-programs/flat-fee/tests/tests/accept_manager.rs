@@ -0,0 +1,69 @@
+use flat_fee_interface::ProgramState;
+use flat_fee_lib::utils::try_program_state;
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, temp_keypair_file, ExtendedCommand},
+    ExtendedBanksClient,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, TestCmd};
+
+async fn assert_accepted_manager(bc: &mut BanksClient, expected_manager: Pubkey) {
+    let state_data = bc.get_account_data(flat_fee_lib::program::STATE_ID).await;
+    let state = try_program_state(&state_data).unwrap();
+    assert_eq!(state.manager, expected_manager);
+    assert_eq!(state.pending_manager, Pubkey::default());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn accept_manager_success_payer_as_pending_manager() {
+    let payer = Keypair::new();
+
+    let program_state = ProgramState {
+        manager: Pubkey::new_unique(),
+        lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: payer.pubkey(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    };
+
+    let (mut cmd, _cfg, mut bc, payer, _rbh) =
+        setup(ProgramTest::default(), payer, Some(program_state), &[], &[]).await;
+
+    cmd.with_flat_fee_program().cmd_accept_manager();
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_accepted_manager(&mut bc, payer.pubkey()).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn accept_manager_success_separate_pending_manager() {
+    let payer = Keypair::new();
+    let pending_manager = Keypair::new();
+    let pending_manager_keyfile = temp_keypair_file(&pending_manager);
+
+    let program_state = ProgramState {
+        manager: payer.pubkey(),
+        lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: pending_manager.pubkey(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    };
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) =
+        setup(ProgramTest::default(), payer, Some(program_state), &[], &[]).await;
+
+    cmd.with_flat_fee_program()
+        .cmd_accept_manager()
+        .arg("-p")
+        .arg(pending_manager_keyfile.path());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_accepted_manager(&mut bc, pending_manager.pubkey()).await;
+}
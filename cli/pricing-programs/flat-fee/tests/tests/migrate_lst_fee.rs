@@ -0,0 +1,55 @@
+use flat_fee_interface::ProgramState;
+use flat_fee_lib::{
+    program::{FEE_ACCOUNT_SIZE, LEGACY_FEE_ACCOUNT_SIZE},
+    utils::try_fee_account,
+};
+use flat_fee_test_utils::{MockFeeAccountArgs, MockLegacyFeeAccount};
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, ExtendedCommand},
+    ExtendedBanksClient, IntoAccount,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn migrate_lst_fee_success() {
+    let lst_mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let program_state = ProgramState {
+        manager: payer.pubkey(),
+        lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    };
+    let (fee_acc, fee_acc_addr) = MockFeeAccountArgs {
+        input_fee_bps: 69,
+        output_fee_bps: 420,
+        lst_mint,
+    }
+    .to_fee_account_and_addr(flat_fee_lib::program::ID);
+    let mut pt = ProgramTest::default();
+    pt.add_account(fee_acc_addr, MockLegacyFeeAccount(fee_acc).into_account());
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) =
+        setup(pt, payer, Some(program_state), &[lst_mint], &[]).await;
+
+    let fee_acc_data = bc.get_account_data(fee_acc_addr).await;
+    assert_eq!(fee_acc_data.len(), LEGACY_FEE_ACCOUNT_SIZE);
+
+    cmd.with_flat_fee_program()
+        .cmd_migrate_lst_fee()
+        .arg(lst_mint.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let fee_acc_data = bc.get_account_data(fee_acc_addr).await;
+    assert_eq!(fee_acc_data.len(), FEE_ACCOUNT_SIZE);
+    assert_eq!(try_fee_account(&fee_acc_data).unwrap(), fee_acc);
+}
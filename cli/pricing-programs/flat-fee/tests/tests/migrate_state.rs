@@ -0,0 +1,44 @@
+use flat_fee_interface::ProgramState;
+use flat_fee_lib::{
+    program::{LEGACY_STATE_SIZE, STATE_ID, STATE_SIZE},
+    utils::try_program_state,
+};
+use flat_fee_test_utils::FlatFeeProgramTest;
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, ExtendedCommand},
+    ExtendedBanksClient,
+};
+use solana_program_test::ProgramTest;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn migrate_state_success() {
+    let payer = Keypair::new();
+
+    let program_state = ProgramState {
+        manager: payer.pubkey(),
+        lp_withdrawal_fee_bps: 10,
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    };
+    let mut pt = ProgramTest::default();
+    pt.add_mock_legacy_program_state(program_state);
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(pt, payer, None, &[], &[]).await;
+
+    let state_data = bc.get_account_data(STATE_ID).await;
+    assert_eq!(state_data.len(), LEGACY_STATE_SIZE);
+
+    cmd.with_flat_fee_program().cmd_migrate_state();
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let state_data = bc.get_account_data(STATE_ID).await;
+    assert_eq!(state_data.len(), STATE_SIZE);
+    assert_eq!(try_program_state(&state_data).unwrap(), program_state);
+}
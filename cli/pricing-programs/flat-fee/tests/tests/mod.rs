@@ -1,11 +1,21 @@
+mod accept_manager;
 mod add_lst;
+mod add_pair_fee;
 mod initialize;
+mod migrate_lst_fee;
+mod migrate_state;
 mod remove_lst;
+mod remove_pair_fee;
+mod set_epoch_surcharge;
+mod set_lp_deposit_fee;
 mod set_lp_withdrawal_fee;
 mod set_lst_fee;
+mod set_lst_fee_ramp;
 mod set_manager;
+mod set_pair_fee;
 mod view;
 mod view_lst;
+mod view_pair_fee;
 
 // TODO: tests for price-exact-in, price-exact-out, price-lp-tokens-to-mint, price-lp-tokens-to-redeem
 // if we can figure out how to get BanksClientRpcServer to simulate transactions with full data
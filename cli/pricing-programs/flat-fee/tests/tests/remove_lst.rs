@@ -16,6 +16,10 @@ async fn remove_lst_success() {
     let program_state = ProgramState {
         manager: payer.pubkey(),
         lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
     };
 
     let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(
@@ -0,0 +1,51 @@
+use flat_fee_interface::ProgramState;
+use flat_fee_lib::utils::try_program_state;
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, ExtendedCommand},
+    ExtendedBanksClient,
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, TestCmd};
+
+async fn assert_epoch_surcharge(
+    bc: &mut BanksClient,
+    expected_epoch_surcharge_bps: u16,
+    expected_epoch_surcharge_slots: u16,
+) {
+    let state_data = bc.get_account_data(flat_fee_lib::program::STATE_ID).await;
+    let state = try_program_state(&state_data).unwrap();
+    assert_eq!(state.epoch_surcharge_bps, expected_epoch_surcharge_bps);
+    assert_eq!(state.epoch_surcharge_slots, expected_epoch_surcharge_slots);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_epoch_surcharge_success() {
+    const NEW_EPOCH_SURCHARGE_BPS: u16 = 69;
+    const NEW_EPOCH_SURCHARGE_SLOTS: u16 = 420;
+
+    let payer = Keypair::new();
+
+    let program_state = ProgramState {
+        manager: payer.pubkey(),
+        lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    };
+    let pt = ProgramTest::default();
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) =
+        setup(pt, payer, Some(program_state), &[], &[]).await;
+
+    cmd.with_flat_fee_program()
+        .cmd_set_epoch_surcharge()
+        .arg(NEW_EPOCH_SURCHARGE_BPS.to_string())
+        .arg(NEW_EPOCH_SURCHARGE_SLOTS.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_epoch_surcharge(&mut bc, NEW_EPOCH_SURCHARGE_BPS, NEW_EPOCH_SURCHARGE_SLOTS).await;
+}
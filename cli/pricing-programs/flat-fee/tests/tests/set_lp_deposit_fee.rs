@@ -0,0 +1,133 @@
+use flat_fee_interface::ProgramState;
+use flat_fee_lib::{
+    pda::FeeAccountFindPdaArgs,
+    utils::{try_fee_account, try_program_state},
+};
+use flat_fee_test_utils::MockFeeAccountArgs;
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, ExtendedCommand},
+    ExtendedBanksClient,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, TestCmd};
+
+async fn assert_lp_deposit_fee_bps(bc: &mut BanksClient, expected_lp_deposit_fee_bps: u16) {
+    let state_data = bc.get_account_data(flat_fee_lib::program::STATE_ID).await;
+    let state = try_program_state(&state_data).unwrap();
+    assert_eq!(state.lp_deposit_fee_bps, expected_lp_deposit_fee_bps);
+}
+
+async fn assert_lp_deposit_fee_bps_override(
+    bc: &mut BanksClient,
+    lst_mint: Pubkey,
+    expected: Option<u16>,
+) {
+    let (fee_acc, _bump) = FeeAccountFindPdaArgs {
+        program_id: flat_fee_lib::program::ID,
+        lst_mint,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let fee_acc_data = bc.get_account_data(fee_acc).await;
+    let fee_acc = try_fee_account(&fee_acc_data).unwrap();
+    let actual = match fee_acc.has_lp_deposit_fee_bps_override {
+        0 => None,
+        _ => Some(fee_acc.lp_deposit_fee_bps_override),
+    };
+    assert_eq!(actual, expected);
+}
+
+fn program_state(manager: Pubkey) -> ProgramState {
+    ProgramState {
+        manager,
+        lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_lp_deposit_fee_success() {
+    const NEW_LP_DEPOSIT_FEE_BPS: u16 = 420;
+
+    let payer = Keypair::new();
+    let program_state = program_state(payer.pubkey());
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) =
+        setup(ProgramTest::default(), payer, Some(program_state), &[], &[]).await;
+
+    cmd.with_flat_fee_program()
+        .cmd_set_lp_deposit_fee()
+        .arg(NEW_LP_DEPOSIT_FEE_BPS.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_lp_deposit_fee_bps(&mut bc, NEW_LP_DEPOSIT_FEE_BPS).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_lst_lp_deposit_fee_success() {
+    const NEW_LP_DEPOSIT_FEE_BPS: u16 = 69;
+
+    let lst_mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+    let program_state = program_state(payer.pubkey());
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        payer,
+        Some(program_state),
+        &[],
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lst_mint,
+        }],
+    )
+    .await;
+
+    cmd.with_flat_fee_program()
+        .cmd_set_lp_deposit_fee()
+        .arg("--lst")
+        .arg(lst_mint.to_string())
+        .arg(NEW_LP_DEPOSIT_FEE_BPS.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_lp_deposit_fee_bps_override(&mut bc, lst_mint, Some(NEW_LP_DEPOSIT_FEE_BPS)).await;
+    assert_lp_deposit_fee_bps(&mut bc, 0).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn clear_lst_lp_deposit_fee_success() {
+    let lst_mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+    let program_state = program_state(payer.pubkey());
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        payer,
+        Some(program_state),
+        &[],
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lst_mint,
+        }],
+    )
+    .await;
+
+    cmd.with_flat_fee_program()
+        .cmd_set_lp_deposit_fee()
+        .arg("--lst")
+        .arg(lst_mint.to_string())
+        .arg("--clear");
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_lp_deposit_fee_bps_override(&mut bc, lst_mint, None).await;
+}
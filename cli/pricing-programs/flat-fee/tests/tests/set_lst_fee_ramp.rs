@@ -0,0 +1,54 @@
+use flat_fee_interface::ProgramState;
+use flat_fee_test_utils::MockFeeAccountArgs;
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_lst_fee_ramp_success() {
+    const TARGET_INPUT_FEE_BPS: i16 = 69;
+    const TARGET_OUTPUT_FEE_BPS: i16 = 420;
+    const START_SLOT: u64 = 1000;
+    const END_SLOT: u64 = 2000;
+
+    let lst_mint: Pubkey = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let program_state = ProgramState {
+        manager: payer.pubkey(),
+        lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    };
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        payer,
+        Some(program_state),
+        &[],
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lst_mint,
+        }],
+    )
+    .await;
+
+    cmd.with_flat_fee_program()
+        .cmd_set_lst_fee_ramp()
+        .arg("--start-slot")
+        .arg(START_SLOT.to_string())
+        .arg("--end-slot")
+        .arg(END_SLOT.to_string())
+        .arg(lst_mint.to_string())
+        .arg(TARGET_INPUT_FEE_BPS.to_string())
+        .arg(TARGET_OUTPUT_FEE_BPS.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+}
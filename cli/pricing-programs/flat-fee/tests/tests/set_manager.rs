@@ -10,10 +10,15 @@ use solana_sdk::{signature::Keypair, signer::Signer};
 
 use crate::common::{setup, TestCmd};
 
-async fn assert_new_manager(bc: &mut BanksClient, expected_new_manager: Pubkey) {
+async fn assert_pending_manager(
+    bc: &mut BanksClient,
+    expected_manager: Pubkey,
+    expected_pending_manager: Pubkey,
+) {
     let state_data = bc.get_account_data(flat_fee_lib::program::STATE_ID).await;
     let state = try_program_state(&state_data).unwrap();
-    assert_eq!(state.manager, expected_new_manager);
+    assert_eq!(state.manager, expected_manager);
+    assert_eq!(state.pending_manager, expected_pending_manager);
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -24,9 +29,13 @@ async fn set_manager_success() {
     let program_state = ProgramState {
         manager: payer.pubkey(),
         lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
     };
 
-    let (mut cmd, _cfg, mut bc, _payer, _rbh) =
+    let (mut cmd, _cfg, mut bc, payer, _rbh) =
         setup(ProgramTest::default(), payer, Some(program_state), &[], &[]).await;
 
     cmd.with_flat_fee_program()
@@ -35,5 +44,5 @@ async fn set_manager_success() {
 
     let exec_res = cmd.exec_b64_txs(&mut bc).await;
     assert_all_txs_success_nonempty(&exec_res);
-    assert_new_manager(&mut bc, new_manager).await;
+    assert_pending_manager(&mut bc, payer.pubkey(), new_manager).await;
 }
@@ -0,0 +1,51 @@
+use flat_fee_interface::ProgramState;
+use flat_fee_test_utils::MockPairFeeAccountArgs;
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{add_mock_pair_fee_accounts, setup, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_pair_fee_success() {
+    const FEE_BPS: i16 = -1;
+
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let program_state = ProgramState {
+        manager: payer.pubkey(),
+        lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    };
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(
+        add_mock_pair_fee_accounts(
+            ProgramTest::default(),
+            &[MockPairFeeAccountArgs {
+                fee_bps: Default::default(),
+                input_lst_mint,
+                output_lst_mint,
+            }],
+        ),
+        payer,
+        Some(program_state),
+        &[],
+        &[],
+    )
+    .await;
+
+    cmd.with_flat_fee_program()
+        .cmd_set_pair_fee()
+        .arg(input_lst_mint.to_string())
+        .arg(output_lst_mint.to_string())
+        .arg(FEE_BPS.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+}
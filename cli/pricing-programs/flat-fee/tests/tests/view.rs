@@ -15,6 +15,10 @@ async fn view_success() {
     let program_state = ProgramState {
         manager: Pubkey::default(),
         lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
     };
 
     let (mut cmd, _cfg, _bc, _payer, _rbh) = setup(
@@ -0,0 +1,49 @@
+use std::process::Output;
+
+use flat_fee_interface::ProgramState;
+use flat_fee_test_utils::MockPairFeeAccountArgs;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_sdk::signature::Keypair;
+
+use crate::common::{add_mock_pair_fee_accounts, setup, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn view_pair_fee_success() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_state = ProgramState {
+        manager: Pubkey::default(),
+        lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    };
+
+    let (mut cmd, _cfg, _bc, _payer, _rbh) = setup(
+        add_mock_pair_fee_accounts(
+            ProgramTest::default(),
+            &[MockPairFeeAccountArgs {
+                fee_bps: Default::default(),
+                input_lst_mint,
+                output_lst_mint,
+            }],
+        ),
+        Keypair::new(),
+        Some(program_state),
+        &[],
+        &[],
+    )
+    .await;
+
+    cmd.with_flat_fee_program()
+        .cmd_view_pair_fee()
+        .arg(input_lst_mint.to_string())
+        .arg(output_lst_mint.to_string());
+
+    let Output { status, stdout, .. } = cmd.output().unwrap();
+    assert!(status.success());
+    eprintln!("{}", std::str::from_utf8(&stdout).unwrap());
+}
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_lib::{
+    add_liquidity_multi_ix_by_mint_full_for_prog, find_lst_state_list_address,
+    find_pool_state_address, AddLiquidityMultiByMintFreeArgs, AddLiquidityMultiLstByMintArgs,
+    AddRemoveLiquidityAccountSuffixes,
+};
+use s_jup_interface::{LstData, SPool, SPoolInitAccounts};
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_lst_list::SanctumLst;
+use sanctum_solana_cli_utils::parse_signer;
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_sdk::{
+    account::Account,
+    native_token::{lamports_to_sol, sol_to_lamports},
+    pubkey::Pubkey,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+
+use crate::{
+    common::{fetch_srlut, SANCTUM_LST_LIST},
+    lst_amt_arg::LstAmtArg,
+    lst_arg::LstArg,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Add liquidity from multiple LSTs in a single instruction",
+    long_about = "Add liquidity from multiple LSTs in a single instruction, minting LP tokens once against their combined SOL value.
+LST tokens are transferred from the authority's ATAs and LP tokens are minted to the authority's LP token ATA, which is created if it does not exist."
+)]
+pub struct AddLiquidityMultiArgs {
+    #[arg(
+        long,
+        help = "Authority of the LST token accounts to add liquidity from. Defaults to config wallet if not set."
+    )]
+    pub authority: Option<String>,
+
+    #[arg(
+        long,
+        help = "Minimum amount of LP tokens to receive",
+        default_value_t = 0.0
+    )]
+    pub min_lp_out: f64,
+
+    #[arg(
+        long,
+        short,
+        help = "Amount of each LST to add liquidity with, in the same order as the LSTs. 'all' to add the authority's entire ATA balance. Ignore clap's help msg and put this after the LST args instead of before.",
+        value_delimiter = ' ',
+        num_args = 1..,
+        required = true,
+        value_parser = StringValueParser::new().try_map(|s| LstAmtArg::parse_arg(&s)),
+    )]
+    pub amts: Vec<LstAmtArg>,
+
+    #[arg(
+        help = "The LSTs to add liquidity from. Only LSTs on sanctum-lst-list are supported. Can either be a pubkey or case-insensitive symbol, e.g. 'bsol'",
+        num_args = 1..,
+        required = true,
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub lsts: Vec<LstArg>,
+}
+
+impl AddLiquidityMultiArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            authority,
+            min_lp_out,
+            amts,
+            lsts,
+        } = match args.subcmd {
+            Subcmd::AddLiquidityMulti(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        if lsts.len() != amts.len() {
+            panic!("Got {} LSTs but {} amounts", lsts.len(), amts.len());
+        }
+        let sanctum_lsts: Vec<&'static SanctumLst> = lsts
+            .iter()
+            .map(|lst| match lst {
+                LstArg::SanctumLst(s) => *s,
+                LstArg::Unknown(lst) => {
+                    panic!("Unknown LST {lst}. Only LSTs on sanctum-lst-list supported")
+                }
+            })
+            .collect();
+
+        let authority = authority.map(|s| parse_signer(&s).unwrap());
+        let authority = authority
+            .as_ref()
+            .map_or_else(|| payer.as_ref(), |s| s.as_ref());
+
+        let (pool_id, _) = find_pool_state_address(program_id);
+        let (lst_state_list_id, _) = find_lst_state_list_address(program_id);
+        let mut fetched = rpc
+            .get_multiple_accounts(&[pool_id, lst_state_list_id])
+            .await
+            .unwrap();
+        let lst_state_list_acc = fetched.pop().unwrap().unwrap();
+        let pool_acc = fetched.pop().unwrap().unwrap();
+
+        let mut spool = SPool::from_init_accounts(
+            program_id,
+            SPoolInitAccounts {
+                lst_state_list: lst_state_list_acc,
+                pool_state: pool_acc,
+            },
+            &SANCTUM_LST_LIST.sanctum_lst_list,
+        )
+        .unwrap();
+
+        let mut accounts_to_fetch = spool.get_accounts_to_update_lsts_filtered(|state, _data| {
+            sanctum_lsts.iter().any(|lst| lst.mint == state.mint)
+        });
+        accounts_to_fetch.extend(spool.get_accounts_to_update_pricing_prog_for_liquidity());
+        accounts_to_fetch.extend(
+            spool.get_accounts_to_update_pricing_prog_for_lsts(
+                sanctum_lsts.iter().map(|lst| lst.mint),
+            ),
+        );
+        accounts_to_fetch.sort();
+        accounts_to_fetch.dedup();
+
+        let account_map: HashMap<Pubkey, Account> = rpc
+            .get_multiple_accounts(&accounts_to_fetch)
+            .await
+            .unwrap()
+            .into_iter()
+            .zip(accounts_to_fetch)
+            .filter_map(|(acc, pk)| acc.map(|acc| (pk, acc)))
+            .collect();
+        spool.update_full(&account_map).unwrap();
+
+        let src_lst_accs: Vec<Pubkey> = sanctum_lsts
+            .iter()
+            .map(|lst| {
+                get_associated_token_address_with_program_id(
+                    &authority.pubkey(),
+                    &lst.mint,
+                    &lst.token_program,
+                )
+            })
+            .collect();
+        let fetched_src_lst_accs = rpc.get_multiple_accounts(&src_lst_accs).await.unwrap();
+
+        let lp_token_mint = spool.lp_token_mint().unwrap();
+        let dst_lp_acc = get_associated_token_address_with_program_id(
+            &authority.pubkey(),
+            &lp_token_mint,
+            &spl_token::ID,
+        );
+
+        let pricing_prog = spool.pricing_prog().unwrap();
+        let mut calc_and_pricing_accounts = Vec::with_capacity(sanctum_lsts.len());
+        let mut lst_amounts = Vec::with_capacity(sanctum_lsts.len());
+        for ((sanctum_lst, amt), fetched_src_lst_acc) in
+            sanctum_lsts.iter().zip(amts).zip(fetched_src_lst_accs)
+        {
+            let symbol = &sanctum_lst.symbol;
+            let balance = match fetched_src_lst_acc {
+                Some(a) => token_account_balance(a).unwrap(),
+                None => panic!("Expected authority to have {symbol} ATA"),
+            };
+            let lst_amount = match amt {
+                LstAmtArg::All => balance,
+                LstAmtArg::Amt(v) => v,
+            };
+            if lst_amount == 0 || lst_amount > balance {
+                panic!(
+                    "Cannot add {} {symbol}, authority ATA has {}",
+                    lamports_to_sol(lst_amount),
+                    lamports_to_sol(balance)
+                );
+            }
+            lst_amounts.push(lst_amount);
+
+            let (_state, LstData { sol_val_calc, .. }) =
+                spool.find_ready_lst(sanctum_lst.mint).unwrap();
+            calc_and_pricing_accounts.push((
+                sol_val_calc.ix_accounts(),
+                pricing_prog
+                    .price_lp_tokens_to_mint_accounts(sanctum_lst.mint)
+                    .unwrap(),
+            ));
+        }
+
+        let ix = add_liquidity_multi_ix_by_mint_full_for_prog(
+            program_id,
+            AddLiquidityMultiByMintFreeArgs {
+                signer: authority.pubkey(),
+                dst_lp_acc,
+                pool_state: spool.pool_state_account.as_ref().unwrap(),
+                lst_state_list: &spool.lst_state_list_account,
+            },
+            sanctum_lsts
+                .iter()
+                .zip(src_lst_accs)
+                .zip(lst_amounts)
+                .zip(calc_and_pricing_accounts.iter())
+                .map(
+                    |(
+                        ((sanctum_lst, src_lst_acc), lst_amount),
+                        (lst_calculator_accounts, pricing_program_price_lp_accounts),
+                    )| AddLiquidityMultiLstByMintArgs {
+                        src_lst_acc,
+                        lst_mint: MintWithTokenProgram {
+                            pubkey: sanctum_lst.mint,
+                            token_program: sanctum_lst.token_program,
+                        },
+                        lst_amount,
+                        account_suffixes: AddRemoveLiquidityAccountSuffixes {
+                            lst_calculator_accounts,
+                            pricing_program_price_lp_accounts,
+                            transfer_hook: None,
+                        },
+                    },
+                )
+                .collect(),
+            sol_to_lamports(min_lp_out),
+        )
+        .unwrap();
+
+        let ixs = vec![
+            create_associated_token_account_idempotent(
+                &payer.pubkey(),
+                &authority.pubkey(),
+                &lp_token_mint,
+                &spl_token::ID,
+            ),
+            ix,
+        ];
+
+        let srlut = fetch_srlut(&rpc).await;
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            ixs,
+            &[srlut],
+            &mut [payer.as_ref(), authority],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,90 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    init_lp_token_metadata_ix_with_program_id, InitLpTokenMetadataIxArgs,
+};
+use s_controller_lib::{try_pool_state, LpTokenMetadataFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::keyed::Keyed;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Initializes the LP token's metadata with the pool state PDA as update authority.
+
+Metadata is stored in the LP token mint itself for Token-2022 LP token mints, whose MetadataPointer extension must point to the mint, and in a metaplex metadata account for Tokenkeg LP token mints."
+)]
+pub struct InitLpTokenMetadataArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's admin authority signer. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(long, help = "The LP token's name")]
+    pub name: String,
+
+    #[arg(long, help = "The LP token's symbol")]
+    pub symbol: String,
+
+    #[arg(long, help = "URI of the LP token's off-chain JSON metadata")]
+    pub uri: String,
+}
+
+impl InitLpTokenMetadataArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            name,
+            symbol,
+            uri,
+        } = match args.subcmd {
+            Subcmd::InitLpTokenMetadata(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let lp_token_mint = pool_state.lp_token_mint;
+        let lp_token_mint_acc = rpc.get_account(&lp_token_mint).await.unwrap();
+
+        let ix = init_lp_token_metadata_ix_with_program_id(
+            program_id,
+            LpTokenMetadataFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: pool_state_acc,
+                lp_token_mint: Keyed {
+                    pubkey: lp_token_mint,
+                    account: lp_token_mint_acc,
+                },
+            }
+            .resolve_init_for_prog(program_id)
+            .unwrap(),
+            InitLpTokenMetadataIxArgs { name, symbol, uri },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
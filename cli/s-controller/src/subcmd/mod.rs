@@ -1,11 +1,13 @@
 use clap::Subcommand;
 
 use self::{
-    add_disable_auth::AddDisableAuthArgs, add_lst::AddLstArgs,
-    disable_lst_input::DisableLstInputArgs, disable_pool::DisablePoolArgs,
+    add_disable_auth::AddDisableAuthArgs, add_liquidity_multi::AddLiquidityMultiArgs,
+    add_lst::AddLstArgs, disable_lst_input::DisableLstInputArgs, disable_pool::DisablePoolArgs,
     enable_lst_input::EnableLstInputArgs, enable_pool::EnablePoolArgs, init::InitArgs,
-    rebal_sol::RebalSolArgs, remove_disable_auth::RemoveDisableAuthArgs, remove_lst::RemoveLstArgs,
-    set_admin::SetAdminArgs, set_pricing_prog::SetPricingProgArgs,
+    init_lp_token_metadata::InitLpTokenMetadataArgs, rebal_sol::RebalSolArgs,
+    remove_disable_auth::RemoveDisableAuthArgs, remove_liquidity_basket::RemoveLiquidityBasketArgs,
+    remove_lst::RemoveLstArgs, set_admin::SetAdminArgs,
+    set_lp_token_metadata::SetLpTokenMetadataArgs, set_pricing_prog::SetPricingProgArgs,
     set_protocol_fee::SetProtocolFeeArgs,
     set_protocol_fee_beneficiary::SetProtocolFeeBeneficiaryArgs,
     set_rebalance_auth::SetRebalanceAuthArgs, set_sol_value_calculator::SetSolValueCalculatorArgs,
@@ -14,16 +16,20 @@ use self::{
 };
 
 mod add_disable_auth;
+mod add_liquidity_multi;
 mod add_lst;
 mod disable_lst_input;
 mod disable_pool;
 mod enable_lst_input;
 mod enable_pool;
 mod init;
+mod init_lp_token_metadata;
 mod rebal_sol;
 mod remove_disable_auth;
+mod remove_liquidity_basket;
 mod remove_lst;
 mod set_admin;
+mod set_lp_token_metadata;
 mod set_pricing_prog;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
@@ -56,6 +62,10 @@ pub enum Subcmd {
     WithdrawProtocolFees(WithdrawProtocolFeesArgs),
     View(ViewArgs),
     RebalSol(RebalSolArgs),
+    InitLpTokenMetadata(InitLpTokenMetadataArgs),
+    SetLpTokenMetadata(SetLpTokenMetadataArgs),
+    AddLiquidityMulti(AddLiquidityMultiArgs),
+    RemoveLiquidityBasket(RemoveLiquidityBasketArgs),
 }
 
 impl Subcmd {
@@ -81,6 +91,10 @@ impl Subcmd {
             Self::WithdrawProtocolFees(_) => WithdrawProtocolFeesArgs::run(args).await,
             Self::View(_) => ViewArgs::run(args).await,
             Self::RebalSol(_) => RebalSolArgs::run(args).await,
+            Self::InitLpTokenMetadata(_) => InitLpTokenMetadataArgs::run(args).await,
+            Self::SetLpTokenMetadata(_) => SetLpTokenMetadataArgs::run(args).await,
+            Self::AddLiquidityMulti(_) => AddLiquidityMultiArgs::run(args).await,
+            Self::RemoveLiquidityBasket(_) => RemoveLiquidityBasketArgs::run(args).await,
         }
     }
 }
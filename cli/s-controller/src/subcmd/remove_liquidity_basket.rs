@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_lib::{
+    find_lst_state_list_address, find_pool_state_address,
+    remove_liquidity_basket_ix_by_mint_full_for_prog, try_lst_state_list,
+    RemoveLiquidityBasketByMintFreeArgs, RemoveLiquidityBasketLstByMintArgs,
+};
+use s_jup_interface::{SPool, SPoolInitAccounts};
+use s_pricing_prog_aggregate::PricingProg;
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_solana_cli_utils::parse_signer;
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_sdk::{account::Account, native_token::lamports_to_sol, pubkey::Pubkey};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+
+use crate::{
+    common::{fetch_srlut, SANCTUM_LST_LIST},
+    lst_amt_arg::LstAmtArg,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Redeem LP tokens for a proportional basket of every LST in the pool",
+    long_about = "Redeem LP tokens for a proportional basket of every LST in the pool, minus the pricing program's LP withdrawal fee.
+LP tokens are burnt from the authority's LP token ATA and each LST is transferred to the authority's ATA for it, which is created if it does not exist.
+Every LST in the pool must be on sanctum-lst-list."
+)]
+pub struct RemoveLiquidityBasketArgs {
+    #[arg(
+        long,
+        help = "Authority of the LP token account to redeem from. Defaults to config wallet if not set."
+    )]
+    pub authority: Option<String>,
+
+    #[arg(
+        help = "Amount of LP tokens to redeem. 'all' to redeem the authority's entire LP token ATA balance.",
+        value_parser = StringValueParser::new().try_map(|s| LstAmtArg::parse_arg(&s)),
+    )]
+    pub amt: LstAmtArg,
+}
+
+impl RemoveLiquidityBasketArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { authority, amt } = match args.subcmd {
+            Subcmd::RemoveLiquidityBasket(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let authority = authority.map(|s| parse_signer(&s).unwrap());
+        let authority = authority
+            .as_ref()
+            .map_or_else(|| payer.as_ref(), |s| s.as_ref());
+
+        let (pool_id, _) = find_pool_state_address(program_id);
+        let (lst_state_list_id, _) = find_lst_state_list_address(program_id);
+        let mut fetched = rpc
+            .get_multiple_accounts(&[pool_id, lst_state_list_id])
+            .await
+            .unwrap();
+        let lst_state_list_acc = fetched.pop().unwrap().unwrap();
+        let pool_acc = fetched.pop().unwrap().unwrap();
+
+        let mut spool = SPool::from_init_accounts(
+            program_id,
+            SPoolInitAccounts {
+                lst_state_list: lst_state_list_acc,
+                pool_state: pool_acc,
+            },
+            &SANCTUM_LST_LIST.sanctum_lst_list,
+        )
+        .unwrap();
+
+        let mut accounts_to_fetch = spool.get_accounts_to_update_lsts_filtered(|_, _| true);
+        accounts_to_fetch.extend(spool.get_accounts_to_update_pricing_prog_for_liquidity());
+        accounts_to_fetch.sort();
+        accounts_to_fetch.dedup();
+
+        let account_map: HashMap<Pubkey, Account> = rpc
+            .get_multiple_accounts(&accounts_to_fetch)
+            .await
+            .unwrap()
+            .into_iter()
+            .zip(accounts_to_fetch)
+            .filter_map(|(acc, pk)| acc.map(|acc| (pk, acc)))
+            .collect();
+        spool.update_full(&account_map).unwrap();
+
+        let lp_token_mint = spool.lp_token_mint().unwrap();
+        let src_lp_acc = get_associated_token_address_with_program_id(
+            &authority.pubkey(),
+            &lp_token_mint,
+            &spl_token::ID,
+        );
+        let balance = match rpc.get_account(&src_lp_acc).await {
+            Ok(a) => token_account_balance(a).unwrap(),
+            Err(_) => panic!("Expected authority to have LP token ATA"),
+        };
+        let lp_token_amount = match amt {
+            LstAmtArg::All => balance,
+            LstAmtArg::Amt(v) => v,
+        };
+        if lp_token_amount == 0 || lp_token_amount > balance {
+            panic!(
+                "Cannot redeem {} LP tokens, authority ATA has {}",
+                lamports_to_sol(lp_token_amount),
+                lamports_to_sol(balance)
+            );
+        }
+
+        let lst_state_list = try_lst_state_list(&spool.lst_state_list_account.data).unwrap();
+        let mut ixs = Vec::with_capacity(lst_state_list.len() + 1);
+        let mut lst_args = Vec::with_capacity(lst_state_list.len());
+        for (lst_state, lst_data) in lst_state_list.iter().zip(spool.lst_data_list.iter()) {
+            let lst_data = lst_data.as_ref().unwrap_or_else(|| {
+                panic!(
+                    "LST {} not supported. Only LSTs on sanctum-lst-list supported",
+                    lst_state.mint
+                )
+            });
+            ixs.push(create_associated_token_account_idempotent(
+                &payer.pubkey(),
+                &authority.pubkey(),
+                &lst_state.mint,
+                &lst_data.token_program,
+            ));
+            lst_args.push((
+                get_associated_token_address_with_program_id(
+                    &authority.pubkey(),
+                    &lst_state.mint,
+                    &lst_data.token_program,
+                ),
+                lst_state.mint,
+                lst_data.token_program,
+                lst_data.sol_val_calc.ix_accounts(),
+            ));
+        }
+
+        // the pricing program is invoked for the first LST on the list
+        let pricing_program_price_lp_accounts = spool
+            .pricing_prog()
+            .unwrap()
+            .price_lp_tokens_to_redeem_accounts(lst_state_list[0].mint)
+            .unwrap();
+
+        let ix = remove_liquidity_basket_ix_by_mint_full_for_prog(
+            program_id,
+            RemoveLiquidityBasketByMintFreeArgs {
+                signer: authority.pubkey(),
+                src_lp_acc,
+                pool_state: spool.pool_state_account.as_ref().unwrap(),
+                lst_state_list: &spool.lst_state_list_account,
+            },
+            lp_token_amount,
+            lst_args
+                .iter()
+                .map(
+                    |(dst_lst_acc, lst_mint, lst_token_program, lst_calculator_accounts)| {
+                        RemoveLiquidityBasketLstByMintArgs {
+                            dst_lst_acc: *dst_lst_acc,
+                            lst_mint: MintWithTokenProgram {
+                                pubkey: *lst_mint,
+                                token_program: *lst_token_program,
+                            },
+                            // payouts are pro-rata shares of each reserves at execution
+                            min_lst_out: 0,
+                            lst_calculator_accounts,
+                        }
+                    },
+                )
+                .collect(),
+            &pricing_program_price_lp_accounts,
+        )
+        .unwrap();
+        ixs.push(ix);
+
+        let srlut = fetch_srlut(&rpc).await;
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            ixs,
+            &[srlut],
+            &mut [payer.as_ref(), authority],
+        )
+        .await;
+    }
+}
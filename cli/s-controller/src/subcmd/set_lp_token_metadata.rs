@@ -0,0 +1,86 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{set_lp_token_metadata_ix_with_program_id, SetLpTokenMetadataIxArgs};
+use s_controller_lib::{try_pool_state, LpTokenMetadataFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::keyed::Keyed;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Updates the name, symbol and URI of the LP token's metadata. The metadata must have been initialized with init-lp-token-metadata."
+)]
+pub struct SetLpTokenMetadataArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's admin authority signer. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(long, help = "The LP token's new name")]
+    pub name: String,
+
+    #[arg(long, help = "The LP token's new symbol")]
+    pub symbol: String,
+
+    #[arg(long, help = "New URI of the LP token's off-chain JSON metadata")]
+    pub uri: String,
+}
+
+impl SetLpTokenMetadataArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            name,
+            symbol,
+            uri,
+        } = match args.subcmd {
+            Subcmd::SetLpTokenMetadata(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let lp_token_mint = pool_state.lp_token_mint;
+        let lp_token_mint_acc = rpc.get_account(&lp_token_mint).await.unwrap();
+
+        let ix = set_lp_token_metadata_ix_with_program_id(
+            program_id,
+            LpTokenMetadataFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: pool_state_acc,
+                lp_token_mint: Keyed {
+                    pubkey: lp_token_mint,
+                    account: lp_token_mint_acc,
+                },
+            }
+            .resolve_set_for_prog(program_id)
+            .unwrap(),
+            SetLpTokenMetadataIxArgs { name, symbol, uri },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
@@ -38,6 +38,10 @@ async fn rebal_all_sol_to_jitosol_rebal_auth_payer() {
         flat_fee_interface::ProgramState {
             manager: Pubkey::default(),
             lp_withdrawal_fee_bps: 0,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         [
             MockFeeAccountArgs {
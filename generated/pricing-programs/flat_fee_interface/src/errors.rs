@@ -18,6 +18,12 @@ pub enum FlatFeeError {
     UnsignedFeeOutOfBound = 4,
     #[error("Math error")]
     MathError = 5,
+    #[error("PairFeeAccount is not initialized for the given LST pair")]
+    UnsupportedLstPair = 6,
+    #[error("Fee ramp must not start in the past and must end after it starts")]
+    InvalidFeeRamp = 7,
+    #[error("Account has the legacy layout, migrate it first")]
+    StateNotMigrated = 8,
 }
 impl From<FlatFeeError> for ProgramError {
     fn from(e: FlatFeeError) -> Self {
@@ -14,6 +14,16 @@ pub enum FlatFeeProgramIx {
     PriceExactOut(PriceExactOutIxArgs),
     PriceLpTokensToMint(PriceLpTokensToMintIxArgs),
     PriceLpTokensToRedeem(PriceLpTokensToRedeemIxArgs),
+    MigrateLstFee,
+    MigrateState,
+    SetEpochSurcharge(SetEpochSurchargeIxArgs),
+    SetLstFeeRamp(SetLstFeeRampIxArgs),
+    SetLstLpDepositFee(SetLstLpDepositFeeIxArgs),
+    SetLpDepositFee(SetLpDepositFeeIxArgs),
+    RemovePairFee,
+    SetPairFee(SetPairFeeIxArgs),
+    AddPairFee(AddPairFeeIxArgs),
+    AcceptManager,
     SetLpWithdrawalFee(SetLpWithdrawalFeeIxArgs),
     SetLstFee(SetLstFeeIxArgs),
     RemoveLst,
@@ -40,6 +50,28 @@ impl FlatFeeProgramIx {
             PRICE_LP_TOKENS_TO_REDEEM_IX_DISCM => Ok(Self::PriceLpTokensToRedeem(
                 PriceLpTokensToRedeemIxArgs::deserialize(&mut reader)?,
             )),
+            MIGRATE_LST_FEE_IX_DISCM => Ok(Self::MigrateLstFee),
+            MIGRATE_STATE_IX_DISCM => Ok(Self::MigrateState),
+            SET_EPOCH_SURCHARGE_IX_DISCM => Ok(Self::SetEpochSurcharge(
+                SetEpochSurchargeIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_LST_FEE_RAMP_IX_DISCM => Ok(Self::SetLstFeeRamp(SetLstFeeRampIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            SET_LST_LP_DEPOSIT_FEE_IX_DISCM => Ok(Self::SetLstLpDepositFee(
+                SetLstLpDepositFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_LP_DEPOSIT_FEE_IX_DISCM => Ok(Self::SetLpDepositFee(
+                SetLpDepositFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            REMOVE_PAIR_FEE_IX_DISCM => Ok(Self::RemovePairFee),
+            SET_PAIR_FEE_IX_DISCM => Ok(Self::SetPairFee(SetPairFeeIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            ADD_PAIR_FEE_IX_DISCM => Ok(Self::AddPairFee(AddPairFeeIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            ACCEPT_MANAGER_IX_DISCM => Ok(Self::AcceptManager),
             SET_LP_WITHDRAWAL_FEE_IX_DISCM => Ok(Self::SetLpWithdrawalFee(
                 SetLpWithdrawalFeeIxArgs::deserialize(&mut reader)?,
             )),
@@ -72,6 +104,34 @@ impl FlatFeeProgramIx {
                 writer.write_all(&[PRICE_LP_TOKENS_TO_REDEEM_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
+            Self::MigrateLstFee => writer.write_all(&[MIGRATE_LST_FEE_IX_DISCM]),
+            Self::MigrateState => writer.write_all(&[MIGRATE_STATE_IX_DISCM]),
+            Self::SetEpochSurcharge(args) => {
+                writer.write_all(&[SET_EPOCH_SURCHARGE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLstFeeRamp(args) => {
+                writer.write_all(&[SET_LST_FEE_RAMP_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLstLpDepositFee(args) => {
+                writer.write_all(&[SET_LST_LP_DEPOSIT_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLpDepositFee(args) => {
+                writer.write_all(&[SET_LP_DEPOSIT_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RemovePairFee => writer.write_all(&[REMOVE_PAIR_FEE_IX_DISCM]),
+            Self::SetPairFee(args) => {
+                writer.write_all(&[SET_PAIR_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddPairFee(args) => {
+                writer.write_all(&[ADD_PAIR_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AcceptManager => writer.write_all(&[ACCEPT_MANAGER_IX_DISCM]),
             Self::SetLpWithdrawalFee(args) => {
                 writer.write_all(&[SET_LP_WITHDRAWAL_FEE_IX_DISCM])?;
                 args.serialize(&mut writer)
@@ -110,7 +170,7 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke_signed(ix, &account_info, seeds)
 }
-pub const PRICE_EXACT_IN_IX_ACCOUNTS_LEN: usize = 4;
+pub const PRICE_EXACT_IN_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactInAccounts<'me, 'info> {
     ///Mint of the input LST
@@ -121,6 +181,8 @@ pub struct PriceExactInAccounts<'me, 'info> {
     pub input_fee_acc: &'me AccountInfo<'info>,
     ///FeeAccount PDA for the output LST
     pub output_fee_acc: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactInKeys {
@@ -132,6 +194,8 @@ pub struct PriceExactInKeys {
     pub input_fee_acc: Pubkey,
     ///FeeAccount PDA for the output LST
     pub output_fee_acc: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
 }
 impl From<PriceExactInAccounts<'_, '_>> for PriceExactInKeys {
     fn from(accounts: PriceExactInAccounts) -> Self {
@@ -140,6 +204,7 @@ impl From<PriceExactInAccounts<'_, '_>> for PriceExactInKeys {
             output_lst_mint: *accounts.output_lst_mint.key,
             input_fee_acc: *accounts.input_fee_acc.key,
             output_fee_acc: *accounts.output_fee_acc.key,
+            state: *accounts.state.key,
         }
     }
 }
@@ -166,6 +231,11 @@ impl From<PriceExactInKeys> for [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -176,6 +246,7 @@ impl From<[Pubkey; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]> for PriceExactInKeys {
             output_lst_mint: pubkeys[1],
             input_fee_acc: pubkeys[2],
             output_fee_acc: pubkeys[3],
+            state: pubkeys[4],
         }
     }
 }
@@ -188,6 +259,7 @@ impl<'info> From<PriceExactInAccounts<'_, 'info>>
             accounts.output_lst_mint.clone(),
             accounts.input_fee_acc.clone(),
             accounts.output_fee_acc.clone(),
+            accounts.state.clone(),
         ]
     }
 }
@@ -200,6 +272,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]>
             output_lst_mint: &arr[1],
             input_fee_acc: &arr[2],
             output_fee_acc: &arr[3],
+            state: &arr[4],
         }
     }
 }
@@ -304,6 +377,7 @@ pub fn price_exact_in_verify_account_keys(
         (accounts.output_lst_mint.key, &keys.output_lst_mint),
         (accounts.input_fee_acc.key, &keys.input_fee_acc),
         (accounts.output_fee_acc.key, &keys.output_fee_acc),
+        (accounts.state.key, &keys.state),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -311,7 +385,7 @@ pub fn price_exact_in_verify_account_keys(
     }
     Ok(())
 }
-pub const PRICE_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 4;
+pub const PRICE_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactOutAccounts<'me, 'info> {
     ///Mint of the input LST
@@ -322,6 +396,8 @@ pub struct PriceExactOutAccounts<'me, 'info> {
     pub input_fee_acc: &'me AccountInfo<'info>,
     ///FeeAccount PDA for the output LST
     pub output_fee_acc: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactOutKeys {
@@ -333,6 +409,8 @@ pub struct PriceExactOutKeys {
     pub input_fee_acc: Pubkey,
     ///FeeAccount PDA for the output LST
     pub output_fee_acc: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
 }
 impl From<PriceExactOutAccounts<'_, '_>> for PriceExactOutKeys {
     fn from(accounts: PriceExactOutAccounts) -> Self {
@@ -341,6 +419,7 @@ impl From<PriceExactOutAccounts<'_, '_>> for PriceExactOutKeys {
             output_lst_mint: *accounts.output_lst_mint.key,
             input_fee_acc: *accounts.input_fee_acc.key,
             output_fee_acc: *accounts.output_fee_acc.key,
+            state: *accounts.state.key,
         }
     }
 }
@@ -367,6 +446,11 @@ impl From<PriceExactOutKeys> for [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -377,6 +461,7 @@ impl From<[Pubkey; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]> for PriceExactOutKeys {
             output_lst_mint: pubkeys[1],
             input_fee_acc: pubkeys[2],
             output_fee_acc: pubkeys[3],
+            state: pubkeys[4],
         }
     }
 }
@@ -389,6 +474,7 @@ impl<'info> From<PriceExactOutAccounts<'_, 'info>>
             accounts.output_lst_mint.clone(),
             accounts.input_fee_acc.clone(),
             accounts.output_fee_acc.clone(),
+            accounts.state.clone(),
         ]
     }
 }
@@ -401,6 +487,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]
             output_lst_mint: &arr[1],
             input_fee_acc: &arr[2],
             output_fee_acc: &arr[3],
+            state: &arr[4],
         }
     }
 }
@@ -505,6 +592,7 @@ pub fn price_exact_out_verify_account_keys(
         (accounts.output_lst_mint.key, &keys.output_lst_mint),
         (accounts.input_fee_acc.key, &keys.input_fee_acc),
         (accounts.output_fee_acc.key, &keys.output_fee_acc),
+        (accounts.state.key, &keys.state),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -512,37 +600,61 @@ pub fn price_exact_out_verify_account_keys(
     }
     Ok(())
 }
-pub const PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN: usize = 1;
+pub const PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceLpTokensToMintAccounts<'me, 'info> {
     ///Mint of the input LST
     pub input_lst_mint: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
+    ///FeeAccount PDA for the input LST
+    pub input_fee_acc: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceLpTokensToMintKeys {
     ///Mint of the input LST
     pub input_lst_mint: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
+    ///FeeAccount PDA for the input LST
+    pub input_fee_acc: Pubkey,
 }
 impl From<PriceLpTokensToMintAccounts<'_, '_>> for PriceLpTokensToMintKeys {
     fn from(accounts: PriceLpTokensToMintAccounts) -> Self {
         Self {
             input_lst_mint: *accounts.input_lst_mint.key,
+            state: *accounts.state.key,
+            input_fee_acc: *accounts.input_fee_acc.key,
         }
     }
 }
 impl From<PriceLpTokensToMintKeys> for [AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN] {
     fn from(keys: PriceLpTokensToMintKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.input_lst_mint,
-            is_signer: false,
-            is_writable: false,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.input_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.input_fee_acc,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
     }
 }
 impl From<[Pubkey; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]> for PriceLpTokensToMintKeys {
     fn from(pubkeys: [Pubkey; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             input_lst_mint: pubkeys[0],
+            state: pubkeys[1],
+            input_fee_acc: pubkeys[2],
         }
     }
 }
@@ -550,7 +662,11 @@ impl<'info> From<PriceLpTokensToMintAccounts<'_, 'info>>
     for [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]
 {
     fn from(accounts: PriceLpTokensToMintAccounts<'_, 'info>) -> Self {
-        [accounts.input_lst_mint.clone()]
+        [
+            accounts.input_lst_mint.clone(),
+            accounts.state.clone(),
+            accounts.input_fee_acc.clone(),
+        ]
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>
@@ -559,6 +675,8 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOU
     fn from(arr: &'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             input_lst_mint: &arr[0],
+            state: &arr[1],
+            input_fee_acc: &arr[2],
         }
     }
 }
@@ -658,7 +776,11 @@ pub fn price_lp_tokens_to_mint_verify_account_keys(
     accounts: PriceLpTokensToMintAccounts<'_, '_>,
     keys: PriceLpTokensToMintKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
-    for (actual, expected) in [(accounts.input_lst_mint.key, &keys.input_lst_mint)] {
+    for (actual, expected) in [
+        (accounts.input_lst_mint.key, &keys.input_lst_mint),
+        (accounts.state.key, &keys.state),
+        (accounts.input_fee_acc.key, &keys.input_fee_acc),
+    ] {
         if actual != expected {
             return Err((*actual, *expected));
         }
@@ -835,6 +957,2141 @@ pub fn price_lp_tokens_to_redeem_verify_account_keys(
     }
     Ok(())
 }
+pub const MIGRATE_LST_FEE_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateLstFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Account paying for the additional rent of the migrated FeeAccount
+    pub payer: &'me AccountInfo<'info>,
+    ///FeeAccount PDA to migrate
+    pub fee_acc: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateLstFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Account paying for the additional rent of the migrated FeeAccount
+    pub payer: Pubkey,
+    ///FeeAccount PDA to migrate
+    pub fee_acc: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<MigrateLstFeeAccounts<'_, '_>> for MigrateLstFeeKeys {
+    fn from(accounts: MigrateLstFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            payer: *accounts.payer.key,
+            fee_acc: *accounts.fee_acc.key,
+            state: *accounts.state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<MigrateLstFeeKeys> for [AccountMeta; MIGRATE_LST_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: MigrateLstFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MIGRATE_LST_FEE_IX_ACCOUNTS_LEN]> for MigrateLstFeeKeys {
+    fn from(pubkeys: [Pubkey; MIGRATE_LST_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            payer: pubkeys[1],
+            fee_acc: pubkeys[2],
+            state: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<MigrateLstFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; MIGRATE_LST_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: MigrateLstFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.payer.clone(),
+            accounts.fee_acc.clone(),
+            accounts.state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; MIGRATE_LST_FEE_IX_ACCOUNTS_LEN]>
+    for MigrateLstFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; MIGRATE_LST_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            payer: &arr[1],
+            fee_acc: &arr[2],
+            state: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const MIGRATE_LST_FEE_IX_DISCM: u8 = 240u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrateLstFeeIxData;
+impl MigrateLstFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != MIGRATE_LST_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    MIGRATE_LST_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[MIGRATE_LST_FEE_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn migrate_lst_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: MigrateLstFeeKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; MIGRATE_LST_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: MigrateLstFeeIxData.try_to_vec()?,
+    })
+}
+pub fn migrate_lst_fee_ix(keys: MigrateLstFeeKeys) -> std::io::Result<Instruction> {
+    migrate_lst_fee_ix_with_program_id(crate::ID, keys)
+}
+pub fn migrate_lst_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateLstFeeAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: MigrateLstFeeKeys = accounts.into();
+    let ix = migrate_lst_fee_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn migrate_lst_fee_invoke(accounts: MigrateLstFeeAccounts<'_, '_>) -> ProgramResult {
+    migrate_lst_fee_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn migrate_lst_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateLstFeeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: MigrateLstFeeKeys = accounts.into();
+    let ix = migrate_lst_fee_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn migrate_lst_fee_invoke_signed(
+    accounts: MigrateLstFeeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    migrate_lst_fee_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn migrate_lst_fee_verify_account_keys(
+    accounts: MigrateLstFeeAccounts<'_, '_>,
+    keys: MigrateLstFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.payer.key, &keys.payer),
+        (accounts.fee_acc.key, &keys.fee_acc),
+        (accounts.state.key, &keys.state),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_lst_fee_verify_writable_privileges<'me, 'info>(
+    accounts: MigrateLstFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.fee_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_lst_fee_verify_signer_privileges<'me, 'info>(
+    accounts: MigrateLstFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_lst_fee_verify_account_privileges<'me, 'info>(
+    accounts: MigrateLstFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    migrate_lst_fee_verify_writable_privileges(accounts)?;
+    migrate_lst_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const MIGRATE_STATE_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateStateAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Account paying for the additional rent of the migrated ProgramState
+    pub payer: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateStateKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Account paying for the additional rent of the migrated ProgramState
+    pub payer: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<MigrateStateAccounts<'_, '_>> for MigrateStateKeys {
+    fn from(accounts: MigrateStateAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            payer: *accounts.payer.key,
+            state: *accounts.state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<MigrateStateKeys> for [AccountMeta; MIGRATE_STATE_IX_ACCOUNTS_LEN] {
+    fn from(keys: MigrateStateKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MIGRATE_STATE_IX_ACCOUNTS_LEN]> for MigrateStateKeys {
+    fn from(pubkeys: [Pubkey; MIGRATE_STATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            payer: pubkeys[1],
+            state: pubkeys[2],
+            system_program: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<MigrateStateAccounts<'_, 'info>>
+    for [AccountInfo<'info>; MIGRATE_STATE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: MigrateStateAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.payer.clone(),
+            accounts.state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; MIGRATE_STATE_IX_ACCOUNTS_LEN]>
+    for MigrateStateAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; MIGRATE_STATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            payer: &arr[1],
+            state: &arr[2],
+            system_program: &arr[3],
+        }
+    }
+}
+pub const MIGRATE_STATE_IX_DISCM: u8 = 241u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrateStateIxData;
+impl MigrateStateIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != MIGRATE_STATE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    MIGRATE_STATE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[MIGRATE_STATE_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn migrate_state_ix_with_program_id(
+    program_id: Pubkey,
+    keys: MigrateStateKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; MIGRATE_STATE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: MigrateStateIxData.try_to_vec()?,
+    })
+}
+pub fn migrate_state_ix(keys: MigrateStateKeys) -> std::io::Result<Instruction> {
+    migrate_state_ix_with_program_id(crate::ID, keys)
+}
+pub fn migrate_state_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateStateAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: MigrateStateKeys = accounts.into();
+    let ix = migrate_state_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn migrate_state_invoke(accounts: MigrateStateAccounts<'_, '_>) -> ProgramResult {
+    migrate_state_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn migrate_state_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateStateAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: MigrateStateKeys = accounts.into();
+    let ix = migrate_state_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn migrate_state_invoke_signed(
+    accounts: MigrateStateAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    migrate_state_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn migrate_state_verify_account_keys(
+    accounts: MigrateStateAccounts<'_, '_>,
+    keys: MigrateStateKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.payer.key, &keys.payer),
+        (accounts.state.key, &keys.state),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_state_verify_writable_privileges<'me, 'info>(
+    accounts: MigrateStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_state_verify_signer_privileges<'me, 'info>(
+    accounts: MigrateStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_state_verify_account_privileges<'me, 'info>(
+    accounts: MigrateStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    migrate_state_verify_writable_privileges(accounts)?;
+    migrate_state_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_EPOCH_SURCHARGE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetEpochSurchargeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetEpochSurchargeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
+}
+impl From<SetEpochSurchargeAccounts<'_, '_>> for SetEpochSurchargeKeys {
+    fn from(accounts: SetEpochSurchargeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetEpochSurchargeKeys> for [AccountMeta; SET_EPOCH_SURCHARGE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetEpochSurchargeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_EPOCH_SURCHARGE_IX_ACCOUNTS_LEN]> for SetEpochSurchargeKeys {
+    fn from(pubkeys: [Pubkey; SET_EPOCH_SURCHARGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            state: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetEpochSurchargeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_EPOCH_SURCHARGE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetEpochSurchargeAccounts<'_, 'info>) -> Self {
+        [accounts.manager.clone(), accounts.state.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_EPOCH_SURCHARGE_IX_ACCOUNTS_LEN]>
+    for SetEpochSurchargeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_EPOCH_SURCHARGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            state: &arr[1],
+        }
+    }
+}
+pub const SET_EPOCH_SURCHARGE_IX_DISCM: u8 = 242u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetEpochSurchargeIxArgs {
+    pub epoch_surcharge_bps: u16,
+    pub epoch_surcharge_slots: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetEpochSurchargeIxData(pub SetEpochSurchargeIxArgs);
+impl From<SetEpochSurchargeIxArgs> for SetEpochSurchargeIxData {
+    fn from(args: SetEpochSurchargeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetEpochSurchargeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_EPOCH_SURCHARGE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_EPOCH_SURCHARGE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetEpochSurchargeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_EPOCH_SURCHARGE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_epoch_surcharge_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetEpochSurchargeKeys,
+    args: SetEpochSurchargeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_EPOCH_SURCHARGE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetEpochSurchargeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_epoch_surcharge_ix(
+    keys: SetEpochSurchargeKeys,
+    args: SetEpochSurchargeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_epoch_surcharge_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_epoch_surcharge_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetEpochSurchargeAccounts<'_, '_>,
+    args: SetEpochSurchargeIxArgs,
+) -> ProgramResult {
+    let keys: SetEpochSurchargeKeys = accounts.into();
+    let ix = set_epoch_surcharge_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_epoch_surcharge_invoke(
+    accounts: SetEpochSurchargeAccounts<'_, '_>,
+    args: SetEpochSurchargeIxArgs,
+) -> ProgramResult {
+    set_epoch_surcharge_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_epoch_surcharge_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetEpochSurchargeAccounts<'_, '_>,
+    args: SetEpochSurchargeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetEpochSurchargeKeys = accounts.into();
+    let ix = set_epoch_surcharge_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_epoch_surcharge_invoke_signed(
+    accounts: SetEpochSurchargeAccounts<'_, '_>,
+    args: SetEpochSurchargeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_epoch_surcharge_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_epoch_surcharge_verify_account_keys(
+    accounts: SetEpochSurchargeAccounts<'_, '_>,
+    keys: SetEpochSurchargeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_epoch_surcharge_verify_writable_privileges<'me, 'info>(
+    accounts: SetEpochSurchargeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_epoch_surcharge_verify_signer_privileges<'me, 'info>(
+    accounts: SetEpochSurchargeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_epoch_surcharge_verify_account_privileges<'me, 'info>(
+    accounts: SetEpochSurchargeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_epoch_surcharge_verify_writable_privileges(accounts)?;
+    set_epoch_surcharge_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LST_FEE_RAMP_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstFeeRampAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///FeeAccount PDA to modify
+    pub fee_acc: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstFeeRampKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///FeeAccount PDA to modify
+    pub fee_acc: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetLstFeeRampAccounts<'_, '_>> for SetLstFeeRampKeys {
+    fn from(accounts: SetLstFeeRampAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            fee_acc: *accounts.fee_acc.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetLstFeeRampKeys> for [AccountMeta; SET_LST_FEE_RAMP_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLstFeeRampKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LST_FEE_RAMP_IX_ACCOUNTS_LEN]> for SetLstFeeRampKeys {
+    fn from(pubkeys: [Pubkey; SET_LST_FEE_RAMP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            fee_acc: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetLstFeeRampAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LST_FEE_RAMP_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLstFeeRampAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.fee_acc.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LST_FEE_RAMP_IX_ACCOUNTS_LEN]>
+    for SetLstFeeRampAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LST_FEE_RAMP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            fee_acc: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SET_LST_FEE_RAMP_IX_DISCM: u8 = 243u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLstFeeRampIxArgs {
+    pub target_input_fee_bps: i16,
+    pub target_output_fee_bps: i16,
+    pub start_slot: u64,
+    pub end_slot: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLstFeeRampIxData(pub SetLstFeeRampIxArgs);
+impl From<SetLstFeeRampIxArgs> for SetLstFeeRampIxData {
+    fn from(args: SetLstFeeRampIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLstFeeRampIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LST_FEE_RAMP_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LST_FEE_RAMP_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLstFeeRampIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LST_FEE_RAMP_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lst_fee_ramp_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLstFeeRampKeys,
+    args: SetLstFeeRampIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LST_FEE_RAMP_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLstFeeRampIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lst_fee_ramp_ix(
+    keys: SetLstFeeRampKeys,
+    args: SetLstFeeRampIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lst_fee_ramp_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lst_fee_ramp_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstFeeRampAccounts<'_, '_>,
+    args: SetLstFeeRampIxArgs,
+) -> ProgramResult {
+    let keys: SetLstFeeRampKeys = accounts.into();
+    let ix = set_lst_fee_ramp_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lst_fee_ramp_invoke(
+    accounts: SetLstFeeRampAccounts<'_, '_>,
+    args: SetLstFeeRampIxArgs,
+) -> ProgramResult {
+    set_lst_fee_ramp_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lst_fee_ramp_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstFeeRampAccounts<'_, '_>,
+    args: SetLstFeeRampIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLstFeeRampKeys = accounts.into();
+    let ix = set_lst_fee_ramp_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lst_fee_ramp_invoke_signed(
+    accounts: SetLstFeeRampAccounts<'_, '_>,
+    args: SetLstFeeRampIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lst_fee_ramp_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lst_fee_ramp_verify_account_keys(
+    accounts: SetLstFeeRampAccounts<'_, '_>,
+    keys: SetLstFeeRampKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.fee_acc.key, &keys.fee_acc),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_fee_ramp_verify_writable_privileges<'me, 'info>(
+    accounts: SetLstFeeRampAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.fee_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_fee_ramp_verify_signer_privileges<'me, 'info>(
+    accounts: SetLstFeeRampAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_fee_ramp_verify_account_privileges<'me, 'info>(
+    accounts: SetLstFeeRampAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lst_fee_ramp_verify_writable_privileges(accounts)?;
+    set_lst_fee_ramp_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstLpDepositFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///FeeAccount PDA to modify
+    pub fee_acc: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstLpDepositFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///FeeAccount PDA to modify
+    pub fee_acc: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetLstLpDepositFeeAccounts<'_, '_>> for SetLstLpDepositFeeKeys {
+    fn from(accounts: SetLstLpDepositFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            fee_acc: *accounts.fee_acc.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetLstLpDepositFeeKeys> for [AccountMeta; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLstLpDepositFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]> for SetLstLpDepositFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            fee_acc: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetLstLpDepositFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLstLpDepositFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.fee_acc.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]>
+    for SetLstLpDepositFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            fee_acc: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SET_LST_LP_DEPOSIT_FEE_IX_DISCM: u8 = 244u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLstLpDepositFeeIxArgs {
+    pub lp_deposit_fee_bps: Option<u16>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLstLpDepositFeeIxData(pub SetLstLpDepositFeeIxArgs);
+impl From<SetLstLpDepositFeeIxArgs> for SetLstLpDepositFeeIxData {
+    fn from(args: SetLstLpDepositFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLstLpDepositFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LST_LP_DEPOSIT_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LST_LP_DEPOSIT_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLstLpDepositFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LST_LP_DEPOSIT_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lst_lp_deposit_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLstLpDepositFeeKeys,
+    args: SetLstLpDepositFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLstLpDepositFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lst_lp_deposit_fee_ix(
+    keys: SetLstLpDepositFeeKeys,
+    args: SetLstLpDepositFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lst_lp_deposit_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lst_lp_deposit_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstLpDepositFeeAccounts<'_, '_>,
+    args: SetLstLpDepositFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetLstLpDepositFeeKeys = accounts.into();
+    let ix = set_lst_lp_deposit_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lst_lp_deposit_fee_invoke(
+    accounts: SetLstLpDepositFeeAccounts<'_, '_>,
+    args: SetLstLpDepositFeeIxArgs,
+) -> ProgramResult {
+    set_lst_lp_deposit_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lst_lp_deposit_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstLpDepositFeeAccounts<'_, '_>,
+    args: SetLstLpDepositFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLstLpDepositFeeKeys = accounts.into();
+    let ix = set_lst_lp_deposit_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lst_lp_deposit_fee_invoke_signed(
+    accounts: SetLstLpDepositFeeAccounts<'_, '_>,
+    args: SetLstLpDepositFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lst_lp_deposit_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lst_lp_deposit_fee_verify_account_keys(
+    accounts: SetLstLpDepositFeeAccounts<'_, '_>,
+    keys: SetLstLpDepositFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.fee_acc.key, &keys.fee_acc),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_lp_deposit_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetLstLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.fee_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_lp_deposit_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetLstLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_lp_deposit_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetLstLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lst_lp_deposit_fee_verify_writable_privileges(accounts)?;
+    set_lst_lp_deposit_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpDepositFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpDepositFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
+}
+impl From<SetLpDepositFeeAccounts<'_, '_>> for SetLpDepositFeeKeys {
+    fn from(accounts: SetLpDepositFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetLpDepositFeeKeys> for [AccountMeta; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLpDepositFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]> for SetLpDepositFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            state: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetLpDepositFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLpDepositFeeAccounts<'_, 'info>) -> Self {
+        [accounts.manager.clone(), accounts.state.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]>
+    for SetLpDepositFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            state: &arr[1],
+        }
+    }
+}
+pub const SET_LP_DEPOSIT_FEE_IX_DISCM: u8 = 245u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLpDepositFeeIxArgs {
+    pub lp_deposit_fee_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLpDepositFeeIxData(pub SetLpDepositFeeIxArgs);
+impl From<SetLpDepositFeeIxArgs> for SetLpDepositFeeIxData {
+    fn from(args: SetLpDepositFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLpDepositFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LP_DEPOSIT_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LP_DEPOSIT_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLpDepositFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LP_DEPOSIT_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lp_deposit_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLpDepositFeeKeys,
+    args: SetLpDepositFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLpDepositFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lp_deposit_fee_ix(
+    keys: SetLpDepositFeeKeys,
+    args: SetLpDepositFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lp_deposit_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lp_deposit_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLpDepositFeeAccounts<'_, '_>,
+    args: SetLpDepositFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetLpDepositFeeKeys = accounts.into();
+    let ix = set_lp_deposit_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lp_deposit_fee_invoke(
+    accounts: SetLpDepositFeeAccounts<'_, '_>,
+    args: SetLpDepositFeeIxArgs,
+) -> ProgramResult {
+    set_lp_deposit_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lp_deposit_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLpDepositFeeAccounts<'_, '_>,
+    args: SetLpDepositFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLpDepositFeeKeys = accounts.into();
+    let ix = set_lp_deposit_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lp_deposit_fee_invoke_signed(
+    accounts: SetLpDepositFeeAccounts<'_, '_>,
+    args: SetLpDepositFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lp_deposit_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lp_deposit_fee_verify_account_keys(
+    accounts: SetLpDepositFeeAccounts<'_, '_>,
+    keys: SetLpDepositFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_deposit_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_deposit_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_deposit_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lp_deposit_fee_verify_writable_privileges(accounts)?;
+    set_lp_deposit_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct RemovePairFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Account to refund SOL rent to
+    pub refund_rent_to: &'me AccountInfo<'info>,
+    ///PairFeeAccount PDA to be closed
+    pub pair_fee_acc: &'me AccountInfo<'info>,
+    ///Mint of the input LST
+    pub input_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the output LST
+    pub output_lst_mint: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemovePairFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Account to refund SOL rent to
+    pub refund_rent_to: Pubkey,
+    ///PairFeeAccount PDA to be closed
+    pub pair_fee_acc: Pubkey,
+    ///Mint of the input LST
+    pub input_lst_mint: Pubkey,
+    ///Mint of the output LST
+    pub output_lst_mint: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<RemovePairFeeAccounts<'_, '_>> for RemovePairFeeKeys {
+    fn from(accounts: RemovePairFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            refund_rent_to: *accounts.refund_rent_to.key,
+            pair_fee_acc: *accounts.pair_fee_acc.key,
+            input_lst_mint: *accounts.input_lst_mint.key,
+            output_lst_mint: *accounts.output_lst_mint.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<RemovePairFeeKeys> for [AccountMeta; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: RemovePairFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pair_fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.input_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN]> for RemovePairFeeKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            refund_rent_to: pubkeys[1],
+            pair_fee_acc: pubkeys[2],
+            input_lst_mint: pubkeys[3],
+            output_lst_mint: pubkeys[4],
+            state: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<RemovePairFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemovePairFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.refund_rent_to.clone(),
+            accounts.pair_fee_acc.clone(),
+            accounts.input_lst_mint.clone(),
+            accounts.output_lst_mint.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN]>
+    for RemovePairFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            refund_rent_to: &arr[1],
+            pair_fee_acc: &arr[2],
+            input_lst_mint: &arr[3],
+            output_lst_mint: &arr[4],
+            state: &arr[5],
+        }
+    }
+}
+pub const REMOVE_PAIR_FEE_IX_DISCM: u8 = 246u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemovePairFeeIxData;
+impl RemovePairFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_PAIR_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_PAIR_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_PAIR_FEE_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_pair_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemovePairFeeKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: RemovePairFeeIxData.try_to_vec()?,
+    })
+}
+pub fn remove_pair_fee_ix(keys: RemovePairFeeKeys) -> std::io::Result<Instruction> {
+    remove_pair_fee_ix_with_program_id(crate::ID, keys)
+}
+pub fn remove_pair_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemovePairFeeAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: RemovePairFeeKeys = accounts.into();
+    let ix = remove_pair_fee_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_pair_fee_invoke(accounts: RemovePairFeeAccounts<'_, '_>) -> ProgramResult {
+    remove_pair_fee_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn remove_pair_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemovePairFeeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemovePairFeeKeys = accounts.into();
+    let ix = remove_pair_fee_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_pair_fee_invoke_signed(
+    accounts: RemovePairFeeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_pair_fee_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn remove_pair_fee_verify_account_keys(
+    accounts: RemovePairFeeAccounts<'_, '_>,
+    keys: RemovePairFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+        (accounts.pair_fee_acc.key, &keys.pair_fee_acc),
+        (accounts.input_lst_mint.key, &keys.input_lst_mint),
+        (accounts.output_lst_mint.key, &keys.output_lst_mint),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_pair_fee_verify_writable_privileges<'me, 'info>(
+    accounts: RemovePairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.refund_rent_to, accounts.pair_fee_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_pair_fee_verify_signer_privileges<'me, 'info>(
+    accounts: RemovePairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_pair_fee_verify_account_privileges<'me, 'info>(
+    accounts: RemovePairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_pair_fee_verify_writable_privileges(accounts)?;
+    remove_pair_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_PAIR_FEE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetPairFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///PairFeeAccount PDA to modify
+    pub pair_fee_acc: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetPairFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///PairFeeAccount PDA to modify
+    pub pair_fee_acc: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetPairFeeAccounts<'_, '_>> for SetPairFeeKeys {
+    fn from(accounts: SetPairFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            pair_fee_acc: *accounts.pair_fee_acc.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetPairFeeKeys> for [AccountMeta; SET_PAIR_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetPairFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pair_fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_PAIR_FEE_IX_ACCOUNTS_LEN]> for SetPairFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            pair_fee_acc: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetPairFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_PAIR_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetPairFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.pair_fee_acc.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PAIR_FEE_IX_ACCOUNTS_LEN]>
+    for SetPairFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            pair_fee_acc: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SET_PAIR_FEE_IX_DISCM: u8 = 247u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPairFeeIxArgs {
+    pub fee_bps: i16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetPairFeeIxData(pub SetPairFeeIxArgs);
+impl From<SetPairFeeIxArgs> for SetPairFeeIxData {
+    fn from(args: SetPairFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetPairFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_PAIR_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_PAIR_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetPairFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_PAIR_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_pair_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetPairFeeKeys,
+    args: SetPairFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_PAIR_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetPairFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_pair_fee_ix(
+    keys: SetPairFeeKeys,
+    args: SetPairFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_pair_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_pair_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetPairFeeAccounts<'_, '_>,
+    args: SetPairFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetPairFeeKeys = accounts.into();
+    let ix = set_pair_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_pair_fee_invoke(
+    accounts: SetPairFeeAccounts<'_, '_>,
+    args: SetPairFeeIxArgs,
+) -> ProgramResult {
+    set_pair_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_pair_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetPairFeeAccounts<'_, '_>,
+    args: SetPairFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetPairFeeKeys = accounts.into();
+    let ix = set_pair_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_pair_fee_invoke_signed(
+    accounts: SetPairFeeAccounts<'_, '_>,
+    args: SetPairFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_pair_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_pair_fee_verify_account_keys(
+    accounts: SetPairFeeAccounts<'_, '_>,
+    keys: SetPairFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.pair_fee_acc.key, &keys.pair_fee_acc),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_pair_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pair_fee_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_pair_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_pair_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_pair_fee_verify_writable_privileges(accounts)?;
+    set_pair_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_PAIR_FEE_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone, Debug)]
+pub struct AddPairFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Account paying for PairFeeAccount's rent
+    pub payer: &'me AccountInfo<'info>,
+    ///PairFeeAccount PDA to be created
+    pub pair_fee_acc: &'me AccountInfo<'info>,
+    ///Mint of the input LST
+    pub input_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the output LST
+    pub output_lst_mint: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddPairFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Account paying for PairFeeAccount's rent
+    pub payer: Pubkey,
+    ///PairFeeAccount PDA to be created
+    pub pair_fee_acc: Pubkey,
+    ///Mint of the input LST
+    pub input_lst_mint: Pubkey,
+    ///Mint of the output LST
+    pub output_lst_mint: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<AddPairFeeAccounts<'_, '_>> for AddPairFeeKeys {
+    fn from(accounts: AddPairFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            payer: *accounts.payer.key,
+            pair_fee_acc: *accounts.pair_fee_acc.key,
+            input_lst_mint: *accounts.input_lst_mint.key,
+            output_lst_mint: *accounts.output_lst_mint.key,
+            state: *accounts.state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<AddPairFeeKeys> for [AccountMeta; ADD_PAIR_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddPairFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pair_fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.input_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_PAIR_FEE_IX_ACCOUNTS_LEN]> for AddPairFeeKeys {
+    fn from(pubkeys: [Pubkey; ADD_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            payer: pubkeys[1],
+            pair_fee_acc: pubkeys[2],
+            input_lst_mint: pubkeys[3],
+            output_lst_mint: pubkeys[4],
+            state: pubkeys[5],
+            system_program: pubkeys[6],
+        }
+    }
+}
+impl<'info> From<AddPairFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ADD_PAIR_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AddPairFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.payer.clone(),
+            accounts.pair_fee_acc.clone(),
+            accounts.input_lst_mint.clone(),
+            accounts.output_lst_mint.clone(),
+            accounts.state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_PAIR_FEE_IX_ACCOUNTS_LEN]>
+    for AddPairFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            payer: &arr[1],
+            pair_fee_acc: &arr[2],
+            input_lst_mint: &arr[3],
+            output_lst_mint: &arr[4],
+            state: &arr[5],
+            system_program: &arr[6],
+        }
+    }
+}
+pub const ADD_PAIR_FEE_IX_DISCM: u8 = 248u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddPairFeeIxArgs {
+    pub fee_bps: i16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddPairFeeIxData(pub AddPairFeeIxArgs);
+impl From<AddPairFeeIxArgs> for AddPairFeeIxData {
+    fn from(args: AddPairFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddPairFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_PAIR_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_PAIR_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddPairFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_PAIR_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_pair_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddPairFeeKeys,
+    args: AddPairFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_PAIR_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddPairFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_pair_fee_ix(
+    keys: AddPairFeeKeys,
+    args: AddPairFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    add_pair_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_pair_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddPairFeeAccounts<'_, '_>,
+    args: AddPairFeeIxArgs,
+) -> ProgramResult {
+    let keys: AddPairFeeKeys = accounts.into();
+    let ix = add_pair_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_pair_fee_invoke(
+    accounts: AddPairFeeAccounts<'_, '_>,
+    args: AddPairFeeIxArgs,
+) -> ProgramResult {
+    add_pair_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_pair_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddPairFeeAccounts<'_, '_>,
+    args: AddPairFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddPairFeeKeys = accounts.into();
+    let ix = add_pair_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_pair_fee_invoke_signed(
+    accounts: AddPairFeeAccounts<'_, '_>,
+    args: AddPairFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_pair_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_pair_fee_verify_account_keys(
+    accounts: AddPairFeeAccounts<'_, '_>,
+    keys: AddPairFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pair_fee_acc.key, &keys.pair_fee_acc),
+        (accounts.input_lst_mint.key, &keys.input_lst_mint),
+        (accounts.output_lst_mint.key, &keys.output_lst_mint),
+        (accounts.state.key, &keys.state),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_pair_fee_verify_writable_privileges<'me, 'info>(
+    accounts: AddPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pair_fee_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_pair_fee_verify_signer_privileges<'me, 'info>(
+    accounts: AddPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_pair_fee_verify_account_privileges<'me, 'info>(
+    accounts: AddPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_pair_fee_verify_writable_privileges(accounts)?;
+    add_pair_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ACCEPT_MANAGER_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptManagerAccounts<'me, 'info> {
+    ///The pending program manager to transfer management to
+    pub pending_manager: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptManagerKeys {
+    ///The pending program manager to transfer management to
+    pub pending_manager: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<AcceptManagerAccounts<'_, '_>> for AcceptManagerKeys {
+    fn from(accounts: AcceptManagerAccounts) -> Self {
+        Self {
+            pending_manager: *accounts.pending_manager.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<AcceptManagerKeys> for [AccountMeta; ACCEPT_MANAGER_IX_ACCOUNTS_LEN] {
+    fn from(keys: AcceptManagerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.pending_manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ACCEPT_MANAGER_IX_ACCOUNTS_LEN]> for AcceptManagerKeys {
+    fn from(pubkeys: [Pubkey; ACCEPT_MANAGER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pending_manager: pubkeys[0],
+            state: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<AcceptManagerAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ACCEPT_MANAGER_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AcceptManagerAccounts<'_, 'info>) -> Self {
+        [accounts.pending_manager.clone(), accounts.state.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ACCEPT_MANAGER_IX_ACCOUNTS_LEN]>
+    for AcceptManagerAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ACCEPT_MANAGER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pending_manager: &arr[0],
+            state: &arr[1],
+        }
+    }
+}
+pub const ACCEPT_MANAGER_IX_DISCM: u8 = 249u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcceptManagerIxData;
+impl AcceptManagerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ACCEPT_MANAGER_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ACCEPT_MANAGER_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ACCEPT_MANAGER_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn accept_manager_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AcceptManagerKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ACCEPT_MANAGER_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: AcceptManagerIxData.try_to_vec()?,
+    })
+}
+pub fn accept_manager_ix(keys: AcceptManagerKeys) -> std::io::Result<Instruction> {
+    accept_manager_ix_with_program_id(crate::ID, keys)
+}
+pub fn accept_manager_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptManagerAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: AcceptManagerKeys = accounts.into();
+    let ix = accept_manager_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn accept_manager_invoke(accounts: AcceptManagerAccounts<'_, '_>) -> ProgramResult {
+    accept_manager_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn accept_manager_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptManagerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AcceptManagerKeys = accounts.into();
+    let ix = accept_manager_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn accept_manager_invoke_signed(
+    accounts: AcceptManagerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    accept_manager_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn accept_manager_verify_account_keys(
+    accounts: AcceptManagerAccounts<'_, '_>,
+    keys: AcceptManagerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.pending_manager.key, &keys.pending_manager),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn accept_manager_verify_writable_privileges<'me, 'info>(
+    accounts: AcceptManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn accept_manager_verify_signer_privileges<'me, 'info>(
+    accounts: AcceptManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.pending_manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn accept_manager_verify_account_privileges<'me, 'info>(
+    accounts: AcceptManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    accept_manager_verify_writable_privileges(accounts)?;
+    accept_manager_verify_signer_privileges(accounts)?;
+    Ok(())
+}
 pub const SET_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct SetLpWithdrawalFeeAccounts<'me, 'info> {
@@ -1714,7 +3971,7 @@ pub const SET_MANAGER_IX_ACCOUNTS_LEN: usize = 3;
 pub struct SetManagerAccounts<'me, 'info> {
     ///The current program manager
     pub current_manager: &'me AccountInfo<'info>,
-    ///The new program manager to set to
+    ///The new program manager to propose
     pub new_manager: &'me AccountInfo<'info>,
     ///The program state PDA
     pub state: &'me AccountInfo<'info>,
@@ -1723,7 +3980,7 @@ pub struct SetManagerAccounts<'me, 'info> {
 pub struct SetManagerKeys {
     ///The current program manager
     pub current_manager: Pubkey,
-    ///The new program manager to set to
+    ///The new program manager to propose
     pub new_manager: Pubkey,
     ///The program state PDA
     pub state: Pubkey,
@@ -7,13 +7,33 @@ use solana_program::pubkey::Pubkey;
 pub struct ProgramState {
     pub manager: Pubkey,
     pub lp_withdrawal_fee_bps: u16,
+    pub pending_manager: Pubkey,
+    pub lp_deposit_fee_bps: u16,
+    pub epoch_surcharge_bps: u16,
+    pub epoch_surcharge_slots: u16,
 }
 #[repr(C)]
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeeAccount {
     pub bump: u8,
-    pub padding: u8,
+    pub has_lp_deposit_fee_bps_override: u8,
     pub input_fee_bps: i16,
     pub output_fee_bps: i16,
+    pub lp_deposit_fee_bps_override: u16,
+    pub ramp_start_slot: u64,
+    pub ramp_end_slot: u64,
+    pub ramp_target_input_fee_bps: i16,
+    pub ramp_target_output_fee_bps: i16,
+    pub ramp_padding: [u8; 4],
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PairFeeAccount {
+    pub bump: u8,
+    pub padding: u8,
+    pub fee_bps: i16,
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
 }
@@ -0,0 +1,47 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum ImbalanceFeeError {
+    #[error("Invalid program state data")]
+    InvalidProgramStateData = 0,
+    #[error("Incorrect program state account")]
+    IncorrectProgramState = 1,
+    #[error("TargetWeight is not initialized for the given LST mint")]
+    UnsupportedLstMint = 2,
+    #[error("Given fee value is out of bound")]
+    FeeOutOfBound = 3,
+    #[error("Given target weight is out of bound")]
+    TargetWeightOutOfBound = 4,
+    #[error("Invalid LstStateList data")]
+    InvalidLstStateListData = 5,
+    #[error("LST is not on the pool's LstStateList")]
+    LstNotInPool = 6,
+    #[error("Math error")]
+    MathError = 7,
+}
+impl From<ImbalanceFeeError> for ProgramError {
+    fn from(e: ImbalanceFeeError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for ImbalanceFeeError {
+    fn type_of() -> &'static str {
+        "ImbalanceFeeError"
+    }
+}
+impl PrintProgramError for ImbalanceFeeError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
@@ -0,0 +1,7 @@
+solana_program::declare_id!("2wCZy4ecVjYhFx9Wr5tQCv1AfWXm3s2XWFsoGrd73Htg");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
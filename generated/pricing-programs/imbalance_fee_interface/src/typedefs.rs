@@ -0,0 +1,23 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProgramState {
+    pub manager: Pubkey,
+    pub pending_manager: Pubkey,
+    pub lp_withdrawal_fee_bps: u16,
+    pub base_fee_bps: u16,
+    pub imbalance_slope_bps: u16,
+    pub max_imbalance_fee_bps: u16,
+    pub max_discount_bps: u16,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetWeight {
+    pub bump: u8,
+    pub padding: u8,
+    pub target_weight_bps: u16,
+}
@@ -0,0 +1,43 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum VolumeSurgeError {
+    #[error("Invalid program state data")]
+    InvalidProgramStateData = 0,
+    #[error("Incorrect program state account")]
+    IncorrectProgramState = 1,
+    #[error("VolumeAccount is not initialized for the given LST mint")]
+    UnsupportedLstMint = 2,
+    #[error("Given fee value is out of bound")]
+    FeeOutOfBound = 3,
+    #[error("Invalid LstStateList data")]
+    InvalidLstStateListData = 4,
+    #[error("Math error")]
+    MathError = 5,
+}
+impl From<VolumeSurgeError> for ProgramError {
+    fn from(e: VolumeSurgeError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for VolumeSurgeError {
+    fn type_of() -> &'static str {
+        "VolumeSurgeError"
+    }
+}
+impl PrintProgramError for VolumeSurgeError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
@@ -0,0 +1,7 @@
+solana_program::declare_id!("Divc29438s334NSXACq9bso2vnnvF3VMyhM5pGU8Pskt");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
@@ -0,0 +1,25 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProgramState {
+    pub manager: Pubkey,
+    pub pending_manager: Pubkey,
+    pub lp_withdrawal_fee_bps: u16,
+    pub base_fee_bps: u16,
+    pub surge_slope_bps: u16,
+    pub max_surge_fee_bps: u16,
+    pub decay_window_slots: u64,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VolumeAccount {
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub last_update_slot: u64,
+    pub inflow_sol_value: u64,
+    pub outflow_sol_value: u64,
+}
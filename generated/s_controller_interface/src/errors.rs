@@ -84,6 +84,16 @@ pub enum SControllerError {
     SwapSameLst = 35,
     #[error("Cannot add the same authority that has already been authorized")]
     DuplicateDisablePoolAuthority = 36,
+    #[error("Amount received differs from amount sent. Exact out swaps do not support LSTs with transfer fees")]
+    TransferAmountMismatch = 37,
+    #[error("LP token mint's token program does not support token metadata")]
+    UnsupportedLpTokenProgram = 38,
+    #[error("Transaction landed after its expiry slot or timestamp")]
+    DeadlineExceeded = 39,
+    #[error("Basket must include every LST on the list, in list order")]
+    IncompleteBasket = 40,
+    #[error("Each LST can only be included once")]
+    DuplicateLstInput = 41,
 }
 impl From<SControllerError> for ProgramError {
     fn from(e: SControllerError) -> Self {
@@ -1,3 +1,4 @@
+use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo,
@@ -33,6 +34,14 @@ pub enum SControllerProgramIx {
     EndRebalance,
     SetRebalanceAuthority,
     Initialize,
+    InitLpTokenMetadata(InitLpTokenMetadataIxArgs),
+    SetLpTokenMetadata(SetLpTokenMetadataIxArgs),
+    SwapExactInWithDeadline(SwapExactInWithDeadlineIxArgs),
+    SwapExactOutWithDeadline(SwapExactOutWithDeadlineIxArgs),
+    AddLiquidityWithDeadline(AddLiquidityWithDeadlineIxArgs),
+    RemoveLiquidityWithDeadline(RemoveLiquidityWithDeadlineIxArgs),
+    AddLiquidityMulti(AddLiquidityMultiIxArgs),
+    RemoveLiquidityBasket(RemoveLiquidityBasketIxArgs),
 }
 impl SControllerProgramIx {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -88,6 +97,30 @@ impl SControllerProgramIx {
             END_REBALANCE_IX_DISCM => Ok(Self::EndRebalance),
             SET_REBALANCE_AUTHORITY_IX_DISCM => Ok(Self::SetRebalanceAuthority),
             INITIALIZE_IX_DISCM => Ok(Self::Initialize),
+            INIT_LP_TOKEN_METADATA_IX_DISCM => Ok(Self::InitLpTokenMetadata(
+                InitLpTokenMetadataIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_LP_TOKEN_METADATA_IX_DISCM => Ok(Self::SetLpTokenMetadata(
+                SetLpTokenMetadataIxArgs::deserialize(&mut reader)?,
+            )),
+            SWAP_EXACT_IN_WITH_DEADLINE_IX_DISCM => Ok(Self::SwapExactInWithDeadline(
+                SwapExactInWithDeadlineIxArgs::deserialize(&mut reader)?,
+            )),
+            SWAP_EXACT_OUT_WITH_DEADLINE_IX_DISCM => Ok(Self::SwapExactOutWithDeadline(
+                SwapExactOutWithDeadlineIxArgs::deserialize(&mut reader)?,
+            )),
+            ADD_LIQUIDITY_WITH_DEADLINE_IX_DISCM => Ok(Self::AddLiquidityWithDeadline(
+                AddLiquidityWithDeadlineIxArgs::deserialize(&mut reader)?,
+            )),
+            REMOVE_LIQUIDITY_WITH_DEADLINE_IX_DISCM => Ok(Self::RemoveLiquidityWithDeadline(
+                RemoveLiquidityWithDeadlineIxArgs::deserialize(&mut reader)?,
+            )),
+            ADD_LIQUIDITY_MULTI_IX_DISCM => Ok(Self::AddLiquidityMulti(
+                AddLiquidityMultiIxArgs::deserialize(&mut reader)?,
+            )),
+            REMOVE_LIQUIDITY_BASKET_IX_DISCM => Ok(Self::RemoveLiquidityBasket(
+                RemoveLiquidityBasketIxArgs::deserialize(&mut reader)?,
+            )),
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!("discm {:?} not found", maybe_discm),
@@ -162,6 +195,38 @@ impl SControllerProgramIx {
             Self::EndRebalance => writer.write_all(&[END_REBALANCE_IX_DISCM]),
             Self::SetRebalanceAuthority => writer.write_all(&[SET_REBALANCE_AUTHORITY_IX_DISCM]),
             Self::Initialize => writer.write_all(&[INITIALIZE_IX_DISCM]),
+            Self::InitLpTokenMetadata(args) => {
+                writer.write_all(&[INIT_LP_TOKEN_METADATA_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLpTokenMetadata(args) => {
+                writer.write_all(&[SET_LP_TOKEN_METADATA_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SwapExactInWithDeadline(args) => {
+                writer.write_all(&[SWAP_EXACT_IN_WITH_DEADLINE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SwapExactOutWithDeadline(args) => {
+                writer.write_all(&[SWAP_EXACT_OUT_WITH_DEADLINE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddLiquidityWithDeadline(args) => {
+                writer.write_all(&[ADD_LIQUIDITY_WITH_DEADLINE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveLiquidityWithDeadline(args) => {
+                writer.write_all(&[REMOVE_LIQUIDITY_WITH_DEADLINE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddLiquidityMulti(args) => {
+                writer.write_all(&[ADD_LIQUIDITY_MULTI_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveLiquidityBasket(args) => {
+                writer.write_all(&[REMOVE_LIQUIDITY_BASKET_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
         }
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -6077,3 +6142,2491 @@ pub fn initialize_verify_account_privileges<'me, 'info>(
     initialize_verify_signer_privileges(accounts)?;
     Ok(())
 }
+pub const INIT_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone, Debug)]
+pub struct InitLpTokenMetadataAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Account paying for the metadata's rent
+    pub payer: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA. LP token mint authority and metadata update authority
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///The LP token metadata account. Metaplex metadata PDA for Tokenkeg LP token mints, the LP token mint itself for Token-2022 LP token mints
+    pub metadata: &'me AccountInfo<'info>,
+    ///Metaplex token metadata program for Tokenkeg LP token mints, Token-2022 for Token-2022 LP token mints
+    pub metadata_program: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct InitLpTokenMetadataKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Account paying for the metadata's rent
+    pub payer: Pubkey,
+    ///The pool's state singleton PDA. LP token mint authority and metadata update authority
+    pub pool_state: Pubkey,
+    ///The pool's LP token mint
+    pub lp_token_mint: Pubkey,
+    ///The LP token metadata account. Metaplex metadata PDA for Tokenkeg LP token mints, the LP token mint itself for Token-2022 LP token mints
+    pub metadata: Pubkey,
+    ///Metaplex token metadata program for Tokenkeg LP token mints, Token-2022 for Token-2022 LP token mints
+    pub metadata_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<InitLpTokenMetadataAccounts<'_, '_>> for InitLpTokenMetadataKeys {
+    fn from(accounts: InitLpTokenMetadataAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            payer: *accounts.payer.key,
+            pool_state: *accounts.pool_state.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            metadata: *accounts.metadata.key,
+            metadata_program: *accounts.metadata_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<InitLpTokenMetadataKeys> for [AccountMeta; INIT_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitLpTokenMetadataKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.metadata_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INIT_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]> for InitLpTokenMetadataKeys {
+    fn from(pubkeys: [Pubkey; INIT_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            payer: pubkeys[1],
+            pool_state: pubkeys[2],
+            lp_token_mint: pubkeys[3],
+            metadata: pubkeys[4],
+            metadata_program: pubkeys[5],
+            system_program: pubkeys[6],
+        }
+    }
+}
+impl<'info> From<InitLpTokenMetadataAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INIT_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitLpTokenMetadataAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.payer.clone(),
+            accounts.pool_state.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.metadata.clone(),
+            accounts.metadata_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INIT_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]>
+    for InitLpTokenMetadataAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INIT_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            payer: &arr[1],
+            pool_state: &arr[2],
+            lp_token_mint: &arr[3],
+            metadata: &arr[4],
+            metadata_program: &arr[5],
+            system_program: &arr[6],
+        }
+    }
+}
+pub const INIT_LP_TOKEN_METADATA_IX_DISCM: u8 = 23u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitLpTokenMetadataIxArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitLpTokenMetadataIxData(pub InitLpTokenMetadataIxArgs);
+impl From<InitLpTokenMetadataIxArgs> for InitLpTokenMetadataIxData {
+    fn from(args: InitLpTokenMetadataIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitLpTokenMetadataIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != INIT_LP_TOKEN_METADATA_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INIT_LP_TOKEN_METADATA_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(InitLpTokenMetadataIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[INIT_LP_TOKEN_METADATA_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn init_lp_token_metadata_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitLpTokenMetadataKeys,
+    args: InitLpTokenMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INIT_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitLpTokenMetadataIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn init_lp_token_metadata_ix(
+    keys: InitLpTokenMetadataKeys,
+    args: InitLpTokenMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    init_lp_token_metadata_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn init_lp_token_metadata_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitLpTokenMetadataAccounts<'_, '_>,
+    args: InitLpTokenMetadataIxArgs,
+) -> ProgramResult {
+    let keys: InitLpTokenMetadataKeys = accounts.into();
+    let ix = init_lp_token_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn init_lp_token_metadata_invoke(
+    accounts: InitLpTokenMetadataAccounts<'_, '_>,
+    args: InitLpTokenMetadataIxArgs,
+) -> ProgramResult {
+    init_lp_token_metadata_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn init_lp_token_metadata_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitLpTokenMetadataAccounts<'_, '_>,
+    args: InitLpTokenMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitLpTokenMetadataKeys = accounts.into();
+    let ix = init_lp_token_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn init_lp_token_metadata_invoke_signed(
+    accounts: InitLpTokenMetadataAccounts<'_, '_>,
+    args: InitLpTokenMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    init_lp_token_metadata_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn init_lp_token_metadata_verify_account_keys(
+    accounts: InitLpTokenMetadataAccounts<'_, '_>,
+    keys: InitLpTokenMetadataKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.metadata.key, &keys.metadata),
+        (accounts.metadata_program.key, &keys.metadata_program),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn init_lp_token_metadata_verify_writable_privileges<'me, 'info>(
+    accounts: InitLpTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.lp_token_mint, accounts.metadata] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn init_lp_token_metadata_verify_signer_privileges<'me, 'info>(
+    accounts: InitLpTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn init_lp_token_metadata_verify_account_privileges<'me, 'info>(
+    accounts: InitLpTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    init_lp_token_metadata_verify_writable_privileges(accounts)?;
+    init_lp_token_metadata_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpTokenMetadataAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Account paying for any additional rent of the metadata
+    pub payer: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA. LP token metadata update authority
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///The LP token metadata account. Metaplex metadata PDA for Tokenkeg LP token mints, the LP token mint itself for Token-2022 LP token mints
+    pub metadata: &'me AccountInfo<'info>,
+    ///Metaplex token metadata program for Tokenkeg LP token mints, Token-2022 for Token-2022 LP token mints
+    pub metadata_program: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpTokenMetadataKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Account paying for any additional rent of the metadata
+    pub payer: Pubkey,
+    ///The pool's state singleton PDA. LP token metadata update authority
+    pub pool_state: Pubkey,
+    ///The pool's LP token mint
+    pub lp_token_mint: Pubkey,
+    ///The LP token metadata account. Metaplex metadata PDA for Tokenkeg LP token mints, the LP token mint itself for Token-2022 LP token mints
+    pub metadata: Pubkey,
+    ///Metaplex token metadata program for Tokenkeg LP token mints, Token-2022 for Token-2022 LP token mints
+    pub metadata_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetLpTokenMetadataAccounts<'_, '_>> for SetLpTokenMetadataKeys {
+    fn from(accounts: SetLpTokenMetadataAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            payer: *accounts.payer.key,
+            pool_state: *accounts.pool_state.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            metadata: *accounts.metadata.key,
+            metadata_program: *accounts.metadata_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetLpTokenMetadataKeys> for [AccountMeta; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLpTokenMetadataKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.metadata_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]> for SetLpTokenMetadataKeys {
+    fn from(pubkeys: [Pubkey; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            payer: pubkeys[1],
+            pool_state: pubkeys[2],
+            lp_token_mint: pubkeys[3],
+            metadata: pubkeys[4],
+            metadata_program: pubkeys[5],
+            system_program: pubkeys[6],
+        }
+    }
+}
+impl<'info> From<SetLpTokenMetadataAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLpTokenMetadataAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.payer.clone(),
+            accounts.pool_state.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.metadata.clone(),
+            accounts.metadata_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]>
+    for SetLpTokenMetadataAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            payer: &arr[1],
+            pool_state: &arr[2],
+            lp_token_mint: &arr[3],
+            metadata: &arr[4],
+            metadata_program: &arr[5],
+            system_program: &arr[6],
+        }
+    }
+}
+pub const SET_LP_TOKEN_METADATA_IX_DISCM: u8 = 24u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLpTokenMetadataIxArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLpTokenMetadataIxData(pub SetLpTokenMetadataIxArgs);
+impl From<SetLpTokenMetadataIxArgs> for SetLpTokenMetadataIxData {
+    fn from(args: SetLpTokenMetadataIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLpTokenMetadataIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LP_TOKEN_METADATA_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LP_TOKEN_METADATA_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLpTokenMetadataIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LP_TOKEN_METADATA_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lp_token_metadata_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLpTokenMetadataKeys,
+    args: SetLpTokenMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLpTokenMetadataIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lp_token_metadata_ix(
+    keys: SetLpTokenMetadataKeys,
+    args: SetLpTokenMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lp_token_metadata_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lp_token_metadata_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLpTokenMetadataAccounts<'_, '_>,
+    args: SetLpTokenMetadataIxArgs,
+) -> ProgramResult {
+    let keys: SetLpTokenMetadataKeys = accounts.into();
+    let ix = set_lp_token_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lp_token_metadata_invoke(
+    accounts: SetLpTokenMetadataAccounts<'_, '_>,
+    args: SetLpTokenMetadataIxArgs,
+) -> ProgramResult {
+    set_lp_token_metadata_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lp_token_metadata_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLpTokenMetadataAccounts<'_, '_>,
+    args: SetLpTokenMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLpTokenMetadataKeys = accounts.into();
+    let ix = set_lp_token_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lp_token_metadata_invoke_signed(
+    accounts: SetLpTokenMetadataAccounts<'_, '_>,
+    args: SetLpTokenMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lp_token_metadata_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lp_token_metadata_verify_account_keys(
+    accounts: SetLpTokenMetadataAccounts<'_, '_>,
+    keys: SetLpTokenMetadataKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.metadata.key, &keys.metadata),
+        (accounts.metadata_program.key, &keys.metadata_program),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_token_metadata_verify_writable_privileges<'me, 'info>(
+    accounts: SetLpTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.lp_token_mint, accounts.metadata] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_token_metadata_verify_signer_privileges<'me, 'info>(
+    accounts: SetLpTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_token_metadata_verify_account_privileges<'me, 'info>(
+    accounts: SetLpTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lp_token_metadata_verify_writable_privileges(accounts)?;
+    set_lp_token_metadata_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SWAP_EXACT_IN_WITH_DEADLINE_IX_ACCOUNTS_LEN: usize = 12;
+#[derive(Copy, Clone, Debug)]
+pub struct SwapExactInWithDeadlineAccounts<'me, 'info> {
+    ///Authority of src_lst_acc. User making the swap.
+    pub signer: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: &'me AccountInfo<'info>,
+    ///LST token account being swapped from
+    pub src_lst_acc: &'me AccountInfo<'info>,
+    ///LST token account to swapped to
+    pub dst_lst_acc: &'me AccountInfo<'info>,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///Source LST token program
+    pub src_lst_token_program: &'me AccountInfo<'info>,
+    ///Destination LST token program
+    pub dst_lst_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: &'me AccountInfo<'info>,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SwapExactInWithDeadlineKeys {
+    ///Authority of src_lst_acc. User making the swap.
+    pub signer: Pubkey,
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: Pubkey,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: Pubkey,
+    ///LST token account being swapped from
+    pub src_lst_acc: Pubkey,
+    ///LST token account to swapped to
+    pub dst_lst_acc: Pubkey,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: Pubkey,
+    ///Source LST token program
+    pub src_lst_token_program: Pubkey,
+    ///Destination LST token program
+    pub dst_lst_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: Pubkey,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: Pubkey,
+}
+impl From<SwapExactInWithDeadlineAccounts<'_, '_>> for SwapExactInWithDeadlineKeys {
+    fn from(accounts: SwapExactInWithDeadlineAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            src_lst_mint: *accounts.src_lst_mint.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            src_lst_acc: *accounts.src_lst_acc.key,
+            dst_lst_acc: *accounts.dst_lst_acc.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            src_lst_token_program: *accounts.src_lst_token_program.key,
+            dst_lst_token_program: *accounts.dst_lst_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            src_pool_reserves: *accounts.src_pool_reserves.key,
+            dst_pool_reserves: *accounts.dst_pool_reserves.key,
+        }
+    }
+}
+impl From<SwapExactInWithDeadlineKeys>
+    for [AccountMeta; SWAP_EXACT_IN_WITH_DEADLINE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: SwapExactInWithDeadlineKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SWAP_EXACT_IN_WITH_DEADLINE_IX_ACCOUNTS_LEN]> for SwapExactInWithDeadlineKeys {
+    fn from(pubkeys: [Pubkey; SWAP_EXACT_IN_WITH_DEADLINE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            src_lst_mint: pubkeys[1],
+            dst_lst_mint: pubkeys[2],
+            src_lst_acc: pubkeys[3],
+            dst_lst_acc: pubkeys[4],
+            protocol_fee_accumulator: pubkeys[5],
+            src_lst_token_program: pubkeys[6],
+            dst_lst_token_program: pubkeys[7],
+            pool_state: pubkeys[8],
+            lst_state_list: pubkeys[9],
+            src_pool_reserves: pubkeys[10],
+            dst_pool_reserves: pubkeys[11],
+        }
+    }
+}
+impl<'info> From<SwapExactInWithDeadlineAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SWAP_EXACT_IN_WITH_DEADLINE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SwapExactInWithDeadlineAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.src_lst_mint.clone(),
+            accounts.dst_lst_mint.clone(),
+            accounts.src_lst_acc.clone(),
+            accounts.dst_lst_acc.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.src_lst_token_program.clone(),
+            accounts.dst_lst_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.src_pool_reserves.clone(),
+            accounts.dst_pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_EXACT_IN_WITH_DEADLINE_IX_ACCOUNTS_LEN]>
+    for SwapExactInWithDeadlineAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SWAP_EXACT_IN_WITH_DEADLINE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            src_lst_mint: &arr[1],
+            dst_lst_mint: &arr[2],
+            src_lst_acc: &arr[3],
+            dst_lst_acc: &arr[4],
+            protocol_fee_accumulator: &arr[5],
+            src_lst_token_program: &arr[6],
+            dst_lst_token_program: &arr[7],
+            pool_state: &arr[8],
+            lst_state_list: &arr[9],
+            src_pool_reserves: &arr[10],
+            dst_pool_reserves: &arr[11],
+        }
+    }
+}
+pub const SWAP_EXACT_IN_WITH_DEADLINE_IX_DISCM: u8 = 25u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapExactInWithDeadlineIxArgs {
+    pub src_lst_value_calc_accs: u8,
+    pub dst_lst_value_calc_accs: u8,
+    pub src_lst_index: u32,
+    pub dst_lst_index: u32,
+    pub min_amount_out: u64,
+    pub amount: u64,
+    pub deadline: Deadline,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapExactInWithDeadlineIxData(pub SwapExactInWithDeadlineIxArgs);
+impl From<SwapExactInWithDeadlineIxArgs> for SwapExactInWithDeadlineIxData {
+    fn from(args: SwapExactInWithDeadlineIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SwapExactInWithDeadlineIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SWAP_EXACT_IN_WITH_DEADLINE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SWAP_EXACT_IN_WITH_DEADLINE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SwapExactInWithDeadlineIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SWAP_EXACT_IN_WITH_DEADLINE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn swap_exact_in_with_deadline_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SwapExactInWithDeadlineKeys,
+    args: SwapExactInWithDeadlineIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SWAP_EXACT_IN_WITH_DEADLINE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SwapExactInWithDeadlineIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn swap_exact_in_with_deadline_ix(
+    keys: SwapExactInWithDeadlineKeys,
+    args: SwapExactInWithDeadlineIxArgs,
+) -> std::io::Result<Instruction> {
+    swap_exact_in_with_deadline_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn swap_exact_in_with_deadline_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SwapExactInWithDeadlineAccounts<'_, '_>,
+    args: SwapExactInWithDeadlineIxArgs,
+) -> ProgramResult {
+    let keys: SwapExactInWithDeadlineKeys = accounts.into();
+    let ix = swap_exact_in_with_deadline_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn swap_exact_in_with_deadline_invoke(
+    accounts: SwapExactInWithDeadlineAccounts<'_, '_>,
+    args: SwapExactInWithDeadlineIxArgs,
+) -> ProgramResult {
+    swap_exact_in_with_deadline_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn swap_exact_in_with_deadline_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SwapExactInWithDeadlineAccounts<'_, '_>,
+    args: SwapExactInWithDeadlineIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SwapExactInWithDeadlineKeys = accounts.into();
+    let ix = swap_exact_in_with_deadline_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn swap_exact_in_with_deadline_invoke_signed(
+    accounts: SwapExactInWithDeadlineAccounts<'_, '_>,
+    args: SwapExactInWithDeadlineIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    swap_exact_in_with_deadline_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn swap_exact_in_with_deadline_verify_account_keys(
+    accounts: SwapExactInWithDeadlineAccounts<'_, '_>,
+    keys: SwapExactInWithDeadlineKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.src_lst_mint.key, &keys.src_lst_mint),
+        (accounts.dst_lst_mint.key, &keys.dst_lst_mint),
+        (accounts.src_lst_acc.key, &keys.src_lst_acc),
+        (accounts.dst_lst_acc.key, &keys.dst_lst_acc),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (
+            accounts.src_lst_token_program.key,
+            &keys.src_lst_token_program,
+        ),
+        (
+            accounts.dst_lst_token_program.key,
+            &keys.dst_lst_token_program,
+        ),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.src_pool_reserves.key, &keys.src_pool_reserves),
+        (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn swap_exact_in_with_deadline_verify_writable_privileges<'me, 'info>(
+    accounts: SwapExactInWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lst_acc,
+        accounts.dst_lst_acc,
+        accounts.protocol_fee_accumulator,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.src_pool_reserves,
+        accounts.dst_pool_reserves,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn swap_exact_in_with_deadline_verify_signer_privileges<'me, 'info>(
+    accounts: SwapExactInWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn swap_exact_in_with_deadline_verify_account_privileges<'me, 'info>(
+    accounts: SwapExactInWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    swap_exact_in_with_deadline_verify_writable_privileges(accounts)?;
+    swap_exact_in_with_deadline_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SWAP_EXACT_OUT_WITH_DEADLINE_IX_ACCOUNTS_LEN: usize = 12;
+#[derive(Copy, Clone, Debug)]
+pub struct SwapExactOutWithDeadlineAccounts<'me, 'info> {
+    ///Authority of src_lst_acc. User making the swap.
+    pub signer: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: &'me AccountInfo<'info>,
+    ///LST token account being swapped from
+    pub src_lst_acc: &'me AccountInfo<'info>,
+    ///LST token account to swapped to
+    pub dst_lst_acc: &'me AccountInfo<'info>,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///
+    pub src_lst_token_program: &'me AccountInfo<'info>,
+    ///
+    pub dst_lst_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: &'me AccountInfo<'info>,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SwapExactOutWithDeadlineKeys {
+    ///Authority of src_lst_acc. User making the swap.
+    pub signer: Pubkey,
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: Pubkey,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: Pubkey,
+    ///LST token account being swapped from
+    pub src_lst_acc: Pubkey,
+    ///LST token account to swapped to
+    pub dst_lst_acc: Pubkey,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: Pubkey,
+    ///
+    pub src_lst_token_program: Pubkey,
+    ///
+    pub dst_lst_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: Pubkey,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: Pubkey,
+}
+impl From<SwapExactOutWithDeadlineAccounts<'_, '_>> for SwapExactOutWithDeadlineKeys {
+    fn from(accounts: SwapExactOutWithDeadlineAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            src_lst_mint: *accounts.src_lst_mint.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            src_lst_acc: *accounts.src_lst_acc.key,
+            dst_lst_acc: *accounts.dst_lst_acc.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            src_lst_token_program: *accounts.src_lst_token_program.key,
+            dst_lst_token_program: *accounts.dst_lst_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            src_pool_reserves: *accounts.src_pool_reserves.key,
+            dst_pool_reserves: *accounts.dst_pool_reserves.key,
+        }
+    }
+}
+impl From<SwapExactOutWithDeadlineKeys>
+    for [AccountMeta; SWAP_EXACT_OUT_WITH_DEADLINE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: SwapExactOutWithDeadlineKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SWAP_EXACT_OUT_WITH_DEADLINE_IX_ACCOUNTS_LEN]> for SwapExactOutWithDeadlineKeys {
+    fn from(pubkeys: [Pubkey; SWAP_EXACT_OUT_WITH_DEADLINE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            src_lst_mint: pubkeys[1],
+            dst_lst_mint: pubkeys[2],
+            src_lst_acc: pubkeys[3],
+            dst_lst_acc: pubkeys[4],
+            protocol_fee_accumulator: pubkeys[5],
+            src_lst_token_program: pubkeys[6],
+            dst_lst_token_program: pubkeys[7],
+            pool_state: pubkeys[8],
+            lst_state_list: pubkeys[9],
+            src_pool_reserves: pubkeys[10],
+            dst_pool_reserves: pubkeys[11],
+        }
+    }
+}
+impl<'info> From<SwapExactOutWithDeadlineAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SWAP_EXACT_OUT_WITH_DEADLINE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SwapExactOutWithDeadlineAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.src_lst_mint.clone(),
+            accounts.dst_lst_mint.clone(),
+            accounts.src_lst_acc.clone(),
+            accounts.dst_lst_acc.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.src_lst_token_program.clone(),
+            accounts.dst_lst_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.src_pool_reserves.clone(),
+            accounts.dst_pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_EXACT_OUT_WITH_DEADLINE_IX_ACCOUNTS_LEN]>
+    for SwapExactOutWithDeadlineAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SWAP_EXACT_OUT_WITH_DEADLINE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            src_lst_mint: &arr[1],
+            dst_lst_mint: &arr[2],
+            src_lst_acc: &arr[3],
+            dst_lst_acc: &arr[4],
+            protocol_fee_accumulator: &arr[5],
+            src_lst_token_program: &arr[6],
+            dst_lst_token_program: &arr[7],
+            pool_state: &arr[8],
+            lst_state_list: &arr[9],
+            src_pool_reserves: &arr[10],
+            dst_pool_reserves: &arr[11],
+        }
+    }
+}
+pub const SWAP_EXACT_OUT_WITH_DEADLINE_IX_DISCM: u8 = 26u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapExactOutWithDeadlineIxArgs {
+    pub src_lst_value_calc_accs: u8,
+    pub dst_lst_value_calc_accs: u8,
+    pub src_lst_index: u32,
+    pub dst_lst_index: u32,
+    pub max_amount_in: u64,
+    pub amount: u64,
+    pub deadline: Deadline,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapExactOutWithDeadlineIxData(pub SwapExactOutWithDeadlineIxArgs);
+impl From<SwapExactOutWithDeadlineIxArgs> for SwapExactOutWithDeadlineIxData {
+    fn from(args: SwapExactOutWithDeadlineIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SwapExactOutWithDeadlineIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SWAP_EXACT_OUT_WITH_DEADLINE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SWAP_EXACT_OUT_WITH_DEADLINE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SwapExactOutWithDeadlineIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SWAP_EXACT_OUT_WITH_DEADLINE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn swap_exact_out_with_deadline_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SwapExactOutWithDeadlineKeys,
+    args: SwapExactOutWithDeadlineIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SWAP_EXACT_OUT_WITH_DEADLINE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SwapExactOutWithDeadlineIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn swap_exact_out_with_deadline_ix(
+    keys: SwapExactOutWithDeadlineKeys,
+    args: SwapExactOutWithDeadlineIxArgs,
+) -> std::io::Result<Instruction> {
+    swap_exact_out_with_deadline_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn swap_exact_out_with_deadline_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SwapExactOutWithDeadlineAccounts<'_, '_>,
+    args: SwapExactOutWithDeadlineIxArgs,
+) -> ProgramResult {
+    let keys: SwapExactOutWithDeadlineKeys = accounts.into();
+    let ix = swap_exact_out_with_deadline_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn swap_exact_out_with_deadline_invoke(
+    accounts: SwapExactOutWithDeadlineAccounts<'_, '_>,
+    args: SwapExactOutWithDeadlineIxArgs,
+) -> ProgramResult {
+    swap_exact_out_with_deadline_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn swap_exact_out_with_deadline_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SwapExactOutWithDeadlineAccounts<'_, '_>,
+    args: SwapExactOutWithDeadlineIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SwapExactOutWithDeadlineKeys = accounts.into();
+    let ix = swap_exact_out_with_deadline_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn swap_exact_out_with_deadline_invoke_signed(
+    accounts: SwapExactOutWithDeadlineAccounts<'_, '_>,
+    args: SwapExactOutWithDeadlineIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    swap_exact_out_with_deadline_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn swap_exact_out_with_deadline_verify_account_keys(
+    accounts: SwapExactOutWithDeadlineAccounts<'_, '_>,
+    keys: SwapExactOutWithDeadlineKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.src_lst_mint.key, &keys.src_lst_mint),
+        (accounts.dst_lst_mint.key, &keys.dst_lst_mint),
+        (accounts.src_lst_acc.key, &keys.src_lst_acc),
+        (accounts.dst_lst_acc.key, &keys.dst_lst_acc),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (
+            accounts.src_lst_token_program.key,
+            &keys.src_lst_token_program,
+        ),
+        (
+            accounts.dst_lst_token_program.key,
+            &keys.dst_lst_token_program,
+        ),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.src_pool_reserves.key, &keys.src_pool_reserves),
+        (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn swap_exact_out_with_deadline_verify_writable_privileges<'me, 'info>(
+    accounts: SwapExactOutWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lst_acc,
+        accounts.dst_lst_acc,
+        accounts.protocol_fee_accumulator,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.src_pool_reserves,
+        accounts.dst_pool_reserves,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn swap_exact_out_with_deadline_verify_signer_privileges<'me, 'info>(
+    accounts: SwapExactOutWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn swap_exact_out_with_deadline_verify_account_privileges<'me, 'info>(
+    accounts: SwapExactOutWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    swap_exact_out_with_deadline_verify_writable_privileges(accounts)?;
+    swap_exact_out_with_deadline_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN: usize = 11;
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityWithDeadlineAccounts<'me, 'info> {
+    ///Authority of src_lst_acc. User who's adding liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///Mint of the LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LST token account to add liquidity from
+    pub src_lst_acc: &'me AccountInfo<'info>,
+    ///LP token account to mint new LP tokens to
+    pub dst_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///LST's token program
+    pub lst_token_program: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityWithDeadlineKeys {
+    ///Authority of src_lst_acc. User who's adding liquidity.
+    pub signer: Pubkey,
+    ///Mint of the LST
+    pub lst_mint: Pubkey,
+    ///LST token account to add liquidity from
+    pub src_lst_acc: Pubkey,
+    ///LP token account to mint new LP tokens to
+    pub dst_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: Pubkey,
+    ///LST's token program
+    pub lst_token_program: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+}
+impl From<AddLiquidityWithDeadlineAccounts<'_, '_>> for AddLiquidityWithDeadlineKeys {
+    fn from(accounts: AddLiquidityWithDeadlineAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            lst_mint: *accounts.lst_mint.key,
+            src_lst_acc: *accounts.src_lst_acc.key,
+            dst_lp_acc: *accounts.dst_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            lst_token_program: *accounts.lst_token_program.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+        }
+    }
+}
+impl From<AddLiquidityWithDeadlineKeys>
+    for [AccountMeta; ADD_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: AddLiquidityWithDeadlineKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]> for AddLiquidityWithDeadlineKeys {
+    fn from(pubkeys: [Pubkey; ADD_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            lst_mint: pubkeys[1],
+            src_lst_acc: pubkeys[2],
+            dst_lp_acc: pubkeys[3],
+            lp_token_mint: pubkeys[4],
+            protocol_fee_accumulator: pubkeys[5],
+            lst_token_program: pubkeys[6],
+            lp_token_program: pubkeys[7],
+            pool_state: pubkeys[8],
+            lst_state_list: pubkeys[9],
+            pool_reserves: pubkeys[10],
+        }
+    }
+}
+impl<'info> From<AddLiquidityWithDeadlineAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ADD_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AddLiquidityWithDeadlineAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.lst_mint.clone(),
+            accounts.src_lst_acc.clone(),
+            accounts.dst_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.lst_token_program.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]>
+    for AddLiquidityWithDeadlineAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            lst_mint: &arr[1],
+            src_lst_acc: &arr[2],
+            dst_lp_acc: &arr[3],
+            lp_token_mint: &arr[4],
+            protocol_fee_accumulator: &arr[5],
+            lst_token_program: &arr[6],
+            lp_token_program: &arr[7],
+            pool_state: &arr[8],
+            lst_state_list: &arr[9],
+            pool_reserves: &arr[10],
+        }
+    }
+}
+pub const ADD_LIQUIDITY_WITH_DEADLINE_IX_DISCM: u8 = 27u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityWithDeadlineIxArgs {
+    pub lst_value_calc_accs: u8,
+    pub lst_index: u32,
+    pub lst_amount: u64,
+    pub min_lp_out: u64,
+    pub deadline: Deadline,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddLiquidityWithDeadlineIxData(pub AddLiquidityWithDeadlineIxArgs);
+impl From<AddLiquidityWithDeadlineIxArgs> for AddLiquidityWithDeadlineIxData {
+    fn from(args: AddLiquidityWithDeadlineIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddLiquidityWithDeadlineIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_LIQUIDITY_WITH_DEADLINE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_LIQUIDITY_WITH_DEADLINE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddLiquidityWithDeadlineIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_LIQUIDITY_WITH_DEADLINE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_liquidity_with_deadline_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddLiquidityWithDeadlineKeys,
+    args: AddLiquidityWithDeadlineIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddLiquidityWithDeadlineIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_liquidity_with_deadline_ix(
+    keys: AddLiquidityWithDeadlineKeys,
+    args: AddLiquidityWithDeadlineIxArgs,
+) -> std::io::Result<Instruction> {
+    add_liquidity_with_deadline_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_liquidity_with_deadline_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityWithDeadlineAccounts<'_, '_>,
+    args: AddLiquidityWithDeadlineIxArgs,
+) -> ProgramResult {
+    let keys: AddLiquidityWithDeadlineKeys = accounts.into();
+    let ix = add_liquidity_with_deadline_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_liquidity_with_deadline_invoke(
+    accounts: AddLiquidityWithDeadlineAccounts<'_, '_>,
+    args: AddLiquidityWithDeadlineIxArgs,
+) -> ProgramResult {
+    add_liquidity_with_deadline_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_liquidity_with_deadline_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityWithDeadlineAccounts<'_, '_>,
+    args: AddLiquidityWithDeadlineIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddLiquidityWithDeadlineKeys = accounts.into();
+    let ix = add_liquidity_with_deadline_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_liquidity_with_deadline_invoke_signed(
+    accounts: AddLiquidityWithDeadlineAccounts<'_, '_>,
+    args: AddLiquidityWithDeadlineIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_liquidity_with_deadline_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_liquidity_with_deadline_verify_account_keys(
+    accounts: AddLiquidityWithDeadlineAccounts<'_, '_>,
+    keys: AddLiquidityWithDeadlineKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.src_lst_acc.key, &keys.src_lst_acc),
+        (accounts.dst_lp_acc.key, &keys.dst_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (accounts.lst_token_program.key, &keys.lst_token_program),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_with_deadline_verify_writable_privileges<'me, 'info>(
+    accounts: AddLiquidityWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lst_acc,
+        accounts.dst_lp_acc,
+        accounts.lp_token_mint,
+        accounts.protocol_fee_accumulator,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.pool_reserves,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_with_deadline_verify_signer_privileges<'me, 'info>(
+    accounts: AddLiquidityWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_with_deadline_verify_account_privileges<'me, 'info>(
+    accounts: AddLiquidityWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_liquidity_with_deadline_verify_writable_privileges(accounts)?;
+    add_liquidity_with_deadline_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN: usize = 11;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityWithDeadlineAccounts<'me, 'info> {
+    ///Authority of lp_acc. User who's removing liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///Mint of the LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LST token account to redeem to
+    pub dst_lst_acc: &'me AccountInfo<'info>,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///LST's token program
+    pub lst_token_program: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityWithDeadlineKeys {
+    ///Authority of lp_acc. User who's removing liquidity.
+    pub signer: Pubkey,
+    ///Mint of the LST
+    pub lst_mint: Pubkey,
+    ///LST token account to redeem to
+    pub dst_lst_acc: Pubkey,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: Pubkey,
+    ///LST's token program
+    pub lst_token_program: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+}
+impl From<RemoveLiquidityWithDeadlineAccounts<'_, '_>> for RemoveLiquidityWithDeadlineKeys {
+    fn from(accounts: RemoveLiquidityWithDeadlineAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            lst_mint: *accounts.lst_mint.key,
+            dst_lst_acc: *accounts.dst_lst_acc.key,
+            src_lp_acc: *accounts.src_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            lst_token_program: *accounts.lst_token_program.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+        }
+    }
+}
+impl From<RemoveLiquidityWithDeadlineKeys>
+    for [AccountMeta; REMOVE_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: RemoveLiquidityWithDeadlineKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]>
+    for RemoveLiquidityWithDeadlineKeys
+{
+    fn from(pubkeys: [Pubkey; REMOVE_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            lst_mint: pubkeys[1],
+            dst_lst_acc: pubkeys[2],
+            src_lp_acc: pubkeys[3],
+            lp_token_mint: pubkeys[4],
+            protocol_fee_accumulator: pubkeys[5],
+            lst_token_program: pubkeys[6],
+            lp_token_program: pubkeys[7],
+            pool_state: pubkeys[8],
+            lst_state_list: pubkeys[9],
+            pool_reserves: pubkeys[10],
+        }
+    }
+}
+impl<'info> From<RemoveLiquidityWithDeadlineAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemoveLiquidityWithDeadlineAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.lst_mint.clone(),
+            accounts.dst_lst_acc.clone(),
+            accounts.src_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.lst_token_program.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN]>
+    for RemoveLiquidityWithDeadlineAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; REMOVE_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            signer: &arr[0],
+            lst_mint: &arr[1],
+            dst_lst_acc: &arr[2],
+            src_lp_acc: &arr[3],
+            lp_token_mint: &arr[4],
+            protocol_fee_accumulator: &arr[5],
+            lst_token_program: &arr[6],
+            lp_token_program: &arr[7],
+            pool_state: &arr[8],
+            lst_state_list: &arr[9],
+            pool_reserves: &arr[10],
+        }
+    }
+}
+pub const REMOVE_LIQUIDITY_WITH_DEADLINE_IX_DISCM: u8 = 28u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityWithDeadlineIxArgs {
+    pub lst_value_calc_accs: u8,
+    pub lst_index: u32,
+    pub lp_token_amount: u64,
+    pub min_lst_out: u64,
+    pub deadline: Deadline,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveLiquidityWithDeadlineIxData(pub RemoveLiquidityWithDeadlineIxArgs);
+impl From<RemoveLiquidityWithDeadlineIxArgs> for RemoveLiquidityWithDeadlineIxData {
+    fn from(args: RemoveLiquidityWithDeadlineIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveLiquidityWithDeadlineIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_LIQUIDITY_WITH_DEADLINE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_LIQUIDITY_WITH_DEADLINE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(RemoveLiquidityWithDeadlineIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_LIQUIDITY_WITH_DEADLINE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_liquidity_with_deadline_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveLiquidityWithDeadlineKeys,
+    args: RemoveLiquidityWithDeadlineIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_WITH_DEADLINE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveLiquidityWithDeadlineIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_with_deadline_ix(
+    keys: RemoveLiquidityWithDeadlineKeys,
+    args: RemoveLiquidityWithDeadlineIxArgs,
+) -> std::io::Result<Instruction> {
+    remove_liquidity_with_deadline_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn remove_liquidity_with_deadline_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityWithDeadlineAccounts<'_, '_>,
+    args: RemoveLiquidityWithDeadlineIxArgs,
+) -> ProgramResult {
+    let keys: RemoveLiquidityWithDeadlineKeys = accounts.into();
+    let ix = remove_liquidity_with_deadline_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_liquidity_with_deadline_invoke(
+    accounts: RemoveLiquidityWithDeadlineAccounts<'_, '_>,
+    args: RemoveLiquidityWithDeadlineIxArgs,
+) -> ProgramResult {
+    remove_liquidity_with_deadline_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_liquidity_with_deadline_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityWithDeadlineAccounts<'_, '_>,
+    args: RemoveLiquidityWithDeadlineIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveLiquidityWithDeadlineKeys = accounts.into();
+    let ix = remove_liquidity_with_deadline_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_liquidity_with_deadline_invoke_signed(
+    accounts: RemoveLiquidityWithDeadlineAccounts<'_, '_>,
+    args: RemoveLiquidityWithDeadlineIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_liquidity_with_deadline_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn remove_liquidity_with_deadline_verify_account_keys(
+    accounts: RemoveLiquidityWithDeadlineAccounts<'_, '_>,
+    keys: RemoveLiquidityWithDeadlineKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.dst_lst_acc.key, &keys.dst_lst_acc),
+        (accounts.src_lp_acc.key, &keys.src_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (accounts.lst_token_program.key, &keys.lst_token_program),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_with_deadline_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveLiquidityWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.dst_lst_acc,
+        accounts.src_lp_acc,
+        accounts.lp_token_mint,
+        accounts.protocol_fee_accumulator,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.pool_reserves,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_with_deadline_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveLiquidityWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_with_deadline_verify_account_privileges<'me, 'info>(
+    accounts: RemoveLiquidityWithDeadlineAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_liquidity_with_deadline_verify_writable_privileges(accounts)?;
+    remove_liquidity_with_deadline_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityMultiAccounts<'me, 'info> {
+    ///Authority of every src_lst_acc. User who's adding liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///LP token account to mint new LP tokens to
+    pub dst_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityMultiKeys {
+    ///Authority of every src_lst_acc. User who's adding liquidity.
+    pub signer: Pubkey,
+    ///LP token account to mint new LP tokens to
+    pub dst_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<AddLiquidityMultiAccounts<'_, '_>> for AddLiquidityMultiKeys {
+    fn from(accounts: AddLiquidityMultiAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            dst_lp_acc: *accounts.dst_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<AddLiquidityMultiKeys> for [AccountMeta; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddLiquidityMultiKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]> for AddLiquidityMultiKeys {
+    fn from(pubkeys: [Pubkey; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            dst_lp_acc: pubkeys[1],
+            lp_token_mint: pubkeys[2],
+            lp_token_program: pubkeys[3],
+            pool_state: pubkeys[4],
+            lst_state_list: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<AddLiquidityMultiAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AddLiquidityMultiAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.dst_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]>
+    for AddLiquidityMultiAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            dst_lp_acc: &arr[1],
+            lp_token_mint: &arr[2],
+            lp_token_program: &arr[3],
+            pool_state: &arr[4],
+            lst_state_list: &arr[5],
+        }
+    }
+}
+pub const ADD_LIQUIDITY_MULTI_IX_DISCM: u8 = 29u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityMultiIxArgs {
+    pub lsts: Vec<AddLiquidityMultiLst>,
+    pub min_lp_out: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddLiquidityMultiIxData(pub AddLiquidityMultiIxArgs);
+impl From<AddLiquidityMultiIxArgs> for AddLiquidityMultiIxData {
+    fn from(args: AddLiquidityMultiIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddLiquidityMultiIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_LIQUIDITY_MULTI_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_LIQUIDITY_MULTI_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddLiquidityMultiIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_LIQUIDITY_MULTI_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_liquidity_multi_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddLiquidityMultiKeys,
+    args: AddLiquidityMultiIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddLiquidityMultiIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_liquidity_multi_ix(
+    keys: AddLiquidityMultiKeys,
+    args: AddLiquidityMultiIxArgs,
+) -> std::io::Result<Instruction> {
+    add_liquidity_multi_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_liquidity_multi_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+) -> ProgramResult {
+    let keys: AddLiquidityMultiKeys = accounts.into();
+    let ix = add_liquidity_multi_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_liquidity_multi_invoke(
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+) -> ProgramResult {
+    add_liquidity_multi_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_liquidity_multi_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddLiquidityMultiKeys = accounts.into();
+    let ix = add_liquidity_multi_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_liquidity_multi_invoke_signed(
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_liquidity_multi_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_liquidity_multi_verify_account_keys(
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    keys: AddLiquidityMultiKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.dst_lp_acc.key, &keys.dst_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_multi_verify_writable_privileges<'me, 'info>(
+    accounts: AddLiquidityMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.dst_lp_acc,
+        accounts.lp_token_mint,
+        accounts.pool_state,
+        accounts.lst_state_list,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_multi_verify_signer_privileges<'me, 'info>(
+    accounts: AddLiquidityMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_multi_verify_account_privileges<'me, 'info>(
+    accounts: AddLiquidityMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_liquidity_multi_verify_writable_privileges(accounts)?;
+    add_liquidity_multi_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityBasketAccounts<'me, 'info> {
+    ///Authority of src_lp_acc. User who's removing liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityBasketKeys {
+    ///Authority of src_lp_acc. User who's removing liquidity.
+    pub signer: Pubkey,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<RemoveLiquidityBasketAccounts<'_, '_>> for RemoveLiquidityBasketKeys {
+    fn from(accounts: RemoveLiquidityBasketAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            src_lp_acc: *accounts.src_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<RemoveLiquidityBasketKeys> for [AccountMeta; REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN] {
+    fn from(keys: RemoveLiquidityBasketKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN]> for RemoveLiquidityBasketKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            src_lp_acc: pubkeys[1],
+            lp_token_mint: pubkeys[2],
+            lp_token_program: pubkeys[3],
+            pool_state: pubkeys[4],
+            lst_state_list: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<RemoveLiquidityBasketAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemoveLiquidityBasketAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.src_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN]>
+    for RemoveLiquidityBasketAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            src_lp_acc: &arr[1],
+            lp_token_mint: &arr[2],
+            lp_token_program: &arr[3],
+            pool_state: &arr[4],
+            lst_state_list: &arr[5],
+        }
+    }
+}
+pub const REMOVE_LIQUIDITY_BASKET_IX_DISCM: u8 = 30u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityBasketIxArgs {
+    pub lp_token_amount: u64,
+    pub lsts: Vec<RemoveLiquidityBasketLst>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveLiquidityBasketIxData(pub RemoveLiquidityBasketIxArgs);
+impl From<RemoveLiquidityBasketIxArgs> for RemoveLiquidityBasketIxData {
+    fn from(args: RemoveLiquidityBasketIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveLiquidityBasketIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_LIQUIDITY_BASKET_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_LIQUIDITY_BASKET_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(RemoveLiquidityBasketIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_LIQUIDITY_BASKET_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_liquidity_basket_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveLiquidityBasketKeys,
+    args: RemoveLiquidityBasketIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveLiquidityBasketIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_basket_ix(
+    keys: RemoveLiquidityBasketKeys,
+    args: RemoveLiquidityBasketIxArgs,
+) -> std::io::Result<Instruction> {
+    remove_liquidity_basket_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn remove_liquidity_basket_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityBasketAccounts<'_, '_>,
+    args: RemoveLiquidityBasketIxArgs,
+) -> ProgramResult {
+    let keys: RemoveLiquidityBasketKeys = accounts.into();
+    let ix = remove_liquidity_basket_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_liquidity_basket_invoke(
+    accounts: RemoveLiquidityBasketAccounts<'_, '_>,
+    args: RemoveLiquidityBasketIxArgs,
+) -> ProgramResult {
+    remove_liquidity_basket_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_liquidity_basket_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityBasketAccounts<'_, '_>,
+    args: RemoveLiquidityBasketIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveLiquidityBasketKeys = accounts.into();
+    let ix = remove_liquidity_basket_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_liquidity_basket_invoke_signed(
+    accounts: RemoveLiquidityBasketAccounts<'_, '_>,
+    args: RemoveLiquidityBasketIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_liquidity_basket_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn remove_liquidity_basket_verify_account_keys(
+    accounts: RemoveLiquidityBasketAccounts<'_, '_>,
+    keys: RemoveLiquidityBasketKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.src_lp_acc.key, &keys.src_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_basket_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveLiquidityBasketAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lp_acc,
+        accounts.lp_token_mint,
+        accounts.pool_state,
+        accounts.lst_state_list,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_basket_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveLiquidityBasketAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_basket_verify_account_privileges<'me, 'info>(
+    accounts: RemoveLiquidityBasketAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_liquidity_basket_verify_writable_privileges(accounts)?;
+    remove_liquidity_basket_verify_signer_privileges(accounts)?;
+    Ok(())
+}
@@ -38,3 +38,23 @@ pub struct RebalanceRecord {
     pub padding: [u8; 4],
     pub dst_lst_index: u32,
 }
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Deadline {
+    pub expiry_slot: Option<u64>,
+    pub expiry_unix_timestamp: Option<i64>,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityMultiLst {
+    pub lst_value_calc_accs: u8,
+    pub pricing_accs: u8,
+    pub lst_index: u32,
+    pub lst_amount: u64,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityBasketLst {
+    pub lst_value_calc_accs: u8,
+    pub min_lst_out: u64,
+}
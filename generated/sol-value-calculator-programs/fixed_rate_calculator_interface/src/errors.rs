@@ -0,0 +1,41 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum FixedRateCalculatorError {
+    #[error("Invalid program state data")]
+    InvalidProgramStateData = 0,
+    #[error("Incorrect program state account")]
+    IncorrectProgramState = 1,
+    #[error("LstRate is not initialized for the given LST mint")]
+    UnsupportedLstMint = 2,
+    #[error("Rate denominator must be nonzero")]
+    ZeroDenominator = 3,
+    #[error("Math error")]
+    MathError = 4,
+}
+impl From<FixedRateCalculatorError> for ProgramError {
+    fn from(e: FixedRateCalculatorError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for FixedRateCalculatorError {
+    fn type_of() -> &'static str {
+        "FixedRateCalculatorError"
+    }
+}
+impl PrintProgramError for FixedRateCalculatorError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
@@ -0,0 +1,7 @@
+solana_program::declare_id!("89e6Hs9FYsfyQn8pxu5J1qNSwjRfv3cVzMAoEcvbQkKB");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
@@ -22,6 +22,8 @@ pub enum GenericPoolCalculatorError {
     InvalidStakePoolProgramData = 1006,
     #[error("Math error")]
     MathError = 1007,
+    #[error("CalculatorState has the legacy layout, run MigrateState first")]
+    StateNotMigrated = 1008,
 }
 impl From<GenericPoolCalculatorError> for ProgramError {
     fn from(e: GenericPoolCalculatorError) -> Self {
@@ -12,6 +12,8 @@ use std::io::Read;
 pub enum GenericPoolCalculatorProgramIx {
     LstToSol(LstToSolIxArgs),
     SolToLst(SolToLstIxArgs),
+    MigrateState,
+    AcceptManager,
     UpdateLastUpgradeSlot,
     SetManager,
     Init,
@@ -25,6 +27,8 @@ impl GenericPoolCalculatorProgramIx {
         match maybe_discm {
             LST_TO_SOL_IX_DISCM => Ok(Self::LstToSol(LstToSolIxArgs::deserialize(&mut reader)?)),
             SOL_TO_LST_IX_DISCM => Ok(Self::SolToLst(SolToLstIxArgs::deserialize(&mut reader)?)),
+            MIGRATE_STATE_IX_DISCM => Ok(Self::MigrateState),
+            ACCEPT_MANAGER_IX_DISCM => Ok(Self::AcceptManager),
             UPDATE_LAST_UPGRADE_SLOT_IX_DISCM => Ok(Self::UpdateLastUpgradeSlot),
             SET_MANAGER_IX_DISCM => Ok(Self::SetManager),
             INIT_IX_DISCM => Ok(Self::Init),
@@ -44,6 +48,8 @@ impl GenericPoolCalculatorProgramIx {
                 writer.write_all(&[SOL_TO_LST_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
+            Self::MigrateState => writer.write_all(&[MIGRATE_STATE_IX_DISCM]),
+            Self::AcceptManager => writer.write_all(&[ACCEPT_MANAGER_IX_DISCM]),
             Self::UpdateLastUpgradeSlot => writer.write_all(&[UPDATE_LAST_UPGRADE_SLOT_IX_DISCM]),
             Self::SetManager => writer.write_all(&[SET_MANAGER_IX_DISCM]),
             Self::Init => writer.write_all(&[INIT_IX_DISCM]),
@@ -488,6 +494,385 @@ pub fn sol_to_lst_verify_account_keys(
     }
     Ok(())
 }
+pub const MIGRATE_STATE_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateStateAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///The account paying for the additional rent of the migrated CalculatorState
+    pub payer: &'me AccountInfo<'info>,
+    ///The CalculatorState PDA
+    pub state: &'me AccountInfo<'info>,
+    ///System Program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateStateKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///The account paying for the additional rent of the migrated CalculatorState
+    pub payer: Pubkey,
+    ///The CalculatorState PDA
+    pub state: Pubkey,
+    ///System Program
+    pub system_program: Pubkey,
+}
+impl From<MigrateStateAccounts<'_, '_>> for MigrateStateKeys {
+    fn from(accounts: MigrateStateAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            payer: *accounts.payer.key,
+            state: *accounts.state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<MigrateStateKeys> for [AccountMeta; MIGRATE_STATE_IX_ACCOUNTS_LEN] {
+    fn from(keys: MigrateStateKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MIGRATE_STATE_IX_ACCOUNTS_LEN]> for MigrateStateKeys {
+    fn from(pubkeys: [Pubkey; MIGRATE_STATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            payer: pubkeys[1],
+            state: pubkeys[2],
+            system_program: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<MigrateStateAccounts<'_, 'info>>
+    for [AccountInfo<'info>; MIGRATE_STATE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: MigrateStateAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.payer.clone(),
+            accounts.state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; MIGRATE_STATE_IX_ACCOUNTS_LEN]>
+    for MigrateStateAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; MIGRATE_STATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            payer: &arr[1],
+            state: &arr[2],
+            system_program: &arr[3],
+        }
+    }
+}
+pub const MIGRATE_STATE_IX_DISCM: u8 = 251u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrateStateIxData;
+impl MigrateStateIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != MIGRATE_STATE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    MIGRATE_STATE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[MIGRATE_STATE_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn migrate_state_ix_with_program_id(
+    program_id: Pubkey,
+    keys: MigrateStateKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; MIGRATE_STATE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: MigrateStateIxData.try_to_vec()?,
+    })
+}
+pub fn migrate_state_ix(keys: MigrateStateKeys) -> std::io::Result<Instruction> {
+    migrate_state_ix_with_program_id(crate::ID, keys)
+}
+pub fn migrate_state_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateStateAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: MigrateStateKeys = accounts.into();
+    let ix = migrate_state_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn migrate_state_invoke(accounts: MigrateStateAccounts<'_, '_>) -> ProgramResult {
+    migrate_state_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn migrate_state_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateStateAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: MigrateStateKeys = accounts.into();
+    let ix = migrate_state_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn migrate_state_invoke_signed(
+    accounts: MigrateStateAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    migrate_state_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn migrate_state_verify_account_keys(
+    accounts: MigrateStateAccounts<'_, '_>,
+    keys: MigrateStateKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.payer.key, &keys.payer),
+        (accounts.state.key, &keys.state),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_state_verify_writable_privileges<'me, 'info>(
+    accounts: MigrateStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_state_verify_signer_privileges<'me, 'info>(
+    accounts: MigrateStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_state_verify_account_privileges<'me, 'info>(
+    accounts: MigrateStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    migrate_state_verify_writable_privileges(accounts)?;
+    migrate_state_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ACCEPT_MANAGER_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptManagerAccounts<'me, 'info> {
+    ///The pending program manager to transfer management to
+    pub pending_manager: &'me AccountInfo<'info>,
+    ///The CalculatorState PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptManagerKeys {
+    ///The pending program manager to transfer management to
+    pub pending_manager: Pubkey,
+    ///The CalculatorState PDA
+    pub state: Pubkey,
+}
+impl From<AcceptManagerAccounts<'_, '_>> for AcceptManagerKeys {
+    fn from(accounts: AcceptManagerAccounts) -> Self {
+        Self {
+            pending_manager: *accounts.pending_manager.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<AcceptManagerKeys> for [AccountMeta; ACCEPT_MANAGER_IX_ACCOUNTS_LEN] {
+    fn from(keys: AcceptManagerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.pending_manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ACCEPT_MANAGER_IX_ACCOUNTS_LEN]> for AcceptManagerKeys {
+    fn from(pubkeys: [Pubkey; ACCEPT_MANAGER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pending_manager: pubkeys[0],
+            state: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<AcceptManagerAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ACCEPT_MANAGER_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AcceptManagerAccounts<'_, 'info>) -> Self {
+        [accounts.pending_manager.clone(), accounts.state.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ACCEPT_MANAGER_IX_ACCOUNTS_LEN]>
+    for AcceptManagerAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ACCEPT_MANAGER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pending_manager: &arr[0],
+            state: &arr[1],
+        }
+    }
+}
+pub const ACCEPT_MANAGER_IX_DISCM: u8 = 252u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcceptManagerIxData;
+impl AcceptManagerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ACCEPT_MANAGER_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ACCEPT_MANAGER_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ACCEPT_MANAGER_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn accept_manager_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AcceptManagerKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ACCEPT_MANAGER_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: AcceptManagerIxData.try_to_vec()?,
+    })
+}
+pub fn accept_manager_ix(keys: AcceptManagerKeys) -> std::io::Result<Instruction> {
+    accept_manager_ix_with_program_id(crate::ID, keys)
+}
+pub fn accept_manager_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptManagerAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: AcceptManagerKeys = accounts.into();
+    let ix = accept_manager_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn accept_manager_invoke(accounts: AcceptManagerAccounts<'_, '_>) -> ProgramResult {
+    accept_manager_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn accept_manager_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptManagerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AcceptManagerKeys = accounts.into();
+    let ix = accept_manager_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn accept_manager_invoke_signed(
+    accounts: AcceptManagerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    accept_manager_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn accept_manager_verify_account_keys(
+    accounts: AcceptManagerAccounts<'_, '_>,
+    keys: AcceptManagerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.pending_manager.key, &keys.pending_manager),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn accept_manager_verify_writable_privileges<'me, 'info>(
+    accounts: AcceptManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn accept_manager_verify_signer_privileges<'me, 'info>(
+    accounts: AcceptManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.pending_manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn accept_manager_verify_account_privileges<'me, 'info>(
+    accounts: AcceptManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    accept_manager_verify_writable_privileges(accounts)?;
+    accept_manager_verify_signer_privileges(accounts)?;
+    Ok(())
+}
 pub const UPDATE_LAST_UPGRADE_SLOT_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct UpdateLastUpgradeSlotAccounts<'me, 'info> {
@@ -702,7 +1087,7 @@ pub const SET_MANAGER_IX_ACCOUNTS_LEN: usize = 3;
 pub struct SetManagerAccounts<'me, 'info> {
     ///The program manager
     pub manager: &'me AccountInfo<'info>,
-    ///The new program manager to set to
+    ///The new program manager to propose
     pub new_manager: &'me AccountInfo<'info>,
     ///The CalculatorState PDA
     pub state: &'me AccountInfo<'info>,
@@ -711,7 +1096,7 @@ pub struct SetManagerAccounts<'me, 'info> {
 pub struct SetManagerKeys {
     ///The program manager
     pub manager: Pubkey,
-    ///The new program manager to set to
+    ///The new program manager to propose
     pub new_manager: Pubkey,
     ///The CalculatorState PDA
     pub state: Pubkey,
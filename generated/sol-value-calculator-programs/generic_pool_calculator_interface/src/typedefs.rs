@@ -7,4 +7,5 @@ use solana_program::pubkey::Pubkey;
 pub struct CalculatorState {
     pub manager: Pubkey,
     pub last_upgrade_slot: u64,
+    pub pending_manager: Pubkey,
 }
@@ -1,12 +1,17 @@
+use flat_fee_interface::FlatFeeError;
+use imbalance_fee_interface::ImbalanceFeeError;
 use std::{
     error::Error,
     fmt::{Display, Formatter},
 };
+use volume_surge_interface::VolumeSurgeError;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum PricingProgErr {
     UnknownPricingProg,
     WrongPricingProg,
+    ClockNotFetched,
+    EpochScheduleNotFetched,
 }
 
 impl Display for PricingProgErr {
@@ -14,8 +19,25 @@ impl Display for PricingProgErr {
         match self {
             Self::UnknownPricingProg => f.write_str("Unknown pricing program"),
             Self::WrongPricingProg => f.write_str("Wrong pricing program"),
+            Self::ClockNotFetched => f.write_str("Clock not yet fetched"),
+            Self::EpochScheduleNotFetched => f.write_str("Epoch schedule not yet fetched"),
         }
     }
 }
 
 impl Error for PricingProgErr {}
+
+/// Returns true if `e` is the arithmetic overflow error of a known pricing program,
+/// e.g. from quoting an amount too large for the program's calculations
+pub fn is_pricing_prog_math_err(e: &anyhow::Error) -> bool {
+    matches!(
+        e.downcast_ref::<FlatFeeError>(),
+        Some(FlatFeeError::MathError)
+    ) || matches!(
+        e.downcast_ref::<ImbalanceFeeError>(),
+        Some(ImbalanceFeeError::MathError)
+    ) || matches!(
+        e.downcast_ref::<VolumeSurgeError>(),
+        Some(VolumeSurgeError::MathError)
+    )
+}
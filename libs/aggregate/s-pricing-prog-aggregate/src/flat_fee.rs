@@ -1,21 +1,30 @@
 use flat_fee_interface::{
-    FeeAccount, FlatFeeError, PriceLpTokensToMintKeys, ProgramState,
-    PRICE_EXACT_IN_IX_ACCOUNTS_LEN, PRICE_EXACT_OUT_IX_ACCOUNTS_LEN,
-    PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN, PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN,
+    FeeAccount, FlatFeeError, PairFeeAccount, ProgramState, PRICE_EXACT_IN_IX_ACCOUNTS_LEN,
+    PRICE_EXACT_OUT_IX_ACCOUNTS_LEN, PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN,
+    PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN,
 };
 use flat_fee_lib::{
     account_resolvers::{
         PriceExactInFreeArgs, PriceExactInWithBumpFreeArgs, PriceExactOutFreeArgs,
-        PriceExactOutWithBumpFreeArgs, PriceLpTokensToRedeemFreeArgs,
+        PriceExactOutWithBumpFreeArgs, PriceLpTokensToMintFreeArgs,
+        PriceLpTokensToMintWithBumpFreeArgs, PriceLpTokensToRedeemFreeArgs,
     },
     calc::{
-        calculate_price_exact_in, calculate_price_exact_out, calculate_price_lp_tokens_to_redeem,
-        CalculatePriceExactInArgs, CalculatePriceExactOutArgs,
+        calculate_price_exact_in, calculate_price_exact_out, calculate_price_lp_tokens_to_mint,
+        calculate_price_lp_tokens_to_redeem, effective_lp_deposit_fee_bps,
+        epoch_surcharge_bps_at_slot, has_epoch_surcharge, has_fee_ramp,
+        lp_fee_bps_with_epoch_surcharge, lst_fee_bps_at_slot, CalculatePriceExactInArgs,
+        CalculatePriceExactOutArgs, LstFeeBps,
     },
-    pda::{FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
-    utils::{try_fee_account, try_program_state},
+    pda::{
+        FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs, PairFeeAccountCreatePdaArgs,
+        PairFeeAccountFindPdaArgs, ProgramStateFindPdaArgs,
+    },
+    utils::{try_fee_account, try_pair_fee_account, try_program_state},
+};
+use solana_program::{
+    clock::Clock, epoch_schedule::EpochSchedule, instruction::AccountMeta, pubkey::Pubkey, sysvar,
 };
-use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_readonly_account::ReadonlyAccountData;
 use std::collections::HashMap;
 
@@ -26,6 +35,17 @@ pub struct FlatFeePricingProg {
     program_id: Pubkey,
     program_state: Option<ProgramState>,
     mints_to_fee_accounts: HashMap<Pubkey, Option<FeeAccount>>, // value = None means FeeAccount not yet fetched
+    pairs_to_pair_fee_accounts: HashMap<(Pubkey, Pubkey), PairFeeAccountEntry>, // key = (input_lst_mint, output_lst_mint), only pairs found by discover_pair_fee_accounts()
+    clock: Option<Clock>, // only required to quote LSTs with fee ramps or if epoch surcharge is set
+    epoch_schedule: Option<EpochSchedule>, // only required to quote if epoch surcharge is set
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PairFeeAccountEntry {
+    address: Pubkey,
+
+    /// None means the PairFeeAccount has since been closed
+    pair_fee_account: Option<PairFeeAccount>,
 }
 
 impl FlatFeePricingProg {
@@ -41,6 +61,66 @@ impl FlatFeePricingProg {
         .0
     }
 
+    /// Supply the clock to quote fee ramps with directly
+    /// instead of fetching the clock sysvar account via [`MutablePricingProg::update`]
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = Some(clock);
+    }
+
+    /// ProgramState and the sysvars required for fee ramps and the epoch surcharge
+    fn state_and_sysvar_accounts(&self) -> [Pubkey; 3] {
+        [
+            self.find_program_state_addr(),
+            sysvar::clock::ID,
+            sysvar::epoch_schedule::ID,
+        ]
+    }
+
+    /// Supply the epoch schedule to quote epoch surcharges with directly
+    /// instead of fetching the epoch schedule sysvar account via [`MutablePricingProg::update`]
+    pub fn set_epoch_schedule(&mut self, epoch_schedule: EpochSchedule) {
+        self.epoch_schedule = Some(epoch_schedule);
+    }
+
+    fn get_program_state_checked(&self) -> Result<&ProgramState, FlatFeeError> {
+        self.program_state
+            .as_ref()
+            .ok_or(FlatFeeError::InvalidProgramStateData)
+    }
+
+    /// Returns the epoch surcharge in effect at the current clock's slot
+    fn get_epoch_surcharge_bps_checked(&self) -> anyhow::Result<u16> {
+        let program_state = self.get_program_state_checked()?;
+        if !has_epoch_surcharge(program_state) {
+            return Ok(0);
+        }
+        let clock = self.clock.as_ref().ok_or(PricingProgErr::ClockNotFetched)?;
+        let epoch_schedule = self
+            .epoch_schedule
+            .as_ref()
+            .ok_or(PricingProgErr::EpochScheduleNotFetched)?;
+        Ok(epoch_surcharge_bps_at_slot(
+            program_state,
+            epoch_schedule,
+            clock.slot,
+        ))
+    }
+
+    /// Returns the fees of `lst_mint` in effect at the current clock's slot
+    fn get_lst_fee_bps_checked(&self, lst_mint: &Pubkey) -> anyhow::Result<LstFeeBps> {
+        let fee_acc = self.get_fee_account_checked(lst_mint)?;
+        let slot = if has_fee_ramp(fee_acc) {
+            self.clock
+                .as_ref()
+                .ok_or(PricingProgErr::ClockNotFetched)?
+                .slot
+        } else {
+            // unused
+            0
+        };
+        Ok(lst_fee_bps_at_slot(fee_acc, slot))
+    }
+
     fn get_fee_account_checked(&self, lst_mint: &Pubkey) -> Result<&FeeAccount, FlatFeeError> {
         match self.mints_to_fee_accounts.get(lst_mint) {
             Some(Some(a)) => Ok(a),
@@ -70,6 +150,87 @@ impl FlatFeePricingProg {
         }
     }
 
+    fn get_pair_fee_bps(&self, input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> Option<i16> {
+        self.pairs_to_pair_fee_accounts
+            .get(&(input_lst_mint, output_lst_mint))
+            .and_then(|entry| entry.pair_fee_account)
+            .map(|PairFeeAccount { fee_bps, .. }| fee_bps)
+    }
+
+    fn get_cached_pair_fee_account_bump(
+        &self,
+        input_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
+    ) -> Option<u8> {
+        self.pairs_to_pair_fee_accounts
+            .get(&(input_lst_mint, output_lst_mint))
+            .and_then(|entry| entry.pair_fee_account)
+            .map(|PairFeeAccount { bump, .. }| bump)
+    }
+
+    /// Returns the addresses of the discovered PairFeeAccounts between `lst_mints`
+    fn pair_fee_accounts_for_mints(&self, lst_mints: &[Pubkey]) -> Vec<Pubkey> {
+        self.pairs_to_pair_fee_accounts
+            .iter()
+            .filter(|((input_lst_mint, output_lst_mint), _)| {
+                lst_mints.contains(input_lst_mint) && lst_mints.contains(output_lst_mint)
+            })
+            .map(|(_, PairFeeAccountEntry { address, .. })| *address)
+            .collect()
+    }
+
+    /// Replaces the tracked PairFeeAccounts with the ones in `program_accounts`,
+    /// e.g. the result of `getProgramAccounts` on the flat fee program
+    /// with a `dataSize = PAIR_FEE_ACCOUNT_SIZE` filter.
+    ///
+    /// Accounts that are not PairFeeAccounts between tracked LSTs are ignored.
+    /// Only discovered PairFeeAccounts are fetched by [`MutablePricingProg::update`],
+    /// so this must be called again to pick up PairFeeAccounts created afterwards.
+    /// Until then, quotes and instruction accounts for those pairs omit the optional PairFeeAccount,
+    /// which matches the fee charged on-chain when it is not passed.
+    pub fn discover_pair_fee_accounts<
+        D: ReadonlyAccountData,
+        I: IntoIterator<Item = (Pubkey, D)>,
+    >(
+        &mut self,
+        program_accounts: I,
+    ) {
+        self.pairs_to_pair_fee_accounts = program_accounts
+            .into_iter()
+            .filter_map(|(address, acc)| {
+                let pair_fee_account = *try_pair_fee_account(&acc.data()).ok()?;
+                let PairFeeAccount {
+                    bump,
+                    input_lst_mint,
+                    output_lst_mint,
+                    ..
+                } = pair_fee_account;
+                if !self.mints_to_fee_accounts.contains_key(&input_lst_mint)
+                    || !self.mints_to_fee_accounts.contains_key(&output_lst_mint)
+                {
+                    return None;
+                }
+                let expected_address = PairFeeAccountCreatePdaArgs {
+                    find_pda_args: PairFeeAccountFindPdaArgs {
+                        program_id: self.program_id,
+                        input_lst_mint,
+                        output_lst_mint,
+                    },
+                    bump,
+                }
+                .get_pair_fee_account_address()
+                .ok()?;
+                (address == expected_address).then_some((
+                    (input_lst_mint, output_lst_mint),
+                    PairFeeAccountEntry {
+                        address,
+                        pair_fee_account: Some(pair_fee_account),
+                    },
+                ))
+            })
+            .collect();
+    }
+
     fn fee_account_for_mint(
         &self,
         lst_mint: &Pubkey,
@@ -107,17 +268,41 @@ impl MutablePricingProg for FlatFeePricingProg {
             program_id,
             program_state: None,
             mints_to_fee_accounts: mints.map(|pk| (pk, None)).collect(),
+            pairs_to_pair_fee_accounts: HashMap::new(),
+            clock: None,
+            epoch_schedule: None,
         })
     }
 
     fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
-        vec![self.find_program_state_addr()]
+        // FeeAccounts are required for per-LST lp_deposit_fee_bps overrides
+        self.state_and_sysvar_accounts()
+            .into_iter()
+            .chain(
+                self.mints_to_fee_accounts
+                    .iter()
+                    .map(|(lst_mint, fee_account_opt)| {
+                        self.fee_account_for_mint(lst_mint, fee_account_opt)
+                    }),
+            )
+            .collect()
     }
 
     fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
-        self.mints_to_fee_accounts
-            .iter()
-            .map(|(lst_mint, fee_account_opt)| self.fee_account_for_mint(lst_mint, fee_account_opt))
+        self.state_and_sysvar_accounts()
+            .into_iter()
+            .chain(
+                self.mints_to_fee_accounts
+                    .iter()
+                    .map(|(lst_mint, fee_account_opt)| {
+                        self.fee_account_for_mint(lst_mint, fee_account_opt)
+                    }),
+            )
+            .chain(
+                self.pairs_to_pair_fee_accounts
+                    .values()
+                    .map(|PairFeeAccountEntry { address, .. }| *address),
+            )
             .collect()
     }
 
@@ -125,15 +310,20 @@ impl MutablePricingProg for FlatFeePricingProg {
         &self,
         lst_mints: I,
     ) -> Vec<Pubkey> {
-        lst_mints
-            .map(|lst_mint| {
+        let lst_mints: Vec<Pubkey> = lst_mints.collect();
+        let mut res: Vec<Pubkey> = self
+            .state_and_sysvar_accounts()
+            .into_iter()
+            .chain(lst_mints.iter().map(|lst_mint| {
                 let fee_account_opt = self
                     .mints_to_fee_accounts
-                    .get(&lst_mint)
+                    .get(lst_mint)
                     .map_or_else(|| &None, |opt| opt);
-                self.fee_account_for_mint(&lst_mint, fee_account_opt)
-            })
-            .collect()
+                self.fee_account_for_mint(lst_mint, fee_account_opt)
+            }))
+            .collect();
+        res.extend(self.pair_fee_accounts_for_mints(&lst_mints));
+        res
     }
 
     fn update<D: ReadonlyAccountData>(
@@ -142,7 +332,15 @@ impl MutablePricingProg for FlatFeePricingProg {
     ) -> anyhow::Result<()> {
         let psa = self.find_program_state_addr();
         if let Some(acc) = account_map.get(&psa) {
-            self.program_state = Some(*try_program_state(&acc.data())?);
+            self.program_state = Some(try_program_state(&acc.data())?);
+        }
+
+        if let Some(acc) = account_map.get(&sysvar::clock::ID) {
+            self.clock = Some(bincode::deserialize::<Clock>(&acc.data())?);
+        }
+
+        if let Some(acc) = account_map.get(&sysvar::epoch_schedule::ID) {
+            self.epoch_schedule = Some(bincode::deserialize::<EpochSchedule>(&acc.data())?);
         }
 
         for (lst_mint, fee_account_opt) in self.mints_to_fee_accounts.iter_mut() {
@@ -159,7 +357,19 @@ impl MutablePricingProg for FlatFeePricingProg {
                 None => find_pda_args.get_fee_account_address_and_bump_seed().0,
             };
             if let Some(acc) = account_map.get(&faa) {
-                *fee_account_opt = Some(*try_fee_account(&acc.data())?);
+                *fee_account_opt = Some(try_fee_account(&acc.data())?);
+            }
+        }
+
+        for PairFeeAccountEntry {
+            address,
+            pair_fee_account,
+        } in self.pairs_to_pair_fee_accounts.values_mut()
+        {
+            // PairFeeAccounts may be closed after discovery,
+            // so treat invalid data as the override not existing
+            if let Some(acc) = account_map.get(address) {
+                *pair_fee_account = try_pair_fee_account(&acc.data()).ok().copied();
             }
         }
 
@@ -173,10 +383,10 @@ impl PricingProg for FlatFeePricingProg {
         _output_lst_mint: Pubkey,
         pricing_programs_interface::PriceLpTokensToRedeemIxArgs { sol_value, .. }: &pricing_programs_interface::PriceLpTokensToRedeemIxArgs,
     ) -> anyhow::Result<u64> {
-        let lp_withdrawal_fee_bps = self
-            .program_state
-            .ok_or(FlatFeeError::InvalidProgramStateData)?
-            .lp_withdrawal_fee_bps;
+        let lp_withdrawal_fee_bps = lp_fee_bps_with_epoch_surcharge(
+            self.get_program_state_checked()?.lp_withdrawal_fee_bps,
+            self.get_epoch_surcharge_bps_checked()?,
+        )?;
         Ok(calculate_price_lp_tokens_to_redeem(
             lp_withdrawal_fee_bps,
             *sol_value,
@@ -197,22 +407,35 @@ impl PricingProg for FlatFeePricingProg {
 
     fn quote_lp_tokens_to_mint(
         &self,
-        _input_lst_mint: Pubkey,
+        input_lst_mint: Pubkey,
         pricing_programs_interface::PriceLpTokensToMintIxArgs { sol_value, .. }: &pricing_programs_interface::PriceLpTokensToMintIxArgs,
     ) -> anyhow::Result<u64> {
-        Ok(*sol_value)
+        let program_state = self.get_program_state_checked()?;
+        let input_fee_acc = self.get_fee_account_checked(&input_lst_mint)?;
+        let lp_deposit_fee_bps = lp_fee_bps_with_epoch_surcharge(
+            effective_lp_deposit_fee_bps(program_state, input_fee_acc),
+            self.get_epoch_surcharge_bps_checked()?,
+        )?;
+        Ok(calculate_price_lp_tokens_to_mint(
+            lp_deposit_fee_bps,
+            *sol_value,
+        )?)
     }
 
     fn price_lp_tokens_to_mint_accounts(
         &self,
         input_lst_mint: Pubkey,
     ) -> anyhow::Result<Vec<AccountMeta>> {
-        Ok(
-            <[AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>::from(
-                PriceLpTokensToMintKeys { input_lst_mint },
-            )
-            .into(),
-        )
+        let args = PriceLpTokensToMintFreeArgs { input_lst_mint };
+        let keys = match self.get_fee_account_checked(&input_lst_mint) {
+            Ok(FeeAccount { bump, .. }) => PriceLpTokensToMintWithBumpFreeArgs {
+                args,
+                input_fee_acc_bump: *bump,
+            }
+            .resolve_for_prog(self.program_id)?,
+            Err(_) => args.resolve_for_prog(self.program_id),
+        };
+        Ok(<[AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>::from(keys).into())
     }
 
     fn quote_exact_in(
@@ -223,11 +446,13 @@ impl PricingProg for FlatFeePricingProg {
         }: pricing_programs_interface::PriceExactInKeys,
         pricing_programs_interface::PriceExactInIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactInIxArgs,
     ) -> anyhow::Result<u64> {
-        let FeeAccount { input_fee_bps, .. } = self.get_fee_account_checked(&input_lst_mint)?;
-        let FeeAccount { output_fee_bps, .. } = self.get_fee_account_checked(&output_lst_mint)?;
+        let LstFeeBps { input_fee_bps, .. } = self.get_lst_fee_bps_checked(&input_lst_mint)?;
+        let LstFeeBps { output_fee_bps, .. } = self.get_lst_fee_bps_checked(&output_lst_mint)?;
         Ok(calculate_price_exact_in(CalculatePriceExactInArgs {
-            input_fee_bps: *input_fee_bps,
-            output_fee_bps: *output_fee_bps,
+            input_fee_bps,
+            output_fee_bps,
+            pair_fee_bps: self.get_pair_fee_bps(input_lst_mint, output_lst_mint),
+            epoch_surcharge_bps: self.get_epoch_surcharge_bps_checked()?,
             in_sol_value: *sol_value,
         })?)
     }
@@ -243,16 +468,21 @@ impl PricingProg for FlatFeePricingProg {
             input_lst_mint,
             output_lst_mint,
         };
-        let keys = match self.get_cached_fee_account_bumps(input_lst_mint, output_lst_mint) {
+        let metas = match self.get_cached_fee_account_bumps(input_lst_mint, output_lst_mint) {
             Some((input_fee_acc_bump, output_fee_acc_bump)) => PriceExactInWithBumpFreeArgs {
                 args,
                 input_fee_acc_bump,
                 output_fee_acc_bump,
+                pair_fee_acc_bump: self
+                    .get_cached_pair_fee_account_bump(input_lst_mint, output_lst_mint),
             }
-            .resolve()?,
-            None => args.resolve(),
+            .resolve_to_account_metas_for_prog(self.program_id)?,
+            None => <[AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]>::from(
+                args.resolve_for_prog(self.program_id),
+            )
+            .into(),
         };
-        Ok(<[AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]>::from(keys).into())
+        Ok(metas)
     }
 
     fn quote_exact_out(
@@ -263,11 +493,13 @@ impl PricingProg for FlatFeePricingProg {
         }: pricing_programs_interface::PriceExactOutKeys,
         pricing_programs_interface::PriceExactOutIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactOutIxArgs,
     ) -> anyhow::Result<u64> {
-        let FeeAccount { input_fee_bps, .. } = self.get_fee_account_checked(&input_lst_mint)?;
-        let FeeAccount { output_fee_bps, .. } = self.get_fee_account_checked(&output_lst_mint)?;
+        let LstFeeBps { input_fee_bps, .. } = self.get_lst_fee_bps_checked(&input_lst_mint)?;
+        let LstFeeBps { output_fee_bps, .. } = self.get_lst_fee_bps_checked(&output_lst_mint)?;
         Ok(calculate_price_exact_out(CalculatePriceExactOutArgs {
-            input_fee_bps: *input_fee_bps,
-            output_fee_bps: *output_fee_bps,
+            input_fee_bps,
+            output_fee_bps,
+            pair_fee_bps: self.get_pair_fee_bps(input_lst_mint, output_lst_mint),
+            epoch_surcharge_bps: self.get_epoch_surcharge_bps_checked()?,
             out_sol_value: *sol_value,
         })?)
     }
@@ -283,16 +515,21 @@ impl PricingProg for FlatFeePricingProg {
             input_lst_mint,
             output_lst_mint,
         };
-        let keys = match self.get_cached_fee_account_bumps(input_lst_mint, output_lst_mint) {
+        let metas = match self.get_cached_fee_account_bumps(input_lst_mint, output_lst_mint) {
             Some((input_fee_acc_bump, output_fee_acc_bump)) => PriceExactOutWithBumpFreeArgs {
                 args,
                 input_fee_acc_bump,
                 output_fee_acc_bump,
+                pair_fee_acc_bump: self
+                    .get_cached_pair_fee_account_bump(input_lst_mint, output_lst_mint),
             }
-            .resolve()?,
-            None => args.resolve(),
+            .resolve_to_account_metas_for_prog(self.program_id)?,
+            None => <[AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]>::from(
+                args.resolve_for_prog(self.program_id),
+            )
+            .into(),
         };
-        Ok(<[AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]>::from(keys).into())
+        Ok(metas)
     }
 }
 
@@ -302,8 +539,7 @@ impl TryFrom<KnownPricingProg> for FlatFeePricingProg {
     fn try_from(value: KnownPricingProg) -> Result<Self, Self::Error> {
         match value {
             KnownPricingProg::FlatFee(f) => Ok(f),
-            // TODO: uncomment when we add more variants
-            // _ => Err(PricingProgErr::WrongPricingProg),
+            _ => Err(PricingProgErr::WrongPricingProg),
         }
     }
 }
@@ -0,0 +1,400 @@
+use imbalance_fee_interface::{
+    ImbalanceFeeError, PriceLpTokensToMintKeys, ProgramState, TargetWeight,
+    PRICE_EXACT_IN_IX_ACCOUNTS_LEN, PRICE_EXACT_OUT_IX_ACCOUNTS_LEN,
+    PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN, PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN,
+};
+use imbalance_fee_lib::{
+    account_resolvers::{
+        PriceExactInFreeArgs, PriceExactInWithBumpFreeArgs, PriceExactOutFreeArgs,
+        PriceExactOutWithBumpFreeArgs, PriceLpTokensToRedeemFreeArgs,
+    },
+    calc::{
+        calculate_price_exact_in, calculate_price_exact_out, calculate_price_lp_tokens_to_redeem,
+        ImbalanceFeeArgs, PoolBalance,
+    },
+    pda::{ProgramStateFindPdaArgs, TargetWeightCreatePdaArgs, TargetWeightFindPdaArgs},
+    utils::{try_lst_state_list, try_program_state, try_target_weight},
+};
+use s_controller_interface::LstState;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+use std::collections::HashMap;
+
+use crate::{KnownPricingProg, MutablePricingProg, PricingProg, PricingProgErr, SyncedSolValues};
+
+#[derive(Clone, Debug, Default)]
+pub struct ImbalanceFeePricingProg {
+    program_id: Pubkey,
+    program_state: Option<ProgramState>,
+    lst_state_list: Option<Vec<LstState>>,
+    mints_to_target_weights: HashMap<Pubkey, Option<TargetWeight>>, // value = None means TargetWeight not yet fetched
+}
+
+impl ImbalanceFeePricingProg {
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    pub fn find_program_state_addr(&self) -> Pubkey {
+        ProgramStateFindPdaArgs {
+            program_id: self.program_id,
+        }
+        .get_program_state_address_and_bump_seed()
+        .0
+    }
+
+    fn get_target_weight_checked(
+        &self,
+        lst_mint: &Pubkey,
+    ) -> Result<&TargetWeight, ImbalanceFeeError> {
+        match self.mints_to_target_weights.get(lst_mint) {
+            Some(Some(a)) => Ok(a),
+            _ => Err(ImbalanceFeeError::UnsupportedLstMint),
+        }
+    }
+
+    /// Returns (input_bump, output_bump)
+    fn get_cached_target_weight_bumps(
+        &self,
+        input_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
+    ) -> Option<(u8, u8)> {
+        match (
+            self.get_target_weight_checked(&input_lst_mint),
+            self.get_target_weight_checked(&output_lst_mint),
+        ) {
+            (
+                Ok(TargetWeight {
+                    bump: input_bump, ..
+                }),
+                Ok(TargetWeight {
+                    bump: output_bump, ..
+                }),
+            ) => Some((*input_bump, *output_bump)),
+            _ => None,
+        }
+    }
+
+    fn target_weight_for_mint(
+        &self,
+        lst_mint: &Pubkey,
+        target_weight_opt: &Option<TargetWeight>,
+    ) -> Pubkey {
+        let find_pda_args = TargetWeightFindPdaArgs {
+            program_id: self.program_id,
+            lst_mint: *lst_mint,
+        };
+        let bump = match target_weight_opt {
+            Some(TargetWeight { bump, .. }) => bump,
+            None => return find_pda_args.get_target_weight_address_and_bump_seed().0,
+        };
+        TargetWeightCreatePdaArgs {
+            find_pda_args,
+            bump: *bump,
+        }
+        .get_target_weight_address()
+        .map_or_else(
+            |_e| find_pda_args.get_target_weight_address_and_bump_seed().0,
+            |pk| pk,
+        )
+    }
+
+    fn imbalance_fee_args_synced(
+        &self,
+        input_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
+        SyncedSolValues {
+            input_lst_sol_value,
+            output_lst_sol_value,
+        }: &SyncedSolValues,
+    ) -> Result<ImbalanceFeeArgs, ImbalanceFeeError> {
+        let mut args = self.imbalance_fee_args(input_lst_mint, output_lst_mint)?;
+        args.pool_balance = args
+            .pool_balance
+            .with_synced_sol_values(*input_lst_sol_value, *output_lst_sol_value)?;
+        Ok(args)
+    }
+
+    fn imbalance_fee_args(
+        &self,
+        input_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
+    ) -> Result<ImbalanceFeeArgs, ImbalanceFeeError> {
+        let program_state = self
+            .program_state
+            .as_ref()
+            .ok_or(ImbalanceFeeError::InvalidProgramStateData)?;
+        let lst_state_list = self
+            .lst_state_list
+            .as_ref()
+            .ok_or(ImbalanceFeeError::InvalidLstStateListData)?;
+        let TargetWeight {
+            target_weight_bps: input_target_weight_bps,
+            ..
+        } = self.get_target_weight_checked(&input_lst_mint)?;
+        let TargetWeight {
+            target_weight_bps: output_target_weight_bps,
+            ..
+        } = self.get_target_weight_checked(&output_lst_mint)?;
+        Ok(ImbalanceFeeArgs {
+            fee_params: program_state.into(),
+            pool_balance: PoolBalance::try_from_lst_state_list(
+                lst_state_list,
+                input_lst_mint,
+                output_lst_mint,
+            )?,
+            input_target_weight_bps: *input_target_weight_bps,
+            output_target_weight_bps: *output_target_weight_bps,
+        })
+    }
+}
+
+impl MutablePricingProg for ImbalanceFeePricingProg {
+    fn try_new<I: Iterator<Item = Pubkey>>(
+        program_id: Pubkey,
+        mints: I,
+    ) -> Result<Self, PricingProgErr>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            program_id,
+            program_state: None,
+            lst_state_list: None,
+            mints_to_target_weights: mints.map(|pk| (pk, None)).collect(),
+        })
+    }
+
+    fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
+        vec![self.find_program_state_addr()]
+    }
+
+    fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
+        [
+            self.find_program_state_addr(),
+            s_controller_lib::program::LST_STATE_LIST_ID,
+        ]
+        .into_iter()
+        .chain(
+            self.mints_to_target_weights
+                .iter()
+                .map(|(lst_mint, target_weight_opt)| {
+                    self.target_weight_for_mint(lst_mint, target_weight_opt)
+                }),
+        )
+        .collect()
+    }
+
+    fn get_accounts_to_update_for_lsts<I: Iterator<Item = Pubkey>>(
+        &self,
+        lst_mints: I,
+    ) -> Vec<Pubkey> {
+        // swap fees depend on the state of the entire pool,
+        // not just the LSTs being swapped
+        [
+            self.find_program_state_addr(),
+            s_controller_lib::program::LST_STATE_LIST_ID,
+        ]
+        .into_iter()
+        .chain(lst_mints.map(|lst_mint| {
+            let target_weight_opt = self
+                .mints_to_target_weights
+                .get(&lst_mint)
+                .map_or_else(|| &None, |opt| opt);
+            self.target_weight_for_mint(&lst_mint, target_weight_opt)
+        }))
+        .collect()
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        let psa = self.find_program_state_addr();
+        if let Some(acc) = account_map.get(&psa) {
+            self.program_state = Some(*try_program_state(&acc.data())?);
+        }
+
+        if let Some(acc) = account_map.get(&s_controller_lib::program::LST_STATE_LIST_ID) {
+            self.lst_state_list = Some(try_lst_state_list(&acc.data())?.to_vec());
+        }
+
+        for (lst_mint, target_weight_opt) in self.mints_to_target_weights.iter_mut() {
+            let find_pda_args = TargetWeightFindPdaArgs {
+                program_id: self.program_id,
+                lst_mint: *lst_mint,
+            };
+            let twa = match target_weight_opt {
+                Some(TargetWeight { bump, .. }) => TargetWeightCreatePdaArgs {
+                    find_pda_args,
+                    bump: *bump,
+                }
+                .get_target_weight_address()?,
+                None => find_pda_args.get_target_weight_address_and_bump_seed().0,
+            };
+            if let Some(acc) = account_map.get(&twa) {
+                *target_weight_opt = Some(*try_target_weight(&acc.data())?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PricingProg for ImbalanceFeePricingProg {
+    fn quote_lp_tokens_to_redeem(
+        &self,
+        _output_lst_mint: Pubkey,
+        pricing_programs_interface::PriceLpTokensToRedeemIxArgs { sol_value, .. }: &pricing_programs_interface::PriceLpTokensToRedeemIxArgs,
+    ) -> anyhow::Result<u64> {
+        let lp_withdrawal_fee_bps = self
+            .program_state
+            .ok_or(ImbalanceFeeError::InvalidProgramStateData)?
+            .lp_withdrawal_fee_bps;
+        Ok(calculate_price_lp_tokens_to_redeem(
+            lp_withdrawal_fee_bps,
+            *sol_value,
+        )?)
+    }
+
+    fn price_lp_tokens_to_redeem_accounts(
+        &self,
+        output_lst_mint: Pubkey,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(
+            <[AccountMeta; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]>::from(
+                PriceLpTokensToRedeemFreeArgs { output_lst_mint }.resolve_for_prog(self.program_id),
+            )
+            .into(),
+        )
+    }
+
+    fn quote_lp_tokens_to_mint(
+        &self,
+        _input_lst_mint: Pubkey,
+        pricing_programs_interface::PriceLpTokensToMintIxArgs { sol_value, .. }: &pricing_programs_interface::PriceLpTokensToMintIxArgs,
+    ) -> anyhow::Result<u64> {
+        Ok(*sol_value)
+    }
+
+    fn price_lp_tokens_to_mint_accounts(
+        &self,
+        input_lst_mint: Pubkey,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(
+            <[AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>::from(
+                PriceLpTokensToMintKeys { input_lst_mint },
+            )
+            .into(),
+        )
+    }
+
+    fn quote_exact_in(
+        &self,
+        pricing_programs_interface::PriceExactInKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactInKeys,
+        pricing_programs_interface::PriceExactInIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactInIxArgs,
+    ) -> anyhow::Result<u64> {
+        let args = self.imbalance_fee_args(input_lst_mint, output_lst_mint)?;
+        Ok(calculate_price_exact_in(&args, *sol_value)?)
+    }
+
+    fn quote_exact_in_synced(
+        &self,
+        pricing_programs_interface::PriceExactInKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactInKeys,
+        pricing_programs_interface::PriceExactInIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactInIxArgs,
+        synced: &SyncedSolValues,
+    ) -> anyhow::Result<u64> {
+        let args = self.imbalance_fee_args_synced(input_lst_mint, output_lst_mint, synced)?;
+        Ok(calculate_price_exact_in(&args, *sol_value)?)
+    }
+
+    fn price_exact_in_accounts(
+        &self,
+        pricing_programs_interface::PriceExactInKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactInKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        let args = PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        };
+        let keys = match self.get_cached_target_weight_bumps(input_lst_mint, output_lst_mint) {
+            Some((input_target_acc_bump, output_target_acc_bump)) => PriceExactInWithBumpFreeArgs {
+                args,
+                input_target_acc_bump,
+                output_target_acc_bump,
+            }
+            .resolve_for_prog(self.program_id)?,
+            None => args.resolve_for_prog(self.program_id),
+        };
+        Ok(<[AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]>::from(keys).into())
+    }
+
+    fn quote_exact_out(
+        &self,
+        pricing_programs_interface::PriceExactOutKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactOutKeys,
+        pricing_programs_interface::PriceExactOutIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactOutIxArgs,
+    ) -> anyhow::Result<u64> {
+        let args = self.imbalance_fee_args(input_lst_mint, output_lst_mint)?;
+        Ok(calculate_price_exact_out(&args, *sol_value)?)
+    }
+
+    fn quote_exact_out_synced(
+        &self,
+        pricing_programs_interface::PriceExactOutKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactOutKeys,
+        pricing_programs_interface::PriceExactOutIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactOutIxArgs,
+        synced: &SyncedSolValues,
+    ) -> anyhow::Result<u64> {
+        let args = self.imbalance_fee_args_synced(input_lst_mint, output_lst_mint, synced)?;
+        Ok(calculate_price_exact_out(&args, *sol_value)?)
+    }
+
+    fn price_exact_out_accounts(
+        &self,
+        pricing_programs_interface::PriceExactOutKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactOutKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        let args = PriceExactOutFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        };
+        let keys = match self.get_cached_target_weight_bumps(input_lst_mint, output_lst_mint) {
+            Some((input_target_acc_bump, output_target_acc_bump)) => {
+                PriceExactOutWithBumpFreeArgs {
+                    args,
+                    input_target_acc_bump,
+                    output_target_acc_bump,
+                }
+                .resolve_for_prog(self.program_id)?
+            }
+            None => args.resolve_for_prog(self.program_id),
+        };
+        Ok(<[AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]>::from(keys).into())
+    }
+}
+
+impl TryFrom<KnownPricingProg> for ImbalanceFeePricingProg {
+    type Error = PricingProgErr;
+
+    fn try_from(value: KnownPricingProg) -> Result<Self, Self::Error> {
+        match value {
+            KnownPricingProg::ImbalanceFee(p) => Ok(p),
+            _ => Err(PricingProgErr::WrongPricingProg),
+        }
+    }
+}
@@ -8,15 +8,26 @@ use std::collections::HashMap;
 
 mod err;
 mod flat_fee;
+mod imbalance_fee;
+mod no_fee;
+mod registry;
 mod traits;
+mod volume_surge;
 
 pub use err::*;
 pub use flat_fee::*;
+pub use imbalance_fee::*;
+pub use no_fee::*;
+pub use registry::*;
 pub use traits::*;
+pub use volume_surge::*;
 
 #[derive(Clone, Debug)]
 pub enum KnownPricingProg {
-    FlatFee(FlatFeePricingProg), // only variant for now
+    FlatFee(FlatFeePricingProg),
+    ImbalanceFee(ImbalanceFeePricingProg),
+    NoFee(NoFeePricingProg),
+    VolumeSurge(VolumeSurgePricingProg),
 }
 
 impl MutablePricingProg for KnownPricingProg {
@@ -28,6 +39,15 @@ impl MutablePricingProg for KnownPricingProg {
             flat_fee_lib::program::ID => {
                 Self::FlatFee(FlatFeePricingProg::try_new(program_id, mints)?)
             }
+            imbalance_fee_lib::program::ID => {
+                Self::ImbalanceFee(ImbalanceFeePricingProg::try_new(program_id, mints)?)
+            }
+            no_fee_pricing_program::ID => {
+                Self::NoFee(NoFeePricingProg::try_new(program_id, mints)?)
+            }
+            volume_surge_lib::program::ID => {
+                Self::VolumeSurge(VolumeSurgePricingProg::try_new(program_id, mints)?)
+            }
             _ => Err(PricingProgErr::UnknownPricingProg)?,
         })
     }
@@ -35,12 +55,18 @@ impl MutablePricingProg for KnownPricingProg {
     fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
         match self {
             Self::FlatFee(p) => p.get_accounts_to_update_for_all_lsts(),
+            Self::ImbalanceFee(p) => p.get_accounts_to_update_for_all_lsts(),
+            Self::NoFee(p) => p.get_accounts_to_update_for_all_lsts(),
+            Self::VolumeSurge(p) => p.get_accounts_to_update_for_all_lsts(),
         }
     }
 
     fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
         match self {
             Self::FlatFee(p) => p.get_accounts_to_update_for_liquidity(),
+            Self::ImbalanceFee(p) => p.get_accounts_to_update_for_liquidity(),
+            Self::NoFee(p) => p.get_accounts_to_update_for_liquidity(),
+            Self::VolumeSurge(p) => p.get_accounts_to_update_for_liquidity(),
         }
     }
 
@@ -50,6 +76,9 @@ impl MutablePricingProg for KnownPricingProg {
     ) -> Vec<Pubkey> {
         match self {
             Self::FlatFee(p) => p.get_accounts_to_update_for_lsts(lst_mints),
+            Self::ImbalanceFee(p) => p.get_accounts_to_update_for_lsts(lst_mints),
+            Self::NoFee(p) => p.get_accounts_to_update_for_lsts(lst_mints),
+            Self::VolumeSurge(p) => p.get_accounts_to_update_for_lsts(lst_mints),
         }
     }
 
@@ -59,6 +88,9 @@ impl MutablePricingProg for KnownPricingProg {
     ) -> anyhow::Result<()> {
         match self {
             Self::FlatFee(p) => p.update(account_map),
+            Self::ImbalanceFee(p) => p.update(account_map),
+            Self::NoFee(p) => p.update(account_map),
+            Self::VolumeSurge(p) => p.update(account_map),
         }
     }
 }
@@ -71,6 +103,9 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<u64> {
         match self {
             Self::FlatFee(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
+            Self::ImbalanceFee(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
+            Self::NoFee(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
+            Self::VolumeSurge(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
         }
     }
 
@@ -80,6 +115,9 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
             Self::FlatFee(p) => p.price_lp_tokens_to_redeem_accounts(output_lst_mint),
+            Self::ImbalanceFee(p) => p.price_lp_tokens_to_redeem_accounts(output_lst_mint),
+            Self::NoFee(p) => p.price_lp_tokens_to_redeem_accounts(output_lst_mint),
+            Self::VolumeSurge(p) => p.price_lp_tokens_to_redeem_accounts(output_lst_mint),
         }
     }
 
@@ -90,6 +128,9 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<u64> {
         match self {
             Self::FlatFee(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
+            Self::ImbalanceFee(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
+            Self::NoFee(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
+            Self::VolumeSurge(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
         }
     }
 
@@ -99,6 +140,9 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
             Self::FlatFee(p) => p.price_lp_tokens_to_mint_accounts(input_lst_mint),
+            Self::ImbalanceFee(p) => p.price_lp_tokens_to_mint_accounts(input_lst_mint),
+            Self::NoFee(p) => p.price_lp_tokens_to_mint_accounts(input_lst_mint),
+            Self::VolumeSurge(p) => p.price_lp_tokens_to_mint_accounts(input_lst_mint),
         }
     }
 
@@ -109,12 +153,32 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<u64> {
         match self {
             Self::FlatFee(p) => p.quote_exact_in(keys, args),
+            Self::ImbalanceFee(p) => p.quote_exact_in(keys, args),
+            Self::NoFee(p) => p.quote_exact_in(keys, args),
+            Self::VolumeSurge(p) => p.quote_exact_in(keys, args),
+        }
+    }
+
+    fn quote_exact_in_synced(
+        &self,
+        keys: PriceExactInKeys,
+        args: &PriceExactInIxArgs,
+        synced: &SyncedSolValues,
+    ) -> anyhow::Result<u64> {
+        match self {
+            Self::FlatFee(p) => p.quote_exact_in_synced(keys, args, synced),
+            Self::ImbalanceFee(p) => p.quote_exact_in_synced(keys, args, synced),
+            Self::NoFee(p) => p.quote_exact_in_synced(keys, args, synced),
+            Self::VolumeSurge(p) => p.quote_exact_in_synced(keys, args, synced),
         }
     }
 
     fn price_exact_in_accounts(&self, keys: PriceExactInKeys) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
             Self::FlatFee(p) => p.price_exact_in_accounts(keys),
+            Self::ImbalanceFee(p) => p.price_exact_in_accounts(keys),
+            Self::NoFee(p) => p.price_exact_in_accounts(keys),
+            Self::VolumeSurge(p) => p.price_exact_in_accounts(keys),
         }
     }
 
@@ -125,6 +189,23 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<u64> {
         match self {
             Self::FlatFee(p) => p.quote_exact_out(keys, args),
+            Self::ImbalanceFee(p) => p.quote_exact_out(keys, args),
+            Self::NoFee(p) => p.quote_exact_out(keys, args),
+            Self::VolumeSurge(p) => p.quote_exact_out(keys, args),
+        }
+    }
+
+    fn quote_exact_out_synced(
+        &self,
+        keys: PriceExactOutKeys,
+        args: &PriceExactOutIxArgs,
+        synced: &SyncedSolValues,
+    ) -> anyhow::Result<u64> {
+        match self {
+            Self::FlatFee(p) => p.quote_exact_out_synced(keys, args, synced),
+            Self::ImbalanceFee(p) => p.quote_exact_out_synced(keys, args, synced),
+            Self::NoFee(p) => p.quote_exact_out_synced(keys, args, synced),
+            Self::VolumeSurge(p) => p.quote_exact_out_synced(keys, args, synced),
         }
     }
 
@@ -134,6 +215,9 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
             Self::FlatFee(p) => p.price_exact_out_accounts(keys),
+            Self::ImbalanceFee(p) => p.price_exact_out_accounts(keys),
+            Self::NoFee(p) => p.price_exact_out_accounts(keys),
+            Self::VolumeSurge(p) => p.price_exact_out_accounts(keys),
         }
     }
 }
@@ -143,3 +227,21 @@ impl From<FlatFeePricingProg> for KnownPricingProg {
         Self::FlatFee(value)
     }
 }
+
+impl From<ImbalanceFeePricingProg> for KnownPricingProg {
+    fn from(value: ImbalanceFeePricingProg) -> Self {
+        Self::ImbalanceFee(value)
+    }
+}
+
+impl From<NoFeePricingProg> for KnownPricingProg {
+    fn from(value: NoFeePricingProg) -> Self {
+        Self::NoFee(value)
+    }
+}
+
+impl From<VolumeSurgePricingProg> for KnownPricingProg {
+    fn from(value: VolumeSurgePricingProg) -> Self {
+        Self::VolumeSurge(value)
+    }
+}
@@ -0,0 +1,138 @@
+use pricing_programs_interface::{
+    PriceExactInIxArgs, PriceExactInKeys, PriceExactOutIxArgs, PriceExactOutKeys,
+    PriceLpTokensToMintIxArgs, PriceLpTokensToMintKeys, PriceLpTokensToRedeemIxArgs,
+    PriceLpTokensToRedeemKeys, PRICE_EXACT_IN_IX_ACCOUNTS_LEN, PRICE_EXACT_OUT_IX_ACCOUNTS_LEN,
+    PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN, PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN,
+};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+use std::collections::HashMap;
+
+use crate::{KnownPricingProg, MutablePricingProg, PricingProg, PricingProgErr};
+
+/// The no-fee pricing program is stateless and returns `sol_value` as-is for every instruction,
+/// so it only requires the common interface accounts
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoFeePricingProg {
+    program_id: Pubkey,
+}
+
+impl NoFeePricingProg {
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+}
+
+impl MutablePricingProg for NoFeePricingProg {
+    fn try_new<I: Iterator<Item = Pubkey>>(
+        program_id: Pubkey,
+        _mints: I,
+    ) -> Result<Self, PricingProgErr>
+    where
+        Self: Sized,
+    {
+        Ok(Self { program_id })
+    }
+
+    fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
+        Vec::new()
+    }
+
+    fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
+        Vec::new()
+    }
+
+    fn get_accounts_to_update_for_lsts<I: Iterator<Item = Pubkey>>(
+        &self,
+        _lst_mints: I,
+    ) -> Vec<Pubkey> {
+        Vec::new()
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        _account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+impl PricingProg for NoFeePricingProg {
+    fn quote_lp_tokens_to_redeem(
+        &self,
+        _output_lst_mint: Pubkey,
+        PriceLpTokensToRedeemIxArgs { sol_value, .. }: &PriceLpTokensToRedeemIxArgs,
+    ) -> anyhow::Result<u64> {
+        Ok(*sol_value)
+    }
+
+    fn price_lp_tokens_to_redeem_accounts(
+        &self,
+        output_lst_mint: Pubkey,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(
+            <[AccountMeta; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]>::from(
+                PriceLpTokensToRedeemKeys { output_lst_mint },
+            )
+            .into(),
+        )
+    }
+
+    fn quote_lp_tokens_to_mint(
+        &self,
+        _input_lst_mint: Pubkey,
+        PriceLpTokensToMintIxArgs { sol_value, .. }: &PriceLpTokensToMintIxArgs,
+    ) -> anyhow::Result<u64> {
+        Ok(*sol_value)
+    }
+
+    fn price_lp_tokens_to_mint_accounts(
+        &self,
+        input_lst_mint: Pubkey,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(
+            <[AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>::from(
+                PriceLpTokensToMintKeys { input_lst_mint },
+            )
+            .into(),
+        )
+    }
+
+    fn quote_exact_in(
+        &self,
+        _keys: PriceExactInKeys,
+        PriceExactInIxArgs { sol_value, .. }: &PriceExactInIxArgs,
+    ) -> anyhow::Result<u64> {
+        Ok(*sol_value)
+    }
+
+    fn price_exact_in_accounts(&self, keys: PriceExactInKeys) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(<[AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]>::from(keys).into())
+    }
+
+    fn quote_exact_out(
+        &self,
+        _keys: PriceExactOutKeys,
+        PriceExactOutIxArgs { sol_value, .. }: &PriceExactOutIxArgs,
+    ) -> anyhow::Result<u64> {
+        Ok(*sol_value)
+    }
+
+    fn price_exact_out_accounts(
+        &self,
+        keys: PriceExactOutKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(<[AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]>::from(keys).into())
+    }
+}
+
+impl TryFrom<KnownPricingProg> for NoFeePricingProg {
+    type Error = PricingProgErr;
+
+    fn try_from(value: KnownPricingProg) -> Result<Self, Self::Error> {
+        match value {
+            KnownPricingProg::NoFee(p) => Ok(p),
+            _ => Err(PricingProgErr::WrongPricingProg),
+        }
+    }
+}
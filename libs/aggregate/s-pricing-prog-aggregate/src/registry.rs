@@ -0,0 +1,169 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{KnownPricingProg, MutablePricingProg, PricingProg, PricingProgErr};
+
+/// Object-safe version of [`MutablePricingProg`] + [`PricingProg`]
+/// with [`MutablePricingProg::update`]'s account type constrained to `D`.
+///
+/// Implement this for private pricing programs and register them
+/// with a [`PricingProgRegistry`] to use them as trait objects.
+pub trait DynPricingProg<D>: PricingProg + Debug + Send + Sync {
+    /// Returns the program ID of the pricing program
+    fn pricing_program_id(&self) -> Pubkey;
+
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        [
+            self.get_accounts_to_update_for_liquidity(),
+            self.get_accounts_to_update_for_all_lsts(),
+        ]
+        .concat()
+    }
+
+    /// See [`MutablePricingProg::get_accounts_to_update_for_liquidity`]
+    fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey>;
+
+    /// See [`MutablePricingProg::get_accounts_to_update_for_all_lsts`]
+    fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey>;
+
+    /// See [`MutablePricingProg::get_accounts_to_update_for_lsts`]
+    fn get_accounts_to_update_for_lsts(&self, lst_mints: &[Pubkey]) -> Vec<Pubkey>;
+
+    /// See [`MutablePricingProg::update`]
+    fn update(&mut self, account_map: &HashMap<Pubkey, D>) -> anyhow::Result<()>;
+
+    fn clone_boxed(&self) -> Box<dyn DynPricingProg<D>>;
+}
+
+impl<D> Clone for Box<dyn DynPricingProg<D>> {
+    fn clone(&self) -> Self {
+        self.clone_boxed()
+    }
+}
+
+impl<D: ReadonlyAccountData> DynPricingProg<D> for KnownPricingProg {
+    fn pricing_program_id(&self) -> Pubkey {
+        match self {
+            Self::FlatFee(p) => p.program_id(),
+            Self::ImbalanceFee(p) => p.program_id(),
+            Self::NoFee(p) => p.program_id(),
+            Self::VolumeSurge(p) => p.program_id(),
+        }
+    }
+
+    fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
+        MutablePricingProg::get_accounts_to_update_for_liquidity(self)
+    }
+
+    fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
+        MutablePricingProg::get_accounts_to_update_for_all_lsts(self)
+    }
+
+    fn get_accounts_to_update_for_lsts(&self, lst_mints: &[Pubkey]) -> Vec<Pubkey> {
+        MutablePricingProg::get_accounts_to_update_for_lsts(self, lst_mints.iter().copied())
+    }
+
+    fn update(&mut self, account_map: &HashMap<Pubkey, D>) -> anyhow::Result<()> {
+        MutablePricingProg::update(self, account_map)
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DynPricingProg<D>> {
+        Box::new(self.clone())
+    }
+}
+
+/// Creates a pricing program for the given program ID and the mints of all LSTs in the pool
+pub type PricingProgFactory<D> =
+    Box<dyn Fn(Pubkey, &[Pubkey]) -> anyhow::Result<Box<dyn DynPricingProg<D>>> + Send + Sync>;
+
+/// Maps pricing program IDs to the factories that create their [`DynPricingProg`]s.
+///
+/// [`Default`] registers all pricing programs in [`KnownPricingProg`].
+pub struct PricingProgRegistry<D> {
+    factories: HashMap<Pubkey, PricingProgFactory<D>>,
+}
+
+impl<D> PricingProgRegistry<D> {
+    /// A registry with no pricing programs registered, not even the built-in ones
+    pub fn empty() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Registers `factory` for `program_id`, returning the previously registered factory if any
+    pub fn register<F>(&mut self, program_id: Pubkey, factory: F) -> Option<PricingProgFactory<D>>
+    where
+        F: Fn(Pubkey, &[Pubkey]) -> anyhow::Result<Box<dyn DynPricingProg<D>>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.factories.insert(program_id, Box::new(factory))
+    }
+
+    pub fn unregister(&mut self, program_id: &Pubkey) -> Option<PricingProgFactory<D>> {
+        self.factories.remove(program_id)
+    }
+
+    pub fn is_registered(&self, program_id: &Pubkey) -> bool {
+        self.factories.contains_key(program_id)
+    }
+
+    pub fn program_ids(&self) -> impl Iterator<Item = &Pubkey> {
+        self.factories.keys()
+    }
+
+    /// Errors with [`PricingProgErr::UnknownPricingProg`] if `program_id` is not registered
+    pub fn try_new_pricing_prog(
+        &self,
+        program_id: Pubkey,
+        mints: &[Pubkey],
+    ) -> anyhow::Result<Box<dyn DynPricingProg<D>>> {
+        let factory = self
+            .factories
+            .get(&program_id)
+            .ok_or(PricingProgErr::UnknownPricingProg)?;
+        factory(program_id, mints)
+    }
+}
+
+impl<D: ReadonlyAccountData + 'static> PricingProgRegistry<D> {
+    pub fn register_known_pricing_prog(
+        &mut self,
+        program_id: Pubkey,
+    ) -> Option<PricingProgFactory<D>> {
+        self.register(program_id, |program_id, mints| {
+            let pp: Box<dyn DynPricingProg<D>> = Box::new(KnownPricingProg::try_new(
+                program_id,
+                mints.iter().copied(),
+            )?);
+            Ok(pp)
+        })
+    }
+}
+
+impl<D: ReadonlyAccountData + 'static> Default for PricingProgRegistry<D> {
+    fn default() -> Self {
+        let mut res = Self::empty();
+        for program_id in [
+            flat_fee_lib::program::ID,
+            imbalance_fee_lib::program::ID,
+            no_fee_pricing_program::ID,
+            volume_surge_lib::program::ID,
+        ] {
+            res.register_known_pricing_prog(program_id);
+        }
+        res
+    }
+}
+
+impl<D> Debug for PricingProgRegistry<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PricingProgRegistry")
+            .field("program_ids", &self.factories.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -74,6 +74,14 @@ pub trait MutablePricingProg {
     ) -> anyhow::Result<()>;
 }
 
+/// SOL values of the input and output LSTs after the S controller's
+/// SyncSolValue that precedes the pricing program CPI in a swap
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SyncedSolValues {
+    pub input_lst_sol_value: u64,
+    pub output_lst_sol_value: u64,
+}
+
 pub trait PricingProg {
     /// Returns SOL value of the LST to redeem
     fn quote_lp_tokens_to_redeem(
@@ -114,6 +122,20 @@ pub trait PricingProg {
         args: &PriceExactInIxArgs,
     ) -> anyhow::Result<u64>;
 
+    /// Same as [`Self::quote_exact_in`], but for programs whose fees depend on
+    /// the LstStateList, first applies the given synced SOL values to match what
+    /// the program sees on-chain.
+    ///
+    /// Defaults to [`Self::quote_exact_in`] for programs that do not read the LstStateList.
+    fn quote_exact_in_synced(
+        &self,
+        keys: PriceExactInKeys,
+        args: &PriceExactInIxArgs,
+        _synced: &SyncedSolValues,
+    ) -> anyhow::Result<u64> {
+        self.quote_exact_in(keys, args)
+    }
+
     /// Returns the account inputs to the program's PriceExactIn
     /// instruction.
     ///
@@ -127,6 +149,20 @@ pub trait PricingProg {
         args: &PriceExactOutIxArgs,
     ) -> anyhow::Result<u64>;
 
+    /// Same as [`Self::quote_exact_out`], but for programs whose fees depend on
+    /// the LstStateList, first applies the given synced SOL values to match what
+    /// the program sees on-chain.
+    ///
+    /// Defaults to [`Self::quote_exact_out`] for programs that do not read the LstStateList.
+    fn quote_exact_out_synced(
+        &self,
+        keys: PriceExactOutKeys,
+        args: &PriceExactOutIxArgs,
+        _synced: &SyncedSolValues,
+    ) -> anyhow::Result<u64> {
+        self.quote_exact_out(keys, args)
+    }
+
     /// Returns the account inputs to the program's PriceExactOut
     /// instruction.
     ///
@@ -0,0 +1,369 @@
+use s_controller_interface::LstState;
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey, sysvar};
+use solana_readonly_account::ReadonlyAccountData;
+use std::collections::HashMap;
+use volume_surge_interface::{
+    PriceLpTokensToMintKeys, ProgramState, VolumeAccount, VolumeSurgeError,
+    PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN, PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN,
+};
+use volume_surge_lib::{
+    account_resolvers::{
+        price_exact_in_account_metas, price_exact_out_account_metas, PriceExactInFreeArgs,
+        PriceExactInWithBumpFreeArgs, PriceExactOutFreeArgs, PriceExactOutWithBumpFreeArgs,
+        PriceLpTokensToRedeemFreeArgs,
+    },
+    calc::{
+        calculate_price_exact_in, calculate_price_exact_out, calculate_price_lp_tokens_to_redeem,
+        pool_total_sol_value, DecayedVolume, SurgeFeeArgs,
+    },
+    pda::{ProgramStateFindPdaArgs, VolumeAccountCreatePdaArgs, VolumeAccountFindPdaArgs},
+    utils::{try_lst_state_list, try_program_state, try_volume_account},
+};
+
+use crate::{KnownPricingProg, MutablePricingProg, PricingProg, PricingProgErr};
+
+#[derive(Clone, Debug, Default)]
+pub struct VolumeSurgePricingProg {
+    program_id: Pubkey,
+    program_state: Option<ProgramState>,
+    lst_state_list: Option<Vec<LstState>>,
+    mints_to_volume_accounts: HashMap<Pubkey, Option<VolumeAccount>>, // value = None means VolumeAccount not yet fetched
+    clock: Option<Clock>, // required to decay recorded volumes
+}
+
+impl VolumeSurgePricingProg {
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    pub fn find_program_state_addr(&self) -> Pubkey {
+        ProgramStateFindPdaArgs {
+            program_id: self.program_id,
+        }
+        .get_program_state_address_and_bump_seed()
+        .0
+    }
+
+    /// Supply the clock to decay recorded volumes with directly
+    /// instead of fetching the clock sysvar account via [`MutablePricingProg::update`]
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = Some(clock);
+    }
+
+    fn get_volume_account_checked(
+        &self,
+        lst_mint: &Pubkey,
+    ) -> Result<&VolumeAccount, VolumeSurgeError> {
+        match self.mints_to_volume_accounts.get(lst_mint) {
+            Some(Some(a)) => Ok(a),
+            _ => Err(VolumeSurgeError::UnsupportedLstMint),
+        }
+    }
+
+    /// Returns (input_bump, output_bump)
+    fn get_cached_volume_account_bumps(
+        &self,
+        input_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
+    ) -> Option<(u8, u8)> {
+        match (
+            self.get_volume_account_checked(&input_lst_mint),
+            self.get_volume_account_checked(&output_lst_mint),
+        ) {
+            (
+                Ok(VolumeAccount {
+                    bump: input_bump, ..
+                }),
+                Ok(VolumeAccount {
+                    bump: output_bump, ..
+                }),
+            ) => Some((*input_bump, *output_bump)),
+            _ => None,
+        }
+    }
+
+    fn volume_account_for_mint(
+        &self,
+        lst_mint: &Pubkey,
+        volume_account_opt: &Option<VolumeAccount>,
+    ) -> Pubkey {
+        let find_pda_args = VolumeAccountFindPdaArgs {
+            program_id: self.program_id,
+            lst_mint: *lst_mint,
+        };
+        let bump = match volume_account_opt {
+            Some(VolumeAccount { bump, .. }) => bump,
+            None => return find_pda_args.get_volume_account_address_and_bump_seed().0,
+        };
+        VolumeAccountCreatePdaArgs {
+            find_pda_args,
+            bump: *bump,
+        }
+        .get_volume_account_address()
+        .map_or_else(
+            |_e| find_pda_args.get_volume_account_address_and_bump_seed().0,
+            |pk| pk,
+        )
+    }
+
+    fn surge_fee_args(
+        &self,
+        input_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
+    ) -> anyhow::Result<SurgeFeeArgs> {
+        let program_state = self
+            .program_state
+            .as_ref()
+            .ok_or(VolumeSurgeError::InvalidProgramStateData)?;
+        let lst_state_list = self
+            .lst_state_list
+            .as_ref()
+            .ok_or(VolumeSurgeError::InvalidLstStateListData)?;
+        let current_slot = self
+            .clock
+            .as_ref()
+            .ok_or(PricingProgErr::ClockNotFetched)?
+            .slot;
+        let [input_volume, output_volume] = [input_lst_mint, output_lst_mint].map(|lst_mint| {
+            self.get_volume_account_checked(&lst_mint).map(|va| {
+                DecayedVolume::at_slot(va, program_state.decay_window_slots, current_slot)
+            })
+        });
+        Ok(SurgeFeeArgs {
+            fee_params: program_state.into(),
+            total_sol_value: pool_total_sol_value(lst_state_list)?,
+            input_volume: input_volume?,
+            output_volume: output_volume?,
+        })
+    }
+}
+
+impl MutablePricingProg for VolumeSurgePricingProg {
+    fn try_new<I: Iterator<Item = Pubkey>>(
+        program_id: Pubkey,
+        mints: I,
+    ) -> Result<Self, PricingProgErr>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            program_id,
+            program_state: None,
+            lst_state_list: None,
+            mints_to_volume_accounts: mints.map(|pk| (pk, None)).collect(),
+            clock: None,
+        })
+    }
+
+    fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
+        vec![self.find_program_state_addr()]
+    }
+
+    fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
+        [
+            self.find_program_state_addr(),
+            s_controller_lib::program::LST_STATE_LIST_ID,
+            sysvar::clock::ID,
+        ]
+        .into_iter()
+        .chain(
+            self.mints_to_volume_accounts
+                .iter()
+                .map(|(lst_mint, volume_account_opt)| {
+                    self.volume_account_for_mint(lst_mint, volume_account_opt)
+                }),
+        )
+        .collect()
+    }
+
+    fn get_accounts_to_update_for_lsts<I: Iterator<Item = Pubkey>>(
+        &self,
+        lst_mints: I,
+    ) -> Vec<Pubkey> {
+        // swap fees are relative to the pool's total SOL value
+        [
+            self.find_program_state_addr(),
+            s_controller_lib::program::LST_STATE_LIST_ID,
+            sysvar::clock::ID,
+        ]
+        .into_iter()
+        .chain(lst_mints.map(|lst_mint| {
+            let volume_account_opt = self
+                .mints_to_volume_accounts
+                .get(&lst_mint)
+                .map_or_else(|| &None, |opt| opt);
+            self.volume_account_for_mint(&lst_mint, volume_account_opt)
+        }))
+        .collect()
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        let psa = self.find_program_state_addr();
+        if let Some(acc) = account_map.get(&psa) {
+            self.program_state = Some(*try_program_state(&acc.data())?);
+        }
+
+        if let Some(acc) = account_map.get(&s_controller_lib::program::LST_STATE_LIST_ID) {
+            self.lst_state_list = Some(try_lst_state_list(&acc.data())?.to_vec());
+        }
+
+        if let Some(acc) = account_map.get(&sysvar::clock::ID) {
+            self.clock = Some(bincode::deserialize::<Clock>(&acc.data())?);
+        }
+
+        for (lst_mint, volume_account_opt) in self.mints_to_volume_accounts.iter_mut() {
+            let find_pda_args = VolumeAccountFindPdaArgs {
+                program_id: self.program_id,
+                lst_mint: *lst_mint,
+            };
+            let vaa = match volume_account_opt {
+                Some(VolumeAccount { bump, .. }) => VolumeAccountCreatePdaArgs {
+                    find_pda_args,
+                    bump: *bump,
+                }
+                .get_volume_account_address()?,
+                None => find_pda_args.get_volume_account_address_and_bump_seed().0,
+            };
+            if let Some(acc) = account_map.get(&vaa) {
+                *volume_account_opt = Some(*try_volume_account(&acc.data())?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PricingProg for VolumeSurgePricingProg {
+    fn quote_lp_tokens_to_redeem(
+        &self,
+        _output_lst_mint: Pubkey,
+        pricing_programs_interface::PriceLpTokensToRedeemIxArgs { sol_value, .. }: &pricing_programs_interface::PriceLpTokensToRedeemIxArgs,
+    ) -> anyhow::Result<u64> {
+        let lp_withdrawal_fee_bps = self
+            .program_state
+            .ok_or(VolumeSurgeError::InvalidProgramStateData)?
+            .lp_withdrawal_fee_bps;
+        Ok(calculate_price_lp_tokens_to_redeem(
+            lp_withdrawal_fee_bps,
+            *sol_value,
+        )?)
+    }
+
+    fn price_lp_tokens_to_redeem_accounts(
+        &self,
+        output_lst_mint: Pubkey,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(
+            <[AccountMeta; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]>::from(
+                PriceLpTokensToRedeemFreeArgs { output_lst_mint }.resolve_for_prog(self.program_id),
+            )
+            .into(),
+        )
+    }
+
+    fn quote_lp_tokens_to_mint(
+        &self,
+        _input_lst_mint: Pubkey,
+        pricing_programs_interface::PriceLpTokensToMintIxArgs { sol_value, .. }: &pricing_programs_interface::PriceLpTokensToMintIxArgs,
+    ) -> anyhow::Result<u64> {
+        Ok(*sol_value)
+    }
+
+    fn price_lp_tokens_to_mint_accounts(
+        &self,
+        input_lst_mint: Pubkey,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(
+            <[AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>::from(
+                PriceLpTokensToMintKeys { input_lst_mint },
+            )
+            .into(),
+        )
+    }
+
+    fn quote_exact_in(
+        &self,
+        pricing_programs_interface::PriceExactInKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactInKeys,
+        pricing_programs_interface::PriceExactInIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactInIxArgs,
+    ) -> anyhow::Result<u64> {
+        let args = self.surge_fee_args(input_lst_mint, output_lst_mint)?;
+        Ok(calculate_price_exact_in(&args, *sol_value)?)
+    }
+
+    fn price_exact_in_accounts(
+        &self,
+        pricing_programs_interface::PriceExactInKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactInKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        let args = PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        };
+        let keys = match self.get_cached_volume_account_bumps(input_lst_mint, output_lst_mint) {
+            Some((input_volume_acc_bump, output_volume_acc_bump)) => PriceExactInWithBumpFreeArgs {
+                args,
+                input_volume_acc_bump,
+                output_volume_acc_bump,
+            }
+            .resolve_for_prog(self.program_id)?,
+            None => args.resolve_for_prog(self.program_id),
+        };
+        Ok(price_exact_in_account_metas(keys).into())
+    }
+
+    fn quote_exact_out(
+        &self,
+        pricing_programs_interface::PriceExactOutKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactOutKeys,
+        pricing_programs_interface::PriceExactOutIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactOutIxArgs,
+    ) -> anyhow::Result<u64> {
+        let args = self.surge_fee_args(input_lst_mint, output_lst_mint)?;
+        Ok(calculate_price_exact_out(&args, *sol_value)?)
+    }
+
+    fn price_exact_out_accounts(
+        &self,
+        pricing_programs_interface::PriceExactOutKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactOutKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        let args = PriceExactOutFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        };
+        let keys = match self.get_cached_volume_account_bumps(input_lst_mint, output_lst_mint) {
+            Some((input_volume_acc_bump, output_volume_acc_bump)) => {
+                PriceExactOutWithBumpFreeArgs {
+                    args,
+                    input_volume_acc_bump,
+                    output_volume_acc_bump,
+                }
+                .resolve_for_prog(self.program_id)?
+            }
+            None => args.resolve_for_prog(self.program_id),
+        };
+        Ok(price_exact_out_account_metas(keys).into())
+    }
+}
+
+impl TryFrom<KnownPricingProg> for VolumeSurgePricingProg {
+    type Error = PricingProgErr;
+
+    fn try_from(value: KnownPricingProg) -> Result<Self, Self::Error> {
+        match value {
+            KnownPricingProg::VolumeSurge(p) => Ok(p),
+            _ => Err(PricingProgErr::WrongPricingProg),
+        }
+    }
+}
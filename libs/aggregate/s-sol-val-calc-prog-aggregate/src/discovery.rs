@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner};
+use spl_calculator_lib::{
+    deserialize_stake_pool_checked, sanctum_spl_multi_sol_val_calc_program,
+    sanctum_spl_sol_val_calc_program, SanctumSplMultiSolValCalc, SanctumSplSolValCalc,
+    SplSolValCalc,
+};
+
+use crate::{KnownLstSolValCalc, LstSolValCalcErr, LstSolValCalcInitArgs};
+
+/// Byte offset of `pool_mint` in a SPL stake pool account:
+/// account_type (1) + manager (32) + staker (32) + stake_deposit_authority (32)
+/// + stake_withdraw_bump_seed (1) + validator_list (32) + reserve_stake (32)
+pub const SPL_STAKE_POOL_POOL_MINT_OFFSET: usize = 162;
+
+/// Source of on-chain accounts for discovering the SOL value calculator
+/// data of LSTs without relying on a static LST list.
+///
+/// Implement this over an RPC client to discover from a live cluster.
+/// [`HashMap`] implements this to allow discovering from a set of pre-fetched accounts.
+pub trait AccountFetcher {
+    type Account: ReadonlyAccountData + ReadonlyAccountOwner;
+
+    fn fetch_account(&self, pubkey: &Pubkey) -> anyhow::Result<Option<Self::Account>>;
+
+    /// Returns all accounts owned by `program_id` whose data at `offset` equals `bytes`,
+    /// i.e. `getProgramAccounts` with a single memcmp filter
+    fn fetch_program_accounts_memcmp(
+        &self,
+        program_id: &Pubkey,
+        offset: usize,
+        bytes: &[u8],
+    ) -> anyhow::Result<Vec<(Pubkey, Self::Account)>>;
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountOwner + Clone> AccountFetcher for HashMap<Pubkey, A> {
+    type Account = A;
+
+    fn fetch_account(&self, pubkey: &Pubkey) -> anyhow::Result<Option<Self::Account>> {
+        Ok(self.get(pubkey).cloned())
+    }
+
+    fn fetch_program_accounts_memcmp(
+        &self,
+        program_id: &Pubkey,
+        offset: usize,
+        bytes: &[u8],
+    ) -> anyhow::Result<Vec<(Pubkey, Self::Account)>> {
+        Ok(self
+            .iter()
+            .filter(|(_, acc)| {
+                *acc.owner() == *program_id
+                    && acc.data().get(offset..offset + bytes.len()) == Some(bytes)
+            })
+            .map(|(pk, acc)| (*pk, acc.clone()))
+            .collect())
+    }
+}
+
+/// Returns the stake pool program whose stake pools `sol_value_calculator` reads from,
+/// if `sol_value_calculator` is a SPL stake pool calculator
+pub fn spl_stake_pool_program_for_calc(sol_value_calculator: Pubkey) -> Option<Pubkey> {
+    match sol_value_calculator {
+        spl_calculator_lib::program::ID => Some(SplSolValCalc::POOL_PROGRAM_ID),
+        sanctum_spl_sol_val_calc_program::ID => Some(SanctumSplSolValCalc::POOL_PROGRAM_ID),
+        sanctum_spl_multi_sol_val_calc_program::ID => {
+            Some(SanctumSplMultiSolValCalc::POOL_PROGRAM_ID)
+        }
+        _ => None,
+    }
+}
+
+/// Finds the address of the single initialized stake pool owned by `stake_pool_program`
+/// whose `pool_mint` is `lst_mint`
+pub fn discover_spl_stake_pool_addr<F: AccountFetcher>(
+    fetcher: &F,
+    stake_pool_program: Pubkey,
+    lst_mint: Pubkey,
+) -> anyhow::Result<Pubkey> {
+    let mut candidates = fetcher
+        .fetch_program_accounts_memcmp(
+            &stake_pool_program,
+            SPL_STAKE_POOL_POOL_MINT_OFFSET,
+            lst_mint.as_ref(),
+        )?
+        .into_iter()
+        .filter(|(_pk, acc)| {
+            deserialize_stake_pool_checked(acc).map_or(false, |pool| pool.pool_mint == lst_mint)
+        })
+        .map(|(pk, _acc)| pk);
+    let stake_pool_addr = candidates
+        .next()
+        .ok_or(LstSolValCalcErr::StakePoolNotFound)?;
+    if candidates.next().is_some() {
+        return Err(LstSolValCalcErr::MultipleStakePoolsFound.into());
+    }
+    Ok(stake_pool_addr)
+}
+
+/// Discovers the [`LstSolValCalcInitArgs`] of `lst_mint` from on-chain accounts.
+///
+/// For SPL stake pool calculators, this looks up the stake pool whose `pool_mint` is `lst_mint`.
+/// For all other calculators, `stake_pool_addr` is left as `None`.
+pub fn discover_lst_sol_val_calc_init_args<F: AccountFetcher>(
+    fetcher: &F,
+    sol_value_calculator: Pubkey,
+    lst_mint: Pubkey,
+) -> anyhow::Result<LstSolValCalcInitArgs> {
+    let stake_pool_addr = spl_stake_pool_program_for_calc(sol_value_calculator)
+        .map(|stake_pool_program| {
+            discover_spl_stake_pool_addr(fetcher, stake_pool_program, lst_mint)
+        })
+        .transpose()?;
+    Ok(LstSolValCalcInitArgs {
+        lst_mint,
+        stake_pool_addr,
+    })
+}
+
+impl KnownLstSolValCalc {
+    /// Creates the [`KnownLstSolValCalc`] for `lst_mint` from its `LstState::sol_value_calculator`,
+    /// looking up any other required accounts with `fetcher`
+    pub fn discover<F: AccountFetcher>(
+        fetcher: &F,
+        sol_value_calculator: Pubkey,
+        lst_mint: Pubkey,
+    ) -> anyhow::Result<Self> {
+        let args = discover_lst_sol_val_calc_init_args(fetcher, sol_value_calculator, lst_mint)?;
+        Ok(Self::try_new(sol_value_calculator, args)?)
+    }
+}
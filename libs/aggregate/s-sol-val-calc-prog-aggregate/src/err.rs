@@ -3,12 +3,28 @@ use std::{error::Error, fmt::Display};
 #[derive(Clone, Copy, Debug)]
 pub enum LstSolValCalcErr {
     WrongLstSolValCalc,
+    UnknownLstSolValCalc,
+    MissingStakePoolAddr,
+    StakePoolNotFound,
+    MultipleStakePoolsFound,
+    ProjectionUnsupported,
+    InvalidProjection,
 }
 
 impl Display for LstSolValCalcErr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::WrongLstSolValCalc => f.write_str("wrong LstSolValCalc variant"),
+            Self::UnknownLstSolValCalc => f.write_str("unknown SOL value calculator program"),
+            Self::MissingStakePoolAddr => f.write_str("stake pool address required"),
+            Self::StakePoolNotFound => f.write_str("no stake pool found for LST mint"),
+            Self::MultipleStakePoolsFound => {
+                f.write_str("more than one stake pool found for LST mint")
+            }
+            Self::ProjectionUnsupported => {
+                f.write_str("SOL value calculator does not support projections")
+            }
+            Self::InvalidProjection => f.write_str("invalid or overflowing projection"),
         }
     }
 }
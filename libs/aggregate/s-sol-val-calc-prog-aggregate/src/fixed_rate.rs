@@ -0,0 +1,113 @@
+use fixed_rate_calculator_lib::{
+    account_resolvers::LstSolCommonFreeArgs, pda::LstRateFindPdaArgs, utils::try_lst_rate,
+    FixedRateCalc,
+};
+use sanctum_token_ratio::U64ValueRange;
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+
+#[derive(Clone, Debug, Default)]
+pub struct FixedRateLstSolValCalc {
+    pub lst_mint: Pubkey,
+    pub lst_rate_addr: Pubkey,
+    pub calc: Option<FixedRateCalc>,
+}
+
+impl FixedRateLstSolValCalc {
+    pub fn from_lst_mint(lst_mint: Pubkey) -> Self {
+        let (lst_rate_addr, _bump) = LstRateFindPdaArgs {
+            program_id: fixed_rate_calculator_lib::program::ID,
+            lst_mint,
+        }
+        .get_lst_rate_address_and_bump_seed();
+        Self {
+            lst_mint,
+            lst_rate_addr,
+            calc: None,
+        }
+    }
+}
+
+impl MutableLstSolValCalc for FixedRateLstSolValCalc {
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        vec![self.lst_rate_addr]
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        if let Some(acc) = account_map.get(&self.lst_rate_addr) {
+            let lst_rate = *try_lst_rate(&acc.data())?;
+            self.calc = Some(lst_rate.into());
+        }
+        Ok(())
+    }
+}
+
+impl LstSolValCalc for FixedRateLstSolValCalc {
+    fn sol_value_calculator_program_id(&self) -> Pubkey {
+        fixed_rate_calculator_lib::program::ID
+    }
+
+    fn lst_mint(&self) -> Pubkey {
+        self.lst_mint
+    }
+
+    fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self
+            .calc
+            .ok_or(FixedRateLstSolValCalcErr::LstRateNotFetched)?;
+        Ok(calc.calc_lst_to_sol(lst_amount)?)
+    }
+
+    fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self
+            .calc
+            .ok_or(FixedRateLstSolValCalcErr::LstRateNotFetched)?;
+        Ok(calc.calc_sol_to_lst(lamports)?)
+    }
+
+    fn ix_accounts(&self) -> Vec<AccountMeta> {
+        Vec::from(
+            LstSolCommonFreeArgs {
+                lst_mint: self.lst_mint,
+            }
+            .resolve_to_account_metas(),
+        )
+    }
+
+    fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
+        self.calc.as_ref().map(|c| c as &dyn SolValueCalculator)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum FixedRateLstSolValCalcErr {
+    LstRateNotFetched,
+}
+
+impl Display for FixedRateLstSolValCalcErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LstRateNotFetched => f.write_str("LstRate not yet fetched"),
+        }
+    }
+}
+
+impl Error for FixedRateLstSolValCalcErr {}
+
+impl TryFrom<KnownLstSolValCalc> for FixedRateLstSolValCalc {
+    type Error = LstSolValCalcErr;
+
+    fn try_from(value: KnownLstSolValCalc) -> Result<Self, Self::Error> {
+        match value {
+            KnownLstSolValCalc::FixedRate(s) => Ok(s),
+            _ => Err(LstSolValCalcErr::WrongLstSolValCalc),
+        }
+    }
+}
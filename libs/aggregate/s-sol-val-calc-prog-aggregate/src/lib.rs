@@ -3,20 +3,33 @@
 
 use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_readonly_account::ReadonlyAccountData;
+use spl_calculator_lib::{
+    sanctum_spl_multi_sol_val_calc_program, sanctum_spl_sol_val_calc_program,
+};
 use std::collections::HashMap;
 
+mod discovery;
 mod err;
+#[cfg(feature = "testing")]
+mod fixed_rate;
 mod lido;
 mod marinade;
+mod projection;
+mod registry;
 mod sanctum_spl;
 mod sanctum_spl_multi;
 mod spl;
 mod traits;
 mod wsol;
 
+pub use discovery::*;
 pub use err::*;
+#[cfg(feature = "testing")]
+pub use fixed_rate::*;
 pub use lido::*;
 pub use marinade::*;
+pub use projection::*;
+pub use registry::*;
 pub use sanctum_spl::*;
 pub use sanctum_spl_multi::*;
 pub use spl::*;
@@ -31,6 +44,37 @@ pub enum KnownLstSolValCalc {
     SanctumSpl(SanctumSplLstSolValCalc),
     Wsol(WsolLstSolValCalc),
     SanctumSplMulti(SanctumSplMultiLstSolValCalc),
+    #[cfg(feature = "testing")]
+    FixedRate(FixedRateLstSolValCalc),
+}
+
+impl KnownLstSolValCalc {
+    /// Errors with [`LstSolValCalcErr::UnknownLstSolValCalc`] if `program_id` is not one of
+    /// the SOL value calculator programs in this enum
+    pub fn try_new(
+        program_id: Pubkey,
+        args: LstSolValCalcInitArgs,
+    ) -> Result<Self, LstSolValCalcErr> {
+        Ok(match program_id {
+            lido_calculator_lib::program::ID => LidoLstSolValCalc::default().into(),
+            marinade_calculator_lib::program::ID => MarinadeLstSolValCalc::default().into(),
+            wsol_calculator_lib::program::ID => WsolLstSolValCalc.into(),
+            spl_calculator_lib::program::ID => {
+                SplLstSolValCalc::from_keys(args.spl_init_keys()?).into()
+            }
+            sanctum_spl_sol_val_calc_program::ID => {
+                SanctumSplLstSolValCalc::from_keys(args.spl_init_keys()?).into()
+            }
+            sanctum_spl_multi_sol_val_calc_program::ID => {
+                SanctumSplMultiLstSolValCalc::from_keys(args.spl_init_keys()?).into()
+            }
+            #[cfg(feature = "testing")]
+            fixed_rate_calculator_lib::program::ID => {
+                FixedRateLstSolValCalc::from_lst_mint(args.lst_mint).into()
+            }
+            _ => return Err(LstSolValCalcErr::UnknownLstSolValCalc),
+        })
+    }
 }
 
 impl MutableLstSolValCalc for KnownLstSolValCalc {
@@ -42,6 +86,8 @@ impl MutableLstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.get_accounts_to_update(),
             Self::Wsol(s) => s.get_accounts_to_update(),
             Self::SanctumSplMulti(s) => s.get_accounts_to_update(),
+            #[cfg(feature = "testing")]
+            Self::FixedRate(s) => s.get_accounts_to_update(),
         }
     }
 
@@ -56,6 +102,8 @@ impl MutableLstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.update(account_map),
             Self::Wsol(s) => s.update(account_map),
             Self::SanctumSplMulti(s) => s.update(account_map),
+            #[cfg(feature = "testing")]
+            Self::FixedRate(s) => s.update(account_map),
         }
     }
 }
@@ -69,6 +117,8 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.sol_value_calculator_program_id(),
             Self::Wsol(s) => s.sol_value_calculator_program_id(),
             Self::SanctumSplMulti(s) => s.sol_value_calculator_program_id(),
+            #[cfg(feature = "testing")]
+            Self::FixedRate(s) => s.sol_value_calculator_program_id(),
         }
     }
 
@@ -79,7 +129,9 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::Spl(s) => s.lst_mint(),
             Self::SanctumSpl(s) => s.lst_mint(),
             Self::Wsol(s) => s.lst_mint(),
-            Self::SanctumSplMulti(s) => s.sol_value_calculator_program_id(),
+            Self::SanctumSplMulti(s) => s.lst_mint(),
+            #[cfg(feature = "testing")]
+            Self::FixedRate(s) => s.lst_mint(),
         }
     }
 
@@ -91,6 +143,8 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.lst_to_sol(lst_amount),
             Self::Wsol(s) => s.lst_to_sol(lst_amount),
             Self::SanctumSplMulti(s) => s.lst_to_sol(lst_amount),
+            #[cfg(feature = "testing")]
+            Self::FixedRate(s) => s.lst_to_sol(lst_amount),
         }
     }
 
@@ -102,6 +156,8 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.sol_to_lst(lamports),
             Self::Wsol(s) => s.sol_to_lst(lamports),
             Self::SanctumSplMulti(s) => s.sol_to_lst(lamports),
+            #[cfg(feature = "testing")]
+            Self::FixedRate(s) => s.sol_to_lst(lamports),
         }
     }
 
@@ -113,6 +169,8 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.ix_accounts(),
             Self::Wsol(s) => s.ix_accounts(),
             Self::SanctumSplMulti(s) => s.ix_accounts(),
+            #[cfg(feature = "testing")]
+            Self::FixedRate(s) => s.ix_accounts(),
         }
     }
 
@@ -124,6 +182,21 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.sol_value_calculator(),
             Self::Wsol(s) => s.sol_value_calculator(),
             Self::SanctumSplMulti(s) => s.sol_value_calculator(),
+            #[cfg(feature = "testing")]
+            Self::FixedRate(s) => s.sol_value_calculator(),
+        }
+    }
+
+    fn project(&mut self, projection: &LstSolValProjection) -> anyhow::Result<()> {
+        match self {
+            Self::Lido(s) => s.project(projection),
+            Self::Marinade(s) => s.project(projection),
+            Self::Spl(s) => s.project(projection),
+            Self::SanctumSpl(s) => s.project(projection),
+            Self::Wsol(s) => s.project(projection),
+            Self::SanctumSplMulti(s) => s.project(projection),
+            #[cfg(feature = "testing")]
+            Self::FixedRate(s) => s.project(projection),
         }
     }
 }
@@ -163,3 +236,10 @@ impl From<WsolLstSolValCalc> for KnownLstSolValCalc {
         Self::Wsol(value)
     }
 }
+
+#[cfg(feature = "testing")]
+impl From<FixedRateLstSolValCalc> for KnownLstSolValCalc {
+    fn from(value: FixedRateLstSolValCalc) -> Self {
+        Self::FixedRate(value)
+    }
+}
@@ -9,7 +9,9 @@ use solana_readonly_account::ReadonlyAccountData;
 use spl_calculator_lib::resolve_to_account_metas_for_calc;
 use std::{collections::HashMap, error::Error, fmt::Display};
 
-use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+use crate::{
+    KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, LstSolValProjection, MutableLstSolValCalc,
+};
 
 #[derive(Clone, Debug, Default)]
 pub struct LidoLstSolValCalc {
@@ -74,6 +76,24 @@ impl LstSolValCalc for LidoLstSolValCalc {
     fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
         self.calc.as_ref().map(|c| c as &dyn SolValueCalculator)
     }
+
+    fn project(&mut self, projection: &LstSolValProjection) -> anyhow::Result<()> {
+        let calc = self
+            .calc
+            .as_mut()
+            .ok_or(LidoLstSolValCalcErr::StateNotFetched)?;
+        let clock = self
+            .clock
+            .as_mut()
+            .ok_or(LidoLstSolValCalcErr::ClockNotFetched)?;
+        let sol_balance = projection.projected_total_lamports(calc.sol_balance)?;
+        let epoch = projection.projected_epoch(clock.epoch)?;
+        calc.sol_balance = sol_balance;
+        // exchange rate is assumed to be updated in the projected epoch
+        calc.computed_in_epoch = epoch;
+        clock.epoch = epoch;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -11,7 +11,9 @@ use solana_readonly_account::ReadonlyAccountData;
 use spl_calculator_lib::resolve_to_account_metas_for_calc;
 use std::{collections::HashMap, error::Error, fmt::Display};
 
-use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+use crate::{
+    KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, LstSolValProjection, MutableLstSolValCalc,
+};
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct MarinadeLstSolValCalc {
@@ -66,6 +68,24 @@ impl LstSolValCalc for MarinadeLstSolValCalc {
     fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
         self.calc.as_ref().map(|c| c as &dyn SolValueCalculator)
     }
+
+    /// Applies the change in total SOL to `total_active_balance`.
+    /// Marinade has no per-epoch update requirement, so the projected epoch is not tracked.
+    fn project(&mut self, projection: &LstSolValProjection) -> anyhow::Result<()> {
+        let calc = self
+            .calc
+            .as_mut()
+            .ok_or(MarinadeLstSolValCalcErr::StateNotFetched)?;
+        let total = calc
+            .total_virtual_staked_lamports()
+            .ok_or(LstSolValCalcErr::InvalidProjection)?;
+        let growth = projection.projected_total_lamports(total)? - total;
+        calc.total_active_balance = calc
+            .total_active_balance
+            .checked_add(growth)
+            .ok_or(LstSolValCalcErr::InvalidProjection)?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,58 @@
+use crate::LstSolValCalcErr;
+
+/// Approximate number of epochs in a year,
+/// assuming 432,000 slot epochs at 400ms slots (2 days per epoch)
+pub const EPOCHS_PER_YEAR: f64 = 182.625;
+
+/// Projected future state of an LST's stake pool,
+/// applied with [`crate::LstSolValCalc::project`] to estimate SOL values
+/// after the stake pool has been cranked for upcoming epochs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LstSolValProjection {
+    /// Stake pool cranked `epochs` epochs ahead with `lamports` added to its total SOL
+    TotalLamportsBump { epochs: u64, lamports: u64 },
+
+    /// Stake pool cranked `epochs` epochs ahead with its total SOL compounding at `apy`.
+    /// `apy` is [0.0, inf), not [0, 100], so 0.07 (NOT 7.0) means 7%
+    Apy { epochs: u64, apy: f64 },
+}
+
+impl LstSolValProjection {
+    /// Projects the next epoch with `apy`
+    pub const fn next_epoch_apy(apy: f64) -> Self {
+        Self::Apy { epochs: 1, apy }
+    }
+
+    pub const fn epochs(&self) -> u64 {
+        match self {
+            Self::TotalLamportsBump { epochs, .. } | Self::Apy { epochs, .. } => *epochs,
+        }
+    }
+
+    pub fn projected_epoch(&self, epoch: u64) -> Result<u64, LstSolValCalcErr> {
+        epoch
+            .checked_add(self.epochs())
+            .ok_or(LstSolValCalcErr::InvalidProjection)
+    }
+
+    /// Returns the stake pool's total SOL after the projection, given its current `total_lamports`
+    pub fn projected_total_lamports(&self, total_lamports: u64) -> Result<u64, LstSolValCalcErr> {
+        match self {
+            Self::TotalLamportsBump { lamports, .. } => total_lamports
+                .checked_add(*lamports)
+                .ok_or(LstSolValCalcErr::InvalidProjection),
+            Self::Apy { epochs, apy } => {
+                if !apy.is_finite() || *apy < 0.0 {
+                    return Err(LstSolValCalcErr::InvalidProjection);
+                }
+                let growth = (1.0 + apy).powf(*epochs as f64 / EPOCHS_PER_YEAR);
+                let projected = (total_lamports as f64 * growth).floor();
+                // `as` saturates, so check for overflow beforehand
+                if projected >= u64::MAX as f64 {
+                    return Err(LstSolValCalcErr::InvalidProjection);
+                }
+                Ok((projected as u64).max(total_lamports))
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::ReadonlyAccountData;
+use spl_calculator_lib::{
+    sanctum_spl_multi_sol_val_calc_program, sanctum_spl_sol_val_calc_program,
+};
+
+use crate::{
+    KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc,
+    SplLstSolValCalcInitKeys,
+};
+
+/// Object-safe version of [`MutableLstSolValCalc`] + [`LstSolValCalc`]
+/// with [`MutableLstSolValCalc::update`]'s account type constrained to `D`.
+///
+/// Implement this for private SOL value calculator programs and register them
+/// with a [`LstSolValCalcRegistry`] to use them as trait objects.
+pub trait DynLstSolValCalc<D>: LstSolValCalc + Debug + Send + Sync {
+    /// See [`MutableLstSolValCalc::get_accounts_to_update`]
+    fn get_accounts_to_update(&self) -> Vec<Pubkey>;
+
+    /// See [`MutableLstSolValCalc::update`]
+    fn update(&mut self, account_map: &HashMap<Pubkey, D>) -> anyhow::Result<()>;
+
+    fn clone_boxed(&self) -> Box<dyn DynLstSolValCalc<D>>;
+}
+
+impl<D> Clone for Box<dyn DynLstSolValCalc<D>> {
+    fn clone(&self) -> Self {
+        self.clone_boxed()
+    }
+}
+
+impl<D: ReadonlyAccountData> DynLstSolValCalc<D> for KnownLstSolValCalc {
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        MutableLstSolValCalc::get_accounts_to_update(self)
+    }
+
+    fn update(&mut self, account_map: &HashMap<Pubkey, D>) -> anyhow::Result<()> {
+        MutableLstSolValCalc::update(self, account_map)
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DynLstSolValCalc<D>> {
+        Box::new(self.clone())
+    }
+}
+
+/// Data required to initialize the SOL value calculator of a single LST
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LstSolValCalcInitArgs {
+    pub lst_mint: Pubkey,
+
+    /// Address of the LST's stake pool account.
+    /// Only required for stake pool programs with multiple stake pools.
+    pub stake_pool_addr: Option<Pubkey>,
+}
+
+impl LstSolValCalcInitArgs {
+    pub fn spl_init_keys(&self) -> Result<SplLstSolValCalcInitKeys, LstSolValCalcErr> {
+        Ok(SplLstSolValCalcInitKeys {
+            lst_mint: self.lst_mint,
+            stake_pool_addr: self
+                .stake_pool_addr
+                .ok_or(LstSolValCalcErr::MissingStakePoolAddr)?,
+        })
+    }
+}
+
+/// Creates the SOL value calculator of a single LST
+pub type LstSolValCalcFactory<D> = Box<
+    dyn Fn(LstSolValCalcInitArgs) -> anyhow::Result<Box<dyn DynLstSolValCalc<D>>> + Send + Sync,
+>;
+
+/// Maps SOL value calculator program IDs to the factories that create their [`DynLstSolValCalc`]s.
+///
+/// [`Default`] registers all SOL value calculator programs in [`KnownLstSolValCalc`].
+pub struct LstSolValCalcRegistry<D> {
+    factories: HashMap<Pubkey, LstSolValCalcFactory<D>>,
+}
+
+impl<D> LstSolValCalcRegistry<D> {
+    /// A registry with no SOL value calculator programs registered, not even the built-in ones
+    pub fn empty() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Registers `factory` for `program_id`, returning the previously registered factory if any
+    pub fn register<F>(&mut self, program_id: Pubkey, factory: F) -> Option<LstSolValCalcFactory<D>>
+    where
+        F: Fn(LstSolValCalcInitArgs) -> anyhow::Result<Box<dyn DynLstSolValCalc<D>>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.factories.insert(program_id, Box::new(factory))
+    }
+
+    pub fn unregister(&mut self, program_id: &Pubkey) -> Option<LstSolValCalcFactory<D>> {
+        self.factories.remove(program_id)
+    }
+
+    pub fn is_registered(&self, program_id: &Pubkey) -> bool {
+        self.factories.contains_key(program_id)
+    }
+
+    pub fn program_ids(&self) -> impl Iterator<Item = &Pubkey> {
+        self.factories.keys()
+    }
+
+    /// Errors with [`LstSolValCalcErr::UnknownLstSolValCalc`] if `program_id` is not registered
+    pub fn try_new_lst_sol_val_calc(
+        &self,
+        program_id: Pubkey,
+        args: LstSolValCalcInitArgs,
+    ) -> anyhow::Result<Box<dyn DynLstSolValCalc<D>>> {
+        let factory = self
+            .factories
+            .get(&program_id)
+            .ok_or(LstSolValCalcErr::UnknownLstSolValCalc)?;
+        factory(args)
+    }
+}
+
+impl<D: ReadonlyAccountData + 'static> LstSolValCalcRegistry<D> {
+    /// Registers a factory that creates the [`KnownLstSolValCalc`] returned by `f`
+    pub fn register_known_lst_sol_val_calc<F>(
+        &mut self,
+        program_id: Pubkey,
+        f: F,
+    ) -> Option<LstSolValCalcFactory<D>>
+    where
+        F: Fn(LstSolValCalcInitArgs) -> Result<KnownLstSolValCalc, LstSolValCalcErr>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register(program_id, move |args| {
+            let calc: Box<dyn DynLstSolValCalc<D>> = Box::new(f(args)?);
+            Ok(calc)
+        })
+    }
+}
+
+impl<D: ReadonlyAccountData + 'static> Default for LstSolValCalcRegistry<D> {
+    fn default() -> Self {
+        let mut res = Self::empty();
+        for program_id in [
+            lido_calculator_lib::program::ID,
+            marinade_calculator_lib::program::ID,
+            wsol_calculator_lib::program::ID,
+            spl_calculator_lib::program::ID,
+            sanctum_spl_sol_val_calc_program::ID,
+            sanctum_spl_multi_sol_val_calc_program::ID,
+            #[cfg(feature = "testing")]
+            fixed_rate_calculator_lib::program::ID,
+        ] {
+            res.register_known_lst_sol_val_calc(program_id, move |args| {
+                KnownLstSolValCalc::try_new(program_id, args)
+            });
+        }
+        res
+    }
+}
+
+impl<D> Debug for LstSolValCalcRegistry<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LstSolValCalcRegistry")
+            .field("program_ids", &self.factories.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
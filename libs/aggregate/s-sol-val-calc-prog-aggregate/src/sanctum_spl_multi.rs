@@ -11,8 +11,8 @@ use spl_calculator_lib::{
 use std::collections::HashMap;
 
 use crate::{
-    KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc, SplLstSolValCalc,
-    SplLstSolValCalcInitKeys,
+    KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, LstSolValProjection, MutableLstSolValCalc,
+    SplLstSolValCalc, SplLstSolValCalcInitKeys,
 };
 
 #[derive(Clone, Debug, Default)]
@@ -82,6 +82,10 @@ impl LstSolValCalc for SanctumSplMultiLstSolValCalc {
     fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
         self.0.sol_value_calculator()
     }
+
+    fn project(&mut self, projection: &LstSolValProjection) -> anyhow::Result<()> {
+        self.0.project(projection)
+    }
 }
 
 impl TryFrom<KnownLstSolValCalc> for SanctumSplMultiLstSolValCalc {
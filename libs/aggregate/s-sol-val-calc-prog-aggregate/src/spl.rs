@@ -14,7 +14,9 @@ use spl_calculator_lib::{
 };
 use std::{collections::HashMap, error::Error, fmt::Display};
 
-use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+use crate::{
+    KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, LstSolValProjection, MutableLstSolValCalc,
+};
 
 #[derive(Clone, Debug, Default)]
 pub struct SplLstSolValCalc {
@@ -123,6 +125,24 @@ impl LstSolValCalc for SplLstSolValCalc {
     fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
         self.calc.as_ref().map(|c| c as &dyn SolValueCalculator)
     }
+
+    fn project(&mut self, projection: &LstSolValProjection) -> anyhow::Result<()> {
+        let calc = self
+            .calc
+            .as_mut()
+            .ok_or(SplLstSolValCalcErr::StakePoolNotFetched)?;
+        let clock = self
+            .clock
+            .as_mut()
+            .ok_or(SplLstSolValCalcErr::ClockNotFetched)?;
+        let total_lamports = projection.projected_total_lamports(calc.total_lamports)?;
+        let epoch = projection.projected_epoch(clock.epoch)?;
+        calc.total_lamports = total_lamports;
+        // stake pool is assumed to be cranked in the projected epoch
+        calc.last_update_epoch = epoch;
+        clock.epoch = epoch;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -5,6 +5,8 @@ use sol_value_calculator_lib::SolValueCalculator;
 use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_readonly_account::ReadonlyAccountData;
 
+use crate::{LstSolValCalcErr, LstSolValProjection};
+
 /// Split from [`LstSolValCalc`] to make [`LstSolValCalc`] object-safe.
 ///
 /// Example to introduce a new trait to make it object-safe by constraining the generics to concrete types:
@@ -69,4 +71,13 @@ pub trait LstSolValCalc {
     /// Returns the underlying [`SolValueCalculator`]` if it has been initialized,
     /// otherwise `None`.
     fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator>;
+
+    /// Applies `projection` to this calculator's state in place so that subsequent
+    /// [`Self::lst_to_sol`] and [`Self::sol_to_lst`] calls return projected values.
+    ///
+    /// Call on a clone to keep the current state.
+    /// Errors with [`LstSolValCalcErr::ProjectionUnsupported`] by default.
+    fn project(&mut self, _projection: &LstSolValProjection) -> anyhow::Result<()> {
+        Err(LstSolValCalcErr::ProjectionUnsupported.into())
+    }
 }
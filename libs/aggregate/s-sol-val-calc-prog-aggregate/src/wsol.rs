@@ -6,7 +6,9 @@ use std::collections::HashMap;
 use wsol_calculator_lib::{WsolSolCalc, WSOL_LST_SOL_COMMON_METAS};
 use wsol_keys::wsol;
 
-use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+use crate::{
+    KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, LstSolValProjection, MutableLstSolValCalc,
+};
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct WsolLstSolValCalc;
@@ -48,6 +50,11 @@ impl LstSolValCalc for WsolLstSolValCalc {
     fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
         Some(&WsolSolCalc)
     }
+
+    /// wSOL is always worth exactly 1 SOL, so projections are no-ops
+    fn project(&mut self, _projection: &LstSolValProjection) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 impl TryFrom<KnownLstSolValCalc> for WsolLstSolValCalc {
@@ -46,7 +46,7 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> AddLstFreeArgs<S> {
         }
 
         let bytes = &state_acc.data();
-        let state: &ProgramState = try_program_state(bytes)?;
+        let state: ProgramState = try_program_state(bytes)?;
 
         let find_pda_args = FeeAccountFindPdaArgs {
             lst_mint,
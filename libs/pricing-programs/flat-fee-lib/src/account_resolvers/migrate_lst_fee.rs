@@ -0,0 +1,48 @@
+use flat_fee_interface::{FlatFeeError, MigrateLstFeeKeys, ProgramState};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{pda::ProgramStateFindPdaArgs, program as flat_fee_program, utils::try_program_state};
+
+pub struct MigrateLstFeeFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub payer: Pubkey,
+    pub fee_acc: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> MigrateLstFeeFreeArgs<S> {
+    pub fn resolve(self) -> Result<MigrateLstFeeKeys, FlatFeeError> {
+        self.resolve_inner(flat_fee_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<MigrateLstFeeKeys, FlatFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<MigrateLstFeeKeys, FlatFeeError> {
+        let MigrateLstFeeFreeArgs {
+            payer,
+            fee_acc,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(FlatFeeError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: ProgramState = try_program_state(bytes)?;
+
+        Ok(MigrateLstFeeKeys {
+            manager: state.manager,
+            payer,
+            fee_acc,
+            state: state_id,
+            system_program: system_program::ID,
+        })
+    }
+}
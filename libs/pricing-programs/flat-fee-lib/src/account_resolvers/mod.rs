@@ -1,19 +1,41 @@
+mod accept_manager;
 mod add_lst;
+mod add_pair_fee;
 mod initialize;
+mod migrate_lst_fee;
+mod migrate_state;
 mod price_exact_in;
 mod price_exact_out;
+mod price_lp_tokens_to_mint;
 mod price_lp_tokens_to_redeem;
 mod remove_lst;
+mod remove_pair_fee;
+mod set_epoch_surcharge;
+mod set_lp_deposit_fee;
 mod set_lp_withdrawal_fee;
 mod set_lst_fee;
+mod set_lst_fee_ramp;
+mod set_lst_lp_deposit_fee;
 mod set_manager;
+mod set_pair_fee;
 
+pub use accept_manager::*;
 pub use add_lst::*;
+pub use add_pair_fee::*;
 pub use initialize::*;
+pub use migrate_lst_fee::*;
+pub use migrate_state::*;
 pub use price_exact_in::*;
 pub use price_exact_out::*;
+pub use price_lp_tokens_to_mint::*;
 pub use price_lp_tokens_to_redeem::*;
 pub use remove_lst::*;
+pub use remove_pair_fee::*;
+pub use set_epoch_surcharge::*;
+pub use set_lp_deposit_fee::*;
 pub use set_lp_withdrawal_fee::*;
 pub use set_lst_fee::*;
+pub use set_lst_fee_ramp::*;
+pub use set_lst_lp_deposit_fee::*;
 pub use set_manager::*;
+pub use set_pair_fee::*;
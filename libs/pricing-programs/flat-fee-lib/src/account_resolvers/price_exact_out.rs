@@ -5,7 +5,10 @@ use solana_program::{
 };
 
 use crate::{
-    pda::{FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs},
+    pda::{
+        FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs, PairFeeAccountCreatePdaArgs,
+        PairFeeAccountFindPdaArgs, ProgramStateFindPdaArgs,
+    },
     program as flat_fee_program,
 };
 
@@ -19,14 +22,18 @@ pub struct PriceExactOutFreeArgs {
 
 impl PriceExactOutFreeArgs {
     pub fn resolve(self) -> PriceExactOutKeys {
-        self.resolve_inner(flat_fee_program::ID)
+        self.resolve_inner(flat_fee_program::STATE_ID, flat_fee_program::ID)
     }
 
     pub fn resolve_for_prog(self, program_id: Pubkey) -> PriceExactOutKeys {
-        self.resolve_inner(program_id)
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
     }
 
-    fn resolve_inner(self, program_id: Pubkey) -> PriceExactOutKeys {
+    fn resolve_inner(self, state_id: Pubkey, program_id: Pubkey) -> PriceExactOutKeys {
         let input_find_pda_args = FeeAccountFindPdaArgs {
             lst_mint: self.input_lst_mint,
             program_id,
@@ -44,6 +51,7 @@ impl PriceExactOutFreeArgs {
             output_lst_mint: self.output_lst_mint,
             input_fee_acc,
             output_fee_acc,
+            state: state_id,
         }
     }
 
@@ -57,18 +65,72 @@ pub struct PriceExactOutWithBumpFreeArgs {
     pub args: PriceExactOutFreeArgs,
     pub input_fee_acc_bump: u8,
     pub output_fee_acc_bump: u8,
+
+    /// Bump of the pair's PairFeeAccount, None if it does not exist.
+    ///
+    /// The PairFeeAccount is an optional trailing account that is not part of
+    /// [`PriceExactOutKeys`], use [`Self::resolve_to_account_metas`] to include it.
+    pub pair_fee_acc_bump: Option<u8>,
 }
 
 impl PriceExactOutWithBumpFreeArgs {
     pub fn resolve(self) -> Result<PriceExactOutKeys, PubkeyError> {
-        self.resolve_inner(flat_fee_program::ID)
+        self.resolve_inner(flat_fee_program::STATE_ID, flat_fee_program::ID)
     }
 
     pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<PriceExactOutKeys, PubkeyError> {
-        self.resolve_inner(program_id)
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    /// Same as [`Self::resolve`], but also appends the pair's PairFeeAccount if `pair_fee_acc_bump` is set
+    pub fn resolve_to_account_metas(self) -> Result<Vec<AccountMeta>, PubkeyError> {
+        let pair_fee_acc_meta = self.pair_fee_acc_meta(flat_fee_program::ID)?;
+        let mut metas: Vec<AccountMeta> =
+            <[AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]>::from(self.resolve()?).into();
+        metas.extend(pair_fee_acc_meta);
+        Ok(metas)
+    }
+
+    /// Same as [`Self::resolve_for_prog`], but also appends the pair's PairFeeAccount if `pair_fee_acc_bump` is set
+    pub fn resolve_to_account_metas_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<Vec<AccountMeta>, PubkeyError> {
+        let pair_fee_acc_meta = self.pair_fee_acc_meta(program_id)?;
+        let mut metas: Vec<AccountMeta> = <[AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]>::from(
+            self.resolve_for_prog(program_id)?,
+        )
+        .into();
+        metas.extend(pair_fee_acc_meta);
+        Ok(metas)
+    }
+
+    fn pair_fee_acc_meta(&self, program_id: Pubkey) -> Result<Option<AccountMeta>, PubkeyError> {
+        self.pair_fee_acc_bump
+            .map(|bump| {
+                PairFeeAccountCreatePdaArgs {
+                    find_pda_args: PairFeeAccountFindPdaArgs {
+                        program_id,
+                        input_lst_mint: self.args.input_lst_mint,
+                        output_lst_mint: self.args.output_lst_mint,
+                    },
+                    bump,
+                }
+                .get_pair_fee_account_address()
+                .map(|pair_fee_acc| AccountMeta::new_readonly(pair_fee_acc, false))
+            })
+            .transpose()
     }
 
-    fn resolve_inner(self, program_id: Pubkey) -> Result<PriceExactOutKeys, PubkeyError> {
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<PriceExactOutKeys, PubkeyError> {
         let input_create_pda_args = FeeAccountCreatePdaArgs {
             find_pda_args: FeeAccountFindPdaArgs {
                 lst_mint: self.args.input_lst_mint,
@@ -92,6 +154,7 @@ impl PriceExactOutWithBumpFreeArgs {
             output_lst_mint: self.args.output_lst_mint,
             input_fee_acc,
             output_fee_acc,
+            state: state_id,
         })
     }
 }
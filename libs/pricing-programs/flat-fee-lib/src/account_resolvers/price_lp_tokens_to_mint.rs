@@ -0,0 +1,95 @@
+use flat_fee_interface::{PriceLpTokensToMintKeys, PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN};
+use solana_program::{
+    instruction::AccountMeta,
+    pubkey::{Pubkey, PubkeyError},
+};
+
+use crate::{
+    pda::{FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
+    program as flat_fee_program,
+};
+
+/// Uses find_program_address, for use with
+/// - initial creation
+/// - client side
+pub struct PriceLpTokensToMintFreeArgs {
+    pub input_lst_mint: Pubkey,
+}
+
+impl PriceLpTokensToMintFreeArgs {
+    pub fn resolve(self) -> PriceLpTokensToMintKeys {
+        self.resolve_inner(flat_fee_program::STATE_ID, flat_fee_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> PriceLpTokensToMintKeys {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey, program_id: Pubkey) -> PriceLpTokensToMintKeys {
+        let find_pda_args = FeeAccountFindPdaArgs {
+            lst_mint: self.input_lst_mint,
+            program_id,
+        };
+        let (input_fee_acc, _bump) = find_pda_args.get_fee_account_address_and_bump_seed();
+
+        PriceLpTokensToMintKeys {
+            input_lst_mint: self.input_lst_mint,
+            state: state_id,
+            input_fee_acc,
+        }
+    }
+
+    pub fn resolve_to_account_metas(
+        self,
+    ) -> [AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN] {
+        let keys = self.resolve();
+        keys.into()
+    }
+}
+
+pub struct PriceLpTokensToMintWithBumpFreeArgs {
+    pub args: PriceLpTokensToMintFreeArgs,
+    pub input_fee_acc_bump: u8,
+}
+
+impl PriceLpTokensToMintWithBumpFreeArgs {
+    pub fn resolve(self) -> Result<PriceLpTokensToMintKeys, PubkeyError> {
+        self.resolve_inner(flat_fee_program::STATE_ID, flat_fee_program::ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<PriceLpTokensToMintKeys, PubkeyError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<PriceLpTokensToMintKeys, PubkeyError> {
+        let create_pda_args = FeeAccountCreatePdaArgs {
+            find_pda_args: FeeAccountFindPdaArgs {
+                lst_mint: self.args.input_lst_mint,
+                program_id,
+            },
+            bump: self.input_fee_acc_bump,
+        };
+        let input_fee_acc = create_pda_args.get_fee_account_address()?;
+
+        Ok(PriceLpTokensToMintKeys {
+            input_lst_mint: self.args.input_lst_mint,
+            state: state_id,
+            input_fee_acc,
+        })
+    }
+}
@@ -51,7 +51,7 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> RemoveLstFreeArgs<S> {
         let (fee_acc, _bump) = find_pda_args.get_fee_account_address_and_bump_seed();
 
         let bytes = &state_acc.data();
-        let state: &ProgramState = try_program_state(bytes)?;
+        let state: ProgramState = try_program_state(bytes)?;
 
         Ok(RemoveLstKeys {
             manager: state.manager,
@@ -32,7 +32,7 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetLpWithdrawalFeeFreeArgs<
         }
 
         let bytes = &state_acc.data();
-        let state: &ProgramState = try_program_state(bytes)?;
+        let state: ProgramState = try_program_state(bytes)?;
 
         Ok(SetLpWithdrawalFeeKeys {
             manager: state.manager,
@@ -33,7 +33,7 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetManagerFreeArgs<S> {
         }
 
         let bytes = &state_acc.data();
-        let state: &ProgramState = try_program_state(bytes)?;
+        let state: ProgramState = try_program_state(bytes)?;
 
         Ok(SetManagerKeys {
             current_manager: state.manager,
@@ -7,6 +7,15 @@ use super::BPS_DENOMINATOR_I16;
 pub struct OutSolValueRatioArgs {
     pub input_fee_bps: i16,
     pub output_fee_bps: i16,
+
+    /// Overrides `input_fee_bps + output_fee_bps` if set and lower.
+    ///
+    /// The PairFeeAccount is an optional account that swappers can omit,
+    /// so a pair fee can only ever lower the pair's fee.
+    pub pair_fee_bps: Option<i16>,
+
+    /// Added on top of the total fee, including pair_fee_bps
+    pub epoch_surcharge_bps: u16,
 }
 
 /// Returns the ratio that returns out_sol_value
@@ -15,11 +24,21 @@ pub fn out_sol_value_ratio(
     OutSolValueRatioArgs {
         input_fee_bps,
         output_fee_bps,
+        pair_fee_bps,
+        epoch_surcharge_bps,
     }: OutSolValueRatioArgs,
 ) -> Result<FloorDiv<U64Ratio<u16, u16>>, FlatFeeError> {
-    let fee_bps = input_fee_bps
+    let lst_fee_bps = input_fee_bps
         .checked_add(output_fee_bps)
         .ok_or(FlatFeeError::MathError)?;
+    let fee_bps = match pair_fee_bps {
+        Some(pair_fee_bps) => pair_fee_bps.min(lst_fee_bps),
+        None => lst_fee_bps,
+    };
+    let fee_bps = i16::try_from(epoch_surcharge_bps)
+        .ok()
+        .and_then(|surcharge_bps| fee_bps.checked_add(surcharge_bps))
+        .ok_or(FlatFeeError::MathError)?;
     // post_fee_bps = 10_000 - fee_bps
     // out_sol_value = floor(in_sol_value * post_fee_bps / 10_000)
     // i16 signed subtraction:
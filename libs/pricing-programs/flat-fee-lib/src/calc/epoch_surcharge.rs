@@ -0,0 +1,54 @@
+use flat_fee_interface::{FlatFeeError, ProgramState};
+use solana_program::epoch_schedule::EpochSchedule;
+
+/// The epoch surcharge is active if both its fee and window are nonzero.
+/// Zero-initialized ProgramStates have no epoch surcharge.
+pub fn has_epoch_surcharge(
+    ProgramState {
+        epoch_surcharge_bps,
+        epoch_surcharge_slots,
+        ..
+    }: &ProgramState,
+) -> bool {
+    *epoch_surcharge_bps != 0 && *epoch_surcharge_slots != 0
+}
+
+/// Returns true if `slot` is within the first or last `window_slots` slots of its epoch
+pub fn is_in_epoch_boundary_window(
+    epoch_schedule: &EpochSchedule,
+    slot: u64,
+    window_slots: u16,
+) -> bool {
+    let window_slots = u64::from(window_slots);
+    let epoch = epoch_schedule.get_epoch(slot);
+    let first_slot = epoch_schedule.get_first_slot_in_epoch(epoch);
+    let last_slot = epoch_schedule.get_last_slot_in_epoch(epoch);
+    slot - first_slot < window_slots || last_slot - slot < window_slots
+}
+
+/// Returns the surcharge in bips to add to all fees at the given slot.
+///
+/// `epoch_schedule` and `slot` are ignored if the ProgramState does not have an epoch surcharge
+pub fn epoch_surcharge_bps_at_slot(
+    state: &ProgramState,
+    epoch_schedule: &EpochSchedule,
+    slot: u64,
+) -> u16 {
+    if has_epoch_surcharge(state)
+        && is_in_epoch_boundary_window(epoch_schedule, slot, state.epoch_surcharge_slots)
+    {
+        state.epoch_surcharge_bps
+    } else {
+        0
+    }
+}
+
+/// Adds the epoch surcharge to an LP deposit or withdrawal fee
+pub fn lp_fee_bps_with_epoch_surcharge(
+    lp_fee_bps: u16,
+    epoch_surcharge_bps: u16,
+) -> Result<u16, FlatFeeError> {
+    lp_fee_bps
+        .checked_add(epoch_surcharge_bps)
+        .ok_or(FlatFeeError::MathError)
+}
@@ -0,0 +1,76 @@
+use flat_fee_interface::FeeAccount;
+
+/// The fees of a single LST
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LstFeeBps {
+    pub input_fee_bps: i16,
+    pub output_fee_bps: i16,
+}
+
+/// A FeeAccount's fee ramp is active if it ends after it starts.
+/// Zero-initialized FeeAccounts have no fee ramp.
+pub fn has_fee_ramp(
+    FeeAccount {
+        ramp_start_slot,
+        ramp_end_slot,
+        ..
+    }: &FeeAccount,
+) -> bool {
+    ramp_end_slot > ramp_start_slot
+}
+
+/// Returns the FeeAccount's fees at the given slot,
+/// linearly interpolating between `input_fee_bps`/`output_fee_bps` and the ramp's targets
+/// if the FeeAccount has a fee ramp.
+///
+/// `slot` is ignored if the FeeAccount does not have a fee ramp
+pub fn lst_fee_bps_at_slot(fee_acc: &FeeAccount, slot: u64) -> LstFeeBps {
+    let FeeAccount {
+        input_fee_bps,
+        output_fee_bps,
+        ramp_start_slot,
+        ramp_end_slot,
+        ramp_target_input_fee_bps,
+        ramp_target_output_fee_bps,
+        ..
+    } = *fee_acc;
+    if !has_fee_ramp(fee_acc) {
+        return LstFeeBps {
+            input_fee_bps,
+            output_fee_bps,
+        };
+    }
+    let ramp = FeeRamp {
+        start_slot: ramp_start_slot,
+        end_slot: ramp_end_slot,
+        slot,
+    };
+    LstFeeBps {
+        input_fee_bps: ramp.interpolate(input_fee_bps, ramp_target_input_fee_bps),
+        output_fee_bps: ramp.interpolate(output_fee_bps, ramp_target_output_fee_bps),
+    }
+}
+
+struct FeeRamp {
+    start_slot: u64,
+    end_slot: u64,
+    slot: u64,
+}
+
+impl FeeRamp {
+    /// Requires end_slot > start_slot
+    fn interpolate(&self, from_bps: i16, to_bps: i16) -> i16 {
+        if self.slot <= self.start_slot {
+            return from_bps;
+        }
+        if self.slot >= self.end_slot {
+            return to_bps;
+        }
+        // i128 to avoid overflow, result always lies between from_bps and to_bps so fits in i16.
+        // Rounds towards from_bps
+        let elapsed = i128::from(self.slot - self.start_slot);
+        let duration = i128::from(self.end_slot - self.start_slot);
+        let delta = (i128::from(to_bps) - i128::from(from_bps)) * elapsed / duration;
+        (i128::from(from_bps) + delta) as i16
+    }
+}
@@ -1,10 +1,16 @@
 mod common;
+mod epoch_surcharge;
+mod fee_ramp;
 mod price_exact_in;
 mod price_exact_out;
+mod price_lp_tokens_to_mint;
 mod price_lp_tokens_to_redeem;
 
+pub use epoch_surcharge::*;
+pub use fee_ramp::*;
 pub use price_exact_in::*;
 pub use price_exact_out::*;
+pub use price_lp_tokens_to_mint::*;
 pub use price_lp_tokens_to_redeem::*;
 
 const BPS_DENOMINATOR_I16: i16 = 10_000;
@@ -7,6 +7,13 @@ use super::common::{out_sol_value_ratio, OutSolValueRatioArgs};
 pub struct CalculatePriceExactInArgs {
     pub input_fee_bps: i16,
     pub output_fee_bps: i16,
+
+    /// fee_bps of the input-output pair's PairFeeAccount, if it was passed
+    pub pair_fee_bps: Option<i16>,
+
+    /// Surcharge in effect if within an epoch boundary window, else 0
+    pub epoch_surcharge_bps: u16,
+
     pub in_sol_value: u64,
 }
 
@@ -15,12 +22,16 @@ pub fn calculate_price_exact_in(
     CalculatePriceExactInArgs {
         input_fee_bps,
         output_fee_bps,
+        pair_fee_bps,
+        epoch_surcharge_bps,
         in_sol_value,
     }: CalculatePriceExactInArgs,
 ) -> Result<u64, FlatFeeError> {
     out_sol_value_ratio(OutSolValueRatioArgs {
         input_fee_bps,
         output_fee_bps,
+        pair_fee_bps,
+        epoch_surcharge_bps,
     })?
     .apply(in_sol_value)
     .map_err(|_e| FlatFeeError::MathError)
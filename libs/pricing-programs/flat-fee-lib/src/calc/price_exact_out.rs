@@ -7,6 +7,13 @@ use super::common::{out_sol_value_ratio, OutSolValueRatioArgs};
 pub struct CalculatePriceExactOutArgs {
     pub input_fee_bps: i16,
     pub output_fee_bps: i16,
+
+    /// fee_bps of the input-output pair's PairFeeAccount, if it was passed
+    pub pair_fee_bps: Option<i16>,
+
+    /// Surcharge in effect if within an epoch boundary window, else 0
+    pub epoch_surcharge_bps: u16,
+
     pub out_sol_value: u64,
 }
 
@@ -15,12 +22,16 @@ pub fn calculate_price_exact_out(
     CalculatePriceExactOutArgs {
         input_fee_bps,
         output_fee_bps,
+        pair_fee_bps,
+        epoch_surcharge_bps,
         out_sol_value,
     }: CalculatePriceExactOutArgs,
 ) -> Result<u64, FlatFeeError> {
     Ok(out_sol_value_ratio(OutSolValueRatioArgs {
         input_fee_bps,
         output_fee_bps,
+        pair_fee_bps,
+        epoch_surcharge_bps,
     })?
     .reverse(out_sol_value)
     .map_err(|_e| FlatFeeError::MathError)?
@@ -0,0 +1,23 @@
+use flat_fee_interface::{FeeAccount, FlatFeeError, ProgramState};
+use sanctum_token_ratio::{CeilDiv, ReversibleFee, U64BpsFee};
+
+/// Returns the input LST's FeeAccount's override if set,
+/// else the program's global lp_deposit_fee_bps
+pub fn effective_lp_deposit_fee_bps(state: &ProgramState, input_fee_acc: &FeeAccount) -> u16 {
+    if input_fee_acc.has_lp_deposit_fee_bps_override == 0 {
+        state.lp_deposit_fee_bps
+    } else {
+        input_fee_acc.lp_deposit_fee_bps_override
+    }
+}
+
+pub fn calculate_price_lp_tokens_to_mint(
+    lp_deposit_fee_bps: u16,
+    sol_value: u64,
+) -> Result<u64, FlatFeeError> {
+    U64BpsFee::try_new(lp_deposit_fee_bps)
+        .map(CeilDiv)
+        .and_then(|f| f.apply(sol_value))
+        .map(|aaf| aaf.amt_after_fee())
+        .map_err(|_e| FlatFeeError::MathError)
+}
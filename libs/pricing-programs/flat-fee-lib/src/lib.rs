@@ -5,8 +5,15 @@ pub mod pda;
 pub mod utils;
 
 pub mod program {
-    pub const STATE_SIZE: usize = 34;
-    pub const FEE_ACCOUNT_SIZE: usize = 6;
+    pub const STATE_SIZE: usize = 72;
+    /// Size of ProgramState before `pending_manager` and the fields after it were added.
+    /// Accounts initialized before then stay at this size until MigrateState is run on them.
+    pub const LEGACY_STATE_SIZE: usize = 34;
+    pub const FEE_ACCOUNT_SIZE: usize = 32;
+    /// Size of FeeAccount before the LP deposit fee override and fee ramp fields were added.
+    /// Accounts initialized before then stay at this size until MigrateLstFee is run on them.
+    pub const LEGACY_FEE_ACCOUNT_SIZE: usize = 6;
+    pub const PAIR_FEE_ACCOUNT_SIZE: usize = 68;
 
     static_assertions::const_assert_eq!(
         std::mem::size_of::<flat_fee_interface::ProgramState>(),
@@ -18,6 +25,11 @@ pub mod program {
         FEE_ACCOUNT_SIZE,
     );
 
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<flat_fee_interface::PairFeeAccount>(),
+        PAIR_FEE_ACCOUNT_SIZE,
+    );
+
     sanctum_macros::declare_program_keys!(
         "f1tUoNEKrDp1oeGn4zxr7bh41eN6VcfHjfrL3ZqQday",
         [("state", b"state")]
@@ -34,4 +46,10 @@ pub mod initial_constants {
     }
 
     pub const INITIAL_LP_WITHDRAWAL_FEE_BPS: u16 = 5;
+
+    pub const INITIAL_LP_DEPOSIT_FEE_BPS: u16 = 0;
+
+    pub const INITIAL_EPOCH_SURCHARGE_BPS: u16 = 0;
+
+    pub const INITIAL_EPOCH_SURCHARGE_SLOTS: u16 = 0;
 }
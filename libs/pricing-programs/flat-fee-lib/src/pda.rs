@@ -3,6 +3,7 @@ use solana_program::pubkey::{Pubkey, PubkeyError};
 use crate::program;
 
 pub const FEE_ACCOUNT_SEED_PREFIX: &[u8] = b"fee";
+pub const PAIR_FEE_ACCOUNT_SEED_PREFIX: &[u8] = b"pair";
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ProgramStateFindPdaArgs {
@@ -67,3 +68,46 @@ impl FeeAccountCreatePdaArgs {
         Pubkey::create_program_address(&self.to_signer_seeds(), &self.find_pda_args.program_id)
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PairFeeAccountFindPdaArgs {
+    pub program_id: Pubkey,
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+}
+
+impl PairFeeAccountFindPdaArgs {
+    pub fn to_seed(&self) -> [&[u8]; 3] {
+        [
+            PAIR_FEE_ACCOUNT_SEED_PREFIX,
+            self.input_lst_mint.as_ref(),
+            self.output_lst_mint.as_ref(),
+        ]
+    }
+
+    pub fn get_pair_fee_account_address_and_bump_seed(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&self.to_seed(), &self.program_id)
+    }
+}
+
+pub struct PairFeeAccountCreatePdaArgs {
+    pub find_pda_args: PairFeeAccountFindPdaArgs,
+    pub bump: u8,
+}
+
+impl PairFeeAccountCreatePdaArgs {
+    pub fn to_signer_seeds(&self) -> [&[u8]; 4] {
+        let [prefix, input_lst_mint, output_lst_mint] = self.find_pda_args.to_seed();
+
+        [
+            prefix,
+            input_lst_mint,
+            output_lst_mint,
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+
+    pub fn get_pair_fee_account_address(&self) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(&self.to_signer_seeds(), &self.find_pda_args.program_id)
+    }
+}
@@ -1,21 +1,59 @@
-use bytemuck::{try_from_bytes, try_from_bytes_mut};
+use bytemuck::{bytes_of_mut, try_from_bytes, try_from_bytes_mut, Zeroable};
 
-use flat_fee_interface::{FeeAccount, FlatFeeError, ProgramState};
+use flat_fee_interface::{FeeAccount, FlatFeeError, PairFeeAccount, ProgramState};
 
-pub fn try_program_state(program_state_acc_data: &[u8]) -> Result<&ProgramState, FlatFeeError> {
-    try_from_bytes(program_state_acc_data).map_err(|_e| FlatFeeError::InvalidProgramStateData)
+use crate::program::{LEGACY_FEE_ACCOUNT_SIZE, LEGACY_STATE_SIZE};
+
+/// Also accepts ProgramStates that have not been migrated yet,
+/// reading the fields missing from the legacy layout as zeroed
+pub fn try_program_state(program_state_acc_data: &[u8]) -> Result<ProgramState, FlatFeeError> {
+    if program_state_acc_data.len() == LEGACY_STATE_SIZE {
+        let mut state = ProgramState::zeroed();
+        bytes_of_mut(&mut state)[..LEGACY_STATE_SIZE].copy_from_slice(program_state_acc_data);
+        return Ok(state);
+    }
+    try_from_bytes(program_state_acc_data)
+        .copied()
+        .map_err(|_e| FlatFeeError::InvalidProgramStateData)
 }
 
+/// Errors with StateNotMigrated if the ProgramState still has the legacy layout
 pub fn try_program_state_mut(
     program_state_acc_data: &mut [u8],
 ) -> Result<&mut ProgramState, FlatFeeError> {
+    if program_state_acc_data.len() == LEGACY_STATE_SIZE {
+        return Err(FlatFeeError::StateNotMigrated);
+    }
     try_from_bytes_mut(program_state_acc_data).map_err(|_e| FlatFeeError::InvalidProgramStateData)
 }
 
-pub fn try_fee_account(fee_acc_data: &[u8]) -> Result<&FeeAccount, FlatFeeError> {
-    try_from_bytes(fee_acc_data).map_err(|_e| FlatFeeError::UnsupportedLstMint)
+/// Also accepts FeeAccounts that have not been migrated yet,
+/// reading the fields missing from the legacy layout as zeroed
+pub fn try_fee_account(fee_acc_data: &[u8]) -> Result<FeeAccount, FlatFeeError> {
+    if fee_acc_data.len() == LEGACY_FEE_ACCOUNT_SIZE {
+        let mut fee_acc = FeeAccount::zeroed();
+        bytes_of_mut(&mut fee_acc)[..LEGACY_FEE_ACCOUNT_SIZE].copy_from_slice(fee_acc_data);
+        return Ok(fee_acc);
+    }
+    try_from_bytes(fee_acc_data)
+        .copied()
+        .map_err(|_e| FlatFeeError::UnsupportedLstMint)
 }
 
+/// Errors with StateNotMigrated if the FeeAccount still has the legacy layout
 pub fn try_fee_account_mut(fee_acc_data: &mut [u8]) -> Result<&mut FeeAccount, FlatFeeError> {
+    if fee_acc_data.len() == LEGACY_FEE_ACCOUNT_SIZE {
+        return Err(FlatFeeError::StateNotMigrated);
+    }
     try_from_bytes_mut(fee_acc_data).map_err(|_e| FlatFeeError::UnsupportedLstMint)
 }
+
+pub fn try_pair_fee_account(pair_fee_acc_data: &[u8]) -> Result<&PairFeeAccount, FlatFeeError> {
+    try_from_bytes(pair_fee_acc_data).map_err(|_e| FlatFeeError::UnsupportedLstPair)
+}
+
+pub fn try_pair_fee_account_mut(
+    pair_fee_acc_data: &mut [u8],
+) -> Result<&mut PairFeeAccount, FlatFeeError> {
+    try_from_bytes_mut(pair_fee_acc_data).map_err(|_e| FlatFeeError::UnsupportedLstPair)
+}
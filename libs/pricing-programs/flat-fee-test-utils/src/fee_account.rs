@@ -1,10 +1,12 @@
 use flat_fee_interface::FeeAccount;
 use flat_fee_lib::{
-    pda::FeeAccountFindPdaArgs, program::FEE_ACCOUNT_SIZE, utils::try_fee_account_mut,
+    pda::FeeAccountFindPdaArgs,
+    program::{FEE_ACCOUNT_SIZE, LEGACY_FEE_ACCOUNT_SIZE},
+    utils::try_fee_account_mut,
 };
 use sanctum_solana_test_utils::{est_rent_exempt_lamports, IntoAccount};
 use solana_program::pubkey::Pubkey;
-use solana_sdk::account::Account;
+use solana_sdk::{account::Account, rent::Rent};
 
 pub struct MockFeeAccount(pub FeeAccount);
 
@@ -23,6 +25,35 @@ impl IntoAccount for MockFeeAccount {
     }
 }
 
+/// FeeAccount account with the legacy layout, with just enough lamports
+/// to be rent-exempt at the legacy size.
+///
+/// Only `bump`, `input_fee_bps` and `output_fee_bps` are written since the legacy layout
+/// does not have the other fields
+pub struct MockLegacyFeeAccount(pub FeeAccount);
+
+impl IntoAccount for MockLegacyFeeAccount {
+    fn into_account(self) -> Account {
+        let FeeAccount {
+            bump,
+            input_fee_bps,
+            output_fee_bps,
+            ..
+        } = self.0;
+        let mut data = Vec::with_capacity(LEGACY_FEE_ACCOUNT_SIZE);
+        data.extend_from_slice(&[bump, 0]);
+        data.extend_from_slice(&input_fee_bps.to_le_bytes());
+        data.extend_from_slice(&output_fee_bps.to_le_bytes());
+        Account {
+            lamports: Rent::default().minimum_balance(LEGACY_FEE_ACCOUNT_SIZE),
+            data,
+            owner: flat_fee_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
 pub struct MockFeeAccountArgs {
     pub input_fee_bps: i16,
     pub output_fee_bps: i16,
@@ -46,7 +77,13 @@ impl MockFeeAccountArgs {
                 input_fee_bps: *input_fee_bps,
                 output_fee_bps: *output_fee_bps,
                 bump,
-                padding: 0u8,
+                has_lp_deposit_fee_bps_override: 0,
+                lp_deposit_fee_bps_override: 0,
+                ramp_start_slot: 0,
+                ramp_end_slot: 0,
+                ramp_target_input_fee_bps: 0,
+                ramp_target_output_fee_bps: 0,
+                ramp_padding: [0; 4],
             },
             addr,
         )
@@ -3,12 +3,14 @@ use flat_fee_lib::program;
 use sanctum_solana_test_utils::{ExtendedProgramTest, IntoAccount};
 use solana_program_test::ProgramTest;
 
-use crate::MockProgramState;
+use crate::{MockLegacyProgramState, MockProgramState};
 
 pub trait FlatFeeProgramTest {
     fn add_mock_program_state_chained(self, program_state: ProgramState) -> Self;
 
     fn add_mock_program_state(&mut self, program_state: ProgramState);
+
+    fn add_mock_legacy_program_state(&mut self, program_state: ProgramState);
 }
 
 impl FlatFeeProgramTest for ProgramTest {
@@ -25,4 +27,11 @@ impl FlatFeeProgramTest for ProgramTest {
             MockProgramState(program_state).into_account(),
         );
     }
+
+    fn add_mock_legacy_program_state(&mut self, program_state: ProgramState) {
+        self.add_account(
+            program::STATE_ID,
+            MockLegacyProgramState(program_state).into_account(),
+        );
+    }
 }
@@ -1,7 +1,9 @@
 mod fee_account;
 mod flat_fee_program_test;
+mod pair_fee_account;
 mod state;
 
 pub use fee_account::*;
 pub use flat_fee_program_test::*;
+pub use pair_fee_account::*;
 pub use state::*;
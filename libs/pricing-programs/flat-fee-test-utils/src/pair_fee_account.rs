@@ -0,0 +1,56 @@
+use flat_fee_interface::PairFeeAccount;
+use flat_fee_lib::{
+    pda::PairFeeAccountFindPdaArgs, program::PAIR_FEE_ACCOUNT_SIZE, utils::try_pair_fee_account_mut,
+};
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, IntoAccount};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+
+pub struct MockPairFeeAccount(pub PairFeeAccount);
+
+impl IntoAccount for MockPairFeeAccount {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; PAIR_FEE_ACCOUNT_SIZE];
+        let dst = try_pair_fee_account_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(PAIR_FEE_ACCOUNT_SIZE),
+            data,
+            owner: flat_fee_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+pub struct MockPairFeeAccountArgs {
+    pub fee_bps: i16,
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+}
+
+impl MockPairFeeAccountArgs {
+    pub fn to_pair_fee_account_and_addr(&self, program_id: Pubkey) -> (PairFeeAccount, Pubkey) {
+        let Self {
+            fee_bps,
+            input_lst_mint,
+            output_lst_mint,
+        } = self;
+        let (addr, bump) = PairFeeAccountFindPdaArgs {
+            input_lst_mint: *input_lst_mint,
+            output_lst_mint: *output_lst_mint,
+            program_id,
+        }
+        .get_pair_fee_account_address_and_bump_seed();
+        (
+            PairFeeAccount {
+                fee_bps: *fee_bps,
+                bump,
+                padding: 0u8,
+                input_lst_mint: *input_lst_mint,
+                output_lst_mint: *output_lst_mint,
+            },
+            addr,
+        )
+    }
+}
@@ -1,17 +1,24 @@
 use async_trait::async_trait;
 use flat_fee_interface::ProgramState;
 use flat_fee_lib::{
-    initial_constants::{initial_manager, INITIAL_LP_WITHDRAWAL_FEE_BPS},
-    program::STATE_SIZE,
+    initial_constants::{
+        initial_manager, INITIAL_EPOCH_SURCHARGE_BPS, INITIAL_EPOCH_SURCHARGE_SLOTS,
+        INITIAL_LP_DEPOSIT_FEE_BPS, INITIAL_LP_WITHDRAWAL_FEE_BPS,
+    },
+    program::{LEGACY_STATE_SIZE, STATE_SIZE},
     utils::try_program_state_mut,
 };
 use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedBanksClient, IntoAccount};
 use solana_program_test::BanksClient;
-use solana_sdk::account::Account;
+use solana_sdk::{account::Account, pubkey::Pubkey, rent::Rent};
 
 pub const DEFAULT_PROGRAM_STATE: ProgramState = ProgramState {
     manager: initial_manager::ID,
     lp_withdrawal_fee_bps: INITIAL_LP_WITHDRAWAL_FEE_BPS,
+    pending_manager: Pubkey::new_from_array([0u8; 32]),
+    lp_deposit_fee_bps: INITIAL_LP_DEPOSIT_FEE_BPS,
+    epoch_surcharge_bps: INITIAL_EPOCH_SURCHARGE_BPS,
+    epoch_surcharge_slots: INITIAL_EPOCH_SURCHARGE_SLOTS,
 };
 
 pub struct MockProgramState(pub ProgramState);
@@ -31,6 +38,33 @@ impl IntoAccount for MockProgramState {
     }
 }
 
+/// ProgramState account with the legacy layout, with just enough lamports
+/// to be rent-exempt at the legacy size.
+///
+/// Only `manager` and `lp_withdrawal_fee_bps` are written since the legacy layout
+/// does not have the other fields
+pub struct MockLegacyProgramState(pub ProgramState);
+
+impl IntoAccount for MockLegacyProgramState {
+    fn into_account(self) -> Account {
+        let ProgramState {
+            manager,
+            lp_withdrawal_fee_bps,
+            ..
+        } = self.0;
+        let mut data = Vec::with_capacity(LEGACY_STATE_SIZE);
+        data.extend_from_slice(manager.as_ref());
+        data.extend_from_slice(&lp_withdrawal_fee_bps.to_le_bytes());
+        Account {
+            lamports: Rent::default().minimum_balance(LEGACY_STATE_SIZE),
+            data,
+            owner: flat_fee_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
 #[async_trait]
 pub trait FlatFeePricingProgramTestBanksClient {
     async fn get_flat_fee_program_state(&mut self) -> Account;
@@ -0,0 +1,72 @@
+use imbalance_fee_interface::{AddLstKeys, ImbalanceFeeError, ProgramState};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::{ProgramStateFindPdaArgs, TargetWeightCreatePdaArgs, TargetWeightFindPdaArgs},
+    program::{self, STATE_ID},
+    utils::try_program_state,
+};
+
+pub struct AddLstFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub payer: Pubkey,
+    pub state_acc: S,
+    pub lst_mint: Pubkey,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> AddLstFreeArgs<S> {
+    pub fn resolve(self) -> Result<(AddLstKeys, TargetWeightCreatePdaArgs), ImbalanceFeeError> {
+        self.resolve_inner(STATE_ID, program::ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<(AddLstKeys, TargetWeightCreatePdaArgs), ImbalanceFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    pub fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<(AddLstKeys, TargetWeightCreatePdaArgs), ImbalanceFeeError> {
+        let Self {
+            payer,
+            state_acc,
+            lst_mint,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(ImbalanceFeeError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        let find_pda_args = TargetWeightFindPdaArgs {
+            lst_mint,
+            program_id,
+        };
+        let (target_acc, bump) = find_pda_args.get_target_weight_address_and_bump_seed();
+
+        Ok((
+            AddLstKeys {
+                manager: state.manager,
+                payer,
+                target_acc,
+                lst_mint,
+                state: state_id,
+                system_program: system_program::ID,
+            },
+            TargetWeightCreatePdaArgs {
+                find_pda_args,
+                bump,
+            },
+        ))
+    }
+}
@@ -0,0 +1,115 @@
+use imbalance_fee_interface::{PriceExactInKeys, PRICE_EXACT_IN_IX_ACCOUNTS_LEN};
+use solana_program::{
+    instruction::AccountMeta,
+    pubkey::{Pubkey, PubkeyError},
+};
+
+use crate::{
+    pda::{ProgramStateFindPdaArgs, TargetWeightCreatePdaArgs, TargetWeightFindPdaArgs},
+    program as imbalance_fee_program,
+};
+
+/// Uses find_program_address, for use with
+/// - initial creation
+/// - client side
+pub struct PriceExactInFreeArgs {
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+}
+
+impl PriceExactInFreeArgs {
+    pub fn resolve(self) -> PriceExactInKeys {
+        self.resolve_inner(imbalance_fee_program::STATE_ID, imbalance_fee_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> PriceExactInKeys {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey, program_id: Pubkey) -> PriceExactInKeys {
+        let input_find_pda_args = TargetWeightFindPdaArgs {
+            lst_mint: self.input_lst_mint,
+            program_id,
+        };
+        let (input_target_acc, _bump) =
+            input_find_pda_args.get_target_weight_address_and_bump_seed();
+
+        let output_find_pda_args = TargetWeightFindPdaArgs {
+            lst_mint: self.output_lst_mint,
+            program_id,
+        };
+        let (output_target_acc, _bump) =
+            output_find_pda_args.get_target_weight_address_and_bump_seed();
+
+        PriceExactInKeys {
+            input_lst_mint: self.input_lst_mint,
+            output_lst_mint: self.output_lst_mint,
+            state: state_id,
+            lst_state_list: s_controller_lib::program::LST_STATE_LIST_ID,
+            input_target_acc,
+            output_target_acc,
+        }
+    }
+
+    pub fn resolve_to_account_metas(self) -> [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] {
+        let keys = self.resolve();
+        keys.into()
+    }
+}
+
+pub struct PriceExactInWithBumpFreeArgs {
+    pub args: PriceExactInFreeArgs,
+    pub input_target_acc_bump: u8,
+    pub output_target_acc_bump: u8,
+}
+
+impl PriceExactInWithBumpFreeArgs {
+    pub fn resolve(self) -> Result<PriceExactInKeys, PubkeyError> {
+        self.resolve_inner(imbalance_fee_program::STATE_ID, imbalance_fee_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<PriceExactInKeys, PubkeyError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<PriceExactInKeys, PubkeyError> {
+        let input_create_pda_args = TargetWeightCreatePdaArgs {
+            find_pda_args: TargetWeightFindPdaArgs {
+                lst_mint: self.args.input_lst_mint,
+                program_id,
+            },
+            bump: self.input_target_acc_bump,
+        };
+        let input_target_acc = input_create_pda_args.get_target_weight_address()?;
+
+        let output_create_pda_args = TargetWeightCreatePdaArgs {
+            find_pda_args: TargetWeightFindPdaArgs {
+                lst_mint: self.args.output_lst_mint,
+                program_id,
+            },
+            bump: self.output_target_acc_bump,
+        };
+        let output_target_acc = output_create_pda_args.get_target_weight_address()?;
+
+        Ok(PriceExactInKeys {
+            input_lst_mint: self.args.input_lst_mint,
+            output_lst_mint: self.args.output_lst_mint,
+            state: state_id,
+            lst_state_list: s_controller_lib::program::LST_STATE_LIST_ID,
+            input_target_acc,
+            output_target_acc,
+        })
+    }
+}
@@ -0,0 +1,103 @@
+use imbalance_fee_interface::{ImbalanceFeeError, ProgramState, SetTargetWeightKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::{ProgramStateFindPdaArgs, TargetWeightFindPdaArgs},
+    program as imbalance_fee_program,
+    utils::try_program_state,
+};
+
+pub struct SetTargetWeightByMintFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub lst_mint: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetTargetWeightByMintFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetTargetWeightKeys, ImbalanceFeeError> {
+        self.resolve_inner(imbalance_fee_program::STATE_ID, imbalance_fee_program::ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetTargetWeightKeys, ImbalanceFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<SetTargetWeightKeys, ImbalanceFeeError> {
+        let SetTargetWeightByMintFreeArgs {
+            lst_mint,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(ImbalanceFeeError::IncorrectProgramState);
+        }
+
+        let find_pda_args = TargetWeightFindPdaArgs {
+            lst_mint,
+            program_id,
+        };
+        let (target_acc, _bump) = find_pda_args.get_target_weight_address_and_bump_seed();
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        Ok(SetTargetWeightKeys {
+            manager: state.manager,
+            target_acc,
+            state: state_id,
+        })
+    }
+}
+
+pub struct SetTargetWeightFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub target_acc: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetTargetWeightFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetTargetWeightKeys, ImbalanceFeeError> {
+        self.resolve_inner(imbalance_fee_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetTargetWeightKeys, ImbalanceFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<SetTargetWeightKeys, ImbalanceFeeError> {
+        let SetTargetWeightFreeArgs {
+            target_acc: _,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(ImbalanceFeeError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        Ok(SetTargetWeightKeys {
+            manager: state.manager,
+            target_acc: self.target_acc,
+            state: state_id,
+        })
+    }
+}
@@ -0,0 +1,18 @@
+use imbalance_fee_interface::ImbalanceFeeError;
+use sanctum_token_ratio::{FloorDiv, U64Ratio, BPS_DENOMINATOR};
+
+/// Returns the ratio that returns out_sol_value
+/// when applied to in_sol_value
+pub fn out_sol_value_ratio(
+    fee_bps: u16,
+) -> Result<FloorDiv<U64Ratio<u16, u16>>, ImbalanceFeeError> {
+    // post_fee_bps = 10_000 - fee_bps
+    // out_sol_value = floor(in_sol_value * post_fee_bps / 10_000)
+    let post_fee_bps = BPS_DENOMINATOR
+        .checked_sub(fee_bps)
+        .ok_or(ImbalanceFeeError::MathError)?;
+    Ok(FloorDiv(U64Ratio {
+        num: post_fee_bps,
+        denom: BPS_DENOMINATOR,
+    }))
+}
@@ -0,0 +1,322 @@
+use imbalance_fee_interface::{ImbalanceFeeError, ProgramState};
+use s_controller_interface::LstState;
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use solana_program::pubkey::Pubkey;
+
+use super::BPS_DENOMINATOR_I64;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeParams {
+    pub base_fee_bps: u16,
+    pub imbalance_slope_bps: u16,
+    pub max_imbalance_fee_bps: u16,
+    pub max_discount_bps: u16,
+}
+
+impl From<&ProgramState> for FeeParams {
+    fn from(
+        ProgramState {
+            base_fee_bps,
+            imbalance_slope_bps,
+            max_imbalance_fee_bps,
+            max_discount_bps,
+            ..
+        }: &ProgramState,
+    ) -> Self {
+        Self {
+            base_fee_bps: *base_fee_bps,
+            imbalance_slope_bps: *imbalance_slope_bps,
+            max_imbalance_fee_bps: *max_imbalance_fee_bps,
+            max_discount_bps: *max_discount_bps,
+        }
+    }
+}
+
+/// SOL values of the pool before the swap, as recorded on the S controller's LstStateList
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolBalance {
+    pub total_sol_value: u64,
+    pub input_lst_sol_value: u64,
+    pub output_lst_sol_value: u64,
+}
+
+impl PoolBalance {
+    pub fn try_from_lst_state_list(
+        lst_state_list: &[LstState],
+        input_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
+    ) -> Result<Self, ImbalanceFeeError> {
+        let mut total_sol_value: u64 = 0;
+        let mut input_lst_sol_value = None;
+        let mut output_lst_sol_value = None;
+        for lst_state in lst_state_list {
+            total_sol_value = total_sol_value
+                .checked_add(lst_state.sol_value)
+                .ok_or(ImbalanceFeeError::MathError)?;
+            if lst_state.mint == input_lst_mint {
+                input_lst_sol_value = Some(lst_state.sol_value);
+            }
+            if lst_state.mint == output_lst_mint {
+                output_lst_sol_value = Some(lst_state.sol_value);
+            }
+        }
+        Ok(Self {
+            total_sol_value,
+            input_lst_sol_value: input_lst_sol_value.ok_or(ImbalanceFeeError::LstNotInPool)?,
+            output_lst_sol_value: output_lst_sol_value.ok_or(ImbalanceFeeError::LstNotInPool)?,
+        })
+    }
+
+    /// Replaces the input and output LSTs' recorded SOL values with their synced values,
+    /// adjusting the total accordingly.
+    ///
+    /// The S controller syncs both LSTs' SOL values before CPI-ing the pricing program,
+    /// so off-chain quotes built from a fetched LstStateList must apply this to match on-chain fees.
+    pub fn with_synced_sol_values(
+        self,
+        synced_input_lst_sol_value: u64,
+        synced_output_lst_sol_value: u64,
+    ) -> Result<Self, ImbalanceFeeError> {
+        let total_sol_value = self
+            .total_sol_value
+            .checked_sub(self.input_lst_sol_value)
+            .and_then(|v| v.checked_sub(self.output_lst_sol_value))
+            .and_then(|v| v.checked_add(synced_input_lst_sol_value))
+            .and_then(|v| v.checked_add(synced_output_lst_sol_value))
+            .ok_or(ImbalanceFeeError::MathError)?;
+        Ok(Self {
+            total_sol_value,
+            input_lst_sol_value: synced_input_lst_sol_value,
+            output_lst_sol_value: synced_output_lst_sol_value,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImbalanceFeeArgs {
+    pub fee_params: FeeParams,
+    pub pool_balance: PoolBalance,
+    pub input_target_weight_bps: u16,
+    pub output_target_weight_bps: u16,
+}
+
+impl ImbalanceFeeArgs {
+    /// Returns the total fee in bps to charge for a swap moving `trade_sol_value`
+    /// from the output LST into the input LST.
+    ///
+    /// The imbalance component is positive if the swap moves the 2 LSTs' weights
+    /// further away from their targets and negative (discount) if it moves them closer.
+    /// Discounts are deducted from the base fee and the total is floored at 0:
+    /// a negative fee would pay out more SOL value than the pool receives,
+    /// which the S controller rejects with `PoolWouldLoseSolValue`.
+    pub fn fee_bps(&self, trade_sol_value: u64) -> Result<u16, ImbalanceFeeError> {
+        let Self {
+            fee_params:
+                FeeParams {
+                    base_fee_bps,
+                    imbalance_slope_bps,
+                    max_imbalance_fee_bps,
+                    max_discount_bps,
+                },
+            pool_balance:
+                PoolBalance {
+                    total_sol_value,
+                    input_lst_sol_value,
+                    output_lst_sol_value,
+                },
+            input_target_weight_bps,
+            output_target_weight_bps,
+        } = *self;
+
+        let post_input_lst_sol_value = input_lst_sol_value
+            .checked_add(trade_sol_value)
+            .ok_or(ImbalanceFeeError::MathError)?;
+        let removed_sol_value = trade_sol_value.min(output_lst_sol_value);
+        let post_output_lst_sol_value = output_lst_sol_value - removed_sol_value;
+        let post_total_sol_value = total_sol_value
+            .checked_add(trade_sol_value - removed_sol_value)
+            .ok_or(ImbalanceFeeError::MathError)?;
+
+        let input_deviation_change_bps = abs_deviation_change_bps(
+            (input_lst_sol_value, total_sol_value),
+            (post_input_lst_sol_value, post_total_sol_value),
+            input_target_weight_bps,
+        )?;
+        let output_deviation_change_bps = abs_deviation_change_bps(
+            (output_lst_sol_value, total_sol_value),
+            (post_output_lst_sol_value, post_total_sol_value),
+            output_target_weight_bps,
+        )?;
+
+        let imbalance_fee_bps = (input_deviation_change_bps + output_deviation_change_bps)
+            * i64::from(imbalance_slope_bps)
+            / BPS_DENOMINATOR_I64;
+        let imbalance_fee_bps = imbalance_fee_bps.clamp(
+            -i64::from(max_discount_bps),
+            i64::from(max_imbalance_fee_bps),
+        );
+
+        let fee_bps = (i64::from(base_fee_bps) + imbalance_fee_bps).clamp(0, BPS_DENOMINATOR_I64);
+        fee_bps
+            .try_into()
+            .map_err(|_e| ImbalanceFeeError::MathError)
+    }
+}
+
+/// `|weight_bps - target_weight_bps|`
+fn abs_deviation_bps(
+    sol_value: u64,
+    total_sol_value: u64,
+    target_weight_bps: u16,
+) -> Result<i64, ImbalanceFeeError> {
+    let weight_bps = if total_sol_value == 0 {
+        0
+    } else {
+        u128::from(sol_value) * u128::from(BPS_DENOMINATOR) / u128::from(total_sol_value)
+    };
+    let weight_bps: i64 = weight_bps
+        .try_into()
+        .map_err(|_e| ImbalanceFeeError::MathError)?;
+    Ok((weight_bps - i64::from(target_weight_bps)).abs())
+}
+
+/// Returns how much the absolute deviation from target weight changes from `pre` to `post`,
+/// where each is `(lst_sol_value, total_sol_value)`
+fn abs_deviation_change_bps(
+    (pre_sol_value, pre_total_sol_value): (u64, u64),
+    (post_sol_value, post_total_sol_value): (u64, u64),
+    target_weight_bps: u16,
+) -> Result<i64, ImbalanceFeeError> {
+    let pre = abs_deviation_bps(pre_sol_value, pre_total_sol_value, target_weight_bps)?;
+    let post = abs_deviation_bps(post_sol_value, post_total_sol_value, target_weight_bps)?;
+    Ok(post - pre)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    prop_compose! {
+        fn fee_params()
+            (
+                base_fee_bps in 0..=10_000u16,
+                imbalance_slope_bps: u16,
+                max_imbalance_fee_bps in 0..=10_000u16,
+                max_discount_bps in 0..=10_000u16,
+            ) -> FeeParams {
+                FeeParams { base_fee_bps, imbalance_slope_bps, max_imbalance_fee_bps, max_discount_bps }
+            }
+    }
+
+    prop_compose! {
+        fn pool_balance()
+            (
+                input_lst_sol_value in 0..=u64::MAX / 4,
+                output_lst_sol_value in 0..=u64::MAX / 4,
+            )
+            (
+                total_sol_value in input_lst_sol_value + output_lst_sol_value..=u64::MAX / 2,
+                input_lst_sol_value in Just(input_lst_sol_value),
+                output_lst_sol_value in Just(output_lst_sol_value),
+            ) -> PoolBalance {
+                PoolBalance { total_sol_value, input_lst_sol_value, output_lst_sol_value }
+            }
+    }
+
+    prop_compose! {
+        fn imbalance_fee_args()
+            (
+                fee_params in fee_params(),
+                pool_balance in pool_balance(),
+                input_target_weight_bps in 0..=10_000u16,
+                output_target_weight_bps in 0..=10_000u16,
+            ) -> ImbalanceFeeArgs {
+                ImbalanceFeeArgs { fee_params, pool_balance, input_target_weight_bps, output_target_weight_bps }
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn fee_bps_within_bounds(args in imbalance_fee_args(), trade_sol_value in 0..=u64::MAX / 4) {
+            let FeeParams { base_fee_bps, max_imbalance_fee_bps, max_discount_bps, .. } = args.fee_params;
+            let fee_bps = args.fee_bps(trade_sol_value).unwrap();
+            prop_assert!(fee_bps <= 10_000);
+            prop_assert!(fee_bps <= base_fee_bps.saturating_add(max_imbalance_fee_bps));
+            prop_assert!(fee_bps >= base_fee_bps.saturating_sub(max_discount_bps));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn zero_slope_is_flat_base_fee(mut args in imbalance_fee_args(), trade_sol_value in 0..=u64::MAX / 4) {
+            args.fee_params.imbalance_slope_bps = 0;
+            prop_assert_eq!(args.fee_bps(trade_sol_value).unwrap(), args.fee_params.base_fee_bps);
+        }
+    }
+
+    #[test]
+    fn fee_when_worsening_discount_when_restoring() {
+        const FEE_PARAMS: FeeParams = FeeParams {
+            base_fee_bps: 30,
+            imbalance_slope_bps: 10_000,
+            max_imbalance_fee_bps: 10_000,
+            max_discount_bps: 10_000,
+        };
+        // targets are 50/50, pool is at 60/40
+        let overweight_in = ImbalanceFeeArgs {
+            fee_params: FEE_PARAMS,
+            pool_balance: PoolBalance {
+                total_sol_value: 1_000,
+                input_lst_sol_value: 600,
+                output_lst_sol_value: 400,
+            },
+            input_target_weight_bps: 5_000,
+            output_target_weight_bps: 5_000,
+        };
+        // 600 -> 610, 400 -> 390: +100 bps deviation on each side
+        assert_eq!(overweight_in.fee_bps(10).unwrap(), 30 + 200);
+
+        let underweight_in = ImbalanceFeeArgs {
+            pool_balance: PoolBalance {
+                total_sol_value: 1_000,
+                input_lst_sol_value: 400,
+                output_lst_sol_value: 600,
+            },
+            ..overweight_in
+        };
+        // 400 -> 401, 600 -> 599: -10 bps deviation on each side
+        assert_eq!(underweight_in.fee_bps(1).unwrap(), 30 - 20);
+        // large discounts are floored at 0
+        assert_eq!(underweight_in.fee_bps(10).unwrap(), 0);
+    }
+
+    #[test]
+    fn synced_sol_values_adjust_total() {
+        let stale = PoolBalance {
+            total_sol_value: 1_000,
+            input_lst_sol_value: 400,
+            output_lst_sol_value: 300,
+        };
+        assert_eq!(
+            stale.with_synced_sol_values(500, 250).unwrap(),
+            PoolBalance {
+                total_sol_value: 1_050,
+                input_lst_sol_value: 500,
+                output_lst_sol_value: 250,
+            }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn unchanged_sync_is_noop(pool_balance in pool_balance()) {
+            let PoolBalance { input_lst_sol_value, output_lst_sol_value, .. } = pool_balance;
+            prop_assert_eq!(
+                pool_balance.with_synced_sol_values(input_lst_sol_value, output_lst_sol_value).unwrap(),
+                pool_balance
+            );
+        }
+    }
+}
@@ -0,0 +1,12 @@
+mod common;
+mod imbalance;
+mod price_exact_in;
+mod price_exact_out;
+mod price_lp_tokens_to_redeem;
+
+pub use imbalance::*;
+pub use price_exact_in::*;
+pub use price_exact_out::*;
+pub use price_lp_tokens_to_redeem::*;
+
+const BPS_DENOMINATOR_I64: i64 = 10_000;
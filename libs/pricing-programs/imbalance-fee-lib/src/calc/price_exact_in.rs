@@ -0,0 +1,15 @@
+use imbalance_fee_interface::ImbalanceFeeError;
+use sanctum_token_ratio::ReversibleRatio;
+
+use super::{common::out_sol_value_ratio, ImbalanceFeeArgs};
+
+/// Returns `out_sol_value`
+pub fn calculate_price_exact_in(
+    args: &ImbalanceFeeArgs,
+    in_sol_value: u64,
+) -> Result<u64, ImbalanceFeeError> {
+    let fee_bps = args.fee_bps(in_sol_value)?;
+    out_sol_value_ratio(fee_bps)?
+        .apply(in_sol_value)
+        .map_err(|_e| ImbalanceFeeError::MathError)
+}
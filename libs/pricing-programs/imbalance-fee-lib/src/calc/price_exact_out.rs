@@ -0,0 +1,16 @@
+use imbalance_fee_interface::ImbalanceFeeError;
+use sanctum_token_ratio::ReversibleRatio;
+
+use super::{common::out_sol_value_ratio, ImbalanceFeeArgs};
+
+/// Returns `in_sol_value`
+pub fn calculate_price_exact_out(
+    args: &ImbalanceFeeArgs,
+    out_sol_value: u64,
+) -> Result<u64, ImbalanceFeeError> {
+    let fee_bps = args.fee_bps(out_sol_value)?;
+    Ok(out_sol_value_ratio(fee_bps)?
+        .reverse(out_sol_value)
+        .map_err(|_e| ImbalanceFeeError::MathError)?
+        .get_max())
+}
@@ -0,0 +1,18 @@
+use imbalance_fee_interface::ImbalanceFeeError;
+
+const MAX_FEE_BPS: u16 = 10_000;
+const MAX_TARGET_WEIGHT_BPS: u16 = 10_000;
+
+pub fn verify_fee_bps_bound(fee_bps: u16) -> Result<(), ImbalanceFeeError> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(ImbalanceFeeError::FeeOutOfBound);
+    }
+    Ok(())
+}
+
+pub fn verify_target_weight_bps_bound(target_weight_bps: u16) -> Result<(), ImbalanceFeeError> {
+    if target_weight_bps > MAX_TARGET_WEIGHT_BPS {
+        return Err(ImbalanceFeeError::TargetWeightOutOfBound);
+    }
+    Ok(())
+}
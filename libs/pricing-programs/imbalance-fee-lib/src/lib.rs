@@ -0,0 +1,46 @@
+pub mod account_resolvers;
+pub mod calc;
+pub mod fee_bound;
+pub mod pda;
+pub mod utils;
+
+pub mod program {
+    pub const STATE_SIZE: usize = 74;
+    pub const TARGET_WEIGHT_SIZE: usize = 4;
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<imbalance_fee_interface::ProgramState>(),
+        STATE_SIZE,
+    );
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<imbalance_fee_interface::TargetWeight>(),
+        TARGET_WEIGHT_SIZE,
+    );
+
+    sanctum_macros::declare_program_keys!(
+        "2wCZy4ecVjYhFx9Wr5tQCv1AfWXm3s2XWFsoGrd73Htg",
+        [("state", b"state")]
+    );
+}
+
+pub mod initial_constants {
+    pub mod initial_manager {
+        #[cfg(feature = "testing")]
+        sanctum_macros::declare_program_keys!("J5aMuYiKNHUzMTpUS85413DxxvDVjNXs63EXW5twG1Mx", []);
+
+        #[cfg(not(feature = "testing"))]
+        sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
+    }
+
+    pub const INITIAL_LP_WITHDRAWAL_FEE_BPS: u16 = 5;
+
+    pub const INITIAL_BASE_FEE_BPS: u16 = 5;
+
+    /// 1 bps of fee for every 1 bps of weight deviation a swap adds
+    pub const INITIAL_IMBALANCE_SLOPE_BPS: u16 = 10_000;
+
+    pub const INITIAL_MAX_IMBALANCE_FEE_BPS: u16 = 100;
+
+    pub const INITIAL_MAX_DISCOUNT_BPS: u16 = 5;
+}
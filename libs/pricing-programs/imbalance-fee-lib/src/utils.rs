@@ -0,0 +1,35 @@
+use bytemuck::{try_from_bytes, try_from_bytes_mut};
+
+use imbalance_fee_interface::{ImbalanceFeeError, ProgramState, TargetWeight};
+use s_controller_interface::LstState;
+
+pub fn try_program_state(
+    program_state_acc_data: &[u8],
+) -> Result<&ProgramState, ImbalanceFeeError> {
+    try_from_bytes(program_state_acc_data).map_err(|_e| ImbalanceFeeError::InvalidProgramStateData)
+}
+
+pub fn try_program_state_mut(
+    program_state_acc_data: &mut [u8],
+) -> Result<&mut ProgramState, ImbalanceFeeError> {
+    try_from_bytes_mut(program_state_acc_data)
+        .map_err(|_e| ImbalanceFeeError::InvalidProgramStateData)
+}
+
+pub fn try_target_weight(target_acc_data: &[u8]) -> Result<&TargetWeight, ImbalanceFeeError> {
+    try_from_bytes(target_acc_data).map_err(|_e| ImbalanceFeeError::UnsupportedLstMint)
+}
+
+pub fn try_target_weight_mut(
+    target_acc_data: &mut [u8],
+) -> Result<&mut TargetWeight, ImbalanceFeeError> {
+    try_from_bytes_mut(target_acc_data).map_err(|_e| ImbalanceFeeError::UnsupportedLstMint)
+}
+
+/// Reinterprets the S controller's LstStateList account data
+pub fn try_lst_state_list(
+    lst_state_list_acc_data: &[u8],
+) -> Result<&[LstState], ImbalanceFeeError> {
+    s_controller_lib::try_lst_state_list(lst_state_list_acc_data)
+        .map_err(|_e| ImbalanceFeeError::InvalidLstStateListData)
+}
@@ -0,0 +1,7 @@
+mod imbalance_fee_program_test;
+mod state;
+mod target_weight;
+
+pub use imbalance_fee_program_test::*;
+pub use state::*;
+pub use target_weight::*;
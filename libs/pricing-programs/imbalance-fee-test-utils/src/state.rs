@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use imbalance_fee_interface::ProgramState;
+use imbalance_fee_lib::{
+    initial_constants::{
+        initial_manager, INITIAL_BASE_FEE_BPS, INITIAL_IMBALANCE_SLOPE_BPS,
+        INITIAL_LP_WITHDRAWAL_FEE_BPS, INITIAL_MAX_IMBALANCE_FEE_BPS, INITIAL_MAX_DISCOUNT_BPS,
+    },
+    program::STATE_SIZE,
+    utils::try_program_state_mut,
+};
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedBanksClient, IntoAccount};
+use solana_program_test::BanksClient;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+pub const DEFAULT_PROGRAM_STATE: ProgramState = ProgramState {
+    manager: initial_manager::ID,
+    pending_manager: Pubkey::new_from_array([0u8; 32]),
+    lp_withdrawal_fee_bps: INITIAL_LP_WITHDRAWAL_FEE_BPS,
+    base_fee_bps: INITIAL_BASE_FEE_BPS,
+    imbalance_slope_bps: INITIAL_IMBALANCE_SLOPE_BPS,
+    max_imbalance_fee_bps: INITIAL_MAX_IMBALANCE_FEE_BPS,
+    max_discount_bps: INITIAL_MAX_DISCOUNT_BPS,
+};
+
+pub struct MockProgramState(pub ProgramState);
+
+impl IntoAccount for MockProgramState {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; STATE_SIZE];
+        let dst = try_program_state_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(STATE_SIZE),
+            data,
+            owner: imbalance_fee_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[async_trait]
+pub trait ImbalanceFeePricingProgramTestBanksClient {
+    async fn get_imbalance_fee_program_state(&mut self) -> Account;
+}
+
+#[async_trait]
+impl ImbalanceFeePricingProgramTestBanksClient for BanksClient {
+    async fn get_imbalance_fee_program_state(&mut self) -> Account {
+        self.get_account_unwrapped(imbalance_fee_lib::program::STATE_ID)
+            .await
+    }
+}
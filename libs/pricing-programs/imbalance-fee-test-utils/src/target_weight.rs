@@ -0,0 +1,51 @@
+use imbalance_fee_interface::TargetWeight;
+use imbalance_fee_lib::{
+    pda::TargetWeightFindPdaArgs, program::TARGET_WEIGHT_SIZE, utils::try_target_weight_mut,
+};
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, IntoAccount};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+
+pub struct MockTargetWeight(pub TargetWeight);
+
+impl IntoAccount for MockTargetWeight {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; TARGET_WEIGHT_SIZE];
+        let dst = try_target_weight_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(TARGET_WEIGHT_SIZE),
+            data,
+            owner: imbalance_fee_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+pub struct MockTargetWeightArgs {
+    pub target_weight_bps: u16,
+    pub lst_mint: Pubkey,
+}
+
+impl MockTargetWeightArgs {
+    pub fn to_target_weight_and_addr(&self, program_id: Pubkey) -> (TargetWeight, Pubkey) {
+        let Self {
+            target_weight_bps,
+            lst_mint,
+        } = self;
+        let (addr, bump) = TargetWeightFindPdaArgs {
+            lst_mint: *lst_mint,
+            program_id,
+        }
+        .get_target_weight_address_and_bump_seed();
+        (
+            TargetWeight {
+                target_weight_bps: *target_weight_bps,
+                bump,
+                padding: 0u8,
+            },
+            addr,
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+use volume_surge_interface::{AcceptManagerKeys, ProgramState, VolumeSurgeError};
+
+use crate::{
+    pda::ProgramStateFindPdaArgs, program as volume_surge_program, utils::try_program_state,
+};
+
+pub struct AcceptManagerFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> AcceptManagerFreeArgs<S> {
+    pub fn resolve(self) -> Result<AcceptManagerKeys, VolumeSurgeError> {
+        self.resolve_inner(volume_surge_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<AcceptManagerKeys, VolumeSurgeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<AcceptManagerKeys, VolumeSurgeError> {
+        let AcceptManagerFreeArgs { state_acc } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(VolumeSurgeError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        Ok(AcceptManagerKeys {
+            pending_manager: state.pending_manager,
+            state: state_id,
+        })
+    }
+}
@@ -0,0 +1,19 @@
+mod accept_manager;
+mod add_lst;
+mod initialize;
+mod price_exact_in;
+mod price_exact_out;
+mod price_lp_tokens_to_redeem;
+mod remove_lst;
+mod set_fee_params;
+mod set_manager;
+
+pub use accept_manager::*;
+pub use add_lst::*;
+pub use initialize::*;
+pub use price_exact_in::*;
+pub use price_exact_out::*;
+pub use price_lp_tokens_to_redeem::*;
+pub use remove_lst::*;
+pub use set_fee_params::*;
+pub use set_manager::*;
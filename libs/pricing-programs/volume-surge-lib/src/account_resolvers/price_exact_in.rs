@@ -0,0 +1,132 @@
+use solana_program::{
+    instruction::AccountMeta,
+    pubkey::{Pubkey, PubkeyError},
+};
+use volume_surge_interface::{PriceExactInKeys, PRICE_EXACT_IN_IX_ACCOUNTS_LEN};
+
+use crate::{
+    pda::{ProgramStateFindPdaArgs, VolumeAccountCreatePdaArgs, VolumeAccountFindPdaArgs},
+    program as volume_surge_program,
+};
+
+/// Account metas for passing to the S controller as the pricing program accounts suffix.
+/// `pool_state` is not marked as a signer since the S controller signs for it
+/// when it CPIs the pricing program
+pub fn price_exact_in_account_metas(
+    keys: PriceExactInKeys,
+) -> [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] {
+    let mut metas: [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] = keys.into();
+    for meta in metas.iter_mut() {
+        if meta.pubkey == s_controller_lib::program::POOL_STATE_ID {
+            meta.is_signer = false;
+        }
+    }
+    metas
+}
+
+/// Uses find_program_address, for use with
+/// - initial creation
+/// - client side
+pub struct PriceExactInFreeArgs {
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+}
+
+impl PriceExactInFreeArgs {
+    pub fn resolve(self) -> PriceExactInKeys {
+        self.resolve_inner(volume_surge_program::STATE_ID, volume_surge_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> PriceExactInKeys {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey, program_id: Pubkey) -> PriceExactInKeys {
+        let input_find_pda_args = VolumeAccountFindPdaArgs {
+            lst_mint: self.input_lst_mint,
+            program_id,
+        };
+        let (input_volume_acc, _bump) =
+            input_find_pda_args.get_volume_account_address_and_bump_seed();
+
+        let output_find_pda_args = VolumeAccountFindPdaArgs {
+            lst_mint: self.output_lst_mint,
+            program_id,
+        };
+        let (output_volume_acc, _bump) =
+            output_find_pda_args.get_volume_account_address_and_bump_seed();
+
+        PriceExactInKeys {
+            input_lst_mint: self.input_lst_mint,
+            output_lst_mint: self.output_lst_mint,
+            state: state_id,
+            pool_state: s_controller_lib::program::POOL_STATE_ID,
+            lst_state_list: s_controller_lib::program::LST_STATE_LIST_ID,
+            input_volume_acc,
+            output_volume_acc,
+        }
+    }
+
+    pub fn resolve_to_account_metas(self) -> [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] {
+        let keys = self.resolve();
+        price_exact_in_account_metas(keys)
+    }
+}
+
+pub struct PriceExactInWithBumpFreeArgs {
+    pub args: PriceExactInFreeArgs,
+    pub input_volume_acc_bump: u8,
+    pub output_volume_acc_bump: u8,
+}
+
+impl PriceExactInWithBumpFreeArgs {
+    pub fn resolve(self) -> Result<PriceExactInKeys, PubkeyError> {
+        self.resolve_inner(volume_surge_program::STATE_ID, volume_surge_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<PriceExactInKeys, PubkeyError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<PriceExactInKeys, PubkeyError> {
+        let input_create_pda_args = VolumeAccountCreatePdaArgs {
+            find_pda_args: VolumeAccountFindPdaArgs {
+                lst_mint: self.args.input_lst_mint,
+                program_id,
+            },
+            bump: self.input_volume_acc_bump,
+        };
+        let input_volume_acc = input_create_pda_args.get_volume_account_address()?;
+
+        let output_create_pda_args = VolumeAccountCreatePdaArgs {
+            find_pda_args: VolumeAccountFindPdaArgs {
+                lst_mint: self.args.output_lst_mint,
+                program_id,
+            },
+            bump: self.output_volume_acc_bump,
+        };
+        let output_volume_acc = output_create_pda_args.get_volume_account_address()?;
+
+        Ok(PriceExactInKeys {
+            input_lst_mint: self.args.input_lst_mint,
+            output_lst_mint: self.args.output_lst_mint,
+            state: state_id,
+            pool_state: s_controller_lib::program::POOL_STATE_ID,
+            lst_state_list: s_controller_lib::program::LST_STATE_LIST_ID,
+            input_volume_acc,
+            output_volume_acc,
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use solana_program::{
+    instruction::AccountMeta,
+    pubkey::{Pubkey, PubkeyError},
+};
+use volume_surge_interface::{PriceExactOutKeys, PRICE_EXACT_OUT_IX_ACCOUNTS_LEN};
+
+use crate::{
+    pda::{ProgramStateFindPdaArgs, VolumeAccountCreatePdaArgs, VolumeAccountFindPdaArgs},
+    program as volume_surge_program,
+};
+
+/// Account metas for passing to the S controller as the pricing program accounts suffix.
+/// `pool_state` is not marked as a signer since the S controller signs for it
+/// when it CPIs the pricing program
+pub fn price_exact_out_account_metas(
+    keys: PriceExactOutKeys,
+) -> [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN] {
+    let mut metas: [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN] = keys.into();
+    for meta in metas.iter_mut() {
+        if meta.pubkey == s_controller_lib::program::POOL_STATE_ID {
+            meta.is_signer = false;
+        }
+    }
+    metas
+}
+
+/// Uses find_program_address, for use with
+/// - initial creation
+/// - client side
+pub struct PriceExactOutFreeArgs {
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+}
+
+impl PriceExactOutFreeArgs {
+    pub fn resolve(self) -> PriceExactOutKeys {
+        self.resolve_inner(volume_surge_program::STATE_ID, volume_surge_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> PriceExactOutKeys {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey, program_id: Pubkey) -> PriceExactOutKeys {
+        let input_find_pda_args = VolumeAccountFindPdaArgs {
+            lst_mint: self.input_lst_mint,
+            program_id,
+        };
+        let (input_volume_acc, _bump) =
+            input_find_pda_args.get_volume_account_address_and_bump_seed();
+
+        let output_find_pda_args = VolumeAccountFindPdaArgs {
+            lst_mint: self.output_lst_mint,
+            program_id,
+        };
+        let (output_volume_acc, _bump) =
+            output_find_pda_args.get_volume_account_address_and_bump_seed();
+
+        PriceExactOutKeys {
+            input_lst_mint: self.input_lst_mint,
+            output_lst_mint: self.output_lst_mint,
+            state: state_id,
+            pool_state: s_controller_lib::program::POOL_STATE_ID,
+            lst_state_list: s_controller_lib::program::LST_STATE_LIST_ID,
+            input_volume_acc,
+            output_volume_acc,
+        }
+    }
+
+    pub fn resolve_to_account_metas(self) -> [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN] {
+        let keys = self.resolve();
+        price_exact_out_account_metas(keys)
+    }
+}
+
+pub struct PriceExactOutWithBumpFreeArgs {
+    pub args: PriceExactOutFreeArgs,
+    pub input_volume_acc_bump: u8,
+    pub output_volume_acc_bump: u8,
+}
+
+impl PriceExactOutWithBumpFreeArgs {
+    pub fn resolve(self) -> Result<PriceExactOutKeys, PubkeyError> {
+        self.resolve_inner(volume_surge_program::STATE_ID, volume_surge_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<PriceExactOutKeys, PubkeyError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<PriceExactOutKeys, PubkeyError> {
+        let input_create_pda_args = VolumeAccountCreatePdaArgs {
+            find_pda_args: VolumeAccountFindPdaArgs {
+                lst_mint: self.args.input_lst_mint,
+                program_id,
+            },
+            bump: self.input_volume_acc_bump,
+        };
+        let input_volume_acc = input_create_pda_args.get_volume_account_address()?;
+
+        let output_create_pda_args = VolumeAccountCreatePdaArgs {
+            find_pda_args: VolumeAccountFindPdaArgs {
+                lst_mint: self.args.output_lst_mint,
+                program_id,
+            },
+            bump: self.output_volume_acc_bump,
+        };
+        let output_volume_acc = output_create_pda_args.get_volume_account_address()?;
+
+        Ok(PriceExactOutKeys {
+            input_lst_mint: self.args.input_lst_mint,
+            output_lst_mint: self.args.output_lst_mint,
+            state: state_id,
+            pool_state: s_controller_lib::program::POOL_STATE_ID,
+            lst_state_list: s_controller_lib::program::LST_STATE_LIST_ID,
+            input_volume_acc,
+            output_volume_acc,
+        })
+    }
+}
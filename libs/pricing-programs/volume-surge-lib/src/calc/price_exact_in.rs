@@ -0,0 +1,15 @@
+use sanctum_token_ratio::ReversibleRatio;
+use volume_surge_interface::VolumeSurgeError;
+
+use super::{common::out_sol_value_ratio, SurgeFeeArgs};
+
+/// Returns `out_sol_value`
+pub fn calculate_price_exact_in(
+    args: &SurgeFeeArgs,
+    in_sol_value: u64,
+) -> Result<u64, VolumeSurgeError> {
+    let fee_bps = args.fee_bps(in_sol_value)?;
+    out_sol_value_ratio(fee_bps)?
+        .apply(in_sol_value)
+        .map_err(|_e| VolumeSurgeError::MathError)
+}
@@ -0,0 +1,16 @@
+use sanctum_token_ratio::ReversibleRatio;
+use volume_surge_interface::VolumeSurgeError;
+
+use super::{common::out_sol_value_ratio, SurgeFeeArgs};
+
+/// Returns `in_sol_value`
+pub fn calculate_price_exact_out(
+    args: &SurgeFeeArgs,
+    out_sol_value: u64,
+) -> Result<u64, VolumeSurgeError> {
+    let fee_bps = args.fee_bps(out_sol_value)?;
+    Ok(out_sol_value_ratio(fee_bps)?
+        .reverse(out_sol_value)
+        .map_err(|_e| VolumeSurgeError::MathError)?
+        .get_max())
+}
@@ -0,0 +1,299 @@
+use s_controller_interface::LstState;
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use volume_surge_interface::{ProgramState, VolumeAccount, VolumeSurgeError};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeParams {
+    pub base_fee_bps: u16,
+    pub surge_slope_bps: u16,
+    pub max_surge_fee_bps: u16,
+}
+
+impl From<&ProgramState> for FeeParams {
+    fn from(
+        ProgramState {
+            base_fee_bps,
+            surge_slope_bps,
+            max_surge_fee_bps,
+            ..
+        }: &ProgramState,
+    ) -> Self {
+        Self {
+            base_fee_bps: *base_fee_bps,
+            surge_slope_bps: *surge_slope_bps,
+            max_surge_fee_bps: *max_surge_fee_bps,
+        }
+    }
+}
+
+/// An LST's recent swap volume, decayed to a given slot
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DecayedVolume {
+    /// SOL value of the LST recently swapped into the pool
+    pub inflow_sol_value: u64,
+    /// SOL value of the LST recently swapped out of the pool
+    pub outflow_sol_value: u64,
+}
+
+impl DecayedVolume {
+    /// Linearly decays the volume recorded in `volume_account` to `current_slot`.
+    /// Volume recorded `decay_window_slots` or more slots ago is fully decayed.
+    pub fn at_slot(
+        VolumeAccount {
+            last_update_slot,
+            inflow_sol_value,
+            outflow_sol_value,
+            ..
+        }: &VolumeAccount,
+        decay_window_slots: u64,
+        current_slot: u64,
+    ) -> Self {
+        let elapsed_slots = current_slot.saturating_sub(*last_update_slot);
+        Self {
+            inflow_sol_value: decay(*inflow_sol_value, elapsed_slots, decay_window_slots),
+            outflow_sol_value: decay(*outflow_sol_value, elapsed_slots, decay_window_slots),
+        }
+    }
+
+    pub fn with_inflow(self, sol_value: u64) -> Self {
+        Self {
+            inflow_sol_value: self.inflow_sol_value.saturating_add(sol_value),
+            ..self
+        }
+    }
+
+    pub fn with_outflow(self, sol_value: u64) -> Self {
+        Self {
+            outflow_sol_value: self.outflow_sol_value.saturating_add(sol_value),
+            ..self
+        }
+    }
+
+    pub fn write_to(self, volume_account: &mut VolumeAccount, current_slot: u64) {
+        volume_account.last_update_slot = current_slot;
+        volume_account.inflow_sol_value = self.inflow_sol_value;
+        volume_account.outflow_sol_value = self.outflow_sol_value;
+    }
+}
+
+fn decay(sol_value: u64, elapsed_slots: u64, decay_window_slots: u64) -> u64 {
+    let remaining_slots = match decay_window_slots.checked_sub(elapsed_slots) {
+        Some(0) | None => return 0,
+        Some(r) => r,
+    };
+    // remaining_slots <= decay_window_slots so result <= sol_value and fits in u64
+    (u128::from(sol_value) * u128::from(remaining_slots) / u128::from(decay_window_slots)) as u64
+}
+
+/// Sum of the SOL values of all LSTs on the S controller's LstStateList
+pub fn pool_total_sol_value(lst_state_list: &[LstState]) -> Result<u64, VolumeSurgeError> {
+    lst_state_list.iter().try_fold(0u64, |total, lst_state| {
+        total
+            .checked_add(lst_state.sol_value)
+            .ok_or(VolumeSurgeError::MathError)
+    })
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SurgeFeeArgs {
+    pub fee_params: FeeParams,
+    pub total_sol_value: u64,
+    pub input_volume: DecayedVolume,
+    pub output_volume: DecayedVolume,
+}
+
+impl SurgeFeeArgs {
+    /// Returns the total fee in bps to charge for a swap moving `trade_sol_value`
+    /// from the output LST into the input LST.
+    ///
+    /// The surge component scales with the one-sided flow after this swap,
+    /// which is the larger of the input LST's net inflow and the output LST's net outflow,
+    /// measured as a share of the pool's total SOL value.
+    /// Flow in the opposite direction offsets it, so balanced two-way volume only pays the base fee.
+    pub fn fee_bps(&self, trade_sol_value: u64) -> Result<u16, VolumeSurgeError> {
+        let Self {
+            fee_params:
+                FeeParams {
+                    base_fee_bps,
+                    surge_slope_bps,
+                    max_surge_fee_bps,
+                },
+            total_sol_value,
+            input_volume,
+            output_volume,
+        } = *self;
+
+        let input_net_inflow = input_volume
+            .inflow_sol_value
+            .saturating_add(trade_sol_value)
+            .saturating_sub(input_volume.outflow_sol_value);
+        let output_net_outflow = output_volume
+            .outflow_sol_value
+            .saturating_add(trade_sol_value)
+            .saturating_sub(output_volume.inflow_sol_value);
+        let one_sided_flow = input_net_inflow.max(output_net_outflow);
+
+        // an empty pool is treated as fully one-sided
+        let surge_fee_bps = if total_sol_value == 0 {
+            u128::from(max_surge_fee_bps)
+        } else {
+            u128::from(one_sided_flow) * u128::from(surge_slope_bps) / u128::from(total_sol_value)
+        };
+        let surge_fee_bps = surge_fee_bps.min(u128::from(max_surge_fee_bps));
+
+        let fee_bps = (u128::from(base_fee_bps) + surge_fee_bps).min(u128::from(BPS_DENOMINATOR));
+        fee_bps.try_into().map_err(|_e| VolumeSurgeError::MathError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    prop_compose! {
+        fn fee_params()
+            (
+                base_fee_bps in 0..=10_000u16,
+                surge_slope_bps: u16,
+                max_surge_fee_bps in 0..=10_000u16,
+            ) -> FeeParams {
+                FeeParams { base_fee_bps, surge_slope_bps, max_surge_fee_bps }
+            }
+    }
+
+    prop_compose! {
+        fn decayed_volume()
+            (inflow_sol_value: u64, outflow_sol_value: u64) -> DecayedVolume {
+                DecayedVolume { inflow_sol_value, outflow_sol_value }
+            }
+    }
+
+    prop_compose! {
+        fn surge_fee_args()
+            (
+                fee_params in fee_params(),
+                total_sol_value: u64,
+                input_volume in decayed_volume(),
+                output_volume in decayed_volume(),
+            ) -> SurgeFeeArgs {
+                SurgeFeeArgs { fee_params, total_sol_value, input_volume, output_volume }
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn fee_bps_within_bounds(args in surge_fee_args(), trade_sol_value: u64) {
+            let FeeParams { base_fee_bps, max_surge_fee_bps, .. } = args.fee_params;
+            let fee_bps = args.fee_bps(trade_sol_value).unwrap();
+            prop_assert!(fee_bps <= 10_000);
+            prop_assert!(fee_bps >= base_fee_bps);
+            prop_assert!(fee_bps <= base_fee_bps.saturating_add(max_surge_fee_bps));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn zero_slope_is_flat_base_fee(mut args in surge_fee_args(), trade_sol_value: u64) {
+            args.fee_params.surge_slope_bps = 0;
+            args.total_sol_value = args.total_sol_value.max(1);
+            prop_assert_eq!(args.fee_bps(trade_sol_value).unwrap(), args.fee_params.base_fee_bps);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn decayed_volume_never_increases(
+            inflow_sol_value: u64,
+            outflow_sol_value: u64,
+            last_update_slot: u64,
+            elapsed_slots: u64,
+            decay_window_slots: u64,
+        ) {
+            let volume_account = VolumeAccount {
+                bump: 0,
+                padding: [0u8; 7],
+                last_update_slot,
+                inflow_sol_value,
+                outflow_sol_value,
+            };
+            let decayed = DecayedVolume::at_slot(
+                &volume_account,
+                decay_window_slots,
+                last_update_slot.saturating_add(elapsed_slots),
+            );
+            prop_assert!(decayed.inflow_sol_value <= inflow_sol_value);
+            prop_assert!(decayed.outflow_sol_value <= outflow_sol_value);
+        }
+    }
+
+    #[test]
+    fn decay_is_linear_over_window() {
+        let volume_account = VolumeAccount {
+            bump: 0,
+            padding: [0u8; 7],
+            last_update_slot: 100,
+            inflow_sol_value: 1_000,
+            outflow_sol_value: 500,
+        };
+        assert_eq!(
+            DecayedVolume::at_slot(&volume_account, 100, 100),
+            DecayedVolume {
+                inflow_sol_value: 1_000,
+                outflow_sol_value: 500,
+            }
+        );
+        assert_eq!(
+            DecayedVolume::at_slot(&volume_account, 100, 125),
+            DecayedVolume {
+                inflow_sol_value: 750,
+                outflow_sol_value: 375,
+            }
+        );
+        assert_eq!(
+            DecayedVolume::at_slot(&volume_account, 100, 200),
+            DecayedVolume::default()
+        );
+        assert_eq!(
+            DecayedVolume::at_slot(&volume_account, 0, 100),
+            DecayedVolume::default()
+        );
+    }
+
+    #[test]
+    fn fee_surges_with_one_sided_flow_only() {
+        const FEE_PARAMS: FeeParams = FeeParams {
+            base_fee_bps: 10,
+            surge_slope_bps: 10_000,
+            max_surge_fee_bps: 500,
+        };
+        let args = SurgeFeeArgs {
+            fee_params: FEE_PARAMS,
+            total_sol_value: 1_000_000,
+            input_volume: DecayedVolume {
+                inflow_sol_value: 20_000,
+                outflow_sol_value: 0,
+            },
+            output_volume: DecayedVolume::default(),
+        };
+        // input net inflow 20_000 + 10_000 = 3% of pool, surge 300 bps
+        assert_eq!(args.fee_bps(10_000).unwrap(), 10 + 300);
+        // surge capped at max_surge_fee_bps
+        assert_eq!(args.fee_bps(100_000).unwrap(), 10 + 500);
+
+        let balanced = SurgeFeeArgs {
+            input_volume: DecayedVolume {
+                inflow_sol_value: 20_000,
+                outflow_sol_value: 30_000,
+            },
+            output_volume: DecayedVolume {
+                inflow_sol_value: 30_000,
+                outflow_sol_value: 20_000,
+            },
+            ..args
+        };
+        // recent flow was in the opposite direction and offsets this swap
+        assert_eq!(balanced.fee_bps(10_000).unwrap(), 10);
+    }
+}
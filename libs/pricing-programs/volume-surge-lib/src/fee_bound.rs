@@ -0,0 +1,10 @@
+use volume_surge_interface::VolumeSurgeError;
+
+const MAX_FEE_BPS: u16 = 10_000;
+
+pub fn verify_fee_bps_bound(fee_bps: u16) -> Result<(), VolumeSurgeError> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(VolumeSurgeError::FeeOutOfBound);
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+pub mod account_resolvers;
+pub mod calc;
+pub mod fee_bound;
+pub mod pda;
+pub mod utils;
+
+pub mod program {
+    pub const STATE_SIZE: usize = 80;
+    pub const VOLUME_ACCOUNT_SIZE: usize = 32;
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<volume_surge_interface::ProgramState>(),
+        STATE_SIZE,
+    );
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<volume_surge_interface::VolumeAccount>(),
+        VOLUME_ACCOUNT_SIZE,
+    );
+
+    sanctum_macros::declare_program_keys!(
+        "Divc29438s334NSXACq9bso2vnnvF3VMyhM5pGU8Pskt",
+        [("state", b"state")]
+    );
+}
+
+pub mod initial_constants {
+    pub mod initial_manager {
+        #[cfg(feature = "testing")]
+        sanctum_macros::declare_program_keys!("J5aMuYiKNHUzMTpUS85413DxxvDVjNXs63EXW5twG1Mx", []);
+
+        #[cfg(not(feature = "testing"))]
+        sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
+    }
+
+    pub const INITIAL_LP_WITHDRAWAL_FEE_BPS: u16 = 5;
+
+    pub const INITIAL_BASE_FEE_BPS: u16 = 5;
+
+    /// 1 bps of fee for every 1 bps of the pool's SOL value that flowed one-sidedly
+    pub const INITIAL_SURGE_SLOPE_BPS: u16 = 10_000;
+
+    pub const INITIAL_MAX_SURGE_FEE_BPS: u16 = 100;
+
+    /// ~1 hour at 400ms slots
+    pub const INITIAL_DECAY_WINDOW_SLOTS: u64 = 9_000;
+}
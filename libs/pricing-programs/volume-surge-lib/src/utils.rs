@@ -0,0 +1,31 @@
+use bytemuck::{try_from_bytes, try_from_bytes_mut};
+
+use s_controller_interface::LstState;
+use volume_surge_interface::{ProgramState, VolumeAccount, VolumeSurgeError};
+
+pub fn try_program_state(program_state_acc_data: &[u8]) -> Result<&ProgramState, VolumeSurgeError> {
+    try_from_bytes(program_state_acc_data).map_err(|_e| VolumeSurgeError::InvalidProgramStateData)
+}
+
+pub fn try_program_state_mut(
+    program_state_acc_data: &mut [u8],
+) -> Result<&mut ProgramState, VolumeSurgeError> {
+    try_from_bytes_mut(program_state_acc_data)
+        .map_err(|_e| VolumeSurgeError::InvalidProgramStateData)
+}
+
+pub fn try_volume_account(volume_acc_data: &[u8]) -> Result<&VolumeAccount, VolumeSurgeError> {
+    try_from_bytes(volume_acc_data).map_err(|_e| VolumeSurgeError::UnsupportedLstMint)
+}
+
+pub fn try_volume_account_mut(
+    volume_acc_data: &mut [u8],
+) -> Result<&mut VolumeAccount, VolumeSurgeError> {
+    try_from_bytes_mut(volume_acc_data).map_err(|_e| VolumeSurgeError::UnsupportedLstMint)
+}
+
+/// Reinterprets the S controller's LstStateList account data
+pub fn try_lst_state_list(lst_state_list_acc_data: &[u8]) -> Result<&[LstState], VolumeSurgeError> {
+    s_controller_lib::try_lst_state_list(lst_state_list_acc_data)
+        .map_err(|_e| VolumeSurgeError::InvalidLstStateListData)
+}
@@ -0,0 +1,7 @@
+mod state;
+mod volume_account;
+mod volume_surge_program_test;
+
+pub use state::*;
+pub use volume_account::*;
+pub use volume_surge_program_test::*;
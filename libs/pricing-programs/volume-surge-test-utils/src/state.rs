@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedBanksClient, IntoAccount};
+use solana_program_test::BanksClient;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use volume_surge_interface::ProgramState;
+use volume_surge_lib::{
+    initial_constants::{
+        initial_manager, INITIAL_BASE_FEE_BPS, INITIAL_DECAY_WINDOW_SLOTS,
+        INITIAL_LP_WITHDRAWAL_FEE_BPS, INITIAL_MAX_SURGE_FEE_BPS, INITIAL_SURGE_SLOPE_BPS,
+    },
+    program::STATE_SIZE,
+    utils::try_program_state_mut,
+};
+
+pub const DEFAULT_PROGRAM_STATE: ProgramState = ProgramState {
+    manager: initial_manager::ID,
+    pending_manager: Pubkey::new_from_array([0u8; 32]),
+    lp_withdrawal_fee_bps: INITIAL_LP_WITHDRAWAL_FEE_BPS,
+    base_fee_bps: INITIAL_BASE_FEE_BPS,
+    surge_slope_bps: INITIAL_SURGE_SLOPE_BPS,
+    max_surge_fee_bps: INITIAL_MAX_SURGE_FEE_BPS,
+    decay_window_slots: INITIAL_DECAY_WINDOW_SLOTS,
+};
+
+pub struct MockProgramState(pub ProgramState);
+
+impl IntoAccount for MockProgramState {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; STATE_SIZE];
+        let dst = try_program_state_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(STATE_SIZE),
+            data,
+            owner: volume_surge_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[async_trait]
+pub trait VolumeSurgePricingProgramTestBanksClient {
+    async fn get_volume_surge_program_state(&mut self) -> Account;
+}
+
+#[async_trait]
+impl VolumeSurgePricingProgramTestBanksClient for BanksClient {
+    async fn get_volume_surge_program_state(&mut self) -> Account {
+        self.get_account_unwrapped(volume_surge_lib::program::STATE_ID)
+            .await
+    }
+}
@@ -0,0 +1,58 @@
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, IntoAccount};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use volume_surge_interface::VolumeAccount;
+use volume_surge_lib::{
+    pda::VolumeAccountFindPdaArgs, program::VOLUME_ACCOUNT_SIZE, utils::try_volume_account_mut,
+};
+
+pub struct MockVolumeAccount(pub VolumeAccount);
+
+impl IntoAccount for MockVolumeAccount {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; VOLUME_ACCOUNT_SIZE];
+        let dst = try_volume_account_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(VOLUME_ACCOUNT_SIZE),
+            data,
+            owner: volume_surge_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MockVolumeAccountArgs {
+    pub lst_mint: Pubkey,
+    pub last_update_slot: u64,
+    pub inflow_sol_value: u64,
+    pub outflow_sol_value: u64,
+}
+
+impl MockVolumeAccountArgs {
+    pub fn to_volume_account_and_addr(&self, program_id: Pubkey) -> (VolumeAccount, Pubkey) {
+        let Self {
+            lst_mint,
+            last_update_slot,
+            inflow_sol_value,
+            outflow_sol_value,
+        } = self;
+        let (addr, bump) = VolumeAccountFindPdaArgs {
+            lst_mint: *lst_mint,
+            program_id,
+        }
+        .get_volume_account_address_and_bump_seed();
+        (
+            VolumeAccount {
+                bump,
+                padding: Default::default(),
+                last_update_slot: *last_update_slot,
+                inflow_sol_value: *inflow_sol_value,
+                outflow_sol_value: *outflow_sol_value,
+            },
+            addr,
+        )
+    }
+}
@@ -0,0 +1,259 @@
+use s_controller_interface::{AddLiquidityMultiKeys, SControllerError};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address, create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id,
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, try_pool_state,
+    AddRemoveLiquidityProgramIds, SwapLiquidityPdas,
+};
+
+pub const ADD_LIQUIDITY_MULTI_LST_ACCOUNTS_LEN: usize = 5;
+
+/// Keys of the fixed-size account group that precedes each LST's
+/// SOL value calculator and pricing program accounts in AddLiquidityMulti's accounts suffix
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddLiquidityMultiLstKeys {
+    pub lst_mint: Pubkey,
+    pub src_lst_acc: Pubkey,
+    pub pool_reserves: Pubkey,
+    pub protocol_fee_accumulator: Pubkey,
+    pub lst_token_program: Pubkey,
+}
+
+impl From<AddLiquidityMultiLstKeys> for [AccountMeta; ADD_LIQUIDITY_MULTI_LST_ACCOUNTS_LEN] {
+    fn from(
+        AddLiquidityMultiLstKeys {
+            lst_mint,
+            src_lst_acc,
+            pool_reserves,
+            protocol_fee_accumulator,
+            lst_token_program,
+        }: AddLiquidityMultiLstKeys,
+    ) -> Self {
+        [
+            AccountMeta::new_readonly(lst_mint, false),
+            AccountMeta::new(src_lst_acc, false),
+            AccountMeta::new(pool_reserves, false),
+            AccountMeta::new(protocol_fee_accumulator, false),
+            AccountMeta::new_readonly(lst_token_program, false),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddLiquidityMultiFreeArgs<
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub signer: Pubkey,
+    pub dst_lp_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > AddLiquidityMultiFreeArgs<S, L>
+{
+    pub fn resolve(&self) -> Result<AddLiquidityMultiKeys, SControllerError> {
+        let Self {
+            signer,
+            dst_lp_acc,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+        } = self;
+        if *pool_state_account.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(AddLiquidityMultiKeys {
+            signer: *signer,
+            dst_lp_acc: *dst_lp_acc,
+            lp_token_mint: pool_state.lp_token_mint,
+            lp_token_program: spl_token::ID,
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+        })
+    }
+
+    /// Resolves the account group of the LST at `lst_index` on lst_state_list
+    pub fn resolve_lst<M: ReadonlyAccountOwner + ReadonlyAccountPubkey>(
+        &self,
+        lst_index: usize,
+        src_lst_acc: Pubkey,
+        lst_mint: M,
+    ) -> Result<AddLiquidityMultiLstKeys, SControllerError> {
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, lst_index)?;
+        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
+        let protocol_fee_accumulator =
+            create_protocol_fee_accumulator_address(lst_state, *lst_mint.owner())?;
+        Ok(AddLiquidityMultiLstKeys {
+            lst_mint: *lst_mint.pubkey(),
+            src_lst_acc,
+            pool_reserves,
+            protocol_fee_accumulator,
+            lst_token_program: *lst_mint.owner(),
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find each LST's lst_index.
+/// Suitable for use on client-side.
+/// Does not check identity of pool_state and lst_state_list
+#[derive(Clone, Copy, Debug)]
+pub struct AddLiquidityMultiByMintFreeArgs<S: ReadonlyAccountData, L: ReadonlyAccountData> {
+    pub signer: Pubkey,
+    pub dst_lp_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> AddLiquidityMultiByMintFreeArgs<S, L> {
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve(&self) -> Result<AddLiquidityMultiKeys, SControllerError> {
+        self.resolve_with_pdas(SwapLiquidityPdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee: PROTOCOL_FEE_ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<AddLiquidityMultiKeys, SControllerError> {
+        self.resolve_with_pdas(SwapLiquidityPdas::find_for_program_id(program_id))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve_with_pdas(
+        &self,
+        SwapLiquidityPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            ..
+        }: SwapLiquidityPdas,
+    ) -> Result<AddLiquidityMultiKeys, SControllerError> {
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        Ok(AddLiquidityMultiKeys {
+            signer: self.signer,
+            dst_lp_acc: self.dst_lp_acc,
+            lp_token_mint: pool_state.lp_token_mint,
+            lp_token_program: spl_token::ID,
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+        })
+    }
+
+    /// Returns:
+    /// (LST account group keys, index of lst on lst_state_list, additional program IDs)
+    pub fn resolve_lst<M: ReadonlyAccountOwner + ReadonlyAccountPubkey>(
+        &self,
+        src_lst_acc: Pubkey,
+        lst_mint: M,
+    ) -> Result<
+        (
+            AddLiquidityMultiLstKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_lst_with_pdas(
+            SwapLiquidityPdas {
+                pool_state: POOL_STATE_ID,
+                lst_state_list: LST_STATE_LIST_ID,
+                protocol_fee: PROTOCOL_FEE_ID,
+            },
+            src_lst_acc,
+            lst_mint,
+        )
+    }
+
+    pub fn resolve_lst_for_prog<M: ReadonlyAccountOwner + ReadonlyAccountPubkey>(
+        &self,
+        program_id: Pubkey,
+        src_lst_acc: Pubkey,
+        lst_mint: M,
+    ) -> Result<
+        (
+            AddLiquidityMultiLstKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_lst_with_pdas(
+            SwapLiquidityPdas::find_for_program_id(program_id),
+            src_lst_acc,
+            lst_mint,
+        )
+    }
+
+    /// Returns:
+    /// (LST account group keys, index of lst on lst_state_list, additional program IDs)
+    pub fn resolve_lst_with_pdas<M: ReadonlyAccountOwner + ReadonlyAccountPubkey>(
+        &self,
+        SwapLiquidityPdas {
+            pool_state: pool_state_id,
+            protocol_fee: protocol_fee_id,
+            ..
+        }: SwapLiquidityPdas,
+        src_lst_acc: Pubkey,
+        lst_mint: M,
+    ) -> Result<
+        (
+            AddLiquidityMultiLstKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let (lst_index, lst_state) = try_find_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
+        let protocol_fee_accumulator =
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                lst_state,
+                *lst_mint.owner(),
+            )?;
+
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok((
+            AddLiquidityMultiLstKeys {
+                lst_mint: *lst_mint.pubkey(),
+                src_lst_acc,
+                pool_reserves,
+                protocol_fee_accumulator,
+                lst_token_program: *lst_mint.owner(),
+            },
+            lst_index,
+            AddRemoveLiquidityProgramIds {
+                lst_calculator_program_id: lst_state.sol_value_calculator,
+                pricing_program_id: pool_state.pricing_program,
+            },
+        ))
+    }
+}
@@ -0,0 +1,150 @@
+use s_controller_interface::{
+    InitLpTokenMetadataKeys, PoolState, SControllerError, SetLpTokenMetadataKeys,
+};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    find_lp_token_mpl_metadata_address, find_pool_state_address, program::POOL_STATE_ID,
+    try_pool_state,
+};
+
+struct LpTokenMetadataComputedKeys {
+    pub admin: Pubkey,
+    pub pool_state: Pubkey,
+    pub lp_token_mint: Pubkey,
+    pub metadata: Pubkey,
+    pub metadata_program: Pubkey,
+}
+
+/// Resolves keys for both InitLpTokenMetadata and SetLpTokenMetadata.
+///
+/// Metadata is stored in the metaplex metadata PDA for Tokenkeg LP token mints
+/// and in the LP token mint itself for Token-2022 LP token mints
+#[derive(Clone, Copy, Debug)]
+pub struct LpTokenMetadataFreeArgs<S, M> {
+    pub payer: Pubkey,
+    pub pool_state: S,
+    pub lp_token_mint: M,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > LpTokenMetadataFreeArgs<S, M>
+{
+    pub fn resolve_init(&self) -> Result<InitLpTokenMetadataKeys, SControllerError> {
+        if *self.pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        Ok(self.compute_keys(POOL_STATE_ID)?.into_init_keys(self.payer))
+    }
+
+    pub fn resolve_set(&self) -> Result<SetLpTokenMetadataKeys, SControllerError> {
+        if *self.pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        Ok(self.compute_keys(POOL_STATE_ID)?.into_set_keys(self.payer))
+    }
+}
+
+impl<S: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
+    LpTokenMetadataFreeArgs<S, M>
+{
+    pub fn resolve_init_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<InitLpTokenMetadataKeys, SControllerError> {
+        let pool_state_id = find_pool_state_address(program_id).0;
+        Ok(self.compute_keys(pool_state_id)?.into_init_keys(self.payer))
+    }
+
+    pub fn resolve_set_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<SetLpTokenMetadataKeys, SControllerError> {
+        let pool_state_id = find_pool_state_address(program_id).0;
+        Ok(self.compute_keys(pool_state_id)?.into_set_keys(self.payer))
+    }
+
+    fn compute_keys(
+        &self,
+        pool_state_id: Pubkey,
+    ) -> Result<LpTokenMetadataComputedKeys, SControllerError> {
+        let Self {
+            pool_state: pool_state_acc,
+            lp_token_mint: lp_token_mint_acc,
+            ..
+        } = self;
+
+        let pool_state_data = pool_state_acc.data();
+        let PoolState {
+            admin,
+            lp_token_mint,
+            ..
+        } = try_pool_state(&pool_state_data)?;
+        if *lp_token_mint_acc.pubkey() != *lp_token_mint {
+            return Err(SControllerError::IncorrectLpMintInitialization);
+        }
+
+        let lp_token_program = *lp_token_mint_acc.owner();
+        let (metadata, metadata_program) = if lp_token_program == spl_token::ID {
+            (
+                find_lp_token_mpl_metadata_address(*lp_token_mint).0,
+                crate::mpl_token_metadata::ID,
+            )
+        } else if lp_token_program == spl_token_2022::ID {
+            (*lp_token_mint, spl_token_2022::ID)
+        } else {
+            return Err(SControllerError::UnsupportedLpTokenProgram);
+        };
+
+        Ok(LpTokenMetadataComputedKeys {
+            admin: *admin,
+            pool_state: pool_state_id,
+            lp_token_mint: *lp_token_mint,
+            metadata,
+            metadata_program,
+        })
+    }
+}
+
+impl LpTokenMetadataComputedKeys {
+    fn into_init_keys(self, payer: Pubkey) -> InitLpTokenMetadataKeys {
+        let Self {
+            admin,
+            pool_state,
+            lp_token_mint,
+            metadata,
+            metadata_program,
+        } = self;
+        InitLpTokenMetadataKeys {
+            admin,
+            payer,
+            pool_state,
+            lp_token_mint,
+            metadata,
+            metadata_program,
+            system_program: system_program::ID,
+        }
+    }
+
+    fn into_set_keys(self, payer: Pubkey) -> SetLpTokenMetadataKeys {
+        let Self {
+            admin,
+            pool_state,
+            lp_token_mint,
+            metadata,
+            metadata_program,
+        } = self;
+        SetLpTokenMetadataKeys {
+            admin,
+            payer,
+            pool_state,
+            lp_token_mint,
+            metadata,
+            metadata_program,
+            system_program: system_program::ID,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod add_disable_pool_authority;
 mod add_liquidity;
+mod add_liquidity_multi;
 mod add_lst;
 mod common;
 mod disable_enable_lst_input;
@@ -7,8 +8,10 @@ mod disable_pool;
 mod enable_pool;
 mod end_rebalance;
 mod initialize;
+mod lp_token_metadata;
 mod remove_disable_pool_authority;
 mod remove_liquidity;
+mod remove_liquidity_basket;
 mod remove_lst;
 mod set_admin;
 mod set_pricing_program;
@@ -23,6 +26,7 @@ mod withdraw_protocol_fees;
 
 pub use add_disable_pool_authority::*;
 pub use add_liquidity::*;
+pub use add_liquidity_multi::*;
 pub use add_lst::*;
 pub use common::*;
 pub use disable_enable_lst_input::*;
@@ -30,8 +34,10 @@ pub use disable_pool::*;
 pub use enable_pool::*;
 pub use end_rebalance::*;
 pub use initialize::*;
+pub use lp_token_metadata::*;
 pub use remove_disable_pool_authority::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_basket::*;
 pub use remove_lst::*;
 pub use set_admin::*;
 pub use set_pricing_program::*;
@@ -59,6 +59,77 @@ pub fn calc_lp_tokens_sol_value(
     .apply(lp_tokens_amount)
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CalcRemoveLiquidityBasketArgs {
+    pub lp_token_supply: u64,
+
+    /// Balance of the LST's pool_reserves
+    pub reserves_balance: u64,
+
+    pub lp_token_amount: u64,
+
+    /// Result of calc_lp_tokens_sol_value(lp_token_amount)
+    pub lp_tokens_sol_value: u64,
+
+    /// Result of CPI PriceLpTokensToRedeem(lp_token_amount, lp_tokens_sol_value)
+    pub lp_tokens_sol_value_after_fees: u64,
+
+    pub lp_protocol_fee_bps: u16,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CalcRemoveLiquidityBasketResult {
+    /// Amount of LST to transfer to the user
+    pub to_user_lst_amount: u64,
+
+    /// Amount of LST to transfer to protocol_fee_accumulator
+    pub to_protocol_fees_lst_amount: u64,
+}
+
+/// Returns amounts of LST to pay out from a pool reserves of `reserves_balance`
+/// when redeeming `lp_token_amount` for a proportional basket of all LSTs.
+///
+/// The LP tokens' pro-rata share of the reserves is reduced by the same ratio as
+/// their SOL value is by the LP withdrawal fee, so every LST is charged the same fee rate.
+/// The protocol's share of that fee is then taken from the fee, the rest stays in the pool
+pub fn calc_remove_liquidity_basket_lst_amounts(
+    CalcRemoveLiquidityBasketArgs {
+        lp_token_supply,
+        reserves_balance,
+        lp_token_amount,
+        lp_tokens_sol_value,
+        lp_tokens_sol_value_after_fees,
+        lp_protocol_fee_bps,
+    }: CalcRemoveLiquidityBasketArgs,
+) -> Result<CalcRemoveLiquidityBasketResult, MathError> {
+    if reserves_balance == 0 || lp_token_supply == 0 {
+        return Ok(CalcRemoveLiquidityBasketResult {
+            to_user_lst_amount: 0,
+            to_protocol_fees_lst_amount: 0,
+        });
+    }
+    let lst_amount_before_fees = FloorDiv(U64Ratio {
+        num: reserves_balance,
+        denom: lp_token_supply,
+    })
+    .apply(lp_token_amount)?;
+    let to_user_lst_amount = if lp_tokens_sol_value == 0 {
+        lst_amount_before_fees
+    } else {
+        FloorDiv(U64Ratio {
+            num: lp_tokens_sol_value_after_fees,
+            denom: lp_tokens_sol_value,
+        })
+        .apply(lst_amount_before_fees)?
+    };
+    let lp_fees_lst_amount = lst_amount_before_fees.saturating_sub(to_user_lst_amount);
+    let aaf = CeilDiv(U64BpsFee::try_new(lp_protocol_fee_bps)?).apply(lp_fees_lst_amount)?;
+    Ok(CalcRemoveLiquidityBasketResult {
+        to_user_lst_amount,
+        to_protocol_fees_lst_amount: aaf.fee_charged(),
+    })
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct CalcAddLiquidityArgs {
     pub lst_amount: u64,
@@ -175,3 +246,108 @@ pub fn calc_swap_protocol_fees(
     .apply(protocol_fees_sol_value)?;
     Ok(to_protocol_fees_lst_amount)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// LSTs worth 1 SOL each so that reserves balances are also SOL values,
+    /// kept small enough for the pool's total SOL value to fit in a u64
+    fn basket_reserves() -> impl Strategy<Value = Vec<u64>> {
+        prop::collection::vec(0..=u64::MAX / 8, 1..=8)
+    }
+
+    prop_compose! {
+        fn basket_args()
+            (reserves in basket_reserves(), lp_token_supply in 1..=u64::MAX)
+            (
+                lp_token_amount in 0..=lp_token_supply,
+                lp_withdrawal_fee_bps in 0..=10_000u64,
+                lp_protocol_fee_bps in 0..=10_000u16,
+                reserves in Just(reserves),
+                lp_token_supply in Just(lp_token_supply),
+            ) -> (Vec<u64>, LpTokenRateArgs, u64, u64, u16) {
+                let pool_total_sol_value = reserves.iter().sum();
+                (
+                    reserves,
+                    LpTokenRateArgs { lp_token_supply, pool_total_sol_value },
+                    lp_token_amount,
+                    lp_withdrawal_fee_bps,
+                    lp_protocol_fee_bps,
+                )
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn basket_never_pays_out_more_than_lp_tokens_sol_value(
+            (reserves, rate_args, lp_token_amount, lp_withdrawal_fee_bps, lp_protocol_fee_bps) in basket_args(),
+        ) {
+            let lp_tokens_sol_value = calc_lp_tokens_sol_value(rate_args, lp_token_amount).unwrap();
+            let lp_tokens_sol_value_after_fees = u64::try_from(
+                u128::from(lp_tokens_sol_value) * u128::from(10_000 - lp_withdrawal_fee_bps) / 10_000,
+            )
+            .unwrap();
+
+            let mut total_to_user: u64 = 0;
+            let mut total_paid: u64 = 0;
+            for reserves_balance in reserves {
+                let CalcRemoveLiquidityBasketResult {
+                    to_user_lst_amount,
+                    to_protocol_fees_lst_amount,
+                } = calc_remove_liquidity_basket_lst_amounts(CalcRemoveLiquidityBasketArgs {
+                    lp_token_supply: rate_args.lp_token_supply,
+                    reserves_balance,
+                    lp_token_amount,
+                    lp_tokens_sol_value,
+                    lp_tokens_sol_value_after_fees,
+                    lp_protocol_fee_bps,
+                })
+                .unwrap();
+                let paid = to_user_lst_amount + to_protocol_fees_lst_amount;
+                prop_assert!(paid <= reserves_balance, "{paid} {reserves_balance}");
+                total_to_user += to_user_lst_amount;
+                total_paid += paid;
+            }
+            prop_assert!(
+                total_to_user <= lp_tokens_sol_value_after_fees,
+                "{total_to_user} {lp_tokens_sol_value_after_fees}"
+            );
+            prop_assert!(total_paid <= lp_tokens_sol_value, "{total_paid} {lp_tokens_sol_value}");
+        }
+
+        #[test]
+        fn basket_zero_supply_or_reserves_pays_nothing(
+            lp_token_supply: u64,
+            reserves_balance: u64,
+            lp_token_amount: u64,
+            lp_tokens_sol_value: u64,
+            lp_tokens_sol_value_after_fees: u64,
+            lp_protocol_fee_bps: u16,
+            zero_supply: bool,
+        ) {
+            let (lp_token_supply, reserves_balance) = if zero_supply {
+                (0, reserves_balance)
+            } else {
+                (lp_token_supply, 0)
+            };
+            let res = calc_remove_liquidity_basket_lst_amounts(CalcRemoveLiquidityBasketArgs {
+                lp_token_supply,
+                reserves_balance,
+                lp_token_amount,
+                lp_tokens_sol_value,
+                lp_tokens_sol_value_after_fees,
+                lp_protocol_fee_bps,
+            })
+            .unwrap();
+            prop_assert_eq!(
+                res,
+                CalcRemoveLiquidityBasketResult {
+                    to_user_lst_amount: 0,
+                    to_protocol_fees_lst_amount: 0,
+                }
+            );
+        }
+    }
+}
@@ -8,6 +8,14 @@ pub mod initial_authority {
     sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
 }
 
+/// Metaplex token metadata program.
+/// Stores the metadata of Tokenkeg LP token mints
+pub mod mpl_token_metadata {
+    sanctum_macros::declare_program_keys!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s", []);
+}
+
+pub const MPL_TOKEN_METADATA_PDA_SEED: &[u8] = b"metadata";
+
 pub const CURRENT_PROGRAM_VERS: u8 = 1;
 
 /// 10% of trading fees
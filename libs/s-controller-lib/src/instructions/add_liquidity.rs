@@ -7,8 +7,9 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, Readonl
 
 use crate::{
     index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
-    ix_extend_with_sol_value_calculator_accounts, AddLiquidityByMintFreeArgs,
-    AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityExtraAccounts,
+    ix_extend_with_sol_value_calculator_accounts, resolve_transfer_hook_accounts,
+    AddLiquidityByMintFreeArgs, AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityExtraAccounts,
+    TransferHookTransfer,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -46,6 +47,7 @@ pub fn add_liquidity_ix_full_for_prog(
         pricing_program_id,
         lst_calculator_accounts,
         pricing_program_price_lp_accounts,
+        transfer_hook,
     }: AddRemoveLiquidityExtraAccounts,
 ) -> Result<Instruction, ProgramError> {
     let lst_index = index_to_u32(lst_index)?;
@@ -71,6 +73,28 @@ pub fn add_liquidity_ix_full_for_prog(
         pricing_program_id,
     )
     .map_err(|_e| SControllerError::MathError)?;
+    if let Some(transfer_hook) = transfer_hook {
+        let mut transfer_hook_accounts = vec![];
+        resolve_transfer_hook_accounts(
+            &mut transfer_hook_accounts,
+            transfer_hook,
+            &[
+                TransferHookTransfer {
+                    source: accounts.src_lst_acc,
+                    destination: accounts.pool_reserves,
+                    authority: accounts.signer,
+                    amount: lst_amount,
+                },
+                TransferHookTransfer {
+                    source: accounts.pool_reserves,
+                    destination: accounts.protocol_fee_accumulator,
+                    authority: accounts.pool_state,
+                    amount: lst_amount,
+                },
+            ],
+        )?;
+        ix.accounts.extend(transfer_hook_accounts);
+    }
     // TODO: better way to update lst_value_calc_accs than double serialization here
     let mut overwrite = &mut ix.data[..];
     AddLiquidityIxData(AddLiquidityIxArgs {
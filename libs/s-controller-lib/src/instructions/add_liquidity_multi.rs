@@ -0,0 +1,186 @@
+use s_controller_interface::{
+    add_liquidity_multi_ix_with_program_id, AddLiquidityMultiIxArgs, AddLiquidityMultiIxData,
+    AddLiquidityMultiKeys, AddLiquidityMultiLst, SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
+    ix_extend_with_sol_value_calculator_accounts, resolve_transfer_hook_accounts,
+    AddLiquidityMultiByMintFreeArgs, AddLiquidityMultiLstKeys, AddRemoveLiquidityAccountSuffixes,
+    AddRemoveLiquidityExtraAccounts, TransferHookTransfer, ADD_LIQUIDITY_MULTI_LST_ACCOUNTS_LEN,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddLiquidityMultiLstFullArgs<'me> {
+    pub keys: AddLiquidityMultiLstKeys,
+    pub lst_index: usize,
+    pub lst_amount: u64,
+    pub extra_accounts: AddRemoveLiquidityExtraAccounts<'me>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddLiquidityMultiLstByMintArgs<'me, M: ReadonlyAccountOwner + ReadonlyAccountPubkey> {
+    pub src_lst_acc: Pubkey,
+    pub lst_mint: M,
+    pub lst_amount: u64,
+    pub account_suffixes: AddRemoveLiquidityAccountSuffixes<'me>,
+}
+
+pub fn add_liquidity_multi_ix_full(
+    accounts: AddLiquidityMultiKeys,
+    lsts: &[AddLiquidityMultiLstFullArgs],
+    min_lp_out: u64,
+) -> Result<Instruction, ProgramError> {
+    add_liquidity_multi_ix_full_for_prog(crate::program::ID, accounts, lsts, min_lp_out)
+}
+
+/// Each LST's account group is appended to the instruction's accounts in the order of `lsts`:
+/// the [`AddLiquidityMultiLstKeys`] accounts, then its SOL value calculator accounts,
+/// then its pricing program PriceLpTokensToMint accounts.
+/// The accounts required by the LSTs' transfer hooks, if any, are appended last
+pub fn add_liquidity_multi_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: AddLiquidityMultiKeys,
+    lsts: &[AddLiquidityMultiLstFullArgs],
+    min_lp_out: u64,
+) -> Result<Instruction, ProgramError> {
+    let mut ix = add_liquidity_multi_ix_with_program_id(
+        program_id,
+        accounts,
+        AddLiquidityMultiIxArgs {
+            lsts: vec![],
+            min_lp_out,
+        },
+    )?;
+    let mut lsts_args = Vec::with_capacity(lsts.len());
+    let mut transfer_hook_accounts = vec![];
+    for AddLiquidityMultiLstFullArgs {
+        keys,
+        lst_index,
+        lst_amount,
+        extra_accounts:
+            AddRemoveLiquidityExtraAccounts {
+                lst_calculator_program_id,
+                pricing_program_id,
+                lst_calculator_accounts,
+                pricing_program_price_lp_accounts,
+                transfer_hook,
+            },
+    } in lsts
+    {
+        let metas: [AccountMeta; ADD_LIQUIDITY_MULTI_LST_ACCOUNTS_LEN] = (*keys).into();
+        ix.accounts.extend(metas);
+        let lst_value_calc_accs = ix_extend_with_sol_value_calculator_accounts(
+            &mut ix,
+            lst_calculator_accounts,
+            *lst_calculator_program_id,
+        )
+        .map_err(|_e| SControllerError::MathError)?;
+        let pricing_accs = ix_extend_with_pricing_program_price_lp_accounts(
+            &mut ix,
+            pricing_program_price_lp_accounts,
+            *pricing_program_id,
+        )
+        .map_err(|_e| SControllerError::MathError)?;
+        if let Some(transfer_hook) = transfer_hook {
+            resolve_transfer_hook_accounts(
+                &mut transfer_hook_accounts,
+                *transfer_hook,
+                &[
+                    TransferHookTransfer {
+                        source: keys.src_lst_acc,
+                        destination: keys.pool_reserves,
+                        authority: accounts.signer,
+                        amount: *lst_amount,
+                    },
+                    TransferHookTransfer {
+                        source: keys.pool_reserves,
+                        destination: keys.protocol_fee_accumulator,
+                        authority: accounts.pool_state,
+                        amount: *lst_amount,
+                    },
+                ],
+            )?;
+        }
+        lsts_args.push(AddLiquidityMultiLst {
+            lst_value_calc_accs,
+            pricing_accs,
+            lst_index: index_to_u32(*lst_index)?,
+            lst_amount: *lst_amount,
+        });
+    }
+    ix.accounts.extend(transfer_hook_accounts);
+    // TODO: better way to update lsts than double serialization here
+    ix.data = AddLiquidityMultiIxData(AddLiquidityMultiIxArgs {
+        lsts: lsts_args,
+        min_lp_out,
+    })
+    .try_to_vec()?;
+    Ok(ix)
+}
+
+pub fn add_liquidity_multi_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: AddLiquidityMultiByMintFreeArgs<S, L>,
+    lsts: Vec<AddLiquidityMultiLstByMintArgs<M>>,
+    min_lp_out: u64,
+) -> Result<Instruction, ProgramError> {
+    let keys = free_args.resolve()?;
+    let mut full_args = Vec::with_capacity(lsts.len());
+    for AddLiquidityMultiLstByMintArgs {
+        src_lst_acc,
+        lst_mint,
+        lst_amount,
+        account_suffixes,
+    } in lsts
+    {
+        let (keys, lst_index, program_ids) = free_args.resolve_lst(src_lst_acc, lst_mint)?;
+        full_args.push(AddLiquidityMultiLstFullArgs {
+            keys,
+            lst_index,
+            lst_amount,
+            extra_accounts: AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+        });
+    }
+    add_liquidity_multi_ix_full(keys, &full_args, min_lp_out)
+}
+
+pub fn add_liquidity_multi_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: AddLiquidityMultiByMintFreeArgs<S, L>,
+    lsts: Vec<AddLiquidityMultiLstByMintArgs<M>>,
+    min_lp_out: u64,
+) -> Result<Instruction, ProgramError> {
+    let keys = free_args.resolve_for_prog(program_id)?;
+    let mut full_args = Vec::with_capacity(lsts.len());
+    for AddLiquidityMultiLstByMintArgs {
+        src_lst_acc,
+        lst_mint,
+        lst_amount,
+        account_suffixes,
+    } in lsts
+    {
+        let (keys, lst_index, program_ids) =
+            free_args.resolve_lst_for_prog(program_id, src_lst_acc, lst_mint)?;
+        full_args.push(AddLiquidityMultiLstFullArgs {
+            keys,
+            lst_index,
+            lst_amount,
+            extra_accounts: AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+        });
+    }
+    add_liquidity_multi_ix_full_for_prog(program_id, keys, &full_args, min_lp_out)
+}
@@ -0,0 +1,33 @@
+use borsh::BorshSerialize;
+use s_controller_interface::{
+    Deadline, ADD_LIQUIDITY_IX_DISCM, ADD_LIQUIDITY_WITH_DEADLINE_IX_DISCM,
+    REMOVE_LIQUIDITY_IX_DISCM, REMOVE_LIQUIDITY_WITH_DEADLINE_IX_DISCM, SWAP_EXACT_IN_IX_DISCM,
+    SWAP_EXACT_IN_WITH_DEADLINE_IX_DISCM, SWAP_EXACT_OUT_IX_DISCM,
+    SWAP_EXACT_OUT_WITH_DEADLINE_IX_DISCM,
+};
+use solana_program::{instruction::Instruction, program_error::ProgramError};
+
+/// Converts a full SwapExactIn, SwapExactOut, AddLiquidity or RemoveLiquidity instruction,
+/// e.g. one returned by [`crate::swap_exact_in_ix_full`], into its *WithDeadline variant
+/// that fails if the transaction lands after `deadline`.
+///
+/// The *WithDeadline variants have the same accounts and the same args with `deadline` appended,
+/// so only the discriminant and data suffix need to change
+pub fn ix_with_deadline(
+    mut ix: Instruction,
+    deadline: &Deadline,
+) -> Result<Instruction, ProgramError> {
+    let discm = ix
+        .data
+        .first_mut()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    *discm = match *discm {
+        SWAP_EXACT_IN_IX_DISCM => SWAP_EXACT_IN_WITH_DEADLINE_IX_DISCM,
+        SWAP_EXACT_OUT_IX_DISCM => SWAP_EXACT_OUT_WITH_DEADLINE_IX_DISCM,
+        ADD_LIQUIDITY_IX_DISCM => ADD_LIQUIDITY_WITH_DEADLINE_IX_DISCM,
+        REMOVE_LIQUIDITY_IX_DISCM => REMOVE_LIQUIDITY_WITH_DEADLINE_IX_DISCM,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+    deadline.serialize(&mut ix.data)?;
+    Ok(ix)
+}
@@ -2,23 +2,31 @@
 //! e.g. those that requires additional accounts for SOL value calculator and pricing program CPI calls
 
 mod add_liquidity;
+mod add_liquidity_multi;
+mod deadline;
 mod disable_enable_lst_input;
 mod end_rebalance;
 mod remove_liquidity;
+mod remove_liquidity_basket;
 mod set_sol_value_calculator;
 mod start_rebalance;
 mod swap_exact_in;
 mod swap_exact_out;
 mod sync_sol_value;
+mod transfer_hook;
 mod utils;
 
 pub use add_liquidity::*;
+pub use add_liquidity_multi::*;
+pub use deadline::*;
 pub use disable_enable_lst_input::*;
 pub use end_rebalance::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_basket::*;
 pub use set_sol_value_calculator::*;
 pub use start_rebalance::*;
 pub use swap_exact_in::*;
 pub use swap_exact_out::*;
 pub use sync_sol_value::*;
+pub use transfer_hook::*;
 pub use utils::*;
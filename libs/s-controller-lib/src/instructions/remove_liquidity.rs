@@ -7,8 +7,9 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, Readonl
 
 use crate::{
     index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
-    ix_extend_with_sol_value_calculator_accounts, AddRemoveLiquidityAccountSuffixes,
-    AddRemoveLiquidityExtraAccounts, RemoveLiquidityByMintFreeArgs,
+    ix_extend_with_sol_value_calculator_accounts, resolve_transfer_hook_accounts,
+    AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityExtraAccounts,
+    RemoveLiquidityByMintFreeArgs, TransferHookTransfer,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -47,6 +48,7 @@ pub fn remove_liquidity_ix_full_for_prog(
         pricing_program_id,
         lst_calculator_accounts,
         pricing_program_price_lp_accounts,
+        transfer_hook,
     }: AddRemoveLiquidityExtraAccounts,
 ) -> Result<Instruction, ProgramError> {
     let lst_index = index_to_u32(lst_index)?;
@@ -72,6 +74,28 @@ pub fn remove_liquidity_ix_full_for_prog(
         pricing_program_id,
     )
     .map_err(|_e| SControllerError::MathError)?;
+    if let Some(transfer_hook) = transfer_hook {
+        let mut transfer_hook_accounts = vec![];
+        resolve_transfer_hook_accounts(
+            &mut transfer_hook_accounts,
+            transfer_hook,
+            &[
+                TransferHookTransfer {
+                    source: accounts.pool_reserves,
+                    destination: accounts.dst_lst_acc,
+                    authority: accounts.pool_state,
+                    amount: min_lst_out,
+                },
+                TransferHookTransfer {
+                    source: accounts.pool_reserves,
+                    destination: accounts.protocol_fee_accumulator,
+                    authority: accounts.pool_state,
+                    amount: min_lst_out,
+                },
+            ],
+        )?;
+        ix.accounts.extend(transfer_hook_accounts);
+    }
     // TODO: better way to update lst_value_calc_accs than double serialization here
     let mut overwrite = &mut ix.data[..];
     RemoveLiquidityIxData(RemoveLiquidityIxArgs {
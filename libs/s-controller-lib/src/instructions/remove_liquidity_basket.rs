@@ -0,0 +1,228 @@
+use s_controller_interface::{
+    remove_liquidity_basket_ix_with_program_id, RemoveLiquidityBasketIxArgs,
+    RemoveLiquidityBasketIxData, RemoveLiquidityBasketKeys, RemoveLiquidityBasketLst,
+    SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    ix_extend_with_pricing_program_price_lp_accounts, ix_extend_with_sol_value_calculator_accounts,
+    try_pool_state, RemoveLiquidityBasketByMintFreeArgs, RemoveLiquidityBasketLstKeys,
+    REMOVE_LIQUIDITY_BASKET_LST_ACCOUNTS_LEN,
+};
+
+/// lst_calculator_accounts should include the common interface account prefixes
+/// but exclude the program ID
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityBasketLstFullArgs<'me> {
+    pub keys: RemoveLiquidityBasketLstKeys,
+    pub min_lst_out: u64,
+    pub lst_calculator_program_id: Pubkey,
+    pub lst_calculator_accounts: &'me [AccountMeta],
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityBasketLstByMintArgs<'me, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
+{
+    pub dst_lst_acc: Pubkey,
+    pub lst_mint: M,
+    pub min_lst_out: u64,
+    pub lst_calculator_accounts: &'me [AccountMeta],
+}
+
+/// pricing_program_price_lp_accounts should include the common interface account prefixes
+/// but exclude the program ID
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityBasketPricingArgs<'me> {
+    pub pricing_program_id: Pubkey,
+
+    /// PriceLpTokensToRedeem accounts for the first LST on lst_state_list
+    pub pricing_program_price_lp_accounts: &'me [AccountMeta],
+}
+
+pub fn remove_liquidity_basket_ix_full(
+    accounts: RemoveLiquidityBasketKeys,
+    lp_token_amount: u64,
+    lsts: &[RemoveLiquidityBasketLstFullArgs],
+    pricing: RemoveLiquidityBasketPricingArgs,
+) -> Result<Instruction, ProgramError> {
+    remove_liquidity_basket_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        lp_token_amount,
+        lsts,
+        pricing,
+    )
+}
+
+/// `lsts` must contain every LST on lst_state_list, in the same order as lst_state_list.
+///
+/// Each LST's account group is appended to the instruction's accounts in that order:
+/// the [`RemoveLiquidityBasketLstKeys`] accounts, then its SOL value calculator accounts.
+/// The pricing program PriceLpTokensToRedeem accounts are appended after all the LSTs'
+pub fn remove_liquidity_basket_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityBasketKeys,
+    lp_token_amount: u64,
+    lsts: &[RemoveLiquidityBasketLstFullArgs],
+    RemoveLiquidityBasketPricingArgs {
+        pricing_program_id,
+        pricing_program_price_lp_accounts,
+    }: RemoveLiquidityBasketPricingArgs,
+) -> Result<Instruction, ProgramError> {
+    let mut ix = remove_liquidity_basket_ix_with_program_id(
+        program_id,
+        accounts,
+        RemoveLiquidityBasketIxArgs {
+            lp_token_amount,
+            lsts: vec![],
+        },
+    )?;
+    let mut lsts_args = Vec::with_capacity(lsts.len());
+    for RemoveLiquidityBasketLstFullArgs {
+        keys,
+        min_lst_out,
+        lst_calculator_program_id,
+        lst_calculator_accounts,
+    } in lsts
+    {
+        let metas: [AccountMeta; REMOVE_LIQUIDITY_BASKET_LST_ACCOUNTS_LEN] = (*keys).into();
+        ix.accounts.extend(metas);
+        let lst_value_calc_accs = ix_extend_with_sol_value_calculator_accounts(
+            &mut ix,
+            lst_calculator_accounts,
+            *lst_calculator_program_id,
+        )
+        .map_err(|_e| SControllerError::MathError)?;
+        lsts_args.push(RemoveLiquidityBasketLst {
+            lst_value_calc_accs,
+            min_lst_out: *min_lst_out,
+        });
+    }
+    ix_extend_with_pricing_program_price_lp_accounts(
+        &mut ix,
+        pricing_program_price_lp_accounts,
+        pricing_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    // TODO: better way to update lsts than double serialization here
+    ix.data = RemoveLiquidityBasketIxData(RemoveLiquidityBasketIxArgs {
+        lp_token_amount,
+        lsts: lsts_args,
+    })
+    .try_to_vec()?;
+    Ok(ix)
+}
+
+/// `lsts` may be in any order, they are sorted into lst_state_list's order.
+///
+/// `pricing_program_price_lp_accounts` are the pool's pricing program's PriceLpTokensToRedeem
+/// accounts for the first LST on lst_state_list, excluding the program ID
+pub fn remove_liquidity_basket_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: RemoveLiquidityBasketByMintFreeArgs<S, L>,
+    lp_token_amount: u64,
+    lsts: Vec<RemoveLiquidityBasketLstByMintArgs<M>>,
+    pricing_program_price_lp_accounts: &[AccountMeta],
+) -> Result<Instruction, ProgramError> {
+    let keys = free_args.resolve()?;
+    let mut indexed_full_args = Vec::with_capacity(lsts.len());
+    for RemoveLiquidityBasketLstByMintArgs {
+        dst_lst_acc,
+        lst_mint,
+        min_lst_out,
+        lst_calculator_accounts,
+    } in lsts
+    {
+        let (keys, lst_index, lst_calculator_program_id) =
+            free_args.resolve_lst(dst_lst_acc, lst_mint)?;
+        indexed_full_args.push((
+            lst_index,
+            RemoveLiquidityBasketLstFullArgs {
+                keys,
+                min_lst_out,
+                lst_calculator_program_id,
+                lst_calculator_accounts,
+            },
+        ));
+    }
+    indexed_full_args.sort_by_key(|(lst_index, _)| *lst_index);
+    let full_args: Vec<RemoveLiquidityBasketLstFullArgs> =
+        indexed_full_args.into_iter().map(|(_, a)| a).collect();
+    let pricing_program_id = {
+        let pool_state_data = free_args.pool_state.data();
+        try_pool_state(&pool_state_data)?.pricing_program
+    };
+    remove_liquidity_basket_ix_full(
+        keys,
+        lp_token_amount,
+        &full_args,
+        RemoveLiquidityBasketPricingArgs {
+            pricing_program_id,
+            pricing_program_price_lp_accounts,
+        },
+    )
+}
+
+/// `lsts` may be in any order, they are sorted into lst_state_list's order.
+///
+/// `pricing_program_price_lp_accounts` are the pool's pricing program's PriceLpTokensToRedeem
+/// accounts for the first LST on lst_state_list, excluding the program ID
+pub fn remove_liquidity_basket_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: RemoveLiquidityBasketByMintFreeArgs<S, L>,
+    lp_token_amount: u64,
+    lsts: Vec<RemoveLiquidityBasketLstByMintArgs<M>>,
+    pricing_program_price_lp_accounts: &[AccountMeta],
+) -> Result<Instruction, ProgramError> {
+    let keys = free_args.resolve_for_prog(program_id)?;
+    let mut indexed_full_args = Vec::with_capacity(lsts.len());
+    for RemoveLiquidityBasketLstByMintArgs {
+        dst_lst_acc,
+        lst_mint,
+        min_lst_out,
+        lst_calculator_accounts,
+    } in lsts
+    {
+        let (keys, lst_index, lst_calculator_program_id) =
+            free_args.resolve_lst_for_prog(program_id, dst_lst_acc, lst_mint)?;
+        indexed_full_args.push((
+            lst_index,
+            RemoveLiquidityBasketLstFullArgs {
+                keys,
+                min_lst_out,
+                lst_calculator_program_id,
+                lst_calculator_accounts,
+            },
+        ));
+    }
+    indexed_full_args.sort_by_key(|(lst_index, _)| *lst_index);
+    let full_args: Vec<RemoveLiquidityBasketLstFullArgs> =
+        indexed_full_args.into_iter().map(|(_, a)| a).collect();
+    let pricing_program_id = {
+        let pool_state_data = free_args.pool_state.data();
+        try_pool_state(&pool_state_data)?.pricing_program
+    };
+    remove_liquidity_basket_ix_full_for_prog(
+        program_id,
+        keys,
+        lp_token_amount,
+        &full_args,
+        RemoveLiquidityBasketPricingArgs {
+            pricing_program_id,
+            pricing_program_price_lp_accounts,
+        },
+    )
+}
@@ -0,0 +1,179 @@
+use s_controller_interface::{SwapExactInKeys, SWAP_EXACT_IN_IX_ACCOUNTS_LEN};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::{
+    get_extra_account_metas_address,
+    instruction::{execute, ExecuteInstruction},
+};
+use spl_type_length_value::state::TlvStateBorrowed;
+
+/// Number of accounts of the transfer hook interface's Execute instruction
+/// preceding the extra accounts: source, mint, destination, authority, ExtraAccountMetaList
+const EXECUTE_IX_FIXED_ACCOUNTS_LEN: usize = 5;
+
+/// Data required to resolve the accounts required by a Token-2022 LST's transfer hook
+#[derive(Debug, Clone, Copy)]
+pub struct TransferHookAccountsData<'me> {
+    pub lst_mint: Pubkey,
+
+    /// The transfer hook program of the LST mint's TransferHook extension
+    pub program_id: Pubkey,
+
+    /// Data of the transfer hook program's ExtraAccountMetaList account for the LST mint
+    pub extra_account_metas: &'me [u8],
+}
+
+/// A transfer of the LST made by the S controller instruction
+#[derive(Debug, Clone, Copy)]
+pub struct TransferHookTransfer {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+
+    /// The exact amount of most transfers out of the pool is only known on-chain,
+    /// so transfer hooks whose extra accounts depend on it are not supported for those transfers
+    pub amount: u64,
+}
+
+/// Resolves the accounts required by the LST's transfer hook for each of `transfers`:
+/// the transfer hook program, its ExtraAccountMetaList account and the extra accounts,
+/// and appends the ones not yet in `resolved` to it.
+///
+/// `resolved` should then be appended to the end of the instruction's accounts,
+/// since the S controller only looks for the transfer hook accounts in its accounts suffix.
+///
+/// Extra accounts with seeds derived from account data are not supported
+/// since no account data is available here.
+pub fn resolve_transfer_hook_accounts(
+    resolved: &mut Vec<AccountMeta>,
+    TransferHookAccountsData {
+        lst_mint,
+        program_id,
+        extra_account_metas,
+    }: TransferHookAccountsData,
+    transfers: &[TransferHookTransfer],
+) -> Result<(), ProgramError> {
+    let tlv_state = TlvStateBorrowed::unpack(extra_account_metas)?;
+    let extra_account_metas =
+        ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state)?;
+    let validate_state = get_extra_account_metas_address(&lst_mint, &program_id);
+    push_or_merge_account_meta(resolved, AccountMeta::new_readonly(program_id, false));
+    push_or_merge_account_meta(resolved, AccountMeta::new_readonly(validate_state, false));
+    for TransferHookTransfer {
+        source,
+        destination,
+        authority,
+        amount,
+    } in transfers
+    {
+        let mut execute_ix = execute(
+            &program_id,
+            source,
+            &lst_mint,
+            destination,
+            authority,
+            &validate_state,
+            *amount,
+        );
+        // extra accounts may be derived from previously resolved extra accounts
+        for extra_account_meta in extra_account_metas.data() {
+            let meta =
+                extra_account_meta.resolve(&execute_ix.data, &program_id, |account_index| {
+                    execute_ix
+                        .accounts
+                        .get(account_index)
+                        .map(|meta| (&meta.pubkey, None))
+                })?;
+            execute_ix.accounts.push(meta);
+        }
+        for meta in execute_ix
+            .accounts
+            .into_iter()
+            .skip(EXECUTE_IX_FIXED_ACCOUNTS_LEN)
+        {
+            push_or_merge_account_meta(resolved, meta);
+        }
+    }
+    Ok(())
+}
+
+/// Appends the accounts required by the transfer hooks of a SwapExactIn or SwapExactOut instruction's LSTs,
+/// if any, to the end of `ix`'s accounts. Both instructions have the same account layout.
+///
+/// `src_amount` and `dst_amount` are the amounts of src and dst LSTs transferred from and to the user
+pub fn ix_extend_with_swap_transfer_hook_accounts(
+    ix: &mut Instruction,
+    src_transfer_hook: Option<TransferHookAccountsData>,
+    src_amount: u64,
+    dst_transfer_hook: Option<TransferHookAccountsData>,
+    dst_amount: u64,
+) -> Result<(), ProgramError> {
+    let keys: [Pubkey; SWAP_EXACT_IN_IX_ACCOUNTS_LEN] = ix
+        .accounts
+        .get(..SWAP_EXACT_IN_IX_ACCOUNTS_LEN)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .iter()
+        .map(|meta| meta.pubkey)
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|_e| ProgramError::NotEnoughAccountKeys)?;
+    let SwapExactInKeys {
+        signer,
+        src_lst_acc,
+        dst_lst_acc,
+        protocol_fee_accumulator,
+        pool_state,
+        src_pool_reserves,
+        dst_pool_reserves,
+        ..
+    } = keys.into();
+    let mut transfer_hook_accounts = vec![];
+    if let Some(src_transfer_hook) = src_transfer_hook {
+        resolve_transfer_hook_accounts(
+            &mut transfer_hook_accounts,
+            src_transfer_hook,
+            &[TransferHookTransfer {
+                source: src_lst_acc,
+                destination: src_pool_reserves,
+                authority: signer,
+                amount: src_amount,
+            }],
+        )?;
+    }
+    if let Some(dst_transfer_hook) = dst_transfer_hook {
+        resolve_transfer_hook_accounts(
+            &mut transfer_hook_accounts,
+            dst_transfer_hook,
+            &[
+                TransferHookTransfer {
+                    source: dst_pool_reserves,
+                    destination: protocol_fee_accumulator,
+                    authority: pool_state,
+                    amount: dst_amount,
+                },
+                TransferHookTransfer {
+                    source: dst_pool_reserves,
+                    destination: dst_lst_acc,
+                    authority: pool_state,
+                    amount: dst_amount,
+                },
+            ],
+        )?;
+    }
+    ix.accounts.extend(transfer_hook_accounts);
+    Ok(())
+}
+
+fn push_or_merge_account_meta(metas: &mut Vec<AccountMeta>, meta: AccountMeta) {
+    match metas.iter_mut().find(|m| m.pubkey == meta.pubkey) {
+        Some(existing) => {
+            existing.is_signer |= meta.is_signer;
+            existing.is_writable |= meta.is_writable;
+        }
+        None => metas.push(meta),
+    }
+}
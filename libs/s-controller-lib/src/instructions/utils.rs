@@ -6,6 +6,8 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
+use crate::TransferHookAccountsData;
+
 #[derive(Debug, Clone, Copy)]
 pub struct SrcDstLstSolValueCalcProgramIds {
     pub src_lst_calculator_program_id: Pubkey,
@@ -68,6 +70,9 @@ pub struct AddRemoveLiquidityProgramIds {
 pub struct AddRemoveLiquidityAccountSuffixes<'me> {
     pub lst_calculator_accounts: &'me [AccountMeta],
     pub pricing_program_price_lp_accounts: &'me [AccountMeta],
+
+    /// None for LSTs without a transfer hook
+    pub transfer_hook: Option<TransferHookAccountsData<'me>>,
 }
 
 /// lst_calculator_accounts & pricing_program_price_lp_accounts should include the common interface account prefixes
@@ -78,6 +83,9 @@ pub struct AddRemoveLiquidityExtraAccounts<'me> {
     pub pricing_program_id: Pubkey,
     pub lst_calculator_accounts: &'me [AccountMeta],
     pub pricing_program_price_lp_accounts: &'me [AccountMeta],
+
+    /// None for LSTs without a transfer hook
+    pub transfer_hook: Option<TransferHookAccountsData<'me>>,
 }
 
 impl<'me> AddRemoveLiquidityExtraAccounts<'me> {
@@ -89,6 +97,7 @@ impl<'me> AddRemoveLiquidityExtraAccounts<'me> {
         AddRemoveLiquidityAccountSuffixes {
             lst_calculator_accounts,
             pricing_program_price_lp_accounts,
+            transfer_hook,
         }: AddRemoveLiquidityAccountSuffixes<'me>,
     ) -> Self {
         Self {
@@ -96,6 +105,7 @@ impl<'me> AddRemoveLiquidityExtraAccounts<'me> {
             pricing_program_id,
             lst_calculator_accounts,
             pricing_program_price_lp_accounts,
+            transfer_hook,
         }
     }
 }
@@ -3,9 +3,10 @@ use sanctum_associated_token_lib::{CreateAtaAddressArgs, FindAtaAddressArgs};
 use solana_program::pubkey::Pubkey;
 
 use crate::{
+    mpl_token_metadata,
     program::{POOL_STATE_ID, PROTOCOL_FEE_ID},
-    DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, LST_STATE_LIST_PDA_SEED, POOL_STATE_PDA_SEED,
-    PROTOCOL_FEE_PDA_SEED, REBALANCE_RECORD_PDA_SEED,
+    DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, LST_STATE_LIST_PDA_SEED, MPL_TOKEN_METADATA_PDA_SEED,
+    POOL_STATE_PDA_SEED, PROTOCOL_FEE_PDA_SEED, REBALANCE_RECORD_PDA_SEED,
 };
 
 pub fn create_pool_reserves_address(
@@ -147,3 +148,16 @@ pub fn find_rebalance_record_address(program_id: Pubkey) -> (Pubkey, u8) {
 pub fn find_protocol_fee_address(program_id: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[PROTOCOL_FEE_PDA_SEED], &program_id)
 }
+
+/// Finds the metaplex token metadata PDA of a Tokenkeg LP token mint.
+/// Token-2022 LP token mints store their metadata in the mint account itself instead
+pub fn find_lp_token_mpl_metadata_address(lp_token_mint: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MPL_TOKEN_METADATA_PDA_SEED,
+            mpl_token_metadata::ID.as_ref(),
+            lp_token_mint.as_ref(),
+        ],
+        &mpl_token_metadata::ID,
+    )
+}
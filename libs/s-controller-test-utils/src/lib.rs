@@ -3,9 +3,13 @@ mod disable_pool_authority_list;
 mod lst_state;
 mod program_test;
 mod state;
+mod token_2022;
+mod transfer_hook;
 
 pub use assertions::*;
 pub use disable_pool_authority_list::*;
 pub use lst_state::*;
 pub use program_test::*;
 pub use state::*;
+pub use token_2022::*;
+pub use transfer_hook::*;
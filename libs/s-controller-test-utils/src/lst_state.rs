@@ -13,6 +13,8 @@ use solana_program::pubkey::Pubkey;
 use solana_program_test::{BanksClient, ProgramTest};
 use solana_sdk::account::Account;
 
+use crate::mock_transfer_fee_token_account;
+
 #[derive(Clone, Copy, Debug)]
 pub struct MockLstStateArgs {
     pub mint: Pubkey,
@@ -33,7 +35,8 @@ pub struct MockLstStateRet {
     pub protocol_fee_accumulator_account: Account,
 }
 
-/// Assumes LST uses original spl-token program
+/// Token-2022 LSTs' accounts are created with the TransferFeeAmount and TransferHookAccount extensions
+/// so that they can hold LSTs with transfer fees and transfer hooks
 pub fn mock_lst_state(
     MockLstStateArgs {
         mint,
@@ -61,12 +64,19 @@ pub fn mock_lst_state(
         padding: Default::default(),
         sol_value_calculator,
     };
-    let reserves_account = mock_tokenkeg_account(MockTokenAccountArgs {
+    let mock_token_account = |args| {
+        if token_program == spl_token_2022::ID {
+            mock_transfer_fee_token_account(args)
+        } else {
+            mock_tokenkeg_account(args).into_account()
+        }
+    };
+    let reserves_account = mock_token_account(MockTokenAccountArgs {
         mint,
         authority: s_controller_lib::program::POOL_STATE_ID,
         amount: reserves_amt,
     });
-    let protocol_fee_accumulator_account = mock_tokenkeg_account(MockTokenAccountArgs {
+    let protocol_fee_accumulator_account = mock_token_account(MockTokenAccountArgs {
         mint,
         authority: s_controller_lib::program::PROTOCOL_FEE_ID,
         amount: protocol_fee_accumulator_amt,
@@ -74,9 +84,9 @@ pub fn mock_lst_state(
     MockLstStateRet {
         lst_state,
         reserves_address,
-        reserves_account: reserves_account.into_account(),
+        reserves_account,
         protocol_fee_accumulator_address,
-        protocol_fee_accumulator_account: protocol_fee_accumulator_account.into_account(),
+        protocol_fee_accumulator_account,
     }
 }
 
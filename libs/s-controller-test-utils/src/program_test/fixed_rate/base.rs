@@ -0,0 +1,110 @@
+use fixed_rate_calculator_test_utils::MockLstRateArgs;
+use s_controller_interface::PoolState;
+use sanctum_solana_test_utils::token::MockMintArgs;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+
+use crate::{
+    AddFixedRateProgramTest, LpTokenProgramTest, LstStateListProgramTest, MockLstStateArgs,
+    DEFAULT_POOL_STATE,
+};
+
+/// A LST whose SOL value is calculated by the fixed-rate calculator
+#[derive(Clone, Copy, Debug)]
+pub struct MockFixedRateLstArgs {
+    pub lst_rate: MockLstRateArgs,
+    pub sol_value: u64,
+    pub reserves: u64,
+    pub protocol_fee_accumulator: u64,
+
+    /// If set, the LST is a Token-2022 mint that charges this transfer fee
+    /// instead of a Tokenkeg mint
+    pub transfer_fee_bps: Option<u16>,
+
+    /// If set, the LST is a Token-2022 mint whose transfer hook is this program,
+    /// charging `transfer_fee_bps` or no transfer fee.
+    /// The transfer hook program and its accounts for the LST must be added separately.
+    pub transfer_hook_program_id: Option<Pubkey>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FixedRateProgramTestArgs {
+    /// lst_state_list is created in this order
+    pub lsts: Vec<MockFixedRateLstArgs>,
+    pub lp_token_mint: Pubkey,
+    pub lp_token_supply: u64,
+}
+
+/// Need to set pricing_program_id on returned PoolState
+/// before adding account.
+/// Dont forget to add the s_controller program afterwards.
+/// Omitted to avoid circular dependencies
+pub fn fixed_rate_base_program_test(
+    FixedRateProgramTestArgs {
+        lsts,
+        lp_token_mint,
+        lp_token_supply,
+    }: FixedRateProgramTestArgs,
+) -> (ProgramTest, PoolState) {
+    let mut program_test = ProgramTest::default().add_fixed_rate_progs();
+    let mut lst_state_args = Vec::with_capacity(lsts.len());
+    let mut total_sol_value = 0;
+    for MockFixedRateLstArgs {
+        lst_rate,
+        sol_value,
+        reserves,
+        protocol_fee_accumulator,
+        transfer_fee_bps,
+        transfer_hook_program_id,
+    } in lsts
+    {
+        let mint_args = MockMintArgs {
+            mint_authority: None,
+            freeze_authority: None,
+            supply: 0,
+            decimals: 9,
+        };
+        let token_program = match (transfer_fee_bps, transfer_hook_program_id) {
+            (None, None) => {
+                program_test = program_test.add_fixed_rate_lst(lst_rate, mint_args);
+                spl_token::ID
+            }
+            (Some(transfer_fee_bps), None) => {
+                program_test = program_test.add_transfer_fee_fixed_rate_lst(
+                    lst_rate,
+                    mint_args,
+                    transfer_fee_bps,
+                );
+                spl_token_2022::ID
+            }
+            (transfer_fee_bps, Some(transfer_hook_program_id)) => {
+                program_test = program_test.add_transfer_hook_fixed_rate_lst(
+                    lst_rate,
+                    mint_args,
+                    transfer_fee_bps.unwrap_or_default(),
+                    transfer_hook_program_id,
+                );
+                spl_token_2022::ID
+            }
+        };
+        lst_state_args.push(MockLstStateArgs {
+            mint: lst_rate.lst_mint,
+            sol_value,
+            reserves_amt: reserves,
+            protocol_fee_accumulator_amt: protocol_fee_accumulator,
+            token_program,
+            sol_value_calculator: fixed_rate_calculator_lib::program::ID,
+            is_input_disabled: false,
+        });
+        total_sol_value += sol_value;
+    }
+    program_test = program_test
+        .add_mock_lst_states(&lst_state_args)
+        .add_mock_lp_mint(lp_token_mint, lp_token_supply);
+
+    let mut pool_state = DEFAULT_POOL_STATE;
+    pool_state.total_sol_value = total_sol_value;
+    pool_state.lp_token_mint = lp_token_mint;
+
+    (program_test, pool_state)
+}
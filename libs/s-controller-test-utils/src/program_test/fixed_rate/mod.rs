@@ -0,0 +1,5 @@
+mod base;
+mod no_fee_pp;
+
+pub use base::*;
+pub use no_fee_pp::*;
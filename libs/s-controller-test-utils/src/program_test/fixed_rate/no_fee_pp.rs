@@ -0,0 +1,21 @@
+use sanctum_solana_test_utils::IntoAccount;
+use solana_program_test::{processor, ProgramTest};
+
+use crate::MockPoolState;
+
+use super::{fixed_rate_base_program_test, FixedRateProgramTestArgs};
+
+pub fn fixed_rate_no_fee_program_test(args: FixedRateProgramTestArgs) -> ProgramTest {
+    let (mut program_test, mut pool_state) = fixed_rate_base_program_test(args);
+    program_test.add_program(
+        "no_fee_pricing_program",
+        no_fee_pricing_program::ID,
+        processor!(no_fee_pricing_program::process_instruction),
+    );
+    pool_state.pricing_program = no_fee_pricing_program::ID;
+    program_test.add_account(
+        s_controller_lib::program::POOL_STATE_ID,
+        MockPoolState(pool_state).into_account(),
+    );
+    program_test
+}
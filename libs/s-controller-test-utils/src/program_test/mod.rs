@@ -1,8 +1,10 @@
+mod fixed_rate;
 mod jito_marinade;
 mod jito_wsol;
 mod lido_wsol;
 mod utils;
 
+pub use fixed_rate::*;
 pub use jito_marinade::*;
 pub use jito_wsol::*;
 pub use lido_wsol::*;
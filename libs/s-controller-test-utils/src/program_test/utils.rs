@@ -1,3 +1,6 @@
+use fixed_rate_calculator_test_utils::{
+    FixedRateCalculatorProgramTest, MockLstRateArgs, DEFAULT_PROGRAM_STATE,
+};
 use generic_pool_calculator_lib::GenericPoolSolValCalc;
 use generic_pool_calculator_test_utils::{
     GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
@@ -5,7 +8,10 @@ use generic_pool_calculator_test_utils::{
 use lido_calculator_lib::LidoSolValCalc;
 use marinade_calculator_lib::MarinadeSolValCalc;
 use sanctum_solana_test_utils::{
-    token::{tokenkeg::mock_tokenkeg_account, MockTokenAccountArgs},
+    token::{
+        tokenkeg::{mock_tokenkeg_account, TokenkegProgramTest},
+        MockMintArgs, MockTokenAccountArgs,
+    },
     ExtendedProgramTest, IntoAccount,
 };
 use solana_program::pubkey::Pubkey;
@@ -16,6 +22,8 @@ use test_utils::{
     SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
 };
 
+use crate::TransferFeeProgramTest;
+
 pub trait AddSplProgramTest {
     fn add_spl_progs(self) -> Self;
 
@@ -33,6 +41,7 @@ impl AddSplProgramTest for ProgramTest {
         self.add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
+            pending_manager: Pubkey::default(),
             owner: SplSolValCalc::ID,
         })
         .add_test_fixtures_account("spl-stake-pool-prog.json")
@@ -62,6 +71,7 @@ impl AddMarinadeProgramTest for ProgramTest {
         self.add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: MARINADE_PROG_LAST_UPDATED_SLOT,
+            pending_manager: Pubkey::default(),
             owner: MarinadeSolValCalc::ID,
         })
         .add_test_fixtures_account("marinade-prog.json")
@@ -91,6 +101,7 @@ impl AddLidoProgramTest for ProgramTest {
         self.add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: LIDO_PROG_LAST_UPDATED_SLOT,
+            pending_manager: Pubkey::default(),
             owner: LidoSolValCalc::ID,
         })
         .add_test_fixtures_account("lido-prog.json")
@@ -103,6 +114,76 @@ impl AddLidoProgramTest for ProgramTest {
     }
 }
 
+/// Fixed-rate calculator for scripting SOL value scenarios without stake pool fixtures.
+/// Use [`fixed_rate_calculator_test_utils::FixedRateCalculatorProgramTestContext`]
+/// to change rates after the test has started.
+pub trait AddFixedRateProgramTest {
+    fn add_fixed_rate_progs(self) -> Self;
+
+    /// Adds both the LST mint and its LstRate PDA
+    fn add_fixed_rate_lst(self, lst_rate_args: MockLstRateArgs, mint_args: MockMintArgs) -> Self;
+
+    /// Adds both the Token-2022 LST mint with a transfer fee and its LstRate PDA
+    fn add_transfer_fee_fixed_rate_lst(
+        self,
+        lst_rate_args: MockLstRateArgs,
+        mint_args: MockMintArgs,
+        transfer_fee_bps: u16,
+    ) -> Self;
+
+    /// Adds both the Token-2022 LST mint with a transfer fee and transfer hook and its LstRate PDA
+    fn add_transfer_hook_fixed_rate_lst(
+        self,
+        lst_rate_args: MockLstRateArgs,
+        mint_args: MockMintArgs,
+        transfer_fee_bps: u16,
+        transfer_hook_program_id: Pubkey,
+    ) -> Self;
+}
+
+impl AddFixedRateProgramTest for ProgramTest {
+    fn add_fixed_rate_progs(mut self) -> Self {
+        // name must match <name>.so filename
+        self.add_program(
+            "fixed_rate_calculator",
+            fixed_rate_calculator_lib::program::ID,
+            processor!(fixed_rate_calculator::entrypoint::process_instruction),
+        );
+        self.add_mock_fixed_rate_program_state(DEFAULT_PROGRAM_STATE)
+    }
+
+    fn add_fixed_rate_lst(self, lst_rate_args: MockLstRateArgs, mint_args: MockMintArgs) -> Self {
+        self.add_tokenkeg_mint_from_args(lst_rate_args.lst_mint, mint_args)
+            .add_mock_lst_rate(lst_rate_args)
+    }
+
+    fn add_transfer_fee_fixed_rate_lst(
+        self,
+        lst_rate_args: MockLstRateArgs,
+        mint_args: MockMintArgs,
+        transfer_fee_bps: u16,
+    ) -> Self {
+        self.add_transfer_fee_mint(lst_rate_args.lst_mint, mint_args, transfer_fee_bps)
+            .add_mock_lst_rate(lst_rate_args)
+    }
+
+    fn add_transfer_hook_fixed_rate_lst(
+        self,
+        lst_rate_args: MockLstRateArgs,
+        mint_args: MockMintArgs,
+        transfer_fee_bps: u16,
+        transfer_hook_program_id: Pubkey,
+    ) -> Self {
+        self.add_transfer_hook_mint(
+            lst_rate_args.lst_mint,
+            mint_args,
+            transfer_fee_bps,
+            transfer_hook_program_id,
+        )
+        .add_mock_lst_rate(lst_rate_args)
+    }
+}
+
 pub trait GenAndAddTokenAccountProgramTest {
     fn gen_and_add_token_account(&mut self, args: MockTokenAccountArgs) -> Pubkey;
 }
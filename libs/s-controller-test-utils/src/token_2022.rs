@@ -0,0 +1,171 @@
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports,
+    token::{MockMintArgs, MockTokenAccountArgs},
+};
+use solana_program::{program_option::COption, pubkey::Pubkey};
+use solana_program_test::ProgramTest;
+use solana_sdk::account::Account;
+use spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeAmount, TransferFeeConfig},
+        transfer_hook::{TransferHook, TransferHookAccount},
+        BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+    },
+    state::{AccountState, Mint},
+};
+
+/// A Token-2022 mint with a TransferFeeConfig extension that charges
+/// `transfer_fee_bps` on every transfer, without a maximum fee
+pub fn mock_transfer_fee_mint(mint_args: MockMintArgs, transfer_fee_bps: u16) -> Account {
+    mock_token_2022_mint(mint_args, transfer_fee_bps, None)
+}
+
+/// A Token-2022 mint with both a TransferFeeConfig extension that charges
+/// `transfer_fee_bps` on every transfer and a TransferHook extension
+/// that invokes `transfer_hook_program_id` on every transfer
+pub fn mock_transfer_hook_mint(
+    mint_args: MockMintArgs,
+    transfer_fee_bps: u16,
+    transfer_hook_program_id: Pubkey,
+) -> Account {
+    mock_token_2022_mint(mint_args, transfer_fee_bps, Some(transfer_hook_program_id))
+}
+
+fn mock_token_2022_mint(
+    MockMintArgs {
+        mint_authority,
+        freeze_authority,
+        supply,
+        decimals,
+    }: MockMintArgs,
+    transfer_fee_bps: u16,
+    transfer_hook_program_id: Option<Pubkey>,
+) -> Account {
+    let mut extension_types = vec![ExtensionType::TransferFeeConfig];
+    if transfer_hook_program_id.is_some() {
+        extension_types.push(ExtensionType::TransferHook);
+    }
+    let space = ExtensionType::try_calculate_account_len::<Mint>(&extension_types).unwrap();
+    let mut data = vec![0u8; space];
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    let fee = TransferFee {
+        transfer_fee_basis_points: transfer_fee_bps.into(),
+        maximum_fee: u64::MAX.into(),
+        ..Default::default()
+    };
+    let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+    config.older_transfer_fee = fee;
+    config.newer_transfer_fee = fee;
+    if let Some(transfer_hook_program_id) = transfer_hook_program_id {
+        let transfer_hook = state.init_extension::<TransferHook>(true).unwrap();
+        transfer_hook.program_id = Some(transfer_hook_program_id).try_into().unwrap();
+    }
+    state.base = Mint {
+        mint_authority: mint_authority.map_or(COption::None, COption::Some),
+        supply,
+        decimals,
+        is_initialized: true,
+        freeze_authority: freeze_authority.map_or(COption::None, COption::Some),
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(space),
+        data,
+        owner: spl_token_2022::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+/// A Token-2022 token account with the TransferFeeAmount and TransferHookAccount extensions
+/// required to transfer LSTs of a mint with TransferFeeConfig and TransferHook extensions
+pub fn mock_transfer_fee_token_account(
+    MockTokenAccountArgs {
+        mint,
+        authority,
+        amount,
+    }: MockTokenAccountArgs,
+) -> Account {
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[
+        ExtensionType::TransferFeeAmount,
+        ExtensionType::TransferHookAccount,
+    ])
+    .unwrap();
+    let mut data = vec![0u8; space];
+    let mut state =
+        StateWithExtensionsMut::<spl_token_2022::state::Account>::unpack_uninitialized(&mut data)
+            .unwrap();
+    state.init_extension::<TransferFeeAmount>(true).unwrap();
+    state.init_extension::<TransferHookAccount>(true).unwrap();
+    state.base = spl_token_2022::state::Account {
+        mint,
+        owner: authority,
+        amount,
+        delegate: COption::None,
+        state: AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(space),
+        data,
+        owner: spl_token_2022::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+pub trait TransferFeeProgramTest {
+    fn add_transfer_fee_mint(
+        self,
+        mint: Pubkey,
+        mint_args: MockMintArgs,
+        transfer_fee_bps: u16,
+    ) -> Self;
+
+    fn add_transfer_hook_mint(
+        self,
+        mint: Pubkey,
+        mint_args: MockMintArgs,
+        transfer_fee_bps: u16,
+        transfer_hook_program_id: Pubkey,
+    ) -> Self;
+
+    fn gen_and_add_transfer_fee_token_account(&mut self, args: MockTokenAccountArgs) -> Pubkey;
+}
+
+impl TransferFeeProgramTest for ProgramTest {
+    fn add_transfer_fee_mint(
+        mut self,
+        mint: Pubkey,
+        mint_args: MockMintArgs,
+        transfer_fee_bps: u16,
+    ) -> Self {
+        self.add_account(mint, mock_transfer_fee_mint(mint_args, transfer_fee_bps));
+        self
+    }
+
+    fn add_transfer_hook_mint(
+        mut self,
+        mint: Pubkey,
+        mint_args: MockMintArgs,
+        transfer_fee_bps: u16,
+        transfer_hook_program_id: Pubkey,
+    ) -> Self {
+        self.add_account(
+            mint,
+            mock_transfer_hook_mint(mint_args, transfer_fee_bps, transfer_hook_program_id),
+        );
+        self
+    }
+
+    fn gen_and_add_transfer_fee_token_account(&mut self, args: MockTokenAccountArgs) -> Pubkey {
+        let addr = Pubkey::new_unique();
+        self.add_account(addr, mock_transfer_fee_token_account(args));
+        addr
+    }
+}
@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedBanksClient};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::{
+    get_extra_account_metas_address,
+    instruction::{ExecuteInstruction, TransferHookInstruction},
+};
+
+pub mod mock_transfer_hook_program {
+    solana_program::declare_id!("G7ZXVk51v7TtD2TiFGDTX5v8Gzi5V68D2p4drAuKPXdA");
+}
+
+pub const MOCK_TRANSFER_HOOK_COUNTER_SEED: &[u8] = b"counter";
+
+/// The mock transfer hook program's PDA that counts the transfers of `lst_mint`
+pub fn find_mock_transfer_hook_counter_address(lst_mint: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MOCK_TRANSFER_HOOK_COUNTER_SEED, lst_mint.as_ref()],
+        &mock_transfer_hook_program::ID,
+    )
+}
+
+/// Transfer hook program that requires the mint's counter PDA as its only extra account
+/// and increments it on every transfer
+pub fn mock_transfer_hook_process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    match TransferHookInstruction::unpack(data)? {
+        TransferHookInstruction::Execute { .. } => (),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+    let [_source, _mint, _destination, _authority, validation, counter, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    ExtraAccountMetaList::check_account_infos::<ExecuteInstruction>(
+        accounts,
+        data,
+        program_id,
+        &validation.try_borrow_data()?,
+    )?;
+    let mut counter_data = counter.try_borrow_mut_data()?;
+    let count = u64::from_le_bytes(
+        counter_data[..8]
+            .try_into()
+            .map_err(|_e| ProgramError::InvalidAccountData)?,
+    );
+    let count = count
+        .checked_add(1)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    counter_data[..8].copy_from_slice(&count.to_le_bytes());
+    Ok(())
+}
+
+/// The mock transfer hook program's ExtraAccountMetaList for any mint
+pub fn mock_transfer_hook_extra_account_metas() -> Account {
+    let extra_account_metas = [ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal {
+                bytes: MOCK_TRANSFER_HOOK_COUNTER_SEED.to_vec(),
+            },
+            Seed::AccountKey { index: 1 },
+        ],
+        false,
+        true,
+    )
+    .unwrap()];
+    let space = ExtraAccountMetaList::size_of(extra_account_metas.len()).unwrap();
+    let mut data = vec![0u8; space];
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_account_metas).unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(space),
+        data,
+        owner: mock_transfer_hook_program::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+pub fn mock_transfer_hook_counter(count: u64) -> Account {
+    let data = count.to_le_bytes().to_vec();
+    Account {
+        lamports: est_rent_exempt_lamports(data.len()),
+        data,
+        owner: mock_transfer_hook_program::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+pub trait MockTransferHookProgramTest {
+    fn add_mock_transfer_hook_program(self) -> Self;
+
+    /// Adds the mock transfer hook program's ExtraAccountMetaList and zeroed counter for `lst_mint`
+    fn add_mock_transfer_hook_accounts(self, lst_mint: Pubkey) -> Self;
+}
+
+impl MockTransferHookProgramTest for ProgramTest {
+    fn add_mock_transfer_hook_program(mut self) -> Self {
+        self.add_program(
+            "mock_transfer_hook",
+            mock_transfer_hook_program::ID,
+            processor!(mock_transfer_hook_process_instruction),
+        );
+        self
+    }
+
+    fn add_mock_transfer_hook_accounts(mut self, lst_mint: Pubkey) -> Self {
+        self.add_account(
+            get_extra_account_metas_address(&lst_mint, &mock_transfer_hook_program::ID),
+            mock_transfer_hook_extra_account_metas(),
+        );
+        self.add_account(
+            find_mock_transfer_hook_counter_address(lst_mint).0,
+            mock_transfer_hook_counter(0),
+        );
+        self
+    }
+}
+
+#[async_trait]
+pub trait MockTransferHookBanksClient {
+    async fn get_mock_transfer_hook_extra_account_metas(&mut self, lst_mint: Pubkey) -> Account;
+
+    /// Number of transfers of `lst_mint` the mock transfer hook program has executed
+    async fn get_mock_transfer_hook_count(&mut self, lst_mint: Pubkey) -> u64;
+}
+
+#[async_trait]
+impl MockTransferHookBanksClient for BanksClient {
+    async fn get_mock_transfer_hook_extra_account_metas(&mut self, lst_mint: Pubkey) -> Account {
+        self.get_account_unwrapped(get_extra_account_metas_address(
+            &lst_mint,
+            &mock_transfer_hook_program::ID,
+        ))
+        .await
+    }
+
+    async fn get_mock_transfer_hook_count(&mut self, lst_mint: Pubkey) -> u64 {
+        let counter = self
+            .get_account_unwrapped(find_mock_transfer_hook_counter_address(lst_mint).0)
+            .await;
+        u64::from_le_bytes(counter.data[..8].try_into().unwrap())
+    }
+}
@@ -0,0 +1,127 @@
+//! Replays a recorded [`SPoolSnapshot`] against a list of quote requests.
+//!
+//! Usage: `s-jup-replay <snapshot.json> <requests.json>`
+//!
+//! `requests.json` is a JSON array of
+//! `{ "input_mint": ..., "output_mint": ..., "amount": ..., "swap_mode": "ExactIn" | "ExactOut" }`.
+//! Prints one JSON result per line, in the same order as the requests.
+
+use std::{fs, path::PathBuf, str::FromStr};
+
+use clap::Parser;
+use jupiter_amm_interface::{Amm, Quote, QuoteParams, SwapMode};
+use s_jup_interface::{SPoolJup, SPoolSnapshot};
+use serde::Deserialize;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Parser, Debug)]
+#[command(
+    author,
+    version,
+    about = "Replay quotes against a recorded S pool snapshot"
+)]
+struct Args {
+    #[arg(help = "path to the JSON SPoolSnapshot to replay")]
+    snapshot: PathBuf,
+
+    #[arg(help = "path to the JSON array of quote requests")]
+    requests: PathBuf,
+}
+
+#[derive(Debug, Deserialize)]
+enum ReplaySwapMode {
+    ExactIn,
+    ExactOut,
+}
+
+impl From<ReplaySwapMode> for SwapMode {
+    fn from(value: ReplaySwapMode) -> Self {
+        match value {
+            ReplaySwapMode::ExactIn => Self::ExactIn,
+            ReplaySwapMode::ExactOut => Self::ExactOut,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct QuoteRequest {
+    input_mint: String,
+    output_mint: String,
+    amount: u64,
+    swap_mode: ReplaySwapMode,
+}
+
+impl QuoteRequest {
+    fn into_quote_params(self) -> anyhow::Result<QuoteParams> {
+        Ok(QuoteParams {
+            amount: self.amount,
+            input_mint: Pubkey::from_str(&self.input_mint)?,
+            output_mint: Pubkey::from_str(&self.output_mint)?,
+            swap_mode: self.swap_mode.into(),
+        })
+    }
+}
+
+fn quote_json(
+    QuoteParams {
+        amount,
+        input_mint,
+        output_mint,
+        swap_mode,
+    }: &QuoteParams,
+    quote: anyhow::Result<Quote>,
+) -> serde_json::Value {
+    let request = json!({
+        "input_mint": input_mint.to_string(),
+        "output_mint": output_mint.to_string(),
+        "amount": amount,
+        "swap_mode": format!("{swap_mode:?}"),
+    });
+    match quote {
+        Ok(Quote {
+            in_amount,
+            out_amount,
+            fee_amount,
+            fee_mint,
+            fee_pct,
+            not_enough_liquidity,
+            ..
+        }) => json!({
+            "request": request,
+            "quote": {
+                "in_amount": in_amount,
+                "out_amount": out_amount,
+                "fee_amount": fee_amount,
+                "fee_mint": fee_mint.to_string(),
+                "fee_pct": fee_pct.to_string(),
+                "not_enough_liquidity": not_enough_liquidity,
+            },
+        }),
+        Err(e) => json!({
+            "request": request,
+            "error": e.to_string(),
+        }),
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let Args { snapshot, requests } = Args::parse();
+    let snapshot: SPoolSnapshot = serde_json::from_slice(&fs::read(snapshot)?)?;
+    let requests: Vec<QuoteRequest> = serde_json::from_slice(&fs::read(requests)?)?;
+
+    let pool = SPoolJup::from_snapshot(&snapshot)?;
+    eprintln!(
+        "Replaying {} quotes against snapshot at slot {}",
+        requests.len(),
+        snapshot.slot
+    );
+    for request in requests {
+        let line = match request.into_quote_params() {
+            Ok(params) => quote_json(&params, pool.quote(&params)),
+            Err(e) => json!({ "error": e.to_string() }),
+        };
+        println!("{line}");
+    }
+    Ok(())
+}
@@ -1,10 +1,11 @@
 use anyhow::anyhow;
 use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapParams};
 use pricing_programs_interface::PriceLpTokensToMintIxArgs;
-use s_controller_interface::SControllerError;
+use s_controller_interface::{PoolState, SControllerError};
 use s_controller_lib::{
-    add_liquidity_ix_by_mint_full_for_prog, calc_lp_tokens_to_mint, try_pool_state,
-    AddLiquidityByMintFreeArgs, AddLiquidityIxAmts, AddRemoveLiquidityAccountSuffixes,
+    add_liquidity_ix_by_mint_full_for_prog, calc_add_liquidity_protocol_fees,
+    calc_lp_tokens_to_mint, try_pool_state, AddLiquidityByMintFreeArgs, AddLiquidityIxAmts,
+    AddRemoveLiquidityAccountSuffixes, CalcAddLiquidityArgs, CalcAddLiquidityProtocolFeesResult,
     LpTokenRateArgs,
 };
 use s_pricing_prog_aggregate::PricingProg;
@@ -16,17 +17,39 @@ use solana_sdk::instruction::Instruction;
 
 use crate::{LstData, SPool};
 
-use super::{apply_sync_sol_value, calc_quote_fees};
+use super::{
+    calc_quote_fees, min_amount_in_for_amount_out, sync_lst, sync_sol_value_with_reserves,
+    BeforeAfter, QuoteDetailed, SyncedLst,
+};
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
-    pub(crate) fn quote_add_liquidity(
+    pub(crate) fn quote_add_liquidity_detailed(
         &self,
         QuoteParams {
             amount, input_mint, ..
         }: &QuoteParams,
-    ) -> anyhow::Result<Quote> {
+    ) -> anyhow::Result<QuoteDetailed> {
         let pool_state_data = self.pool_state_data()?;
         let pool_state = try_pool_state(&pool_state_data)?;
+
+        let (input_lst_state, input_lst_data) = self.find_ready_lst(*input_mint)?;
+        let (pool_state, input) = sync_lst(*pool_state, input_lst_state, input_lst_data)?;
+
+        self.quote_add_liquidity_synced(pool_state, &input, *amount)
+    }
+
+    /// `pool_state` must have `input` synced
+    pub(crate) fn quote_add_liquidity_synced(
+        &self,
+        pool_state: PoolState,
+        SyncedLst {
+            lst_state: input_lst_state,
+            lst_data: input_lst_data,
+            reserves_balance: input_reserves_balance,
+            ..
+        }: &SyncedLst<'_, S>,
+        amount: u64,
+    ) -> anyhow::Result<QuoteDetailed> {
         let pricing_prog = self
             .pricing_prog
             .as_ref()
@@ -34,17 +57,18 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         let lp_token_supply = self
             .lp_mint_supply
             .ok_or_else(|| anyhow!("LP mint not fetched"))?;
+        let input_mint = input_lst_state.mint;
+        let input_reserves_balance = *input_reserves_balance;
 
-        let (input_lst_state, input_lst_data) = self.find_ready_lst(*input_mint)?;
-        let (pool_state, _input_lst_state, _input_reserves_balance) =
-            apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
-
-        let lst_amount_sol_value = input_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_min();
+        // pool prices what it receives after transfer fees, see process_add_liquidity()
+        let amount_received = input_lst_data.amount_after_transfer_fee(amount)?;
+        let lst_amount_sol_value_range = input_lst_data.sol_val_calc.lst_to_sol(amount_received)?;
+        let lst_amount_sol_value = lst_amount_sol_value_range.get_min();
 
         let lst_amount_sol_value_after_fees = pricing_prog.quote_lp_tokens_to_mint(
-            *input_mint,
+            input_mint,
             &PriceLpTokensToMintIxArgs {
-                amount: *amount,
+                amount: amount_received,
                 sol_value: lst_amount_sol_value,
             },
         )?;
@@ -61,20 +85,91 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         let (fee_amount, fee_pct) = calc_quote_fees(
             AmtsAfterFeeBuilder::new_amt_bef_fee(lst_amount_sol_value)
                 .with_amt_aft_fee(lst_amount_sol_value_after_fees)?,
-            &input_lst_data.sol_val_calc,
+            input_lst_data.sol_val_calc.as_ref(),
         )?;
-        Ok(Quote {
-            not_enough_liquidity: false,
-            min_in_amount: None,
-            min_out_amount: None,
-            in_amount: *amount,
-            out_amount: lp_tokens_to_mint,
-            fee_mint: *input_mint,
-            fee_amount,
-            fee_pct,
+
+        let CalcAddLiquidityProtocolFeesResult {
+            to_reserves_lst_amount,
+            to_protocol_fees_lst_amount,
+        } = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
+            lst_amount: amount_received,
+            lst_amount_sol_value,
+            lst_amount_sol_value_after_fees,
+            lp_protocol_fee_bps: pool_state.lp_protocol_fee_bps,
+        })?;
+        let input_reserves = BeforeAfter {
+            before: input_reserves_balance,
+            after: input_reserves_balance
+                .checked_add(to_reserves_lst_amount)
+                .ok_or(SControllerError::MathError)?,
+        };
+        let lp_token_supply = BeforeAfter {
+            before: lp_token_supply,
+            after: lp_token_supply
+                .checked_add(lp_tokens_to_mint)
+                .ok_or(SControllerError::MathError)?,
+        };
+        let (pool_state_after, _) = sync_sol_value_with_reserves(
+            pool_state,
+            *input_lst_state,
+            *input_lst_data,
+            input_reserves.after,
+        )?;
+
+        Ok(QuoteDetailed {
+            quote: Quote {
+                not_enough_liquidity: false,
+                min_in_amount: None,
+                min_out_amount: None,
+                in_amount: amount,
+                out_amount: lp_tokens_to_mint,
+                fee_mint: input_mint,
+                fee_amount,
+                fee_pct,
+            },
+            pool_state_before: pool_state,
+            pool_state_after,
+            in_sol_value: lst_amount_sol_value,
+            out_sol_value: lst_amount_sol_value_after_fees,
+            input_calc_range: Some(lst_amount_sol_value_range),
+            output_calc_range: None,
+            to_protocol_fees_lst_amount,
+            input_reserves: Some(input_reserves),
+            output_reserves: None,
+            lp_token_supply: Some(lp_token_supply),
         })
     }
 
+    /// Off-chain helper that quotes the min LST amount required to mint at least `amount` LP tokens
+    /// by binary searching over the ExactIn calculation. `swap_mode` is ignored.
+    ///
+    /// There is no ExactOut add liquidity instruction, so this is not used by [`Self::quote_full`].
+    /// The returned quote is an ExactIn quote whose `out_amount` is the LP tokens minted for its `in_amount`,
+    /// which may exceed `amount` due to rounding. Execute it with [`Self::swap_ix`] and `SwapMode::ExactIn`,
+    /// with `in_amount` as the exact amount in and `amount` as the min LP tokens out
+    /// to enforce the bound on-chain.
+    pub fn quote_add_liquidity_min_in_detailed(
+        &self,
+        QuoteParams {
+            amount, input_mint, ..
+        }: &QuoteParams,
+    ) -> anyhow::Result<QuoteDetailed> {
+        let pool_state_data = self.pool_state_data()?;
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let (input_lst_state, input_lst_data) = self.find_ready_lst(*input_mint)?;
+        let (pool_state, input) = sync_lst(*pool_state, input_lst_state, input_lst_data)?;
+
+        let lst_amount = min_amount_in_for_amount_out(*amount, |lst_amount| {
+            Ok(self
+                .quote_add_liquidity_synced(pool_state, &input, lst_amount)?
+                .quote
+                .out_amount)
+        })?
+        .ok_or_else(|| anyhow!("No LST amount mints {amount} LP tokens"))?;
+        self.quote_add_liquidity_synced(pool_state, &input, lst_amount)
+    }
+
     pub(crate) fn add_liquidity_ix(
         &self,
         SwapParams {
@@ -87,14 +182,12 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             ..
         }: &SwapParams,
     ) -> anyhow::Result<Instruction> {
-        let (
-            _,
-            LstData {
-                token_program: src_token_program,
-                sol_val_calc: src_sol_val_calc,
-                ..
-            },
-        ) = self.find_ready_lst(*source_mint)?;
+        let (_, lst_data) = self.find_ready_lst(*source_mint)?;
+        let LstData {
+            token_program: src_token_program,
+            sol_val_calc: src_sol_val_calc,
+            ..
+        } = lst_data;
         Ok(add_liquidity_ix_by_mint_full_for_prog(
             self.program_id,
             AddLiquidityByMintFreeArgs {
@@ -120,6 +213,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 pricing_program_price_lp_accounts: &self
                     .pricing_prog()?
                     .price_lp_tokens_to_mint_accounts(*source_mint)?,
+                transfer_hook: lst_data.transfer_hook_accounts_data(*source_mint)?,
             },
         )?)
     }
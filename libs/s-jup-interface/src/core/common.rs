@@ -1,37 +1,87 @@
 use anyhow::anyhow;
 use rust_decimal::{prelude::FromPrimitive, Decimal};
-use s_controller_interface::{LstState, PoolState};
+use s_controller_interface::{LstState, PoolState, SControllerError};
 use s_controller_lib::sync_sol_value_with_retval;
-use s_sol_val_calc_prog_aggregate::{KnownLstSolValCalc, LstSolValCalc};
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
 use sanctum_token_ratio::AmtsAfterFee;
 
 use crate::LstData;
 
 /// Returns
 /// (updated pool state, update lst state, reserves balance)
-pub fn apply_sync_sol_value(
-    mut pool_state: PoolState,
-    mut lst_state: LstState,
-    LstData {
-        sol_val_calc,
-        reserves_balance,
-        token_program: _,
-    }: &LstData,
+pub fn apply_sync_sol_value<D>(
+    pool_state: PoolState,
+    lst_state: LstState,
+    lst_data: &LstData<D>,
 ) -> anyhow::Result<(PoolState, LstState, u64)> {
-    let reserves_balance = *reserves_balance
-        .as_ref()
+    let reserves_balance = lst_data
+        .reserves_balance
         .ok_or_else(|| anyhow!("Reserves balance not fetched"))?;
+    let (pool_state, lst_state) =
+        sync_sol_value_with_reserves(pool_state, lst_state, lst_data, reserves_balance)?;
+    Ok((pool_state, lst_state, reserves_balance))
+}
+
+/// An LST whose SOL value has been synced to its reserves balance
+pub struct SyncedLst<'a, D> {
+    /// LST state before syncing, required to replay the sync onto other pool states
+    pub unsynced_lst_state: LstState,
+    pub lst_state: LstState,
+    pub lst_data: &'a LstData<D>,
+    pub reserves_balance: u64,
+}
+
+/// Same as [`apply_sync_sol_value`], but returns the result as a [`SyncedLst`]
+pub fn sync_lst<D>(
+    pool_state: PoolState,
+    unsynced_lst_state: LstState,
+    lst_data: &LstData<D>,
+) -> anyhow::Result<(PoolState, SyncedLst<'_, D>)> {
+    let (pool_state, lst_state, reserves_balance) =
+        apply_sync_sol_value(pool_state, unsynced_lst_state, lst_data)?;
+    Ok((
+        pool_state,
+        SyncedLst {
+            unsynced_lst_state,
+            lst_state,
+            lst_data,
+            reserves_balance,
+        },
+    ))
+}
+
+/// Applies an already computed sync of `synced`'s SOL value to `pool_state`
+/// without calling its SOL value calculator again.
+///
+/// `pool_state` must not have had `synced` applied to it yet.
+pub fn apply_synced_lst<D>(
+    mut pool_state: PoolState,
+    synced: &SyncedLst<'_, D>,
+) -> anyhow::Result<PoolState> {
+    let mut lst_state = synced.unsynced_lst_state;
+    sync_sol_value_with_retval(&mut pool_state, &mut lst_state, synced.lst_state.sol_value)?;
+    Ok(pool_state)
+}
+
+/// Returns (updated pool state, updated lst state) with
+/// the LST's SOL value synced to `reserves_balance`
+pub fn sync_sol_value_with_reserves<D>(
+    mut pool_state: PoolState,
+    mut lst_state: LstState,
+    LstData { sol_val_calc, .. }: &LstData<D>,
+    reserves_balance: u64,
+) -> anyhow::Result<(PoolState, LstState)> {
     let ret_sol_val = sol_val_calc.lst_to_sol(reserves_balance)?;
     sync_sol_value_with_retval(&mut pool_state, &mut lst_state, ret_sol_val.get_min())?;
-    Ok((pool_state, lst_state, reserves_balance))
+    Ok((pool_state, lst_state))
 }
 
 /// Returns (fee_amount, fee_pct)
 /// fee_pct is [0.0, 1.0], not [0, 100],
 /// so 0.1 (NOT 10.0) means 10%
-pub fn calc_quote_fees(
+pub fn calc_quote_fees<C: LstSolValCalc + ?Sized>(
     sol_value_amts: AmtsAfterFee,
-    sol_val_calc: &KnownLstSolValCalc,
+    sol_val_calc: &C,
 ) -> anyhow::Result<(u64, Decimal)> {
     let fee_amount_sol = sol_value_amts.fee_charged();
     let fee_pct_num = Decimal::from_u64(fee_amount_sol)
@@ -44,3 +94,38 @@ pub fn calc_quote_fees(
     let fee_amount = sol_val_calc.sol_to_lst(fee_amount_sol)?.get_min();
     Ok((fee_amount, fee_pct))
 }
+
+/// Returns the smallest `amount_in` such that `calc_amount_out(amount_in) >= target_amount_out`,
+/// or None if even `calc_amount_out(u64::MAX) < target_amount_out`.
+///
+/// Off-chain helper to invert ExactIn calculations that have no on-chain ExactOut counterpart,
+/// e.g. add/remove liquidity. Only the ExactIn instruction can be executed with the result.
+/// `calc_amount_out` must be non-decreasing in `amount_in`.
+pub fn min_amount_in_for_amount_out<F: FnMut(u64) -> anyhow::Result<u64>>(
+    target_amount_out: u64,
+    mut calc_amount_out: F,
+) -> anyhow::Result<Option<u64>> {
+    if target_amount_out == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+    // exponential search for upper bound, saturating at u64::MAX
+    let mut hi: u64 = 1;
+    while calc_amount_out(hi)? < target_amount_out {
+        if hi == u64::MAX {
+            return Ok(None);
+        }
+        hi = hi.saturating_mul(2);
+    }
+    // binary search in (lo, hi],
+    // invariant: calc_amount_out(lo) < target_amount_out <= calc_amount_out(hi)
+    let mut lo = hi / 2;
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if calc_amount_out(mid)? >= target_amount_out {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Ok(Some(hi))
+}
@@ -0,0 +1,163 @@
+use anyhow::anyhow;
+use jupiter_amm_interface::{QuoteParams, SwapMode};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use s_controller_interface::SControllerError;
+use s_pricing_prog_aggregate::is_pricing_prog_math_err;
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::SPool;
+
+use super::{min_amount_in_for_amount_out, QuoteDetailed};
+
+/// SOL value of the input LST amount used to sample the spot rate (1 SOL).
+///
+/// Large enough for calculator and fee rounding to be negligible,
+/// small enough to not move the pool.
+pub const SPOT_RATE_SAMPLE_SOL_VALUE: u64 = 1_000_000_000;
+
+/// A single sample of [`SPool::price_impact_curve`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceImpactPoint {
+    pub in_amount: u64,
+
+    pub out_amount: u64,
+
+    /// `out_amount / in_amount`, in atomics of the output LST per atomic of the input LST
+    pub rate: Decimal,
+
+    /// `1 - rate / spot_rate`.
+    /// [0.0, 1.0], not [0, 100], so 0.1 (NOT 10.0) means 10%
+    pub price_impact: Decimal,
+
+    /// Same as [`jupiter_amm_interface::Quote::not_enough_liquidity`]
+    pub not_enough_liquidity: bool,
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Returns the exchange rate between 2 LSTs after all fees,
+    /// in atomics of `output_mint` per atomic of `input_mint`.
+    ///
+    /// This is not the true marginal rate at zero size, but the average rate of a swap of
+    /// [`SPOT_RATE_SAMPLE_SOL_VALUE`] (1 SOL) worth of `input_mint`, so it includes
+    /// that sample's rounding and, for size-dependent pricing programs, its own price impact.
+    pub fn spot_rate(&self, input_mint: Pubkey, output_mint: Pubkey) -> anyhow::Result<Decimal> {
+        let (_, input_lst_data) = self.find_ready_lst(input_mint)?;
+        let sample_amount = input_lst_data
+            .sol_val_calc
+            .sol_to_lst(SPOT_RATE_SAMPLE_SOL_VALUE)?
+            .get_min();
+        let detailed = self.quote_swap_exact_in_detailed(&QuoteParams {
+            amount: sample_amount,
+            input_mint,
+            output_mint,
+            swap_mode: SwapMode::ExactIn,
+        })?;
+        quote_rate(&detailed)
+    }
+
+    /// Returns the largest amount of `input_mint` that can be swapped for `output_mint`
+    /// without the quote running into `not_enough_liquidity`,
+    /// i.e. the output LST amount before transfer fees plus protocol fees
+    /// does not exceed the output LST's reserves.
+    ///
+    /// Amounts too large to quote without arithmetic overflow are treated as exceeding the reserves.
+    /// Returns `u64::MAX` if no amount exceeds the reserves.
+    ///
+    /// Returns 0 if no nonzero amount can be swapped.
+    pub fn max_in_amount(&self, input_mint: Pubkey, output_mint: Pubkey) -> anyhow::Result<u64> {
+        let (_, output_lst_data) = self.find_ready_lst(output_mint)?;
+        let output_reserves_balance = output_lst_data
+            .reserves_balance
+            .ok_or_else(|| anyhow!("Reserves balance not fetched"))?;
+        let Some(target) = output_reserves_balance.checked_add(1) else {
+            // reserves are u64::MAX, output can never exceed them
+            return Ok(u64::MAX);
+        };
+        let min_exceeding_amount = min_amount_in_for_amount_out(target, |amount| {
+            match self.quote_swap_exact_in_detailed(&QuoteParams {
+                amount,
+                input_mint,
+                output_mint,
+                swap_mode: SwapMode::ExactIn,
+            }) {
+                // the reserves are debited the output amount before transfer fees
+                Ok(detailed) => detailed
+                    .output_calc_range
+                    .ok_or_else(|| anyhow!("Output calc range missing"))?
+                    .get_min()
+                    .checked_add(detailed.to_protocol_fees_lst_amount)
+                    .ok_or_else(|| SControllerError::MathError.into()),
+                // amount too small to output anything
+                Err(e) if is_zero_value_err(&e) => Ok(0),
+                // amount so large that quoting it overflows, so it certainly exceeds the reserves
+                Err(e) if is_math_err(&e) => Ok(u64::MAX),
+                Err(e) => Err(e),
+            }
+        })?;
+        Ok(min_exceeding_amount.map_or(u64::MAX, |amount| amount - 1))
+    }
+
+    /// Quotes swapping each of `in_amounts` of `input_mint` for `output_mint`
+    /// and compares the resulting rate against [`Self::spot_rate`].
+    ///
+    /// Samples are returned in the same order as `in_amounts`.
+    pub fn price_impact_curve(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        in_amounts: &[u64],
+    ) -> anyhow::Result<Vec<PriceImpactPoint>> {
+        let spot_rate = self.spot_rate(input_mint, output_mint)?;
+        in_amounts
+            .iter()
+            .map(|amount| {
+                let detailed = self.quote_swap_exact_in_detailed(&QuoteParams {
+                    amount: *amount,
+                    input_mint,
+                    output_mint,
+                    swap_mode: SwapMode::ExactIn,
+                })?;
+                let rate = quote_rate(&detailed)?;
+                let price_impact = Decimal::ONE
+                    - rate
+                        .checked_div(spot_rate)
+                        .ok_or_else(|| anyhow!("Decimal price_impact div err"))?;
+                Ok(PriceImpactPoint {
+                    in_amount: detailed.quote.in_amount,
+                    out_amount: detailed.quote.out_amount,
+                    rate,
+                    price_impact,
+                    not_enough_liquidity: detailed.quote.not_enough_liquidity,
+                })
+            })
+            .collect()
+    }
+}
+
+fn quote_rate(detailed: &QuoteDetailed) -> anyhow::Result<Decimal> {
+    let num = Decimal::from_u64(detailed.quote.out_amount)
+        .ok_or_else(|| anyhow!("Decimal conv error out_amount"))?;
+    let denom = Decimal::from_u64(detailed.quote.in_amount)
+        .ok_or_else(|| anyhow!("Decimal conv error in_amount"))?;
+    num.checked_div(denom)
+        .ok_or_else(|| anyhow!("Decimal rate div err"))
+}
+
+fn is_zero_value_err(e: &anyhow::Error) -> bool {
+    matches!(
+        e.downcast_ref::<SControllerError>(),
+        Some(SControllerError::ZeroValue)
+    )
+}
+
+fn is_math_err(e: &anyhow::Error) -> bool {
+    matches!(
+        e.downcast_ref::<SControllerError>(),
+        Some(SControllerError::MathError)
+    ) || matches!(
+        e.downcast_ref::<ProgramError>(),
+        Some(ProgramError::ArithmeticOverflow)
+    ) || is_pricing_prog_math_err(e)
+}
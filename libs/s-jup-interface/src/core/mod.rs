@@ -2,8 +2,8 @@
 
 use anyhow::anyhow;
 use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapMode, SwapParams};
-use s_controller_interface::LstState;
-use s_controller_lib::try_lst_state_list;
+use s_controller_interface::{Deadline, LstState};
+use s_controller_lib::{ix_with_deadline, try_lst_state_list};
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
 
@@ -11,11 +11,18 @@ use crate::SPool;
 
 mod add_liquidity;
 mod common;
+mod depth;
+mod pair_matrix;
+mod projection;
+mod quote_detailed;
 mod remove_liquidity;
 mod swap_exact_in;
 mod swap_exact_out;
 
 pub use add_liquidity::*;
+pub use depth::*;
+pub use pair_matrix::*;
+pub use quote_detailed::*;
 pub use remove_liquidity::*;
 pub use swap_exact_in::*;
 pub use swap_exact_out::*;
@@ -48,22 +55,39 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         }
     }
 
+    /// Same as [`Self::swap_ix`], but returns the instruction's *WithDeadline variant,
+    /// which fails if the transaction lands after `deadline`
+    pub fn swap_ix_with_deadline(
+        &self,
+        swap_params: &SwapParams,
+        swap_mode: SwapMode,
+        deadline: &Deadline,
+    ) -> anyhow::Result<Instruction> {
+        let ix = self.swap_ix(swap_params, swap_mode)?;
+        Ok(ix_with_deadline(ix, deadline)?)
+    }
+
     pub fn quote_full(&self, quote_params: &QuoteParams) -> anyhow::Result<Quote> {
+        Ok(self.quote_detailed(quote_params)?.quote)
+    }
+
+    /// Same as [`Self::quote_full`], but also returns every intermediate value of the quote
+    pub fn quote_detailed(&self, quote_params: &QuoteParams) -> anyhow::Result<QuoteDetailed> {
         let lp_mint = self.lp_token_mint()?;
         if quote_params.input_mint == lp_mint {
             if let SwapMode::ExactOut = quote_params.swap_mode {
                 return Err(anyhow!("ExactOut not supported for remove liquidity"));
             }
-            self.quote_remove_liquidity(quote_params)
+            self.quote_remove_liquidity_detailed(quote_params)
         } else if quote_params.output_mint == lp_mint {
             if let SwapMode::ExactOut = quote_params.swap_mode {
                 return Err(anyhow!("ExactOut not supported for add liquidity"));
             }
-            self.quote_add_liquidity(quote_params)
+            self.quote_add_liquidity_detailed(quote_params)
         } else {
             match quote_params.swap_mode {
-                SwapMode::ExactIn => self.quote_swap_exact_in(quote_params),
-                SwapMode::ExactOut => self.quote_swap_exact_out(quote_params),
+                SwapMode::ExactIn => self.quote_swap_exact_in_detailed(quote_params),
+                SwapMode::ExactOut => self.quote_swap_exact_out_detailed(quote_params),
             }
         }
     }
@@ -0,0 +1,237 @@
+use std::{error::Error, fmt::Display};
+
+use anyhow::anyhow;
+use s_controller_interface::PoolState;
+use s_controller_lib::{
+    calc_lp_tokens_to_mint, try_lst_state_list, try_pool_state, LpTokenRateArgs, U8Bool,
+};
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::SPool;
+
+use super::{apply_synced_lst, sync_lst, QuoteDetailed, SyncedLst};
+
+/// Reason an entry of a [`PairQuoteMatrix`] could not be quoted
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PairQuoteErr {
+    /// Input and output are the same LST
+    SameMint,
+
+    /// The pool is disabled or rebalancing
+    PoolUnavailable,
+
+    /// Input has been disabled for the input LST
+    InputDisabled,
+
+    /// The LST's SOL value calculator is not known to the LST registry
+    /// or its accounts have not been fetched
+    UnknownCalculator,
+
+    /// Output amount plus protocol fees exceeds the output LST's reserves
+    NotEnoughLiquidity,
+
+    /// Any other quoting error, e.g. the notional being too small
+    Quote(String),
+}
+
+impl PairQuoteErr {
+    fn quote(e: anyhow::Error) -> Self {
+        Self::Quote(format!("{e:#}"))
+    }
+}
+
+impl Display for PairQuoteErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SameMint => f.write_str("input and output LST are the same"),
+            Self::PoolUnavailable => f.write_str("pool is disabled or rebalancing"),
+            Self::InputDisabled => f.write_str("input disabled for LST"),
+            Self::UnknownCalculator => f.write_str("unknown or unfetched SOL value calculator"),
+            Self::NotEnoughLiquidity => f.write_str("not enough liquidity"),
+            Self::Quote(e) => write!(f, "quote failed: {e}"),
+        }
+    }
+}
+
+impl Error for PairQuoteErr {}
+
+pub type PairQuoteResult = Result<QuoteDetailed, PairQuoteErr>;
+
+/// ExactIn quotes for every tradeable pair of a pool, see [`SPool::quote_pair_matrix`]
+#[derive(Clone, Debug)]
+pub struct PairQuoteMatrix {
+    /// SOL value of the input amount of every entry
+    pub notional_sol_value: u64,
+
+    /// Mints of every LST on the LST state list, in list order
+    pub lst_mints: Vec<Pubkey>,
+
+    /// `swaps[i][j]` is the quote for swapping `lst_mints[i]` for `lst_mints[j]`
+    pub swaps: Vec<Vec<PairQuoteResult>>,
+
+    /// `add_liquidity[i]` is the quote for adding `lst_mints[i]` as liquidity
+    pub add_liquidity: Vec<PairQuoteResult>,
+
+    /// `remove_liquidity[i]` is the quote for removing liquidity as `lst_mints[i]`
+    pub remove_liquidity: Vec<PairQuoteResult>,
+}
+
+impl PairQuoteMatrix {
+    fn lst_index(&self, mint: Pubkey) -> Option<usize> {
+        self.lst_mints.iter().position(|m| *m == mint)
+    }
+
+    /// Returns `None` if either mint is not on the LST state list
+    pub fn swap(&self, input_mint: Pubkey, output_mint: Pubkey) -> Option<&PairQuoteResult> {
+        let i = self.lst_index(input_mint)?;
+        let j = self.lst_index(output_mint)?;
+        Some(&self.swaps[i][j])
+    }
+
+    /// Returns `None` if `input_mint` is not on the LST state list
+    pub fn add_liquidity(&self, input_mint: Pubkey) -> Option<&PairQuoteResult> {
+        self.lst_index(input_mint).map(|i| &self.add_liquidity[i])
+    }
+
+    /// Returns `None` if `output_mint` is not on the LST state list
+    pub fn remove_liquidity(&self, output_mint: Pubkey) -> Option<&PairQuoteResult> {
+        self.lst_index(output_mint)
+            .map(|i| &self.remove_liquidity[i])
+    }
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Quotes ExactIn swaps for every (input, output) LST pair,
+    /// and add and remove liquidity for every LST,
+    /// with `notional_sol_value` worth of input for every entry.
+    ///
+    /// Unlike calling [`Self::quote_full`] for every pair,
+    /// each LST's SOL value is only synced once.
+    ///
+    /// Only fails if the pool state or LST state list could not be read.
+    /// Each entry reports its own error otherwise.
+    pub fn quote_pair_matrix(&self, notional_sol_value: u64) -> anyhow::Result<PairQuoteMatrix> {
+        let pool_state_data = self.pool_state_data()?;
+        let pool_state = *try_pool_state(&pool_state_data)?;
+        let lst_state_list_data = self.lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_data)?;
+        let pool_unavailable =
+            U8Bool(pool_state.is_disabled).is_true() || U8Bool(pool_state.is_rebalancing).is_true();
+
+        let synced_lsts: Vec<Result<SyncedLst<'_, S>, PairQuoteErr>> = lst_state_list
+            .iter()
+            .zip(self.lst_data_list.iter())
+            .map(|(lst_state, lst_data)| {
+                let lst_data = lst_data.as_ref().ok_or(PairQuoteErr::UnknownCalculator)?;
+                let (_, synced) =
+                    sync_lst(pool_state, *lst_state, lst_data).map_err(PairQuoteErr::quote)?;
+                Ok(synced)
+            })
+            .collect();
+        let lst_in_amounts: Vec<Result<u64, PairQuoteErr>> = synced_lsts
+            .iter()
+            .map(|synced| {
+                let synced = synced.as_ref().map_err(Clone::clone)?;
+                let range = synced
+                    .lst_data
+                    .sol_val_calc
+                    .sol_to_lst(notional_sol_value)
+                    .map_err(PairQuoteErr::quote)?;
+                Ok(range.get_min())
+            })
+            .collect();
+
+        let input_lst = |i: usize| {
+            if pool_unavailable {
+                return Err(PairQuoteErr::PoolUnavailable);
+            }
+            let input = synced_lsts[i].as_ref().map_err(Clone::clone)?;
+            if U8Bool(input.lst_state.is_input_disabled).is_true() {
+                return Err(PairQuoteErr::InputDisabled);
+            }
+            let amount = lst_in_amounts[i].clone()?;
+            Ok::<_, PairQuoteErr>((input, amount))
+        };
+
+        let swaps = (0..synced_lsts.len())
+            .map(|i| {
+                (0..synced_lsts.len())
+                    .map(|j| {
+                        if i == j {
+                            return Err(PairQuoteErr::SameMint);
+                        }
+                        let (input, amount) = input_lst(i)?;
+                        let output = synced_lsts[j].as_ref().map_err(Clone::clone)?;
+                        let pool_state = apply_synced_lst(pool_state, input)
+                            .and_then(|ps| apply_synced_lst(ps, output))
+                            .map_err(PairQuoteErr::quote)?;
+                        check_liquidity(
+                            self.quote_swap_exact_in_synced(pool_state, input, output, amount),
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let add_liquidity = (0..synced_lsts.len())
+            .map(|i| {
+                let (input, amount) = input_lst(i)?;
+                let pool_state =
+                    apply_synced_lst(pool_state, input).map_err(PairQuoteErr::quote)?;
+                check_liquidity(self.quote_add_liquidity_synced(pool_state, input, amount))
+            })
+            .collect();
+
+        let remove_liquidity = synced_lsts
+            .iter()
+            .map(|output| {
+                if pool_unavailable {
+                    return Err(PairQuoteErr::PoolUnavailable);
+                }
+                let output = output.as_ref().map_err(Clone::clone)?;
+                let pool_state =
+                    apply_synced_lst(pool_state, output).map_err(PairQuoteErr::quote)?;
+                let amount = self
+                    .lp_tokens_for_sol_value(&pool_state, notional_sol_value)
+                    .map_err(PairQuoteErr::quote)?;
+                check_liquidity(self.quote_remove_liquidity_synced(pool_state, output, amount))
+            })
+            .collect();
+
+        Ok(PairQuoteMatrix {
+            notional_sol_value,
+            lst_mints: lst_state_list.iter().map(|s| s.mint).collect(),
+            swaps,
+            add_liquidity,
+            remove_liquidity,
+        })
+    }
+
+    /// Amount of LP tokens worth `sol_value` at `pool_state`'s LP token rate, before fees
+    fn lp_tokens_for_sol_value(
+        &self,
+        pool_state: &PoolState,
+        sol_value: u64,
+    ) -> anyhow::Result<u64> {
+        let lp_token_supply = self
+            .lp_mint_supply
+            .ok_or_else(|| anyhow!("LP mint not fetched"))?;
+        Ok(calc_lp_tokens_to_mint(
+            LpTokenRateArgs {
+                lp_token_supply,
+                pool_total_sol_value: pool_state.total_sol_value,
+            },
+            sol_value,
+        )?)
+    }
+}
+
+fn check_liquidity(res: anyhow::Result<QuoteDetailed>) -> PairQuoteResult {
+    let detailed = res.map_err(PairQuoteErr::quote)?;
+    if detailed.quote.not_enough_liquidity {
+        return Err(PairQuoteErr::NotEnoughLiquidity);
+    }
+    Ok(detailed)
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use s_controller_interface::PoolState;
+use s_controller_lib::{
+    calc_lp_tokens_sol_value, try_lst_state_list, try_pool_state, LpTokenRateArgs,
+};
+use s_sol_val_calc_prog_aggregate::{LstSolValCalc, LstSolValProjection};
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::SPool;
+
+use super::apply_sync_sol_value;
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Returns the pool state with the SOL value of every LST synced to its reserves balance.
+    ///
+    /// Errors if any LST on the list is not supported or its reserves have not been fetched
+    pub fn fully_synced_pool_state(&self) -> anyhow::Result<PoolState> {
+        let pool_state_data = self.pool_state_data()?;
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_data = self.lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_data)?;
+        lst_state_list
+            .iter()
+            .zip(self.lst_data_list.iter())
+            .try_fold(*pool_state, |pool_state, (lst_state, lst_data)| {
+                let lst_data = lst_data
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("LST {} not supported", lst_state.mint))?;
+                let (pool_state, _, _) = apply_sync_sol_value(pool_state, *lst_state, lst_data)?;
+                Ok(pool_state)
+            })
+    }
+
+    /// Returns the SOL value of `lp_token_amount` LP tokens, before any LP withdrawal fees,
+    /// with the SOL value of every LST synced
+    pub fn lp_tokens_sol_value(&self, lp_token_amount: u64) -> anyhow::Result<u64> {
+        let lp_token_supply = self
+            .lp_mint_supply
+            .ok_or_else(|| anyhow!("LP mint not fetched"))?;
+        let pool_state = self.fully_synced_pool_state()?;
+        Ok(calc_lp_tokens_sol_value(
+            LpTokenRateArgs {
+                lp_token_supply,
+                pool_total_sol_value: pool_state.total_sol_value,
+            },
+            lp_token_amount,
+        )?)
+    }
+}
+
+impl<S: Clone, L: Clone> SPool<S, L> {
+    /// Returns a copy of this pool with each projection in `projections`, keyed by LST mint,
+    /// applied to the LST's SOL value calculator.
+    ///
+    /// Quotes and LP token values of the returned pool are estimates for the projected epoch.
+    /// LSTs without a projection keep their current SOL value calculator state.
+    ///
+    /// Errors if a projected LST is not supported or its calculator does not support projections
+    pub fn projected(
+        &self,
+        projections: &HashMap<Pubkey, LstSolValProjection>,
+    ) -> anyhow::Result<Self> {
+        let mut res = self.clone();
+        for (mint, projection) in projections {
+            let lst_data = res
+                .lst_data_list
+                .iter_mut()
+                .flatten()
+                .find(|lst_data| lst_data.sol_val_calc.lst_mint() == *mint)
+                .ok_or_else(|| anyhow!("LST {mint} not supported"))?;
+            lst_data.sol_val_calc.project(projection)?;
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,72 @@
+use jupiter_amm_interface::Quote;
+use s_controller_interface::PoolState;
+use sanctum_token_ratio::U64ValueRange;
+
+/// Value of an amount before and after the quoted trade
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BeforeAfter {
+    pub before: u64,
+    pub after: u64,
+}
+
+/// Every intermediate value computed while quoting a trade, for displaying a full breakdown of it.
+///
+/// The SOL value fields are:
+/// - swaps: SOL value of input LST, SOL value of output LST
+/// - add liquidity: SOL value of input LST, SOL value of input LST after pricing program fees
+/// - remove liquidity: SOL value of input LP tokens, SOL value of input LP tokens after pricing program fees
+#[derive(Clone, Debug)]
+pub struct QuoteDetailed {
+    /// The quote returned by [`crate::SPool::quote_full`]
+    pub quote: Quote,
+
+    /// Pool state with the SOL values of the involved LSTs synced, before the trade
+    pub pool_state_before: PoolState,
+
+    /// Pool state with the SOL values of the involved LSTs synced, after the trade
+    pub pool_state_after: PoolState,
+
+    pub in_sol_value: u64,
+
+    pub out_sol_value: u64,
+
+    /// Range returned by the input LST's SOL value calculator,
+    /// `lst_to_sol(in_amount)` for ExactIn and `sol_to_lst(in_sol_value)` for ExactOut.
+    ///
+    /// For ExactIn, `in_amount` is after input LST transfer fees.
+    ///
+    /// `None` if the input is the LP token
+    pub input_calc_range: Option<U64ValueRange>,
+
+    /// Range returned by the output LST's SOL value calculator,
+    /// `sol_to_lst(out_sol_value)` for ExactIn and `lst_to_sol(out_amount)` for ExactOut.
+    ///
+    /// For ExactIn, this is before output LST transfer fees,
+    /// while the quote's `out_amount` is after.
+    ///
+    /// `None` if the output is the LP token
+    pub output_calc_range: Option<U64ValueRange>,
+
+    /// Amount of LST transferred to the protocol fee accumulator.
+    /// This is in the input LST for add liquidity and the output LST otherwise.
+    /// For LSTs with transfer fees, this is debited from the reserves
+    /// and the accumulator receives this amount minus the transfer fee.
+    pub to_protocol_fees_lst_amount: u64,
+
+    /// `None` if the input is the LP token
+    pub input_reserves: Option<BeforeAfter>,
+
+    /// `None` if the output is the LP token
+    pub output_reserves: Option<BeforeAfter>,
+
+    /// `None` for swaps
+    pub lp_token_supply: Option<BeforeAfter>,
+}
+
+impl QuoteDetailed {
+    /// Total fees charged by the pricing program in SOL value,
+    /// including the share that goes to the protocol
+    pub fn fee_sol_value(&self) -> u64 {
+        self.in_sol_value.saturating_sub(self.out_sol_value)
+    }
+}
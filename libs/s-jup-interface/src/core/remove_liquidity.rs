@@ -1,7 +1,7 @@
 use anyhow::anyhow;
 use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapParams};
 use pricing_programs_interface::PriceLpTokensToRedeemIxArgs;
-use s_controller_interface::SControllerError;
+use s_controller_interface::{PoolState, SControllerError};
 use s_controller_lib::{
     calc_lp_tokens_sol_value, calc_remove_liquidity_protocol_fees,
     remove_liquidity_ix_by_mint_full_for_prog, try_pool_state, AddRemoveLiquidityAccountSuffixes,
@@ -17,19 +17,41 @@ use solana_sdk::instruction::Instruction;
 
 use crate::{LstData, SPool};
 
-use super::{apply_sync_sol_value, calc_quote_fees};
+use super::{
+    calc_quote_fees, min_amount_in_for_amount_out, sync_lst, sync_sol_value_with_reserves,
+    BeforeAfter, QuoteDetailed, SyncedLst,
+};
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
-    pub(crate) fn quote_remove_liquidity(
+    pub(crate) fn quote_remove_liquidity_detailed(
         &self,
         QuoteParams {
             amount,
             output_mint,
             ..
         }: &QuoteParams,
-    ) -> anyhow::Result<Quote> {
+    ) -> anyhow::Result<QuoteDetailed> {
         let pool_state_data = self.pool_state_data()?;
         let pool_state = try_pool_state(&pool_state_data)?;
+
+        let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
+        let (pool_state, output) = sync_lst(*pool_state, output_lst_state, output_lst_data)?;
+
+        self.quote_remove_liquidity_synced(pool_state, &output, *amount)
+    }
+
+    /// `pool_state` must have `output` synced
+    pub(crate) fn quote_remove_liquidity_synced(
+        &self,
+        pool_state: PoolState,
+        SyncedLst {
+            lst_state: output_lst_state,
+            lst_data: output_lst_data,
+            reserves_balance: output_reserves_balance,
+            ..
+        }: &SyncedLst<'_, S>,
+        amount: u64,
+    ) -> anyhow::Result<QuoteDetailed> {
         let pricing_prog = self
             .pricing_prog
             .as_ref()
@@ -37,10 +59,8 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         let lp_token_supply = self
             .lp_mint_supply
             .ok_or_else(|| anyhow!("LP mint not fetched"))?;
-
-        let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
-        let (pool_state, _output_lst_state, output_reserves_balance) =
-            apply_sync_sol_value(*pool_state, output_lst_state, output_lst_data)?;
+        let output_mint = output_lst_state.mint;
+        let output_reserves_balance = *output_reserves_balance;
 
         let pool_total_sol_value = pool_state.total_sol_value;
         let lp_tokens_sol_value = calc_lp_tokens_sol_value(
@@ -48,23 +68,23 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 lp_token_supply,
                 pool_total_sol_value,
             },
-            *amount,
+            amount,
         )?;
 
         let lp_tokens_sol_value_after_fees = pricing_prog.quote_lp_tokens_to_redeem(
-            *output_mint,
+            output_mint,
             &PriceLpTokensToRedeemIxArgs {
-                amount: *amount,
+                amount,
                 sol_value: lp_tokens_sol_value,
             },
         )?;
         if lp_tokens_sol_value_after_fees > lp_tokens_sol_value {
             return Err(SControllerError::PoolWouldLoseSolValue.into());
         }
-        let to_user_lst_amount = output_lst_data
+        let to_user_lst_amount_range = output_lst_data
             .sol_val_calc
-            .sol_to_lst(lp_tokens_sol_value_after_fees)?
-            .get_min();
+            .sol_to_lst(lp_tokens_sol_value_after_fees)?;
+        let to_user_lst_amount = to_user_lst_amount_range.get_min();
         let to_protocol_fees_lst_amount =
             calc_remove_liquidity_protocol_fees(CalcRemoveLiquidityProtocolFeesArgs {
                 lp_tokens_sol_value,
@@ -76,23 +96,84 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             .checked_add(to_protocol_fees_lst_amount)
             .ok_or(SControllerError::MathError)?;
         let not_enough_liquidity = total_dst_lst_out > output_reserves_balance;
+        let out_amount = output_lst_data.amount_after_transfer_fee(to_user_lst_amount)?;
         let (fee_amount, fee_pct) = calc_quote_fees(
             AmtsAfterFeeBuilder::new_amt_bef_fee(lp_tokens_sol_value)
                 .with_amt_aft_fee(lp_tokens_sol_value_after_fees)?,
-            &output_lst_data.sol_val_calc,
+            output_lst_data.sol_val_calc.as_ref(),
         )?;
-        Ok(Quote {
-            not_enough_liquidity,
-            min_in_amount: None,
-            min_out_amount: None,
-            in_amount: *amount,
-            out_amount: to_user_lst_amount,
-            fee_mint: *output_mint,
-            fee_amount,
-            fee_pct,
+
+        let output_reserves = BeforeAfter {
+            before: output_reserves_balance,
+            after: output_reserves_balance.saturating_sub(total_dst_lst_out),
+        };
+        let lp_token_supply = BeforeAfter {
+            before: lp_token_supply,
+            after: lp_token_supply.saturating_sub(amount),
+        };
+        let (pool_state_after, _) = sync_sol_value_with_reserves(
+            pool_state,
+            *output_lst_state,
+            *output_lst_data,
+            output_reserves.after,
+        )?;
+
+        Ok(QuoteDetailed {
+            quote: Quote {
+                not_enough_liquidity,
+                min_in_amount: None,
+                min_out_amount: None,
+                in_amount: amount,
+                out_amount,
+                fee_mint: output_mint,
+                fee_amount,
+                fee_pct,
+            },
+            pool_state_before: pool_state,
+            pool_state_after,
+            in_sol_value: lp_tokens_sol_value,
+            out_sol_value: lp_tokens_sol_value_after_fees,
+            input_calc_range: None,
+            output_calc_range: Some(to_user_lst_amount_range),
+            to_protocol_fees_lst_amount,
+            input_reserves: None,
+            output_reserves: Some(output_reserves),
+            lp_token_supply: Some(lp_token_supply),
         })
     }
 
+    /// Off-chain helper that quotes the min LP token amount required to redeem at least `amount` LSTs
+    /// by binary searching over the ExactIn calculation. `swap_mode` is ignored.
+    ///
+    /// There is no ExactOut remove liquidity instruction, so this is not used by [`Self::quote_full`].
+    /// The returned quote is an ExactIn quote whose `out_amount` is the LSTs redeemed for its `in_amount`,
+    /// which may exceed `amount` due to rounding. Execute it with [`Self::swap_ix`] and `SwapMode::ExactIn`,
+    /// with `in_amount` as the exact amount in and `amount` as the min LSTs out
+    /// to enforce the bound on-chain.
+    pub fn quote_remove_liquidity_min_in_detailed(
+        &self,
+        QuoteParams {
+            amount,
+            output_mint,
+            ..
+        }: &QuoteParams,
+    ) -> anyhow::Result<QuoteDetailed> {
+        let pool_state_data = self.pool_state_data()?;
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
+        let (pool_state, output) = sync_lst(*pool_state, output_lst_state, output_lst_data)?;
+
+        let lp_token_amount = min_amount_in_for_amount_out(*amount, |lp_token_amount| {
+            Ok(self
+                .quote_remove_liquidity_synced(pool_state, &output, lp_token_amount)?
+                .quote
+                .out_amount)
+        })?
+        .ok_or_else(|| anyhow!("No LP token amount redeems {amount} LSTs"))?;
+        self.quote_remove_liquidity_synced(pool_state, &output, lp_token_amount)
+    }
+
     pub(crate) fn remove_liquidity_ix(
         &self,
         SwapParams {
@@ -105,14 +186,12 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             ..
         }: &SwapParams,
     ) -> anyhow::Result<Instruction> {
-        let (
-            _,
-            LstData {
-                token_program: src_token_program,
-                sol_val_calc: src_sol_val_calc,
-                ..
-            },
-        ) = self.find_ready_lst(*destination_mint)?;
+        let (_, lst_data) = self.find_ready_lst(*destination_mint)?;
+        let LstData {
+            token_program: src_token_program,
+            sol_val_calc: src_sol_val_calc,
+            ..
+        } = lst_data;
         Ok(remove_liquidity_ix_by_mint_full_for_prog(
             self.program_id,
             RemoveLiquidityByMintFreeArgs {
@@ -138,6 +217,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 pricing_program_price_lp_accounts: &self
                     .pricing_prog()?
                     .price_lp_tokens_to_redeem_accounts(*destination_mint)?,
+                transfer_hook: lst_data.transfer_hook_accounts_data(*destination_mint)?,
             },
         )?)
     }
@@ -1,13 +1,13 @@
 use anyhow::anyhow;
 use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapParams};
 use pricing_programs_interface::{PriceExactInIxArgs, PriceExactInKeys};
-use s_controller_interface::SControllerError;
+use s_controller_interface::{PoolState, SControllerError};
 use s_controller_lib::{
-    calc_swap_protocol_fees, swap_exact_in_ix_by_mint_full_for_prog, try_pool_state,
-    CalcSwapProtocolFeesArgs, SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs,
-    SwapExactInAmounts,
+    calc_swap_protocol_fees, ix_extend_with_swap_transfer_hook_accounts,
+    swap_exact_in_ix_by_mint_full_for_prog, try_pool_state, CalcSwapProtocolFeesArgs,
+    SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs, SwapExactInAmounts,
 };
-use s_pricing_prog_aggregate::PricingProg;
+use s_pricing_prog_aggregate::{PricingProg, SyncedSolValues};
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
 use sanctum_token_lib::MintWithTokenProgram;
 use sanctum_token_ratio::AmtsAfterFeeBuilder;
@@ -16,10 +16,12 @@ use solana_sdk::instruction::Instruction;
 
 use crate::{LstData, SPool};
 
-use super::{apply_sync_sol_value, calc_quote_fees};
+use super::{
+    calc_quote_fees, sync_lst, sync_sol_value_with_reserves, BeforeAfter, QuoteDetailed, SyncedLst,
+};
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
-    pub(crate) fn quote_swap_exact_in(
+    pub(crate) fn quote_swap_exact_in_detailed(
         &self,
         QuoteParams {
             amount,
@@ -27,42 +29,71 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             output_mint,
             ..
         }: &QuoteParams,
-    ) -> anyhow::Result<Quote> {
+    ) -> anyhow::Result<QuoteDetailed> {
         let pool_state_data = self.pool_state_data()?;
         let pool_state = try_pool_state(&pool_state_data)?;
+
+        let (input_lst_state, input_lst_data) = self.find_ready_lst(*input_mint)?;
+        let (pool_state, input) = sync_lst(*pool_state, input_lst_state, input_lst_data)?;
+        let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
+        let (pool_state, output) = sync_lst(pool_state, output_lst_state, output_lst_data)?;
+
+        self.quote_swap_exact_in_synced(pool_state, &input, &output, *amount)
+    }
+
+    /// `pool_state` must have both `input` and `output` synced
+    pub(crate) fn quote_swap_exact_in_synced(
+        &self,
+        pool_state: PoolState,
+        SyncedLst {
+            lst_state: input_lst_state,
+            lst_data: input_lst_data,
+            reserves_balance: input_reserves_balance,
+            ..
+        }: &SyncedLst<'_, S>,
+        SyncedLst {
+            lst_state: output_lst_state,
+            lst_data: output_lst_data,
+            reserves_balance: output_reserves_balance,
+            ..
+        }: &SyncedLst<'_, S>,
+        amount: u64,
+    ) -> anyhow::Result<QuoteDetailed> {
         let pricing_prog = self
             .pricing_prog
             .as_ref()
             .ok_or_else(|| anyhow!("pricing program not fetched"))?;
+        let input_mint = input_lst_state.mint;
+        let output_mint = output_lst_state.mint;
+        let input_reserves_balance = *input_reserves_balance;
+        let output_reserves_balance = *output_reserves_balance;
 
-        let (input_lst_state, input_lst_data) = self.find_ready_lst(*input_mint)?;
-        let (pool_state, _input_lst_state, _input_reserves_balance) =
-            apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
-        let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
-        let (pool_state, _output_lst_state, output_reserves_balance) =
-            apply_sync_sol_value(pool_state, output_lst_state, output_lst_data)?;
-
-        let in_sol_value = input_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_min();
+        // pool prices what it receives after transfer fees, see process_swap_exact_in()
+        let amount_received = input_lst_data.amount_after_transfer_fee(amount)?;
+        let in_sol_value_range = input_lst_data.sol_val_calc.lst_to_sol(amount_received)?;
+        let in_sol_value = in_sol_value_range.get_min();
         if in_sol_value == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
-        let out_sol_value = pricing_prog.quote_exact_in(
+        let out_sol_value = pricing_prog.quote_exact_in_synced(
             PriceExactInKeys {
-                input_lst_mint: *input_mint,
-                output_lst_mint: *output_mint,
+                input_lst_mint: input_mint,
+                output_lst_mint: output_mint,
             },
             &PriceExactInIxArgs {
-                amount: *amount,
+                amount: amount_received,
                 sol_value: in_sol_value,
             },
+            &SyncedSolValues {
+                input_lst_sol_value: input_lst_state.sol_value,
+                output_lst_sol_value: output_lst_state.sol_value,
+            },
         )?;
         if out_sol_value > in_sol_value {
             return Err(SControllerError::PoolWouldLoseSolValue.into());
         }
-        let dst_lst_out = output_lst_data
-            .sol_val_calc
-            .sol_to_lst(out_sol_value)?
-            .get_min();
+        let dst_lst_out_range = output_lst_data.sol_val_calc.sol_to_lst(out_sol_value)?;
+        let dst_lst_out = dst_lst_out_range.get_min();
         if dst_lst_out == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
@@ -76,19 +107,56 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             .checked_add(to_protocol_fees_lst_amount)
             .ok_or(SControllerError::MathError)?;
         let not_enough_liquidity = total_dst_lst_out > output_reserves_balance;
+        let out_amount = output_lst_data.amount_after_transfer_fee(dst_lst_out)?;
         let (fee_amount, fee_pct) = calc_quote_fees(
             AmtsAfterFeeBuilder::new_amt_bef_fee(in_sol_value).with_amt_aft_fee(out_sol_value)?,
-            &output_lst_data.sol_val_calc,
+            output_lst_data.sol_val_calc.as_ref(),
         )?;
-        Ok(Quote {
-            not_enough_liquidity,
-            min_in_amount: None,
-            min_out_amount: None,
-            in_amount: *amount,
-            out_amount: dst_lst_out,
-            fee_mint: *output_mint,
-            fee_amount,
-            fee_pct,
+
+        let input_reserves = BeforeAfter {
+            before: input_reserves_balance,
+            after: input_reserves_balance
+                .checked_add(amount_received)
+                .ok_or(SControllerError::MathError)?,
+        };
+        let output_reserves = BeforeAfter {
+            before: output_reserves_balance,
+            after: output_reserves_balance.saturating_sub(total_dst_lst_out),
+        };
+        let (pool_state_after, _) = sync_sol_value_with_reserves(
+            pool_state,
+            *input_lst_state,
+            *input_lst_data,
+            input_reserves.after,
+        )?;
+        let (pool_state_after, _) = sync_sol_value_with_reserves(
+            pool_state_after,
+            *output_lst_state,
+            *output_lst_data,
+            output_reserves.after,
+        )?;
+
+        Ok(QuoteDetailed {
+            quote: Quote {
+                not_enough_liquidity,
+                min_in_amount: None,
+                min_out_amount: None,
+                in_amount: amount,
+                out_amount,
+                fee_mint: output_mint,
+                fee_amount,
+                fee_pct,
+            },
+            pool_state_before: pool_state,
+            pool_state_after,
+            in_sol_value,
+            out_sol_value,
+            input_calc_range: Some(in_sol_value_range),
+            output_calc_range: Some(dst_lst_out_range),
+            to_protocol_fees_lst_amount,
+            input_reserves: Some(input_reserves),
+            output_reserves: Some(output_reserves),
+            lp_token_supply: None,
         })
     }
 
@@ -105,27 +173,23 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             ..
         }: &SwapParams,
     ) -> anyhow::Result<Instruction> {
-        let (
-            _,
-            LstData {
-                token_program: src_token_program,
-                sol_val_calc: src_sol_val_calc,
-                ..
-            },
-        ) = self.find_ready_lst(*source_mint)?;
-        let (
-            _,
-            LstData {
-                token_program: dst_token_program,
-                sol_val_calc: dst_sol_val_calc,
-                ..
-            },
-        ) = self.find_ready_lst(*destination_mint)?;
+        let (_, src_lst_data) = self.find_ready_lst(*source_mint)?;
+        let LstData {
+            token_program: src_token_program,
+            sol_val_calc: src_sol_val_calc,
+            ..
+        } = src_lst_data;
+        let (_, dst_lst_data) = self.find_ready_lst(*destination_mint)?;
+        let LstData {
+            token_program: dst_token_program,
+            sol_val_calc: dst_sol_val_calc,
+            ..
+        } = dst_lst_data;
         let pricing_program = {
             let pool_state_data = self.pool_state_data()?;
             try_pool_state(&pool_state_data)?.pricing_program
         };
-        Ok(swap_exact_in_ix_by_mint_full_for_prog(
+        let mut ix = swap_exact_in_ix_by_mint_full_for_prog(
             self.program_id,
             SwapByMintsFreeArgs {
                 signer: *token_transfer_authority,
@@ -157,7 +221,15 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                     output_lst_mint: *destination_mint,
                 })?,
             pricing_program,
-        )?)
+        )?;
+        ix_extend_with_swap_transfer_hook_accounts(
+            &mut ix,
+            src_lst_data.transfer_hook_accounts_data(*source_mint)?,
+            *in_amount,
+            dst_lst_data.transfer_hook_accounts_data(*destination_mint)?,
+            *out_amount,
+        )?;
+        Ok(ix)
     }
 
     pub(crate) fn swap_exact_in_swap_and_account_metas(
@@ -3,11 +3,11 @@ use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapParams}
 use pricing_programs_interface::{PriceExactOutIxArgs, PriceExactOutKeys};
 use s_controller_interface::SControllerError;
 use s_controller_lib::{
-    calc_swap_protocol_fees, swap_exact_out_ix_by_mint_full_for_prog, try_pool_state,
-    CalcSwapProtocolFeesArgs, SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs,
-    SwapExactOutAmounts,
+    calc_swap_protocol_fees, ix_extend_with_swap_transfer_hook_accounts,
+    swap_exact_out_ix_by_mint_full_for_prog, try_pool_state, CalcSwapProtocolFeesArgs,
+    SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs, SwapExactOutAmounts,
 };
-use s_pricing_prog_aggregate::PricingProg;
+use s_pricing_prog_aggregate::{PricingProg, SyncedSolValues};
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
 use sanctum_token_lib::MintWithTokenProgram;
 use sanctum_token_ratio::AmtsAfterFeeBuilder;
@@ -16,10 +16,12 @@ use solana_sdk::instruction::Instruction;
 
 use crate::{LstData, SPool};
 
-use super::{apply_sync_sol_value, calc_quote_fees};
+use super::{
+    apply_sync_sol_value, calc_quote_fees, sync_sol_value_with_reserves, BeforeAfter, QuoteDetailed,
+};
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
-    pub(crate) fn quote_swap_exact_out(
+    pub(crate) fn quote_swap_exact_out_detailed(
         &self,
         QuoteParams {
             amount,
@@ -27,7 +29,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             output_mint,
             ..
         }: &QuoteParams,
-    ) -> anyhow::Result<Quote> {
+    ) -> anyhow::Result<QuoteDetailed> {
         let pool_state_data = self.pool_state_data()?;
         let pool_state = try_pool_state(&pool_state_data)?;
         let pricing_prog = self
@@ -36,17 +38,18 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             .ok_or_else(|| anyhow!("pricing program not fetched"))?;
 
         let (input_lst_state, input_lst_data) = self.find_ready_lst(*input_mint)?;
-        let (pool_state, _input_lst_state, _input_reserves_balance) =
+        let (pool_state, input_lst_state, input_reserves_balance) =
             apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
         let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
-        let (pool_state, _output_lst_state, output_reserves_balance) =
+        let (pool_state, output_lst_state, output_reserves_balance) =
             apply_sync_sol_value(pool_state, output_lst_state, output_lst_data)?;
 
-        let out_sol_value = output_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_max();
+        let out_sol_value_range = output_lst_data.sol_val_calc.lst_to_sol(*amount)?;
+        let out_sol_value = out_sol_value_range.get_max();
         if out_sol_value == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
-        let in_sol_value = pricing_prog.quote_exact_out(
+        let in_sol_value = pricing_prog.quote_exact_out_synced(
             PriceExactOutKeys {
                 input_lst_mint: *input_mint,
                 output_lst_mint: *output_mint,
@@ -55,17 +58,27 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 amount: *amount,
                 sol_value: out_sol_value,
             },
+            &SyncedSolValues {
+                input_lst_sol_value: input_lst_state.sol_value,
+                output_lst_sol_value: output_lst_state.sol_value,
+            },
         )?;
         if out_sol_value > in_sol_value {
             return Err(SControllerError::PoolWouldLoseSolValue.into());
         }
-        let src_lst_in = input_lst_data
-            .sol_val_calc
-            .sol_to_lst(in_sol_value)?
-            .get_max();
+        let src_lst_in_range = input_lst_data.sol_val_calc.sol_to_lst(in_sol_value)?;
+        let src_lst_in = src_lst_in_range.get_max();
         if src_lst_in == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
+        // the program rejects transfers that are charged a transfer fee
+        // since the exact amounts are priced, so LSTs with the transfer fee extension
+        // can still be swapped as long as no fee is charged on these amounts
+        if input_lst_data.transfer_fee(src_lst_in)? != 0
+            || output_lst_data.transfer_fee(*amount)? != 0
+        {
+            return Err(SControllerError::TransferAmountMismatch.into());
+        }
         let to_protocol_fees_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
             in_sol_value,
             out_sol_value,
@@ -78,17 +91,53 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         let not_enough_liquidity = total_dst_lst_out > output_reserves_balance;
         let (fee_amount, fee_pct) = calc_quote_fees(
             AmtsAfterFeeBuilder::new_amt_bef_fee(in_sol_value).with_amt_aft_fee(out_sol_value)?,
-            &output_lst_data.sol_val_calc,
+            output_lst_data.sol_val_calc.as_ref(),
         )?;
-        Ok(Quote {
-            not_enough_liquidity,
-            min_in_amount: None,
-            min_out_amount: None,
-            in_amount: src_lst_in,
-            out_amount: *amount,
-            fee_mint: *output_mint,
-            fee_amount,
-            fee_pct,
+
+        let input_reserves = BeforeAfter {
+            before: input_reserves_balance,
+            after: input_reserves_balance
+                .checked_add(src_lst_in)
+                .ok_or(SControllerError::MathError)?,
+        };
+        let output_reserves = BeforeAfter {
+            before: output_reserves_balance,
+            after: output_reserves_balance.saturating_sub(total_dst_lst_out),
+        };
+        let (pool_state_after, _) = sync_sol_value_with_reserves(
+            pool_state,
+            input_lst_state,
+            input_lst_data,
+            input_reserves.after,
+        )?;
+        let (pool_state_after, _) = sync_sol_value_with_reserves(
+            pool_state_after,
+            output_lst_state,
+            output_lst_data,
+            output_reserves.after,
+        )?;
+
+        Ok(QuoteDetailed {
+            quote: Quote {
+                not_enough_liquidity,
+                min_in_amount: None,
+                min_out_amount: None,
+                in_amount: src_lst_in,
+                out_amount: *amount,
+                fee_mint: *output_mint,
+                fee_amount,
+                fee_pct,
+            },
+            pool_state_before: pool_state,
+            pool_state_after,
+            in_sol_value,
+            out_sol_value,
+            input_calc_range: Some(src_lst_in_range),
+            output_calc_range: Some(out_sol_value_range),
+            to_protocol_fees_lst_amount,
+            input_reserves: Some(input_reserves),
+            output_reserves: Some(output_reserves),
+            lp_token_supply: None,
         })
     }
 
@@ -105,27 +154,23 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             ..
         }: &SwapParams,
     ) -> anyhow::Result<Instruction> {
-        let (
-            _,
-            LstData {
-                token_program: src_token_program,
-                sol_val_calc: src_sol_val_calc,
-                ..
-            },
-        ) = self.find_ready_lst(*source_mint)?;
-        let (
-            _,
-            LstData {
-                token_program: dst_token_program,
-                sol_val_calc: dst_sol_val_calc,
-                ..
-            },
-        ) = self.find_ready_lst(*destination_mint)?;
+        let (_, src_lst_data) = self.find_ready_lst(*source_mint)?;
+        let LstData {
+            token_program: src_token_program,
+            sol_val_calc: src_sol_val_calc,
+            ..
+        } = src_lst_data;
+        let (_, dst_lst_data) = self.find_ready_lst(*destination_mint)?;
+        let LstData {
+            token_program: dst_token_program,
+            sol_val_calc: dst_sol_val_calc,
+            ..
+        } = dst_lst_data;
         let pricing_program = {
             let pool_state_data = self.pool_state_data()?;
             try_pool_state(&pool_state_data)?.pricing_program
         };
-        Ok(swap_exact_out_ix_by_mint_full_for_prog(
+        let mut ix = swap_exact_out_ix_by_mint_full_for_prog(
             self.program_id,
             SwapByMintsFreeArgs {
                 signer: *token_transfer_authority,
@@ -157,7 +202,15 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                     output_lst_mint: *destination_mint,
                 })?,
             pricing_program,
-        )?)
+        )?;
+        ix_extend_with_swap_transfer_hook_accounts(
+            &mut ix,
+            src_lst_data.transfer_hook_accounts_data(*source_mint)?,
+            *in_amount,
+            dst_lst_data.transfer_hook_accounts_data(*destination_mint)?,
+            *out_amount,
+        )?;
+        Ok(ix)
     }
 
     pub(crate) fn swap_exact_out_swap_and_account_metas(
@@ -0,0 +1,82 @@
+use s_controller_lib::try_lst_state_list;
+use s_sol_val_calc_prog_aggregate::AccountFetcher;
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{utils::try_lst_data_discovered, SPool, SPoolRegistries};
+
+impl<S, L: ReadonlyAccountData> SPool<S, L> {
+    /// Same as [`Self::from_lst_state_list_account_with_registries`], but discovers
+    /// each LST's SOL value calculator data from on-chain accounts fetched by `fetcher`
+    /// instead of from a static LST list.
+    ///
+    /// LSTs that fail discovery are left as `None` in `lst_data_list`,
+    /// their mints and errors are returned alongside the created pool.
+    pub fn from_lst_state_list_account_discovered<F: AccountFetcher>(
+        program_id: Pubkey,
+        lst_state_list_account: L,
+        fetcher: &F,
+        registries: SPoolRegistries<S>,
+    ) -> anyhow::Result<(Self, Vec<(Pubkey, anyhow::Error)>)> {
+        let mut res = Self::from_lst_state_list_account_with_registries(
+            program_id,
+            lst_state_list_account,
+            &[],
+            registries,
+        )?;
+        let discovery_errs = res.discover_missing_lst_data_per_mint(fetcher)?;
+        Ok((res, discovery_errs))
+    }
+
+    /// Discovers the data of all LSTs that are `None` in `lst_data_list`,
+    /// e.g. LSTs that are not in the LST list used for initialization, or that were
+    /// added to the pool after initialization, from on-chain accounts fetched by `fetcher`.
+    ///
+    /// Newly discovered LSTs must be updated before they can be used.
+    ///
+    /// Returns the first encountered error, but tries to discover every LST eagerly
+    /// even after encountering an error.
+    pub fn discover_missing_lst_data<F: AccountFetcher>(
+        &mut self,
+        fetcher: &F,
+    ) -> anyhow::Result<()> {
+        match self
+            .discover_missing_lst_data_per_mint(fetcher)?
+            .into_iter()
+            .next()
+        {
+            Some((_mint, e)) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Same as [`Self::discover_missing_lst_data`], but returns the mint and error
+    /// of every LST that failed discovery.
+    ///
+    /// Only errors if the LST state list is invalid.
+    pub fn discover_missing_lst_data_per_mint<F: AccountFetcher>(
+        &mut self,
+        fetcher: &F,
+    ) -> anyhow::Result<Vec<(Pubkey, anyhow::Error)>> {
+        let lst_state_list_acc_data = self.lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        Ok(lst_state_list
+            .iter()
+            .zip(self.lst_data_list.iter_mut())
+            .filter(|(_, lst_data)| lst_data.is_none())
+            .filter_map(|(lst_state, lst_data)| {
+                match try_lst_data_discovered(
+                    &self.registries.lst_sol_val_calcs,
+                    fetcher,
+                    lst_state,
+                ) {
+                    Ok(d) => {
+                        *lst_data = Some(d);
+                        None
+                    }
+                    Err(e) => Some((lst_state.mint, e)),
+                }
+            })
+            .collect())
+    }
+}
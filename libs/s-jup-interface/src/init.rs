@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use s_controller_lib::{
     find_lst_state_list_address, find_pool_state_address, try_lst_state_list, try_pool_state,
 };
@@ -7,7 +9,7 @@ use solana_readonly_account::ReadonlyAccountData;
 
 use crate::{
     utils::{try_lst_data, try_pricing_prog},
-    SPool,
+    SPool, SPoolRegistries,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -53,7 +55,7 @@ impl<S, L> SPool<S, L> {
     }
 }
 
-impl<S, L: ReadonlyAccountData> SPool<S, L> {
+impl<S: ReadonlyAccountData + 'static, L: ReadonlyAccountData> SPool<S, L> {
     /// `Self`s created from this fn must be update_full() 2 more times before they can be used
     /// - first update fetches pool_state, updates various sol value calculator programs and pricing program
     /// - second update fetches LP token mint read from fetched pool_state
@@ -61,6 +63,24 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
         program_id: Pubkey,
         lst_state_list_account: L,
         lst_list: &[SanctumLst],
+    ) -> anyhow::Result<Self> {
+        Self::from_lst_state_list_account_with_registries(
+            program_id,
+            lst_state_list_account,
+            lst_list,
+            SPoolRegistries::default(),
+        )
+    }
+}
+
+impl<S, L: ReadonlyAccountData> SPool<S, L> {
+    /// Same as [`Self::from_lst_state_list_account`], but resolves the pricing program
+    /// and SOL value calculator programs with `registries` instead of the default ones
+    pub fn from_lst_state_list_account_with_registries(
+        program_id: Pubkey,
+        lst_state_list_account: L,
+        lst_list: &[SanctumLst],
+        registries: SPoolRegistries<S>,
     ) -> anyhow::Result<Self> {
         let SPoolInitKeys {
             lst_state_list: lst_state_list_addr,
@@ -71,7 +91,7 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
             let lst_state_list = try_lst_state_list(&lst_state_list_account_data)?;
             lst_state_list
                 .iter()
-                .map(|lst_state| try_lst_data(lst_list, lst_state))
+                .map(|lst_state| try_lst_data(&registries.lst_sol_val_calcs, lst_list, lst_state))
                 .collect()
         };
         Ok(Self {
@@ -83,29 +103,54 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
             lp_mint_supply: None,
             lst_state_list_account,
             lst_data_list,
+            registries,
+            account_slots: HashMap::new(),
         })
     }
 }
 
-impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+impl<S: ReadonlyAccountData + 'static, L: ReadonlyAccountData> SPool<S, L> {
     /// `Self`s created from this fn must be update_full() 1 more time before they can be used.
     ///  - this update updates the various sol value calculator programs and pricing program
     pub fn from_init_accounts(
+        program_id: Pubkey,
+        init_accounts: SPoolInitAccounts<S, L>,
+        lst_list: &[SanctumLst],
+    ) -> anyhow::Result<Self> {
+        Self::from_init_accounts_with_registries(
+            program_id,
+            init_accounts,
+            lst_list,
+            SPoolRegistries::default(),
+        )
+    }
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Same as [`Self::from_init_accounts`], but resolves the pricing program
+    /// and SOL value calculator programs with `registries` instead of the default ones
+    pub fn from_init_accounts_with_registries(
         program_id: Pubkey,
         SPoolInitAccounts {
             lst_state_list: lst_state_list_acc,
             pool_state: pool_state_acc,
         }: SPoolInitAccounts<S, L>,
         lst_list: &[SanctumLst],
+        registries: SPoolRegistries<S>,
     ) -> anyhow::Result<Self> {
         let pricing_prog = {
             let lst_state_list_acc_data = lst_state_list_acc.data();
             let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
             let pool_state_acc_data = pool_state_acc.data();
             let pool_state = try_pool_state(&pool_state_acc_data)?;
-            try_pricing_prog(pool_state, lst_state_list)?
+            try_pricing_prog(&registries.pricing_progs, pool_state, lst_state_list)?
         };
-        let mut res = Self::from_lst_state_list_account(program_id, lst_state_list_acc, lst_list)?;
+        let mut res = Self::from_lst_state_list_account_with_registries(
+            program_id,
+            lst_state_list_acc,
+            lst_list,
+            registries,
+        )?;
         res.pool_state_account = Some(pool_state_acc);
         res.pricing_prog = Some(pricing_prog);
         Ok(res)
@@ -93,7 +93,13 @@ impl Amm for SPoolJup {
         true
     }
 
-    /// TODO: this is not true for AddLiquidity and RemoveLiquidity
+    /// ExactOut is only supported for swaps between LSTs.
+    ///
+    /// This returns true since it applies to the whole pool, but there are no ExactOut add and remove
+    /// liquidity instructions, so [`Amm::quote`] and [`Amm::get_swap_and_account_metas`] return an error
+    /// for ExactOut pairs involving the LP token mint. See
+    /// [`crate::SPool::quote_add_liquidity_min_in_detailed`]
+    /// and [`crate::SPool::quote_remove_liquidity_min_in_detailed`] for ExactIn alternatives
     fn supports_exact_out(&self) -> bool {
         true
     }
@@ -1,30 +1,70 @@
+use std::collections::HashMap;
+
 use anyhow::anyhow;
 use s_controller_interface::LstState;
 use s_controller_lib::{try_lst_state_list, try_pool_state};
-use s_pricing_prog_aggregate::KnownPricingProg;
-use s_sol_val_calc_prog_aggregate::KnownLstSolValCalc;
+use s_pricing_prog_aggregate::DynPricingProg;
+use s_sol_val_calc_prog_aggregate::DynLstSolValCalc;
 use sanctum_associated_token_lib::{CreateAtaAddressArgs, FindAtaAddressArgs};
 use solana_program::pubkey::{Pubkey, PubkeyError};
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::account::Account;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
 
 pub(crate) mod utils;
 
 mod core;
+mod discovery;
 mod init;
 mod jup_interface;
+mod registries;
+mod slots;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+mod stream;
+mod token_2022;
+mod tx_builder;
 mod update;
 
 pub use core::*;
+pub use discovery::*;
 pub use init::*;
 pub use jup_interface::*;
+pub use registries::*;
+pub use slots::*;
+#[cfg(feature = "snapshot")]
+pub use snapshot::*;
+pub use stream::*;
+pub use token_2022::*;
+pub use tx_builder::*;
 pub use update::*;
 
-#[derive(Debug, Clone)]
-pub struct LstData {
-    pub sol_val_calc: KnownLstSolValCalc,
+#[derive(Debug)]
+pub struct LstData<D> {
+    pub sol_val_calc: Box<dyn DynLstSolValCalc<D>>,
     pub reserves_balance: Option<u64>,
     pub token_program: Pubkey,
+    // None for Tokenkeg LSTs and Token-2022 LSTs without the transfer fee extension
+    pub transfer_fee_config: Option<TransferFeeConfig>,
+    // None for LSTs without the transfer hook extension
+    pub transfer_hook_program_id: Option<Pubkey>,
+    // data of the transfer hook program's ExtraAccountMetaList account for the LST mint.
+    // Fetched on the update after transfer_hook_program_id is read from the mint
+    pub transfer_hook_extra_account_metas: Option<Vec<u8>>,
+}
+
+// manual impl to avoid derive(Clone)'s `D: Clone` bound
+impl<D> Clone for LstData<D> {
+    fn clone(&self) -> Self {
+        Self {
+            sol_val_calc: self.sol_val_calc.clone(),
+            reserves_balance: self.reserves_balance,
+            token_program: self.token_program,
+            transfer_fee_config: self.transfer_fee_config,
+            transfer_hook_program_id: self.transfer_hook_program_id,
+            transfer_hook_extra_account_metas: self.transfer_hook_extra_account_metas.clone(),
+        }
+    }
 }
 
 /// Convenience type alias for jupiter
@@ -39,14 +79,19 @@ pub struct SPool<S, L> {
     // pool_state optional since lst_state_list is the KeyedAccount we initialize with
     pub pool_state_account: Option<S>,
     pub lst_state_list_account: L,
-    pub pricing_prog: Option<KnownPricingProg>,
+    pub pricing_prog: Option<Box<dyn DynPricingProg<S>>>,
     // indices match that of lst_state_list.
     // None means we don't know how to handle the given lst
     // this could be due to incomplete data or unknown LST sol value calculator program
-    pub lst_data_list: Vec<Option<LstData>>,
+    pub lst_data_list: Vec<Option<LstData<S>>>,
+    // used to initialize pricing_prog and lst_data_list
+    pub registries: SPoolRegistries<S>,
+    // context slot each account was last updated at.
+    // Only populated by slot-aware updates, see update_full_slotted()
+    pub account_slots: HashMap<Pubkey, u64>,
 }
 
-impl<S, L: Default> Default for SPool<S, L> {
+impl<S: ReadonlyAccountData + 'static, L: Default> Default for SPool<S, L> {
     fn default() -> Self {
         Self {
             program_id: s_controller_lib::program::ID,
@@ -57,6 +102,8 @@ impl<S, L: Default> Default for SPool<S, L> {
             pricing_prog: None,
             lst_state_list_account: L::default(),
             lst_data_list: Vec::new(),
+            registries: SPoolRegistries::default(),
+            account_slots: HashMap::new(),
         }
     }
 }
@@ -64,9 +111,9 @@ impl<S, L: Default> Default for SPool<S, L> {
 // More impl blocks in other files
 
 impl<S, L> SPool<S, L> {
-    pub fn pricing_prog(&self) -> anyhow::Result<&KnownPricingProg> {
+    pub fn pricing_prog(&self) -> anyhow::Result<&dyn DynPricingProg<S>> {
         self.pricing_prog
-            .as_ref()
+            .as_deref()
             .ok_or_else(|| anyhow!("pricing program not fetched"))
     }
 
@@ -77,7 +124,7 @@ impl<S, L> SPool<S, L> {
             pool_reserves_bump,
             ..
         }: &LstState,
-        LstData { token_program, .. }: &LstData,
+        LstData { token_program, .. }: &LstData<S>,
     ) -> Result<Pubkey, PubkeyError> {
         CreateAtaAddressArgs {
             find_ata_args: FindAtaAddressArgs {
@@ -109,7 +156,7 @@ impl<S: ReadonlyAccountData, L> SPool<S, L> {
 }
 
 impl<S, L: ReadonlyAccountData> SPool<S, L> {
-    pub fn find_ready_lst(&self, lst_mint: Pubkey) -> anyhow::Result<(LstState, &LstData)> {
+    pub fn find_ready_lst(&self, lst_mint: Pubkey) -> anyhow::Result<(LstState, &LstData<S>)> {
         let lst_state_list_account_data = self.lst_state_list_account.data();
         let lst_state_list = try_lst_state_list(&lst_state_list_account_data)?;
         let (lst_state, lst_data) = lst_state_list
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use s_pricing_prog_aggregate::PricingProgRegistry;
+use s_sol_val_calc_prog_aggregate::LstSolValCalcRegistry;
+use solana_readonly_account::ReadonlyAccountData;
+
+/// The registries [`crate::SPool`] uses to resolve `pool_state.pricing_program`
+/// and each `LstState::sol_value_calculator` to their implementations.
+///
+/// [`Default`] only has the programs in this repo registered.
+/// Register additional programs to quote pools that use private pricing or SOL value calculator programs.
+#[derive(Debug)]
+pub struct SPoolRegistries<D> {
+    pub pricing_progs: Arc<PricingProgRegistry<D>>,
+    pub lst_sol_val_calcs: Arc<LstSolValCalcRegistry<D>>,
+}
+
+// manual impl to avoid derive(Clone)'s `D: Clone` bound
+impl<D> Clone for SPoolRegistries<D> {
+    fn clone(&self) -> Self {
+        Self {
+            pricing_progs: Arc::clone(&self.pricing_progs),
+            lst_sol_val_calcs: Arc::clone(&self.lst_sol_val_calcs),
+        }
+    }
+}
+
+impl<D: ReadonlyAccountData + 'static> Default for SPoolRegistries<D> {
+    fn default() -> Self {
+        Self {
+            pricing_progs: Arc::new(PricingProgRegistry::default()),
+            lst_sol_val_calcs: Arc::new(LstSolValCalcRegistry::default()),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::anyhow;
+use jupiter_amm_interface::{Quote, QuoteParams};
+use s_controller_interface::LstState;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::SPool;
+
+/// An account and the context slot it was fetched at
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlottedAccount<D> {
+    pub context_slot: u64,
+    pub account: D,
+}
+
+/// Inclusive range of the context slots of a set of accounts
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SlotRange {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl SlotRange {
+    pub const fn spread(&self) -> u64 {
+        self.max - self.min
+    }
+
+    fn including(self, slot: u64) -> Self {
+        Self {
+            min: self.min.min(slot),
+            max: self.max.max(slot),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SlotCheckedQuote {
+    pub quote: Quote,
+
+    /// Context slots of the accounts the quote was computed from.
+    /// `None` if any of them was not updated with a context slot
+    pub slot_range: Option<SlotRange>,
+
+    /// `slot_range` is unknown or spreads over more slots than allowed.
+    ///
+    /// Unsafe quotes mix account states from slots too far apart and may revert on-chain
+    pub is_unsafe: bool,
+}
+
+impl<D: ReadonlyAccountData + Clone> SPool<D, D> {
+    /// Same as [`Self::update_full`], but also records the context slot of each account.
+    ///
+    /// Accounts with an older context slot than the one they were last updated at are stale
+    /// and are not applied, but all other accounts are still updated.
+    ///
+    /// Returns an error if any account was stale, else the error of [`Self::update_full`], if any
+    pub fn update_full_slotted(
+        &mut self,
+        account_map: &HashMap<Pubkey, SlottedAccount<D>>,
+    ) -> anyhow::Result<()> {
+        let mut stale = Vec::new();
+        let fresh: HashMap<Pubkey, D> = account_map
+            .iter()
+            .filter_map(
+                |(
+                    pubkey,
+                    SlottedAccount {
+                        context_slot,
+                        account,
+                    },
+                )| {
+                    match self.account_slots.get(pubkey) {
+                        Some(last_slot) if last_slot > context_slot => {
+                            stale.push(*pubkey);
+                            None
+                        }
+                        _ => Some((*pubkey, account.clone())),
+                    }
+                },
+            )
+            .collect();
+        let r = self.update_full(&fresh);
+        self.account_slots.extend(
+            fresh
+                .keys()
+                .map(|pubkey| (*pubkey, account_map[pubkey].context_slot)),
+        );
+        // stop tracking accounts the pool no longer depends on, e.g. of removed LSTs
+        let tracked: HashSet<Pubkey> = self.get_accounts_to_update_full().into_iter().collect();
+        self.account_slots
+            .retain(|pubkey, _| tracked.contains(pubkey));
+        let stale_res = if stale.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!("Stale account updates refused: {stale:?}"))
+        };
+        stale_res.and(r)
+    }
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Returns the oldest context slot of the accounts in [`Self::get_accounts_to_update_full`].
+    /// The pool's state reflects on-chain state as of at least this slot.
+    ///
+    /// `None` if any of them was not updated with a context slot
+    pub fn min_consistent_slot(&self) -> Option<u64> {
+        self.slot_range(self.get_accounts_to_update_full())
+            .map(|SlotRange { min, .. }| min)
+    }
+
+    /// Returns the accounts that quoting from `input_mint` to `output_mint` depends on
+    ///
+    /// Optional pricing program accounts, like flat fee PairFeeAccounts, are only included
+    /// if they were discovered to exist, so accounts that don't exist on-chain never leave
+    /// [`Self::pair_slot_range`] unknown. Call `FlatFeePricingProg::discover_pair_fee_accounts`
+    /// again after a tracked PairFeeAccount is closed, else its slot stops advancing
+    pub fn get_accounts_for_pair(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+    ) -> anyhow::Result<Vec<Pubkey>> {
+        let lp_mint = self.lp_token_mint()?;
+        let lst_mints: Vec<Pubkey> = [input_mint, output_mint]
+            .into_iter()
+            .filter(|mint| *mint != lp_mint)
+            .collect();
+        let mut res: Vec<Pubkey> = self.get_accounts_to_update_base().into();
+        if lst_mints.len() < 2 {
+            // add/remove liquidity
+            res.push(lp_mint);
+            res.extend(self.get_accounts_to_update_pricing_prog_for_liquidity());
+        } else {
+            res.extend(
+                self.get_accounts_to_update_pricing_prog_for_lsts(lst_mints.iter().copied()),
+            );
+        }
+        res.extend(
+            self.get_accounts_to_update_lsts_filtered(|LstState { mint, .. }, _| {
+                lst_mints.contains(mint)
+            }),
+        );
+        Ok(res)
+    }
+
+    /// Returns the context slots of [`Self::get_accounts_for_pair`].
+    ///
+    /// `None` if any of them was not updated with a context slot
+    pub fn pair_slot_range(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+    ) -> anyhow::Result<Option<SlotRange>> {
+        Ok(self.slot_range(self.get_accounts_for_pair(input_mint, output_mint)?))
+    }
+
+    /// Same as [`Self::quote_full`], but also marks the quote as unsafe if the accounts
+    /// it was computed from spread over more than `max_slot_spread` slots
+    pub fn quote_slot_checked(
+        &self,
+        quote_params: &QuoteParams,
+        max_slot_spread: u64,
+    ) -> anyhow::Result<SlotCheckedQuote> {
+        let quote = self.quote_full(quote_params)?;
+        let slot_range = self.pair_slot_range(quote_params.input_mint, quote_params.output_mint)?;
+        let is_unsafe = !slot_range.is_some_and(|range| range.spread() <= max_slot_spread);
+        Ok(SlotCheckedQuote {
+            quote,
+            slot_range,
+            is_unsafe,
+        })
+    }
+
+    fn slot_range<I: IntoIterator<Item = Pubkey>>(&self, accounts: I) -> Option<SlotRange> {
+        accounts
+            .into_iter()
+            .try_fold(None, |range: Option<SlotRange>, pubkey| {
+                let slot = *self.account_slots.get(&pubkey)?;
+                Some(Some(range.map_or(
+                    SlotRange {
+                        min: slot,
+                        max: slot,
+                    },
+                    |r| r.including(slot),
+                )))
+            })
+            .flatten()
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::anyhow;
+use s_controller_interface::LstState;
+use s_controller_lib::try_lst_state_list;
+use serde::{Deserialize, Serialize};
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+use crate::{SPool, SPoolInitKeys, SPoolRegistries};
+
+/// Raw accounts, and the slot they were fetched at, behind the state of an [`SPool`].
+///
+/// Save with [`SPool::snapshot`] and load with [`SPool::from_snapshot`]
+/// to replay quotes against the exact same pool state offline.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SPoolSnapshot {
+    pub slot: u64,
+
+    #[serde(with = "pubkey_str")]
+    pub program_id: Pubkey,
+
+    pub accounts: Vec<AccountSnapshot>,
+}
+
+/// Serializable [`Account`] with its pubkey. Pubkeys are base58 and data is base64 encoded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountSnapshot {
+    #[serde(with = "pubkey_str")]
+    pub pubkey: Pubkey,
+
+    pub lamports: u64,
+
+    #[serde(with = "base64_bytes")]
+    pub data: Vec<u8>,
+
+    #[serde(with = "pubkey_str")]
+    pub owner: Pubkey,
+
+    pub executable: bool,
+
+    pub rent_epoch: u64,
+}
+
+impl AccountSnapshot {
+    pub fn new(pubkey: Pubkey, account: &Account) -> Self {
+        Self {
+            pubkey,
+            lamports: account.lamports,
+            data: account.data.clone(),
+            owner: account.owner,
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+        }
+    }
+}
+
+impl From<&AccountSnapshot> for Account {
+    fn from(
+        AccountSnapshot {
+            lamports,
+            data,
+            owner,
+            executable,
+            rent_epoch,
+            ..
+        }: &AccountSnapshot,
+    ) -> Self {
+        Self {
+            lamports: *lamports,
+            data: data.clone(),
+            owner: *owner,
+            executable: *executable,
+            rent_epoch: *rent_epoch,
+        }
+    }
+}
+
+impl SPoolSnapshot {
+    pub fn account_map(&self) -> HashMap<Pubkey, Account> {
+        self.accounts
+            .iter()
+            .map(|acc| (acc.pubkey, acc.into()))
+            .collect()
+    }
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Records the accounts in `account_map` required to recreate this pool with
+    /// [`SPool::from_snapshot`], i.e. [`Self::get_accounts_to_update_full`] and every LST mint.
+    ///
+    /// `account_map` should be the accounts this pool was last updated with,
+    /// and `slot` the slot they were fetched at.
+    /// Accounts missing from `account_map` are omitted from the snapshot.
+    pub fn snapshot(&self, slot: u64, account_map: &HashMap<Pubkey, Account>) -> SPoolSnapshot {
+        let mut keys: HashSet<Pubkey> = self.get_accounts_to_update_full().into_iter().collect();
+        if let Ok(lst_state_list) = try_lst_state_list(&self.lst_state_list_account.data()) {
+            keys.extend(lst_state_list.iter().map(|LstState { mint, .. }| *mint));
+        }
+        let mut accounts: Vec<AccountSnapshot> = keys
+            .into_iter()
+            .filter_map(|pubkey| {
+                account_map
+                    .get(&pubkey)
+                    .map(|acc| AccountSnapshot::new(pubkey, acc))
+            })
+            .collect();
+        // deterministic output
+        accounts.sort_by_key(|acc| acc.pubkey);
+        SPoolSnapshot {
+            slot,
+            program_id: self.program_id,
+            accounts,
+        }
+    }
+}
+
+impl SPool<Account, Account> {
+    /// Recreates the pool recorded in `snapshot`, ready for quoting
+    pub fn from_snapshot(snapshot: &SPoolSnapshot) -> anyhow::Result<Self> {
+        Self::from_snapshot_with_registries(snapshot, SPoolRegistries::default())
+    }
+
+    /// Same as [`Self::from_snapshot`], but resolves the pricing program
+    /// and SOL value calculator programs with `registries` instead of the default ones
+    pub fn from_snapshot_with_registries(
+        snapshot: &SPoolSnapshot,
+        registries: SPoolRegistries<Account>,
+    ) -> anyhow::Result<Self> {
+        let account_map = snapshot.account_map();
+        let SPoolInitKeys { lst_state_list, .. } = Self::init_keys(snapshot.program_id);
+        let lst_state_list_account = account_map
+            .get(&lst_state_list)
+            .ok_or_else(|| anyhow!("LST state list {lst_state_list} not in snapshot"))?
+            .clone();
+        // LST data is discovered from the snapshot's accounts
+        // so that replays do not depend on the current LST list.
+        // LSTs that could not be discovered when the snapshot was captured
+        // don't have their accounts in it, so they remain None, same as in the captured pool
+        let (mut res, _undiscovered) = Self::from_lst_state_list_account_discovered(
+            snapshot.program_id,
+            lst_state_list_account,
+            &account_map,
+            registries,
+        )?;
+        // same as the initial 2x update of a newly initialized SPool
+        res.update_full(&account_map)?;
+        res.update_full(&account_map)?;
+        Ok(res)
+    }
+}
+
+mod pubkey_str {
+    use std::str::FromStr;
+
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use solana_sdk::pubkey::Pubkey;
+
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(pubkey)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Pubkey::from_str(&s).map_err(D::Error::custom)
+    }
+}
+
+mod base64_bytes {
+    use data_encoding::BASE64;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64.encode(data))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        BASE64.decode(s.as_bytes()).map_err(D::Error::custom)
+    }
+}
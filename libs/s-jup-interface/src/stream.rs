@@ -0,0 +1,253 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::anyhow;
+use s_controller_lib::try_lst_state_list;
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_token_lib::token_account_balance;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::SPool;
+
+/// A single account update, as produced by a geyser or websocket account subscription
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountUpdate<D> {
+    pub pubkey: Pubkey,
+    pub account: D,
+    pub slot: u64,
+}
+
+/// A source of [`AccountUpdate`]s
+pub trait AccountUpdateStream<D> {
+    /// Returns the next pending update, or `None` if there are no more pending updates
+    fn next_update(&mut self) -> Option<AccountUpdate<D>>;
+}
+
+/// [`AccountUpdateStream`] that yields updates in the order they were pushed
+#[derive(Clone, Debug)]
+pub struct InMemoryAccountUpdateStream<D> {
+    updates: VecDeque<AccountUpdate<D>>,
+}
+
+// manual impl to avoid derive(Default)'s `D: Default` bound
+impl<D> Default for InMemoryAccountUpdateStream<D> {
+    fn default() -> Self {
+        Self {
+            updates: VecDeque::new(),
+        }
+    }
+}
+
+impl<D> InMemoryAccountUpdateStream<D> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, update: AccountUpdate<D>) {
+        self.updates.push_back(update);
+    }
+
+    pub fn len(&self) -> usize {
+        self.updates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.updates.is_empty()
+    }
+}
+
+impl<D> FromIterator<AccountUpdate<D>> for InMemoryAccountUpdateStream<D> {
+    fn from_iter<T: IntoIterator<Item = AccountUpdate<D>>>(iter: T) -> Self {
+        Self {
+            updates: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<D> AccountUpdateStream<D> for InMemoryAccountUpdateStream<D> {
+    fn next_update(&mut self) -> Option<AccountUpdate<D>> {
+        self.updates.pop_front()
+    }
+}
+
+/// The parts of an [`SPool`] an [`AccountUpdate`] was applied to
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountUpdateRoute {
+    pub lst_state_list: bool,
+    pub pool_state: bool,
+    pub lp_token_mint: bool,
+    pub pricing_prog: bool,
+
+    /// Indices, in `lst_data_list`, of the LSTs whose SOL value calculator depends on the account
+    pub sol_val_calcs: Vec<usize>,
+
+    /// Indices, in `lst_data_list`, of the LSTs whose pool reserves account this is
+    pub reserves: Vec<usize>,
+
+    /// Indices, in `lst_data_list`, of the Token-2022 LSTs whose mint this is
+    pub transfer_fee_configs: Vec<usize>,
+
+    /// Indices, in `lst_data_list`, of the Token-2022 LSTs whose mint
+    /// or transfer hook ExtraAccountMetaList account this is
+    pub transfer_hooks: Vec<usize>,
+}
+
+impl AccountUpdateRoute {
+    /// The account is not one the pool depends on
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Keeps state fresh by applying individual account updates as they arrive,
+/// instead of polling every account in [`SPool::get_accounts_to_update_full`]
+pub trait AccountUpdateDriver<D> {
+    /// Applies `update` to every part of the state that depends on the updated account
+    fn apply_account_update(
+        &mut self,
+        update: AccountUpdate<D>,
+    ) -> anyhow::Result<AccountUpdateRoute>;
+
+    /// Applies every pending update of `stream` in order.
+    ///
+    /// Returns the first encountered error, but tries to apply every update eagerly
+    /// even after encountering an error
+    fn drive<St: AccountUpdateStream<D> + ?Sized>(
+        &mut self,
+        stream: &mut St,
+    ) -> anyhow::Result<()> {
+        let mut res = Ok(());
+        while let Some(update) = stream.next_update() {
+            let r = self.apply_account_update(update).map(|_| ());
+            res = res.and(r);
+        }
+        res
+    }
+}
+
+impl<D: ReadonlyAccountData + Clone> AccountUpdateDriver<D> for SPool<D, D> {
+    /// Updates are checked for staleness and recorded against [`SPool::account_slots`],
+    /// same as [`SPool::update_full_slotted`].
+    ///
+    /// Updates of accounts the pool does not depend on are ignored and return an empty route.
+    fn apply_account_update(
+        &mut self,
+        AccountUpdate {
+            pubkey,
+            account,
+            slot,
+        }: AccountUpdate<D>,
+    ) -> anyhow::Result<AccountUpdateRoute> {
+        if let Some(last_slot) = self.account_slots.get(&pubkey) {
+            if *last_slot > slot {
+                return Err(anyhow!(
+                    "Stale update of {pubkey} at slot {slot} refused, last updated at slot {last_slot}"
+                ));
+            }
+        }
+        let account_map = HashMap::from([(pubkey, account)]);
+        let mut route = AccountUpdateRoute::default();
+
+        // same order as update_full()
+        let mut res = self.route_lst_data_update(&account_map, &mut route);
+        if self.get_accounts_to_update_pricing_prog().contains(&pubkey) {
+            route.pricing_prog = true;
+            res = res.and(self.update_pricing_prog(&account_map));
+        }
+        if pubkey == self.lst_state_list_addr {
+            route.lst_state_list = true;
+            res = res.and(self.update_lst_state_list(&account_map));
+        }
+        if pubkey == self.pool_state_addr {
+            route.pool_state = true;
+            res = res.and(self.update_pool_state(&account_map));
+        }
+        if self.lp_token_mint().ok() == Some(pubkey) {
+            route.lp_token_mint = true;
+            res = res.and(self.update_lp_token_supply(&account_map));
+        }
+
+        if !route.is_empty() {
+            self.account_slots.insert(pubkey, slot);
+        }
+        res.map(|_| route)
+    }
+}
+
+impl<D: ReadonlyAccountData> SPool<D, D> {
+    /// `account_map` must only contain the single updated account
+    fn route_lst_data_update(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+        route: &mut AccountUpdateRoute,
+    ) -> anyhow::Result<()> {
+        let pubkey = match account_map.keys().next() {
+            Some(pk) => *pk,
+            None => return Ok(()),
+        };
+        {
+            let lst_state_list_acc_data = self.lst_state_list_account.data();
+            let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+            for (i, (lst_state, lst_data)) in lst_state_list
+                .iter()
+                .zip(self.lst_data_list.iter())
+                .enumerate()
+            {
+                let lst_data = match lst_data {
+                    Some(l) => l,
+                    None => continue,
+                };
+                if lst_data
+                    .sol_val_calc
+                    .get_accounts_to_update()
+                    .contains(&pubkey)
+                {
+                    route.sol_val_calcs.push(i);
+                }
+                if self.pool_reserves_account(lst_state, lst_data).ok() == Some(pubkey) {
+                    route.reserves.push(i);
+                }
+                if lst_data.token_program == spl_token_2022::ID && lst_state.mint == pubkey {
+                    route.transfer_fee_configs.push(i);
+                }
+                if lst_data.token_program == spl_token_2022::ID
+                    && (lst_state.mint == pubkey
+                        || lst_data.transfer_hook_extra_account_metas_address(lst_state.mint)
+                            == Some(pubkey))
+                {
+                    route.transfer_hooks.push(i);
+                }
+            }
+        }
+        let updated = &account_map[&pubkey];
+        #[allow(clippy::manual_try_fold)] // we dont want to short-circuit, so dont try_fold()
+        self.lst_data_list
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(i, lst_data)| lst_data.as_mut().map(|l| (i, l)))
+            .map(|(i, lst_data)| {
+                let mut r = Ok(());
+                if route.sol_val_calcs.contains(&i) {
+                    r = r.and(lst_data.sol_val_calc.update(account_map));
+                }
+                if route.reserves.contains(&i) {
+                    r = r.and(token_account_balance(updated).map_or_else(
+                        |e| Err(e.into()),
+                        |balance| {
+                            lst_data.reserves_balance = Some(balance);
+                            Ok(())
+                        },
+                    ));
+                }
+                if route.transfer_fee_configs.contains(&i) {
+                    r = r.and(lst_data.update_transfer_fee_config(pubkey, account_map));
+                }
+                if route.transfer_hooks.contains(&i) {
+                    let lst_mint = lst_data.sol_val_calc.lst_mint();
+                    r = r.and(lst_data.update_transfer_hook(lst_mint, account_map));
+                }
+                r
+            })
+            .fold(Ok(()), |res, curr_res| res.and(curr_res))
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use s_controller_lib::TransferHookAccountsData;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::{
+    extension::{
+        transfer_fee::TransferFeeConfig, transfer_hook, BaseStateWithExtensions,
+        StateWithExtensions,
+    },
+    state::Mint,
+};
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+
+use crate::LstData;
+
+/// Reads the transfer fee config of a Token-2022 mint account, if it has the extension
+pub fn try_transfer_fee_config<D: ReadonlyAccountData>(
+    mint_account: &D,
+) -> anyhow::Result<Option<TransferFeeConfig>> {
+    let data = mint_account.data();
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(mint.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Reads the transfer hook program of a Token-2022 mint account, if it has the extension
+pub fn try_transfer_hook_program_id<D: ReadonlyAccountData>(
+    mint_account: &D,
+) -> anyhow::Result<Option<Pubkey>> {
+    let data = mint_account.data();
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(transfer_hook::get_program_id(&mint))
+}
+
+/// Returns the max transfer fee charged for transferring `amount` under either
+/// the older or newer transfer fee of `config`.
+///
+/// Using the max removes the need to know the current epoch
+/// at the cost of overestimating fees while the older and newer fees differ
+pub fn max_transfer_fee(config: &TransferFeeConfig, amount: u64) -> anyhow::Result<u64> {
+    [&config.older_transfer_fee, &config.newer_transfer_fee]
+        .into_iter()
+        .map(|fee| {
+            fee.calculate_fee(amount)
+                .ok_or_else(|| anyhow!("Transfer fee calculation overflow"))
+        })
+        .try_fold(0, |max, fee| fee.map(|fee| max.max(fee)))
+}
+
+impl<D> LstData<D> {
+    /// No-op for Tokenkeg LSTs or if `lst_mint` is not in `account_map`
+    pub fn update_transfer_fee_config<A: ReadonlyAccountData>(
+        &mut self,
+        lst_mint: Pubkey,
+        account_map: &HashMap<Pubkey, A>,
+    ) -> anyhow::Result<()> {
+        if self.token_program != spl_token_2022::ID {
+            return Ok(());
+        }
+        if let Some(mint_account) = account_map.get(&lst_mint) {
+            self.transfer_fee_config = try_transfer_fee_config(mint_account)?;
+        }
+        Ok(())
+    }
+
+    /// Address of the transfer hook program's ExtraAccountMetaList account for `lst_mint`.
+    /// None for LSTs without the transfer hook extension
+    pub fn transfer_hook_extra_account_metas_address(&self, lst_mint: Pubkey) -> Option<Pubkey> {
+        self.transfer_hook_program_id
+            .map(|program_id| get_extra_account_metas_address(&lst_mint, &program_id))
+    }
+
+    /// Updates the transfer hook program from `lst_mint`
+    /// and its ExtraAccountMetaList account, if they are in `account_map`.
+    ///
+    /// No-op for Tokenkeg LSTs
+    pub fn update_transfer_hook<A: ReadonlyAccountData>(
+        &mut self,
+        lst_mint: Pubkey,
+        account_map: &HashMap<Pubkey, A>,
+    ) -> anyhow::Result<()> {
+        if self.token_program != spl_token_2022::ID {
+            return Ok(());
+        }
+        if let Some(mint_account) = account_map.get(&lst_mint) {
+            let transfer_hook_program_id = try_transfer_hook_program_id(mint_account)?;
+            if transfer_hook_program_id != self.transfer_hook_program_id {
+                self.transfer_hook_extra_account_metas = None;
+            }
+            self.transfer_hook_program_id = transfer_hook_program_id;
+        }
+        if let Some(extra_account_metas) = self
+            .transfer_hook_extra_account_metas_address(lst_mint)
+            .and_then(|addr| account_map.get(&addr))
+        {
+            self.transfer_hook_extra_account_metas = Some(extra_account_metas.data().to_vec());
+        }
+        Ok(())
+    }
+
+    /// Returns the data required to resolve the accounts required by this LST's transfer hook
+    /// for [`s_controller_lib::resolve_transfer_hook_accounts`].
+    /// None for LSTs without the transfer hook extension
+    pub fn transfer_hook_accounts_data(
+        &self,
+        lst_mint: Pubkey,
+    ) -> anyhow::Result<Option<TransferHookAccountsData<'_>>> {
+        let program_id = match self.transfer_hook_program_id {
+            Some(p) => p,
+            None => return Ok(None),
+        };
+        let extra_account_metas = self
+            .transfer_hook_extra_account_metas
+            .as_deref()
+            .ok_or_else(|| anyhow!("Transfer hook accounts of LST {lst_mint} not fetched"))?;
+        Ok(Some(TransferHookAccountsData {
+            lst_mint,
+            program_id,
+            extra_account_metas,
+        }))
+    }
+
+    /// Returns the transfer fee charged for transferring `amount` of this LST,
+    /// which is 0 for LSTs without the transfer fee extension. See [`max_transfer_fee`]
+    pub fn transfer_fee(&self, amount: u64) -> anyhow::Result<u64> {
+        match self.transfer_fee_config.as_ref() {
+            Some(config) => max_transfer_fee(config, amount),
+            None => Ok(0),
+        }
+    }
+
+    /// Returns the amount the recipient receives when `amount` of this LST is transferred,
+    /// which is `amount` minus transfer fees for Token-2022 LSTs with the transfer fee extension
+    pub fn amount_after_transfer_fee(&self, amount: u64) -> anyhow::Result<u64> {
+        Ok(amount.saturating_sub(self.transfer_fee(amount)?))
+    }
+}
@@ -0,0 +1,298 @@
+use anyhow::anyhow;
+use jupiter_amm_interface::{Quote, QuoteParams, SwapMode, SwapParams};
+use s_controller_interface::Deadline;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::{
+    address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
+    compute_budget::ComputeBudgetInstruction,
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, VersionedMessage},
+    pubkey::Pubkey,
+    system_instruction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token::native_mint;
+
+use crate::SPool;
+
+/// Lookup table of accounts commonly used by S controller instructions
+pub mod srlut {
+    solana_program::declare_id!("KtrvWWkPkhSWM9VMqafZhgnTuozQiHzrBDT8oPcMj3T");
+}
+
+pub const BPS_DENOM: u16 = 10_000;
+
+/// Deserializes a fetched address lookup table account, e.g. the [`srlut`]
+pub fn lookup_table_account<D: ReadonlyAccountData>(
+    key: Pubkey,
+    account: &D,
+) -> anyhow::Result<AddressLookupTableAccount> {
+    let data = account.data();
+    let lut = AddressLookupTable::deserialize(&data)?;
+    Ok(AddressLookupTableAccount {
+        key,
+        addresses: lut.addresses.to_vec(),
+    })
+}
+
+/// Returns the minimum amount out of `amount` after slippage of `slippage_bps`, rounded down
+pub fn min_out_with_slippage(amount: u64, slippage_bps: u16) -> u64 {
+    let remaining_bps = BPS_DENOM.saturating_sub(slippage_bps);
+    // cannot overflow: remaining_bps <= BPS_DENOM
+    (u128::from(amount) * u128::from(remaining_bps) / u128::from(BPS_DENOM)) as u64
+}
+
+/// Returns the maximum amount in of `amount` after slippage of `slippage_bps`, rounded up.
+/// Saturates at u64::MAX
+pub fn max_in_with_slippage(amount: u64, slippage_bps: u16) -> u64 {
+    let denom = u128::from(BPS_DENOM);
+    let num = u128::from(amount) * (denom + u128::from(slippage_bps));
+    u64::try_from((num + denom - 1) / denom).unwrap_or(u64::MAX)
+}
+
+/// Hook for estimating the compute unit limit of a transaction with instructions `ixs`,
+/// e.g. by simulating them against an RPC.
+///
+/// Closures of the same signature implement this trait.
+pub trait ComputeUnitEstimator {
+    fn estimate_compute_units(
+        &self,
+        payer: &Pubkey,
+        ixs: &[Instruction],
+        luts: &[AddressLookupTableAccount],
+    ) -> anyhow::Result<u32>;
+}
+
+impl<F: Fn(&Pubkey, &[Instruction], &[AddressLookupTableAccount]) -> anyhow::Result<u32>>
+    ComputeUnitEstimator for F
+{
+    fn estimate_compute_units(
+        &self,
+        payer: &Pubkey,
+        ixs: &[Instruction],
+        luts: &[AddressLookupTableAccount],
+    ) -> anyhow::Result<u32> {
+        self(payer, ixs, luts)
+    }
+}
+
+/// [`ComputeUnitEstimator`] that always returns the same compute unit limit
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedComputeUnits(pub u32);
+
+impl ComputeUnitEstimator for FixedComputeUnits {
+    fn estimate_compute_units(
+        &self,
+        _payer: &Pubkey,
+        _ixs: &[Instruction],
+        _luts: &[AddressLookupTableAccount],
+    ) -> anyhow::Result<u32> {
+        Ok(self.0)
+    }
+}
+
+pub struct SwapTxComputeBudget<'a> {
+    pub estimator: &'a dyn ComputeUnitEstimator,
+
+    /// Priority fee. No compute unit price instruction is added if 0
+    pub micro_lamports_per_cu: u64,
+}
+
+pub struct SwapTxArgs<'a> {
+    /// Owner of the input and output token accounts, and signer of the swap
+    pub user: Pubkey,
+
+    /// Transaction fee payer and rent payer of created ATAs
+    pub payer: Pubkey,
+
+    pub quote_params: &'a QuoteParams,
+
+    /// Quote of `quote_params`, e.g. from [`SPool::quote_full`]
+    pub quote: &'a Quote,
+
+    /// Slippage applied to `quote` for the instruction's min amount out,
+    /// or max amount in for `SwapMode::ExactOut` swaps between LSTs
+    pub slippage_bps: u16,
+
+    /// If the input or output mint is the wSOL LST, wrap the user's native SOL into
+    /// or unwrap it out of the user's wSOL ATA. The wSOL ATA is closed at the end of the transaction
+    pub wrap_unwrap_sol: bool,
+
+    /// If set, the swap instruction is the *WithDeadline variant that fails
+    /// if the transaction lands after the deadline's expiry slot or timestamp
+    pub deadline: Option<Deadline>,
+
+    /// No compute budget instructions are added if `None`
+    pub compute_budget: Option<SwapTxComputeBudget<'a>>,
+
+    /// Lookup tables to compile the message with, e.g. the [`srlut`]
+    pub luts: &'a [AddressLookupTableAccount],
+
+    pub recent_blockhash: Hash,
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Returns the (in_amount, out_amount) of the [`SwapParams`] for executing `quote`
+    /// with `slippage_bps`, as required by [`Self::swap_ix`]
+    pub fn swap_params_amounts(
+        &self,
+        QuoteParams {
+            input_mint,
+            output_mint,
+            swap_mode,
+            ..
+        }: &QuoteParams,
+        Quote {
+            in_amount,
+            out_amount,
+            ..
+        }: &Quote,
+        slippage_bps: u16,
+    ) -> anyhow::Result<(u64, u64)> {
+        let lp_mint = self.lp_token_mint()?;
+        let is_liquidity = *input_mint == lp_mint || *output_mint == lp_mint;
+        Ok(match (swap_mode, is_liquidity) {
+            (SwapMode::ExactOut, false) => {
+                (max_in_with_slippage(*in_amount, slippage_bps), *out_amount)
+            }
+            // add/remove liquidity instructions always take (exact amount in, min amount out)
+            _ => (*in_amount, min_out_with_slippage(*out_amount, slippage_bps)),
+        })
+    }
+
+    /// Returns the token program of `mint`, which must be either the LP token mint or an LST of the pool
+    pub fn mint_token_program(&self, mint: Pubkey) -> anyhow::Result<Pubkey> {
+        if mint == self.lp_token_mint()? {
+            return Ok(spl_token::ID);
+        }
+        let (_, lst_data) = self.find_ready_lst(mint)?;
+        Ok(lst_data.token_program)
+    }
+
+    /// Returns a ready-to-sign message that swaps with the user's ATAs, consisting of:
+    /// - compute budget instructions, if any
+    /// - wrapping of native SOL into the user's wSOL ATA, if input mint is wSOL
+    /// - idempotent creation of the user's output mint ATA
+    /// - the swap instruction returned by [`Self::swap_ix`]
+    /// - closing of the user's wSOL ATA, if either mint is wSOL
+    pub fn swap_tx_message(
+        &self,
+        SwapTxArgs {
+            user,
+            payer,
+            quote_params,
+            quote,
+            slippage_bps,
+            wrap_unwrap_sol,
+            deadline,
+            compute_budget,
+            luts,
+            recent_blockhash,
+        }: SwapTxArgs,
+    ) -> anyhow::Result<VersionedMessage> {
+        if quote.not_enough_liquidity {
+            return Err(anyhow!("Not enough liquidity for quote"));
+        }
+        let QuoteParams {
+            input_mint,
+            output_mint,
+            swap_mode,
+            ..
+        } = quote_params;
+        let (in_amount, out_amount) =
+            self.swap_params_amounts(quote_params, quote, slippage_bps)?;
+        let source_token_account = get_associated_token_address_with_program_id(
+            &user,
+            input_mint,
+            &self.mint_token_program(*input_mint)?,
+        );
+        let output_token_program = self.mint_token_program(*output_mint)?;
+        let destination_token_account =
+            get_associated_token_address_with_program_id(&user, output_mint, &output_token_program);
+
+        let mut ixs = Vec::new();
+        if wrap_unwrap_sol && *input_mint == native_mint::ID {
+            ixs.push(create_associated_token_account_idempotent(
+                &payer,
+                &user,
+                &native_mint::ID,
+                &spl_token::ID,
+            ));
+            ixs.push(system_instruction::transfer(
+                &user,
+                &source_token_account,
+                in_amount,
+            ));
+            ixs.push(spl_token::instruction::sync_native(
+                &spl_token::ID,
+                &source_token_account,
+            )?);
+        }
+        ixs.push(create_associated_token_account_idempotent(
+            &payer,
+            &user,
+            output_mint,
+            &output_token_program,
+        ));
+        let swap_params = SwapParams {
+            in_amount,
+            out_amount,
+            source_mint: *input_mint,
+            destination_mint: *output_mint,
+            source_token_account,
+            destination_token_account,
+            token_transfer_authority: user,
+            // dont cares
+            open_order_address: None,
+            quote_mint_to_referrer: None,
+            jupiter_program_id: &Pubkey::default(),
+        };
+        ixs.push(match deadline.as_ref() {
+            Some(deadline) => self.swap_ix_with_deadline(&swap_params, *swap_mode, deadline)?,
+            None => self.swap_ix(&swap_params, *swap_mode)?,
+        });
+        if wrap_unwrap_sol {
+            if let Some(wsol_ata) = [
+                (input_mint, source_token_account),
+                (output_mint, destination_token_account),
+            ]
+            .into_iter()
+            .find_map(|(mint, ata)| (*mint == native_mint::ID).then_some(ata))
+            {
+                ixs.push(spl_token::instruction::close_account(
+                    &spl_token::ID,
+                    &wsol_ata,
+                    &user,
+                    &user,
+                    &[],
+                )?);
+            }
+        }
+
+        if let Some(SwapTxComputeBudget {
+            estimator,
+            micro_lamports_per_cu,
+        }) = compute_budget
+        {
+            let cu_limit = estimator.estimate_compute_units(&payer, &ixs, luts)?;
+            let mut cb_ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(cu_limit)];
+            if micro_lamports_per_cu > 0 {
+                cb_ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                    micro_lamports_per_cu,
+                ));
+            }
+            ixs.splice(0..0, cb_ixs);
+        }
+
+        Ok(VersionedMessage::V0(v0::Message::try_compile(
+            &payer,
+            &ixs,
+            luts,
+            recent_blockhash,
+        )?))
+    }
+}
@@ -2,8 +2,8 @@ use std::collections::HashMap;
 
 use s_controller_interface::LstState;
 use s_controller_lib::{try_lst_state_list, try_pool_state};
-use s_pricing_prog_aggregate::MutablePricingProg;
-use s_sol_val_calc_prog_aggregate::{LstSolValCalc, MutableLstSolValCalc};
+use s_pricing_prog_aggregate::DynPricingProg;
+use s_sol_val_calc_prog_aggregate::{DynLstSolValCalc, LstSolValCalc};
 use sanctum_token_lib::{mint_supply, token_account_balance};
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::pubkey::Pubkey;
@@ -60,9 +60,9 @@ impl<S, L> SPool<S, L> {
         &self,
         lst_mints: I,
     ) -> Vec<Pubkey> {
-        self.pricing_prog
-            .as_ref()
-            .map_or_else(Vec::new, |pp| pp.get_accounts_to_update_for_lsts(lst_mints))
+        self.pricing_prog.as_ref().map_or_else(Vec::new, |pp| {
+            pp.get_accounts_to_update_for_lsts(&lst_mints.collect::<Vec<_>>())
+        })
     }
 
     pub fn get_accounts_to_update_pricing_prog_for_liquidity(&self) -> Vec<Pubkey> {
@@ -71,10 +71,7 @@ impl<S, L> SPool<S, L> {
             .map_or_else(Vec::new, |pp| pp.get_accounts_to_update_for_liquidity())
     }
 
-    pub fn update_pricing_prog<D: ReadonlyAccountData>(
-        &mut self,
-        account_map: &HashMap<Pubkey, D>,
-    ) -> anyhow::Result<()> {
+    pub fn update_pricing_prog(&mut self, account_map: &HashMap<Pubkey, S>) -> anyhow::Result<()> {
         if let Some(pp) = self.pricing_prog.as_mut() {
             pp.update(account_map)?;
         }
@@ -86,7 +83,7 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
     fn lst_accounts_to_update(
         &self,
         lst_state: &LstState,
-        lst_data: &Option<LstData>,
+        lst_data: &Option<LstData<S>>,
     ) -> Vec<Pubkey> {
         let lst_data = match lst_data.as_ref() {
             Some(l) => l,
@@ -96,6 +93,13 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
         if let Ok(ata) = self.pool_reserves_account(lst_state, lst_data) {
             res.push(ata);
         }
+        // for transfer fee config and transfer hook
+        if lst_data.token_program == spl_token_2022::ID {
+            res.push(lst_state.mint);
+        }
+        if let Some(addr) = lst_data.transfer_hook_extra_account_metas_address(lst_state.mint) {
+            res.push(addr);
+        }
         res
     }
 
@@ -113,7 +117,9 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
     }
 
     /// Used to only fetch certain accounts for partial updates for specific LSTs
-    pub fn get_accounts_to_update_lsts_filtered<F: FnMut(&LstState, &Option<LstData>) -> bool>(
+    pub fn get_accounts_to_update_lsts_filtered<
+        F: FnMut(&LstState, &Option<LstData<S>>) -> bool,
+    >(
         &self,
         mut filter_pred: F,
     ) -> Vec<Pubkey> {
@@ -135,11 +141,10 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
             .flatten()
             .collect()
     }
+}
 
-    pub fn update_lst_data_list<D: ReadonlyAccountData>(
-        &mut self,
-        account_map: &HashMap<Pubkey, D>,
-    ) -> anyhow::Result<()> {
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    pub fn update_lst_data_list(&mut self, account_map: &HashMap<Pubkey, S>) -> anyhow::Result<()> {
         // use raw indices to avoid lifetime errs from borrowing immut field (self.lst_state_list)
         // while borrowing mut field (self.lst_data_list)
         #[allow(clippy::manual_try_fold)] // we dont want to short-circuit, so dont try_fold()
@@ -151,12 +156,17 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
                 };
                 let lst_state_list_acc_data = self.lst_state_list_account.data();
                 let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+                let lst_mint = lst_state_list[i].mint;
                 let ata_res = self.pool_reserves_account(&lst_state_list[i], ld);
                 let ld = match &mut self.lst_data_list[i] {
                     Some(l) => l,
                     None => return Ok(()),
                 };
-                let r = ld.sol_val_calc.update(account_map);
+                let r = ld
+                    .sol_val_calc
+                    .update(account_map)
+                    .and(ld.update_transfer_fee_config(lst_mint, account_map))
+                    .and(ld.update_transfer_hook(lst_mint, account_map));
                 r.and(ata_res.map_or_else(
                     |e| Err(e.into()),
                     |ata| {
@@ -182,9 +192,10 @@ impl<S, L: ReadonlyAccountData + Clone> SPool<S, L> {
         // simple model for diffs:
         // - if new and old list differs in mints, then try to find the mismatches and replace them
         // - if sol val calc program changed, then just invalidate to None. Otherwise we would need a
-        //   SanctumLstList to reinitialize the KnownLstSolValCalc
+        //   SanctumLstList to reinitialize the LstSolValCalc
         // - if list was extended, the new entries will just be None and we cant handle it. Otherwise we would need a
-        //   SanctumLstList to initialize the KnownLstSolValCalc
+        //   SanctumLstList to initialize the LstSolValCalc. Use discover_missing_lst_data() to initialize them
+        //   from on-chain accounts instead
         let lst_state_list_acc_data = self.lst_state_list_account.data();
         let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
         let new_lst_state_list_account_data = new_lst_state_list_account.data();
@@ -299,12 +310,16 @@ impl<S: ReadonlyAccountData + Clone, L: ReadonlyAccountData> SPool<S, L> {
                 if should_reinitialize_pricing_program {
                     // None if unable to initialize new_pricing_prog, with error captured
                     // for return later
-                    let new_pricing_prog = try_pricing_prog(new_pool_state, lst_state_list)
-                        .map(|mut pp| {
-                            r = pp.update(account_map);
-                            pp
-                        })
-                        .ok();
+                    let new_pricing_prog = try_pricing_prog(
+                        &self.registries.pricing_progs,
+                        new_pool_state,
+                        lst_state_list,
+                    )
+                    .map(|mut pp| {
+                        r = pp.update(account_map);
+                        pp
+                    })
+                    .ok();
                     self.pricing_prog = new_pricing_prog;
                 }
                 self.pool_state_account = Some(new_pool_state_acc.clone());
@@ -1,70 +1,109 @@
+use anyhow::anyhow;
 use s_controller_interface::{LstState, PoolState};
-use s_pricing_prog_aggregate::{KnownPricingProg, MutablePricingProg};
+use s_pricing_prog_aggregate::{DynPricingProg, PricingProgRegistry};
 use s_sol_val_calc_prog_aggregate::{
-    KnownLstSolValCalc, LidoLstSolValCalc, LstSolValCalc, MarinadeLstSolValCalc,
-    SanctumSplLstSolValCalc, SanctumSplMultiLstSolValCalc, SplLstSolValCalc,
-    SplLstSolValCalcInitKeys, WsolLstSolValCalc,
+    discover_lst_sol_val_calc_init_args, AccountFetcher, LstSolValCalc, LstSolValCalcInitArgs,
+    LstSolValCalcRegistry,
 };
 use sanctum_lst_list::{PoolInfo, SanctumLst, SplPoolAccounts};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::ReadonlyAccountOwner;
 
-use crate::LstData;
+use crate::{try_transfer_fee_config, try_transfer_hook_program_id, LstData};
 
-pub fn try_pricing_prog(
+pub fn try_pricing_prog<D>(
+    registry: &PricingProgRegistry<D>,
     pool_state: &PoolState,
     lst_state_list: &[LstState],
-) -> anyhow::Result<KnownPricingProg> {
-    Ok(KnownPricingProg::try_new(
-        pool_state.pricing_program,
-        lst_state_list.iter().map(|LstState { mint, .. }| *mint),
-    )?)
+) -> anyhow::Result<Box<dyn DynPricingProg<D>>> {
+    let mints: Vec<Pubkey> = lst_state_list
+        .iter()
+        .map(|LstState { mint, .. }| *mint)
+        .collect();
+    registry.try_new_pricing_prog(pool_state.pricing_program, &mints)
 }
 
-pub fn try_lst_data(
+pub fn try_lst_data<D>(
+    registry: &LstSolValCalcRegistry<D>,
     lst_list: &[SanctumLst],
     LstState {
         mint,
         sol_value_calculator,
         ..
     }: &LstState,
-) -> Option<LstData> {
+) -> Option<LstData<D>> {
     let SanctumLst {
         pool,
         token_program,
         ..
     } = lst_list.iter().find(|s| s.mint == *mint)?;
-    let calc = match pool {
-        PoolInfo::Lido => KnownLstSolValCalc::Lido(LidoLstSolValCalc::default()),
-        PoolInfo::Marinade => KnownLstSolValCalc::Marinade(MarinadeLstSolValCalc::default()),
-        PoolInfo::ReservePool => KnownLstSolValCalc::Wsol(WsolLstSolValCalc),
-        PoolInfo::SanctumSpl(SplPoolAccounts { pool, .. }) => KnownLstSolValCalc::SanctumSpl(
-            SanctumSplLstSolValCalc::from_keys(SplLstSolValCalcInitKeys {
-                lst_mint: *mint,
-                stake_pool_addr: *pool,
-            }),
-        ),
-        PoolInfo::Spl(SplPoolAccounts { pool, .. }) => {
-            KnownLstSolValCalc::Spl(SplLstSolValCalc::from_keys(SplLstSolValCalcInitKeys {
-                lst_mint: *mint,
-                stake_pool_addr: *pool,
-            }))
-        }
-        PoolInfo::SanctumSplMulti(SplPoolAccounts { pool, .. }) => {
-            KnownLstSolValCalc::SanctumSplMulti(SanctumSplMultiLstSolValCalc::from_keys(
-                SplLstSolValCalcInitKeys {
-                    lst_mint: *mint,
-                    stake_pool_addr: *pool,
-                },
-            ))
-        }
-        PoolInfo::SPool(_) => None?,
+    let stake_pool_addr = match pool {
+        PoolInfo::Spl(SplPoolAccounts { pool, .. })
+        | PoolInfo::SanctumSpl(SplPoolAccounts { pool, .. })
+        | PoolInfo::SanctumSplMulti(SplPoolAccounts { pool, .. }) => Some(*pool),
+        _ => None,
     };
-    if *sol_value_calculator != calc.sol_value_calculator_program_id() {
+    let calc = registry
+        .try_new_lst_sol_val_calc(
+            *sol_value_calculator,
+            LstSolValCalcInitArgs {
+                lst_mint: *mint,
+                stake_pool_addr,
+            },
+        )
+        .ok()?;
+    if calc.lst_mint() != *mint {
         None
     } else {
         Some(LstData {
             sol_val_calc: calc,
             reserves_balance: None,
             token_program: *token_program,
+            // fetched on first update for Token-2022 LSTs
+            transfer_fee_config: None,
+            transfer_hook_program_id: None,
+            transfer_hook_extra_account_metas: None,
         })
     }
 }
+
+/// Same as [`try_lst_data`], but discovers the LST's data from on-chain accounts
+/// fetched by `fetcher` instead of from a static LST list
+pub fn try_lst_data_discovered<D, F: AccountFetcher>(
+    registry: &LstSolValCalcRegistry<D>,
+    fetcher: &F,
+    LstState {
+        mint,
+        sol_value_calculator,
+        ..
+    }: &LstState,
+) -> anyhow::Result<LstData<D>> {
+    let args = discover_lst_sol_val_calc_init_args(fetcher, *sol_value_calculator, *mint)?;
+    let sol_val_calc = registry.try_new_lst_sol_val_calc(*sol_value_calculator, args)?;
+    if sol_val_calc.lst_mint() != *mint {
+        return Err(anyhow!(
+            "SOL value calculator {sol_value_calculator} does not support LST {mint}"
+        ));
+    }
+    let mint_account = fetcher
+        .fetch_account(mint)?
+        .ok_or_else(|| anyhow!("LST mint {mint} not found"))?;
+    let token_program = *mint_account.owner();
+    let (transfer_fee_config, transfer_hook_program_id) = if token_program == spl_token_2022::ID {
+        (
+            try_transfer_fee_config(&mint_account)?,
+            try_transfer_hook_program_id(&mint_account)?,
+        )
+    } else {
+        (None, None)
+    };
+    Ok(LstData {
+        sol_val_calc,
+        reserves_balance: None,
+        token_program,
+        transfer_fee_config,
+        transfer_hook_program_id,
+        // fetched on first update
+        transfer_hook_extra_account_metas: None,
+    })
+}
@@ -1,23 +1,25 @@
 use flat_fee_test_utils::MockFeeAccountArgs;
-use jupiter_amm_interface::{QuoteParams, SwapMode};
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
 use marinade_keys::msol;
 use s_controller_test_utils::{
     jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
 };
+use s_jup_interface::SPoolJup;
 use sanctum_solana_test_utils::ExtendedProgramTest;
 use sanctum_token_lib::MintWithTokenProgram;
-use solana_program_test::ProgramTestContext;
+use solana_program_test::{BanksClient, ProgramTestContext};
 use solana_sdk::{clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer};
 use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
 
 use crate::{assert_quote_swap_eq, fully_init_amm, MiscProgramTest};
 
-#[tokio::test]
-async fn add_liquidity_jito_basic() {
-    const AMT: u64 = 1_000_000_000;
+const AMT: u64 = 1_000_000_000;
 
-    let wallet = Keypair::new();
-    let lp_token_mint = Pubkey::new_unique();
+/// `wallet` starts with `4 * AMT` jitoSOL (enough for exact out) and an empty LP token ATA
+async fn jito_marinade_flat_fee_add_liquidity_setup(
+    wallet: &Keypair,
+    lp_token_mint: Pubkey,
+) -> (BanksClient, SPoolJup) {
     let pt = jito_marinade_flat_fee_program_test(
         JitoMarinadeProgramTestArgs {
             jitosol_sol_value: 10_000_000_000,
@@ -32,6 +34,10 @@ async fn add_liquidity_jito_basic() {
         flat_fee_interface::ProgramState {
             manager: Pubkey::default(),
             lp_withdrawal_fee_bps: 0,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         [
             MockFeeAccountArgs {
@@ -58,7 +64,7 @@ async fn add_liquidity_jito_basic() {
             pubkey: jitosol::ID,
             token_program: spl_token::ID,
         },
-        AMT,
+        4 * AMT,
     )
     .add_ata(
         wallet.pubkey(),
@@ -79,6 +85,14 @@ async fn add_liquidity_jito_basic() {
     } = ctx;
 
     let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    (bc, s)
+}
+
+#[tokio::test]
+async fn add_liquidity_jito_basic() {
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let (mut bc, s) = jito_marinade_flat_fee_add_liquidity_setup(&wallet, lp_token_mint).await;
     assert_quote_swap_eq(
         &mut bc,
         &s,
@@ -92,3 +106,42 @@ async fn add_liquidity_jito_basic() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn add_liquidity_jito_min_in() {
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let (mut bc, s) = jito_marinade_flat_fee_add_liquidity_setup(&wallet, lp_token_mint).await;
+
+    let exact_out = QuoteParams {
+        amount: AMT,
+        input_mint: jitosol::ID,
+        output_mint: lp_token_mint,
+        swap_mode: SwapMode::ExactOut,
+    };
+    // no ExactOut add liquidity instruction
+    assert!(s.quote(&exact_out).is_err());
+
+    let quote = s
+        .quote_add_liquidity_min_in_detailed(&exact_out)
+        .unwrap()
+        .quote;
+    assert!(quote.out_amount >= AMT);
+    let exact_in = QuoteParams {
+        amount: quote.in_amount,
+        swap_mode: SwapMode::ExactIn,
+        ..exact_out
+    };
+    assert_eq!(s.quote(&exact_in).unwrap().out_amount, quote.out_amount);
+    // in_amount should be the min required
+    let one_less = s
+        .quote(&QuoteParams {
+            amount: quote.in_amount - 1,
+            ..exact_in
+        })
+        .unwrap();
+    assert!(one_less.out_amount < AMT);
+
+    // executes the ExactIn instruction with the quoted in_amount
+    assert_quote_swap_eq(&mut bc, &s, &wallet, &exact_in).await;
+}
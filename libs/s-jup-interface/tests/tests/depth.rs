@@ -0,0 +1,177 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use rust_decimal::Decimal;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::SPoolJup;
+use sanctum_solana_test_utils::ExtendedProgramTest;
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_sdk::{clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{assert_quote_swap_eq, fully_init_amm, MiscProgramTest};
+
+/// More than enough jitoSOL to drain the mSOL reserves
+const WALLET_JITOSOL: u64 = 100_000_000_000;
+
+async fn jito_marinade_flat_fee_setup(wallet: &Keypair) -> (BanksClient, SPoolJup) {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 0,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 0,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program()
+    .add_system_account(wallet.pubkey(), 1_000_000_000)
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        WALLET_JITOSOL,
+    )
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        0,
+    );
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    (bc, s)
+}
+
+fn exact_in(amount: u64) -> QuoteParams {
+    QuoteParams {
+        amount,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    }
+}
+
+#[tokio::test]
+async fn spot_rate_matches_small_quote() {
+    let wallet = Keypair::new();
+    let (_bc, s) = jito_marinade_flat_fee_setup(&wallet).await;
+
+    let spot_rate = s.spot_rate(jitosol::ID, msol::ID).unwrap();
+    assert!(spot_rate > Decimal::ZERO);
+
+    let quote = s.quote(&exact_in(1_000_000_000)).unwrap();
+    let quote_rate = Decimal::from(quote.out_amount) / Decimal::from(quote.in_amount);
+    // flat fee pricing is linear, only rounding should differ
+    assert!((quote_rate - spot_rate).abs() < Decimal::new(1, 6));
+}
+
+#[tokio::test]
+async fn max_in_amount_is_liquidity_boundary() {
+    let wallet = Keypair::new();
+    let (mut bc, s) = jito_marinade_flat_fee_setup(&wallet).await;
+
+    let max_in_amount = s.max_in_amount(jitosol::ID, msol::ID).unwrap();
+    assert!(max_in_amount > 0);
+    assert!(max_in_amount < WALLET_JITOSOL);
+    assert!(
+        s.quote(&exact_in(max_in_amount + 1))
+            .unwrap()
+            .not_enough_liquidity
+    );
+    // asserts !not_enough_liquidity and that the swap succeeds on-chain
+    assert_quote_swap_eq(&mut bc, &s, &wallet, &exact_in(max_in_amount)).await;
+}
+
+#[tokio::test]
+async fn price_impact_curve_flat_fee() {
+    let wallet = Keypair::new();
+    let (_bc, s) = jito_marinade_flat_fee_setup(&wallet).await;
+
+    let max_in_amount = s.max_in_amount(jitosol::ID, msol::ID).unwrap();
+    let in_amounts = [
+        1_000_000_000,
+        max_in_amount / 4,
+        max_in_amount / 2,
+        max_in_amount,
+        max_in_amount + 1,
+    ];
+    let curve = s
+        .price_impact_curve(jitosol::ID, msol::ID, &in_amounts)
+        .unwrap();
+    assert_eq!(curve.len(), in_amounts.len());
+    for (point, in_amount) in curve.iter().zip(in_amounts) {
+        assert_eq!(point.in_amount, in_amount);
+        // flat fee pricing has no price impact apart from rounding
+        assert!(point.price_impact.abs() < Decimal::new(1, 6));
+        assert_eq!(point.not_enough_liquidity, in_amount > max_in_amount);
+    }
+}
+
+#[tokio::test]
+async fn max_in_amount_treats_overflow_as_exceeding_reserves() {
+    let wallet = Keypair::new();
+    let (_bc, mut s) = jito_marinade_flat_fee_setup(&wallet).await;
+    let msol_index = s
+        .get_reserve_mints_full()
+        .iter()
+        .position(|m| *m == msol::ID)
+        .unwrap();
+    // large enough that the pool's total SOL value overflows
+    // before enough jitoSOL to drain the reserves is quoted
+    s.lst_data_list[msol_index]
+        .as_mut()
+        .unwrap()
+        .reserves_balance = Some(u64::MAX / 2);
+
+    let max_in_amount = s.max_in_amount(jitosol::ID, msol::ID).unwrap();
+    assert!(max_in_amount > 0);
+    assert!(
+        !s.quote(&exact_in(max_in_amount))
+            .unwrap()
+            .not_enough_liquidity
+    );
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_test_utils::{jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs};
+use s_jup_interface::{SPoolInitKeys, SPoolJup, SPoolRegistries};
+use s_sol_val_calc_prog_aggregate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_sdk::{account::Account, clock::Clock, pubkey::Pubkey};
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, UpdatingSPoolJup};
+
+async fn jito_marinade_bc() -> BanksClient {
+    let pt = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 10_000_000_000,
+        msol_sol_value: 10_000_000_000,
+        jitosol_reserves: 10_000_000_000,
+        msol_reserves: 10_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 10_000_000_000,
+    });
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext { banks_client, .. } = ctx;
+    banks_client
+}
+
+async fn fetch_accounts(bc: &mut BanksClient, pubkeys: &[Pubkey]) -> HashMap<Pubkey, Account> {
+    let mut res = HashMap::new();
+    for pk in pubkeys {
+        res.insert(*pk, bc.get_account_unwrapped(*pk).await);
+    }
+    res
+}
+
+async fn discovered_amm_with_errs(
+    bc: &mut BanksClient,
+    fetcher: &HashMap<Pubkey, Account>,
+) -> (SPoolJup, Vec<(Pubkey, anyhow::Error)>) {
+    let program_id = s_controller_lib::program::ID;
+    let SPoolInitKeys { lst_state_list, .. } = SPoolJup::init_keys(program_id);
+    let lst_state_list_acc = bc.get_account_unwrapped(lst_state_list).await;
+    SPoolJup::from_lst_state_list_account_discovered(
+        program_id,
+        lst_state_list_acc,
+        fetcher,
+        SPoolRegistries::default(),
+    )
+    .unwrap()
+}
+
+async fn discovered_amm(bc: &mut BanksClient, fetcher: &HashMap<Pubkey, Account>) -> SPoolJup {
+    let (s, errs) = discovered_amm_with_errs(bc, fetcher).await;
+    assert!(errs.is_empty(), "{errs:?}");
+    s
+}
+
+#[tokio::test]
+async fn discover_spl_stake_pool() {
+    let mut bc = jito_marinade_bc().await;
+    let fetcher = fetch_accounts(&mut bc, &[jito_stake_pool::ID, msol::ID]).await;
+
+    let calc = KnownLstSolValCalc::discover(&fetcher, spl_calculator_lib::program::ID, jitosol::ID)
+        .unwrap();
+    let KnownLstSolValCalc::Spl(spl) = calc else {
+        panic!("expected Spl, got {calc:?}");
+    };
+    assert_eq!(spl.stake_pool_addr, jito_stake_pool::ID);
+    assert_eq!(spl.lst_mint(), jitosol::ID);
+
+    let err = KnownLstSolValCalc::discover(&fetcher, spl_calculator_lib::program::ID, msol::ID)
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<LstSolValCalcErr>(),
+        Some(LstSolValCalcErr::StakePoolNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn discovered_matches_sanctum_lst_list() {
+    let mut bc = jito_marinade_bc().await;
+    let fetcher = fetch_accounts(&mut bc, &[jito_stake_pool::ID, jitosol::ID, msol::ID]).await;
+
+    let from_list = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    let discovered = discovered_amm(&mut bc, &fetcher)
+        .await
+        .initial_update(&mut bc)
+        .await;
+
+    assert_eq!(
+        from_list.lst_data_list.len(),
+        discovered.lst_data_list.len()
+    );
+    for (l, d) in from_list
+        .lst_data_list
+        .iter()
+        .zip(discovered.lst_data_list.iter())
+    {
+        let (l, d) = (l.as_ref().unwrap(), d.as_ref().unwrap());
+        assert_eq!(l.token_program, d.token_program);
+        assert_eq!(l.reserves_balance, d.reserves_balance);
+        assert_eq!(l.sol_val_calc.ix_accounts(), d.sol_val_calc.ix_accounts());
+    }
+
+    let params = QuoteParams {
+        amount: 1_000_000_000,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let from_list_quote = from_list.quote(&params).unwrap();
+    let discovered_quote = discovered.quote(&params).unwrap();
+    assert_eq!(from_list_quote.out_amount, discovered_quote.out_amount);
+    assert_eq!(from_list_quote.fee_amount, discovered_quote.fee_amount);
+}
+
+#[tokio::test]
+async fn discover_missing_lst_data_after_stake_pool_fetched() {
+    let mut bc = jito_marinade_bc().await;
+    let mut fetcher = fetch_accounts(&mut bc, &[jitosol::ID, msol::ID]).await;
+
+    let (mut s, errs) = discovered_amm_with_errs(&mut bc, &fetcher).await;
+    let jitosol_idx = s
+        .lst_data_list
+        .iter()
+        .position(Option::is_none)
+        .expect("jitoSOL should not be discovered without its stake pool");
+    assert_eq!(s.lst_data_list.iter().filter(|d| d.is_some()).count(), 1);
+    let [(undiscovered_mint, err)] = errs.as_slice() else {
+        panic!("expected only jitoSOL to fail discovery, got {errs:?}");
+    };
+    assert_eq!(*undiscovered_mint, jitosol::ID);
+    assert!(matches!(
+        err.downcast_ref::<LstSolValCalcErr>(),
+        Some(LstSolValCalcErr::StakePoolNotFound)
+    ));
+
+    let err = s.discover_missing_lst_data(&fetcher).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<LstSolValCalcErr>(),
+        Some(LstSolValCalcErr::StakePoolNotFound)
+    ));
+
+    fetcher.insert(
+        jito_stake_pool::ID,
+        bc.get_account_unwrapped(jito_stake_pool::ID).await,
+    );
+    s.discover_missing_lst_data(&fetcher).unwrap();
+    let discovered_calc = &s.lst_data_list[jitosol_idx].as_ref().unwrap().sol_val_calc;
+    assert_eq!(discovered_calc.lst_mint(), jitosol::ID);
+
+    let s = s.initial_update(&mut bc).await;
+    s.quote(&QuoteParams {
+        amount: 1_000_000_000,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    })
+    .unwrap();
+}
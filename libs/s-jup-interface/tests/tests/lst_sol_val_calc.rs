@@ -0,0 +1,17 @@
+use s_sol_val_calc_prog_aggregate::{
+    KnownLstSolValCalc, LstSolValCalc, SanctumSplMultiLstSolValCalc, SplLstSolValCalcInitKeys,
+};
+use solana_sdk::pubkey::Pubkey;
+
+#[test]
+fn sanctum_spl_multi_lst_mint_is_pool_mint() {
+    let lst_mint = Pubkey::new_unique();
+    let calc: KnownLstSolValCalc =
+        SanctumSplMultiLstSolValCalc::from_keys(SplLstSolValCalcInitKeys {
+            lst_mint,
+            stake_pool_addr: Pubkey::new_unique(),
+        })
+        .into();
+    assert_eq!(calc.lst_mint(), lst_mint);
+    assert_ne!(calc.lst_mint(), calc.sol_value_calculator_program_id());
+}
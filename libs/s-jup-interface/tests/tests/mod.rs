@@ -2,6 +2,20 @@
 //! TODO: test lst state list changed
 
 mod add_liquidity;
+mod depth;
+mod discovery;
+mod lst_sol_val_calc;
+mod no_fee;
+mod pair_matrix;
+mod projection;
+mod quote_detailed;
+mod registry;
 mod remove_liquidity;
+mod slots;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+mod stream;
 mod swap_exact_in;
 mod swap_exact_out;
+mod token_2022;
+mod tx_builder;
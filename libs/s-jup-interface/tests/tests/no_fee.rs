@@ -0,0 +1,146 @@
+use jupiter_amm_interface::{QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_test_utils::{jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs};
+use s_jup_interface::SPoolJup;
+use s_pricing_prog_aggregate::DynPricingProg;
+use sanctum_solana_test_utils::ExtendedProgramTest;
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_sdk::{clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{assert_quote_swap_eq, fully_init_amm, MiscProgramTest};
+
+const AMT: u64 = 1_000_000_000;
+
+/// Returns a fully initialized SPoolJup for a jito-marinade pool using the no-fee pricing program,
+/// with `wallet` holding `4 * AMT` jitoSOL (enough for exactout), `AMT` LP tokens and an empty mSOL ATA
+async fn no_fee_jito_marinade_setup(
+    wallet: &Keypair,
+    lp_token_mint: Pubkey,
+) -> (BanksClient, SPoolJup) {
+    let pt = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 10_000_000_000,
+        msol_sol_value: 10_000_000_000,
+        jitosol_reserves: 10_000_000_000,
+        msol_reserves: 10_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 10_000_000_000,
+    })
+    .add_s_program()
+    .add_system_account(wallet.pubkey(), 1_000_000_000)
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        4 * AMT,
+    )
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        0,
+    )
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: lp_token_mint,
+            token_program: spl_token::ID,
+        },
+        AMT,
+    );
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    assert_eq!(
+        s.pricing_prog().unwrap().pricing_program_id(),
+        no_fee_pricing_program::ID
+    );
+    (bc, s)
+}
+
+async fn assert_no_fee_quote_swap_eq(
+    wallet: &Keypair,
+    lp_token_mint: Pubkey,
+    input_mint: Pubkey,
+    output_mint: Pubkey,
+    swap_mode: SwapMode,
+) {
+    let (mut bc, s) = no_fee_jito_marinade_setup(wallet, lp_token_mint).await;
+    assert_quote_swap_eq(
+        &mut bc,
+        &s,
+        wallet,
+        &QuoteParams {
+            amount: AMT,
+            input_mint,
+            output_mint,
+            swap_mode,
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn swap_exact_in_jito_marinade_no_fee() {
+    assert_no_fee_quote_swap_eq(
+        &Keypair::new(),
+        Pubkey::new_unique(),
+        jitosol::ID,
+        msol::ID,
+        SwapMode::ExactIn,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn swap_exact_out_jito_marinade_no_fee() {
+    assert_no_fee_quote_swap_eq(
+        &Keypair::new(),
+        Pubkey::new_unique(),
+        jitosol::ID,
+        msol::ID,
+        SwapMode::ExactOut,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn add_liquidity_jito_no_fee() {
+    let lp_token_mint = Pubkey::new_unique();
+    assert_no_fee_quote_swap_eq(
+        &Keypair::new(),
+        lp_token_mint,
+        jitosol::ID,
+        lp_token_mint,
+        SwapMode::ExactIn,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn remove_liquidity_jito_no_fee() {
+    let lp_token_mint = Pubkey::new_unique();
+    assert_no_fee_quote_swap_eq(
+        &Keypair::new(),
+        lp_token_mint,
+        lp_token_mint,
+        jitosol::ID,
+        SwapMode::ExactIn,
+    )
+    .await;
+}
@@ -0,0 +1,193 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_lib::try_lst_state_list_mut;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::{PairQuoteErr, PairQuoteResult, SPoolJup};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{clock::Clock, pubkey::Pubkey};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, MiscProgramTest};
+
+const NOTIONAL_SOL_VALUE: u64 = 1_000_000_000;
+
+async fn jito_marinade_flat_fee_amm() -> SPoolJup {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 10_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    fully_init_amm(&mut bc, s_controller_lib::program::ID).await
+}
+
+fn assert_matches_quote_detailed(
+    s: &SPoolJup,
+    entry: &PairQuoteResult,
+    input_mint: Pubkey,
+    output_mint: Pubkey,
+) {
+    let entry = entry.as_ref().unwrap();
+    let expected = s
+        .quote_detailed(&QuoteParams {
+            amount: entry.quote.in_amount,
+            input_mint,
+            output_mint,
+            swap_mode: SwapMode::ExactIn,
+        })
+        .unwrap();
+    assert_eq!(entry.quote.out_amount, expected.quote.out_amount);
+    assert_eq!(entry.quote.fee_amount, expected.quote.fee_amount);
+    assert_eq!(
+        entry.to_protocol_fees_lst_amount,
+        expected.to_protocol_fees_lst_amount
+    );
+    assert_eq!(entry.pool_state_before, expected.pool_state_before);
+    assert_eq!(entry.pool_state_after, expected.pool_state_after);
+}
+
+#[tokio::test]
+async fn pair_matrix_matches_individual_quotes() {
+    let s = jito_marinade_flat_fee_amm().await;
+    let lp_token_mint = s.lp_token_mint().unwrap();
+    let matrix = s.quote_pair_matrix(NOTIONAL_SOL_VALUE).unwrap();
+    assert_eq!(matrix.lst_mints.len(), 2);
+
+    for (input_mint, output_mint) in [(jitosol::ID, msol::ID), (msol::ID, jitosol::ID)] {
+        let entry = matrix.swap(input_mint, output_mint).unwrap();
+        assert_matches_quote_detailed(&s, entry, input_mint, output_mint);
+        assert_eq!(
+            matrix
+                .swap(input_mint, input_mint)
+                .unwrap()
+                .as_ref()
+                .unwrap_err(),
+            &PairQuoteErr::SameMint
+        );
+    }
+    for mint in [jitosol::ID, msol::ID] {
+        let add = matrix.add_liquidity(mint).unwrap();
+        assert_matches_quote_detailed(&s, add, mint, lp_token_mint);
+        let remove = matrix.remove_liquidity(mint).unwrap();
+        assert_matches_quote_detailed(&s, remove, lp_token_mint, mint);
+        // converting the notional to LP tokens and back may round down
+        let lp_tokens_sol_value = remove.as_ref().unwrap().in_sol_value;
+        assert!(lp_tokens_sol_value <= NOTIONAL_SOL_VALUE);
+        assert!(lp_tokens_sol_value >= NOTIONAL_SOL_VALUE - 1);
+    }
+}
+
+#[tokio::test]
+async fn pair_matrix_reports_entry_errors() {
+    let mut s = jito_marinade_flat_fee_amm().await;
+    let jitosol_index = s
+        .get_reserve_mints_full()
+        .iter()
+        .position(|m| *m == jitosol::ID)
+        .unwrap();
+    try_lst_state_list_mut(&mut s.lst_state_list_account.data).unwrap()[jitosol_index]
+        .is_input_disabled = 1;
+
+    let matrix = s.quote_pair_matrix(NOTIONAL_SOL_VALUE).unwrap();
+    assert_eq!(
+        matrix
+            .swap(jitosol::ID, msol::ID)
+            .unwrap()
+            .as_ref()
+            .unwrap_err(),
+        &PairQuoteErr::InputDisabled
+    );
+    assert_eq!(
+        matrix
+            .add_liquidity(jitosol::ID)
+            .unwrap()
+            .as_ref()
+            .unwrap_err(),
+        &PairQuoteErr::InputDisabled
+    );
+    assert!(matrix.swap(msol::ID, jitosol::ID).unwrap().is_ok());
+    assert!(matrix.remove_liquidity(jitosol::ID).unwrap().is_ok());
+
+    let msol_index = 1 - jitosol_index;
+    s.lst_data_list[msol_index] = None;
+    let matrix = s.quote_pair_matrix(NOTIONAL_SOL_VALUE).unwrap();
+    assert_eq!(
+        matrix
+            .swap(msol::ID, jitosol::ID)
+            .unwrap()
+            .as_ref()
+            .unwrap_err(),
+        &PairQuoteErr::UnknownCalculator
+    );
+    assert_eq!(
+        matrix
+            .remove_liquidity(msol::ID)
+            .unwrap()
+            .as_ref()
+            .unwrap_err(),
+        &PairQuoteErr::UnknownCalculator
+    );
+    assert!(matrix.remove_liquidity(jitosol::ID).unwrap().is_ok());
+}
+
+#[tokio::test]
+async fn pair_matrix_reports_not_enough_liquidity() {
+    let s = jito_marinade_flat_fee_amm().await;
+    // more than the 10 SOL worth of reserves of each LST
+    let matrix = s.quote_pair_matrix(20_000_000_000).unwrap();
+    for (input_mint, output_mint) in [(jitosol::ID, msol::ID), (msol::ID, jitosol::ID)] {
+        assert_eq!(
+            matrix
+                .swap(input_mint, output_mint)
+                .unwrap()
+                .as_ref()
+                .unwrap_err(),
+            &PairQuoteErr::NotEnoughLiquidity
+        );
+    }
+    assert!(matrix.add_liquidity(jitosol::ID).unwrap().is_ok());
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::SPoolJup;
+use s_sol_val_calc_prog_aggregate::LstSolValProjection;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{clock::Clock, pubkey::Pubkey};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, MiscProgramTest};
+
+const AMT: u64 = 1_000_000_000;
+
+async fn jito_marinade_flat_fee_amm() -> SPoolJup {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 10_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    fully_init_amm(&mut bc, s_controller_lib::program::ID).await
+}
+
+fn jitosol_to_msol_out(s: &SPoolJup) -> u64 {
+    s.quote(&QuoteParams {
+        amount: AMT,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    })
+    .unwrap()
+    .out_amount
+}
+
+#[tokio::test]
+async fn projection_without_growth_does_not_change_quotes() {
+    let s = jito_marinade_flat_fee_amm().await;
+    let projected = s
+        .projected(&HashMap::from([
+            (jitosol::ID, LstSolValProjection::next_epoch_apy(0.0)),
+            (msol::ID, LstSolValProjection::next_epoch_apy(0.0)),
+        ]))
+        .unwrap();
+    assert_eq!(jitosol_to_msol_out(&s), jitosol_to_msol_out(&projected));
+    assert_eq!(
+        s.lp_tokens_sol_value(AMT).unwrap(),
+        projected.lp_tokens_sol_value(AMT).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn projection_increases_lst_and_lp_values() {
+    let s = jito_marinade_flat_fee_amm().await;
+    let out_before = jitosol_to_msol_out(&s);
+    let lp_value_before = s.lp_tokens_sol_value(AMT).unwrap();
+
+    let jitosol_apy = s
+        .projected(&HashMap::from([(
+            jitosol::ID,
+            LstSolValProjection::next_epoch_apy(0.5),
+        )]))
+        .unwrap();
+    assert!(jitosol_to_msol_out(&jitosol_apy) > out_before);
+    assert!(jitosol_apy.lp_tokens_sol_value(AMT).unwrap() > lp_value_before);
+
+    let msol_bump = s
+        .projected(&HashMap::from([(
+            msol::ID,
+            LstSolValProjection::TotalLamportsBump {
+                epochs: 1,
+                lamports: 1_000_000_000_000_000,
+            },
+        )]))
+        .unwrap();
+    assert!(jitosol_to_msol_out(&msol_bump) < out_before);
+    assert!(msol_bump.lp_tokens_sol_value(AMT).unwrap() > lp_value_before);
+
+    // original pool untouched
+    assert_eq!(jitosol_to_msol_out(&s), out_before);
+    assert_eq!(s.lp_tokens_sol_value(AMT).unwrap(), lp_value_before);
+}
+
+#[tokio::test]
+async fn projection_of_unknown_lst_fails() {
+    let s = jito_marinade_flat_fee_amm().await;
+    assert!(s
+        .projected(&HashMap::from([(
+            Pubkey::new_unique(),
+            LstSolValProjection::next_epoch_apy(0.07),
+        )]))
+        .is_err());
+}
@@ -0,0 +1,212 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_lib::try_pool_state;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::{BeforeAfter, SPoolJup};
+use sanctum_solana_test_utils::{ExtendedBanksClient, ExtendedProgramTest};
+use sanctum_token_lib::{mint_supply, token_account_balance, MintWithTokenProgram};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_sdk::{clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{assert_quote_swap_eq, fully_init_amm, MiscProgramTest};
+
+const AMT: u64 = 1_000_000_000;
+
+/// `wallet` starts with `AMT` jitoSOL, an empty mSOL ATA and `AMT` LP tokens
+async fn jito_marinade_flat_fee_setup(
+    wallet: &Keypair,
+    lp_token_mint: Pubkey,
+) -> (BanksClient, SPoolJup) {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: 10_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program()
+    .add_system_account(wallet.pubkey(), 1_000_000_000)
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        AMT,
+    )
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        0,
+    )
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: lp_token_mint,
+            token_program: spl_token::ID,
+        },
+        AMT,
+    );
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    (bc, s)
+}
+
+async fn reserves_balance(bc: &mut BanksClient, s: &SPoolJup, mint: Pubkey) -> u64 {
+    let (lst_state, lst_data) = s.find_ready_lst(mint).unwrap();
+    let reserves = s.pool_reserves_account(&lst_state, lst_data).unwrap();
+    token_account_balance(bc.get_account_unwrapped(reserves).await).unwrap()
+}
+
+/// Executes the quoted trade and checks that every post-trade value
+/// in the detailed quote matches on-chain state
+async fn assert_quote_detailed_matches_chain(
+    bc: &mut BanksClient,
+    s: &SPoolJup,
+    wallet: &Keypair,
+    params: &QuoteParams,
+) {
+    let lp_token_mint = s.lp_token_mint().unwrap();
+    let detailed = s.quote_detailed(params).unwrap();
+    let quote = s.quote(params).unwrap();
+    assert_eq!(detailed.quote.in_amount, quote.in_amount);
+    assert_eq!(detailed.quote.out_amount, quote.out_amount);
+    assert_eq!(detailed.quote.fee_amount, quote.fee_amount);
+    assert_eq!(detailed.quote.fee_pct, quote.fee_pct);
+    assert!(detailed.fee_sol_value() > 0);
+    assert!(detailed.to_protocol_fees_lst_amount > 0);
+
+    for (mint, expected) in [
+        (params.input_mint, detailed.input_reserves),
+        (params.output_mint, detailed.output_reserves),
+    ] {
+        if mint == lp_token_mint {
+            assert!(expected.is_none());
+        } else {
+            let before = reserves_balance(bc, s, mint).await;
+            assert_eq!(expected.unwrap().before, before);
+        }
+    }
+
+    assert_quote_swap_eq(bc, s, wallet, params).await;
+
+    for (mint, expected) in [
+        (params.input_mint, detailed.input_reserves),
+        (params.output_mint, detailed.output_reserves),
+    ] {
+        if mint != lp_token_mint {
+            let BeforeAfter { after, .. } = expected.unwrap();
+            assert_eq!(after, reserves_balance(bc, s, mint).await);
+        }
+    }
+    if let Some(BeforeAfter { after, .. }) = detailed.lp_token_supply {
+        let lp_mint_acc = bc.get_account_unwrapped(lp_token_mint).await;
+        assert_eq!(after, mint_supply(lp_mint_acc).unwrap());
+    }
+    let pool_state_acc = bc.get_account_unwrapped(s.pool_state_addr).await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(
+        detailed.pool_state_after.total_sol_value,
+        pool_state.total_sol_value
+    );
+}
+
+#[tokio::test]
+async fn quote_detailed_swap_exact_in() {
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let (mut bc, s) = jito_marinade_flat_fee_setup(&wallet, lp_token_mint).await;
+    let params = QuoteParams {
+        amount: AMT,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let detailed = s.quote_detailed(&params).unwrap();
+    assert!(detailed.lp_token_supply.is_none());
+    assert_eq!(
+        detailed.input_calc_range.unwrap().get_min(),
+        detailed.in_sol_value
+    );
+    assert_eq!(
+        detailed.output_calc_range.unwrap().get_min(),
+        detailed.quote.out_amount
+    );
+    assert_quote_detailed_matches_chain(&mut bc, &s, &wallet, &params).await;
+}
+
+#[tokio::test]
+async fn quote_detailed_swap_exact_out() {
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let (mut bc, s) = jito_marinade_flat_fee_setup(&wallet, lp_token_mint).await;
+    let params = QuoteParams {
+        amount: AMT / 2,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactOut,
+    };
+    assert_quote_detailed_matches_chain(&mut bc, &s, &wallet, &params).await;
+}
+
+#[tokio::test]
+async fn quote_detailed_remove_liquidity() {
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let (mut bc, s) = jito_marinade_flat_fee_setup(&wallet, lp_token_mint).await;
+    let params = QuoteParams {
+        amount: AMT,
+        input_mint: lp_token_mint,
+        output_mint: jitosol::ID,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let detailed = s.quote_detailed(&params).unwrap();
+    assert!(detailed.input_calc_range.is_none());
+    assert!(detailed.input_reserves.is_none());
+    assert_quote_detailed_matches_chain(&mut bc, &s, &wallet, &params).await;
+}
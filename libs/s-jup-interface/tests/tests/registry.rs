@@ -0,0 +1,119 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_test_utils::{jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs};
+use s_jup_interface::{SPoolInitKeys, SPoolJup, SPoolRegistries};
+use s_pricing_prog_aggregate::{DynPricingProg, KnownPricingProg, PricingProgRegistry};
+use s_sol_val_calc_prog_aggregate::LstSolValCalcRegistry;
+use sanctum_lst_list::SanctumLstList;
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_sdk::{account::Account, clock::Clock, pubkey::Pubkey};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::UpdatingSPoolJup;
+
+const AMT: u64 = 1_000_000_000;
+
+async fn init_amm_with_registries(registries: SPoolRegistries<Account>) -> SPoolJup {
+    let pt = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 10_000_000_000,
+        msol_sol_value: 10_000_000_000,
+        jitosol_reserves: 10_000_000_000,
+        msol_reserves: 10_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 10_000_000_000,
+    });
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+    init_amm(&mut bc, registries).await
+}
+
+async fn init_amm(bc: &mut BanksClient, registries: SPoolRegistries<Account>) -> SPoolJup {
+    let program_id = s_controller_lib::program::ID;
+    let SPoolInitKeys { lst_state_list, .. } = SPoolJup::init_keys(program_id);
+    let lst_state_list_acc = bc.get_account_unwrapped(lst_state_list).await;
+    SPoolJup::from_lst_state_list_account_with_registries(
+        program_id,
+        lst_state_list_acc,
+        &SanctumLstList::load().sanctum_lst_list,
+        registries,
+    )
+    .unwrap()
+    .initial_update(bc)
+    .await
+}
+
+fn jitosol_to_msol_quote_params() -> QuoteParams {
+    QuoteParams {
+        amount: AMT,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    }
+}
+
+#[tokio::test]
+async fn unregistered_pricing_prog_not_initialized() {
+    let s = init_amm_with_registries(SPoolRegistries {
+        pricing_progs: Arc::new(PricingProgRegistry::empty()),
+        ..Default::default()
+    })
+    .await;
+    assert!(s.pricing_prog.is_none());
+    assert!(s.lst_data_list.iter().all(Option::is_some));
+    assert!(s.quote(&jitosol_to_msol_quote_params()).is_err());
+}
+
+#[tokio::test]
+async fn unregistered_sol_val_calcs_not_initialized() {
+    let s = init_amm_with_registries(SPoolRegistries {
+        lst_sol_val_calcs: Arc::new(LstSolValCalcRegistry::empty()),
+        ..Default::default()
+    })
+    .await;
+    assert!(s.pricing_prog.is_some());
+    assert!(s.lst_data_list.iter().all(Option::is_none));
+    assert!(s.quote(&jitosol_to_msol_quote_params()).is_err());
+}
+
+#[tokio::test]
+async fn registered_pricing_prog_factory_used() {
+    let factory_called = Arc::new(AtomicBool::new(false));
+    let mut pricing_progs = PricingProgRegistry::empty();
+    let fc = Arc::clone(&factory_called);
+    pricing_progs.register(no_fee_pricing_program::ID, move |program_id, mints| {
+        fc.store(true, Ordering::Relaxed);
+        let pp: Box<dyn DynPricingProg<_>> = Box::new(KnownPricingProg::try_new(
+            program_id,
+            mints.iter().copied(),
+        )?);
+        Ok(pp)
+    });
+    let s = init_amm_with_registries(SPoolRegistries {
+        pricing_progs: Arc::new(pricing_progs),
+        ..Default::default()
+    })
+    .await;
+    assert!(factory_called.load(Ordering::Relaxed));
+    assert_eq!(
+        s.pricing_prog().unwrap().pricing_program_id(),
+        no_fee_pricing_program::ID
+    );
+    let quote = s.quote(&jitosol_to_msol_quote_params()).unwrap();
+    assert_eq!(quote.fee_amount, 0);
+    assert!(quote.out_amount > 0);
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::{SPoolJup, SlotRange, SlottedAccount};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_sdk::{account::Account, clock::Clock, pubkey::Pubkey};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, MiscProgramTest};
+
+const AMT: u64 = 1_000_000_000;
+
+async fn jito_marinade_flat_fee_amm() -> (SPoolJup, BanksClient) {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 10_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    (s, bc)
+}
+
+async fn fetch_slotted<I: IntoIterator<Item = Pubkey>>(
+    bc: &mut BanksClient,
+    accounts: I,
+    context_slot: u64,
+) -> HashMap<Pubkey, SlottedAccount<Account>> {
+    let mut res = HashMap::new();
+    for pk in accounts {
+        res.insert(
+            pk,
+            SlottedAccount {
+                context_slot,
+                account: bc.get_account_unwrapped(pk).await,
+            },
+        );
+    }
+    res
+}
+
+fn jitosol_to_msol() -> QuoteParams {
+    QuoteParams {
+        amount: AMT,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    }
+}
+
+#[tokio::test]
+async fn slotted_update_tracks_min_consistent_slot() {
+    let (mut s, mut bc) = jito_marinade_flat_fee_amm().await;
+    assert_eq!(s.min_consistent_slot(), None);
+    assert!(
+        s.quote_slot_checked(&jitosol_to_msol(), 0)
+            .unwrap()
+            .is_unsafe
+    );
+
+    let all = fetch_slotted(&mut bc, s.get_accounts_to_update_full(), 10).await;
+    s.update_full_slotted(&all).unwrap();
+    assert_eq!(s.min_consistent_slot(), Some(10));
+    let checked = s.quote_slot_checked(&jitosol_to_msol(), 0).unwrap();
+    assert_eq!(checked.slot_range, Some(SlotRange { min: 10, max: 10 }));
+    assert!(!checked.is_unsafe);
+}
+
+#[tokio::test]
+async fn slotted_update_refuses_older_slots() {
+    let (mut s, mut bc) = jito_marinade_flat_fee_amm().await;
+    let accounts = s.get_accounts_to_update_full();
+    s.update_full_slotted(&fetch_slotted(&mut bc, accounts.clone(), 10).await)
+        .unwrap();
+
+    let mut older = fetch_slotted(&mut bc, accounts, 9).await;
+    older.get_mut(&s.pool_state_addr).unwrap().context_slot = 11;
+    assert!(s.update_full_slotted(&older).is_err());
+    // fresh accounts still applied, stale ones kept at their newer slot
+    assert_eq!(s.account_slots[&s.pool_state_addr], 11);
+    assert_eq!(s.account_slots[&s.lst_state_list_addr], 10);
+    assert_eq!(s.min_consistent_slot(), Some(10));
+}
+
+#[tokio::test]
+async fn pair_slot_spread_marks_quotes_unsafe() {
+    let (mut s, mut bc) = jito_marinade_flat_fee_amm().await;
+    s.update_full_slotted(&fetch_slotted(&mut bc, s.get_accounts_to_update_full(), 10).await)
+        .unwrap();
+
+    let lp_token_mint = s.lp_token_mint().unwrap();
+    let jitosol_accounts =
+        s.get_accounts_to_update_lsts_filtered(|lst_state, _| lst_state.mint == jitosol::ID);
+    s.update_full_slotted(&fetch_slotted(&mut bc, jitosol_accounts, 20).await)
+        .unwrap();
+    assert_eq!(s.min_consistent_slot(), Some(10));
+
+    assert_eq!(
+        s.pair_slot_range(jitosol::ID, msol::ID).unwrap(),
+        Some(SlotRange { min: 10, max: 20 })
+    );
+    assert!(
+        s.quote_slot_checked(&jitosol_to_msol(), 5)
+            .unwrap()
+            .is_unsafe
+    );
+    assert!(
+        !s.quote_slot_checked(&jitosol_to_msol(), 10)
+            .unwrap()
+            .is_unsafe
+    );
+
+    // pairs not involving jitosol are unaffected
+    assert_eq!(
+        s.pair_slot_range(lp_token_mint, msol::ID).unwrap(),
+        Some(SlotRange { min: 10, max: 10 })
+    );
+}
+
+#[tokio::test]
+async fn nonexistent_pair_fee_accounts_dont_mark_quotes_unsafe() {
+    // no PairFeeAccounts exist for this pool
+    let (mut s, mut bc) = jito_marinade_flat_fee_amm().await;
+    for pk in s.get_accounts_for_pair(jitosol::ID, msol::ID).unwrap() {
+        assert!(bc.get_account(pk).await.unwrap().is_some(), "{pk}");
+    }
+
+    s.update_full_slotted(&fetch_slotted(&mut bc, s.get_accounts_to_update_full(), 10).await)
+        .unwrap();
+    let checked = s.quote_slot_checked(&jitosol_to_msol(), 0).unwrap();
+    assert_eq!(checked.slot_range, Some(SlotRange { min: 10, max: 10 }));
+    assert!(!checked.is_unsafe);
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::{SPoolJup, SPoolSnapshot};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{account::Account, clock::Clock, pubkey::Pubkey};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, MiscProgramTest};
+
+const AMT: u64 = 1_000_000_000;
+
+const SLOT: u64 = 123;
+
+async fn jito_marinade_flat_fee_amm_and_accounts() -> (SPoolJup, HashMap<Pubkey, Account>) {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 10_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    let mut accounts = HashMap::new();
+    for pk in s
+        .get_accounts_to_update_full()
+        .into_iter()
+        .chain(s.get_reserve_mints_full())
+    {
+        accounts.insert(pk, bc.get_account_unwrapped(pk).await);
+    }
+    (s, accounts)
+}
+
+fn all_quote_params(s: &SPoolJup) -> Vec<QuoteParams> {
+    let lp_token_mint = s.lp_token_mint().unwrap();
+    let mut res = Vec::new();
+    for (input_mint, output_mint) in [
+        (jitosol::ID, msol::ID),
+        (msol::ID, jitosol::ID),
+        (jitosol::ID, lp_token_mint),
+        (lp_token_mint, msol::ID),
+    ] {
+        let is_liquidity = input_mint == lp_token_mint || output_mint == lp_token_mint;
+        for swap_mode in [SwapMode::ExactIn, SwapMode::ExactOut] {
+            if is_liquidity && matches!(swap_mode, SwapMode::ExactOut) {
+                continue;
+            }
+            res.push(QuoteParams {
+                amount: AMT,
+                input_mint,
+                output_mint,
+                swap_mode,
+            });
+        }
+    }
+    res
+}
+
+fn assert_same_quotes(expected: &SPoolJup, actual: &SPoolJup) {
+    for params in all_quote_params(expected) {
+        let expected = expected.quote(&params).unwrap();
+        let actual = actual.quote(&params).unwrap();
+        assert_eq!(expected.in_amount, actual.in_amount);
+        assert_eq!(expected.out_amount, actual.out_amount);
+        assert_eq!(expected.fee_amount, actual.fee_amount);
+        assert_eq!(expected.fee_mint, actual.fee_mint);
+        assert_eq!(expected.fee_pct, actual.fee_pct);
+    }
+}
+
+#[tokio::test]
+async fn snapshot_roundtrip_replays_same_quotes() {
+    let (s, accounts) = jito_marinade_flat_fee_amm_and_accounts().await;
+    let snapshot = s.snapshot(SLOT, &accounts);
+    assert_eq!(snapshot.slot, SLOT);
+    assert_eq!(snapshot.program_id, s_controller_lib::program::ID);
+
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let deserialized: SPoolSnapshot = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, snapshot);
+
+    let replayed = SPoolJup::from_snapshot(&deserialized).unwrap();
+    assert_same_quotes(&s, &replayed);
+    // snapshotting the replayed pool records the same accounts
+    assert_eq!(
+        replayed.snapshot(SLOT, &deserialized.account_map()),
+        snapshot
+    );
+}
+
+#[tokio::test]
+async fn snapshot_missing_lst_state_list_fails() {
+    let (s, mut accounts) = jito_marinade_flat_fee_amm_and_accounts().await;
+    accounts.remove(&s.lst_state_list_addr);
+    let snapshot = s.snapshot(SLOT, &accounts);
+    assert!(SPoolJup::from_snapshot(&snapshot).is_err());
+}
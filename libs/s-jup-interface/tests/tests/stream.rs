@@ -0,0 +1,212 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, KeyedAccount, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::{
+    AccountUpdate, AccountUpdateDriver, AccountUpdateRoute, AccountUpdateStream,
+    InMemoryAccountUpdateStream, SPoolInitKeys, SPoolJup,
+};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_sdk::{account::Account, clock::Clock, pubkey::Pubkey};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, MiscProgramTest};
+
+const AMT: u64 = 1_000_000_000;
+
+async fn jito_marinade_flat_fee_amm() -> (SPoolJup, BanksClient) {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 10_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    (s, bc)
+}
+
+async fn stream_of<I: IntoIterator<Item = Pubkey>>(
+    bc: &mut BanksClient,
+    accounts: I,
+    slot: u64,
+) -> InMemoryAccountUpdateStream<Account> {
+    let mut res = InMemoryAccountUpdateStream::new();
+    for pubkey in accounts {
+        res.push(AccountUpdate {
+            pubkey,
+            account: bc.get_account_unwrapped(pubkey).await,
+            slot,
+        });
+    }
+    res
+}
+
+fn assert_same_quotes(expected: &SPoolJup, actual: &SPoolJup) {
+    let lp_token_mint = expected.lp_token_mint().unwrap();
+    for (input_mint, output_mint) in [
+        (jitosol::ID, msol::ID),
+        (msol::ID, jitosol::ID),
+        (jitosol::ID, lp_token_mint),
+        (lp_token_mint, msol::ID),
+    ] {
+        let params = QuoteParams {
+            amount: AMT,
+            input_mint,
+            output_mint,
+            swap_mode: SwapMode::ExactIn,
+        };
+        assert_eq!(
+            expected.quote(&params).unwrap().out_amount,
+            actual.quote(&params).unwrap().out_amount
+        );
+    }
+}
+
+#[tokio::test]
+async fn streamed_updates_match_polled_updates() {
+    let (polled, mut bc) = jito_marinade_flat_fee_amm().await;
+
+    let program_id = s_controller_lib::program::ID;
+    let SPoolInitKeys { lst_state_list, .. } = SPoolJup::init_keys(program_id);
+    let mut streamed = SPoolJup::from_keyed_account(&KeyedAccount {
+        key: lst_state_list,
+        account: bc.get_account_unwrapped(lst_state_list).await,
+        params: Some(serde_json::Value::String(program_id.to_string())),
+    })
+    .unwrap();
+    // pool state comes before pricing program accounts,
+    // so a single pass is enough to initialize the pricing program
+    let mut stream = stream_of(&mut bc, polled.get_accounts_to_update_full(), 1).await;
+    streamed.drive(&mut stream).unwrap();
+    assert!(stream.is_empty());
+
+    assert_same_quotes(&polled, &streamed);
+    assert_eq!(streamed.min_consistent_slot(), Some(1));
+}
+
+#[tokio::test]
+async fn account_updates_routed_to_dependents() {
+    let (mut s, mut bc) = jito_marinade_flat_fee_amm().await;
+    let jitosol_index = s
+        .get_reserve_mints_full()
+        .iter()
+        .position(|m| *m == jitosol::ID)
+        .unwrap();
+    let jitosol_reserves = *s
+        .get_accounts_to_update_lsts_filtered(|lst_state, _| lst_state.mint == jitosol::ID)
+        .last()
+        .unwrap();
+
+    let mut stream = stream_of(
+        &mut bc,
+        [
+            s.pool_state_addr,
+            jitosol_reserves,
+            s.lp_token_mint().unwrap(),
+        ],
+        1,
+    )
+    .await;
+    let routes: Vec<AccountUpdateRoute> = std::iter::from_fn(|| {
+        stream
+            .next_update()
+            .map(|update| s.apply_account_update(update).unwrap())
+    })
+    .collect();
+    assert_eq!(
+        routes,
+        [
+            AccountUpdateRoute {
+                pool_state: true,
+                ..Default::default()
+            },
+            AccountUpdateRoute {
+                reserves: vec![jitosol_index],
+                ..Default::default()
+            },
+            AccountUpdateRoute {
+                lp_token_mint: true,
+                ..Default::default()
+            },
+        ]
+    );
+
+    let unrelated = s
+        .apply_account_update(AccountUpdate {
+            pubkey: Pubkey::new_unique(),
+            account: Account::default(),
+            slot: 1,
+        })
+        .unwrap();
+    assert!(unrelated.is_empty());
+    assert_eq!(s.account_slots.len(), 3);
+}
+
+#[tokio::test]
+async fn stale_stream_updates_refused() {
+    let (mut s, mut bc) = jito_marinade_flat_fee_amm().await;
+    let pool_state = bc.get_account_unwrapped(s.pool_state_addr).await;
+    let lst_state_list = bc.get_account_unwrapped(s.lst_state_list_addr).await;
+    let mut stream: InMemoryAccountUpdateStream<Account> = [
+        (s.pool_state_addr, pool_state.clone(), 5),
+        (s.pool_state_addr, pool_state, 3),
+        (s.lst_state_list_addr, lst_state_list, 4),
+    ]
+    .into_iter()
+    .map(|(pubkey, account, slot)| AccountUpdate {
+        pubkey,
+        account,
+        slot,
+    })
+    .collect();
+
+    assert!(s.drive(&mut stream).is_err());
+    // updates after the stale one are still applied
+    assert!(stream.is_empty());
+    assert_eq!(s.account_slots[&s.pool_state_addr], 5);
+    assert_eq!(s.account_slots[&s.lst_state_list_addr], 4);
+}
@@ -0,0 +1,196 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::{max_transfer_fee, SPoolJup};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{clock::Clock, pubkey::Pubkey};
+use spl_token_2022::extension::transfer_fee::{TransferFee, TransferFeeConfig};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, MiscProgramTest};
+
+const AMT: u64 = 1_000_000_000;
+
+async fn jito_marinade_flat_fee_amm() -> SPoolJup {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 10_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    fully_init_amm(&mut bc, s_controller_lib::program::ID).await
+}
+
+fn transfer_fee_config(older_bps: u16, newer_bps: u16) -> TransferFeeConfig {
+    let fee = |bps: u16| TransferFee {
+        transfer_fee_basis_points: bps.into(),
+        maximum_fee: u64::MAX.into(),
+        ..Default::default()
+    };
+    TransferFeeConfig {
+        older_transfer_fee: fee(older_bps),
+        newer_transfer_fee: fee(newer_bps),
+        ..Default::default()
+    }
+}
+
+fn set_transfer_fee(s: &mut SPoolJup, mint: Pubkey, config: TransferFeeConfig) {
+    let index = s
+        .get_reserve_mints_full()
+        .iter()
+        .position(|m| *m == mint)
+        .unwrap();
+    s.lst_data_list[index].as_mut().unwrap().transfer_fee_config = Some(config);
+}
+
+fn quote(s: &SPoolJup, amount: u64, input_mint: Pubkey, output_mint: Pubkey) -> u64 {
+    s.quote(&QuoteParams {
+        amount,
+        input_mint,
+        output_mint,
+        swap_mode: SwapMode::ExactIn,
+    })
+    .unwrap()
+    .out_amount
+}
+
+#[test]
+fn max_transfer_fee_uses_larger_fee() {
+    let fee = max_transfer_fee(&transfer_fee_config(100, 50), AMT).unwrap();
+    assert_eq!(fee, AMT / 100);
+    let fee = max_transfer_fee(&transfer_fee_config(50, 100), AMT).unwrap();
+    assert_eq!(fee, AMT / 100);
+}
+
+#[tokio::test]
+async fn swap_exact_in_quotes_net_of_input_transfer_fee() {
+    let mut s = jito_marinade_flat_fee_amm().await;
+    let config = transfer_fee_config(100, 100);
+    let expected = quote(
+        &s,
+        AMT - max_transfer_fee(&config, AMT).unwrap(),
+        jitosol::ID,
+        msol::ID,
+    );
+
+    set_transfer_fee(&mut s, jitosol::ID, config);
+    assert_eq!(quote(&s, AMT, jitosol::ID, msol::ID), expected);
+}
+
+#[tokio::test]
+async fn swap_exact_in_quotes_net_of_output_transfer_fee() {
+    let mut s = jito_marinade_flat_fee_amm().await;
+    let config = transfer_fee_config(100, 100);
+    let out_before_fee = quote(&s, AMT, jitosol::ID, msol::ID);
+
+    set_transfer_fee(&mut s, msol::ID, config);
+    assert_eq!(
+        quote(&s, AMT, jitosol::ID, msol::ID),
+        out_before_fee - max_transfer_fee(&config, out_before_fee).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn max_in_amount_ignores_output_transfer_fee() {
+    let mut s = jito_marinade_flat_fee_amm().await;
+    let expected = s.max_in_amount(jitosol::ID, msol::ID).unwrap();
+
+    // reserves are debited the output amount before transfer fees
+    set_transfer_fee(&mut s, msol::ID, transfer_fee_config(100, 100));
+    assert_eq!(s.max_in_amount(jitosol::ID, msol::ID).unwrap(), expected);
+}
+
+#[tokio::test]
+async fn liquidity_quotes_net_of_transfer_fee() {
+    let mut s = jito_marinade_flat_fee_amm().await;
+    let lp_mint = s.lp_token_mint().unwrap();
+    let config = transfer_fee_config(100, 100);
+    let expected_lp_out = quote(
+        &s,
+        AMT - max_transfer_fee(&config, AMT).unwrap(),
+        jitosol::ID,
+        lp_mint,
+    );
+    let lst_out_before_fee = quote(&s, AMT, lp_mint, jitosol::ID);
+
+    set_transfer_fee(&mut s, jitosol::ID, config);
+    assert_eq!(quote(&s, AMT, jitosol::ID, lp_mint), expected_lp_out);
+    assert_eq!(
+        quote(&s, AMT, lp_mint, jitosol::ID),
+        lst_out_before_fee - max_transfer_fee(&config, lst_out_before_fee).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn swap_exact_out_rejects_transfer_fees_charged() {
+    let mut s = jito_marinade_flat_fee_amm().await;
+    let params = QuoteParams {
+        amount: AMT,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactOut,
+    };
+    assert!(s.quote(&params).is_ok());
+
+    set_transfer_fee(&mut s, jitosol::ID, transfer_fee_config(100, 100));
+    assert!(s.quote(&params).is_err());
+
+    // zero fees are allowed
+    set_transfer_fee(&mut s, jitosol::ID, transfer_fee_config(0, 0));
+    assert!(s.quote(&params).is_ok());
+
+    // nonzero bps are allowed if no fee is charged on the amounts swapped
+    let mut uncharged = transfer_fee_config(100, 100);
+    uncharged.older_transfer_fee.maximum_fee = 0.into();
+    uncharged.newer_transfer_fee.maximum_fee = 0.into();
+    set_transfer_fee(&mut s, jitosol::ID, uncharged);
+    set_transfer_fee(&mut s, msol::ID, uncharged);
+    assert!(s.quote(&params).is_ok());
+
+    set_transfer_fee(&mut s, msol::ID, transfer_fee_config(0, 100));
+    assert!(s.quote(&params).is_err());
+}
@@ -0,0 +1,236 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode, SwapParams};
+use marinade_keys::msol;
+use s_controller_interface::{Deadline, SControllerProgramIx};
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::{
+    max_in_with_slippage, min_out_with_slippage, FixedComputeUnits, SPoolJup, SwapTxArgs,
+    SwapTxComputeBudget,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount, clock::Clock, compute_budget, hash::Hash,
+    message::VersionedMessage, pubkey::Pubkey,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, MiscProgramTest};
+
+const AMT: u64 = 1_000_000_000;
+
+const SLIPPAGE_BPS: u16 = 50;
+
+async fn jito_marinade_flat_fee_amm() -> SPoolJup {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 10_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    fully_init_amm(&mut bc, s_controller_lib::program::ID).await
+}
+
+#[test]
+fn slippage_rounding() {
+    assert_eq!(min_out_with_slippage(10_000, 50), 9_950);
+    assert_eq!(min_out_with_slippage(10_001, 50), 9_950);
+    assert_eq!(min_out_with_slippage(10_000, 20_000), 0);
+    assert_eq!(max_in_with_slippage(10_000, 50), 10_050);
+    assert_eq!(max_in_with_slippage(10_001, 50), 10_052);
+    assert_eq!(max_in_with_slippage(u64::MAX, 1), u64::MAX);
+}
+
+#[tokio::test]
+async fn swap_tx_message_exact_in() {
+    let s = jito_marinade_flat_fee_amm().await;
+    let user = Pubkey::new_unique();
+    let quote_params = QuoteParams {
+        amount: AMT,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let quote = s.quote(&quote_params).unwrap();
+    let lut = AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses: vec![s.lst_state_list_addr, s.pool_state_addr],
+    };
+    let msg = s
+        .swap_tx_message(SwapTxArgs {
+            user,
+            payer: user,
+            quote_params: &quote_params,
+            quote: &quote,
+            slippage_bps: SLIPPAGE_BPS,
+            wrap_unwrap_sol: true,
+            deadline: None,
+            compute_budget: Some(SwapTxComputeBudget {
+                estimator: &FixedComputeUnits(200_000),
+                micro_lamports_per_cu: 1_000,
+            }),
+            luts: &[lut],
+            recent_blockhash: Hash::default(),
+        })
+        .unwrap();
+    let msg = match msg {
+        VersionedMessage::V0(m) => m,
+        _ => panic!("expected v0 message"),
+    };
+    assert_eq!(msg.account_keys[0], user);
+    assert_eq!(msg.address_table_lookups.len(), 1);
+    // 2 compute budget ixs + create output ATA + swap
+    assert_eq!(msg.instructions.len(), 4);
+    for ix in &msg.instructions[..2] {
+        assert_eq!(
+            msg.account_keys[usize::from(ix.program_id_index)],
+            compute_budget::ID
+        );
+    }
+
+    let [source_token_account, destination_token_account] = [jitosol::ID, msol::ID]
+        .map(|mint| get_associated_token_address_with_program_id(&user, &mint, &spl_token::ID));
+    let expected_swap_ix = s
+        .swap_ix(
+            &SwapParams {
+                in_amount: AMT,
+                out_amount: min_out_with_slippage(quote.out_amount, SLIPPAGE_BPS),
+                source_mint: jitosol::ID,
+                destination_mint: msol::ID,
+                source_token_account,
+                destination_token_account,
+                token_transfer_authority: user,
+                open_order_address: None,
+                quote_mint_to_referrer: None,
+                jupiter_program_id: &Pubkey::default(),
+            },
+            SwapMode::ExactIn,
+        )
+        .unwrap();
+    let swap_ix = msg.instructions.last().unwrap();
+    assert_eq!(
+        msg.account_keys[usize::from(swap_ix.program_id_index)],
+        s.program_id
+    );
+    assert_eq!(swap_ix.data, expected_swap_ix.data);
+    assert_eq!(swap_ix.accounts.len(), expected_swap_ix.accounts.len());
+}
+
+#[tokio::test]
+async fn swap_params_amounts_apply_slippage() {
+    let s = jito_marinade_flat_fee_amm().await;
+    let lp_token_mint = s.lp_token_mint().unwrap();
+    for (input_mint, output_mint, swap_mode, slippage_on_in) in [
+        (jitosol::ID, msol::ID, SwapMode::ExactIn, false),
+        (jitosol::ID, msol::ID, SwapMode::ExactOut, true),
+        (jitosol::ID, lp_token_mint, SwapMode::ExactIn, false),
+        (lp_token_mint, msol::ID, SwapMode::ExactIn, false),
+    ] {
+        let quote_params = QuoteParams {
+            amount: AMT,
+            input_mint,
+            output_mint,
+            swap_mode,
+        };
+        let quote = s.quote(&quote_params).unwrap();
+        let amounts = s
+            .swap_params_amounts(&quote_params, &quote, SLIPPAGE_BPS)
+            .unwrap();
+        let expected = if slippage_on_in {
+            (
+                max_in_with_slippage(quote.in_amount, SLIPPAGE_BPS),
+                quote.out_amount,
+            )
+        } else {
+            (
+                quote.in_amount,
+                min_out_with_slippage(quote.out_amount, SLIPPAGE_BPS),
+            )
+        };
+        assert_eq!(amounts, expected);
+    }
+}
+
+#[tokio::test]
+async fn swap_tx_message_with_deadline() {
+    let s = jito_marinade_flat_fee_amm().await;
+    let user = Pubkey::new_unique();
+    let quote_params = QuoteParams {
+        amount: AMT,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let quote = s.quote(&quote_params).unwrap();
+    let deadline = Deadline {
+        expiry_slot: Some(1_000),
+        expiry_unix_timestamp: None,
+    };
+    let msg = s
+        .swap_tx_message(SwapTxArgs {
+            user,
+            payer: user,
+            quote_params: &quote_params,
+            quote: &quote,
+            slippage_bps: SLIPPAGE_BPS,
+            wrap_unwrap_sol: false,
+            deadline: Some(deadline.clone()),
+            compute_budget: None,
+            luts: &[],
+            recent_blockhash: Hash::default(),
+        })
+        .unwrap();
+    let swap_ix = msg.instructions().last().unwrap();
+    let args = match SControllerProgramIx::deserialize(&swap_ix.data).unwrap() {
+        SControllerProgramIx::SwapExactInWithDeadline(args) => args,
+        ix => panic!("expected SwapExactInWithDeadline, got {ix:?}"),
+    };
+    assert_eq!(args.deadline, deadline);
+    assert_eq!(
+        args.min_amount_out,
+        min_out_with_slippage(quote.out_amount, SLIPPAGE_BPS)
+    );
+}
@@ -0,0 +1,81 @@
+use fixed_rate_calculator_interface::{LstToSolKeys, SolToLstKeys, LST_TO_SOL_IX_ACCOUNTS_LEN};
+use solana_program::{
+    instruction::AccountMeta,
+    pubkey::{Pubkey, PubkeyError},
+};
+
+use crate::{
+    pda::{LstRateCreatePdaArgs, LstRateFindPdaArgs},
+    program as fixed_rate_calculator_program,
+};
+
+/// Uses find_program_address, for use with
+/// - initial creation
+/// - client side
+pub struct LstSolCommonFreeArgs {
+    pub lst_mint: Pubkey,
+}
+
+impl LstSolCommonFreeArgs {
+    pub fn resolve(self) -> LstToSolKeys {
+        self.resolve_inner(fixed_rate_calculator_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> LstToSolKeys {
+        self.resolve_inner(program_id)
+    }
+
+    fn resolve_inner(self, program_id: Pubkey) -> LstToSolKeys {
+        let (lst_rate, _bump) = LstRateFindPdaArgs {
+            program_id,
+            lst_mint: self.lst_mint,
+        }
+        .get_lst_rate_address_and_bump_seed();
+
+        LstToSolKeys {
+            lst_mint: self.lst_mint,
+            lst_rate,
+        }
+    }
+
+    pub fn resolve_sol_to_lst(self) -> SolToLstKeys {
+        let LstToSolKeys { lst_mint, lst_rate } = self.resolve();
+        SolToLstKeys { lst_mint, lst_rate }
+    }
+
+    pub fn resolve_to_account_metas(self) -> [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+        let keys = self.resolve();
+        keys.into()
+    }
+}
+
+pub struct LstSolCommonWithBumpFreeArgs {
+    pub args: LstSolCommonFreeArgs,
+    pub lst_rate_bump: u8,
+}
+
+impl LstSolCommonWithBumpFreeArgs {
+    pub fn resolve(self) -> Result<LstToSolKeys, PubkeyError> {
+        self.resolve_inner(fixed_rate_calculator_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<LstToSolKeys, PubkeyError> {
+        self.resolve_inner(program_id)
+    }
+
+    fn resolve_inner(self, program_id: Pubkey) -> Result<LstToSolKeys, PubkeyError> {
+        let lst_rate = LstRateCreatePdaArgs {
+            find_pda_args: LstRateFindPdaArgs {
+                program_id,
+                lst_mint: self.args.lst_mint,
+            },
+            bump: self.lst_rate_bump,
+        }
+        .get_lst_rate_address()?;
+
+        Ok(LstToSolKeys {
+            lst_mint: self.args.lst_mint,
+            lst_rate,
+        })
+    }
+}
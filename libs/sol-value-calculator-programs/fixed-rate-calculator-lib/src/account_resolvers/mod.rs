@@ -0,0 +1,9 @@
+mod initialize;
+mod lst_sol_common;
+mod set_lst_rate;
+mod set_manager;
+
+pub use initialize::*;
+pub use lst_sol_common::*;
+pub use set_lst_rate::*;
+pub use set_manager::*;
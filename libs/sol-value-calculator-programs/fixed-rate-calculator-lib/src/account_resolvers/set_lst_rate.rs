@@ -0,0 +1,74 @@
+use fixed_rate_calculator_interface::{FixedRateCalculatorError, ProgramState, SetLstRateKeys};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::{LstRateCreatePdaArgs, LstRateFindPdaArgs, ProgramStateFindPdaArgs},
+    program::{self, STATE_ID},
+    utils::try_program_state,
+};
+
+pub struct SetLstRateFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub payer: Pubkey,
+    pub state_acc: S,
+    pub lst_mint: Pubkey,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetLstRateFreeArgs<S> {
+    pub fn resolve(
+        self,
+    ) -> Result<(SetLstRateKeys, LstRateCreatePdaArgs), FixedRateCalculatorError> {
+        self.resolve_inner(STATE_ID, program::ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<(SetLstRateKeys, LstRateCreatePdaArgs), FixedRateCalculatorError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<(SetLstRateKeys, LstRateCreatePdaArgs), FixedRateCalculatorError> {
+        let Self {
+            payer,
+            state_acc,
+            lst_mint,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(FixedRateCalculatorError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        let find_pda_args = LstRateFindPdaArgs {
+            program_id,
+            lst_mint,
+        };
+        let (lst_rate, bump) = find_pda_args.get_lst_rate_address_and_bump_seed();
+
+        Ok((
+            SetLstRateKeys {
+                manager: state.manager,
+                payer,
+                lst_rate,
+                lst_mint,
+                state: state_id,
+                system_program: system_program::ID,
+            },
+            LstRateCreatePdaArgs {
+                find_pda_args,
+                bump,
+            },
+        ))
+    }
+}
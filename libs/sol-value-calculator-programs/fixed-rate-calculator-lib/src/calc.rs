@@ -0,0 +1,116 @@
+use fixed_rate_calculator_interface::LstRate;
+use sanctum_token_ratio::{FloorDiv, ReversibleRatio, U64Ratio, U64ValueRange};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::program_error::ProgramError;
+
+/// Parameters from LstRate required to calculate SOL value.
+///
+/// `num_min` and `num_max` are used to compute the min and max of the returned ranges
+/// respectively and are deliberately not checked against each other so that
+/// faulty calculators returning min > max can be simulated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FixedRateCalc {
+    pub num_min: u64,
+    pub num_max: u64,
+    pub denom: u64,
+}
+
+impl From<LstRate> for FixedRateCalc {
+    fn from(
+        LstRate {
+            num_min,
+            num_max,
+            denom,
+            ..
+        }: LstRate,
+    ) -> Self {
+        Self {
+            num_min,
+            num_max,
+            denom,
+        }
+    }
+}
+
+impl FixedRateCalc {
+    pub const fn min_lst_to_lamports_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        FloorDiv(U64Ratio {
+            num: self.num_min,
+            denom: self.denom,
+        })
+    }
+
+    pub const fn max_lst_to_lamports_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        FloorDiv(U64Ratio {
+            num: self.num_max,
+            denom: self.denom,
+        })
+    }
+}
+
+impl SolValueCalculator for FixedRateCalc {
+    fn calc_lst_to_sol(&self, lst_amount: u64) -> Result<U64ValueRange, ProgramError> {
+        let min = self.min_lst_to_lamports_ratio().apply(lst_amount)?;
+        let max = self.max_lst_to_lamports_ratio().apply(lst_amount)?;
+        Ok(U64ValueRange::from_min_max_unchecked(min, max))
+    }
+
+    fn calc_sol_to_lst(&self, lamports_amount: u64) -> Result<U64ValueRange, ProgramError> {
+        let min = self
+            .max_lst_to_lamports_ratio()
+            .reverse(lamports_amount)?
+            .get_min();
+        let max = self
+            .min_lst_to_lamports_ratio()
+            .reverse(lamports_amount)?
+            .get_max();
+        Ok(U64ValueRange::from_min_max_unchecked(min, max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    prop_compose! {
+        fn single_rate_calc()
+            (denom in 1..=u64::MAX)
+            (num in (denom / 2).max(1)..=denom, denom in Just(denom)) -> FixedRateCalc {
+                FixedRateCalc {
+                    num_min: num,
+                    num_max: num,
+                    denom,
+                }
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn lst_sol_round_trip(lst_amt in 0..=u64::MAX / 2, calc in single_rate_calc()) {
+            let r = calc.calc_lst_to_sol(lst_amt).unwrap();
+            let sol_amt = r.get_min();
+            prop_assert_eq!(sol_amt, r.get_max());
+            let r = calc.calc_sol_to_lst(sol_amt).unwrap();
+
+            // round trip from min should not exceed original
+            let min_round_trip = calc.calc_lst_to_sol(r.get_min()).unwrap();
+            prop_assert!(sol_amt >= min_round_trip.get_max(), "{sol_amt} {}", min_round_trip.get_max());
+
+            // round trip from max should not be smaller than original
+            let max_round_trip = calc.calc_lst_to_sol(r.get_max()).unwrap();
+            prop_assert!(sol_amt <= max_round_trip.get_min(), "{sol_amt} {}", max_round_trip.get_min());
+        }
+
+        #[test]
+        fn faulty_range_unchecked(lst_amt: u64, calc in single_rate_calc()) {
+            let calc = FixedRateCalc {
+                num_min: calc.num_max,
+                num_max: calc.num_max / 2,
+                ..calc
+            };
+            let r = calc.calc_lst_to_sol(lst_amt).unwrap();
+            prop_assert!(r.get_min() >= r.get_max());
+        }
+    }
+}
@@ -0,0 +1,36 @@
+pub mod account_resolvers;
+pub mod calc;
+pub mod pda;
+pub mod utils;
+
+pub use calc::*;
+
+pub mod program {
+    pub const STATE_SIZE: usize = 32;
+    pub const LST_RATE_SIZE: usize = 32;
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<fixed_rate_calculator_interface::ProgramState>(),
+        STATE_SIZE,
+    );
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<fixed_rate_calculator_interface::LstRate>(),
+        LST_RATE_SIZE,
+    );
+
+    sanctum_macros::declare_program_keys!(
+        "89e6Hs9FYsfyQn8pxu5J1qNSwjRfv3cVzMAoEcvbQkKB",
+        [("state", b"state")]
+    );
+}
+
+pub mod initial_constants {
+    pub mod initial_manager {
+        #[cfg(feature = "testing")]
+        sanctum_macros::declare_program_keys!("J5aMuYiKNHUzMTpUS85413DxxvDVjNXs63EXW5twG1Mx", []);
+
+        #[cfg(not(feature = "testing"))]
+        sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
+    }
+}
@@ -0,0 +1,27 @@
+use bytemuck::{try_from_bytes, try_from_bytes_mut};
+
+use fixed_rate_calculator_interface::{FixedRateCalculatorError, LstRate, ProgramState};
+
+pub fn try_program_state(
+    program_state_acc_data: &[u8],
+) -> Result<&ProgramState, FixedRateCalculatorError> {
+    try_from_bytes(program_state_acc_data)
+        .map_err(|_e| FixedRateCalculatorError::InvalidProgramStateData)
+}
+
+pub fn try_program_state_mut(
+    program_state_acc_data: &mut [u8],
+) -> Result<&mut ProgramState, FixedRateCalculatorError> {
+    try_from_bytes_mut(program_state_acc_data)
+        .map_err(|_e| FixedRateCalculatorError::InvalidProgramStateData)
+}
+
+pub fn try_lst_rate(lst_rate_acc_data: &[u8]) -> Result<&LstRate, FixedRateCalculatorError> {
+    try_from_bytes(lst_rate_acc_data).map_err(|_e| FixedRateCalculatorError::UnsupportedLstMint)
+}
+
+pub fn try_lst_rate_mut(
+    lst_rate_acc_data: &mut [u8],
+) -> Result<&mut LstRate, FixedRateCalculatorError> {
+    try_from_bytes_mut(lst_rate_acc_data).map_err(|_e| FixedRateCalculatorError::UnsupportedLstMint)
+}
@@ -0,0 +1,38 @@
+use fixed_rate_calculator_interface::ProgramState;
+use fixed_rate_calculator_lib::program;
+use sanctum_solana_test_utils::{ExtendedProgramTest, IntoAccount};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+
+use crate::{MockLstRate, MockLstRateArgs, MockProgramState};
+
+pub trait FixedRateCalculatorProgramTest {
+    fn add_mock_fixed_rate_program_state(self, program_state: ProgramState) -> Self;
+
+    fn add_mock_lst_rate(self, args: MockLstRateArgs) -> Self;
+}
+
+impl FixedRateCalculatorProgramTest for ProgramTest {
+    fn add_mock_fixed_rate_program_state(self, program_state: ProgramState) -> Self {
+        self.add_account_chained(
+            program::STATE_ID,
+            MockProgramState(program_state).into_account(),
+        )
+    }
+
+    fn add_mock_lst_rate(self, args: MockLstRateArgs) -> Self {
+        let (lst_rate, addr) = args.to_lst_rate_and_addr(program::ID);
+        self.add_account_chained(addr, MockLstRate(lst_rate).into_account())
+    }
+}
+
+/// For scripting rate changes, e.g. value jumps, in the middle of a test
+pub trait FixedRateCalculatorProgramTestContext {
+    fn set_mock_lst_rate(&mut self, args: MockLstRateArgs);
+}
+
+impl FixedRateCalculatorProgramTestContext for ProgramTestContext {
+    fn set_mock_lst_rate(&mut self, args: MockLstRateArgs) {
+        let (lst_rate, addr) = args.to_lst_rate_and_addr(program::ID);
+        self.set_account(&addr, &MockLstRate(lst_rate).into_account().into());
+    }
+}
@@ -0,0 +1,7 @@
+mod fixed_rate_program_test;
+mod lst_rate;
+mod state;
+
+pub use fixed_rate_program_test::*;
+pub use lst_rate::*;
+pub use state::*;
@@ -0,0 +1,70 @@
+use fixed_rate_calculator_interface::LstRate;
+use fixed_rate_calculator_lib::{
+    pda::LstRateFindPdaArgs, program::LST_RATE_SIZE, utils::try_lst_rate_mut,
+};
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, IntoAccount};
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+pub struct MockLstRate(pub LstRate);
+
+impl IntoAccount for MockLstRate {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; LST_RATE_SIZE];
+        let dst = try_lst_rate_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(LST_RATE_SIZE),
+            data,
+            owner: fixed_rate_calculator_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+/// 1 LST = `num_min / denom` to `num_max / denom` SOL.
+///
+/// Setting `num_min > num_max` results in a calculator that returns faulty ranges (min > max).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MockLstRateArgs {
+    pub lst_mint: Pubkey,
+    pub num_min: u64,
+    pub num_max: u64,
+    pub denom: u64,
+}
+
+impl MockLstRateArgs {
+    /// A calculator that returns a single value for the given rate
+    pub const fn single(lst_mint: Pubkey, num: u64, denom: u64) -> Self {
+        Self {
+            lst_mint,
+            num_min: num,
+            num_max: num,
+            denom,
+        }
+    }
+
+    pub fn to_lst_rate_and_addr(&self, program_id: Pubkey) -> (LstRate, Pubkey) {
+        let Self {
+            lst_mint,
+            num_min,
+            num_max,
+            denom,
+        } = self;
+        let (addr, bump) = LstRateFindPdaArgs {
+            program_id,
+            lst_mint: *lst_mint,
+        }
+        .get_lst_rate_address_and_bump_seed();
+        (
+            LstRate {
+                num_min: *num_min,
+                num_max: *num_max,
+                denom: *denom,
+                bump,
+                padding: [0u8; 7],
+            },
+            addr,
+        )
+    }
+}
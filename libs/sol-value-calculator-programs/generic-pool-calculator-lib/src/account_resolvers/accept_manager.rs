@@ -0,0 +1,24 @@
+use generic_pool_calculator_interface::{AcceptManagerKeys, GenericPoolCalculatorError};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{utils::try_calculator_state, GenericPoolSolValCalc};
+
+pub struct AcceptManagerFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub state: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> AcceptManagerFreeArgs<S> {
+    pub fn resolve<P: GenericPoolSolValCalc>(
+        self,
+    ) -> Result<AcceptManagerKeys, GenericPoolCalculatorError> {
+        if *self.state.pubkey() != P::CALCULATOR_STATE_PDA {
+            return Err(GenericPoolCalculatorError::WrongCalculatorStatePda);
+        }
+        let bytes = &self.state.data();
+        let calc_state = try_calculator_state(bytes)?;
+        Ok(AcceptManagerKeys {
+            pending_manager: calc_state.pending_manager,
+            state: P::CALCULATOR_STATE_PDA,
+        })
+    }
+}
@@ -0,0 +1,28 @@
+use generic_pool_calculator_interface::{GenericPoolCalculatorError, MigrateStateKeys};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{utils::try_calculator_state, GenericPoolSolValCalc};
+
+pub struct MigrateStateFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub payer: Pubkey,
+    pub state: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> MigrateStateFreeArgs<S> {
+    pub fn resolve<P: GenericPoolSolValCalc>(
+        self,
+    ) -> Result<MigrateStateKeys, GenericPoolCalculatorError> {
+        if *self.state.pubkey() != P::CALCULATOR_STATE_PDA {
+            return Err(GenericPoolCalculatorError::WrongCalculatorStatePda);
+        }
+        let bytes = &self.state.data();
+        let calc_state = try_calculator_state(bytes)?;
+        Ok(MigrateStateKeys {
+            manager: calc_state.manager,
+            payer: self.payer,
+            state: P::CALCULATOR_STATE_PDA,
+            system_program: system_program::ID,
+        })
+    }
+}
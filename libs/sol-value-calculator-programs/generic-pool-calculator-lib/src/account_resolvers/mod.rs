@@ -1,9 +1,13 @@
+mod accept_manager;
 mod init;
 mod lst_sol_common;
+mod migrate_state;
 mod set_manager;
 mod update_last_upgrade_slot;
 
+pub use accept_manager::*;
 pub use init::*;
 pub use lst_sol_common::*;
+pub use migrate_state::*;
 pub use set_manager::*;
 pub use update_last_upgrade_slot::*;
@@ -14,12 +14,16 @@ pub const CALCULATOR_STATE_SEED: &[u8] = b"state";
 
 // std::mem::size_of is a const fn so we dont technically need this
 // but this assert helps guard against unexpected size changes
-pub const CALCULATOR_STATE_SIZE: usize = 40;
+pub const CALCULATOR_STATE_SIZE: usize = 72;
 const_assert_eq!(
     std::mem::size_of::<CalculatorState>(),
     CALCULATOR_STATE_SIZE
 );
 
+/// Size of CalculatorState before `pending_manager` was added.
+/// Accounts initialized before then stay at this size until MigrateState is run on them.
+pub const LEGACY_CALCULATOR_STATE_SIZE: usize = 40;
+
 /// Implement this trait for individual generic pool SOL value calculator programs
 pub trait GenericPoolSolValCalc {
     /// Program ID of the stake pool program that the calculator program works for
@@ -1,8 +1,10 @@
-use bytemuck::{try_from_bytes, try_from_bytes_mut};
+use bytemuck::{bytes_of_mut, try_from_bytes, try_from_bytes_mut, Zeroable};
 use generic_pool_calculator_interface::{CalculatorState, GenericPoolCalculatorError};
 use solana_program::{bpf_loader_upgradeable::UpgradeableLoaderState, pubkey::Pubkey};
 use solana_readonly_account::ReadonlyAccountData;
 
+use crate::LEGACY_CALCULATOR_STATE_SIZE;
+
 /// Attempts to deserialize a program account and read the
 /// programdata_address contained within
 pub fn read_programdata_addr<D: ReadonlyAccountData>(
@@ -44,18 +46,33 @@ pub fn read_stake_pool_progdata_meta<D: ReadonlyAccountData>(
     }
 }
 
-/// Tries to reinterpret calculator_state_acc_data bytes as a CalculatorState
+/// Tries to read calculator_state_acc_data bytes as a CalculatorState.
+///
+/// Also accepts CalculatorStates that have not been migrated yet,
+/// reading the fields missing from the legacy layout as zeroed
 pub fn try_calculator_state(
     calculator_state_acc_data: &[u8],
-) -> Result<&CalculatorState, GenericPoolCalculatorError> {
+) -> Result<CalculatorState, GenericPoolCalculatorError> {
+    if calculator_state_acc_data.len() == LEGACY_CALCULATOR_STATE_SIZE {
+        let mut calc_state = CalculatorState::zeroed();
+        bytes_of_mut(&mut calc_state)[..LEGACY_CALCULATOR_STATE_SIZE]
+            .copy_from_slice(calculator_state_acc_data);
+        return Ok(calc_state);
+    }
     try_from_bytes(calculator_state_acc_data)
+        .copied()
         .map_err(|_e| GenericPoolCalculatorError::InvalidCalculatorStateData)
 }
 
-/// Tries to reinterpret calculator_state_acc_data bytes as a mutable CalculatorState
+/// Tries to reinterpret calculator_state_acc_data bytes as a mutable CalculatorState.
+///
+/// Errors with StateNotMigrated if the CalculatorState still has the legacy layout
 pub fn try_calculator_state_mut(
     calculator_state_acc_data: &mut [u8],
 ) -> Result<&mut CalculatorState, GenericPoolCalculatorError> {
+    if calculator_state_acc_data.len() == LEGACY_CALCULATOR_STATE_SIZE {
+        return Err(GenericPoolCalculatorError::StateNotMigrated);
+    }
     try_from_bytes_mut(calculator_state_acc_data)
         .map_err(|_e| GenericPoolCalculatorError::InvalidCalculatorStateData)
 }
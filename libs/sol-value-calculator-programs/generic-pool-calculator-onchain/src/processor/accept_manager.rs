@@ -0,0 +1,42 @@
+use generic_pool_calculator_interface::{
+    accept_manager_verify_account_keys, accept_manager_verify_account_privileges,
+    AcceptManagerAccounts, AcceptManagerKeys,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::AcceptManagerFreeArgs, utils::try_calculator_state_mut,
+    GenericPoolSolValCalc,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+/// Call on resolved and checked AcceptManagerAccounts
+pub fn process_accept_manager_unchecked(
+    AcceptManagerAccounts {
+        pending_manager,
+        state,
+    }: AcceptManagerAccounts,
+) -> Result<(), ProgramError> {
+    let mut bytes = state.try_borrow_mut_data()?;
+    let calc_state = try_calculator_state_mut(&mut bytes)?;
+    calc_state.manager = *pending_manager.key;
+    calc_state.pending_manager = Pubkey::default();
+    Ok(())
+}
+
+pub fn verify_accept_manager<'me, 'info, P: GenericPoolSolValCalc>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<AcceptManagerAccounts<'me, 'info>, ProgramError> {
+    let actual: AcceptManagerAccounts = load_accounts(accounts)?;
+
+    let root_keys = AcceptManagerFreeArgs {
+        state: actual.state,
+    };
+    let expected: AcceptManagerKeys = root_keys.resolve::<P>()?;
+
+    accept_manager_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    accept_manager_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,65 @@
+use generic_pool_calculator_interface::{
+    migrate_state_verify_account_keys, migrate_state_verify_account_privileges,
+    MigrateStateAccounts, MigrateStateKeys,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::MigrateStateFreeArgs, utils::try_calculator_state_mut,
+    GenericPoolSolValCalc, CALCULATOR_STATE_SIZE, LEGACY_CALCULATOR_STATE_SIZE,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::ResizableAccount;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use system_program_interface::{transfer_invoke, TransferAccounts, TransferIxArgs};
+
+/// Call on resolved and checked MigrateStateAccounts.
+///
+/// No-op if the CalculatorState has already been migrated
+pub fn process_migrate_state_unchecked(
+    MigrateStateAccounts {
+        manager: _,
+        payer,
+        state,
+        system_program: _,
+    }: MigrateStateAccounts,
+) -> Result<(), ProgramError> {
+    if state.data_len() != LEGACY_CALCULATOR_STATE_SIZE {
+        return Ok(());
+    }
+
+    let lamports_short = state.extend_by(CALCULATOR_STATE_SIZE - LEGACY_CALCULATOR_STATE_SIZE)?;
+    if lamports_short > 0 {
+        transfer_invoke(
+            TransferAccounts {
+                from: payer,
+                to: state,
+            },
+            TransferIxArgs {
+                lamports: lamports_short,
+            },
+        )?;
+    }
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let calc_state = try_calculator_state_mut(&mut bytes)?;
+    calc_state.pending_manager = Pubkey::default();
+    Ok(())
+}
+
+pub fn verify_migrate_state<'me, 'info, P: GenericPoolSolValCalc>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<MigrateStateAccounts<'me, 'info>, ProgramError> {
+    let actual: MigrateStateAccounts = load_accounts(accounts)?;
+
+    let root_keys = MigrateStateFreeArgs {
+        payer: *actual.payer.key,
+        state: actual.state,
+    };
+    let expected: MigrateStateKeys = root_keys.resolve::<P>()?;
+
+    migrate_state_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    migrate_state_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
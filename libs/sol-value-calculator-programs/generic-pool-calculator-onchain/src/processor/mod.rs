@@ -1,7 +1,11 @@
+mod accept_manager;
 mod init;
+mod migrate_state;
 mod set_manager;
 mod update_last_upgrade_slot;
 
+pub use accept_manager::*;
 pub use init::*;
+pub use migrate_state::*;
 pub use set_manager::*;
 pub use update_last_upgrade_slot::*;
@@ -20,7 +20,7 @@ pub fn process_set_manager_unchecked(
 ) -> Result<(), ProgramError> {
     let mut bytes = state.try_borrow_mut_data()?;
     let calc_state = try_calculator_state_mut(&mut bytes)?;
-    calc_state.manager = *new_manager.key;
+    calc_state.pending_manager = *new_manager.key;
     Ok(())
 }
 
@@ -1,3 +1,5 @@
+mod process_accept_manager;
 mod process_init;
+mod process_migrate_state;
 mod process_set_manager;
 mod process_update_last_upgrade_slot;
@@ -0,0 +1,141 @@
+use generic_pool_calculator_interface::{migrate_state_ix, MigrateStateKeys};
+use generic_pool_calculator_lib::{
+    account_resolvers::MigrateStateFreeArgs, utils::try_calculator_state, CALCULATOR_STATE_SIZE,
+    LEGACY_CALCULATOR_STATE_SIZE,
+};
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use sanctum_solana_test_utils::{assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+use solana_program_test::{processor, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use mock_calculator_program::MockCalculatorProgram;
+
+mod mock_calculator_program {
+    use generic_pool_calculator_lib::GenericPoolSolValCalc;
+    use generic_pool_calculator_onchain::processor::{
+        process_migrate_state_unchecked, verify_migrate_state,
+    };
+    use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+    use spl_stake_pool_keys::{spl_stake_pool_program, spl_stake_pool_program_progdata};
+
+    sanctum_macros::declare_program_keys!(
+        "8kbLzKfKo5gjbGQf2HmULGGTXQx6hnfYGJ8inL1zvVeL",
+        [("state", b"state")]
+    );
+
+    pub struct MockCalculatorProgram;
+
+    impl GenericPoolSolValCalc for MockCalculatorProgram {
+        const POOL_PROGRAM_ID: Pubkey = spl_stake_pool_program::ID;
+        const POOL_PROGRAM_PROGDATA_ID: Pubkey = spl_stake_pool_program_progdata::ID;
+        const CALCULATOR_STATE_PDA: Pubkey = STATE_ID;
+        const CALCULATOR_STATE_BUMP: u8 = STATE_BUMP;
+        const ID: Pubkey = ID;
+    }
+
+    pub fn process_instruction(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        _instruction_data: &[u8],
+    ) -> ProgramResult {
+        let checked = verify_migrate_state::<MockCalculatorProgram>(accounts)?;
+        process_migrate_state_unchecked(checked)
+    }
+}
+
+const LAST_UPGRADE_SLOT: u64 = 12345;
+
+fn mock_prog_program_test(manager: Pubkey) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.prefer_bpf(false);
+    program_test.add_program(
+        "mock_calculator_program",
+        mock_calculator_program::ID,
+        processor!(mock_calculator_program::process_instruction),
+    );
+    program_test.add_mock_legacy_calculator_state(MockCalculatorStateAccountArgs {
+        manager,
+        last_upgrade_slot: LAST_UPGRADE_SLOT,
+        pending_manager: Pubkey::default(),
+        owner: mock_calculator_program::ID,
+    })
+}
+
+#[tokio::test]
+async fn migrate_state_basic() {
+    let manager = Keypair::new();
+
+    let program_test = mock_prog_program_test(manager.pubkey());
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let legacy_state = banks_client
+        .get_account_unwrapped(mock_calculator_program::STATE_ID)
+        .await;
+    assert_eq!(legacy_state.data.len(), LEGACY_CALCULATOR_STATE_SIZE);
+    // legacy CalculatorState should still be readable before migration
+    let legacy_calc_state = try_calculator_state(&legacy_state.data).unwrap();
+    assert_eq!(legacy_calc_state.manager, manager.pubkey());
+    assert_eq!(legacy_calc_state.last_upgrade_slot, LAST_UPGRADE_SLOT);
+    assert_eq!(legacy_calc_state.pending_manager, Pubkey::default());
+
+    let free_args = MigrateStateFreeArgs {
+        payer: payer.pubkey(),
+        state: KeyedAccount {
+            pubkey: mock_calculator_program::STATE_ID,
+            account: legacy_state,
+        },
+    };
+    let mut ix = migrate_state_ix(free_args.resolve::<MockCalculatorProgram>().unwrap()).unwrap();
+    ix.program_id = mock_calculator_program::ID;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], recent_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state = banks_client
+        .get_account_unwrapped(mock_calculator_program::STATE_ID)
+        .await;
+    assert_eq!(state.data.len(), CALCULATOR_STATE_SIZE);
+    assert_eq!(
+        state.lamports,
+        banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(CALCULATOR_STATE_SIZE)
+    );
+    let calc_state = try_calculator_state(&state.data).unwrap();
+    assert_eq!(calc_state.manager, manager.pubkey());
+    assert_eq!(calc_state.last_upgrade_slot, LAST_UPGRADE_SLOT);
+    assert_eq!(calc_state.pending_manager, Pubkey::default());
+}
+
+#[tokio::test]
+async fn fail_migrate_state_unauthorized() {
+    let manager = Pubkey::new_unique();
+
+    let program_test = mock_prog_program_test(manager);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let mut ix = migrate_state_ix(MigrateStateKeys {
+        manager: payer.pubkey(),
+        payer: payer.pubkey(),
+        state: mock_calculator_program::STATE_ID,
+        system_program: system_program::ID,
+    })
+    .unwrap();
+    ix.program_id = mock_calculator_program::ID;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], recent_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    let state = banks_client
+        .get_account_unwrapped(mock_calculator_program::STATE_ID)
+        .await;
+    assert_eq!(state.data.len(), LEGACY_CALCULATOR_STATE_SIZE);
+}
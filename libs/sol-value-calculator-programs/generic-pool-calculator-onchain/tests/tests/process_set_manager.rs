@@ -1,11 +1,13 @@
-use generic_pool_calculator_interface::{set_manager_ix, SetManagerKeys};
+use generic_pool_calculator_interface::{
+    set_manager_ix, GenericPoolCalculatorError, SetManagerKeys,
+};
 use generic_pool_calculator_lib::{
     account_resolvers::SetManagerFreeArgs, utils::try_calculator_state,
 };
 use generic_pool_calculator_test_utils::{
     GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
 };
-use sanctum_solana_test_utils::{assert_program_error, ExtendedBanksClient};
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
 use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 use solana_program_test::{processor, BanksClient, ProgramTest};
 use solana_readonly_account::sdk::KeyedAccount;
@@ -57,17 +59,23 @@ fn mock_prog_program_test(manager: Pubkey) -> ProgramTest {
     program_test.add_mock_calculator_state(MockCalculatorStateAccountArgs {
         manager,
         last_upgrade_slot: Default::default(),
+        pending_manager: Pubkey::default(),
         owner: mock_calculator_program::ID,
     })
 }
 
-async fn verify_correct_manager(banks_client: &mut BanksClient, expected_manager: Pubkey) {
+async fn verify_correct_managers(
+    banks_client: &mut BanksClient,
+    expected_manager: Pubkey,
+    expected_pending_manager: Pubkey,
+) {
     let state_account = banks_client
         .get_account_unwrapped(mock_calculator_program::STATE_ID)
         .await;
     let state_bytes = state_account.data;
     let calc_state = try_calculator_state(&state_bytes).unwrap();
     assert_eq!(calc_state.manager, expected_manager);
+    assert_eq!(calc_state.pending_manager, expected_pending_manager);
 }
 
 #[tokio::test]
@@ -81,7 +89,7 @@ async fn set_manager_basic() {
     let mock_state = banks_client
         .get_account_unwrapped(mock_calculator_program::STATE_ID)
         .await;
-    verify_correct_manager(&mut banks_client, manager.pubkey()).await;
+    verify_correct_managers(&mut banks_client, manager.pubkey(), Pubkey::default()).await;
 
     let free_args = SetManagerFreeArgs {
         new_manager,
@@ -96,7 +104,8 @@ async fn set_manager_basic() {
     tx.sign(&[&payer, &manager], recent_blockhash);
     assert!(banks_client.process_transaction(tx).await.is_ok());
 
-    verify_correct_manager(&mut banks_client, new_manager).await;
+    // manager only changes once new_manager accepts
+    verify_correct_managers(&mut banks_client, manager.pubkey(), new_manager).await;
 }
 
 #[tokio::test]
@@ -106,7 +115,7 @@ async fn fail_set_manager_unauthorized_manager() {
     let program_test = mock_prog_program_test(manager);
     let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
 
-    verify_correct_manager(&mut banks_client, manager).await;
+    verify_correct_managers(&mut banks_client, manager, Pubkey::default()).await;
     let mut ix = set_manager_ix(SetManagerKeys {
         manager: payer.pubkey(),
         new_manager: payer.pubkey(),
@@ -128,7 +137,7 @@ async fn fail_set_manager_missing_signature() {
     let program_test = mock_prog_program_test(manager);
     let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
 
-    verify_correct_manager(&mut banks_client, manager).await;
+    verify_correct_managers(&mut banks_client, manager, Pubkey::default()).await;
     let mut ix = set_manager_ix(SetManagerKeys {
         manager,
         new_manager: payer.pubkey(),
@@ -143,3 +152,38 @@ async fn fail_set_manager_missing_signature() {
     let err = banks_client.process_transaction(tx).await.unwrap_err();
     assert_program_error(err, ProgramError::MissingRequiredSignature);
 }
+
+#[tokio::test]
+async fn fail_set_manager_legacy_state() {
+    let manager = Keypair::new();
+    let new_manager = Pubkey::new_unique();
+
+    let mut program_test = ProgramTest::default();
+    program_test.prefer_bpf(false);
+    program_test.add_program(
+        "mock_calculator_program",
+        mock_calculator_program::ID,
+        processor!(mock_calculator_program::process_instruction),
+    );
+    let program_test =
+        program_test.add_mock_legacy_calculator_state(MockCalculatorStateAccountArgs {
+            manager: manager.pubkey(),
+            last_upgrade_slot: Default::default(),
+            pending_manager: Pubkey::default(),
+            owner: mock_calculator_program::ID,
+        });
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let mut ix = set_manager_ix(SetManagerKeys {
+        manager: manager.pubkey(),
+        new_manager,
+        state: mock_calculator_program::STATE_ID,
+    })
+    .unwrap();
+    ix.program_id = mock_calculator_program::ID;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], recent_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, GenericPoolCalculatorError::StateNotMigrated);
+}
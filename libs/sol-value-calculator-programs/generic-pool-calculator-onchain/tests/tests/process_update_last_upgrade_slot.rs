@@ -68,6 +68,7 @@ fn mock_prog_program_test_with_spl(manager: Pubkey) -> ProgramTest {
     program_test.add_mock_calculator_state(MockCalculatorStateAccountArgs {
         manager,
         last_upgrade_slot: INITIAL_LAST_UPGRADE_SLOT,
+        pending_manager: Pubkey::default(),
         owner: mock_calculator_program::ID,
     })
 }
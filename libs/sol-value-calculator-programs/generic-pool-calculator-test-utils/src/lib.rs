@@ -1,13 +1,15 @@
 use generic_pool_calculator_lib::{
     pda::CalculatorStateFindPdaArgs, utils::try_calculator_state_mut, CALCULATOR_STATE_SIZE,
+    LEGACY_CALCULATOR_STATE_SIZE,
 };
 use sanctum_solana_test_utils::{ExtendedProgramTest, IntoAccount};
 use solana_program_test::ProgramTest;
-use solana_sdk::{account::Account, pubkey::Pubkey};
+use solana_sdk::{account::Account, pubkey::Pubkey, rent::Rent};
 
 pub struct MockCalculatorStateAccountArgs {
     pub manager: Pubkey,
     pub last_upgrade_slot: u64,
+    pub pending_manager: Pubkey,
 
     /// GenericPoolCalculator program ID
     pub owner: Pubkey,
@@ -18,12 +20,14 @@ impl IntoAccount for MockCalculatorStateAccountArgs {
         let Self {
             manager,
             last_upgrade_slot,
+            pending_manager,
             owner,
         } = self;
         let mut data = vec![0u8; CALCULATOR_STATE_SIZE];
         let state = try_calculator_state_mut(&mut data).unwrap();
         state.manager = manager;
         state.last_upgrade_slot = last_upgrade_slot;
+        state.pending_manager = pending_manager;
         Account {
             lamports: 1_000_000_000, // just do 1 SOL lol
             data,
@@ -34,8 +38,35 @@ impl IntoAccount for MockCalculatorStateAccountArgs {
     }
 }
 
+impl MockCalculatorStateAccountArgs {
+    /// A CalculatorState that has not been migrated yet,
+    /// with just enough lamports to be rent-exempt at the legacy size.
+    ///
+    /// `pending_manager` is ignored since the legacy layout does not have it
+    pub fn into_legacy_account(self) -> Account {
+        let Self {
+            manager,
+            last_upgrade_slot,
+            owner,
+            ..
+        } = self;
+        let mut data = Vec::with_capacity(LEGACY_CALCULATOR_STATE_SIZE);
+        data.extend_from_slice(manager.as_ref());
+        data.extend_from_slice(&last_upgrade_slot.to_le_bytes());
+        Account {
+            lamports: Rent::default().minimum_balance(LEGACY_CALCULATOR_STATE_SIZE),
+            data,
+            owner,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
 pub trait GenericPoolCalculatorProgramTest {
     fn add_mock_calculator_state(self, args: MockCalculatorStateAccountArgs) -> Self;
+
+    fn add_mock_legacy_calculator_state(self, args: MockCalculatorStateAccountArgs) -> Self;
 }
 
 impl GenericPoolCalculatorProgramTest for ProgramTest {
@@ -46,4 +77,12 @@ impl GenericPoolCalculatorProgramTest for ProgramTest {
         .get_calculator_state_address_and_bump_seed();
         self.add_account_chained(addr, args.into_account())
     }
+
+    fn add_mock_legacy_calculator_state(self, args: MockCalculatorStateAccountArgs) -> Self {
+        let (addr, _bump) = CalculatorStateFindPdaArgs {
+            program_id: args.owner,
+        }
+        .get_calculator_state_address_and_bump_seed();
+        self.add_account_chained(addr, args.into_legacy_account())
+    }
 }
@@ -30,6 +30,18 @@ pub fn process_instruction(
         FlatFeeProgramIx::PriceLpTokensToRedeem(args) => {
             process_price_lp_tokens_to_redeem(accounts, args)
         }
+        FlatFeeProgramIx::MigrateLstFee => process_migrate_lst_fee(accounts),
+        FlatFeeProgramIx::MigrateState => process_migrate_state(accounts),
+        FlatFeeProgramIx::SetEpochSurcharge(args) => process_set_epoch_surcharge(accounts, args),
+        FlatFeeProgramIx::SetLstFeeRamp(args) => process_set_lst_fee_ramp(accounts, args),
+        FlatFeeProgramIx::SetLstLpDepositFee(args) => {
+            process_set_lst_lp_deposit_fee(accounts, args)
+        }
+        FlatFeeProgramIx::SetLpDepositFee(args) => process_set_lp_deposit_fee(accounts, args),
+        FlatFeeProgramIx::RemovePairFee => process_remove_pair_fee(accounts),
+        FlatFeeProgramIx::SetPairFee(args) => process_set_pair_fee(accounts, args),
+        FlatFeeProgramIx::AddPairFee(args) => process_add_pair_fee(accounts, args),
+        FlatFeeProgramIx::AcceptManager => process_accept_manager(accounts),
         FlatFeeProgramIx::SetLpWithdrawalFee(args) => process_set_lp_withdrawal_fee(accounts, args),
         FlatFeeProgramIx::SetLstFee(args) => process_set_lst_fee(accounts, args),
         FlatFeeProgramIx::RemoveLst => process_remove_lst(accounts),
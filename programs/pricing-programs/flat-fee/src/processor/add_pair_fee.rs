@@ -0,0 +1,87 @@
+use flat_fee_interface::{
+    add_pair_fee_verify_account_keys, add_pair_fee_verify_account_privileges, AddPairFeeAccounts,
+    AddPairFeeIxArgs, AddPairFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::AddPairFeeFreeArgs, fee_bound::verify_signed_fee_bps_bound,
+    pda::PairFeeAccountCreatePdaArgs, program, utils::try_pair_fee_account_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_s_common::token::verify_tokenkeg_or_22_mint;
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use system_program_interface::CreateAccountAccounts;
+
+pub fn process_add_pair_fee(accounts: &[AccountInfo], args: AddPairFeeIxArgs) -> ProgramResult {
+    let (
+        AddPairFeeAccounts {
+            payer,
+            pair_fee_acc,
+            ..
+        },
+        AddPairFeeIxArgs { fee_bps },
+        create_pda_args,
+    ) = verify_add_pair_fee(accounts, args)?;
+
+    init_rent_exempt_account_invoke_signed(
+        CreateAccountAccounts {
+            from: payer,
+            to: pair_fee_acc,
+        },
+        InitRentExemptAccountArgs {
+            space: program::PAIR_FEE_ACCOUNT_SIZE,
+            owner: program::ID,
+        },
+        &[create_pda_args.to_signer_seeds().as_slice()],
+    )?;
+
+    let mut bytes = pair_fee_acc.try_borrow_mut_data()?;
+    let pair_fee_acc = try_pair_fee_account_mut(&mut bytes)?;
+
+    pair_fee_acc.bump = create_pda_args.bump;
+    pair_fee_acc.fee_bps = fee_bps;
+    pair_fee_acc.input_lst_mint = create_pda_args.find_pda_args.input_lst_mint;
+    pair_fee_acc.output_lst_mint = create_pda_args.find_pda_args.output_lst_mint;
+
+    Ok(())
+}
+
+fn verify_add_pair_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    args: AddPairFeeIxArgs,
+) -> Result<
+    (
+        AddPairFeeAccounts<'me, 'info>,
+        AddPairFeeIxArgs,
+        PairFeeAccountCreatePdaArgs,
+    ),
+    ProgramError,
+> {
+    let actual: AddPairFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = AddPairFeeFreeArgs {
+        payer: *actual.payer.key,
+        state_acc: actual.state,
+        input_lst_mint: *actual.input_lst_mint.key,
+        output_lst_mint: *actual.output_lst_mint.key,
+    };
+    let (expected, pair_fee_account_create_pda_args): (
+        AddPairFeeKeys,
+        PairFeeAccountCreatePdaArgs,
+    ) = free_args.resolve()?;
+
+    add_pair_fee_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    add_pair_fee_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_tokenkeg_or_22_mint(actual.input_lst_mint)?;
+    verify_tokenkeg_or_22_mint(actual.output_lst_mint)?;
+    verify_signed_fee_bps_bound(args.fee_bps)?;
+
+    Ok((actual, args, pair_fee_account_create_pda_args))
+}
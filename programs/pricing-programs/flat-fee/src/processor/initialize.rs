@@ -4,7 +4,10 @@ use flat_fee_interface::{
 };
 use flat_fee_lib::{
     account_resolvers::InitializeFreeArgs,
-    initial_constants::{initial_manager, INITIAL_LP_WITHDRAWAL_FEE_BPS},
+    initial_constants::{
+        initial_manager, INITIAL_EPOCH_SURCHARGE_BPS, INITIAL_EPOCH_SURCHARGE_SLOTS,
+        INITIAL_LP_DEPOSIT_FEE_BPS, INITIAL_LP_WITHDRAWAL_FEE_BPS,
+    },
     program,
     utils::try_program_state_mut,
 };
@@ -38,6 +41,9 @@ pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
 
     state.manager = initial_manager::ID;
     state.lp_withdrawal_fee_bps = INITIAL_LP_WITHDRAWAL_FEE_BPS;
+    state.lp_deposit_fee_bps = INITIAL_LP_DEPOSIT_FEE_BPS;
+    state.epoch_surcharge_bps = INITIAL_EPOCH_SURCHARGE_BPS;
+    state.epoch_surcharge_slots = INITIAL_EPOCH_SURCHARGE_SLOTS;
 
     Ok(())
 }
@@ -0,0 +1,69 @@
+use flat_fee_interface::{
+    migrate_lst_fee_verify_account_keys, migrate_lst_fee_verify_account_privileges,
+    MigrateLstFeeAccounts, MigrateLstFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::MigrateLstFeeFreeArgs,
+    program::{FEE_ACCOUNT_SIZE, LEGACY_FEE_ACCOUNT_SIZE},
+    utils::try_fee_account_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::ResizableAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use system_program_interface::{transfer_invoke, TransferAccounts, TransferIxArgs};
+
+/// No-op if the FeeAccount has already been migrated
+pub fn process_migrate_lst_fee(accounts: &[AccountInfo]) -> ProgramResult {
+    let MigrateLstFeeAccounts { payer, fee_acc, .. } = verify_migrate_lst_fee(accounts)?;
+
+    if fee_acc.data_len() != LEGACY_FEE_ACCOUNT_SIZE {
+        return Ok(());
+    }
+
+    let lamports_short = fee_acc.extend_by(FEE_ACCOUNT_SIZE - LEGACY_FEE_ACCOUNT_SIZE)?;
+    if lamports_short > 0 {
+        transfer_invoke(
+            TransferAccounts {
+                from: payer,
+                to: fee_acc,
+            },
+            TransferIxArgs {
+                lamports: lamports_short,
+            },
+        )?;
+    }
+
+    let mut bytes = fee_acc.try_borrow_mut_data()?;
+    let fee_acc = try_fee_account_mut(&mut bytes)?;
+    fee_acc.has_lp_deposit_fee_bps_override = 0;
+    fee_acc.lp_deposit_fee_bps_override = 0;
+    fee_acc.ramp_start_slot = 0;
+    fee_acc.ramp_end_slot = 0;
+    fee_acc.ramp_target_input_fee_bps = 0;
+    fee_acc.ramp_target_output_fee_bps = 0;
+    fee_acc.ramp_padding = [0; 4];
+
+    Ok(())
+}
+
+fn verify_migrate_lst_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<MigrateLstFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: MigrateLstFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = MigrateLstFeeFreeArgs {
+        payer: *actual.payer.key,
+        fee_acc: *actual.fee_acc.key,
+        state_acc: actual.state,
+    };
+    let expected: MigrateLstFeeKeys = free_args.resolve()?;
+
+    migrate_lst_fee_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    migrate_lst_fee_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
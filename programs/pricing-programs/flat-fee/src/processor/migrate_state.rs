@@ -0,0 +1,69 @@
+use flat_fee_interface::{
+    migrate_state_verify_account_keys, migrate_state_verify_account_privileges,
+    MigrateStateAccounts, MigrateStateKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::MigrateStateFreeArgs,
+    initial_constants::{
+        INITIAL_EPOCH_SURCHARGE_BPS, INITIAL_EPOCH_SURCHARGE_SLOTS, INITIAL_LP_DEPOSIT_FEE_BPS,
+    },
+    program::{LEGACY_STATE_SIZE, STATE_SIZE},
+    utils::try_program_state_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::ResizableAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use system_program_interface::{transfer_invoke, TransferAccounts, TransferIxArgs};
+
+/// No-op if the ProgramState has already been migrated
+pub fn process_migrate_state(accounts: &[AccountInfo]) -> ProgramResult {
+    let MigrateStateAccounts { payer, state, .. } = verify_migrate_state(accounts)?;
+
+    if state.data_len() != LEGACY_STATE_SIZE {
+        return Ok(());
+    }
+
+    let lamports_short = state.extend_by(STATE_SIZE - LEGACY_STATE_SIZE)?;
+    if lamports_short > 0 {
+        transfer_invoke(
+            TransferAccounts {
+                from: payer,
+                to: state,
+            },
+            TransferIxArgs {
+                lamports: lamports_short,
+            },
+        )?;
+    }
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+    state.pending_manager = Pubkey::default();
+    state.lp_deposit_fee_bps = INITIAL_LP_DEPOSIT_FEE_BPS;
+    state.epoch_surcharge_bps = INITIAL_EPOCH_SURCHARGE_BPS;
+    state.epoch_surcharge_slots = INITIAL_EPOCH_SURCHARGE_SLOTS;
+
+    Ok(())
+}
+
+fn verify_migrate_state<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<MigrateStateAccounts<'me, 'info>, ProgramError> {
+    let actual: MigrateStateAccounts = load_accounts(accounts)?;
+
+    let free_args = MigrateStateFreeArgs {
+        payer: *actual.payer.key,
+        state_acc: actual.state,
+    };
+    let expected: MigrateStateKeys = free_args.resolve()?;
+
+    migrate_state_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    migrate_state_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
@@ -1,21 +1,132 @@
+use flat_fee_lib::{
+    pda::{PairFeeAccountCreatePdaArgs, PairFeeAccountFindPdaArgs},
+    program,
+    utils::try_pair_fee_account,
+};
+use sanctum_misc_utils::log_and_return_wrong_acc_err;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+mod accept_manager;
 mod add_lst;
+mod add_pair_fee;
 mod initialize;
+mod migrate_lst_fee;
+mod migrate_state;
 mod price_exact_in;
 mod price_exact_out;
 mod price_lp_tokens_to_mint;
 mod price_lp_tokens_to_redeem;
 mod remove_lst;
+mod remove_pair_fee;
+mod set_epoch_surcharge;
+mod set_lp_deposit_fee;
 mod set_lp_withdrawal_fee;
 mod set_lst_fee;
+mod set_lst_fee_ramp;
+mod set_lst_lp_deposit_fee;
 mod set_manager;
+mod set_pair_fee;
 
+pub use accept_manager::*;
 pub use add_lst::*;
+pub use add_pair_fee::*;
 pub use initialize::*;
+pub use migrate_lst_fee::*;
+pub use migrate_state::*;
 pub use price_exact_in::*;
 pub use price_exact_out::*;
 pub use price_lp_tokens_to_mint::*;
 pub use price_lp_tokens_to_redeem::*;
 pub use remove_lst::*;
+pub use remove_pair_fee::*;
+pub use set_epoch_surcharge::*;
+pub use set_lp_deposit_fee::*;
 pub use set_lp_withdrawal_fee::*;
 pub use set_lst_fee::*;
+pub use set_lst_fee_ramp::*;
+pub use set_lst_lp_deposit_fee::*;
 pub use set_manager::*;
+pub use set_pair_fee::*;
+
+/// Number of accounts of PriceExactIn/PriceExactOut's version 1.0.0 account layout,
+/// which does not include `state`
+const LEGACY_PRICE_EXACT_IX_ACCOUNTS_LEN: usize = 4;
+
+/// PriceExactIn/PriceExactOut's verified accounts required for pricing
+struct PriceExactAccounts<'me, 'info> {
+    input_fee_acc: &'me AccountInfo<'info>,
+    output_fee_acc: &'me AccountInfo<'info>,
+
+    /// None for clients using the version 1.0.0 account layout,
+    /// which are not charged the epoch surcharge
+    state: Option<&'me AccountInfo<'info>>,
+
+    pair_fee_bps: Option<i16>,
+}
+
+/// Verifies the accounts of PriceExactIn/PriceExactOut's version 1.0.0 account layout
+/// against `expected_keys`, then checks if they are followed by `state`
+/// and the optional PairFeeAccount.
+///
+/// `accounts` must contain at least [`LEGACY_PRICE_EXACT_IX_ACCOUNTS_LEN`] accounts.
+fn verify_price_exact_optional_accs<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    expected_keys: [Pubkey; LEGACY_PRICE_EXACT_IX_ACCOUNTS_LEN],
+    expected_state: Pubkey,
+) -> Result<PriceExactAccounts<'me, 'info>, ProgramError> {
+    for (actual, expected) in accounts.iter().zip(expected_keys) {
+        if *actual.key != expected {
+            return Err(log_and_return_wrong_acc_err((*actual.key, expected)));
+        }
+    }
+    let [input_lst_mint, output_lst_mint, ..] = expected_keys;
+    let state = accounts
+        .get(LEGACY_PRICE_EXACT_IX_ACCOUNTS_LEN)
+        .filter(|a| *a.key == expected_state);
+    let pair_fee_acc =
+        accounts.get(LEGACY_PRICE_EXACT_IX_ACCOUNTS_LEN + usize::from(state.is_some()));
+    let pair_fee_bps = verify_optional_pair_fee_acc(pair_fee_acc, input_lst_mint, output_lst_mint)?;
+    Ok(PriceExactAccounts {
+        input_fee_acc: &accounts[2],
+        output_fee_acc: &accounts[3],
+        state,
+        pair_fee_bps,
+    })
+}
+
+/// Returns the fee of PriceExactIn/PriceExactOut's optional trailing PairFeeAccount.
+///
+/// The S controller forwards every trailing account of its instruction to the pricing program,
+/// e.g. the transfer hook accounts of the LSTs, so the account following the fixed accounts
+/// is only treated as a PairFeeAccount if it is the input-output pair's initialized PairFeeAccount
+/// and ignored otherwise.
+///
+/// Uses the bump stored in the account to avoid find_program_address().
+fn verify_optional_pair_fee_acc(
+    pair_fee_acc: Option<&AccountInfo>,
+    input_lst_mint: Pubkey,
+    output_lst_mint: Pubkey,
+) -> Result<Option<i16>, ProgramError> {
+    let pair_fee_acc = match pair_fee_acc {
+        Some(a) if *a.owner == program::ID => a,
+        _ => return Ok(None),
+    };
+    let pair_fee_acc_bytes = pair_fee_acc.try_borrow_data()?;
+    let pair_fee_acc_data = match try_pair_fee_account(&pair_fee_acc_bytes) {
+        Ok(d) => d,
+        Err(_e) => return Ok(None),
+    };
+    let expected = PairFeeAccountCreatePdaArgs {
+        find_pda_args: PairFeeAccountFindPdaArgs {
+            program_id: program::ID,
+            input_lst_mint,
+            output_lst_mint,
+        },
+        bump: pair_fee_acc_data.bump,
+    }
+    .get_pair_fee_account_address();
+    match expected {
+        Ok(expected) if *pair_fee_acc.key == expected => Ok(Some(pair_fee_acc_data.fee_bps)),
+        _ => Ok(None),
+    }
+}
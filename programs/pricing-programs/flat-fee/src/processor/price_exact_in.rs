@@ -1,25 +1,31 @@
-use flat_fee_interface::{
-    price_exact_in_verify_account_keys, PriceExactInAccounts, PriceExactInIxArgs, PriceExactInKeys,
-};
+use flat_fee_interface::{PriceExactInIxArgs, PriceExactInKeys};
 use flat_fee_lib::{
     account_resolvers::{PriceExactInFreeArgs, PriceExactInWithBumpFreeArgs},
-    calc::{calculate_price_exact_in, CalculatePriceExactInArgs},
-    utils::try_fee_account,
+    calc::{
+        calculate_price_exact_in, epoch_surcharge_bps_at_slot, lst_fee_bps_at_slot,
+        CalculatePriceExactInArgs,
+    },
+    utils::{try_fee_account, try_program_state},
 };
-use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
-    program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    epoch_schedule::EpochSchedule, program::set_return_data, program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+use super::{
+    verify_price_exact_optional_accs, PriceExactAccounts, LEGACY_PRICE_EXACT_IX_ACCOUNTS_LEN,
 };
 
 pub fn process_price_exact_in(
     accounts: &[AccountInfo],
     PriceExactInIxArgs { sol_value, .. }: PriceExactInIxArgs,
 ) -> ProgramResult {
-    let PriceExactInAccounts {
+    let PriceExactAccounts {
         input_fee_acc,
         output_fee_acc,
-        ..
+        state,
+        pair_fee_bps,
     } = verify_price_exact_in(accounts)?;
 
     let input_fee_acc_bytes = input_fee_acc.try_borrow_data()?;
@@ -27,9 +33,23 @@ pub fn process_price_exact_in(
     let output_fee_acc_bytes = output_fee_acc.try_borrow_data()?;
     let output_fee_acc = try_fee_account(&output_fee_acc_bytes)?;
 
+    let slot = Clock::get()?.slot;
+    let input_fee_bps = lst_fee_bps_at_slot(&input_fee_acc, slot).input_fee_bps;
+    let output_fee_bps = lst_fee_bps_at_slot(&output_fee_acc, slot).output_fee_bps;
+    let epoch_surcharge_bps = match state {
+        Some(state) => {
+            let state_bytes = state.try_borrow_data()?;
+            let state = try_program_state(&state_bytes)?;
+            epoch_surcharge_bps_at_slot(&state, &EpochSchedule::get()?, slot)
+        }
+        None => 0,
+    };
+
     let result = calculate_price_exact_in(CalculatePriceExactInArgs {
-        input_fee_bps: input_fee_acc.input_fee_bps,
-        output_fee_bps: output_fee_acc.output_fee_bps,
+        input_fee_bps,
+        output_fee_bps,
+        pair_fee_bps,
+        epoch_surcharge_bps,
         in_sol_value: sol_value,
     })?;
     let result_le = result.to_le_bytes();
@@ -40,25 +60,37 @@ pub fn process_price_exact_in(
 
 fn verify_price_exact_in<'me, 'info>(
     accounts: &'me [AccountInfo<'info>],
-) -> Result<PriceExactInAccounts<'me, 'info>, ProgramError> {
-    let actual: PriceExactInAccounts = load_accounts(accounts)?;
+) -> Result<PriceExactAccounts<'me, 'info>, ProgramError> {
+    let Some([input_lst_mint, output_lst_mint, input_fee_acc, output_fee_acc]) =
+        accounts.get(..LEGACY_PRICE_EXACT_IX_ACCOUNTS_LEN)
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
 
-    let input_fee_acc_bytes = actual.input_fee_acc.try_borrow_data()?;
+    let input_fee_acc_bytes = input_fee_acc.try_borrow_data()?;
     let input_fee_acc_bump = try_fee_account(&input_fee_acc_bytes)?.bump;
-    let output_fee_acc_bytes = actual.output_fee_acc.try_borrow_data()?;
+    let output_fee_acc_bytes = output_fee_acc.try_borrow_data()?;
     let output_fee_acc_bump = try_fee_account(&output_fee_acc_bytes)?.bump;
 
     let free_args = PriceExactInWithBumpFreeArgs {
         args: PriceExactInFreeArgs {
-            input_lst_mint: *actual.input_lst_mint.key,
-            output_lst_mint: *actual.output_lst_mint.key,
+            input_lst_mint: *input_lst_mint.key,
+            output_lst_mint: *output_lst_mint.key,
         },
         input_fee_acc_bump,
         output_fee_acc_bump,
+        pair_fee_acc_bump: None,
     };
     let expected: PriceExactInKeys = free_args.resolve()?;
 
-    price_exact_in_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
-
-    Ok(actual)
+    verify_price_exact_optional_accs(
+        accounts,
+        [
+            expected.input_lst_mint,
+            expected.output_lst_mint,
+            expected.input_fee_acc,
+            expected.output_fee_acc,
+        ],
+        expected.state,
+    )
 }
@@ -1,26 +1,31 @@
-use flat_fee_interface::{
-    price_exact_out_verify_account_keys, PriceExactOutAccounts, PriceExactOutIxArgs,
-    PriceExactOutKeys,
-};
+use flat_fee_interface::{PriceExactOutIxArgs, PriceExactOutKeys};
 use flat_fee_lib::{
     account_resolvers::{PriceExactOutFreeArgs, PriceExactOutWithBumpFreeArgs},
-    calc::{calculate_price_exact_out, CalculatePriceExactOutArgs},
-    utils::try_fee_account,
+    calc::{
+        calculate_price_exact_out, epoch_surcharge_bps_at_slot, lst_fee_bps_at_slot,
+        CalculatePriceExactOutArgs,
+    },
+    utils::{try_fee_account, try_program_state},
 };
-use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
-    program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    epoch_schedule::EpochSchedule, program::set_return_data, program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+use super::{
+    verify_price_exact_optional_accs, PriceExactAccounts, LEGACY_PRICE_EXACT_IX_ACCOUNTS_LEN,
 };
 
 pub fn process_price_exact_out(
     accounts: &[AccountInfo],
     PriceExactOutIxArgs { sol_value, .. }: PriceExactOutIxArgs,
 ) -> ProgramResult {
-    let PriceExactOutAccounts {
+    let PriceExactAccounts {
         input_fee_acc,
         output_fee_acc,
-        ..
+        state,
+        pair_fee_bps,
     } = verify_price_exact_out(accounts)?;
 
     let input_fee_acc_bytes = input_fee_acc.try_borrow_data()?;
@@ -28,9 +33,23 @@ pub fn process_price_exact_out(
     let output_fee_acc_bytes = output_fee_acc.try_borrow_data()?;
     let output_fee_acc = try_fee_account(&output_fee_acc_bytes)?;
 
+    let slot = Clock::get()?.slot;
+    let input_fee_bps = lst_fee_bps_at_slot(&input_fee_acc, slot).input_fee_bps;
+    let output_fee_bps = lst_fee_bps_at_slot(&output_fee_acc, slot).output_fee_bps;
+    let epoch_surcharge_bps = match state {
+        Some(state) => {
+            let state_bytes = state.try_borrow_data()?;
+            let state = try_program_state(&state_bytes)?;
+            epoch_surcharge_bps_at_slot(&state, &EpochSchedule::get()?, slot)
+        }
+        None => 0,
+    };
+
     let result = calculate_price_exact_out(CalculatePriceExactOutArgs {
-        input_fee_bps: input_fee_acc.input_fee_bps,
-        output_fee_bps: output_fee_acc.output_fee_bps,
+        input_fee_bps,
+        output_fee_bps,
+        pair_fee_bps,
+        epoch_surcharge_bps,
         out_sol_value: sol_value,
     })?;
     let result_le = result.to_le_bytes();
@@ -41,25 +60,37 @@ pub fn process_price_exact_out(
 
 fn verify_price_exact_out<'me, 'info>(
     accounts: &'me [AccountInfo<'info>],
-) -> Result<PriceExactOutAccounts<'me, 'info>, ProgramError> {
-    let actual: PriceExactOutAccounts = load_accounts(accounts)?;
+) -> Result<PriceExactAccounts<'me, 'info>, ProgramError> {
+    let Some([input_lst_mint, output_lst_mint, input_fee_acc, output_fee_acc]) =
+        accounts.get(..LEGACY_PRICE_EXACT_IX_ACCOUNTS_LEN)
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
 
-    let input_fee_acc_bytes = actual.input_fee_acc.try_borrow_data()?;
+    let input_fee_acc_bytes = input_fee_acc.try_borrow_data()?;
     let input_fee_acc_bump = try_fee_account(&input_fee_acc_bytes)?.bump;
-    let output_fee_acc_bytes = actual.output_fee_acc.try_borrow_data()?;
+    let output_fee_acc_bytes = output_fee_acc.try_borrow_data()?;
     let output_fee_acc_bump = try_fee_account(&output_fee_acc_bytes)?.bump;
 
     let free_args = PriceExactOutWithBumpFreeArgs {
         args: PriceExactOutFreeArgs {
-            input_lst_mint: *actual.input_lst_mint.key,
-            output_lst_mint: *actual.output_lst_mint.key,
+            input_lst_mint: *input_lst_mint.key,
+            output_lst_mint: *output_lst_mint.key,
         },
         input_fee_acc_bump,
         output_fee_acc_bump,
+        pair_fee_acc_bump: None,
     };
     let expected: PriceExactOutKeys = free_args.resolve()?;
 
-    price_exact_out_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
-
-    Ok(actual)
+    verify_price_exact_optional_accs(
+        accounts,
+        [
+            expected.input_lst_mint,
+            expected.output_lst_mint,
+            expected.input_fee_acc,
+            expected.output_fee_acc,
+        ],
+        expected.state,
+    )
 }
@@ -1,15 +1,83 @@
-use flat_fee_interface::PriceLpTokensToMintIxArgs;
+use flat_fee_interface::{
+    price_lp_tokens_to_mint_verify_account_keys, PriceLpTokensToMintAccounts,
+    PriceLpTokensToMintIxArgs, PriceLpTokensToMintKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::{PriceLpTokensToMintFreeArgs, PriceLpTokensToMintWithBumpFreeArgs},
+    calc::{
+        calculate_price_lp_tokens_to_mint, effective_lp_deposit_fee_bps,
+        epoch_surcharge_bps_at_slot, lp_fee_bps_with_epoch_surcharge,
+    },
+    program::STATE_ID,
+    utils::{try_fee_account, try_program_state},
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    epoch_schedule::EpochSchedule, program::set_return_data, program_error::ProgramError,
+    sysvar::Sysvar,
 };
 
-// flat fee program doesnt charge add liquidity fees,
-// simply return passed in sol_value
 pub fn process_price_lp_tokens_to_mint(
-    _accounts: &[AccountInfo],
+    accounts: &[AccountInfo],
     PriceLpTokensToMintIxArgs { sol_value, .. }: PriceLpTokensToMintIxArgs,
 ) -> ProgramResult {
-    let result_le = sol_value.to_le_bytes();
+    let lp_deposit_fee_bps = match verify_price_lp_tokens_to_mint(accounts)? {
+        Some(PriceLpTokensToMintAccounts {
+            state,
+            input_fee_acc,
+            ..
+        }) => {
+            let state_bytes = state.try_borrow_data()?;
+            let state = try_program_state(&state_bytes)?;
+            let input_fee_acc_bytes = input_fee_acc.try_borrow_data()?;
+            let input_fee_acc = try_fee_account(&input_fee_acc_bytes)?;
+
+            let epoch_surcharge_bps =
+                epoch_surcharge_bps_at_slot(&state, &EpochSchedule::get()?, Clock::get()?.slot);
+            lp_fee_bps_with_epoch_surcharge(
+                effective_lp_deposit_fee_bps(&state, &input_fee_acc),
+                epoch_surcharge_bps,
+            )?
+        }
+        // version 1.0.0 account layout: LP tokens are minted without a fee
+        None => 0,
+    };
+
+    let result = calculate_price_lp_tokens_to_mint(lp_deposit_fee_bps, sol_value)?;
+    let result_le = result.to_le_bytes();
     set_return_data(&result_le);
+
     Ok(())
 }
+
+/// Returns None if `accounts` is the version 1.0.0 account layout,
+/// which only consists of `input_lst_mint`, identified by the absence of `state`
+fn verify_price_lp_tokens_to_mint<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<Option<PriceLpTokensToMintAccounts<'me, 'info>>, ProgramError> {
+    if accounts.is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    if accounts.get(1).map_or(true, |state| *state.key != STATE_ID) {
+        return Ok(None);
+    }
+
+    let actual: PriceLpTokensToMintAccounts = load_accounts(accounts)?;
+
+    let input_fee_acc_bytes = actual.input_fee_acc.try_borrow_data()?;
+    let input_fee_acc_bump = try_fee_account(&input_fee_acc_bytes)?.bump;
+
+    let free_args = PriceLpTokensToMintWithBumpFreeArgs {
+        args: PriceLpTokensToMintFreeArgs {
+            input_lst_mint: *actual.input_lst_mint.key,
+        },
+        input_fee_acc_bump,
+    };
+    let expected: PriceLpTokensToMintKeys = free_args.resolve()?;
+
+    price_lp_tokens_to_mint_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+
+    Ok(Some(actual))
+}
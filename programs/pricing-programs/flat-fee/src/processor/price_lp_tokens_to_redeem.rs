@@ -3,13 +3,18 @@ use flat_fee_interface::{
     PriceLpTokensToRedeemIxArgs, PriceLpTokensToRedeemKeys,
 };
 use flat_fee_lib::{
-    account_resolvers::PriceLpTokensToRedeemFreeArgs, calc::calculate_price_lp_tokens_to_redeem,
+    account_resolvers::PriceLpTokensToRedeemFreeArgs,
+    calc::{
+        calculate_price_lp_tokens_to_redeem, epoch_surcharge_bps_at_slot,
+        lp_fee_bps_with_epoch_surcharge,
+    },
     utils::try_program_state,
 };
 use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
-    program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    epoch_schedule::EpochSchedule, program::set_return_data, program_error::ProgramError,
+    sysvar::Sysvar,
 };
 
 pub fn process_price_lp_tokens_to_redeem(
@@ -21,7 +26,12 @@ pub fn process_price_lp_tokens_to_redeem(
     let bytes = state.try_borrow_data()?;
     let state = try_program_state(&bytes)?;
 
-    let result = calculate_price_lp_tokens_to_redeem(state.lp_withdrawal_fee_bps, sol_value)?;
+    let epoch_surcharge_bps =
+        epoch_surcharge_bps_at_slot(&state, &EpochSchedule::get()?, Clock::get()?.slot);
+    let lp_withdrawal_fee_bps =
+        lp_fee_bps_with_epoch_surcharge(state.lp_withdrawal_fee_bps, epoch_surcharge_bps)?;
+
+    let result = calculate_price_lp_tokens_to_redeem(lp_withdrawal_fee_bps, sol_value)?;
     let result_le = result.to_le_bytes();
     set_return_data(&result_le);
 
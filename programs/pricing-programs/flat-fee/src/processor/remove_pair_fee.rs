@@ -0,0 +1,46 @@
+use flat_fee_interface::{
+    remove_pair_fee_verify_account_keys, remove_pair_fee_verify_account_privileges,
+    RemovePairFeeAccounts, RemovePairFeeKeys,
+};
+use flat_fee_lib::account_resolvers::RemovePairFeeFreeArgs;
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{close_account, CloseAccountAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_remove_pair_fee(accounts: &[AccountInfo]) -> ProgramResult {
+    let RemovePairFeeAccounts {
+        pair_fee_acc,
+        refund_rent_to,
+        ..
+    } = verify_remove_pair_fee(accounts)?;
+
+    close_account(CloseAccountAccounts {
+        refund_rent_to,
+        close: pair_fee_acc,
+    })?;
+
+    Ok(())
+}
+
+fn verify_remove_pair_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<RemovePairFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: RemovePairFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = RemovePairFeeFreeArgs {
+        refund_rent_to: *actual.refund_rent_to.key,
+        input_lst_mint: *actual.input_lst_mint.key,
+        output_lst_mint: *actual.output_lst_mint.key,
+        state_acc: actual.state,
+    };
+    let expected: RemovePairFeeKeys = free_args.resolve()?;
+
+    remove_pair_fee_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    remove_pair_fee_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,52 @@
+use flat_fee_interface::{
+    set_epoch_surcharge_verify_account_keys, set_epoch_surcharge_verify_account_privileges,
+    SetEpochSurchargeAccounts, SetEpochSurchargeIxArgs, SetEpochSurchargeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetEpochSurchargeFreeArgs, fee_bound::verify_unsigned_fee_bps_bound,
+    utils::try_program_state_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_set_epoch_surcharge(
+    accounts: &[AccountInfo],
+    args: SetEpochSurchargeIxArgs,
+) -> ProgramResult {
+    let SetEpochSurchargeAccounts { state, .. } = verify_set_epoch_surcharge(accounts, &args)?;
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+    state.epoch_surcharge_bps = args.epoch_surcharge_bps;
+    state.epoch_surcharge_slots = args.epoch_surcharge_slots;
+
+    Ok(())
+}
+
+fn verify_set_epoch_surcharge<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetEpochSurchargeIxArgs {
+        epoch_surcharge_bps,
+        ..
+    }: &SetEpochSurchargeIxArgs,
+) -> Result<SetEpochSurchargeAccounts<'me, 'info>, ProgramError> {
+    let actual: SetEpochSurchargeAccounts = load_accounts(accounts)?;
+
+    let free_args = SetEpochSurchargeFreeArgs {
+        state_acc: actual.state,
+    };
+    let expected: SetEpochSurchargeKeys = free_args.resolve()?;
+
+    set_epoch_surcharge_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_epoch_surcharge_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_unsigned_fee_bps_bound(*epoch_surcharge_bps)?;
+
+    Ok(actual)
+}
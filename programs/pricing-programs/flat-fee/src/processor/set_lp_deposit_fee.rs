@@ -0,0 +1,48 @@
+use flat_fee_interface::{
+    set_lp_deposit_fee_verify_account_keys, set_lp_deposit_fee_verify_account_privileges,
+    SetLpDepositFeeAccounts, SetLpDepositFeeIxArgs, SetLpDepositFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLpDepositFeeFreeArgs, fee_bound::verify_unsigned_fee_bps_bound,
+    utils::try_program_state_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_set_lp_deposit_fee(
+    accounts: &[AccountInfo],
+    args: SetLpDepositFeeIxArgs,
+) -> ProgramResult {
+    let SetLpDepositFeeAccounts { state, .. } = verify_set_lp_deposit_fee(accounts, &args)?;
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+    state.lp_deposit_fee_bps = args.lp_deposit_fee_bps;
+
+    Ok(())
+}
+
+fn verify_set_lp_deposit_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetLpDepositFeeIxArgs { lp_deposit_fee_bps }: &SetLpDepositFeeIxArgs,
+) -> Result<SetLpDepositFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: SetLpDepositFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = SetLpDepositFeeFreeArgs {
+        state_acc: actual.state,
+    };
+    let expected: SetLpDepositFeeKeys = free_args.resolve()?;
+
+    set_lp_deposit_fee_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_lp_deposit_fee_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_unsigned_fee_bps_bound(*lp_deposit_fee_bps)?;
+
+    Ok(actual)
+}
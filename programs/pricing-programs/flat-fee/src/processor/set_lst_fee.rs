@@ -21,6 +21,11 @@ pub fn process_set_lst_fee(accounts: &[AccountInfo], args: SetLstFeeIxArgs) -> P
 
     fee_acc.input_fee_bps = args.input_fee_bps;
     fee_acc.output_fee_bps = args.output_fee_bps;
+    // SetLstFee takes effect immediately, cancelling any ongoing fee ramp
+    fee_acc.ramp_start_slot = 0;
+    fee_acc.ramp_end_slot = 0;
+    fee_acc.ramp_target_input_fee_bps = 0;
+    fee_acc.ramp_target_output_fee_bps = 0;
 
     Ok(())
 }
@@ -0,0 +1,77 @@
+use flat_fee_interface::{
+    set_lst_fee_ramp_verify_account_keys, set_lst_fee_ramp_verify_account_privileges, FlatFeeError,
+    SetLstFeeRampAccounts, SetLstFeeRampIxArgs, SetLstFeeRampKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLstFeeRampFreeArgs,
+    calc::{lst_fee_bps_at_slot, LstFeeBps},
+    fee_bound::verify_signed_fee_bps_bound,
+    utils::try_fee_account_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+pub fn process_set_lst_fee_ramp(
+    accounts: &[AccountInfo],
+    args: SetLstFeeRampIxArgs,
+) -> ProgramResult {
+    let slot = Clock::get()?.slot;
+    let SetLstFeeRampAccounts { fee_acc, .. } = verify_set_lst_fee_ramp(accounts, &args, slot)?;
+
+    let mut bytes = fee_acc.try_borrow_mut_data()?;
+    let fee_acc = try_fee_account_mut(&mut bytes)?;
+
+    // ramp from the fees currently in effect,
+    // which may be partway through a previous ramp
+    let LstFeeBps {
+        input_fee_bps,
+        output_fee_bps,
+    } = lst_fee_bps_at_slot(fee_acc, slot);
+    fee_acc.input_fee_bps = input_fee_bps;
+    fee_acc.output_fee_bps = output_fee_bps;
+
+    fee_acc.ramp_start_slot = args.start_slot;
+    fee_acc.ramp_end_slot = args.end_slot;
+    fee_acc.ramp_target_input_fee_bps = args.target_input_fee_bps;
+    fee_acc.ramp_target_output_fee_bps = args.target_output_fee_bps;
+
+    Ok(())
+}
+
+fn verify_set_lst_fee_ramp<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetLstFeeRampIxArgs {
+        target_input_fee_bps,
+        target_output_fee_bps,
+        start_slot,
+        end_slot,
+    }: &SetLstFeeRampIxArgs,
+    slot: u64,
+) -> Result<SetLstFeeRampAccounts<'me, 'info>, ProgramError> {
+    let actual: SetLstFeeRampAccounts = load_accounts(accounts)?;
+
+    let free_args = SetLstFeeRampFreeArgs {
+        state_acc: actual.state,
+        fee_acc: *actual.fee_acc.key,
+    };
+    let expected: SetLstFeeRampKeys = free_args.resolve()?;
+
+    set_lst_fee_ramp_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_lst_fee_ramp_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_signed_fee_bps_bound(*target_input_fee_bps)?;
+    verify_signed_fee_bps_bound(*target_output_fee_bps)?;
+
+    // a ramp starting in the past would make the fees jump
+    // to partway through the ramp immediately
+    if end_slot <= start_slot || *start_slot < slot {
+        return Err(FlatFeeError::InvalidFeeRamp.into());
+    }
+
+    Ok(actual)
+}
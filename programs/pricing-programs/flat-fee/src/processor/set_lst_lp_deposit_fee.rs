@@ -0,0 +1,62 @@
+use flat_fee_interface::{
+    set_lst_lp_deposit_fee_verify_account_keys, set_lst_lp_deposit_fee_verify_account_privileges,
+    SetLstLpDepositFeeAccounts, SetLstLpDepositFeeIxArgs, SetLstLpDepositFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLstLpDepositFeeFreeArgs, fee_bound::verify_unsigned_fee_bps_bound,
+    utils::try_fee_account_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_set_lst_lp_deposit_fee(
+    accounts: &[AccountInfo],
+    args: SetLstLpDepositFeeIxArgs,
+) -> ProgramResult {
+    let SetLstLpDepositFeeAccounts { fee_acc, .. } =
+        verify_set_lst_lp_deposit_fee(accounts, &args)?;
+
+    let mut bytes = fee_acc.try_borrow_mut_data()?;
+    let fee_acc = try_fee_account_mut(&mut bytes)?;
+
+    match args.lp_deposit_fee_bps {
+        Some(lp_deposit_fee_bps) => {
+            fee_acc.has_lp_deposit_fee_bps_override = 1;
+            fee_acc.lp_deposit_fee_bps_override = lp_deposit_fee_bps;
+        }
+        None => {
+            fee_acc.has_lp_deposit_fee_bps_override = 0;
+            fee_acc.lp_deposit_fee_bps_override = 0;
+        }
+    }
+
+    Ok(())
+}
+
+fn verify_set_lst_lp_deposit_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetLstLpDepositFeeIxArgs { lp_deposit_fee_bps }: &SetLstLpDepositFeeIxArgs,
+) -> Result<SetLstLpDepositFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: SetLstLpDepositFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = SetLstLpDepositFeeFreeArgs {
+        state_acc: actual.state,
+        fee_acc: *actual.fee_acc.key,
+    };
+    let expected: SetLstLpDepositFeeKeys = free_args.resolve()?;
+
+    set_lst_lp_deposit_fee_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_lst_lp_deposit_fee_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    if let Some(lp_deposit_fee_bps) = lp_deposit_fee_bps {
+        verify_unsigned_fee_bps_bound(*lp_deposit_fee_bps)?;
+    }
+
+    Ok(actual)
+}
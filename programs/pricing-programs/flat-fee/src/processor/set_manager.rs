@@ -18,7 +18,7 @@ pub fn process_set_manager(accounts: &[AccountInfo]) -> ProgramResult {
     let mut bytes = state.try_borrow_mut_data()?;
     let state = try_program_state_mut(&mut bytes)?;
 
-    state.manager = *new_manager.key;
+    state.pending_manager = *new_manager.key;
 
     Ok(())
 }
@@ -0,0 +1,45 @@
+use flat_fee_interface::{
+    set_pair_fee_verify_account_keys, set_pair_fee_verify_account_privileges, SetPairFeeAccounts,
+    SetPairFeeIxArgs, SetPairFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetPairFeeFreeArgs, fee_bound::verify_signed_fee_bps_bound,
+    utils::try_pair_fee_account_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_set_pair_fee(accounts: &[AccountInfo], args: SetPairFeeIxArgs) -> ProgramResult {
+    let SetPairFeeAccounts { pair_fee_acc, .. } = verify_set_pair_fee(accounts, &args)?;
+
+    let mut bytes = pair_fee_acc.try_borrow_mut_data()?;
+    let pair_fee_acc = try_pair_fee_account_mut(&mut bytes)?;
+
+    pair_fee_acc.fee_bps = args.fee_bps;
+
+    Ok(())
+}
+
+fn verify_set_pair_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetPairFeeIxArgs { fee_bps }: &SetPairFeeIxArgs,
+) -> Result<SetPairFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: SetPairFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = SetPairFeeFreeArgs {
+        state_acc: actual.state,
+        pair_fee_acc: *actual.pair_fee_acc.key,
+    };
+    let expected: SetPairFeeKeys = free_args.resolve()?;
+
+    set_pair_fee_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_pair_fee_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_signed_fee_bps_bound(*fee_bps)?;
+
+    Ok(actual)
+}
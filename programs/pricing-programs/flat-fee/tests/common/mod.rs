@@ -1,5 +1,7 @@
 mod fee_account;
+mod pair_fee_account;
 mod program_test;
 
 pub use fee_account::*;
+pub use pair_fee_account::*;
 pub use program_test::*;
@@ -0,0 +1,55 @@
+use flat_fee_lib::{pda::PairFeeAccountFindPdaArgs, program, utils::try_pair_fee_account};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::BanksClient;
+use solana_sdk::instruction::Instruction;
+
+pub async fn verify_pair_fee_account(
+    banks_client: &mut BanksClient,
+    input_lst_mint: Pubkey,
+    output_lst_mint: Pubkey,
+    expected_fee_bps: i16,
+) {
+    let find_pda_args = PairFeeAccountFindPdaArgs {
+        input_lst_mint,
+        output_lst_mint,
+        program_id: program::ID,
+    };
+    let (addr, bump) = find_pda_args.get_pair_fee_account_address_and_bump_seed();
+    let actual_acc = banks_client.get_account_unwrapped(addr).await;
+    let actual = try_pair_fee_account(&actual_acc.data).unwrap();
+    assert_eq!(actual.bump, bump);
+    assert_eq!(actual.fee_bps, expected_fee_bps);
+    assert_eq!(actual.input_lst_mint, input_lst_mint);
+    assert_eq!(actual.output_lst_mint, output_lst_mint);
+}
+
+pub async fn verify_pair_fee_account_does_not_exist(
+    banks_client: &mut BanksClient,
+    input_lst_mint: Pubkey,
+    output_lst_mint: Pubkey,
+) {
+    let find_pda_args = PairFeeAccountFindPdaArgs {
+        input_lst_mint,
+        output_lst_mint,
+        program_id: program::ID,
+    };
+    let (addr, _bump) = find_pda_args.get_pair_fee_account_address_and_bump_seed();
+    assert!(banks_client.get_account(addr).await.unwrap().is_none());
+}
+
+/// Appends the pair's PairFeeAccount as PriceExactIn/PriceExactOut's optional trailing account
+pub fn with_pair_fee_acc(
+    mut ix: Instruction,
+    input_lst_mint: Pubkey,
+    output_lst_mint: Pubkey,
+) -> Instruction {
+    let (addr, _bump) = PairFeeAccountFindPdaArgs {
+        input_lst_mint,
+        output_lst_mint,
+        program_id: program::ID,
+    }
+    .get_pair_fee_account_address_and_bump_seed();
+    ix.accounts.push(AccountMeta::new_readonly(addr, false));
+    ix
+}
@@ -1,6 +1,9 @@
 use flat_fee_interface::ProgramState;
 use flat_fee_lib::program::STATE_ID;
-use flat_fee_test_utils::{MockFeeAccount, MockFeeAccountArgs, MockProgramState};
+use flat_fee_test_utils::{
+    MockFeeAccount, MockFeeAccountArgs, MockLegacyFeeAccount, MockLegacyProgramState,
+    MockPairFeeAccount, MockPairFeeAccountArgs, MockProgramState,
+};
 use sanctum_solana_test_utils::IntoAccount;
 use solana_program_test::{processor, ProgramTest};
 
@@ -21,3 +24,47 @@ pub fn normal_program_test(
     }
     program_test
 }
+
+pub fn pair_fee_program_test(
+    state: ProgramState,
+    mock_fee_accounts: &[MockFeeAccountArgs],
+    mock_pair_fee_accounts: &[MockPairFeeAccountArgs],
+) -> ProgramTest {
+    let mut program_test = normal_program_test(state, mock_fee_accounts);
+    for mpfa in mock_pair_fee_accounts {
+        let (acc, addr) = mpfa.to_pair_fee_account_and_addr(flat_fee_lib::program::ID);
+        program_test.add_account(addr, MockPairFeeAccount(acc).into_account());
+    }
+    program_test
+}
+
+/// Same as [`normal_program_test`] but with the ProgramState in the legacy layout
+pub fn legacy_state_program_test(state: ProgramState) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "flat_fee",
+        flat_fee_lib::program::ID,
+        processor!(flat_fee::entrypoint::process_instruction),
+    );
+    program_test.add_account(STATE_ID, MockLegacyProgramState(state).into_account());
+    program_test
+}
+
+/// Same as [`normal_program_test`] but with the FeeAccounts in the legacy layout
+pub fn legacy_fee_accounts_program_test(
+    state: ProgramState,
+    mock_fee_accounts: &[MockFeeAccountArgs],
+) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "flat_fee",
+        flat_fee_lib::program::ID,
+        processor!(flat_fee::entrypoint::process_instruction),
+    );
+    program_test.add_account(STATE_ID, MockProgramState(state).into_account());
+    for mfa in mock_fee_accounts {
+        let (acc, addr) = mfa.to_fee_account_and_addr(flat_fee_lib::program::ID);
+        program_test.add_account(addr, MockLegacyFeeAccount(acc).into_account());
+    }
+    program_test
+}
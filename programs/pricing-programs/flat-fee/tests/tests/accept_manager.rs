@@ -0,0 +1,126 @@
+use flat_fee_interface::{accept_manager_ix, AcceptManagerKeys, ProgramState};
+use flat_fee_lib::{
+    account_resolvers::AcceptManagerFreeArgs, program::STATE_ID, utils::try_program_state,
+};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::{assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::normal_program_test;
+
+#[tokio::test]
+async fn accept_manager_basic() {
+    let manager = Keypair::new();
+    let pending_manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: pending_manager.pubkey(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[],
+    );
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = accept_manager_ix(
+        AcceptManagerFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &pending_manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.manager, pending_manager.pubkey());
+    assert_eq!(state.pending_manager, Pubkey::default());
+}
+
+#[tokio::test]
+async fn accept_manager_fail_not_pending_manager() {
+    let manager = Keypair::new();
+    let pending_manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: pending_manager.pubkey(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[],
+    );
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = accept_manager_ix(AcceptManagerKeys {
+        pending_manager: manager.pubkey(),
+        state: STATE_ID,
+    })
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.manager, manager.pubkey());
+    assert_eq!(state.pending_manager, pending_manager.pubkey());
+}
+
+#[tokio::test]
+async fn accept_manager_fail_no_pending_manager() {
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Pubkey::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[],
+    );
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = accept_manager_ix(AcceptManagerKeys {
+        pending_manager: payer.pubkey(),
+        state: STATE_ID,
+    })
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
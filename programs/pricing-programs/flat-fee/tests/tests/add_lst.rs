@@ -24,6 +24,10 @@ fn add_lst_program_test() -> (ProgramTest, Keypair, Pubkey) {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         &[],
     )
@@ -168,6 +172,10 @@ async fn add_lst_fail_invalid_lst_mint() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         &[],
     );
@@ -0,0 +1,147 @@
+use flat_fee_interface::{add_pair_fee_ix, AddPairFeeIxArgs, FlatFeeError, ProgramState};
+use flat_fee_lib::{account_resolvers::AddPairFeeFreeArgs, program::STATE_ID};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::{
+    assert_custom_err, assert_program_error,
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs},
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::{
+    normal_program_test, verify_pair_fee_account, verify_pair_fee_account_does_not_exist,
+};
+
+const MOCK_MINT_ARGS: MockMintArgs = MockMintArgs {
+    mint_authority: None,
+    freeze_authority: None,
+    supply: 0,
+    decimals: 9,
+};
+
+fn add_pair_fee_program_test() -> (ProgramTest, Keypair, Pubkey, Pubkey) {
+    let manager = Keypair::new();
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[],
+    )
+    .add_tokenkeg_mint_from_args(input_lst_mint, MOCK_MINT_ARGS)
+    .add_tokenkeg_mint_from_args(output_lst_mint, MOCK_MINT_ARGS);
+    (program_test, manager, input_lst_mint, output_lst_mint)
+}
+
+#[tokio::test]
+async fn add_pair_fee_basic() {
+    const FEE_BPS: i16 = 1;
+
+    let (program_test, manager, input_lst_mint, output_lst_mint) = add_pair_fee_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    verify_pair_fee_account_does_not_exist(&mut banks_client, input_lst_mint, output_lst_mint)
+        .await;
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+
+    let (keys, _pda) = AddPairFeeFreeArgs {
+        payer: payer.pubkey(),
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .resolve()
+    .unwrap();
+    let ix = add_pair_fee_ix(keys, AddPairFeeIxArgs { fee_bps: FEE_BPS }).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_pair_fee_account(&mut banks_client, input_lst_mint, output_lst_mint, FEE_BPS).await;
+    // pairs are directional
+    verify_pair_fee_account_does_not_exist(&mut banks_client, output_lst_mint, input_lst_mint)
+        .await;
+}
+
+#[tokio::test]
+async fn add_pair_fee_fail_invalid_fee() {
+    let (program_test, manager, input_lst_mint, output_lst_mint) = add_pair_fee_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let keyed_state_acc = KeyedAccount {
+        pubkey: STATE_ID,
+        account: state_acc,
+    };
+
+    for bad_fee_bps in [10_001, -10_001] {
+        let (keys, _pda) = AddPairFeeFreeArgs {
+            payer: payer.pubkey(),
+            state_acc: &keyed_state_acc,
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve()
+        .unwrap();
+        let ix = add_pair_fee_ix(
+            keys,
+            AddPairFeeIxArgs {
+                fee_bps: bad_fee_bps,
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, FlatFeeError::SignedFeeOutOfBound);
+
+        verify_pair_fee_account_does_not_exist(&mut banks_client, input_lst_mint, output_lst_mint)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn add_pair_fee_fail_unauthorized() {
+    let (program_test, _manager, input_lst_mint, output_lst_mint) = add_pair_fee_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let (mut keys, _pda) = AddPairFeeFreeArgs {
+        payer: payer.pubkey(),
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .resolve()
+    .unwrap();
+    keys.manager = payer.pubkey();
+    let ix = add_pair_fee_ix(keys, AddPairFeeIxArgs { fee_bps: 0 }).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    verify_pair_fee_account_does_not_exist(&mut banks_client, input_lst_mint, output_lst_mint)
+        .await;
+}
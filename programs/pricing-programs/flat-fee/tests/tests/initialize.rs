@@ -30,5 +30,5 @@ async fn initialize_basic() {
 
     let state_acc = banks_client.get_flat_fee_program_state().await;
     let state = try_program_state(&state_acc.data).unwrap();
-    assert_eq!(*state, DEFAULT_PROGRAM_STATE);
+    assert_eq!(state, DEFAULT_PROGRAM_STATE);
 }
@@ -0,0 +1,203 @@
+use flat_fee_interface::{
+    migrate_lst_fee_ix, price_exact_in_ix, set_lst_fee_ix, FeeAccount, FlatFeeError,
+    MigrateLstFeeKeys, PriceExactInIxArgs, ProgramState, SetLstFeeIxArgs,
+};
+use flat_fee_lib::{
+    account_resolvers::{MigrateLstFeeFreeArgs, PriceExactInFreeArgs, SetLstFeeByMintFreeArgs},
+    pda::FeeAccountFindPdaArgs,
+    program::{self, FEE_ACCOUNT_SIZE, LEGACY_FEE_ACCOUNT_SIZE, STATE_ID},
+    utils::try_fee_account,
+};
+use flat_fee_test_utils::{MockFeeAccountArgs, DEFAULT_PROGRAM_STATE};
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::legacy_fee_accounts_program_test;
+
+const SOL_VALUE: u64 = 1_000_000_000;
+
+fn program_state(manager: Pubkey) -> ProgramState {
+    ProgramState {
+        manager,
+        ..DEFAULT_PROGRAM_STATE
+    }
+}
+
+fn fee_account_addr(lst_mint: Pubkey) -> Pubkey {
+    FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed()
+    .0
+}
+
+#[tokio::test]
+async fn migrate_lst_fee_basic() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+    let mock_fee_account = MockFeeAccountArgs {
+        input_fee_bps: 10,
+        output_fee_bps: 20,
+        lst_mint,
+    };
+    let (expected_fee_acc, fee_acc) = mock_fee_account.to_fee_account_and_addr(program::ID);
+
+    let program_test =
+        legacy_fee_accounts_program_test(program_state(manager.pubkey()), &[mock_fee_account]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let legacy_fee_acc = banks_client.get_account_unwrapped(fee_acc).await;
+    assert_eq!(legacy_fee_acc.data.len(), LEGACY_FEE_ACCOUNT_SIZE);
+    // legacy FeeAccount should still be readable before migration
+    assert_eq!(
+        try_fee_account(&legacy_fee_acc.data).unwrap(),
+        expected_fee_acc
+    );
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = migrate_lst_fee_ix(
+        MigrateLstFeeFreeArgs {
+            payer: payer.pubkey(),
+            fee_acc,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let fee_acc = banks_client.get_account_unwrapped(fee_acc).await;
+    assert_eq!(fee_acc.data.len(), FEE_ACCOUNT_SIZE);
+    assert_eq!(
+        fee_acc.lamports,
+        banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(FEE_ACCOUNT_SIZE)
+    );
+    let fee_acc: FeeAccount = try_fee_account(&fee_acc.data).unwrap();
+    assert_eq!(fee_acc, expected_fee_acc);
+}
+
+#[tokio::test]
+async fn migrate_lst_fee_fail_unauthorized() {
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = legacy_fee_accounts_program_test(
+        program_state(Pubkey::new_unique()),
+        &[MockFeeAccountArgs {
+            input_fee_bps: 10,
+            output_fee_bps: 20,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let fee_acc = fee_account_addr(lst_mint);
+    let ix = migrate_lst_fee_ix(MigrateLstFeeKeys {
+        manager: payer.pubkey(),
+        payer: payer.pubkey(),
+        fee_acc,
+        state: STATE_ID,
+        system_program: system_program::ID,
+    })
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    let fee_acc = banks_client.get_account_unwrapped(fee_acc).await;
+    assert_eq!(fee_acc.data.len(), LEGACY_FEE_ACCOUNT_SIZE);
+}
+
+#[tokio::test]
+async fn price_exact_in_legacy_fee_accounts() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = legacy_fee_accounts_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &[
+            MockFeeAccountArgs {
+                input_fee_bps: 10,
+                output_fee_bps: 0,
+                lst_mint: input_lst_mint,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 0,
+                output_fee_bps: 20,
+                lst_mint: output_lst_mint,
+            },
+        ],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: SOL_VALUE,
+            sol_value: SOL_VALUE,
+        },
+    )
+    .unwrap();
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 997_000_000)
+        .await;
+}
+
+#[tokio::test]
+async fn set_lst_fee_fail_legacy_fee_account() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = legacy_fee_accounts_program_test(
+        program_state(manager.pubkey()),
+        &[MockFeeAccountArgs {
+            input_fee_bps: 10,
+            output_fee_bps: 20,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = set_lst_fee_ix(
+        SetLstFeeByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLstFeeIxArgs {
+            input_fee_bps: 1,
+            output_fee_bps: 2,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::StateNotMigrated);
+}
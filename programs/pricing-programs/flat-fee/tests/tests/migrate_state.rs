@@ -0,0 +1,123 @@
+use flat_fee_interface::{
+    migrate_state_ix, set_manager_ix, FlatFeeError, MigrateStateKeys, ProgramState,
+};
+use flat_fee_lib::{
+    account_resolvers::{MigrateStateFreeArgs, SetManagerFreeArgs},
+    program::{LEGACY_STATE_SIZE, STATE_ID, STATE_SIZE},
+    utils::try_program_state,
+};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::legacy_state_program_test;
+
+const LP_WITHDRAWAL_FEE_BPS: u16 = 10;
+
+fn legacy_program_state(manager: Pubkey) -> ProgramState {
+    ProgramState {
+        manager,
+        lp_withdrawal_fee_bps: LP_WITHDRAWAL_FEE_BPS,
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn migrate_state_basic() {
+    let manager = Keypair::new();
+
+    let program_test = legacy_state_program_test(legacy_program_state(manager.pubkey()));
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let legacy_state_acc = banks_client.get_flat_fee_program_state().await;
+    assert_eq!(legacy_state_acc.data.len(), LEGACY_STATE_SIZE);
+    // legacy ProgramState should still be readable before migration
+    let legacy_state = try_program_state(&legacy_state_acc.data).unwrap();
+    assert_eq!(legacy_state, legacy_program_state(manager.pubkey()));
+
+    let ix = migrate_state_ix(
+        MigrateStateFreeArgs {
+            payer: payer.pubkey(),
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: legacy_state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    assert_eq!(state_acc.data.len(), STATE_SIZE);
+    assert_eq!(
+        state_acc.lamports,
+        banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(STATE_SIZE)
+    );
+    let state = try_program_state(&state_acc.data).unwrap();
+    assert_eq!(state, legacy_program_state(manager.pubkey()));
+}
+
+#[tokio::test]
+async fn migrate_state_fail_unauthorized() {
+    let manager = Pubkey::new_unique();
+
+    let program_test = legacy_state_program_test(legacy_program_state(manager));
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = migrate_state_ix(MigrateStateKeys {
+        manager: payer.pubkey(),
+        payer: payer.pubkey(),
+        state: STATE_ID,
+        system_program: system_program::ID,
+    })
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    assert_eq!(state_acc.data.len(), LEGACY_STATE_SIZE);
+}
+
+#[tokio::test]
+async fn set_manager_fail_legacy_state() {
+    let manager = Keypair::new();
+
+    let program_test = legacy_state_program_test(legacy_program_state(manager.pubkey()));
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = set_manager_ix(
+        SetManagerFreeArgs {
+            new_manager: Pubkey::new_unique(),
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::StateNotMigrated);
+}
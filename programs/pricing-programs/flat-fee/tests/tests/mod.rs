@@ -1,10 +1,20 @@
+mod accept_manager;
 mod add_lst;
+mod add_pair_fee;
 mod initialize;
+mod migrate_lst_fee;
+mod migrate_state;
 mod price_exact_in;
 mod price_exact_out;
 mod price_lp_tokens_to_mint;
 mod price_lp_tokens_to_redeem;
 mod remove_lst;
+mod remove_pair_fee;
+mod set_epoch_surcharge;
+mod set_lp_deposit_fee;
 mod set_lp_withdrawal_fee;
 mod set_lst_fee;
+mod set_lst_fee_ramp;
+mod set_lst_lp_deposit_fee;
 mod set_manager;
+mod set_pair_fee;
@@ -1 +1,539 @@
+use std::collections::HashMap;
 
+use flat_fee_interface::{price_exact_in_ix, PriceExactInIxArgs, ProgramState};
+use flat_fee_lib::{account_resolvers::PriceExactInFreeArgs, pda::PairFeeAccountFindPdaArgs};
+use flat_fee_test_utils::{
+    MockFeeAccount, MockFeeAccountArgs, MockPairFeeAccount, MockPairFeeAccountArgs,
+    DEFAULT_PROGRAM_STATE,
+};
+use pricing_programs_interface::PriceExactInKeys;
+use s_pricing_prog_aggregate::{FlatFeePricingProg, MutablePricingProg, PricingProg};
+use sanctum_solana_test_utils::IntoAccount;
+use solana_program::{
+    clock::Clock, epoch_schedule::EpochSchedule, instruction::AccountMeta, pubkey::Pubkey,
+};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::instruction::Instruction;
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::{legacy_state_program_test, pair_fee_program_test, with_pair_fee_acc};
+
+const SOL_VALUE: u64 = 1_000_000_000;
+
+/// input_fee_bps + output_fee_bps = 30
+fn mock_fee_accounts(input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> [MockFeeAccountArgs; 2] {
+    [
+        MockFeeAccountArgs {
+            input_fee_bps: 10,
+            output_fee_bps: 0,
+            lst_mint: input_lst_mint,
+        },
+        MockFeeAccountArgs {
+            input_fee_bps: 0,
+            output_fee_bps: 20,
+            lst_mint: output_lst_mint,
+        },
+    ]
+}
+
+fn price_exact_in_ix_for_mints(input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> Instruction {
+    price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: SOL_VALUE,
+            sol_value: SOL_VALUE,
+        },
+    )
+    .unwrap()
+}
+
+/// PriceExactIn with the version 1.0.0 account layout, which does not include `state`
+fn legacy_price_exact_in_ix_for_mints(
+    input_lst_mint: Pubkey,
+    output_lst_mint: Pubkey,
+) -> Instruction {
+    let mut ix = price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint);
+    ix.accounts.truncate(4);
+    ix
+}
+
+#[tokio::test]
+async fn price_exact_in_no_pair_fee() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 997_000_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_pair_fee_overrides_lst_fees() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[MockPairFeeAccountArgs {
+            fee_bps: 5,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = with_pair_fee_acc(
+        price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint),
+        input_lst_mint,
+        output_lst_mint,
+    );
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 999_500_000)
+        .await;
+
+    // pair fees are directional, reverse pair should not be affected
+    // (output_fee_bps of input_lst_mint + input_fee_bps of output_lst_mint = 0)
+    let ix = price_exact_in_ix_for_mints(output_lst_mint, input_lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, SOL_VALUE)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_pair_fee_acc_omitted() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[MockPairFeeAccountArgs {
+            fee_bps: 5,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 997_000_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_higher_pair_fee_not_applied() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[MockPairFeeAccountArgs {
+            fee_bps: 50,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    // swappers can omit the PairFeeAccount, so it can only lower the fee
+    let ix = with_pair_fee_acc(
+        price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint),
+        input_lst_mint,
+        output_lst_mint,
+    );
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 997_000_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_spoofed_pair_fee_acc_ignored() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let mut program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[],
+    );
+    // valid PairFeeAccount data, but not at the pair's PDA
+    let (pair_fee_acc, _addr) = MockPairFeeAccountArgs {
+        fee_bps: 0,
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .to_pair_fee_account_and_addr(flat_fee_lib::program::ID);
+    let spoofed_addr = Pubkey::new_unique();
+    program_test.add_account(
+        spoofed_addr,
+        MockPairFeeAccount(pair_fee_acc).into_account(),
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    // LST fees are charged as if no PairFeeAccount was passed
+    let mut ix = price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint);
+    ix.accounts
+        .push(AccountMeta::new_readonly(spoofed_addr, false));
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 997_000_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_trailing_non_pair_fee_accounts_ignored() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[MockPairFeeAccountArgs {
+            fee_bps: 5,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    // e.g. transfer hook accounts forwarded by the S controller:
+    // a nonexistent account and an account owned by this program that is not a PairFeeAccount
+    let keys = PriceExactInFreeArgs {
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .resolve();
+    let trailing_accounts = [
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(keys.input_fee_acc, false),
+    ];
+
+    let mut ix = price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint);
+    ix.accounts.extend(trailing_accounts.clone());
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 997_000_000)
+        .await;
+
+    // PairFeeAccount still applies when followed by other accounts
+    let mut ix = with_pair_fee_acc(
+        price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint),
+        input_lst_mint,
+        output_lst_mint,
+    );
+    ix.accounts.extend(trailing_accounts);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 999_500_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_matches_quote_with_discovered_pair_fee_accounts() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let other_lst_mint = Pubkey::new_unique();
+
+    let [input_fee_acc_args, output_fee_acc_args] =
+        mock_fee_accounts(input_lst_mint, output_lst_mint);
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &[
+            input_fee_acc_args,
+            output_fee_acc_args,
+            MockFeeAccountArgs {
+                input_fee_bps: 0,
+                output_fee_bps: 0,
+                lst_mint: other_lst_mint,
+            },
+        ],
+        &[MockPairFeeAccountArgs {
+            fee_bps: 5,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let mut pricing_prog = FlatFeePricingProg::try_new(
+        flat_fee_lib::program::ID,
+        [input_lst_mint, output_lst_mint, other_lst_mint].into_iter(),
+    )
+    .unwrap();
+    let (pair_fee_acc, _bump) = PairFeeAccountFindPdaArgs {
+        program_id: flat_fee_lib::program::ID,
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .get_pair_fee_account_address_and_bump_seed();
+    // no PairFeeAccounts are fetched until discovered
+    let accounts_to_update = pricing_prog.get_accounts_to_update();
+    assert!(!accounts_to_update.contains(&pair_fee_acc));
+
+    let mut account_map = HashMap::new();
+    for addr in accounts_to_update {
+        let account = banks_client.get_account(addr).await.unwrap().unwrap();
+        account_map.insert(addr, account);
+    }
+    // stand-in for getProgramAccounts, FeeAccounts are ignored
+    let program_accounts: Vec<_> = account_map
+        .iter()
+        .map(|(addr, acc)| (*addr, acc.clone()))
+        .chain([(
+            pair_fee_acc,
+            banks_client
+                .get_account(pair_fee_acc)
+                .await
+                .unwrap()
+                .unwrap(),
+        )])
+        .collect();
+    pricing_prog.discover_pair_fee_accounts(program_accounts);
+
+    // only PairFeeAccounts that exist are fetched
+    assert!(pricing_prog
+        .get_accounts_to_update_for_lsts([input_lst_mint, output_lst_mint].into_iter())
+        .contains(&pair_fee_acc));
+    assert!(!pricing_prog
+        .get_accounts_to_update_for_lsts([output_lst_mint, other_lst_mint].into_iter())
+        .contains(&pair_fee_acc));
+    pricing_prog.update(&account_map).unwrap();
+
+    let keys = PriceExactInKeys {
+        input_lst_mint,
+        output_lst_mint,
+    };
+    let quote = pricing_prog
+        .quote_exact_in(
+            keys,
+            &pricing_programs_interface::PriceExactInIxArgs {
+                amount: SOL_VALUE,
+                sol_value: SOL_VALUE,
+            },
+        )
+        .unwrap();
+    assert_eq!(quote, 999_500_000);
+
+    let mut ix = price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint);
+    ix.accounts = pricing_prog.price_exact_in_accounts(keys).unwrap();
+    assert_eq!(ix.accounts.last().unwrap().pubkey, pair_fee_acc);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, quote)
+        .await;
+}
+
+const RAMP_START_SLOT: u64 = 1000;
+
+const RAMP_END_SLOT: u64 = 2000;
+
+/// Same as [`mock_fee_accounts`], but input_lst_mint's input_fee_bps
+/// ramps from 10 to 30 over [RAMP_START_SLOT, RAMP_END_SLOT]
+fn fee_ramp_program_test(input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> ProgramTest {
+    let [input_fee_acc_args, output_fee_acc_args] =
+        mock_fee_accounts(input_lst_mint, output_lst_mint);
+    let mut program_test =
+        pair_fee_program_test(DEFAULT_PROGRAM_STATE, &[output_fee_acc_args], &[]);
+    let (mut fee_acc, addr) = input_fee_acc_args.to_fee_account_and_addr(flat_fee_lib::program::ID);
+    fee_acc.ramp_start_slot = RAMP_START_SLOT;
+    fee_acc.ramp_end_slot = RAMP_END_SLOT;
+    fee_acc.ramp_target_input_fee_bps = 30;
+    fee_acc.ramp_target_output_fee_bps = 0;
+    program_test.add_account(addr, MockFeeAccount(fee_acc).into_account());
+    program_test
+}
+
+async fn exec_price_exact_in_at_slot(slot: u64, expected: u64) {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = fee_ramp_program_test(input_lst_mint, output_lst_mint);
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        slot,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = ctx;
+
+    let ix = price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, expected)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_fee_ramp_not_started() {
+    // 10 + 20 = 30 bps
+    exec_price_exact_in_at_slot(RAMP_START_SLOT - 1, 997_000_000).await;
+}
+
+#[tokio::test]
+async fn price_exact_in_fee_ramp_midway() {
+    // 20 + 20 = 40 bps
+    exec_price_exact_in_at_slot((RAMP_START_SLOT + RAMP_END_SLOT) / 2, 996_000_000).await;
+}
+
+#[tokio::test]
+async fn price_exact_in_fee_ramp_ended() {
+    // 30 + 20 = 50 bps
+    exec_price_exact_in_at_slot(RAMP_END_SLOT + 1, 995_000_000).await;
+}
+
+const EPOCH_SURCHARGE_SLOTS: u16 = 100;
+
+async fn exec_price_exact_in_with_epoch_surcharge_at_slot(
+    ix_for_mints: fn(Pubkey, Pubkey) -> Instruction,
+    slot: u64,
+    expected: u64,
+) {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        ProgramState {
+            epoch_surcharge_bps: 20,
+            epoch_surcharge_slots: EPOCH_SURCHARGE_SLOTS,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[],
+    );
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&EpochSchedule::without_warmup());
+    ctx.set_sysvar(&Clock {
+        slot,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = ctx;
+
+    let ix = ix_for_mints(input_lst_mint, output_lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, expected)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_epoch_surcharge_start_of_epoch() {
+    let first_slot_of_epoch_1 = EpochSchedule::without_warmup().get_first_slot_in_epoch(1);
+    // 10 + 20 + 20 = 50 bps
+    exec_price_exact_in_with_epoch_surcharge_at_slot(
+        price_exact_in_ix_for_mints,
+        first_slot_of_epoch_1 + u64::from(EPOCH_SURCHARGE_SLOTS) - 1,
+        995_000_000,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_epoch_surcharge_end_of_epoch() {
+    let last_slot_of_epoch_1 = EpochSchedule::without_warmup().get_last_slot_in_epoch(1);
+    // 10 + 20 + 20 = 50 bps
+    exec_price_exact_in_with_epoch_surcharge_at_slot(
+        price_exact_in_ix_for_mints,
+        last_slot_of_epoch_1 + 1 - u64::from(EPOCH_SURCHARGE_SLOTS),
+        995_000_000,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_epoch_surcharge_mid_epoch() {
+    let first_slot_of_epoch_1 = EpochSchedule::without_warmup().get_first_slot_in_epoch(1);
+    // 10 + 20 = 30 bps
+    exec_price_exact_in_with_epoch_surcharge_at_slot(
+        price_exact_in_ix_for_mints,
+        first_slot_of_epoch_1 + u64::from(EPOCH_SURCHARGE_SLOTS),
+        997_000_000,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_legacy_accounts_no_epoch_surcharge() {
+    let first_slot_of_epoch_1 = EpochSchedule::without_warmup().get_first_slot_in_epoch(1);
+    // 10 + 20 = 30 bps
+    exec_price_exact_in_with_epoch_surcharge_at_slot(
+        legacy_price_exact_in_ix_for_mints,
+        first_slot_of_epoch_1,
+        997_000_000,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_legacy_accounts_with_pair_fee_acc() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[MockPairFeeAccountArgs {
+            fee_bps: 5,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = with_pair_fee_acc(
+        legacy_price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint),
+        input_lst_mint,
+        output_lst_mint,
+    );
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 999_500_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_legacy_state_no_epoch_surcharge() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    // legacy ProgramStates have no epoch surcharge fields, so they must be read as 0
+    let mut program_test = legacy_state_program_test(DEFAULT_PROGRAM_STATE);
+    for mfa in mock_fee_accounts(input_lst_mint, output_lst_mint) {
+        let (acc, addr) = mfa.to_fee_account_and_addr(flat_fee_lib::program::ID);
+        program_test.add_account(addr, MockFeeAccount(acc).into_account());
+    }
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&EpochSchedule::without_warmup());
+    ctx.set_sysvar(&Clock {
+        slot: EpochSchedule::without_warmup().get_first_slot_in_epoch(1),
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = ctx;
+
+    // 10 + 20 = 30 bps
+    let ix = price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 997_000_000)
+        .await;
+}
@@ -1 +1,223 @@
+use flat_fee_interface::{price_exact_out_ix, PriceExactOutIxArgs};
+use flat_fee_lib::account_resolvers::PriceExactOutFreeArgs;
+use flat_fee_test_utils::{
+    MockFeeAccountArgs, MockPairFeeAccount, MockPairFeeAccountArgs, DEFAULT_PROGRAM_STATE,
+};
+use sanctum_solana_test_utils::IntoAccount;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_sdk::instruction::Instruction;
+use test_utils::BorshReturnDataBanksClient;
 
+use crate::common::{pair_fee_program_test, with_pair_fee_acc};
+
+const SOL_VALUE: u64 = 1_000_000_000;
+
+/// input_fee_bps + output_fee_bps = 30
+fn mock_fee_accounts(input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> [MockFeeAccountArgs; 2] {
+    [
+        MockFeeAccountArgs {
+            input_fee_bps: 10,
+            output_fee_bps: 0,
+            lst_mint: input_lst_mint,
+        },
+        MockFeeAccountArgs {
+            input_fee_bps: 0,
+            output_fee_bps: 20,
+            lst_mint: output_lst_mint,
+        },
+    ]
+}
+
+fn price_exact_out_ix_for_mints(input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> Instruction {
+    price_exact_out_ix(
+        PriceExactOutFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve(),
+        PriceExactOutIxArgs {
+            amount: SOL_VALUE,
+            sol_value: SOL_VALUE,
+        },
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn price_exact_out_no_pair_fee() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_out_ix_for_mints(input_lst_mint, output_lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 1_003_009_028)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_out_pair_fee_overrides_lst_fees() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[MockPairFeeAccountArgs {
+            fee_bps: 5,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = with_pair_fee_acc(
+        price_exact_out_ix_for_mints(input_lst_mint, output_lst_mint),
+        input_lst_mint,
+        output_lst_mint,
+    );
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 1_000_500_251)
+        .await;
+
+    // pair fees are directional, reverse pair should not be affected
+    // (output_fee_bps of input_lst_mint + input_fee_bps of output_lst_mint = 0)
+    let ix = price_exact_out_ix_for_mints(output_lst_mint, input_lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, SOL_VALUE)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_out_pair_fee_acc_omitted() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[MockPairFeeAccountArgs {
+            fee_bps: 5,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_out_ix_for_mints(input_lst_mint, output_lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 1_003_009_028)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_out_higher_pair_fee_not_applied() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[MockPairFeeAccountArgs {
+            fee_bps: 50,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    // swappers can omit the PairFeeAccount, so it can only lower the fee
+    let ix = with_pair_fee_acc(
+        price_exact_out_ix_for_mints(input_lst_mint, output_lst_mint),
+        input_lst_mint,
+        output_lst_mint,
+    );
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 1_003_009_028)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_out_spoofed_pair_fee_acc_ignored() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let mut program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[],
+    );
+    // valid PairFeeAccount data, but not at the pair's PDA
+    let (pair_fee_acc, _addr) = MockPairFeeAccountArgs {
+        fee_bps: 0,
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .to_pair_fee_account_and_addr(flat_fee_lib::program::ID);
+    let spoofed_addr = Pubkey::new_unique();
+    program_test.add_account(
+        spoofed_addr,
+        MockPairFeeAccount(pair_fee_acc).into_account(),
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    // LST fees are charged as if no PairFeeAccount was passed
+    let mut ix = price_exact_out_ix_for_mints(input_lst_mint, output_lst_mint);
+    ix.accounts
+        .push(AccountMeta::new_readonly(spoofed_addr, false));
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 1_003_009_028)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_out_trailing_non_pair_fee_accounts_ignored() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &mock_fee_accounts(input_lst_mint, output_lst_mint),
+        &[MockPairFeeAccountArgs {
+            fee_bps: 5,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    // e.g. transfer hook accounts forwarded by the S controller:
+    // a nonexistent account and an account owned by this program that is not a PairFeeAccount
+    let keys = PriceExactOutFreeArgs {
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .resolve();
+    let trailing_accounts = [
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(keys.input_fee_acc, false),
+    ];
+
+    let mut ix = price_exact_out_ix_for_mints(input_lst_mint, output_lst_mint);
+    ix.accounts.extend(trailing_accounts.clone());
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 1_003_009_028)
+        .await;
+
+    // PairFeeAccount still applies when followed by other accounts
+    let mut ix = with_pair_fee_acc(
+        price_exact_out_ix_for_mints(input_lst_mint, output_lst_mint),
+        input_lst_mint,
+        output_lst_mint,
+    );
+    ix.accounts.extend(trailing_accounts);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 1_000_500_251)
+        .await;
+}
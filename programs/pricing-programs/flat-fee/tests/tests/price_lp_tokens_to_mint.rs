@@ -1 +1,141 @@
+use flat_fee_interface::{price_lp_tokens_to_mint_ix, PriceLpTokensToMintIxArgs, ProgramState};
+use flat_fee_lib::account_resolvers::PriceLpTokensToMintFreeArgs;
+use flat_fee_test_utils::{MockFeeAccount, MockFeeAccountArgs, DEFAULT_PROGRAM_STATE};
+use sanctum_solana_test_utils::IntoAccount;
+use solana_program::{clock::Clock, epoch_schedule::EpochSchedule, pubkey::Pubkey};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::instruction::Instruction;
+use test_utils::BorshReturnDataBanksClient;
 
+use crate::common::normal_program_test;
+
+const SOL_VALUE: u64 = 1_000_000_000;
+
+const LP_DEPOSIT_FEE_BPS: u16 = 10;
+
+fn lp_deposit_fee_program_test(
+    lst_mint: Pubkey,
+    lp_deposit_fee_bps_override: Option<u16>,
+) -> ProgramTest {
+    let mut program_test = normal_program_test(
+        ProgramState {
+            lp_deposit_fee_bps: LP_DEPOSIT_FEE_BPS,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let (mut fee_acc, addr) = MockFeeAccountArgs {
+        input_fee_bps: 0,
+        output_fee_bps: 0,
+        lst_mint,
+    }
+    .to_fee_account_and_addr(flat_fee_lib::program::ID);
+    if let Some(lp_deposit_fee_bps) = lp_deposit_fee_bps_override {
+        fee_acc.has_lp_deposit_fee_bps_override = 1;
+        fee_acc.lp_deposit_fee_bps_override = lp_deposit_fee_bps;
+    }
+    program_test.add_account(addr, MockFeeAccount(fee_acc).into_account());
+    program_test
+}
+
+fn price_lp_tokens_to_mint_ix_for_mint(input_lst_mint: Pubkey) -> Instruction {
+    price_lp_tokens_to_mint_ix(
+        PriceLpTokensToMintFreeArgs { input_lst_mint }.resolve(),
+        PriceLpTokensToMintIxArgs {
+            amount: SOL_VALUE,
+            sol_value: SOL_VALUE,
+        },
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn price_lp_tokens_to_mint_legacy_accounts_no_fee() {
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = lp_deposit_fee_program_test(lst_mint, None);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    // version 1.0.0 account layout, which only consists of input_lst_mint
+    let mut ix = price_lp_tokens_to_mint_ix_for_mint(lst_mint);
+    ix.accounts.truncate(1);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, SOL_VALUE)
+        .await;
+}
+
+#[tokio::test]
+async fn price_lp_tokens_to_mint_global_fee() {
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = lp_deposit_fee_program_test(lst_mint, None);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_lp_tokens_to_mint_ix_for_mint(lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 999_000_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_lp_tokens_to_mint_lst_override() {
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = lp_deposit_fee_program_test(lst_mint, Some(50));
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_lp_tokens_to_mint_ix_for_mint(lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 995_000_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_lp_tokens_to_mint_lst_override_zero() {
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = lp_deposit_fee_program_test(lst_mint, Some(0));
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_lp_tokens_to_mint_ix_for_mint(lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, SOL_VALUE)
+        .await;
+}
+
+#[tokio::test]
+async fn price_lp_tokens_to_mint_epoch_surcharge() {
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            lp_deposit_fee_bps: LP_DEPOSIT_FEE_BPS,
+            epoch_surcharge_bps: 20,
+            epoch_surcharge_slots: 100,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: 0,
+            output_fee_bps: 0,
+            lst_mint,
+        }],
+    );
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&EpochSchedule::without_warmup());
+    ctx.set_sysvar(&Clock {
+        slot: EpochSchedule::without_warmup().get_first_slot_in_epoch(1),
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = ctx;
+
+    // 10 + 20 = 30 bps
+    let ix = price_lp_tokens_to_mint_ix_for_mint(lst_mint);
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 997_000_000)
+        .await;
+}
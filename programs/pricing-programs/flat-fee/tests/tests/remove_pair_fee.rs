@@ -0,0 +1,94 @@
+use flat_fee_interface::{remove_pair_fee_ix, ProgramState};
+use flat_fee_lib::{account_resolvers::RemovePairFeeFreeArgs, program::STATE_ID};
+use flat_fee_test_utils::{MockPairFeeAccountArgs, DEFAULT_PROGRAM_STATE};
+use sanctum_solana_test_utils::{assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use test_utils::jitosol;
+
+use crate::common::{
+    pair_fee_program_test, verify_pair_fee_account, verify_pair_fee_account_does_not_exist,
+};
+
+const FEE_BPS: i16 = 1;
+
+fn mock_pair_fee_account_args(output_lst_mint: Pubkey) -> MockPairFeeAccountArgs {
+    MockPairFeeAccountArgs {
+        fee_bps: FEE_BPS,
+        input_lst_mint: jitosol::ID,
+        output_lst_mint,
+    }
+}
+
+#[tokio::test]
+async fn remove_pair_fee_basic() {
+    let manager = Keypair::new();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+        &[mock_pair_fee_account_args(output_lst_mint)],
+    );
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let free_args = RemovePairFeeFreeArgs {
+        refund_rent_to: payer.pubkey(),
+        input_lst_mint: jitosol::ID,
+        output_lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: banks_client.get_account_unwrapped(STATE_ID).await,
+        },
+    };
+    let ix = remove_pair_fee_ix(free_args.resolve().unwrap()).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_pair_fee_account_does_not_exist(&mut banks_client, jitosol::ID, output_lst_mint).await;
+}
+
+#[tokio::test]
+async fn remove_pair_fee_fail_unauthorized() {
+    let manager = Keypair::new();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+        &[mock_pair_fee_account_args(output_lst_mint)],
+    );
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let mut keys = RemovePairFeeFreeArgs {
+        refund_rent_to: payer.pubkey(),
+        input_lst_mint: jitosol::ID,
+        output_lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: banks_client.get_account_unwrapped(STATE_ID).await,
+        },
+    }
+    .resolve()
+    .unwrap();
+    keys.manager = payer.pubkey();
+    let ix = remove_pair_fee_ix(keys).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    verify_pair_fee_account(&mut banks_client, jitosol::ID, output_lst_mint, FEE_BPS).await;
+}
@@ -0,0 +1,155 @@
+use flat_fee_interface::{
+    set_epoch_surcharge_ix, FlatFeeError, ProgramState, SetEpochSurchargeIxArgs,
+    SetEpochSurchargeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetEpochSurchargeFreeArgs, program::STATE_ID, utils::try_program_state,
+};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error};
+use solana_program::program_error::ProgramError;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::normal_program_test;
+
+#[tokio::test]
+async fn set_epoch_surcharge_basic() {
+    const NEW_EPOCH_SURCHARGE_BPS: u16 = 420;
+    const NEW_EPOCH_SURCHARGE_SLOTS: u16 = 1000;
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let ix = set_epoch_surcharge_ix(
+        SetEpochSurchargeFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetEpochSurchargeIxArgs {
+            epoch_surcharge_bps: NEW_EPOCH_SURCHARGE_BPS,
+            epoch_surcharge_slots: NEW_EPOCH_SURCHARGE_SLOTS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.epoch_surcharge_bps, NEW_EPOCH_SURCHARGE_BPS);
+    assert_eq!(state.epoch_surcharge_slots, NEW_EPOCH_SURCHARGE_SLOTS);
+}
+
+#[tokio::test]
+async fn set_epoch_surcharge_fail_invalid_fee() {
+    const BAD_EPOCH_SURCHARGE_BPS: u16 = 10_001;
+
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let ix = set_epoch_surcharge_ix(
+        SetEpochSurchargeFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetEpochSurchargeIxArgs {
+            epoch_surcharge_bps: BAD_EPOCH_SURCHARGE_BPS,
+            epoch_surcharge_slots: 100,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::UnsignedFeeOutOfBound);
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_ne!(state.epoch_surcharge_bps, BAD_EPOCH_SURCHARGE_BPS);
+}
+
+#[tokio::test]
+async fn set_epoch_surcharge_fail_unauthorized() {
+    const NEW_EPOCH_SURCHARGE_BPS: u16 = 69;
+    const NEW_EPOCH_SURCHARGE_SLOTS: u16 = 100;
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_epoch_surcharge_ix(
+        SetEpochSurchargeKeys {
+            manager: payer.pubkey(),
+            state: STATE_ID,
+        },
+        SetEpochSurchargeIxArgs {
+            epoch_surcharge_bps: NEW_EPOCH_SURCHARGE_BPS,
+            epoch_surcharge_slots: NEW_EPOCH_SURCHARGE_SLOTS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_ne!(state.epoch_surcharge_bps, NEW_EPOCH_SURCHARGE_BPS);
+    assert_ne!(state.epoch_surcharge_slots, NEW_EPOCH_SURCHARGE_SLOTS);
+}
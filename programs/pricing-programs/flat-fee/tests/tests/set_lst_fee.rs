@@ -29,6 +29,10 @@ async fn set_lst_fee_basic() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         &[MockFeeAccountArgs {
             input_fee_bps: Default::default(),
@@ -82,6 +86,10 @@ async fn set_lst_fee_fail_invalid_fee() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         &[MockFeeAccountArgs {
             input_fee_bps: Default::default(),
@@ -155,6 +163,10 @@ async fn set_lst_fee_fail_unauthorized() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         &[MockFeeAccountArgs {
             input_fee_bps: Default::default(),
@@ -222,6 +234,10 @@ async fn set_lst_fee_fail_fee_account_not_exists() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         &[],
     );
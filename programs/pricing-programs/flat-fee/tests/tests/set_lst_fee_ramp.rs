@@ -0,0 +1,311 @@
+use flat_fee_interface::{
+    set_lst_fee_ramp_ix, AddLstIxArgs, FlatFeeError, ProgramState, SetLstFeeRampIxArgs,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLstFeeRampByMintFreeArgs,
+    pda::FeeAccountFindPdaArgs,
+    program::{self, STATE_ID},
+    utils::try_fee_account,
+};
+use flat_fee_test_utils::MockFeeAccountArgs;
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
+use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::*;
+
+const INITIAL_FEE_ARGS: AddLstIxArgs = AddLstIxArgs {
+    input_fee_bps: 1,
+    output_fee_bps: 2,
+};
+
+const RAMP_ARGS: SetLstFeeRampIxArgs = SetLstFeeRampIxArgs {
+    target_input_fee_bps: -2,
+    target_output_fee_bps: 10000,
+    start_slot: 1000,
+    end_slot: 2000,
+};
+
+fn mock_fee_account_args(lst_mint: Pubkey) -> MockFeeAccountArgs {
+    MockFeeAccountArgs {
+        input_fee_bps: INITIAL_FEE_ARGS.input_fee_bps,
+        output_fee_bps: INITIAL_FEE_ARGS.output_fee_bps,
+        lst_mint,
+    }
+}
+
+async fn verify_no_fee_ramp(banks_client: &mut BanksClient, lst_mint: Pubkey) {
+    let (fee_account_pk, _bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let fee_account_acc = banks_client.get_account_unwrapped(fee_account_pk).await;
+    let fee_account = try_fee_account(&fee_account_acc.data).unwrap();
+    assert_eq!(fee_account.ramp_start_slot, 0);
+    assert_eq!(fee_account.ramp_end_slot, 0);
+    verify_fee_account(banks_client, lst_mint, INITIAL_FEE_ARGS).await;
+}
+
+#[tokio::test]
+async fn set_lst_fee_ramp_basic() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[mock_fee_account_args(lst_mint)],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = set_lst_fee_ramp_ix(
+        SetLstFeeRampByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        RAMP_ARGS,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // no previous ramp, fees ramp from the current fees
+    verify_fee_account(&mut banks_client, lst_mint, INITIAL_FEE_ARGS).await;
+
+    let (fee_account_pk, _bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let fee_account_acc = banks_client.get_account_unwrapped(fee_account_pk).await;
+    let fee_account = try_fee_account(&fee_account_acc.data).unwrap();
+    assert_eq!(fee_account.ramp_start_slot, RAMP_ARGS.start_slot);
+    assert_eq!(fee_account.ramp_end_slot, RAMP_ARGS.end_slot);
+    assert_eq!(
+        fee_account.ramp_target_input_fee_bps,
+        RAMP_ARGS.target_input_fee_bps
+    );
+    assert_eq!(
+        fee_account.ramp_target_output_fee_bps,
+        RAMP_ARGS.target_output_fee_bps
+    );
+}
+
+#[tokio::test]
+async fn set_lst_fee_ramp_fail_invalid_ramp() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[mock_fee_account_args(lst_mint)],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let keyed_state_acc = KeyedAccount {
+        pubkey: STATE_ID,
+        account: state_acc,
+    };
+
+    for end_slot in [RAMP_ARGS.start_slot, RAMP_ARGS.start_slot - 1] {
+        let ix = set_lst_fee_ramp_ix(
+            SetLstFeeRampByMintFreeArgs {
+                lst_mint,
+                state_acc: &keyed_state_acc,
+            }
+            .resolve()
+            .unwrap(),
+            SetLstFeeRampIxArgs {
+                end_slot,
+                ..RAMP_ARGS
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+        assert_custom_err(err, FlatFeeError::InvalidFeeRamp);
+
+        verify_no_fee_ramp(&mut banks_client, lst_mint).await;
+    }
+}
+
+#[tokio::test]
+async fn set_lst_fee_ramp_fail_start_slot_in_past() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[mock_fee_account_args(lst_mint)],
+    );
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        slot: RAMP_ARGS.start_slot + 1,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = ctx;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = set_lst_fee_ramp_ix(
+        SetLstFeeRampByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        RAMP_ARGS,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, FlatFeeError::InvalidFeeRamp);
+
+    verify_no_fee_ramp(&mut banks_client, lst_mint).await;
+}
+
+#[tokio::test]
+async fn set_lst_fee_ramp_fail_invalid_fee() {
+    const BAD_RAMP_ARGS_1: SetLstFeeRampIxArgs = SetLstFeeRampIxArgs {
+        target_input_fee_bps: 10_001,
+        ..RAMP_ARGS
+    };
+    const BAD_RAMP_ARGS_2: SetLstFeeRampIxArgs = SetLstFeeRampIxArgs {
+        target_output_fee_bps: -10_001,
+        ..RAMP_ARGS
+    };
+
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[mock_fee_account_args(lst_mint)],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let keyed_state_acc = KeyedAccount {
+        pubkey: STATE_ID,
+        account: state_acc,
+    };
+
+    for bad_ramp_args in [BAD_RAMP_ARGS_1, BAD_RAMP_ARGS_2] {
+        let ix = set_lst_fee_ramp_ix(
+            SetLstFeeRampByMintFreeArgs {
+                lst_mint,
+                state_acc: &keyed_state_acc,
+            }
+            .resolve()
+            .unwrap(),
+            bad_ramp_args,
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+        assert_custom_err(err, FlatFeeError::SignedFeeOutOfBound);
+
+        verify_no_fee_ramp(&mut banks_client, lst_mint).await;
+    }
+}
+
+#[tokio::test]
+async fn set_lst_fee_ramp_fail_unauthorized() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[mock_fee_account_args(lst_mint)],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let mut keys = SetLstFeeRampByMintFreeArgs {
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+    keys.manager = payer.pubkey();
+
+    let ix = set_lst_fee_ramp_ix(keys, RAMP_ARGS).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    verify_no_fee_ramp(&mut banks_client, lst_mint).await;
+}
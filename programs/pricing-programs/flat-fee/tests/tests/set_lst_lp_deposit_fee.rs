@@ -0,0 +1,159 @@
+use flat_fee_interface::{
+    set_lst_lp_deposit_fee_ix, FlatFeeError, ProgramState, SetLstLpDepositFeeIxArgs,
+    SetLstLpDepositFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLstLpDepositFeeByMintFreeArgs,
+    pda::FeeAccountFindPdaArgs,
+    program::{self, STATE_ID},
+    utils::try_fee_account,
+};
+use flat_fee_test_utils::MockFeeAccountArgs;
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::*;
+
+fn program_test_with_lst(manager: Pubkey, lst_mint: Pubkey) -> ProgramTest {
+    normal_program_test(
+        ProgramState {
+            manager,
+            lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lst_mint,
+        }],
+    )
+}
+
+async fn verify_lp_deposit_fee_bps_override(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    expected: Option<u16>,
+) {
+    let (addr, _bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let fee_acc = banks_client.get_account_unwrapped(addr).await;
+    let fee_acc = try_fee_account(&fee_acc.data).unwrap();
+    let actual = match fee_acc.has_lp_deposit_fee_bps_override {
+        0 => None,
+        _ => Some(fee_acc.lp_deposit_fee_bps_override),
+    };
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn set_lst_lp_deposit_fee_basic() {
+    const NEW_LP_DEPOSIT_FEE_BPS: u16 = 42;
+
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = program_test_with_lst(manager.pubkey(), lst_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let keys = SetLstLpDepositFeeByMintFreeArgs {
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+
+    for lp_deposit_fee_bps in [Some(NEW_LP_DEPOSIT_FEE_BPS), None] {
+        let ix = set_lst_lp_deposit_fee_ix(keys, SetLstLpDepositFeeIxArgs { lp_deposit_fee_bps })
+            .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        banks_client.process_transaction(tx).await.unwrap();
+
+        verify_lp_deposit_fee_bps_override(&mut banks_client, lst_mint, lp_deposit_fee_bps).await;
+    }
+}
+
+#[tokio::test]
+async fn set_lst_lp_deposit_fee_fail_invalid_fee() {
+    const BAD_LP_DEPOSIT_FEE_BPS: u16 = 10_001;
+
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = program_test_with_lst(manager.pubkey(), lst_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = set_lst_lp_deposit_fee_ix(
+        SetLstLpDepositFeeByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLstLpDepositFeeIxArgs {
+            lp_deposit_fee_bps: Some(BAD_LP_DEPOSIT_FEE_BPS),
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::UnsignedFeeOutOfBound);
+
+    verify_lp_deposit_fee_bps_override(&mut banks_client, lst_mint, None).await;
+}
+
+#[tokio::test]
+async fn set_lst_lp_deposit_fee_fail_unauthorized() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = program_test_with_lst(manager.pubkey(), lst_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let (fee_acc, _bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let ix = set_lst_lp_deposit_fee_ix(
+        SetLstLpDepositFeeKeys {
+            manager: payer.pubkey(),
+            fee_acc,
+            state: STATE_ID,
+        },
+        SetLstLpDepositFeeIxArgs {
+            lp_deposit_fee_bps: Some(69),
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    verify_lp_deposit_fee_bps_override(&mut banks_client, lst_mint, None).await;
+}
@@ -4,7 +4,7 @@ use flat_fee_lib::{
 };
 use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
 use sanctum_solana_test_utils::{assert_program_error, ExtendedBanksClient};
-use solana_program::program_error::ProgramError;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
 
@@ -20,6 +20,10 @@ async fn set_manager_basic() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         &[],
     );
@@ -48,7 +52,9 @@ async fn set_manager_basic() {
     let state_acc = banks_client.get_flat_fee_program_state().await;
     let state = try_program_state(&state_acc.data).unwrap();
 
-    assert_eq!(state.manager, new_manager.pubkey());
+    // manager only changes once new_manager accepts
+    assert_eq!(state.manager, manager.pubkey());
+    assert_eq!(state.pending_manager, new_manager.pubkey());
 }
 
 #[tokio::test]
@@ -61,6 +67,10 @@ async fn set_manager_fail_unauthorized() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         &[],
     );
@@ -92,4 +102,5 @@ async fn set_manager_fail_unauthorized() {
     let state = try_program_state(&state_acc.data).unwrap();
 
     assert_eq!(state.manager, manager.pubkey());
+    assert_eq!(state.pending_manager, Pubkey::default());
 }
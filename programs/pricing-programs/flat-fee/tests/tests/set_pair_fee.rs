@@ -0,0 +1,143 @@
+use flat_fee_interface::{set_pair_fee_ix, FlatFeeError, ProgramState, SetPairFeeIxArgs};
+use flat_fee_lib::{account_resolvers::SetPairFeeByMintsFreeArgs, program::STATE_ID};
+use flat_fee_test_utils::MockPairFeeAccountArgs;
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::*;
+
+const INITIAL_FEE_BPS: i16 = 2;
+
+fn state_with_manager(manager: &Keypair) -> ProgramState {
+    ProgramState {
+        manager: manager.pubkey(),
+        lp_withdrawal_fee_bps: Default::default(),
+        pending_manager: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        epoch_surcharge_bps: Default::default(),
+        epoch_surcharge_slots: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn set_pair_fee_basic() {
+    const FEE_BPS: i16 = -1;
+
+    let manager = Keypair::new();
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        state_with_manager(&manager),
+        &[],
+        &[MockPairFeeAccountArgs {
+            fee_bps: INITIAL_FEE_BPS,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = set_pair_fee_ix(
+        SetPairFeeByMintsFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetPairFeeIxArgs { fee_bps: FEE_BPS },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_pair_fee_account(&mut banks_client, input_lst_mint, output_lst_mint, FEE_BPS).await;
+}
+
+#[tokio::test]
+async fn set_pair_fee_fail_unauthorized() {
+    let manager = Keypair::new();
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = pair_fee_program_test(
+        state_with_manager(&manager),
+        &[],
+        &[MockPairFeeAccountArgs {
+            fee_bps: INITIAL_FEE_BPS,
+            input_lst_mint,
+            output_lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let mut keys = SetPairFeeByMintsFreeArgs {
+        input_lst_mint,
+        output_lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+    keys.manager = payer.pubkey();
+    let ix = set_pair_fee_ix(keys, SetPairFeeIxArgs { fee_bps: 0 }).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    verify_pair_fee_account(
+        &mut banks_client,
+        input_lst_mint,
+        output_lst_mint,
+        INITIAL_FEE_BPS,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn set_pair_fee_fail_pair_fee_account_not_exists() {
+    let manager = Keypair::new();
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(state_with_manager(&manager), &[]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = set_pair_fee_ix(
+        SetPairFeeByMintsFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetPairFeeIxArgs { fee_bps: 0 },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::UnsupportedLstPair);
+}
@@ -0,0 +1,41 @@
+use imbalance_fee_interface::ImbalanceFeeProgramIx;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::processor::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if *program_id != imbalance_fee_lib::program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let ix = ImbalanceFeeProgramIx::deserialize(instruction_data)?;
+    solana_program::msg!("{:?}", ix);
+
+    match ix {
+        ImbalanceFeeProgramIx::PriceExactIn(args) => process_price_exact_in(accounts, args),
+        ImbalanceFeeProgramIx::PriceExactOut(args) => process_price_exact_out(accounts, args),
+        ImbalanceFeeProgramIx::PriceLpTokensToMint(args) => {
+            process_price_lp_tokens_to_mint(accounts, args)
+        }
+        ImbalanceFeeProgramIx::PriceLpTokensToRedeem(args) => {
+            process_price_lp_tokens_to_redeem(accounts, args)
+        }
+        ImbalanceFeeProgramIx::AcceptManager => process_accept_manager(accounts),
+        ImbalanceFeeProgramIx::SetFeeParams(args) => process_set_fee_params(accounts, args),
+        ImbalanceFeeProgramIx::SetTargetWeight(args) => process_set_target_weight(accounts, args),
+        ImbalanceFeeProgramIx::RemoveLst => process_remove_lst(accounts),
+        ImbalanceFeeProgramIx::AddLst(args) => process_add_lst(accounts, args),
+        ImbalanceFeeProgramIx::SetManager => process_set_manager(accounts),
+        ImbalanceFeeProgramIx::Initialize => process_initialize(accounts),
+    }
+}
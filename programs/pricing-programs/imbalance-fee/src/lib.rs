@@ -0,0 +1,2 @@
+pub mod entrypoint;
+pub mod processor;
@@ -0,0 +1,79 @@
+use imbalance_fee_interface::{
+    add_lst_verify_account_keys, add_lst_verify_account_privileges, AddLstAccounts, AddLstIxArgs,
+    AddLstKeys,
+};
+use imbalance_fee_lib::{
+    account_resolvers::AddLstFreeArgs, fee_bound::verify_target_weight_bps_bound,
+    pda::TargetWeightCreatePdaArgs, program, utils::try_target_weight_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_s_common::token::verify_tokenkeg_or_22_mint;
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use system_program_interface::CreateAccountAccounts;
+
+pub fn process_add_lst(accounts: &[AccountInfo], args: AddLstIxArgs) -> ProgramResult {
+    let (
+        AddLstAccounts {
+            payer, target_acc, ..
+        },
+        AddLstIxArgs { target_weight_bps },
+        create_pda_args,
+    ) = verify_add_lst(accounts, args)?;
+
+    init_rent_exempt_account_invoke_signed(
+        CreateAccountAccounts {
+            from: payer,
+            to: target_acc,
+        },
+        InitRentExemptAccountArgs {
+            space: program::TARGET_WEIGHT_SIZE,
+            owner: program::ID,
+        },
+        &[create_pda_args.to_signer_seeds().as_slice()],
+    )?;
+
+    let mut bytes = target_acc.try_borrow_mut_data()?;
+    let target_acc = try_target_weight_mut(&mut bytes)?;
+
+    target_acc.bump = create_pda_args.bump;
+    target_acc.target_weight_bps = target_weight_bps;
+
+    Ok(())
+}
+
+fn verify_add_lst<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    args: AddLstIxArgs,
+) -> Result<
+    (
+        AddLstAccounts<'me, 'info>,
+        AddLstIxArgs,
+        TargetWeightCreatePdaArgs,
+    ),
+    ProgramError,
+> {
+    let actual: AddLstAccounts = load_accounts(accounts)?;
+
+    let free_args = AddLstFreeArgs {
+        payer: *actual.payer.key,
+        state_acc: actual.state,
+        lst_mint: *actual.lst_mint.key,
+    };
+    let (expected, target_weight_create_pda_args): (AddLstKeys, TargetWeightCreatePdaArgs) =
+        free_args.resolve()?;
+
+    add_lst_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    add_lst_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_tokenkeg_or_22_mint(actual.lst_mint)?;
+    verify_target_weight_bps_bound(args.target_weight_bps)?;
+
+    Ok((actual, args, target_weight_create_pda_args))
+}
@@ -0,0 +1,77 @@
+use imbalance_fee_interface::{
+    price_exact_in_verify_account_keys, PriceExactInAccounts, PriceExactInIxArgs, PriceExactInKeys,
+};
+use imbalance_fee_lib::{
+    account_resolvers::{PriceExactInFreeArgs, PriceExactInWithBumpFreeArgs},
+    calc::{calculate_price_exact_in, ImbalanceFeeArgs, PoolBalance},
+    utils::{try_lst_state_list, try_program_state, try_target_weight},
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
+    program_error::ProgramError,
+};
+
+pub fn process_price_exact_in(
+    accounts: &[AccountInfo],
+    PriceExactInIxArgs { sol_value, .. }: PriceExactInIxArgs,
+) -> ProgramResult {
+    let PriceExactInAccounts {
+        input_lst_mint,
+        output_lst_mint,
+        state,
+        lst_state_list,
+        input_target_acc,
+        output_target_acc,
+    } = verify_price_exact_in(accounts)?;
+
+    let state_bytes = state.try_borrow_data()?;
+    let state = try_program_state(&state_bytes)?;
+    let lst_state_list_bytes = lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let input_target_acc_bytes = input_target_acc.try_borrow_data()?;
+    let input_target_acc = try_target_weight(&input_target_acc_bytes)?;
+    let output_target_acc_bytes = output_target_acc.try_borrow_data()?;
+    let output_target_acc = try_target_weight(&output_target_acc_bytes)?;
+
+    let args = ImbalanceFeeArgs {
+        fee_params: state.into(),
+        pool_balance: PoolBalance::try_from_lst_state_list(
+            lst_state_list,
+            *input_lst_mint.key,
+            *output_lst_mint.key,
+        )?,
+        input_target_weight_bps: input_target_acc.target_weight_bps,
+        output_target_weight_bps: output_target_acc.target_weight_bps,
+    };
+    let result = calculate_price_exact_in(&args, sol_value)?;
+    let result_le = result.to_le_bytes();
+    set_return_data(&result_le);
+
+    Ok(())
+}
+
+fn verify_price_exact_in<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<PriceExactInAccounts<'me, 'info>, ProgramError> {
+    let actual: PriceExactInAccounts = load_accounts(accounts)?;
+
+    let input_target_acc_bytes = actual.input_target_acc.try_borrow_data()?;
+    let input_target_acc_bump = try_target_weight(&input_target_acc_bytes)?.bump;
+    let output_target_acc_bytes = actual.output_target_acc.try_borrow_data()?;
+    let output_target_acc_bump = try_target_weight(&output_target_acc_bytes)?.bump;
+
+    let free_args = PriceExactInWithBumpFreeArgs {
+        args: PriceExactInFreeArgs {
+            input_lst_mint: *actual.input_lst_mint.key,
+            output_lst_mint: *actual.output_lst_mint.key,
+        },
+        input_target_acc_bump,
+        output_target_acc_bump,
+    };
+    let expected: PriceExactInKeys = free_args.resolve()?;
+
+    price_exact_in_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+
+    Ok(actual)
+}
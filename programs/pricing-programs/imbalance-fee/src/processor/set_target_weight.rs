@@ -0,0 +1,50 @@
+use imbalance_fee_interface::{
+    set_target_weight_verify_account_keys, set_target_weight_verify_account_privileges,
+    SetTargetWeightAccounts, SetTargetWeightIxArgs, SetTargetWeightKeys,
+};
+use imbalance_fee_lib::{
+    account_resolvers::SetTargetWeightFreeArgs, fee_bound::verify_target_weight_bps_bound,
+    utils::try_target_weight_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_set_target_weight(
+    accounts: &[AccountInfo],
+    args: SetTargetWeightIxArgs,
+) -> ProgramResult {
+    let SetTargetWeightAccounts { target_acc, .. } = verify_set_target_weight(accounts, &args)?;
+
+    let mut bytes = target_acc.try_borrow_mut_data()?;
+    let target_acc = try_target_weight_mut(&mut bytes)?;
+
+    target_acc.target_weight_bps = args.target_weight_bps;
+
+    Ok(())
+}
+
+fn verify_set_target_weight<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetTargetWeightIxArgs { target_weight_bps }: &SetTargetWeightIxArgs,
+) -> Result<SetTargetWeightAccounts<'me, 'info>, ProgramError> {
+    let actual: SetTargetWeightAccounts = load_accounts(accounts)?;
+
+    let free_args = SetTargetWeightFreeArgs {
+        state_acc: actual.state,
+        target_acc: *actual.target_acc.key,
+    };
+    let expected: SetTargetWeightKeys = free_args.resolve()?;
+
+    set_target_weight_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_target_weight_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_target_weight_bps_bound(*target_weight_bps)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,5 @@
+mod program_test;
+mod target_weight;
+
+pub use program_test::*;
+pub use target_weight::*;
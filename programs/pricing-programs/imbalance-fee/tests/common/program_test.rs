@@ -0,0 +1,69 @@
+use imbalance_fee_interface::ProgramState;
+use imbalance_fee_lib::program::STATE_ID;
+use imbalance_fee_test_utils::{MockProgramState, MockTargetWeight, MockTargetWeightArgs};
+use s_controller_interface::LstState;
+use s_controller_test_utils::LstStateListProgramTest;
+use sanctum_solana_test_utils::IntoAccount;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+
+pub fn normal_program_test(
+    state: ProgramState,
+    mock_target_weights: &[MockTargetWeightArgs],
+) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "imbalance_fee",
+        imbalance_fee_lib::program::ID,
+        processor!(imbalance_fee::entrypoint::process_instruction),
+    );
+    program_test.add_account(STATE_ID, MockProgramState(state).into_account());
+    for mtw in mock_target_weights {
+        let (acc, addr) = mtw.to_target_weight_and_addr(imbalance_fee_lib::program::ID);
+        program_test.add_account(addr, MockTargetWeight(acc).into_account());
+    }
+    program_test
+}
+
+pub fn program_state_with_manager(manager: Pubkey) -> ProgramState {
+    ProgramState {
+        manager,
+        pending_manager: Default::default(),
+        lp_withdrawal_fee_bps: Default::default(),
+        base_fee_bps: Default::default(),
+        imbalance_slope_bps: Default::default(),
+        max_imbalance_fee_bps: Default::default(),
+        max_discount_bps: Default::default(),
+    }
+}
+
+pub struct MockPoolLst {
+    pub mint: Pubkey,
+    pub sol_value: u64,
+    pub target_weight_bps: u16,
+}
+
+/// Adds the LSTs to the S controller's LstStateList and creates their TargetWeight accounts
+pub fn pricing_program_test(state: ProgramState, pool_lsts: &[MockPoolLst]) -> ProgramTest {
+    let mock_target_weights: Vec<MockTargetWeightArgs> = pool_lsts
+        .iter()
+        .map(|lst| MockTargetWeightArgs {
+            target_weight_bps: lst.target_weight_bps,
+            lst_mint: lst.mint,
+        })
+        .collect();
+    let lst_states: Vec<LstState> = pool_lsts.iter().map(mock_lst_state).collect();
+    normal_program_test(state, &mock_target_weights).add_lst_state_list(&lst_states)
+}
+
+pub fn mock_lst_state(pool_lst: &MockPoolLst) -> LstState {
+    LstState {
+        is_input_disabled: 0,
+        pool_reserves_bump: 0,
+        protocol_fee_accumulator_bump: 0,
+        padding: Default::default(),
+        sol_value: pool_lst.sol_value,
+        mint: pool_lst.mint,
+        sol_value_calculator: Pubkey::default(),
+    }
+}
@@ -0,0 +1,29 @@
+use imbalance_fee_lib::{pda::TargetWeightFindPdaArgs, program, utils::try_target_weight};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClient;
+
+pub async fn verify_target_weight(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    expected_target_weight_bps: u16,
+) {
+    let find_pda_args = TargetWeightFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    };
+    let (addr, bump) = find_pda_args.get_target_weight_address_and_bump_seed();
+    let actual_acc = banks_client.get_account_unwrapped(addr).await;
+    let actual = try_target_weight(&actual_acc.data).unwrap();
+    assert_eq!(actual.bump, bump);
+    assert_eq!(actual.target_weight_bps, expected_target_weight_bps);
+}
+
+pub async fn verify_target_weight_does_not_exist(banks_client: &mut BanksClient, lst_mint: Pubkey) {
+    let find_pda_args = TargetWeightFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    };
+    let (addr, _bump) = find_pda_args.get_target_weight_address_and_bump_seed();
+    assert!(banks_client.get_account(addr).await.unwrap().is_none());
+}
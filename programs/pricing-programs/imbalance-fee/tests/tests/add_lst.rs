@@ -0,0 +1,140 @@
+use imbalance_fee_interface::{add_lst_ix, AddLstIxArgs, AddLstKeys, ImbalanceFeeError};
+use imbalance_fee_lib::{
+    account_resolvers::AddLstFreeArgs,
+    pda::TargetWeightFindPdaArgs,
+    program::{self, STATE_ID},
+};
+use imbalance_fee_test_utils::ImbalanceFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::{
+    assert_custom_err, assert_program_error,
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs},
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::{
+    normal_program_test, program_state_with_manager, verify_target_weight,
+    verify_target_weight_does_not_exist,
+};
+
+fn add_lst_program_test() -> (ProgramTest, Keypair, Pubkey) {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(program_state_with_manager(manager.pubkey()), &[])
+        .add_tokenkeg_mint_from_args(
+            lst_mint,
+            MockMintArgs {
+                mint_authority: None,
+                freeze_authority: None,
+                supply: 0,
+                decimals: 9,
+            },
+        );
+    (program_test, manager, lst_mint)
+}
+
+#[tokio::test]
+async fn add_lst_basic() {
+    const TARGET_WEIGHT_BPS: u16 = 2_500;
+
+    let (program_test, manager, lst_mint) = add_lst_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    verify_target_weight_does_not_exist(&mut banks_client, lst_mint).await;
+    let state_acc = banks_client.get_imbalance_fee_program_state().await;
+
+    let free_args = AddLstFreeArgs {
+        payer: payer.pubkey(),
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    };
+    let (keys, _pda) = free_args.resolve().unwrap();
+    let ix = add_lst_ix(
+        keys,
+        AddLstIxArgs {
+            target_weight_bps: TARGET_WEIGHT_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_target_weight(&mut banks_client, lst_mint, TARGET_WEIGHT_BPS).await;
+}
+
+#[tokio::test]
+async fn add_lst_fail_invalid_target_weight() {
+    let (program_test, manager, lst_mint) = add_lst_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_imbalance_fee_program_state().await;
+
+    let free_args = AddLstFreeArgs {
+        payer: payer.pubkey(),
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    };
+    let (keys, _pda) = free_args.resolve().unwrap();
+    let ix = add_lst_ix(
+        keys,
+        AddLstIxArgs {
+            target_weight_bps: 10_001,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, ImbalanceFeeError::TargetWeightOutOfBound);
+
+    verify_target_weight_does_not_exist(&mut banks_client, lst_mint).await;
+}
+
+#[tokio::test]
+async fn add_lst_fail_unauthorized() {
+    let (program_test, _manager, lst_mint) = add_lst_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let find_pda_args = TargetWeightFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    };
+    let (target_acc, _bump) = find_pda_args.get_target_weight_address_and_bump_seed();
+
+    let ix = add_lst_ix(
+        AddLstKeys {
+            manager: payer.pubkey(),
+            payer: payer.pubkey(),
+            target_acc,
+            lst_mint,
+            state: STATE_ID,
+            system_program: solana_program::system_program::ID,
+        },
+        AddLstIxArgs {
+            target_weight_bps: 0,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    verify_target_weight_does_not_exist(&mut banks_client, lst_mint).await;
+}
@@ -0,0 +1,6 @@
+mod add_lst;
+mod initialize;
+mod price_exact_in;
+mod price_exact_out;
+mod set_fee_params;
+mod set_target_weight;
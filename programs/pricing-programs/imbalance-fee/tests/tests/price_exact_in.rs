@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use imbalance_fee_interface::{
+    price_exact_in_ix, ImbalanceFeeError, PriceExactInIxArgs, ProgramState,
+};
+use imbalance_fee_lib::account_resolvers::PriceExactInFreeArgs;
+use imbalance_fee_test_utils::MockTargetWeightArgs;
+use pricing_programs_interface::PriceExactInKeys;
+use s_controller_lib::{program::LST_STATE_LIST_ID, try_lst_state_list_mut};
+use s_controller_test_utils::LstStateListProgramTest;
+use s_pricing_prog_aggregate::{
+    ImbalanceFeePricingProg, MutablePricingProg, PricingProg, SyncedSolValues,
+};
+use sanctum_solana_test_utils::assert_custom_err;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::*;
+
+const TOTAL_SOL_VALUE: u64 = 1_000_000_000;
+const TRADE_SOL_VALUE: u64 = 10_000_000;
+
+fn fee_params_state(max_discount_bps: u16) -> ProgramState {
+    ProgramState {
+        base_fee_bps: 10,
+        imbalance_slope_bps: 10_000,
+        max_imbalance_fee_bps: 10_000,
+        max_discount_bps,
+        ..program_state_with_manager(Pubkey::default())
+    }
+}
+
+/// Pool is at 60/40 with 50/50 targets
+fn overweight_underweight_lsts() -> (MockPoolLst, MockPoolLst) {
+    (
+        MockPoolLst {
+            mint: Pubkey::new_unique(),
+            sol_value: TOTAL_SOL_VALUE * 6 / 10,
+            target_weight_bps: 5_000,
+        },
+        MockPoolLst {
+            mint: Pubkey::new_unique(),
+            sol_value: TOTAL_SOL_VALUE * 4 / 10,
+            target_weight_bps: 5_000,
+        },
+    )
+}
+
+#[tokio::test]
+async fn price_exact_in_worsening_imbalance_charges_fee() {
+    let (overweight, underweight) = overweight_underweight_lsts();
+    let input_lst_mint = overweight.mint;
+    let output_lst_mint = underweight.mint;
+    let program_test = pricing_program_test(fee_params_state(10_000), &[overweight, underweight]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: TRADE_SOL_VALUE,
+            sol_value: TRADE_SOL_VALUE,
+        },
+    )
+    .unwrap();
+    // 60/40 -> 61/39: +100 bps deviation on both sides, total fee 10 + 200 = 210 bps
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 9_790_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_restoring_balance_gives_discount() {
+    let (overweight, underweight) = overweight_underweight_lsts();
+    let input_lst_mint = underweight.mint;
+    let output_lst_mint = overweight.mint;
+    let program_test = pricing_program_test(fee_params_state(5), &[overweight, underweight]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: TRADE_SOL_VALUE,
+            sol_value: TRADE_SOL_VALUE,
+        },
+    )
+    .unwrap();
+    // discount capped at max_discount_bps, total fee 10 - 5 = 5 bps
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 9_995_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_fail_lst_not_in_pool() {
+    let (overweight, underweight) = overweight_underweight_lsts();
+    let input_lst_mint = overweight.mint;
+    let output_lst_mint = underweight.mint;
+    let program_test = normal_program_test(
+        fee_params_state(10_000),
+        &[
+            MockTargetWeightArgs {
+                target_weight_bps: overweight.target_weight_bps,
+                lst_mint: input_lst_mint,
+            },
+            MockTargetWeightArgs {
+                target_weight_bps: underweight.target_weight_bps,
+                lst_mint: output_lst_mint,
+            },
+        ],
+    )
+    .add_lst_state_list(&[mock_lst_state(&overweight)]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: TRADE_SOL_VALUE,
+            sol_value: TRADE_SOL_VALUE,
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, ImbalanceFeeError::LstNotInPool);
+}
+
+#[tokio::test]
+async fn price_exact_in_matches_quote_on_synced_sol_values() {
+    let (overweight, underweight) = overweight_underweight_lsts();
+    let input_lst_mint = overweight.mint;
+    let output_lst_mint = underweight.mint;
+    // LstStateList as fetched off-chain, before the swap's SyncSolValue
+    let stale_lst_states = [mock_lst_state(&overweight), mock_lst_state(&underweight)];
+    // the S controller syncs the input LST's SOL value down from 60% to 50%
+    // of the stale total before CPI-ing the pricing program
+    let synced_sol_values = SyncedSolValues {
+        input_lst_sol_value: TOTAL_SOL_VALUE * 5 / 10,
+        output_lst_sol_value: underweight.sol_value,
+    };
+    let program_test = pricing_program_test(
+        fee_params_state(10_000),
+        &[
+            MockPoolLst {
+                sol_value: synced_sol_values.input_lst_sol_value,
+                ..overweight
+            },
+            underweight,
+        ],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let mut pricing_prog = ImbalanceFeePricingProg::try_new(
+        imbalance_fee_lib::program::ID,
+        [input_lst_mint, output_lst_mint].into_iter(),
+    )
+    .unwrap();
+    let mut account_map = HashMap::new();
+    for addr in pricing_prog.get_accounts_to_update() {
+        let account = banks_client.get_account(addr).await.unwrap().unwrap();
+        account_map.insert(addr, account);
+    }
+    let lst_state_list_acc = account_map.get_mut(&LST_STATE_LIST_ID).unwrap();
+    try_lst_state_list_mut(&mut lst_state_list_acc.data)
+        .unwrap()
+        .copy_from_slice(&stale_lst_states);
+    pricing_prog.update(&account_map).unwrap();
+
+    let keys = PriceExactInKeys {
+        input_lst_mint,
+        output_lst_mint,
+    };
+    let args = pricing_programs_interface::PriceExactInIxArgs {
+        amount: TRADE_SOL_VALUE,
+        sol_value: TRADE_SOL_VALUE,
+    };
+    let quote = pricing_prog
+        .quote_exact_in_synced(keys, &args, &synced_sol_values)
+        .unwrap();
+    // quoting on the stale 60/40 balance undercharges
+    assert!(quote > pricing_prog.quote_exact_in(keys, &args).unwrap());
+
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: TRADE_SOL_VALUE,
+            sol_value: TRADE_SOL_VALUE,
+        },
+    )
+    .unwrap();
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, quote)
+        .await;
+}
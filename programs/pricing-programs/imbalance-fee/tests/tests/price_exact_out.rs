@@ -0,0 +1,54 @@
+use imbalance_fee_interface::{price_exact_out_ix, PriceExactOutIxArgs, ProgramState};
+use imbalance_fee_lib::account_resolvers::PriceExactOutFreeArgs;
+use solana_program::pubkey::Pubkey;
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::*;
+
+#[tokio::test]
+async fn price_exact_out_worsening_imbalance_charges_fee() {
+    const OUT_SOL_VALUE: u64 = 10_000_000;
+
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let program_test = pricing_program_test(
+        ProgramState {
+            base_fee_bps: 10,
+            imbalance_slope_bps: 10_000,
+            max_imbalance_fee_bps: 10_000,
+            max_discount_bps: 10_000,
+            ..program_state_with_manager(Pubkey::default())
+        },
+        &[
+            MockPoolLst {
+                mint: input_lst_mint,
+                sol_value: 600_000_000,
+                target_weight_bps: 5_000,
+            },
+            MockPoolLst {
+                mint: output_lst_mint,
+                sol_value: 400_000_000,
+                target_weight_bps: 5_000,
+            },
+        ],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_out_ix(
+        PriceExactOutFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve(),
+        PriceExactOutIxArgs {
+            amount: OUT_SOL_VALUE,
+            sol_value: OUT_SOL_VALUE,
+        },
+    )
+    .unwrap();
+    // 60/40 -> 61/39: total fee 10 + 200 = 210 bps,
+    // in_sol_value = ceil(10_000_000 * 10_000 / 9_790)
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 10_214_505)
+        .await;
+}
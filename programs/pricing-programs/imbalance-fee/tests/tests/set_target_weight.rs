@@ -0,0 +1,84 @@
+use imbalance_fee_interface::{set_target_weight_ix, ImbalanceFeeError, SetTargetWeightIxArgs};
+use imbalance_fee_lib::{account_resolvers::SetTargetWeightByMintFreeArgs, program::STATE_ID};
+use imbalance_fee_test_utils::MockTargetWeightArgs;
+use sanctum_solana_test_utils::{assert_custom_err, ExtendedBanksClient};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::*;
+
+const INITIAL_TARGET_WEIGHT_BPS: u16 = 1_000;
+
+fn set_target_weight_program_test() -> (ProgramTest, Keypair, Pubkey) {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        program_state_with_manager(manager.pubkey()),
+        &[MockTargetWeightArgs {
+            target_weight_bps: INITIAL_TARGET_WEIGHT_BPS,
+            lst_mint,
+        }],
+    );
+    (program_test, manager, lst_mint)
+}
+
+async fn set_target_weight_ix_for_mint(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    target_weight_bps: u16,
+) -> Instruction {
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    set_target_weight_ix(
+        SetTargetWeightByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetTargetWeightIxArgs { target_weight_bps },
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn set_target_weight_basic() {
+    const NEW_TARGET_WEIGHT_BPS: u16 = 10_000;
+
+    let (program_test, manager, lst_mint) = set_target_weight_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix =
+        set_target_weight_ix_for_mint(&mut banks_client, lst_mint, NEW_TARGET_WEIGHT_BPS).await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_target_weight(&mut banks_client, lst_mint, NEW_TARGET_WEIGHT_BPS).await;
+}
+
+#[tokio::test]
+async fn set_target_weight_fail_invalid_target_weight() {
+    const BAD_TARGET_WEIGHT_BPS: u16 = 10_001;
+
+    let (program_test, manager, lst_mint) = set_target_weight_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix =
+        set_target_weight_ix_for_mint(&mut banks_client, lst_mint, BAD_TARGET_WEIGHT_BPS).await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, ImbalanceFeeError::TargetWeightOutOfBound);
+
+    verify_target_weight(&mut banks_client, lst_mint, INITIAL_TARGET_WEIGHT_BPS).await;
+}
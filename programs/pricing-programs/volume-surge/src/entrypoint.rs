@@ -0,0 +1,40 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use volume_surge_interface::VolumeSurgeProgramIx;
+
+use crate::processor::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if *program_id != volume_surge_lib::program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let ix = VolumeSurgeProgramIx::deserialize(instruction_data)?;
+    solana_program::msg!("{:?}", ix);
+
+    match ix {
+        VolumeSurgeProgramIx::PriceExactIn(args) => process_price_exact_in(accounts, args),
+        VolumeSurgeProgramIx::PriceExactOut(args) => process_price_exact_out(accounts, args),
+        VolumeSurgeProgramIx::PriceLpTokensToMint(args) => {
+            process_price_lp_tokens_to_mint(accounts, args)
+        }
+        VolumeSurgeProgramIx::PriceLpTokensToRedeem(args) => {
+            process_price_lp_tokens_to_redeem(accounts, args)
+        }
+        VolumeSurgeProgramIx::AcceptManager => process_accept_manager(accounts),
+        VolumeSurgeProgramIx::SetFeeParams(args) => process_set_fee_params(accounts, args),
+        VolumeSurgeProgramIx::RemoveLst => process_remove_lst(accounts),
+        VolumeSurgeProgramIx::AddLst => process_add_lst(accounts),
+        VolumeSurgeProgramIx::SetManager => process_set_manager(accounts),
+        VolumeSurgeProgramIx::Initialize => process_initialize(accounts),
+    }
+}
@@ -0,0 +1,43 @@
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use volume_surge_interface::{
+    accept_manager_verify_account_keys, accept_manager_verify_account_privileges,
+    AcceptManagerAccounts, AcceptManagerKeys,
+};
+use volume_surge_lib::{account_resolvers::AcceptManagerFreeArgs, utils::try_program_state_mut};
+
+pub fn process_accept_manager(accounts: &[AccountInfo]) -> ProgramResult {
+    let AcceptManagerAccounts {
+        pending_manager,
+        state,
+    } = verify_accept_manager(accounts)?;
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+
+    state.manager = *pending_manager.key;
+    state.pending_manager = Pubkey::default();
+
+    Ok(())
+}
+
+fn verify_accept_manager<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<AcceptManagerAccounts<'me, 'info>, ProgramError> {
+    let actual: AcceptManagerAccounts = load_accounts(accounts)?;
+
+    let free_args = AcceptManagerFreeArgs {
+        state_acc: actual.state,
+    };
+    let expected: AcceptManagerKeys = free_args.resolve()?;
+
+    accept_manager_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    accept_manager_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,69 @@
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_s_common::token::verify_tokenkeg_or_22_mint;
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+use system_program_interface::CreateAccountAccounts;
+use volume_surge_interface::{
+    add_lst_verify_account_keys, add_lst_verify_account_privileges, AddLstAccounts, AddLstKeys,
+};
+use volume_surge_lib::{
+    account_resolvers::AddLstFreeArgs, pda::VolumeAccountCreatePdaArgs, program,
+    utils::try_volume_account_mut,
+};
+
+pub fn process_add_lst(accounts: &[AccountInfo]) -> ProgramResult {
+    let (
+        AddLstAccounts {
+            payer, volume_acc, ..
+        },
+        create_pda_args,
+    ) = verify_add_lst(accounts)?;
+
+    init_rent_exempt_account_invoke_signed(
+        CreateAccountAccounts {
+            from: payer,
+            to: volume_acc,
+        },
+        InitRentExemptAccountArgs {
+            space: program::VOLUME_ACCOUNT_SIZE,
+            owner: program::ID,
+        },
+        &[create_pda_args.to_signer_seeds().as_slice()],
+    )?;
+
+    let mut bytes = volume_acc.try_borrow_mut_data()?;
+    let volume_acc = try_volume_account_mut(&mut bytes)?;
+
+    volume_acc.bump = create_pda_args.bump;
+    volume_acc.last_update_slot = Clock::get()?.slot;
+
+    Ok(())
+}
+
+fn verify_add_lst<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<(AddLstAccounts<'me, 'info>, VolumeAccountCreatePdaArgs), ProgramError> {
+    let actual: AddLstAccounts = load_accounts(accounts)?;
+
+    let free_args = AddLstFreeArgs {
+        payer: *actual.payer.key,
+        state_acc: actual.state,
+        lst_mint: *actual.lst_mint.key,
+    };
+    let (expected, volume_account_create_pda_args): (AddLstKeys, VolumeAccountCreatePdaArgs) =
+        free_args.resolve()?;
+
+    add_lst_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    add_lst_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_tokenkeg_or_22_mint(actual.lst_mint)?;
+
+    Ok((actual, volume_account_create_pda_args))
+}
@@ -0,0 +1,107 @@
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::set_return_data,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+use volume_surge_interface::{
+    price_exact_in_verify_account_keys, price_exact_in_verify_account_privileges,
+    PriceExactInAccounts, PriceExactInIxArgs, PriceExactInKeys,
+};
+use volume_surge_lib::{
+    account_resolvers::{PriceExactInFreeArgs, PriceExactInWithBumpFreeArgs},
+    calc::{calculate_price_exact_in, pool_total_sol_value, DecayedVolume, SurgeFeeArgs},
+    utils::{try_lst_state_list, try_program_state, try_volume_account, try_volume_account_mut},
+};
+
+pub fn process_price_exact_in(
+    accounts: &[AccountInfo],
+    PriceExactInIxArgs { sol_value, .. }: PriceExactInIxArgs,
+) -> ProgramResult {
+    let PriceExactInAccounts {
+        state,
+        lst_state_list,
+        input_volume_acc,
+        output_volume_acc,
+        ..
+    } = verify_price_exact_in(accounts)?;
+
+    let current_slot = Clock::get()?.slot;
+    let state_bytes = state.try_borrow_data()?;
+    let state = try_program_state(&state_bytes)?;
+    let lst_state_list_bytes = lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+
+    let input_volume = {
+        let bytes = input_volume_acc.try_borrow_data()?;
+        DecayedVolume::at_slot(
+            try_volume_account(&bytes)?,
+            state.decay_window_slots,
+            current_slot,
+        )
+    };
+    let output_volume = {
+        let bytes = output_volume_acc.try_borrow_data()?;
+        DecayedVolume::at_slot(
+            try_volume_account(&bytes)?,
+            state.decay_window_slots,
+            current_slot,
+        )
+    };
+
+    let args = SurgeFeeArgs {
+        fee_params: state.into(),
+        total_sol_value: pool_total_sol_value(lst_state_list)?,
+        input_volume,
+        output_volume,
+    };
+    let result = calculate_price_exact_in(&args, sol_value)?;
+
+    // re-read each account before writing in case input and output are the same LST
+    {
+        let mut bytes = input_volume_acc.try_borrow_mut_data()?;
+        let input_volume_acc = try_volume_account_mut(&mut bytes)?;
+        DecayedVolume::at_slot(input_volume_acc, state.decay_window_slots, current_slot)
+            .with_inflow(sol_value)
+            .write_to(input_volume_acc, current_slot);
+    }
+    {
+        let mut bytes = output_volume_acc.try_borrow_mut_data()?;
+        let output_volume_acc = try_volume_account_mut(&mut bytes)?;
+        DecayedVolume::at_slot(output_volume_acc, state.decay_window_slots, current_slot)
+            .with_outflow(result)
+            .write_to(output_volume_acc, current_slot);
+    }
+
+    let result_le = result.to_le_bytes();
+    set_return_data(&result_le);
+
+    Ok(())
+}
+
+fn verify_price_exact_in<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<PriceExactInAccounts<'me, 'info>, ProgramError> {
+    let actual: PriceExactInAccounts = load_accounts(accounts)?;
+
+    let input_volume_acc_bytes = actual.input_volume_acc.try_borrow_data()?;
+    let input_volume_acc_bump = try_volume_account(&input_volume_acc_bytes)?.bump;
+    let output_volume_acc_bytes = actual.output_volume_acc.try_borrow_data()?;
+    let output_volume_acc_bump = try_volume_account(&output_volume_acc_bytes)?.bump;
+
+    let free_args = PriceExactInWithBumpFreeArgs {
+        args: PriceExactInFreeArgs {
+            input_lst_mint: *actual.input_lst_mint.key,
+            output_lst_mint: *actual.output_lst_mint.key,
+        },
+        input_volume_acc_bump,
+        output_volume_acc_bump,
+    };
+    let expected: PriceExactInKeys = free_args.resolve()?;
+
+    price_exact_in_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    price_exact_in_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
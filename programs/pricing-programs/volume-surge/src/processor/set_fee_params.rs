@@ -0,0 +1,56 @@
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use volume_surge_interface::{
+    set_fee_params_verify_account_keys, set_fee_params_verify_account_privileges,
+    SetFeeParamsAccounts, SetFeeParamsIxArgs, SetFeeParamsKeys,
+};
+use volume_surge_lib::{
+    account_resolvers::SetFeeParamsFreeArgs, fee_bound::verify_fee_bps_bound,
+    utils::try_program_state_mut,
+};
+
+pub fn process_set_fee_params(accounts: &[AccountInfo], args: SetFeeParamsIxArgs) -> ProgramResult {
+    let SetFeeParamsAccounts { state, .. } = verify_set_fee_params(accounts, &args)?;
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+
+    state.lp_withdrawal_fee_bps = args.lp_withdrawal_fee_bps;
+    state.base_fee_bps = args.base_fee_bps;
+    state.surge_slope_bps = args.surge_slope_bps;
+    state.max_surge_fee_bps = args.max_surge_fee_bps;
+    state.decay_window_slots = args.decay_window_slots;
+
+    Ok(())
+}
+
+fn verify_set_fee_params<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetFeeParamsIxArgs {
+        lp_withdrawal_fee_bps,
+        base_fee_bps,
+        max_surge_fee_bps,
+        ..
+    }: &SetFeeParamsIxArgs,
+) -> Result<SetFeeParamsAccounts<'me, 'info>, ProgramError> {
+    let actual: SetFeeParamsAccounts = load_accounts(accounts)?;
+
+    let free_args = SetFeeParamsFreeArgs {
+        state_acc: actual.state,
+    };
+    let expected: SetFeeParamsKeys = free_args.resolve()?;
+
+    set_fee_params_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_fee_params_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    // surge_slope_bps is a multiplier and is allowed to exceed 10_000
+    verify_fee_bps_bound(*lp_withdrawal_fee_bps)?;
+    verify_fee_bps_bound(*base_fee_bps)?;
+    verify_fee_bps_bound(*max_surge_fee_bps)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,63 @@
+use s_controller_lib::program::{POOL_STATE_BUMP, POOL_STATE_ID, POOL_STATE_SEED};
+use sanctum_misc_utils::ToAccountMeta;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{get_return_data, invoke_signed, set_return_data},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Stands in for the S controller's CPI to the pricing program:
+/// accounts are `[pricing_program, ...pricing_program_accounts]`,
+/// data is forwarded as-is and the pool state PDA is signed for
+pub fn mock_s_controller_process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let (pricing_program, pricing_accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let ix = Instruction {
+        program_id: *pricing_program.key,
+        accounts: pricing_accounts
+            .iter()
+            .map(|a| {
+                let mut meta = a.to_account_meta();
+                if *a.key == POOL_STATE_ID {
+                    meta.is_signer = true;
+                }
+                meta
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+    invoke_signed(
+        &ix,
+        pricing_accounts,
+        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+    )?;
+    // re-set so that the transaction's return data is attributed to the outer instruction
+    let (_pk, res) = get_return_data().ok_or(ProgramError::InvalidInstructionData)?;
+    set_return_data(&res);
+    Ok(())
+}
+
+/// Wraps a pricing program instruction in a call to the mock S controller,
+/// which CPIs it with the pool state PDA's signature
+pub fn via_s_controller(ix: Instruction) -> Instruction {
+    let mut accounts = vec![AccountMeta::new_readonly(ix.program_id, false)];
+    accounts.extend(ix.accounts.into_iter().map(|mut meta| {
+        if meta.pubkey == POOL_STATE_ID {
+            meta.is_signer = false;
+        }
+        meta
+    }));
+    Instruction {
+        program_id: s_controller_lib::program::ID,
+        accounts,
+        data: ix.data,
+    }
+}
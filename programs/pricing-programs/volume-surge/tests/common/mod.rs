@@ -0,0 +1,7 @@
+mod mock_s_controller;
+mod program_test;
+mod volume_account;
+
+pub use mock_s_controller::*;
+pub use program_test::*;
+pub use volume_account::*;
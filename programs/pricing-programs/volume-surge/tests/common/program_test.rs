@@ -0,0 +1,70 @@
+use s_controller_interface::LstState;
+use s_controller_test_utils::LstStateListProgramTest;
+use sanctum_solana_test_utils::IntoAccount;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+use volume_surge_interface::ProgramState;
+use volume_surge_lib::program::STATE_ID;
+use volume_surge_test_utils::{MockProgramState, MockVolumeAccount, MockVolumeAccountArgs};
+
+use super::mock_s_controller_process_instruction;
+
+pub fn normal_program_test(
+    state: ProgramState,
+    mock_volume_accounts: &[MockVolumeAccountArgs],
+) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "volume_surge",
+        volume_surge_lib::program::ID,
+        processor!(volume_surge::entrypoint::process_instruction),
+    );
+    program_test.add_program(
+        "mock_s_controller",
+        s_controller_lib::program::ID,
+        processor!(mock_s_controller_process_instruction),
+    );
+    program_test.add_account(STATE_ID, MockProgramState(state).into_account());
+    for mva in mock_volume_accounts {
+        let (acc, addr) = mva.to_volume_account_and_addr(volume_surge_lib::program::ID);
+        program_test.add_account(addr, MockVolumeAccount(acc).into_account());
+    }
+    program_test
+}
+
+pub fn program_state_with_manager(manager: Pubkey) -> ProgramState {
+    ProgramState {
+        manager,
+        pending_manager: Default::default(),
+        lp_withdrawal_fee_bps: Default::default(),
+        base_fee_bps: Default::default(),
+        surge_slope_bps: Default::default(),
+        max_surge_fee_bps: Default::default(),
+        decay_window_slots: Default::default(),
+    }
+}
+
+pub struct MockPoolLst {
+    pub sol_value: u64,
+    pub volume: MockVolumeAccountArgs,
+}
+
+/// Adds the LSTs to the S controller's LstStateList and creates their VolumeAccounts
+pub fn pricing_program_test(state: ProgramState, pool_lsts: &[MockPoolLst]) -> ProgramTest {
+    let mock_volume_accounts: Vec<MockVolumeAccountArgs> =
+        pool_lsts.iter().map(|lst| lst.volume).collect();
+    let lst_states: Vec<LstState> = pool_lsts.iter().map(mock_lst_state).collect();
+    normal_program_test(state, &mock_volume_accounts).add_lst_state_list(&lst_states)
+}
+
+pub fn mock_lst_state(pool_lst: &MockPoolLst) -> LstState {
+    LstState {
+        is_input_disabled: 0,
+        pool_reserves_bump: 0,
+        protocol_fee_accumulator_bump: 0,
+        padding: Default::default(),
+        sol_value: pool_lst.sol_value,
+        mint: pool_lst.volume.lst_mint,
+        sol_value_calculator: Pubkey::default(),
+    }
+}
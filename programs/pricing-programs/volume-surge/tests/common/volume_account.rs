@@ -0,0 +1,29 @@
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClient;
+use volume_surge_interface::VolumeAccount;
+use volume_surge_lib::{pda::VolumeAccountFindPdaArgs, program, utils::try_volume_account};
+
+pub async fn get_volume_account(banks_client: &mut BanksClient, lst_mint: Pubkey) -> VolumeAccount {
+    let find_pda_args = VolumeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    };
+    let (addr, bump) = find_pda_args.get_volume_account_address_and_bump_seed();
+    let actual_acc = banks_client.get_account_unwrapped(addr).await;
+    let actual = *try_volume_account(&actual_acc.data).unwrap();
+    assert_eq!(actual.bump, bump);
+    actual
+}
+
+pub async fn verify_volume_account_does_not_exist(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+) {
+    let find_pda_args = VolumeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    };
+    let (addr, _bump) = find_pda_args.get_volume_account_address_and_bump_seed();
+    assert!(banks_client.get_account(addr).await.unwrap().is_none());
+}
@@ -0,0 +1,225 @@
+use sanctum_solana_test_utils::assert_program_error;
+use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
+use test_utils::BorshReturnDataBanksClient;
+use volume_surge_interface::{price_exact_in_ix, PriceExactInIxArgs, ProgramState};
+use volume_surge_lib::account_resolvers::PriceExactInFreeArgs;
+use volume_surge_test_utils::MockVolumeAccountArgs;
+
+use crate::common::*;
+
+const LST_SOL_VALUE: u64 = 500_000_000;
+const TRADE_SOL_VALUE: u64 = 10_000_000;
+const DECAY_WINDOW_SLOTS: u64 = 1_000;
+
+fn fee_params_state() -> ProgramState {
+    ProgramState {
+        base_fee_bps: 10,
+        surge_slope_bps: 10_000,
+        max_surge_fee_bps: 500,
+        decay_window_slots: DECAY_WINDOW_SLOTS,
+        ..program_state_with_manager(Pubkey::default())
+    }
+}
+
+/// 2 LSTs making up a pool of 1_000_000_000 SOL value,
+/// 20_000_000 of the input LST was swapped in at slot 0
+fn pool_lsts_with_recent_inflow(
+    input_lst_mint: Pubkey,
+    output_lst_mint: Pubkey,
+) -> [MockPoolLst; 2] {
+    [
+        MockPoolLst {
+            sol_value: LST_SOL_VALUE,
+            volume: MockVolumeAccountArgs {
+                lst_mint: input_lst_mint,
+                inflow_sol_value: 20_000_000,
+                ..Default::default()
+            },
+        },
+        MockPoolLst {
+            sol_value: LST_SOL_VALUE,
+            volume: MockVolumeAccountArgs {
+                lst_mint: output_lst_mint,
+                ..Default::default()
+            },
+        },
+    ]
+}
+
+/// Pricing instruction as called directly by a client
+fn price_exact_in_ix_for_mints(input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> Instruction {
+    price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: TRADE_SOL_VALUE,
+            sol_value: TRADE_SOL_VALUE,
+        },
+    )
+    .unwrap()
+}
+
+async fn start_at_slot(pool_lsts: &[MockPoolLst], slot: u64) -> ProgramTestContext {
+    let program_test = pricing_program_test(fee_params_state(), pool_lsts);
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        slot,
+        ..Default::default()
+    });
+    ctx
+}
+
+async fn exec_price_exact_in_at_slot(slot: u64, expected: u64) {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = start_at_slot(
+        &pool_lsts_with_recent_inflow(input_lst_mint, output_lst_mint),
+        slot,
+    )
+    .await;
+
+    let ix = via_s_controller(price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint));
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, expected)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_one_sided_flow_surges() {
+    // input net inflow 20_000_000 + 10_000_000 = 3% of pool, total fee 10 + 300 = 310 bps
+    exec_price_exact_in_at_slot(0, 9_690_000).await;
+}
+
+#[tokio::test]
+async fn price_exact_in_recent_flow_decays() {
+    // input net inflow 10_000_000 + 10_000_000 = 2% of pool, total fee 10 + 200 = 210 bps
+    exec_price_exact_in_at_slot(DECAY_WINDOW_SLOTS / 2, 9_790_000).await;
+}
+
+#[tokio::test]
+async fn price_exact_in_recent_flow_fully_decayed() {
+    // only this swap counts, 1% of pool, total fee 10 + 100 = 110 bps
+    exec_price_exact_in_at_slot(DECAY_WINDOW_SLOTS, 9_890_000).await;
+}
+
+#[tokio::test]
+async fn price_exact_in_opposite_flow_offsets_surge() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let [mut input_lst, mut output_lst] =
+        pool_lsts_with_recent_inflow(input_lst_mint, output_lst_mint);
+    input_lst.volume.outflow_sol_value = 30_000_000;
+    output_lst.volume.inflow_sol_value = 10_000_000;
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = start_at_slot(&[input_lst, output_lst], 0).await;
+
+    let ix = via_s_controller(price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint));
+    // no net one-sided flow after this swap, only base fee of 10 bps
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 9_990_000)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_records_volume() {
+    const SLOT: u64 = DECAY_WINDOW_SLOTS / 2;
+
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = start_at_slot(
+        &pool_lsts_with_recent_inflow(input_lst_mint, output_lst_mint),
+        SLOT,
+    )
+    .await;
+
+    let ix = via_s_controller(price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint));
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let input_volume_acc = get_volume_account(&mut banks_client, input_lst_mint).await;
+    assert_eq!(input_volume_acc.last_update_slot, SLOT);
+    // 20_000_000 decayed by half + 10_000_000
+    assert_eq!(input_volume_acc.inflow_sol_value, 20_000_000);
+    assert_eq!(input_volume_acc.outflow_sol_value, 0);
+
+    let output_volume_acc = get_volume_account(&mut banks_client, output_lst_mint).await;
+    assert_eq!(output_volume_acc.last_update_slot, SLOT);
+    assert_eq!(output_volume_acc.inflow_sol_value, 0);
+    assert_eq!(output_volume_acc.outflow_sol_value, 9_790_000);
+}
+
+#[tokio::test]
+async fn price_exact_in_fail_readonly_volume_acc() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = start_at_slot(
+        &pool_lsts_with_recent_inflow(input_lst_mint, output_lst_mint),
+        0,
+    )
+    .await;
+
+    let mut ix = price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint);
+    for meta in ix.accounts.iter_mut() {
+        meta.is_writable = false;
+    }
+    let ix = via_s_controller(ix);
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidAccountData);
+}
+
+#[tokio::test]
+async fn price_exact_in_fail_direct_call() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = start_at_slot(
+        &pool_lsts_with_recent_inflow(input_lst_mint, output_lst_mint),
+        0,
+    )
+    .await;
+
+    let mut ix = price_exact_in_ix_for_mints(input_lst_mint, output_lst_mint);
+    for meta in ix.accounts.iter_mut() {
+        meta.is_signer = false;
+    }
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::MissingRequiredSignature);
+
+    let input_volume_acc = get_volume_account(&mut banks_client, input_lst_mint).await;
+    assert_eq!(input_volume_acc.inflow_sol_value, 20_000_000);
+}
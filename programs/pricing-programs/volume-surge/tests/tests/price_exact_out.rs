@@ -0,0 +1,70 @@
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::ProgramTestContext;
+use test_utils::BorshReturnDataBanksClient;
+use volume_surge_interface::{price_exact_out_ix, PriceExactOutIxArgs, ProgramState};
+use volume_surge_lib::account_resolvers::PriceExactOutFreeArgs;
+use volume_surge_test_utils::MockVolumeAccountArgs;
+
+use crate::common::*;
+
+#[tokio::test]
+async fn price_exact_out_one_sided_flow_surges() {
+    const OUT_SOL_VALUE: u64 = 10_000_000;
+
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let program_test = pricing_program_test(
+        ProgramState {
+            base_fee_bps: 10,
+            surge_slope_bps: 10_000,
+            max_surge_fee_bps: 500,
+            decay_window_slots: 1_000,
+            ..program_state_with_manager(Pubkey::default())
+        },
+        &[
+            MockPoolLst {
+                sol_value: 500_000_000,
+                volume: MockVolumeAccountArgs {
+                    lst_mint: input_lst_mint,
+                    ..Default::default()
+                },
+            },
+            MockPoolLst {
+                sol_value: 500_000_000,
+                volume: MockVolumeAccountArgs {
+                    lst_mint: output_lst_mint,
+                    outflow_sol_value: 10_000_000,
+                    ..Default::default()
+                },
+            },
+        ],
+    );
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock::default());
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        ..
+    } = ctx;
+
+    let ix = via_s_controller(
+        price_exact_out_ix(
+            PriceExactOutFreeArgs {
+                input_lst_mint,
+                output_lst_mint,
+            }
+            .resolve(),
+            PriceExactOutIxArgs {
+                amount: OUT_SOL_VALUE,
+                sol_value: OUT_SOL_VALUE,
+            },
+        )
+        .unwrap(),
+    );
+    // output net outflow 10_000_000 + 10_000_000 = 2% of pool, total fee 10 + 200 = 210 bps,
+    // in_sol_value = ceil(10_000_000 * 10_000 / 9_790)
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, 10_214_505)
+        .await;
+}
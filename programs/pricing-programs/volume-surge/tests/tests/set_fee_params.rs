@@ -0,0 +1,133 @@
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error};
+use solana_program::program_error::ProgramError;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use volume_surge_interface::{
+    set_fee_params_ix, SetFeeParamsIxArgs, SetFeeParamsKeys, VolumeSurgeError,
+};
+use volume_surge_lib::{
+    account_resolvers::SetFeeParamsFreeArgs, program::STATE_ID, utils::try_program_state,
+};
+use volume_surge_test_utils::VolumeSurgePricingProgramTestBanksClient;
+
+use crate::common::{normal_program_test, program_state_with_manager};
+
+const NEW_FEE_PARAMS: SetFeeParamsIxArgs = SetFeeParamsIxArgs {
+    lp_withdrawal_fee_bps: 1,
+    base_fee_bps: 2,
+    surge_slope_bps: 20_000,
+    max_surge_fee_bps: 3,
+    decay_window_slots: 4,
+};
+
+#[tokio::test]
+async fn set_fee_params_basic() {
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(program_state_with_manager(manager.pubkey()), &[]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_volume_surge_program_state().await;
+    let ix = set_fee_params_ix(
+        SetFeeParamsFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        NEW_FEE_PARAMS,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_volume_surge_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.manager, manager.pubkey());
+    assert_eq!(
+        state.lp_withdrawal_fee_bps,
+        NEW_FEE_PARAMS.lp_withdrawal_fee_bps
+    );
+    assert_eq!(state.base_fee_bps, NEW_FEE_PARAMS.base_fee_bps);
+    assert_eq!(state.surge_slope_bps, NEW_FEE_PARAMS.surge_slope_bps);
+    assert_eq!(state.max_surge_fee_bps, NEW_FEE_PARAMS.max_surge_fee_bps);
+    assert_eq!(state.decay_window_slots, NEW_FEE_PARAMS.decay_window_slots);
+}
+
+#[tokio::test]
+async fn set_fee_params_fail_invalid_fee() {
+    const BAD_FEE_PARAMS: [SetFeeParamsIxArgs; 3] = [
+        SetFeeParamsIxArgs {
+            lp_withdrawal_fee_bps: 10_001,
+            ..NEW_FEE_PARAMS
+        },
+        SetFeeParamsIxArgs {
+            base_fee_bps: 10_001,
+            ..NEW_FEE_PARAMS
+        },
+        SetFeeParamsIxArgs {
+            max_surge_fee_bps: 10_001,
+            ..NEW_FEE_PARAMS
+        },
+    ];
+
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(program_state_with_manager(manager.pubkey()), &[]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    for bad_fee_params in BAD_FEE_PARAMS {
+        let state_acc = banks_client.get_volume_surge_program_state().await;
+        let ix = set_fee_params_ix(
+            SetFeeParamsFreeArgs {
+                state_acc: KeyedAccount {
+                    pubkey: STATE_ID,
+                    account: state_acc,
+                },
+            }
+            .resolve()
+            .unwrap(),
+            bad_fee_params,
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, VolumeSurgeError::FeeOutOfBound);
+    }
+}
+
+#[tokio::test]
+async fn set_fee_params_fail_unauthorized() {
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(program_state_with_manager(manager.pubkey()), &[]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_fee_params_ix(
+        SetFeeParamsKeys {
+            manager: payer.pubkey(),
+            state: STATE_ID,
+        },
+        NEW_FEE_PARAMS,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    let state_acc = banks_client.get_volume_surge_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+    assert_ne!(state.base_fee_bps, NEW_FEE_PARAMS.base_fee_bps);
+}
@@ -0,0 +1,257 @@
+use borsh::BorshSerialize;
+use s_controller_lib::mpl_token_metadata;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    program_error::ProgramError,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+use spl_token_metadata_interface::{
+    instruction::{initialize, update_field},
+    state::{Field, TokenMetadata},
+};
+use system_program_interface::{transfer_invoke, TransferAccounts, TransferIxArgs};
+
+const MPL_CREATE_METADATA_ACCOUNT_V3_DISCM: u8 = 33;
+
+const MPL_UPDATE_METADATA_ACCOUNT_V2_DISCM: u8 = 15;
+
+#[derive(Clone, Debug)]
+pub struct LpTokenMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+/// CPI calls to initialize or update the LP token's metadata with the pool state PDA as update authority.
+///
+/// Uses the token metadata interface of the LP token mint itself if `metadata_program` is Token-2022,
+/// and the metaplex token metadata program otherwise
+#[derive(Clone, Copy, Debug)]
+pub struct LpTokenMetadataCpi<'me, 'info> {
+    pub payer: &'me AccountInfo<'info>,
+    pub pool_state: &'me AccountInfo<'info>,
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    pub metadata: &'me AccountInfo<'info>,
+    pub metadata_program: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+
+impl<'me, 'info> LpTokenMetadataCpi<'me, 'info> {
+    fn is_token_2022(&self) -> bool {
+        *self.metadata_program.key == spl_token_2022::ID
+    }
+
+    /// `signer_seeds` must be the pool state PDA's
+    pub fn invoke_init_signed(
+        &self,
+        args: LpTokenMetadataArgs,
+        signer_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        if self.is_token_2022() {
+            self.invoke_token_2022_init_signed(args, signer_seeds)
+        } else {
+            self.invoke_mpl_create_signed(args, signer_seeds)
+        }
+    }
+
+    /// `signer_seeds` must be the pool state PDA's
+    pub fn invoke_set_signed(
+        &self,
+        args: LpTokenMetadataArgs,
+        signer_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        if self.is_token_2022() {
+            self.invoke_token_2022_update_signed(args, signer_seeds)
+        } else {
+            self.invoke_mpl_update_signed(args, signer_seeds)
+        }
+    }
+
+    fn invoke_token_2022_init_signed(
+        &self,
+        LpTokenMetadataArgs { name, symbol, uri }: LpTokenMetadataArgs,
+        signer_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        // update_authority and mint are fixed-size, so default values give the correct tlv size
+        let token_metadata = TokenMetadata {
+            name,
+            symbol,
+            uri,
+            ..Default::default()
+        };
+        let new_len = self
+            .lp_token_mint
+            .data_len()
+            .checked_add(token_metadata.tlv_size_of()?)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        self.transfer_rent_shortfall(new_len)?;
+
+        let TokenMetadata {
+            name, symbol, uri, ..
+        } = token_metadata;
+        let ix = initialize(
+            &spl_token_2022::ID,
+            self.lp_token_mint.key,
+            self.pool_state.key,
+            self.lp_token_mint.key,
+            self.pool_state.key,
+            name,
+            symbol,
+            uri,
+        );
+        invoke_signed(
+            &ix,
+            &[self.lp_token_mint.clone(), self.pool_state.clone()],
+            signer_seeds,
+        )
+    }
+
+    fn invoke_token_2022_update_signed(
+        &self,
+        LpTokenMetadataArgs { name, symbol, uri }: LpTokenMetadataArgs,
+        signer_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let mut token_metadata = {
+            let mint_data = self.lp_token_mint.try_borrow_data()?;
+            StateWithExtensions::<Mint>::unpack(&mint_data)?
+                .get_variable_len_extension::<TokenMetadata>()?
+        };
+        let fields = [
+            (Field::Name, name),
+            (Field::Symbol, symbol),
+            (Field::Uri, uri),
+        ];
+
+        // each update_field reallocs the mint,
+        // so the mint needs to be rent-exempt for the largest intermediate size
+        let len_without_metadata = self
+            .lp_token_mint
+            .data_len()
+            .checked_sub(token_metadata.tlv_size_of()?)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let mut max_len = self.lp_token_mint.data_len();
+        for (field, value) in fields.iter() {
+            token_metadata.update(field.clone(), value.clone());
+            let len = len_without_metadata
+                .checked_add(token_metadata.tlv_size_of()?)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            max_len = max_len.max(len);
+        }
+        self.transfer_rent_shortfall(max_len)?;
+
+        for (field, value) in fields {
+            let ix = update_field(
+                &spl_token_2022::ID,
+                self.lp_token_mint.key,
+                self.pool_state.key,
+                field,
+                value,
+            );
+            invoke_signed(
+                &ix,
+                &[self.lp_token_mint.clone(), self.pool_state.clone()],
+                signer_seeds,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn transfer_rent_shortfall(&self, new_len: usize) -> ProgramResult {
+        let rent_exempt_min = Rent::get()?.minimum_balance(new_len);
+        let shortfall = rent_exempt_min.saturating_sub(self.lp_token_mint.lamports());
+        if shortfall > 0 {
+            transfer_invoke(
+                TransferAccounts {
+                    from: self.payer,
+                    to: self.lp_token_mint,
+                },
+                TransferIxArgs {
+                    lamports: shortfall,
+                },
+            )?;
+        }
+        Ok(())
+    }
+
+    fn invoke_mpl_create_signed(
+        &self,
+        args: LpTokenMetadataArgs,
+        signer_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let mut data = vec![MPL_CREATE_METADATA_ACCOUNT_V3_DISCM];
+        serialize_mpl_data_v2(&args, &mut data)?;
+        // is_mutable: true, collection_details: None
+        data.extend([1, 0]);
+        let ix = Instruction {
+            program_id: mpl_token_metadata::ID,
+            accounts: vec![
+                AccountMeta::new(*self.metadata.key, false),
+                AccountMeta::new_readonly(*self.lp_token_mint.key, false),
+                AccountMeta::new_readonly(*self.pool_state.key, true),
+                AccountMeta::new(*self.payer.key, true),
+                AccountMeta::new_readonly(*self.pool_state.key, true),
+                AccountMeta::new_readonly(*self.system_program.key, false),
+            ],
+            data,
+        };
+        invoke_signed(
+            &ix,
+            &[
+                self.metadata.clone(),
+                self.lp_token_mint.clone(),
+                self.pool_state.clone(),
+                self.payer.clone(),
+                self.system_program.clone(),
+            ],
+            signer_seeds,
+        )
+    }
+
+    fn invoke_mpl_update_signed(
+        &self,
+        args: LpTokenMetadataArgs,
+        signer_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        // data: Some
+        let mut data = vec![MPL_UPDATE_METADATA_ACCOUNT_V2_DISCM, 1];
+        serialize_mpl_data_v2(&args, &mut data)?;
+        // new_update_authority, primary_sale_happened, is_mutable: None
+        data.extend([0, 0, 0]);
+        let ix = Instruction {
+            program_id: mpl_token_metadata::ID,
+            accounts: vec![
+                AccountMeta::new(*self.metadata.key, false),
+                AccountMeta::new_readonly(*self.pool_state.key, true),
+            ],
+            data,
+        };
+        invoke_signed(
+            &ix,
+            &[self.metadata.clone(), self.pool_state.clone()],
+            signer_seeds,
+        )
+    }
+}
+
+/// Serializes metaplex's `DataV2` with no seller fees, creators, collection or uses
+fn serialize_mpl_data_v2(
+    LpTokenMetadataArgs { name, symbol, uri }: &LpTokenMetadataArgs,
+    data: &mut Vec<u8>,
+) -> Result<(), ProgramError> {
+    name.serialize(data)?;
+    symbol.serialize(data)?;
+    uri.serialize(data)?;
+    // seller_fee_basis_points
+    0u16.serialize(data)?;
+    // creators, collection, uses: None
+    data.extend([0, 0, 0]);
+    Ok(())
+}
@@ -1,7 +1,11 @@
 //! Utilities for CPI
 
+mod lp_token_metadata;
 mod pricing_program;
 mod sol_value_calculator;
+mod token_transfer;
 
+pub use lp_token_metadata::*;
 pub use pricing_program::*;
 pub use sol_value_calculator::*;
+pub use token_transfer::*;
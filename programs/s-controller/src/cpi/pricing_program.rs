@@ -4,12 +4,15 @@ use pricing_programs_interface::{
     PriceLpTokensToRedeemIxData,
 };
 use s_controller_interface::SControllerError;
-use s_controller_lib::try_pool_state;
+use s_controller_lib::{
+    program::{POOL_STATE_BUMP, POOL_STATE_ID, POOL_STATE_SEED},
+    try_pool_state,
+};
 use sanctum_misc_utils::{get_borsh_return_data, ToAccountMeta};
 use solana_program::{
     account_info::AccountInfo,
     instruction::{AccountMeta, Instruction},
-    program::invoke,
+    program::{invoke, invoke_signed},
     program_error::ProgramError,
 };
 
@@ -32,7 +35,9 @@ pub struct PricingProgramPriceLpCpi<'me, 'info> {
     /// The mint of the LST that the pricing program is being called for
     pub lst_mint: &'me AccountInfo<'info>,
 
-    /// Remaining accounts required by the pricing program
+    /// Remaining accounts required by the pricing program.
+    /// Passed to the pricing program with the same privileges
+    /// they had in the calling instruction, so these may be writable
     pub remaining_accounts: &'me [AccountInfo<'info>],
 }
 
@@ -126,6 +131,8 @@ impl<'me, 'info> PricingProgramPriceLpCpi<'me, 'info> {
         [std::slice::from_ref(lst_mint), remaining_accounts].concat()
     }
 
+    /// LST mints are always readonly, remaining accounts retain
+    /// their signer and writable privileges
     fn create_account_metas(&self) -> Vec<AccountMeta> {
         let mut res = vec![AccountMeta::new_readonly(*self.lst_mint.key, false)];
         for r in self.remaining_accounts.iter() {
@@ -145,7 +152,10 @@ pub struct PricingProgramPriceSwapCpi<'me, 'info> {
 
     pub output_lst_mint: &'me AccountInfo<'info>,
 
-    /// Remaining accounts required by the pricing program
+    /// Remaining accounts required by the pricing program.
+    /// Passed to the pricing program with the same privileges
+    /// they had in the calling instruction, so these may be writable.
+    /// The pool state PDA is additionally signed for
     pub remaining_accounts: &'me [AccountInfo<'info>],
 }
 
@@ -221,7 +231,11 @@ impl<'me, 'info> PricingProgramPriceSwapCpi<'me, 'info> {
 
     fn invoke_interface_ix(self, interface_ix: Instruction) -> Result<u64, ProgramError> {
         let accounts = self.create_account_info_slice();
-        invoke(&interface_ix, &accounts)?;
+        invoke_signed(
+            &interface_ix,
+            &accounts,
+            &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        )?;
         let (_pk, res) = get_borsh_return_data().ok_or(SControllerError::FaultyPricingProgram)?;
         Ok(res)
     }
@@ -241,14 +255,34 @@ impl<'me, 'info> PricingProgramPriceSwapCpi<'me, 'info> {
         .concat()
     }
 
+    /// LST mints are always readonly, remaining accounts retain
+    /// their signer and writable privileges, except the pool state PDA,
+    /// which is signed for by the S controller
     fn create_account_metas(&self) -> Vec<AccountMeta> {
         let mut res = vec![
             AccountMeta::new_readonly(*self.input_lst_mint.key, false),
             AccountMeta::new_readonly(*self.output_lst_mint.key, false),
         ];
         for r in self.remaining_accounts.iter() {
-            res.push(r.to_account_meta());
+            res.push(remaining_account_meta(r));
         }
         res
     }
 }
+
+/// Pricing programs can require the pool state PDA to be a signer when pricing swaps
+/// to verify that they are being invoked by the S controller,
+/// e.g. before recording swap volume.
+///
+/// The pool state PDA is the authority of the pool's reserves, so this gives the pricing program
+/// full control over the reserves for the duration of the CPI. This is acceptable because
+/// the pricing program is set by the admin and already fully trusted with the reserves
+/// through the prices it returns. LP pricing CPIs are not signed for
+/// since no pricing program requires it.
+fn remaining_account_meta(r: &AccountInfo) -> AccountMeta {
+    let mut meta = r.to_account_meta();
+    if *r.key == POOL_STATE_ID {
+        meta.is_signer = true;
+    }
+    meta
+}
@@ -0,0 +1,69 @@
+use s_controller_interface::SControllerError;
+use sanctum_token_lib::token_account_balance;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use spl_token_2022::{
+    extension::StateWithExtensions, onchain::invoke_transfer_checked, state::Mint,
+};
+
+/// CPI call to `TransferChecked` that supports Token-2022 mints
+/// with the transfer fee and transfer hook extensions
+#[derive(Clone, Copy, Debug)]
+pub struct TransferCheckedWithHookCpi<'me, 'info> {
+    pub from: &'me AccountInfo<'info>,
+    pub to: &'me AccountInfo<'info>,
+    pub token_program: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+    pub mint: &'me AccountInfo<'info>,
+
+    /// Accounts to look up the accounts required by the mint's transfer hook program, if any.
+    /// Should be the calling instruction's accounts suffix slice,
+    /// which clients should end with the transfer hook accounts
+    pub additional_accounts: &'me [AccountInfo<'info>],
+}
+
+impl<'me, 'info> TransferCheckedWithHookCpi<'me, 'info> {
+    pub fn invoke_signed(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> ProgramResult {
+        let decimals = {
+            let mint_data = self.mint.try_borrow_data()?;
+            StateWithExtensions::<Mint>::unpack(&mint_data)?
+                .base
+                .decimals
+        };
+        invoke_transfer_checked(
+            self.token_program.key,
+            self.from.clone(),
+            self.mint.clone(),
+            self.to.clone(),
+            self.authority.clone(),
+            self.additional_accounts,
+            amount,
+            decimals,
+            signer_seeds,
+        )
+    }
+
+    pub fn invoke(&self, amount: u64) -> ProgramResult {
+        self.invoke_signed(amount, &[])
+    }
+
+    /// Same as [`Self::invoke_signed`], but returns the amount `to` actually received,
+    /// which is less than `amount` for mints with the transfer fee extension
+    pub fn invoke_signed_measure_received(
+        &self,
+        amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u64, ProgramError> {
+        let balance_before = token_account_balance(self.to)?;
+        self.invoke_signed(amount, signer_seeds)?;
+        let balance_after = token_account_balance(self.to)?;
+        balance_after
+            .checked_sub(balance_before)
+            .ok_or_else(|| SControllerError::MathError.into())
+    }
+
+    pub fn invoke_measure_received(&self, amount: u64) -> Result<u64, ProgramError> {
+        self.invoke_signed_measure_received(amount, &[])
+    }
+}
@@ -57,6 +57,30 @@ pub fn process_instruction(
         SControllerProgramIx::EndRebalance => process_end_rebalance(accounts),
         SControllerProgramIx::SetRebalanceAuthority => process_set_rebalance_authority(accounts),
         SControllerProgramIx::Initialize => process_initialize(accounts),
+        SControllerProgramIx::InitLpTokenMetadata(args) => {
+            process_init_lp_token_metadata(accounts, args)
+        }
+        SControllerProgramIx::SetLpTokenMetadata(args) => {
+            process_set_lp_token_metadata(accounts, args)
+        }
+        SControllerProgramIx::SwapExactInWithDeadline(args) => {
+            process_swap_exact_in_with_deadline(accounts, args)
+        }
+        SControllerProgramIx::SwapExactOutWithDeadline(args) => {
+            process_swap_exact_out_with_deadline(accounts, args)
+        }
+        SControllerProgramIx::AddLiquidityWithDeadline(args) => {
+            process_add_liquidity_with_deadline(accounts, args)
+        }
+        SControllerProgramIx::RemoveLiquidityWithDeadline(args) => {
+            process_remove_liquidity_with_deadline(accounts, args)
+        }
+        SControllerProgramIx::AddLiquidityMulti(args) => {
+            process_add_liquidity_multi(accounts, args)
+        }
+        SControllerProgramIx::RemoveLiquidityBasket(args) => {
+            process_remove_liquidity_basket(accounts, args)
+        }
     };
     if let Err(e) = res.as_ref() {
         e.print::<SControllerError>();
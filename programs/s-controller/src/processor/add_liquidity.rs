@@ -1,6 +1,7 @@
 use s_controller_interface::{
     add_liquidity_verify_account_keys, add_liquidity_verify_account_privileges,
-    AddLiquidityAccounts, AddLiquidityIxArgs, SControllerError, ADD_LIQUIDITY_IX_ACCOUNTS_LEN,
+    AddLiquidityAccounts, AddLiquidityIxArgs, AddLiquidityWithDeadlineIxArgs, SControllerError,
+    ADD_LIQUIDITY_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
     calc_add_liquidity_protocol_fees, calc_lp_tokens_to_mint, index_to_usize,
@@ -12,25 +13,28 @@ use s_controller_lib::{
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
-use sanctum_token_lib::{
-    mint_supply, mint_to_invoke_signed, transfer_checked_decimal_agnostic_invoke, MintToAccounts,
-    TransferCheckedAccounts,
-};
+use sanctum_token_lib::{mint_supply, mint_to_invoke_signed, MintToAccounts};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
 use crate::{
-    cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
+    cpi::{
+        PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi,
+        TransferCheckedWithHookCpi,
+    },
     verify::{
-        verify_lp_cpis, verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
-        VerifyLpCpiAccounts,
+        verify_deadline, verify_lp_cpis, verify_lst_input_not_disabled,
+        verify_not_rebalancing_and_not_disabled, VerifyLpCpiAccounts,
     },
 };
 
 use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
 
 pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs) -> ProgramResult {
+    let accounts_suffix_slice = accounts
+        .get(ADD_LIQUIDITY_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
     let (
         accounts,
         AddLiquidityIxFullArgs {
@@ -51,10 +55,23 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
 
     let start_total_sol_value = accounts.pool_state.total_sol_value()?;
 
-    let lst_amount_sol_value = lst_cpi.invoke_lst_to_sol(lst_amount)?.get_min();
+    // Transfer everything into the reserves first to price the amount the pool actually received,
+    // which is less than lst_amount for LSTs with transfer fees.
+    // Protocol fees are then transferred out of the reserves below.
+    let lst_amount_received = TransferCheckedWithHookCpi {
+        from: accounts.src_lst_acc,
+        to: accounts.pool_reserves,
+        token_program: accounts.lst_token_program,
+        authority: accounts.signer,
+        mint: accounts.lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_measure_received(lst_amount)?;
+
+    let lst_amount_sol_value = lst_cpi.invoke_lst_to_sol(lst_amount_received)?.get_min();
     let lst_amount_sol_value_after_fees =
         pricing_cpi.invoke_price_lp_tokens_to_mint(PricingProgramIxArgs {
-            amount: lst_amount,
+            amount: lst_amount_received,
             sol_value: lst_amount_sol_value,
         })?;
     // Will dilute existing LPs if unchecked
@@ -66,7 +83,7 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
         to_reserves_lst_amount,
         to_protocol_fees_lst_amount,
     } = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
-        lst_amount,
+        lst_amount: lst_amount_received,
         lst_amount_sol_value,
         lst_amount_sol_value_after_fees,
         lp_protocol_fee_bps: accounts.pool_state.lp_protocol_fee_bps()?,
@@ -90,25 +107,17 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
         return Err(SControllerError::SlippageToleranceExceeded.into());
     }
 
-    transfer_checked_decimal_agnostic_invoke(
-        TransferCheckedAccounts {
-            from: accounts.src_lst_acc,
-            to: accounts.pool_reserves,
-            token_program: accounts.lst_token_program,
-            authority: accounts.signer,
-            mint: accounts.lst_mint,
-        },
-        to_reserves_lst_amount,
-    )?;
-    transfer_checked_decimal_agnostic_invoke(
-        TransferCheckedAccounts {
-            from: accounts.src_lst_acc,
-            to: accounts.protocol_fee_accumulator,
-            token_program: accounts.lst_token_program,
-            authority: accounts.signer,
-            mint: accounts.lst_mint,
-        },
+    TransferCheckedWithHookCpi {
+        from: accounts.pool_reserves,
+        to: accounts.protocol_fee_accumulator,
+        token_program: accounts.lst_token_program,
+        authority: accounts.pool_state,
+        mint: accounts.lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_signed(
         to_protocol_fees_lst_amount,
+        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
     )?;
     mint_to_invoke_signed(
         MintToAccounts {
@@ -130,6 +139,29 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
     Ok(())
 }
 
+/// Same as [`process_add_liquidity`], but fails if the transaction lands after `deadline`
+pub fn process_add_liquidity_with_deadline(
+    accounts: &[AccountInfo],
+    AddLiquidityWithDeadlineIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        lst_amount,
+        min_lp_out,
+        deadline,
+    }: AddLiquidityWithDeadlineIxArgs,
+) -> ProgramResult {
+    verify_deadline(&deadline)?;
+    process_add_liquidity(
+        accounts,
+        AddLiquidityIxArgs {
+            lst_value_calc_accs,
+            lst_index,
+            lst_amount,
+            min_lp_out,
+        },
+    )
+}
+
 fn verify_add_liquidity<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
     AddLiquidityIxArgs {
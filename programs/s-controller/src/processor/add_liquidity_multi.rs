@@ -0,0 +1,326 @@
+use s_controller_interface::{
+    add_liquidity_multi_verify_account_keys, add_liquidity_multi_verify_account_privileges,
+    AddLiquidityMultiAccounts, AddLiquidityMultiIxArgs, AddLiquidityMultiLst, SControllerError,
+    ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_add_liquidity_protocol_fees, calc_lp_tokens_to_mint, index_to_usize,
+    program::{POOL_STATE_BUMP, POOL_STATE_SEED},
+    try_lst_state_list, try_pool_state, AddLiquidityMultiFreeArgs, CalcAddLiquidityArgs,
+    CalcAddLiquidityProtocolFeesResult, LpTokenRateArgs, PoolStateAccount,
+    ADD_LIQUIDITY_MULTI_LST_ACCOUNTS_LEN,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{mint_supply, mint_to_invoke_signed, MintToAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::{
+        PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi,
+        TransferCheckedWithHookCpi,
+    },
+    verify::{
+        verify_lp_cpis, verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
+        VerifyLpCpiAccounts,
+    },
+};
+
+use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+/// A single LST's verified account group and amount
+#[derive(Clone, Copy, Debug)]
+struct AddLiquidityMultiLstInput<'me, 'info> {
+    lst_index: usize,
+    lst_amount: u64,
+    lst_mint: &'me AccountInfo<'info>,
+    src_lst_acc: &'me AccountInfo<'info>,
+    pool_reserves: &'me AccountInfo<'info>,
+    protocol_fee_accumulator: &'me AccountInfo<'info>,
+    lst_token_program: &'me AccountInfo<'info>,
+    lst_cpi: SolValueCalculatorCpi<'me, 'info>,
+    pricing_cpi: PricingProgramPriceLpCpi<'me, 'info>,
+}
+
+pub fn process_add_liquidity_multi(
+    accounts: &[AccountInfo],
+    args: AddLiquidityMultiIxArgs,
+) -> ProgramResult {
+    let accounts_suffix_slice = accounts
+        .get(ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let (accounts, lst_inputs, min_lp_out) = verify_add_liquidity_multi(accounts, args)?;
+
+    for lst_input in lst_inputs.iter() {
+        sync_lst_sol_value(accounts, lst_input)?;
+    }
+
+    // LP tokens are minted against the pool's total SOL value before any of the deposits
+    let start_total_sol_value = accounts.pool_state.total_sol_value()?;
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let lp_protocol_fee_bps = accounts.pool_state.lp_protocol_fee_bps()?;
+
+    let mut total_sol_value_after_fees: u64 = 0;
+    for lst_input in lst_inputs.iter() {
+        // Transfer everything into the reserves first to price the amount the pool actually received,
+        // which is less than lst_amount for LSTs with transfer fees.
+        // Protocol fees are then transferred out of the reserves below.
+        let lst_amount_received = TransferCheckedWithHookCpi {
+            from: lst_input.src_lst_acc,
+            to: lst_input.pool_reserves,
+            token_program: lst_input.lst_token_program,
+            authority: accounts.signer,
+            mint: lst_input.lst_mint,
+            additional_accounts: accounts_suffix_slice,
+        }
+        .invoke_measure_received(lst_input.lst_amount)?;
+
+        let lst_amount_sol_value = lst_input
+            .lst_cpi
+            .invoke_lst_to_sol(lst_amount_received)?
+            .get_min();
+        let lst_amount_sol_value_after_fees = lst_input
+            .pricing_cpi
+            .invoke_price_lp_tokens_to_mint(PricingProgramIxArgs {
+                amount: lst_amount_received,
+                sol_value: lst_amount_sol_value,
+            })?;
+        // Will dilute existing LPs if unchecked
+        if lst_amount_sol_value_after_fees > lst_amount_sol_value {
+            return Err(SControllerError::PoolWouldLoseSolValue.into());
+        }
+
+        let CalcAddLiquidityProtocolFeesResult {
+            to_reserves_lst_amount,
+            to_protocol_fees_lst_amount,
+        } = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
+            lst_amount: lst_amount_received,
+            lst_amount_sol_value,
+            lst_amount_sol_value_after_fees,
+            lp_protocol_fee_bps,
+        })?;
+        if to_reserves_lst_amount == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+
+        TransferCheckedWithHookCpi {
+            from: lst_input.pool_reserves,
+            to: lst_input.protocol_fee_accumulator,
+            token_program: lst_input.lst_token_program,
+            authority: accounts.pool_state,
+            mint: lst_input.lst_mint,
+            additional_accounts: accounts_suffix_slice,
+        }
+        .invoke_signed(
+            to_protocol_fees_lst_amount,
+            &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        )?;
+
+        total_sol_value_after_fees = total_sol_value_after_fees
+            .checked_add(lst_amount_sol_value_after_fees)
+            .ok_or(SControllerError::MathError)?;
+    }
+
+    let lp_tokens_to_mint = calc_lp_tokens_to_mint(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value: start_total_sol_value,
+        },
+        total_sol_value_after_fees,
+    )?;
+
+    if lp_tokens_to_mint == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    if lp_tokens_to_mint < min_lp_out {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+
+    mint_to_invoke_signed(
+        MintToAccounts {
+            mint: accounts.lp_token_mint,
+            mint_to: accounts.dst_lp_acc,
+            mint_authority: accounts.pool_state,
+            token_program: accounts.lp_token_program,
+        },
+        lp_tokens_to_mint,
+        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+    )?;
+
+    for lst_input in lst_inputs.iter() {
+        sync_lst_sol_value(accounts, lst_input)?;
+    }
+
+    let end_total_sol_value = accounts.pool_state.total_sol_value()?;
+    if end_total_sol_value < start_total_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    Ok(())
+}
+
+fn sync_lst_sol_value<'a, 'info>(
+    accounts: AddLiquidityMultiAccounts<'a, 'info>,
+    lst_input: &AddLiquidityMultiLstInput<'a, 'info>,
+) -> Result<(), ProgramError> {
+    sync_sol_value_unchecked(
+        SyncSolValueUncheckedAccounts {
+            pool_reserves: lst_input.pool_reserves,
+            pool_state: accounts.pool_state,
+            lst_state_list: accounts.lst_state_list,
+        },
+        lst_input.lst_cpi,
+        lst_input.lst_index,
+    )
+}
+
+fn verify_add_liquidity_multi<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    AddLiquidityMultiIxArgs { lsts, min_lp_out }: AddLiquidityMultiIxArgs,
+) -> Result<
+    (
+        AddLiquidityMultiAccounts<'a, 'info>,
+        Vec<AddLiquidityMultiLstInput<'a, 'info>>,
+        u64,
+    ),
+    ProgramError,
+> {
+    if lsts.is_empty() {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let actual: AddLiquidityMultiAccounts = load_accounts(accounts)?;
+
+    let free_args = AddLiquidityMultiFreeArgs {
+        signer: *actual.signer.key,
+        dst_lp_acc: *actual.dst_lp_acc.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+    };
+    let expected = free_args.resolve()?;
+
+    add_liquidity_multi_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    add_liquidity_multi_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let mut lst_inputs: Vec<AddLiquidityMultiLstInput> = Vec::with_capacity(lsts.len());
+    let mut lst_accounts_start = ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN;
+    for AddLiquidityMultiLst {
+        lst_value_calc_accs,
+        pricing_accs,
+        lst_index,
+        lst_amount,
+    } in lsts
+    {
+        if lst_amount == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+        let lst_index = index_to_usize(lst_index)?;
+        if lst_inputs
+            .iter()
+            .any(|lst_input| lst_input.lst_index == lst_index)
+        {
+            return Err(SControllerError::DuplicateLstInput.into());
+        }
+
+        let lst_accounts_end = lst_accounts_start
+            + ADD_LIQUIDITY_MULTI_LST_ACCOUNTS_LEN
+            + usize::from(lst_value_calc_accs)
+            + usize::from(pricing_accs);
+        let lst_accounts = accounts
+            .get(lst_accounts_start..lst_accounts_end)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        lst_inputs.push(verify_add_liquidity_multi_lst(
+            &free_args,
+            actual,
+            lst_accounts,
+            lst_value_calc_accs,
+            lst_index,
+            lst_amount,
+        )?);
+
+        lst_accounts_start = lst_accounts_end;
+    }
+
+    Ok((actual, lst_inputs, min_lp_out))
+}
+
+/// `lst_accounts` is the LST's account group followed by
+/// its SOL value calculator and pricing program accounts
+fn verify_add_liquidity_multi_lst<'a, 'info>(
+    free_args: &AddLiquidityMultiFreeArgs<&'a AccountInfo<'info>, &'a AccountInfo<'info>>,
+    actual: AddLiquidityMultiAccounts<'a, 'info>,
+    lst_accounts: &'a [AccountInfo<'info>],
+    lst_value_calc_accs: u8,
+    lst_index: usize,
+    lst_amount: u64,
+) -> Result<AddLiquidityMultiLstInput<'a, 'info>, ProgramError> {
+    let (lst_group, cpi_accounts_suffix_slice) =
+        lst_accounts.split_at(ADD_LIQUIDITY_MULTI_LST_ACCOUNTS_LEN);
+    let [lst_mint, src_lst_acc, pool_reserves, protocol_fee_accumulator, lst_token_program] =
+        lst_group
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let expected = free_args.resolve_lst(lst_index, *src_lst_acc.key, lst_mint)?;
+    for (actual_key, expected_key) in [
+        (lst_mint.key, &expected.lst_mint),
+        (src_lst_acc.key, &expected.src_lst_acc),
+        (pool_reserves.key, &expected.pool_reserves),
+        (
+            protocol_fee_accumulator.key,
+            &expected.protocol_fee_accumulator,
+        ),
+        (lst_token_program.key, &expected.lst_token_program),
+    ] {
+        if actual_key != expected_key {
+            return Err(log_and_return_wrong_acc_err((*actual_key, *expected_key)));
+        }
+    }
+    for should_be_writable in [src_lst_acc, pool_reserves, protocol_fee_accumulator] {
+        if !should_be_writable.is_writable {
+            return Err(log_and_return_acc_privilege_err((
+                should_be_writable,
+                ProgramError::InvalidAccountData,
+            )));
+        }
+    }
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    // lst_index checked above
+    verify_lst_input_not_disabled(&lst_state_list[lst_index])?;
+
+    let (lst_cpi, pricing_cpi) = verify_lp_cpis(
+        VerifyLpCpiAccounts {
+            lst_state_list: actual.lst_state_list,
+            pool_state: actual.pool_state,
+            lst_mint,
+        },
+        cpi_accounts_suffix_slice,
+        lst_value_calc_accs,
+        lst_index,
+    )?;
+
+    Ok(AddLiquidityMultiLstInput {
+        lst_index,
+        lst_amount,
+        lst_mint,
+        src_lst_acc,
+        pool_reserves,
+        protocol_fee_accumulator,
+        lst_token_program,
+        lst_cpi,
+        pricing_cpi,
+    })
+}
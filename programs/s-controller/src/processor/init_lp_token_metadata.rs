@@ -0,0 +1,63 @@
+use s_controller_interface::{
+    init_lp_token_metadata_verify_account_keys, init_lp_token_metadata_verify_account_privileges,
+    InitLpTokenMetadataAccounts, InitLpTokenMetadataIxArgs,
+};
+use s_controller_lib::{
+    program::{POOL_STATE_BUMP, POOL_STATE_SEED},
+    try_pool_state, LpTokenMetadataFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::{LpTokenMetadataArgs, LpTokenMetadataCpi},
+    verify::verify_not_rebalancing_and_not_disabled,
+};
+
+pub fn process_init_lp_token_metadata(
+    accounts: &[AccountInfo],
+    InitLpTokenMetadataIxArgs { name, symbol, uri }: InitLpTokenMetadataIxArgs,
+) -> ProgramResult {
+    let checked = verify_init_lp_token_metadata(accounts)?;
+
+    LpTokenMetadataCpi {
+        payer: checked.payer,
+        pool_state: checked.pool_state,
+        lp_token_mint: checked.lp_token_mint,
+        metadata: checked.metadata,
+        metadata_program: checked.metadata_program,
+        system_program: checked.system_program,
+    }
+    .invoke_init_signed(
+        LpTokenMetadataArgs { name, symbol, uri },
+        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+    )
+}
+
+fn verify_init_lp_token_metadata<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<InitLpTokenMetadataAccounts<'a, 'info>, ProgramError> {
+    let actual: InitLpTokenMetadataAccounts = load_accounts(accounts)?;
+
+    let expected = LpTokenMetadataFreeArgs {
+        payer: *actual.payer.key,
+        pool_state: actual.pool_state,
+        lp_token_mint: actual.lp_token_mint,
+    }
+    .resolve_init()?;
+
+    init_lp_token_metadata_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    init_lp_token_metadata_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    Ok(actual)
+}
@@ -1,16 +1,20 @@
 mod add_disable_pool_authority;
 mod add_liquidity;
+mod add_liquidity_multi;
 mod add_lst;
 mod disable_lst_input;
 mod disable_pool;
 mod enable_lst_input;
 mod enable_pool;
 mod end_rebalance;
+mod init_lp_token_metadata;
 mod initialize;
 mod remove_disable_pool_authority;
 mod remove_liquidity;
+mod remove_liquidity_basket;
 mod remove_lst;
 mod set_admin;
+mod set_lp_token_metadata;
 mod set_pricing_program;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
@@ -24,17 +28,21 @@ mod withdraw_protocol_fees;
 
 pub use add_disable_pool_authority::*;
 pub use add_liquidity::*;
+pub use add_liquidity_multi::*;
 pub use add_lst::*;
 pub use disable_lst_input::*;
 pub use disable_pool::*;
 pub use enable_lst_input::*;
 pub use enable_pool::*;
 pub use end_rebalance::*;
+pub use init_lp_token_metadata::*;
 pub use initialize::*;
 pub use remove_disable_pool_authority::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_basket::*;
 pub use remove_lst::*;
 pub use set_admin::*;
+pub use set_lp_token_metadata::*;
 pub use set_pricing_program::*;
 pub use set_protocol_fee::*;
 pub use set_protocol_fee_beneficiary::*;
@@ -1,7 +1,7 @@
 use s_controller_interface::{
     remove_liquidity_verify_account_keys, remove_liquidity_verify_account_privileges,
-    RemoveLiquidityAccounts, RemoveLiquidityIxArgs, SControllerError,
-    REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN,
+    RemoveLiquidityAccounts, RemoveLiquidityIxArgs, RemoveLiquidityWithDeadlineIxArgs,
+    SControllerError, REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
     calc_lp_tokens_sol_value, calc_remove_liquidity_protocol_fees, index_to_usize,
@@ -12,17 +12,20 @@ use s_controller_lib::{
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
-use sanctum_token_lib::{
-    burn_invoke, mint_supply, transfer_checked_decimal_agnostic_invoke_signed, BurnAccounts,
-    TransferCheckedAccounts,
-};
+use sanctum_token_lib::{burn_invoke, mint_supply, BurnAccounts};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
 use crate::{
-    cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
-    verify::{verify_lp_cpis, verify_not_rebalancing_and_not_disabled, VerifyLpCpiAccounts},
+    cpi::{
+        PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi,
+        TransferCheckedWithHookCpi,
+    },
+    verify::{
+        verify_deadline, verify_lp_cpis, verify_not_rebalancing_and_not_disabled,
+        VerifyLpCpiAccounts,
+    },
 };
 
 use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
@@ -31,6 +34,9 @@ pub fn process_remove_liquidity(
     accounts: &[AccountInfo],
     args: RemoveLiquidityIxArgs,
 ) -> ProgramResult {
+    let accounts_suffix_slice = accounts
+        .get(REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
     let (
         accounts,
         RemoveLiquidityIxFullArgs {
@@ -95,26 +101,34 @@ pub fn process_remove_liquidity(
         lp_token_amount,
     )?;
 
-    transfer_checked_decimal_agnostic_invoke_signed(
-        TransferCheckedAccounts {
-            to: accounts.dst_lst_acc,
-            token_program: accounts.lst_token_program,
-            from: accounts.pool_reserves,
-            authority: accounts.pool_state,
-            mint: accounts.lst_mint,
-        },
+    let to_user_lst_received = TransferCheckedWithHookCpi {
+        from: accounts.pool_reserves,
+        to: accounts.dst_lst_acc,
+        token_program: accounts.lst_token_program,
+        authority: accounts.pool_state,
+        mint: accounts.lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_signed_measure_received(
         to_user_lst_amount,
         &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
     )?;
+    // user receives less than to_user_lst_amount for LSTs with transfer fees
+    if to_user_lst_received < min_lst_out {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
 
-    transfer_checked_decimal_agnostic_invoke_signed(
-        TransferCheckedAccounts {
-            to: accounts.protocol_fee_accumulator,
-            token_program: accounts.lst_token_program,
-            from: accounts.pool_reserves,
-            authority: accounts.pool_state,
-            mint: accounts.lst_mint,
-        },
+    // charged a transfer fee too for LSTs with transfer fees,
+    // so the protocol fee accumulator receives less than to_protocol_fees_lst_amount
+    TransferCheckedWithHookCpi {
+        from: accounts.pool_reserves,
+        to: accounts.protocol_fee_accumulator,
+        token_program: accounts.lst_token_program,
+        authority: accounts.pool_state,
+        mint: accounts.lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_signed(
         to_protocol_fees_lst_amount,
         &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
     )?;
@@ -122,6 +136,29 @@ pub fn process_remove_liquidity(
     sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)
 }
 
+/// Same as [`process_remove_liquidity`], but fails if the transaction lands after `deadline`
+pub fn process_remove_liquidity_with_deadline(
+    accounts: &[AccountInfo],
+    RemoveLiquidityWithDeadlineIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        lp_token_amount,
+        min_lst_out,
+        deadline,
+    }: RemoveLiquidityWithDeadlineIxArgs,
+) -> ProgramResult {
+    verify_deadline(&deadline)?;
+    process_remove_liquidity(
+        accounts,
+        RemoveLiquidityIxArgs {
+            lst_value_calc_accs,
+            lst_index,
+            lp_token_amount,
+            min_lst_out,
+        },
+    )
+}
+
 fn verify_remove_liquidity<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
     RemoveLiquidityIxArgs {
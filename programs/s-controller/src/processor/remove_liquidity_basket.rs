@@ -0,0 +1,335 @@
+use s_controller_interface::{
+    remove_liquidity_basket_verify_account_keys, remove_liquidity_basket_verify_account_privileges,
+    RemoveLiquidityBasketAccounts, RemoveLiquidityBasketIxArgs, RemoveLiquidityBasketLst,
+    SControllerError, REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_lp_tokens_sol_value, calc_remove_liquidity_basket_lst_amounts,
+    program::{POOL_STATE_BUMP, POOL_STATE_SEED},
+    try_lst_state_list, try_pool_state, CalcRemoveLiquidityBasketArgs,
+    CalcRemoveLiquidityBasketResult, LpTokenRateArgs, PoolStateAccount,
+    RemoveLiquidityBasketFreeArgs, REMOVE_LIQUIDITY_BASKET_LST_ACCOUNTS_LEN,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{burn_invoke, mint_supply, token_account_balance, BurnAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::{
+        PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi,
+        TransferCheckedWithHookCpi,
+    },
+    verify::{
+        verify_lst_sol_val_calc_cpi, verify_not_rebalancing_and_not_disabled,
+        verify_pricing_lp_cpi, VerifyLstSolValCalcCpiAccounts, VerifyPricingLpCpiAccounts,
+    },
+};
+
+use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+/// A single LST's verified account group and slippage limit
+#[derive(Clone, Copy, Debug)]
+struct RemoveLiquidityBasketLstOutput<'me, 'info> {
+    lst_index: usize,
+    min_lst_out: u64,
+    lst_mint: &'me AccountInfo<'info>,
+    dst_lst_acc: &'me AccountInfo<'info>,
+    pool_reserves: &'me AccountInfo<'info>,
+    protocol_fee_accumulator: &'me AccountInfo<'info>,
+    lst_token_program: &'me AccountInfo<'info>,
+    lst_cpi: SolValueCalculatorCpi<'me, 'info>,
+}
+
+pub fn process_remove_liquidity_basket(
+    accounts: &[AccountInfo],
+    args: RemoveLiquidityBasketIxArgs,
+) -> ProgramResult {
+    let accounts_suffix_slice = accounts
+        .get(REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let (accounts, lst_outputs, lp_token_amount, pricing_cpi) =
+        verify_remove_liquidity_basket(accounts, args)?;
+
+    for lst_output in lst_outputs.iter() {
+        sync_lst_sol_value(accounts, lst_output)?;
+    }
+
+    // The LP withdrawal fee is priced once on the LP tokens' total SOL value
+    // and charged on each LST's pro-rata share at the same rate,
+    // so the pool's composition is unchanged
+    let pool_total_sol_value = accounts.pool_state.total_sol_value()?;
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let lp_tokens_sol_value = calc_lp_tokens_sol_value(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value,
+        },
+        lp_token_amount,
+    )?;
+
+    let lp_tokens_sol_value_after_fees =
+        pricing_cpi.invoke_price_lp_tokens_to_redeem(PricingProgramIxArgs {
+            amount: lp_token_amount,
+            sol_value: lp_tokens_sol_value,
+        })?;
+    if lp_tokens_sol_value_after_fees > lp_tokens_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    let lp_protocol_fee_bps = accounts.pool_state.lp_protocol_fee_bps()?;
+    let mut lst_amounts = Vec::with_capacity(lst_outputs.len());
+    for lst_output in lst_outputs.iter() {
+        lst_amounts.push(calc_remove_liquidity_basket_lst_amounts(
+            CalcRemoveLiquidityBasketArgs {
+                lp_token_supply,
+                reserves_balance: token_account_balance(lst_output.pool_reserves)?,
+                lp_token_amount,
+                lp_tokens_sol_value,
+                lp_tokens_sol_value_after_fees,
+                lp_protocol_fee_bps,
+            },
+        )?);
+    }
+    if lst_amounts.iter().all(|amts| amts.to_user_lst_amount == 0) {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    burn_invoke(
+        BurnAccounts {
+            mint: accounts.lp_token_mint,
+            burn_from: accounts.src_lp_acc,
+            burn_from_authority: accounts.signer,
+            token_program: accounts.lp_token_program,
+        },
+        lp_token_amount,
+    )?;
+
+    for (
+        lst_output,
+        CalcRemoveLiquidityBasketResult {
+            to_user_lst_amount,
+            to_protocol_fees_lst_amount,
+        },
+    ) in lst_outputs.iter().zip(lst_amounts)
+    {
+        let to_user_lst_received = if to_user_lst_amount == 0 {
+            0
+        } else {
+            TransferCheckedWithHookCpi {
+                from: lst_output.pool_reserves,
+                to: lst_output.dst_lst_acc,
+                token_program: lst_output.lst_token_program,
+                authority: accounts.pool_state,
+                mint: lst_output.lst_mint,
+                additional_accounts: accounts_suffix_slice,
+            }
+            .invoke_signed_measure_received(
+                to_user_lst_amount,
+                &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+            )?
+        };
+        // user receives less than to_user_lst_amount for LSTs with transfer fees
+        if to_user_lst_received < lst_output.min_lst_out {
+            return Err(SControllerError::SlippageToleranceExceeded.into());
+        }
+
+        if to_protocol_fees_lst_amount != 0 {
+            // charged a transfer fee too for LSTs with transfer fees,
+            // so the protocol fee accumulator receives less than to_protocol_fees_lst_amount
+            TransferCheckedWithHookCpi {
+                from: lst_output.pool_reserves,
+                to: lst_output.protocol_fee_accumulator,
+                token_program: lst_output.lst_token_program,
+                authority: accounts.pool_state,
+                mint: lst_output.lst_mint,
+                additional_accounts: accounts_suffix_slice,
+            }
+            .invoke_signed(
+                to_protocol_fees_lst_amount,
+                &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+            )?;
+        }
+    }
+
+    for lst_output in lst_outputs.iter() {
+        sync_lst_sol_value(accounts, lst_output)?;
+    }
+
+    Ok(())
+}
+
+fn sync_lst_sol_value<'a, 'info>(
+    accounts: RemoveLiquidityBasketAccounts<'a, 'info>,
+    lst_output: &RemoveLiquidityBasketLstOutput<'a, 'info>,
+) -> Result<(), ProgramError> {
+    sync_sol_value_unchecked(
+        SyncSolValueUncheckedAccounts {
+            pool_reserves: lst_output.pool_reserves,
+            pool_state: accounts.pool_state,
+            lst_state_list: accounts.lst_state_list,
+        },
+        lst_output.lst_cpi,
+        lst_output.lst_index,
+    )
+}
+
+fn verify_remove_liquidity_basket<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    RemoveLiquidityBasketIxArgs {
+        lp_token_amount,
+        lsts,
+    }: RemoveLiquidityBasketIxArgs,
+) -> Result<
+    (
+        RemoveLiquidityBasketAccounts<'a, 'info>,
+        Vec<RemoveLiquidityBasketLstOutput<'a, 'info>>,
+        u64,
+        PricingProgramPriceLpCpi<'a, 'info>,
+    ),
+    ProgramError,
+> {
+    if lp_token_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let actual: RemoveLiquidityBasketAccounts = load_accounts(accounts)?;
+
+    let free_args = RemoveLiquidityBasketFreeArgs {
+        signer: *actual.signer.key,
+        src_lp_acc: *actual.src_lp_acc.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+    };
+    let expected = free_args.resolve()?;
+
+    remove_liquidity_basket_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    remove_liquidity_basket_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    // every LST must be paid out, else the remaining LPs' pool composition changes.
+    // This caps the number of LSTs this instruction supports at what fits in a transaction,
+    // pools with more LSTs must use RemoveLiquidity for each LST instead
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list_len = try_lst_state_list(&lst_state_list_bytes)?.len();
+    if lsts.len() != lst_state_list_len {
+        return Err(SControllerError::IncompleteBasket.into());
+    }
+    if lsts.is_empty() {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let mut lst_outputs = Vec::with_capacity(lsts.len());
+    let mut lst_accounts_start = REMOVE_LIQUIDITY_BASKET_IX_ACCOUNTS_LEN;
+    for (
+        lst_index,
+        RemoveLiquidityBasketLst {
+            lst_value_calc_accs,
+            min_lst_out,
+        },
+    ) in lsts.into_iter().enumerate()
+    {
+        let lst_accounts_end = lst_accounts_start
+            + REMOVE_LIQUIDITY_BASKET_LST_ACCOUNTS_LEN
+            + usize::from(lst_value_calc_accs);
+        let lst_accounts = accounts
+            .get(lst_accounts_start..lst_accounts_end)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        lst_outputs.push(verify_remove_liquidity_basket_lst(
+            &free_args,
+            actual,
+            lst_accounts,
+            lst_index,
+            min_lst_out,
+        )?);
+
+        lst_accounts_start = lst_accounts_end;
+    }
+
+    // The pricing program is invoked for the first LST
+    // with the accounts following all the LSTs' accounts.
+    // PriceLpTokensToRedeem only takes a single output LST and the fee is priced
+    // on the LP tokens' total SOL value, so pricing programs whose LP withdrawal fee
+    // depends on the output LST charge the whole basket at the first LST's rate
+    let pricing_accounts_suffix_slice = accounts
+        .get(lst_accounts_start..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let pricing_cpi = verify_pricing_lp_cpi(
+        VerifyPricingLpCpiAccounts {
+            pool_state: actual.pool_state,
+            lst_mint: lst_outputs[0].lst_mint,
+        },
+        pricing_accounts_suffix_slice,
+    )?;
+
+    Ok((actual, lst_outputs, lp_token_amount, pricing_cpi))
+}
+
+/// `lst_accounts` is the LST's account group followed by its SOL value calculator accounts
+fn verify_remove_liquidity_basket_lst<'a, 'info>(
+    free_args: &RemoveLiquidityBasketFreeArgs<&'a AccountInfo<'info>, &'a AccountInfo<'info>>,
+    actual: RemoveLiquidityBasketAccounts<'a, 'info>,
+    lst_accounts: &'a [AccountInfo<'info>],
+    lst_index: usize,
+    min_lst_out: u64,
+) -> Result<RemoveLiquidityBasketLstOutput<'a, 'info>, ProgramError> {
+    let (lst_group, cpi_accounts_suffix_slice) =
+        lst_accounts.split_at(REMOVE_LIQUIDITY_BASKET_LST_ACCOUNTS_LEN);
+    let [lst_mint, dst_lst_acc, pool_reserves, protocol_fee_accumulator, lst_token_program] =
+        lst_group
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let expected = free_args.resolve_lst(lst_index, *dst_lst_acc.key, lst_mint)?;
+    for (actual_key, expected_key) in [
+        (lst_mint.key, &expected.lst_mint),
+        (dst_lst_acc.key, &expected.dst_lst_acc),
+        (pool_reserves.key, &expected.pool_reserves),
+        (
+            protocol_fee_accumulator.key,
+            &expected.protocol_fee_accumulator,
+        ),
+        (lst_token_program.key, &expected.lst_token_program),
+    ] {
+        if actual_key != expected_key {
+            return Err(log_and_return_wrong_acc_err((*actual_key, *expected_key)));
+        }
+    }
+    for should_be_writable in [dst_lst_acc, pool_reserves, protocol_fee_accumulator] {
+        if !should_be_writable.is_writable {
+            return Err(log_and_return_acc_privilege_err((
+                should_be_writable,
+                ProgramError::InvalidAccountData,
+            )));
+        }
+    }
+
+    let lst_cpi = verify_lst_sol_val_calc_cpi(
+        VerifyLstSolValCalcCpiAccounts {
+            lst_state_list: actual.lst_state_list,
+            lst_mint,
+        },
+        cpi_accounts_suffix_slice,
+        lst_index,
+    )?;
+
+    Ok(RemoveLiquidityBasketLstOutput {
+        lst_index,
+        min_lst_out,
+        lst_mint,
+        dst_lst_acc,
+        pool_reserves,
+        protocol_fee_accumulator,
+        lst_token_program,
+        lst_cpi,
+    })
+}
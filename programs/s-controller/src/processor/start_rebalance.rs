@@ -13,9 +13,7 @@ use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
 use sanctum_system_program_lib::{space_to_u64, transfer_direct_increment};
-use sanctum_token_lib::{
-    token_account_balance, transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
-};
+use sanctum_token_lib::token_account_balance;
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
@@ -31,7 +29,7 @@ use system_program_interface::{
 
 use crate::{
     account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
-    cpi::SrcDstLstSolValueCalculatorCpis,
+    cpi::{SrcDstLstSolValueCalculatorCpis, TransferCheckedWithHookCpi},
     verify::{
         verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
         verify_src_dst_lst_sol_val_calc_cpis, VerifySrcDstLstSolValCalcCpiAccounts,
@@ -54,6 +52,7 @@ pub fn process_start_rebalance(
             src_lst_index,
             dst_lst_index,
         },
+        accounts_suffix_slice,
     ) = verify_start_rebalance(accounts, &args)?;
 
     let src_sync_sol_value_accounts =
@@ -67,17 +66,15 @@ pub fn process_start_rebalance(
 
     let old_total_sol_value = accounts.pool_state.total_sol_value()?;
 
-    transfer_checked_decimal_agnostic_invoke_signed(
-        TransferCheckedAccounts {
-            token_program: accounts.src_lst_token_program,
-            from: accounts.src_pool_reserves,
-            to: accounts.withdraw_to,
-            authority: accounts.pool_state,
-            mint: accounts.src_lst_mint,
-        },
-        args.amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
-    )?;
+    TransferCheckedWithHookCpi {
+        from: accounts.src_pool_reserves,
+        to: accounts.withdraw_to,
+        token_program: accounts.src_lst_token_program,
+        authority: accounts.pool_state,
+        mint: accounts.src_lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_signed(args.amount, &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]])?;
 
     sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
 
@@ -134,6 +131,7 @@ fn verify_start_rebalance<'a, 'info>(
         StartRebalanceAccounts<'a, 'info>,
         SrcDstLstSolValueCalculatorCpis<'a, 'info>,
         SrcDstLstIndexes,
+        &'a [AccountInfo<'info>],
     ),
     ProgramError,
 > {
@@ -190,7 +188,12 @@ fn verify_start_rebalance<'a, 'info>(
 
     verify_has_succeeding_end_rebalance_ix(actual.instructions, *actual.dst_lst_mint.key)?;
 
-    Ok((actual, src_dst_lst_cpis, src_dst_lst_indexes))
+    Ok((
+        actual,
+        src_dst_lst_cpis,
+        src_dst_lst_indexes,
+        accounts_suffix_slice,
+    ))
 }
 
 fn verify_has_succeeding_end_rebalance_ix(
@@ -1,6 +1,7 @@
 use s_controller_interface::{
     swap_exact_in_verify_account_keys, swap_exact_in_verify_account_privileges, SControllerError,
-    SwapExactInAccounts, SwapExactInIxArgs, SWAP_EXACT_IN_IX_ACCOUNTS_LEN,
+    SwapExactInAccounts, SwapExactInIxArgs, SwapExactInWithDeadlineIxArgs,
+    SWAP_EXACT_IN_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
     calc_swap_protocol_fees, index_to_usize,
@@ -11,27 +12,30 @@ use s_controller_lib::{
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
-use sanctum_token_lib::{
-    token_account_balance, transfer_checked_decimal_agnostic_invoke,
-    transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
-};
+use sanctum_token_lib::token_account_balance;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
 use crate::{
     account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
-    cpi::{PricingProgramIxArgs, PricingProgramPriceSwapCpi, SrcDstLstSolValueCalculatorCpis},
+    cpi::{
+        PricingProgramIxArgs, PricingProgramPriceSwapCpi, SrcDstLstSolValueCalculatorCpis,
+        TransferCheckedWithHookCpi,
+    },
     processor::sync_sol_value_unchecked,
     verify::{
-        verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled, verify_swap_cpis,
-        verify_swap_not_same_lst, VerifySwapCpiAccounts,
+        verify_deadline, verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
+        verify_swap_cpis, verify_swap_not_same_lst, VerifySwapCpiAccounts,
     },
 };
 
 use super::SyncSolValueUncheckedAccounts;
 
 pub fn process_swap_exact_in(accounts: &[AccountInfo], args: SwapExactInIxArgs) -> ProgramResult {
+    let accounts_suffix_slice = accounts
+        .get(SWAP_EXACT_IN_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
     let (
         accounts,
         SwapExactInAmounts {
@@ -58,12 +62,24 @@ pub fn process_swap_exact_in(accounts: &[AccountInfo], args: SwapExactInIxArgs)
 
     let start_total_sol_value = accounts.pool_state.total_sol_value()?;
 
-    let in_sol_value = src_lst_cpi.invoke_lst_to_sol(amount)?.get_min();
+    // Transfer in first to price the amount the pool actually received,
+    // which is less than amount for LSTs with transfer fees
+    let src_lst_received = TransferCheckedWithHookCpi {
+        from: accounts.src_lst_acc,
+        to: accounts.src_pool_reserves,
+        token_program: accounts.src_lst_token_program,
+        authority: accounts.signer,
+        mint: accounts.src_lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_measure_received(amount)?;
+
+    let in_sol_value = src_lst_cpi.invoke_lst_to_sol(src_lst_received)?.get_min();
     if in_sol_value == 0 {
         return Err(SControllerError::ZeroValue.into());
     }
     let out_sol_value = pricing_cpi.invoke_price_exact_in(PricingProgramIxArgs {
-        amount,
+        amount: src_lst_received,
         sol_value: in_sol_value,
     })?;
     let dst_lst_out = dst_lst_cpi.invoke_sol_to_lst(out_sol_value)?.get_min();
@@ -90,38 +106,35 @@ pub fn process_swap_exact_in(accounts: &[AccountInfo], args: SwapExactInIxArgs)
         return Err(SControllerError::NotEnoughLiquidity.into());
     }
 
-    transfer_checked_decimal_agnostic_invoke(
-        TransferCheckedAccounts {
-            from: accounts.src_lst_acc,
-            to: accounts.src_pool_reserves,
-            token_program: accounts.src_lst_token_program,
-            authority: accounts.signer,
-            mint: accounts.src_lst_mint,
-        },
-        amount,
-    )?;
-    transfer_checked_decimal_agnostic_invoke_signed(
-        TransferCheckedAccounts {
-            from: accounts.dst_pool_reserves,
-            to: accounts.protocol_fee_accumulator,
-            token_program: accounts.dst_lst_token_program,
-            authority: accounts.pool_state,
-            mint: accounts.dst_lst_mint,
-        },
+    // For LSTs with transfer fees, the fee is charged on both transfers out of the reserves:
+    // the reserves are debited total_dst_lst_out, the protocol fee accumulator receives
+    // less than to_protocol_fees_lst_amount and the user receives less than dst_lst_out.
+    // Each recipient bears the fee on its own share, so the pool's sol value is unaffected
+    TransferCheckedWithHookCpi {
+        from: accounts.dst_pool_reserves,
+        to: accounts.protocol_fee_accumulator,
+        token_program: accounts.dst_lst_token_program,
+        authority: accounts.pool_state,
+        mint: accounts.dst_lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_signed(
         to_protocol_fees_lst_amount,
         &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
     )?;
-    transfer_checked_decimal_agnostic_invoke_signed(
-        TransferCheckedAccounts {
-            from: accounts.dst_pool_reserves,
-            to: accounts.dst_lst_acc,
-            token_program: accounts.dst_lst_token_program,
-            authority: accounts.pool_state,
-            mint: accounts.dst_lst_mint,
-        },
-        dst_lst_out,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
-    )?;
+    let dst_lst_received = TransferCheckedWithHookCpi {
+        from: accounts.dst_pool_reserves,
+        to: accounts.dst_lst_acc,
+        token_program: accounts.dst_lst_token_program,
+        authority: accounts.pool_state,
+        mint: accounts.dst_lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_signed_measure_received(dst_lst_out, &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]])?;
+    // user receives less than dst_lst_out for LSTs with transfer fees
+    if dst_lst_received < min_amount_out {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
 
     sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
     sync_sol_value_unchecked(dst_sync_sol_value_accounts, dst_lst_cpi, dst_lst_index)?;
@@ -134,6 +147,33 @@ pub fn process_swap_exact_in(accounts: &[AccountInfo], args: SwapExactInIxArgs)
     Ok(())
 }
 
+/// Same as [`process_swap_exact_in`], but fails if the transaction lands after `deadline`
+pub fn process_swap_exact_in_with_deadline(
+    accounts: &[AccountInfo],
+    SwapExactInWithDeadlineIxArgs {
+        src_lst_value_calc_accs,
+        dst_lst_value_calc_accs,
+        src_lst_index,
+        dst_lst_index,
+        min_amount_out,
+        amount,
+        deadline,
+    }: SwapExactInWithDeadlineIxArgs,
+) -> ProgramResult {
+    verify_deadline(&deadline)?;
+    process_swap_exact_in(
+        accounts,
+        SwapExactInIxArgs {
+            src_lst_value_calc_accs,
+            dst_lst_value_calc_accs,
+            src_lst_index,
+            dst_lst_index,
+            min_amount_out,
+            amount,
+        },
+    )
+}
+
 fn verify_swap_exact_in<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
     SwapExactInIxArgs {
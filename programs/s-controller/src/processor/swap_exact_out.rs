@@ -1,6 +1,7 @@
 use s_controller_interface::{
     swap_exact_out_verify_account_keys, swap_exact_out_verify_account_privileges, SControllerError,
-    SwapExactOutAccounts, SwapExactOutIxArgs, SWAP_EXACT_OUT_IX_ACCOUNTS_LEN,
+    SwapExactOutAccounts, SwapExactOutIxArgs, SwapExactOutWithDeadlineIxArgs,
+    SWAP_EXACT_OUT_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
     calc_swap_protocol_fees, index_to_usize,
@@ -11,26 +12,29 @@ use s_controller_lib::{
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
-use sanctum_token_lib::{
-    token_account_balance, transfer_checked_decimal_agnostic_invoke,
-    transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
-};
+use sanctum_token_lib::token_account_balance;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
 use crate::{
     account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
-    cpi::{PricingProgramIxArgs, PricingProgramPriceSwapCpi, SrcDstLstSolValueCalculatorCpis},
+    cpi::{
+        PricingProgramIxArgs, PricingProgramPriceSwapCpi, SrcDstLstSolValueCalculatorCpis,
+        TransferCheckedWithHookCpi,
+    },
     verify::{
-        verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled, verify_swap_cpis,
-        verify_swap_not_same_lst, VerifySwapCpiAccounts,
+        verify_deadline, verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
+        verify_swap_cpis, verify_swap_not_same_lst, VerifySwapCpiAccounts,
     },
 };
 
 use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
 
 pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs) -> ProgramResult {
+    let accounts_suffix_slice = accounts
+        .get(SWAP_EXACT_OUT_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
     let (
         accounts,
         SwapExactOutAmounts {
@@ -89,38 +93,46 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
         return Err(SControllerError::NotEnoughLiquidity.into());
     }
 
-    transfer_checked_decimal_agnostic_invoke(
-        TransferCheckedAccounts {
-            from: accounts.src_lst_acc,
-            to: accounts.src_pool_reserves,
-            token_program: accounts.src_lst_token_program,
-            authority: accounts.signer,
-            mint: accounts.src_lst_mint,
-        },
-        src_lst_in,
-    )?;
-    transfer_checked_decimal_agnostic_invoke_signed(
-        TransferCheckedAccounts {
-            from: accounts.dst_pool_reserves,
-            to: accounts.protocol_fee_accumulator,
-            token_program: accounts.dst_lst_token_program,
-            authority: accounts.pool_state,
-            mint: accounts.dst_lst_mint,
-        },
+    // Pricing is done on the exact amounts, so reject transfers that are charged a transfer fee
+    // instead of having the pool or the user bear it.
+    // The protocol fee transfer below is not checked: if it is charged a fee,
+    // the accumulator receives less than to_protocol_fees_lst_amount, same as in SwapExactIn
+    let src_lst_received = TransferCheckedWithHookCpi {
+        from: accounts.src_lst_acc,
+        to: accounts.src_pool_reserves,
+        token_program: accounts.src_lst_token_program,
+        authority: accounts.signer,
+        mint: accounts.src_lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_measure_received(src_lst_in)?;
+    if src_lst_received != src_lst_in {
+        return Err(SControllerError::TransferAmountMismatch.into());
+    }
+    TransferCheckedWithHookCpi {
+        from: accounts.dst_pool_reserves,
+        to: accounts.protocol_fee_accumulator,
+        token_program: accounts.dst_lst_token_program,
+        authority: accounts.pool_state,
+        mint: accounts.dst_lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_signed(
         to_protocol_fees_lst_amount,
         &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
     )?;
-    transfer_checked_decimal_agnostic_invoke_signed(
-        TransferCheckedAccounts {
-            from: accounts.dst_pool_reserves,
-            to: accounts.dst_lst_acc,
-            token_program: accounts.dst_lst_token_program,
-            authority: accounts.pool_state,
-            mint: accounts.dst_lst_mint,
-        },
-        amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
-    )?;
+    let dst_lst_received = TransferCheckedWithHookCpi {
+        from: accounts.dst_pool_reserves,
+        to: accounts.dst_lst_acc,
+        token_program: accounts.dst_lst_token_program,
+        authority: accounts.pool_state,
+        mint: accounts.dst_lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_signed_measure_received(amount, &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]])?;
+    if dst_lst_received != amount {
+        return Err(SControllerError::TransferAmountMismatch.into());
+    }
 
     sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
     sync_sol_value_unchecked(dst_sync_sol_value_accounts, dst_lst_cpi, dst_lst_index)?;
@@ -133,6 +145,33 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
     Ok(())
 }
 
+/// Same as [`process_swap_exact_out`], but fails if the transaction lands after `deadline`
+pub fn process_swap_exact_out_with_deadline(
+    accounts: &[AccountInfo],
+    SwapExactOutWithDeadlineIxArgs {
+        src_lst_value_calc_accs,
+        dst_lst_value_calc_accs,
+        src_lst_index,
+        dst_lst_index,
+        max_amount_in,
+        amount,
+        deadline,
+    }: SwapExactOutWithDeadlineIxArgs,
+) -> ProgramResult {
+    verify_deadline(&deadline)?;
+    process_swap_exact_out(
+        accounts,
+        SwapExactOutIxArgs {
+            src_lst_value_calc_accs,
+            dst_lst_value_calc_accs,
+            src_lst_index,
+            dst_lst_index,
+            max_amount_in,
+            amount,
+        },
+    )
+}
+
 fn verify_swap_exact_out<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
     SwapExactOutIxArgs {
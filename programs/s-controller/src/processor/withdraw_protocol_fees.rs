@@ -1,6 +1,7 @@
 use s_controller_interface::{
     withdraw_protocol_fees_verify_account_keys, withdraw_protocol_fees_verify_account_privileges,
     SControllerError, WithdrawProtocolFeesAccounts, WithdrawProtocolFeesIxArgs,
+    WITHDRAW_PROTOCOL_FEES_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
     program::{PROTOCOL_FEE_BUMP, PROTOCOL_FEE_SEED},
@@ -9,43 +10,47 @@ use s_controller_lib::{
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
-use sanctum_token_lib::{
-    token_account_balance, transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
-};
+use sanctum_token_lib::token_account_balance;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::{cpi::TransferCheckedWithHookCpi, verify::verify_not_rebalancing_and_not_disabled};
 
 pub fn process_withdraw_protocol_fees(
     accounts: &[AccountInfo],
     args: WithdrawProtocolFeesIxArgs,
 ) -> ProgramResult {
-    let accounts = verify_withdraw_protocol_fees(accounts)?;
+    let (accounts, accounts_suffix_slice) = verify_withdraw_protocol_fees(accounts)?;
 
     if args.amount > token_account_balance(accounts.protocol_fee_accumulator)? {
         return Err(SControllerError::NotEnoughFees.into());
     }
 
-    transfer_checked_decimal_agnostic_invoke_signed(
-        TransferCheckedAccounts {
-            from: accounts.protocol_fee_accumulator,
-            to: accounts.withdraw_to,
-            token_program: accounts.token_program,
-            authority: accounts.protocol_fee_accumulator_auth,
-            mint: accounts.lst_mint,
-        },
-        args.amount,
-        &[&[PROTOCOL_FEE_SEED, &[PROTOCOL_FEE_BUMP]]],
-    )?;
+    TransferCheckedWithHookCpi {
+        from: accounts.protocol_fee_accumulator,
+        to: accounts.withdraw_to,
+        token_program: accounts.token_program,
+        authority: accounts.protocol_fee_accumulator_auth,
+        mint: accounts.lst_mint,
+        additional_accounts: accounts_suffix_slice,
+    }
+    .invoke_signed(args.amount, &[&[PROTOCOL_FEE_SEED, &[PROTOCOL_FEE_BUMP]]])?;
 
     Ok(())
 }
 
+/// Returns the verified accounts and the accounts suffix slice,
+/// which holds the accounts required by the LST's transfer hook, if any
 fn verify_withdraw_protocol_fees<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
-) -> Result<WithdrawProtocolFeesAccounts<'a, 'info>, ProgramError> {
+) -> Result<
+    (
+        WithdrawProtocolFeesAccounts<'a, 'info>,
+        &'a [AccountInfo<'info>],
+    ),
+    ProgramError,
+> {
     let actual: WithdrawProtocolFeesAccounts = load_accounts(accounts)?;
 
     let free_args = WithdrawProtocolFeesFreeArgs {
@@ -64,5 +69,9 @@ fn verify_withdraw_protocol_fees<'a, 'info>(
 
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
-    Ok(actual)
+    let accounts_suffix_slice = accounts
+        .get(WITHDRAW_PROTOCOL_FEES_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    Ok((actual, accounts_suffix_slice))
 }
@@ -1,11 +1,14 @@
 //! Common verification functions used across multiple instruction processors
 
-use s_controller_interface::{LstState, PoolState, SControllerError};
+use s_controller_interface::{Deadline, LstState, PoolState, SControllerError};
 use s_controller_lib::{
     try_disable_pool_authority_list, try_find_element_in_list, SrcDstLstIndexes,
     SrcDstLstValueCalcAccs, U8Bool,
 };
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
 
 use crate::{
     account_traits::{
@@ -37,6 +40,31 @@ pub const fn verify_lst_input_not_disabled(lst_state: &LstState) -> Result<(), S
     Ok(())
 }
 
+/// Errors if the current slot or unix timestamp is past `deadline`'s.
+/// Does not read the clock if neither are set
+pub fn verify_deadline(
+    Deadline {
+        expiry_slot,
+        expiry_unix_timestamp,
+    }: &Deadline,
+) -> Result<(), ProgramError> {
+    if expiry_slot.is_none() && expiry_unix_timestamp.is_none() {
+        return Ok(());
+    }
+    let Clock {
+        slot,
+        unix_timestamp,
+        ..
+    } = Clock::get()?;
+    if expiry_slot.is_some_and(|expiry_slot| slot > expiry_slot)
+        || expiry_unix_timestamp
+            .is_some_and(|expiry_unix_timestamp| unix_timestamp > expiry_unix_timestamp)
+    {
+        return Err(SControllerError::DeadlineExceeded.into());
+    }
+    Ok(())
+}
+
 pub fn verify_admin_or_disable_pool_authority(
     signer: Pubkey,
     pool_state: &PoolState,
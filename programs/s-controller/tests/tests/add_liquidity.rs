@@ -145,6 +145,10 @@ async fn basic_add_liquidity_twice_flat_fee() {
         flat_fee_interface::ProgramState {
             manager: Default::default(),
             lp_withdrawal_fee_bps: 1000,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         [
             MockFeeAccountArgs {
@@ -291,6 +295,7 @@ async fn exec_verify_add_liq_success_no_fees(
             pricing_program_id,
             lst_calculator_accounts,
             pricing_program_price_lp_accounts: pricing_program_accounts,
+            transfer_hook: None,
         },
     )
     .unwrap();
@@ -443,6 +448,7 @@ async fn fail_add_liquidity_slippage() {
                 is_signer: false,
                 is_writable: false,
             }],
+            transfer_hook: None,
         },
     )
     .unwrap();
@@ -0,0 +1,296 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    add_liquidity_multi_ix_full, try_pool_state, AddLiquidityMultiByMintFreeArgs,
+    AddLiquidityMultiLstFullArgs, AddRemoveLiquidityAccountSuffixes,
+    AddRemoveLiquidityExtraAccounts,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::token_account_balance;
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::ProgramTestContext;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::*;
+
+#[tokio::test]
+async fn basic_add_liquidity_multi_no_fee() {
+    const JITOSOL_TO_ADD: u64 = 1_000_000_000;
+    const MSOL_TO_ADD: u64 = 2_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: JITOSOL_TO_ADD,
+        });
+    let liquidity_provider_msol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: msol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: MSOL_TO_ADD,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jitosol_mint_acc = banks_client.get_account_unwrapped(jitosol::ID).await;
+    let msol_mint_acc = banks_client.get_account_unwrapped(msol::ID).await;
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let free_args = AddLiquidityMultiByMintFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        dst_lp_acc: liquidity_provider_lp_token_acc_addr,
+        pool_state: &pool_state_account,
+        lst_state_list: &lst_state_list_account,
+    };
+    let keys = free_args.resolve().unwrap();
+    let (jitosol_keys, jitosol_index, jitosol_program_ids) = free_args
+        .resolve_lst(
+            liquidity_provider_jitosol_acc_addr,
+            KeyedAccount {
+                pubkey: jitosol::ID,
+                account: jitosol_mint_acc,
+            },
+        )
+        .unwrap();
+    let (msol_keys, msol_index, msol_program_ids) = free_args
+        .resolve_lst(
+            liquidity_provider_msol_acc_addr,
+            KeyedAccount {
+                pubkey: msol::ID,
+                account: msol_mint_acc,
+            },
+        )
+        .unwrap();
+
+    let jitosol_calculator_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let [jitosol_pricing_accounts, msol_pricing_accounts] = [jitosol::ID, msol::ID].map(|mint| {
+        [AccountMeta {
+            pubkey: mint,
+            is_signer: false,
+            is_writable: false,
+        }]
+    });
+    let ix = add_liquidity_multi_ix_full(
+        keys,
+        &[
+            AddLiquidityMultiLstFullArgs {
+                keys: jitosol_keys,
+                lst_index: jitosol_index,
+                lst_amount: JITOSOL_TO_ADD,
+                extra_accounts: AddRemoveLiquidityExtraAccounts::new(
+                    jitosol_program_ids,
+                    AddRemoveLiquidityAccountSuffixes {
+                        lst_calculator_accounts: &jitosol_calculator_accounts,
+                        pricing_program_price_lp_accounts: &jitosol_pricing_accounts,
+                        transfer_hook: None,
+                    },
+                ),
+            },
+            AddLiquidityMultiLstFullArgs {
+                keys: msol_keys,
+                lst_index: msol_index,
+                lst_amount: MSOL_TO_ADD,
+                extra_accounts: AddRemoveLiquidityExtraAccounts::new(
+                    msol_program_ids,
+                    AddRemoveLiquidityAccountSuffixes {
+                        lst_calculator_accounts: &marinade_sol_val_calc_account_metas(),
+                        pricing_program_price_lp_accounts: &msol_pricing_accounts,
+                        transfer_hook: None,
+                    },
+                ),
+            },
+        ],
+        0,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    for (src_lst_acc, pool_reserves, amount) in [
+        (
+            liquidity_provider_jitosol_acc_addr,
+            jitosol_keys.pool_reserves,
+            JITOSOL_TO_ADD,
+        ),
+        (
+            liquidity_provider_msol_acc_addr,
+            msol_keys.pool_reserves,
+            MSOL_TO_ADD,
+        ),
+    ] {
+        let src_lst_acc = banks_client.get_account_unwrapped(src_lst_acc).await;
+        assert_eq!(token_account_balance(src_lst_acc).unwrap(), 0);
+        // since no fees
+        let pool_reserves = banks_client.get_account_unwrapped(pool_reserves).await;
+        assert_eq!(token_account_balance(pool_reserves).unwrap(), amount);
+    }
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let pool_total_sol_value = try_pool_state(&pool_state_account.data)
+        .unwrap()
+        .total_sol_value;
+    // since LSTs should be worth >1 SOL
+    assert!(pool_total_sol_value > JITOSOL_TO_ADD + MSOL_TO_ADD);
+
+    // LP tokens are minted 1:1 with SOL value for an empty pool, with no fees
+    let lp_token_acc = banks_client
+        .get_account_unwrapped(liquidity_provider_lp_token_acc_addr)
+        .await;
+    assert_eq!(
+        token_account_balance(lp_token_acc).unwrap(),
+        pool_total_sol_value
+    );
+}
+
+#[tokio::test]
+async fn add_liquidity_multi_rejects_duplicate_lst() {
+    const JITOSOL_TO_ADD: u64 = 1_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 2 * JITOSOL_TO_ADD,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jitosol_mint_acc = banks_client.get_account_unwrapped(jitosol::ID).await;
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let free_args = AddLiquidityMultiByMintFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        dst_lp_acc: liquidity_provider_lp_token_acc_addr,
+        pool_state: &pool_state_account,
+        lst_state_list: &lst_state_list_account,
+    };
+    let keys = free_args.resolve().unwrap();
+    let (jitosol_keys, jitosol_index, jitosol_program_ids) = free_args
+        .resolve_lst(
+            liquidity_provider_jitosol_acc_addr,
+            KeyedAccount {
+                pubkey: jitosol::ID,
+                account: jitosol_mint_acc,
+            },
+        )
+        .unwrap();
+
+    let jitosol_calculator_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let jitosol_pricing_accounts = [AccountMeta {
+        pubkey: jitosol::ID,
+        is_signer: false,
+        is_writable: false,
+    }];
+    let jitosol_args = AddLiquidityMultiLstFullArgs {
+        keys: jitosol_keys,
+        lst_index: jitosol_index,
+        lst_amount: JITOSOL_TO_ADD,
+        extra_accounts: AddRemoveLiquidityExtraAccounts::new(
+            jitosol_program_ids,
+            AddRemoveLiquidityAccountSuffixes {
+                lst_calculator_accounts: &jitosol_calculator_accounts,
+                pricing_program_price_lp_accounts: &jitosol_pricing_accounts,
+                transfer_hook: None,
+            },
+        ),
+    };
+    let ix = add_liquidity_multi_ix_full(keys, &[jitosol_args, jitosol_args], 0).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::DuplicateLstInput);
+}
@@ -0,0 +1,694 @@
+//! Scenarios that stake pool fixtures cannot express,
+//! scripted with the fixed-rate SOL value calculator
+
+use std::collections::HashMap;
+
+use fixed_rate_calculator_test_utils::{FixedRateCalculatorProgramTestContext, MockLstRateArgs};
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    add_liquidity_ix_by_mint_full, create_pool_reserves_address,
+    ix_extend_with_swap_transfer_hook_accounts, remove_liquidity_basket_ix_by_mint_full,
+    swap_exact_in_ix_by_mint_full, swap_exact_out_ix_by_mint_full, sync_sol_value_ix_by_mint_full,
+    try_pool_state, AddLiquidityByMintFreeArgs, AddLiquidityIxAmts,
+    AddRemoveLiquidityAccountSuffixes, RemoveLiquidityBasketByMintFreeArgs,
+    RemoveLiquidityBasketLstByMintArgs, SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs,
+    SwapExactInAmounts, SwapExactOutAmounts, SyncSolValueByMintFreeArgs, TransferHookAccountsData,
+};
+use s_controller_test_utils::{
+    fixed_rate_no_fee_program_test, mock_transfer_hook_program, FixedRateProgramTestArgs,
+    GenAndAddTokenAccountProgramTest, LstStateListBanksClient, MockFixedRateLstArgs,
+    MockTransferHookBanksClient, MockTransferHookProgramTest, PoolStateBanksClient,
+    TransferFeeProgramTest,
+};
+use s_sol_val_calc_prog_aggregate::{FixedRateLstSolValCalc, LstSolValCalc, MutableLstSolValCalc};
+use sanctum_solana_test_utils::{
+    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use sanctum_token_ratio::{CeilDiv, ReversibleFee, U64BpsFee};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::SControllerProgramTest;
+
+/// Quotes with the same off-chain calculator that aggregators use
+async fn fetch_fixed_rate_calc(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+) -> FixedRateLstSolValCalc {
+    let mut calc = FixedRateLstSolValCalc::from_lst_mint(lst_mint);
+    let account_map: HashMap<Pubkey, Account> = HashMap::from_iter([(
+        calc.lst_rate_addr,
+        banks_client.get_account_unwrapped(calc.lst_rate_addr).await,
+    )]);
+    calc.update(&account_map).unwrap();
+    calc
+}
+
+/// Quote for SwapExactIn with the no-fee pricing program
+fn quote_swap_exact_in_no_fee(
+    src_calc: &FixedRateLstSolValCalc,
+    dst_calc: &FixedRateLstSolValCalc,
+    amount: u64,
+) -> u64 {
+    let sol_value = src_calc.lst_to_sol(amount).unwrap().get_min();
+    dst_calc.sol_to_lst(sol_value).unwrap().get_min()
+}
+
+#[tokio::test]
+async fn swap_exact_in_fails_on_value_jump_between_quote_and_swap() {
+    const STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const SRC_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    let src_lst_mint = Pubkey::new_unique();
+    let dst_lst_mint = Pubkey::new_unique();
+    let swapper = Keypair::new();
+
+    let mut program_test = fixed_rate_no_fee_program_test(FixedRateProgramTestArgs {
+        lsts: [src_lst_mint, dst_lst_mint]
+            .map(|lst_mint| MockFixedRateLstArgs {
+                lst_rate: MockLstRateArgs::single(lst_mint, 1, 1),
+                sol_value: STARTING_POOL_RESERVES,
+                reserves: STARTING_POOL_RESERVES,
+                protocol_fee_accumulator: 0,
+                transfer_fee_bps: None,
+                transfer_hook_program_id: None,
+            })
+            .to_vec(),
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let swapper_src_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: src_lst_mint,
+        authority: swapper.pubkey(),
+        amount: SRC_TO_SWAP_IN,
+    });
+    let swapper_dst_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: dst_lst_mint,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+
+    let mut ctx = program_test.start_with_context().await;
+
+    let src_calc = fetch_fixed_rate_calc(&mut ctx.banks_client, src_lst_mint).await;
+    let dst_calc = fetch_fixed_rate_calc(&mut ctx.banks_client, dst_lst_mint).await;
+    let quoted = quote_swap_exact_in_no_fee(&src_calc, &dst_calc, SRC_TO_SWAP_IN);
+    assert_eq!(quoted, SRC_TO_SWAP_IN);
+
+    // dst LST doubles in SOL value after the quote was made, halving the amount out
+    ctx.set_mock_lst_rate(MockLstRateArgs::single(dst_lst_mint, 2, 1));
+
+    let lst_state_list_account = ctx.banks_client.get_lst_state_list_acc().await;
+    let swap_ix = |min_amount_out: u64| {
+        swap_exact_in_ix_by_mint_full(
+            SwapByMintsFreeArgs {
+                signer: swapper.pubkey(),
+                src_lst_acc: swapper_src_acc_addr,
+                dst_lst_acc: swapper_dst_acc_addr,
+                src_lst_mint: MintWithTokenProgram {
+                    pubkey: src_lst_mint,
+                    token_program: spl_token::ID,
+                },
+                dst_lst_mint: MintWithTokenProgram {
+                    pubkey: dst_lst_mint,
+                    token_program: spl_token::ID,
+                },
+                lst_state_list: lst_state_list_account.clone(),
+            },
+            SwapExactInAmounts {
+                min_amount_out,
+                amount: SRC_TO_SWAP_IN,
+            },
+            SrcDstLstSolValueCalcAccountSuffixes {
+                src_lst_calculator_accounts: &src_calc.ix_accounts(),
+                dst_lst_calculator_accounts: &dst_calc.ix_accounts(),
+            },
+            &[
+                AccountMeta::new_readonly(src_lst_mint, false),
+                AccountMeta::new_readonly(dst_lst_mint, false),
+            ],
+            no_fee_pricing_program::ID,
+        )
+        .unwrap()
+    };
+
+    let last_blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    let mut tx = Transaction::new_with_payer(&[swap_ix(quoted)], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer, &swapper], last_blockhash);
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
+
+    // requoting with the new rate should match the program exactly
+    let dst_calc = fetch_fixed_rate_calc(&mut ctx.banks_client, dst_lst_mint).await;
+    let requoted = quote_swap_exact_in_no_fee(&src_calc, &dst_calc, SRC_TO_SWAP_IN);
+    assert_eq!(requoted, SRC_TO_SWAP_IN / 2);
+
+    let mut tx = Transaction::new_with_payer(&[swap_ix(requoted)], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer, &swapper], last_blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let swapper_dst_acc = ctx
+        .banks_client
+        .get_account_unwrapped(swapper_dst_acc_addr)
+        .await;
+    assert_eq!(token_account_balance(swapper_dst_acc).unwrap(), requoted);
+}
+
+#[tokio::test]
+async fn sync_sol_value_uses_min_of_faulty_range() {
+    const RESERVES: u64 = 1_000_000_000;
+    // 1 LST = 1.5 SOL min, 0.5 SOL max
+    const EXPECTED_SOL_VALUE: u64 = 1_500_000_000;
+
+    let lst_mint = Pubkey::new_unique();
+    let faulty_lst_rate = MockLstRateArgs {
+        lst_mint,
+        num_min: 3,
+        num_max: 1,
+        denom: 2,
+    };
+
+    let program_test = fixed_rate_no_fee_program_test(FixedRateProgramTestArgs {
+        lsts: vec![MockFixedRateLstArgs {
+            lst_rate: faulty_lst_rate,
+            sol_value: RESERVES,
+            reserves: RESERVES,
+            protocol_fee_accumulator: 0,
+            transfer_fee_bps: None,
+            transfer_hook_program_id: None,
+        }],
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let calc = fetch_fixed_rate_calc(&mut banks_client, lst_mint).await;
+    let quoted_range = calc.lst_to_sol(RESERVES).unwrap();
+    assert!(quoted_range.get_min() > quoted_range.get_max());
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let lst_mint_acc = banks_client.get_account_unwrapped(lst_mint).await;
+    let ix = sync_sol_value_ix_by_mint_full(
+        SyncSolValueByMintFreeArgs {
+            lst_state_list: lst_state_list_acc,
+            lst_mint: KeyedAccount {
+                pubkey: lst_mint,
+                account: lst_mint_acc,
+            },
+        },
+        &calc.ix_accounts(),
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // the range is not checked for min <= max, the min is always taken as the SOL value
+    let lst_state = banks_client.get_lst_state(lst_mint).await;
+    assert_eq!(lst_state.sol_value, quoted_range.get_min());
+    assert_eq!(lst_state.sol_value, EXPECTED_SOL_VALUE);
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    assert_eq!(
+        try_pool_state(&pool_state_acc.data)
+            .unwrap()
+            .total_sol_value,
+        EXPECTED_SOL_VALUE
+    );
+}
+
+#[tokio::test]
+async fn remove_liquidity_basket_slippage_uses_amount_after_transfer_fee() {
+    const LP_TOKEN_SUPPLY: u64 = 1_000_000_000;
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_SUPPLY / 2;
+    const RESERVES: u64 = 1_000_000_000;
+    const TRANSFER_FEE_BPS: u16 = 100;
+
+    let lst_mint = Pubkey::new_unique();
+    let lp_token_mint = Pubkey::new_unique();
+    let liquidity_provider = Keypair::new();
+
+    let mut program_test = fixed_rate_no_fee_program_test(FixedRateProgramTestArgs {
+        lsts: vec![MockFixedRateLstArgs {
+            lst_rate: MockLstRateArgs::single(lst_mint, 1, 1),
+            sol_value: RESERVES,
+            reserves: RESERVES,
+            protocol_fee_accumulator: 0,
+            transfer_fee_bps: Some(TRANSFER_FEE_BPS),
+            transfer_hook_program_id: None,
+        }],
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+    })
+    .add_s_program();
+    let liquidity_provider_lst_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: lst_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: LP_TOKENS_TO_REMOVE,
+        });
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let calc = fetch_fixed_rate_calc(&mut banks_client, lst_mint).await;
+    let calculator_accounts = calc.ix_accounts();
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let remove_ix = |min_lst_out: u64| {
+        remove_liquidity_basket_ix_by_mint_full(
+            RemoveLiquidityBasketByMintFreeArgs {
+                signer: liquidity_provider.pubkey(),
+                src_lp_acc: liquidity_provider_lp_token_acc_addr,
+                pool_state: &pool_state_account,
+                lst_state_list: &lst_state_list_account,
+            },
+            LP_TOKENS_TO_REMOVE,
+            vec![RemoveLiquidityBasketLstByMintArgs {
+                dst_lst_acc: liquidity_provider_lst_acc_addr,
+                lst_mint: MintWithTokenProgram {
+                    pubkey: lst_mint,
+                    token_program: spl_token_2022::ID,
+                },
+                min_lst_out,
+                lst_calculator_accounts: &calculator_accounts,
+            }],
+            &[AccountMeta::new_readonly(lst_mint, false)],
+        )
+        .unwrap()
+    };
+
+    // the pool sends its pro-rata share, the transfer fee is withheld from what the LP receives
+    let to_user_lst_amount = RESERVES / 2;
+    let expected_received = CeilDiv(U64BpsFee::new_unchecked(TRANSFER_FEE_BPS))
+        .apply(to_user_lst_amount)
+        .unwrap()
+        .amt_after_fee();
+    assert!(expected_received < to_user_lst_amount);
+
+    let mut tx =
+        Transaction::new_with_payer(&[remove_ix(to_user_lst_amount)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
+
+    let mut tx =
+        Transaction::new_with_payer(&[remove_ix(expected_received)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let liquidity_provider_lst_acc = banks_client
+        .get_account_unwrapped(liquidity_provider_lst_acc_addr)
+        .await;
+    assert_eq!(
+        token_account_balance(liquidity_provider_lst_acc).unwrap(),
+        expected_received
+    );
+    let lst_state = banks_client.get_lst_state(lst_mint).await;
+    let pool_reserves = banks_client
+        .get_account_unwrapped(
+            create_pool_reserves_address(&lst_state, spl_token_2022::ID).unwrap(),
+        )
+        .await;
+    assert_eq!(
+        token_account_balance(pool_reserves).unwrap(),
+        RESERVES - to_user_lst_amount
+    );
+}
+
+/// Program test with the mock transfer hook program, whose counter
+/// for each LST counts the transfers the S controller made
+fn transfer_hook_program_test(
+    lsts: Vec<MockFixedRateLstArgs>,
+    lp_token_mint: Pubkey,
+) -> ProgramTest {
+    let hooked_lst_mints: Vec<Pubkey> = lsts
+        .iter()
+        .filter(|lst| lst.transfer_hook_program_id.is_some())
+        .map(|lst| lst.lst_rate.lst_mint)
+        .collect();
+    let lp_token_supply = lsts.iter().map(|lst| lst.sol_value).sum();
+    let program_test = fixed_rate_no_fee_program_test(FixedRateProgramTestArgs {
+        lsts,
+        lp_token_mint,
+        lp_token_supply,
+    })
+    .add_s_program()
+    .add_mock_transfer_hook_program();
+    hooked_lst_mints
+        .into_iter()
+        .fold(program_test, |program_test, lst_mint| {
+            program_test.add_mock_transfer_hook_accounts(lst_mint)
+        })
+}
+
+fn transfer_hook_lst_args(
+    lst_mint: Pubkey,
+    reserves: u64,
+    transfer_fee_bps: Option<u16>,
+) -> MockFixedRateLstArgs {
+    MockFixedRateLstArgs {
+        lst_rate: MockLstRateArgs::single(lst_mint, 1, 1),
+        sol_value: reserves,
+        reserves,
+        protocol_fee_accumulator: 0,
+        transfer_fee_bps,
+        transfer_hook_program_id: Some(mock_transfer_hook_program::ID),
+    }
+}
+
+fn amt_after_transfer_fee(transfer_fee_bps: u16, amount: u64) -> u64 {
+    CeilDiv(U64BpsFee::new_unchecked(transfer_fee_bps))
+        .apply(amount)
+        .unwrap()
+        .amt_after_fee()
+}
+
+#[tokio::test]
+async fn swap_exact_in_transfer_hook_and_transfer_fee_lsts() {
+    const STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const SRC_TO_SWAP_IN: u64 = 1_000_000_000;
+    const TRANSFER_FEE_BPS: u16 = 100;
+
+    let src_lst_mint = Pubkey::new_unique();
+    let dst_lst_mint = Pubkey::new_unique();
+    let swapper = Keypair::new();
+
+    let mut program_test = transfer_hook_program_test(
+        [src_lst_mint, dst_lst_mint]
+            .map(|lst_mint| {
+                transfer_hook_lst_args(lst_mint, STARTING_POOL_RESERVES, Some(TRANSFER_FEE_BPS))
+            })
+            .to_vec(),
+        Pubkey::new_unique(),
+    );
+    let swapper_src_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: src_lst_mint,
+            authority: swapper.pubkey(),
+            amount: SRC_TO_SWAP_IN,
+        });
+    let swapper_dst_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: dst_lst_mint,
+            authority: swapper.pubkey(),
+            amount: 0,
+        });
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    // 1:1 rates and no pricing fee: the pool pays out what it received,
+    // the transfer fee is charged on both the transfer in and out
+    let dst_lst_out = amt_after_transfer_fee(TRANSFER_FEE_BPS, SRC_TO_SWAP_IN);
+    let expected_received = amt_after_transfer_fee(TRANSFER_FEE_BPS, dst_lst_out);
+
+    let src_calc = fetch_fixed_rate_calc(&mut banks_client, src_lst_mint).await;
+    let dst_calc = fetch_fixed_rate_calc(&mut banks_client, dst_lst_mint).await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let src_extra_account_metas = banks_client
+        .get_mock_transfer_hook_extra_account_metas(src_lst_mint)
+        .await;
+    let dst_extra_account_metas = banks_client
+        .get_mock_transfer_hook_extra_account_metas(dst_lst_mint)
+        .await;
+    let mut ix = swap_exact_in_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_src_acc_addr,
+            dst_lst_acc: swapper_dst_acc_addr,
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: src_lst_mint,
+                token_program: spl_token_2022::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: dst_lst_mint,
+                token_program: spl_token_2022::ID,
+            },
+            lst_state_list: lst_state_list_account,
+        },
+        SwapExactInAmounts {
+            min_amount_out: expected_received,
+            amount: SRC_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &src_calc.ix_accounts(),
+            dst_lst_calculator_accounts: &dst_calc.ix_accounts(),
+        },
+        &[
+            AccountMeta::new_readonly(src_lst_mint, false),
+            AccountMeta::new_readonly(dst_lst_mint, false),
+        ],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    ix_extend_with_swap_transfer_hook_accounts(
+        &mut ix,
+        Some(TransferHookAccountsData {
+            lst_mint: src_lst_mint,
+            program_id: mock_transfer_hook_program::ID,
+            extra_account_metas: &src_extra_account_metas.data,
+        }),
+        SRC_TO_SWAP_IN,
+        Some(TransferHookAccountsData {
+            lst_mint: dst_lst_mint,
+            program_id: mock_transfer_hook_program::ID,
+            extra_account_metas: &dst_extra_account_metas.data,
+        }),
+        dst_lst_out,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let swapper_dst_acc = banks_client
+        .get_account_unwrapped(swapper_dst_acc_addr)
+        .await;
+    assert_eq!(
+        token_account_balance(swapper_dst_acc).unwrap(),
+        expected_received
+    );
+    // src: user to reserves. dst: reserves to protocol fee accumulator, reserves to user
+    assert_eq!(
+        banks_client
+            .get_mock_transfer_hook_count(src_lst_mint)
+            .await,
+        1
+    );
+    assert_eq!(
+        banks_client
+            .get_mock_transfer_hook_count(dst_lst_mint)
+            .await,
+        2
+    );
+}
+
+#[tokio::test]
+async fn swap_exact_out_transfer_hook_lsts() {
+    const STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const DST_TO_RECEIVE: u64 = 1_000_000_000;
+
+    let src_lst_mint = Pubkey::new_unique();
+    let dst_lst_mint = Pubkey::new_unique();
+    let swapper = Keypair::new();
+
+    // SwapExactOut rejects LSTs that charge transfer fees
+    let mut program_test = transfer_hook_program_test(
+        [src_lst_mint, dst_lst_mint]
+            .map(|lst_mint| transfer_hook_lst_args(lst_mint, STARTING_POOL_RESERVES, None))
+            .to_vec(),
+        Pubkey::new_unique(),
+    );
+    let swapper_src_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: src_lst_mint,
+            authority: swapper.pubkey(),
+            amount: DST_TO_RECEIVE,
+        });
+    let swapper_dst_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: dst_lst_mint,
+            authority: swapper.pubkey(),
+            amount: 0,
+        });
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let src_calc = fetch_fixed_rate_calc(&mut banks_client, src_lst_mint).await;
+    let dst_calc = fetch_fixed_rate_calc(&mut banks_client, dst_lst_mint).await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let src_extra_account_metas = banks_client
+        .get_mock_transfer_hook_extra_account_metas(src_lst_mint)
+        .await;
+    let dst_extra_account_metas = banks_client
+        .get_mock_transfer_hook_extra_account_metas(dst_lst_mint)
+        .await;
+    let mut ix = swap_exact_out_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_src_acc_addr,
+            dst_lst_acc: swapper_dst_acc_addr,
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: src_lst_mint,
+                token_program: spl_token_2022::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: dst_lst_mint,
+                token_program: spl_token_2022::ID,
+            },
+            lst_state_list: lst_state_list_account,
+        },
+        SwapExactOutAmounts {
+            max_amount_in: DST_TO_RECEIVE,
+            amount: DST_TO_RECEIVE,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &src_calc.ix_accounts(),
+            dst_lst_calculator_accounts: &dst_calc.ix_accounts(),
+        },
+        &[
+            AccountMeta::new_readonly(src_lst_mint, false),
+            AccountMeta::new_readonly(dst_lst_mint, false),
+        ],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    ix_extend_with_swap_transfer_hook_accounts(
+        &mut ix,
+        Some(TransferHookAccountsData {
+            lst_mint: src_lst_mint,
+            program_id: mock_transfer_hook_program::ID,
+            extra_account_metas: &src_extra_account_metas.data,
+        }),
+        DST_TO_RECEIVE,
+        Some(TransferHookAccountsData {
+            lst_mint: dst_lst_mint,
+            program_id: mock_transfer_hook_program::ID,
+            extra_account_metas: &dst_extra_account_metas.data,
+        }),
+        DST_TO_RECEIVE,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let swapper_src_acc = banks_client
+        .get_account_unwrapped(swapper_src_acc_addr)
+        .await;
+    assert_eq!(token_account_balance(swapper_src_acc).unwrap(), 0);
+    let swapper_dst_acc = banks_client
+        .get_account_unwrapped(swapper_dst_acc_addr)
+        .await;
+    assert_eq!(
+        token_account_balance(swapper_dst_acc).unwrap(),
+        DST_TO_RECEIVE
+    );
+    // src: user to reserves. dst: reserves to protocol fee accumulator, reserves to user
+    assert_eq!(
+        banks_client
+            .get_mock_transfer_hook_count(src_lst_mint)
+            .await,
+        1
+    );
+    assert_eq!(
+        banks_client
+            .get_mock_transfer_hook_count(dst_lst_mint)
+            .await,
+        2
+    );
+}
+
+#[tokio::test]
+async fn add_liquidity_transfer_hook_and_transfer_fee_lst() {
+    const STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const LST_TO_ADD: u64 = 1_000_000_000;
+    const TRANSFER_FEE_BPS: u16 = 100;
+
+    let lst_mint = Pubkey::new_unique();
+    let lp_token_mint = Pubkey::new_unique();
+    let liquidity_provider = Keypair::new();
+
+    let mut program_test = transfer_hook_program_test(
+        vec![transfer_hook_lst_args(
+            lst_mint,
+            STARTING_POOL_RESERVES,
+            Some(TRANSFER_FEE_BPS),
+        )],
+        lp_token_mint,
+    );
+    let liquidity_provider_lst_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: lst_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: LST_TO_ADD,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    // 1:1 rates, no pricing fee and LP token supply == pool total SOL value:
+    // LP tokens are minted 1:1 for what the pool received
+    let expected_lp_tokens = amt_after_transfer_fee(TRANSFER_FEE_BPS, LST_TO_ADD);
+
+    let calc = fetch_fixed_rate_calc(&mut banks_client, lst_mint).await;
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let extra_account_metas = banks_client
+        .get_mock_transfer_hook_extra_account_metas(lst_mint)
+        .await;
+    let ix = add_liquidity_ix_by_mint_full(
+        AddLiquidityByMintFreeArgs {
+            signer: liquidity_provider.pubkey(),
+            src_lst_acc: liquidity_provider_lst_acc_addr,
+            dst_lp_acc: liquidity_provider_lp_token_acc_addr,
+            pool_state: &pool_state_account,
+            lst_state_list: &lst_state_list_account,
+            lst_mint: MintWithTokenProgram {
+                pubkey: lst_mint,
+                token_program: spl_token_2022::ID,
+            },
+        },
+        AddLiquidityIxAmts {
+            lst_amount: LST_TO_ADD,
+            min_lp_out: expected_lp_tokens,
+        },
+        AddRemoveLiquidityAccountSuffixes {
+            lst_calculator_accounts: &calc.ix_accounts(),
+            pricing_program_price_lp_accounts: &[AccountMeta::new_readonly(lst_mint, false)],
+            transfer_hook: Some(TransferHookAccountsData {
+                lst_mint,
+                program_id: mock_transfer_hook_program::ID,
+                extra_account_metas: &extra_account_metas.data,
+            }),
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let liquidity_provider_lp_token_acc = banks_client
+        .get_account_unwrapped(liquidity_provider_lp_token_acc_addr)
+        .await;
+    assert_eq!(
+        token_account_balance(liquidity_provider_lp_token_acc).unwrap(),
+        expected_lp_tokens
+    );
+    // user to reserves, reserves to protocol fee accumulator
+    assert_eq!(banks_client.get_mock_transfer_hook_count(lst_mint).await, 2);
+}
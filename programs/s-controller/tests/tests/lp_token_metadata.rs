@@ -0,0 +1,185 @@
+use s_controller_interface::{
+    init_lp_token_metadata_ix, set_lp_token_metadata_ix, InitLpTokenMetadataIxArgs, PoolState,
+    SetLpTokenMetadataIxArgs,
+};
+use s_controller_lib::{program::POOL_STATE_ID, LpTokenMetadataFreeArgs};
+use s_controller_test_utils::{MockPoolState, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use sanctum_solana_test_utils::{
+    assert_program_error, test_fixtures_dir, ExtendedBanksClient, IntoAccount,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_instruction};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    hash::Hash,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_token_2022::{
+    extension::{metadata_pointer, BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::Mint,
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::common::SControllerProgramTest;
+
+/// Returns (banks_client, payer, last_blockhash, pool_state) of a pool whose
+/// LP token mint is a Token-2022 mint with a metadata pointer to itself
+async fn setup_token_2022_lp_mint() -> (BanksClient, Keypair, Hash, PoolState) {
+    let lp_token_mint_kp = Keypair::new();
+    let pool_state = PoolState {
+        lp_token_mint: lp_token_mint_kp.pubkey(),
+        ..DEFAULT_POOL_STATE
+    };
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(pool_state);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let lp_token_mint = lp_token_mint_kp.pubkey();
+    let space = ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::MetadataPointer])
+        .unwrap();
+    let lamports = banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(space);
+    let ixs = [
+        system_instruction::create_account(
+            &payer.pubkey(),
+            &lp_token_mint,
+            lamports,
+            space.try_into().unwrap(),
+            &spl_token_2022::ID,
+        ),
+        metadata_pointer::instruction::initialize(
+            &spl_token_2022::ID,
+            &lp_token_mint,
+            None,
+            Some(lp_token_mint),
+        )
+        .unwrap(),
+        spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::ID,
+            &lp_token_mint,
+            &POOL_STATE_ID,
+            Some(&POOL_STATE_ID),
+            9,
+        )
+        .unwrap(),
+    ];
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &lp_token_mint_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    (banks_client, payer, last_blockhash, pool_state)
+}
+
+async fn lp_token_metadata_free_args(
+    banks_client: &mut BanksClient,
+    payer: Pubkey,
+    pool_state: PoolState,
+) -> LpTokenMetadataFreeArgs<KeyedAccount, KeyedAccount> {
+    let lp_token_mint_acc = banks_client
+        .get_account_unwrapped(pool_state.lp_token_mint)
+        .await;
+    LpTokenMetadataFreeArgs {
+        payer,
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: MockPoolState(pool_state).into_account(),
+        },
+        lp_token_mint: KeyedAccount {
+            pubkey: pool_state.lp_token_mint,
+            account: lp_token_mint_acc,
+        },
+    }
+}
+
+async fn fetch_token_metadata(banks_client: &mut BanksClient, mint: Pubkey) -> TokenMetadata {
+    let mint_acc = banks_client.get_account_unwrapped(mint).await;
+    StateWithExtensions::<Mint>::unpack(&mint_acc.data)
+        .unwrap()
+        .get_variable_len_extension::<TokenMetadata>()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn init_then_set_token_2022_lp_token_metadata() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let (mut banks_client, payer, last_blockhash, pool_state) = setup_token_2022_lp_mint().await;
+    let free_args =
+        lp_token_metadata_free_args(&mut banks_client, payer.pubkey(), pool_state).await;
+
+    let ix = init_lp_token_metadata_ix(
+        free_args.resolve_init().unwrap(),
+        InitLpTokenMetadataIxArgs {
+            name: "Infinity".into(),
+            symbol: "INF".into(),
+            uri: "https://example.com/inf.json".into(),
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let token_metadata = fetch_token_metadata(&mut banks_client, pool_state.lp_token_mint).await;
+    assert_eq!(
+        Option::<Pubkey>::from(token_metadata.update_authority),
+        Some(POOL_STATE_ID)
+    );
+    assert_eq!(token_metadata.mint, pool_state.lp_token_mint);
+    assert_eq!(token_metadata.name, "Infinity");
+    assert_eq!(token_metadata.symbol, "INF");
+    assert_eq!(token_metadata.uri, "https://example.com/inf.json");
+
+    // longer values require the mint to be topped up for rent
+    let ix = set_lp_token_metadata_ix(
+        free_args.resolve_set().unwrap(),
+        SetLpTokenMetadataIxArgs {
+            name: "Sanctum Infinity".into(),
+            symbol: "INF".into(),
+            uri: "https://example.com/sanctum-infinity.json".into(),
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let token_metadata = fetch_token_metadata(&mut banks_client, pool_state.lp_token_mint).await;
+    assert_eq!(token_metadata.name, "Sanctum Infinity");
+    assert_eq!(token_metadata.symbol, "INF");
+    assert_eq!(
+        token_metadata.uri,
+        "https://example.com/sanctum-infinity.json"
+    );
+}
+
+#[tokio::test]
+async fn unauthorized_admin() {
+    let (mut banks_client, payer, last_blockhash, pool_state) = setup_token_2022_lp_mint().await;
+    let free_args =
+        lp_token_metadata_free_args(&mut banks_client, payer.pubkey(), pool_state).await;
+
+    let mut keys = free_args.resolve_init().unwrap();
+    keys.admin = payer.pubkey(); // payer is unauthorized
+    let ix = init_lp_token_metadata_ix(
+        keys,
+        InitLpTokenMetadataIxArgs {
+            name: "Infinity".into(),
+            symbol: "INF".into(),
+            uri: "https://example.com/inf.json".into(),
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    // InvalidArgument thrown by mismatch keys in *_verify_account_keys()
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
@@ -1,13 +1,17 @@
 mod add_disable_pool_authority;
 mod add_liquidity;
+mod add_liquidity_multi;
 mod add_lst;
 mod disable_pool;
 mod enable_disable_lst_input;
 mod enable_pool;
+mod fixed_rate;
 mod initialize;
+mod lp_token_metadata;
 mod rebalance;
 mod remove_disable_pool_authority;
 mod remove_liquidity;
+mod remove_liquidity_basket;
 mod remove_lst;
 mod set_admin;
 mod set_pricing_program;
@@ -116,6 +116,7 @@ async fn basic_redeem_full_no_fees() {
                 is_signer: false,
                 is_writable: false,
             }],
+            transfer_hook: None,
         },
     )
     .unwrap();
@@ -183,6 +184,10 @@ async fn basic_redeem_full_flat_fees() {
         flat_fee_interface::ProgramState {
             manager: Pubkey::default(),
             lp_withdrawal_fee_bps: LP_WITHDRAWAL_FEE_BPS,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         [
             MockFeeAccountArgs {
@@ -251,6 +256,7 @@ async fn basic_redeem_full_flat_fees() {
                 output_lst_mint: native_mint::ID,
             }
             .resolve_to_account_metas(),
+            transfer_hook: None,
         },
     )
     .unwrap();
@@ -388,6 +394,7 @@ async fn fail_remove_liquidity_slippage() {
                 is_signer: false,
                 is_writable: false,
             }],
+            transfer_hook: None,
         },
     )
     .unwrap();
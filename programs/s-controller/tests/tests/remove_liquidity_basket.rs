@@ -0,0 +1,551 @@
+use flat_fee_lib::account_resolvers::PriceLpTokensToRedeemFreeArgs;
+use flat_fee_test_utils::MockFeeAccountArgs;
+use lido_calculator_lib::lido_sol_val_calc_account_metas;
+use lido_keys::stsol;
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    create_pool_reserves_address, create_protocol_fee_accumulator_address,
+    remove_liquidity_basket_ix_by_mint_full, RemoveLiquidityBasketByMintFreeArgs,
+    RemoveLiquidityBasketLstByMintArgs,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, lido_wsol_flat_fee_program_test,
+    GenAndAddTokenAccountProgramTest, JitoMarinadeProgramTestArgs, LidoWsolProgramTestArgs,
+    LstStateListBanksClient, MockProtocolFeeBps, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{mint_supply, token_account_balance, MintWithTokenProgram};
+use sanctum_token_ratio::{CeilDiv, ReversibleFee, U64BpsFee};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::ProgramTestContext;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use spl_token::native_mint;
+use test_utils::{
+    jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH, LIDO_STATE_LAST_UPDATE_EPOCH,
+};
+use wsol_calculator_lib::WSOL_LST_SOL_COMMON_METAS;
+
+use crate::common::*;
+
+#[tokio::test]
+async fn basic_remove_liquidity_basket_half() {
+    const LP_TOKEN_SUPPLY: u64 = 4_000_000_000;
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_SUPPLY / 2;
+    const JITOSOL_RESERVES_STARTING_BALANCE: u64 = 1_000_000_000;
+    const MSOL_RESERVES_STARTING_BALANCE: u64 = 2_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        // will increase on SyncSolValue
+        jitosol_sol_value: JITOSOL_RESERVES_STARTING_BALANCE,
+        msol_sol_value: MSOL_RESERVES_STARTING_BALANCE,
+        jitosol_reserves: JITOSOL_RESERVES_STARTING_BALANCE,
+        msol_reserves: MSOL_RESERVES_STARTING_BALANCE,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+    })
+    .add_s_program();
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_msol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: msol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: LP_TOKENS_TO_REMOVE,
+        });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let jitosol_calculator_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let msol_calculator_accounts = marinade_sol_val_calc_account_metas();
+
+    // not in lst_state_list order, builder should sort them
+    let ix = remove_liquidity_basket_ix_by_mint_full(
+        RemoveLiquidityBasketByMintFreeArgs {
+            signer: liquidity_provider.pubkey(),
+            src_lp_acc: liquidity_provider_lp_token_acc_addr,
+            pool_state: &pool_state_account,
+            lst_state_list: &lst_state_list_account,
+        },
+        LP_TOKENS_TO_REMOVE,
+        vec![
+            RemoveLiquidityBasketLstByMintArgs {
+                dst_lst_acc: liquidity_provider_msol_acc_addr,
+                lst_mint: MintWithTokenProgram {
+                    pubkey: msol::ID,
+                    token_program: spl_token::ID,
+                },
+                min_lst_out: MSOL_RESERVES_STARTING_BALANCE / 2,
+                lst_calculator_accounts: &msol_calculator_accounts,
+            },
+            RemoveLiquidityBasketLstByMintArgs {
+                dst_lst_acc: liquidity_provider_jitosol_acc_addr,
+                lst_mint: MintWithTokenProgram {
+                    pubkey: jitosol::ID,
+                    token_program: spl_token::ID,
+                },
+                min_lst_out: JITOSOL_RESERVES_STARTING_BALANCE / 2,
+                lst_calculator_accounts: &jitosol_calculator_accounts,
+            },
+        ],
+        &[AccountMeta::new_readonly(jitosol::ID, false)],
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // half the LP supply redeemed, so half of each reserves paid out
+    for (dst_lst_acc, starting_reserves) in [
+        (
+            liquidity_provider_jitosol_acc_addr,
+            JITOSOL_RESERVES_STARTING_BALANCE,
+        ),
+        (
+            liquidity_provider_msol_acc_addr,
+            MSOL_RESERVES_STARTING_BALANCE,
+        ),
+    ] {
+        let dst_lst_acc = banks_client.get_account_unwrapped(dst_lst_acc).await;
+        assert_eq!(
+            token_account_balance(dst_lst_acc).unwrap(),
+            starting_reserves / 2
+        );
+    }
+
+    for (lst_mint, starting_reserves) in [
+        (jitosol::ID, JITOSOL_RESERVES_STARTING_BALANCE),
+        (msol::ID, MSOL_RESERVES_STARTING_BALANCE),
+    ] {
+        let lst_state = banks_client.get_lst_state(lst_mint).await;
+        let pool_reserves = banks_client
+            .get_account_unwrapped(create_pool_reserves_address(&lst_state, spl_token::ID).unwrap())
+            .await;
+        assert_eq!(
+            token_account_balance(pool_reserves).unwrap(),
+            starting_reserves / 2
+        );
+    }
+
+    let lp_token_acc = banks_client
+        .get_account_unwrapped(liquidity_provider_lp_token_acc_addr)
+        .await;
+    assert_eq!(token_account_balance(lp_token_acc).unwrap(), 0);
+    let lp_token_mint_acc = banks_client.get_account_unwrapped(lp_token_mint).await;
+    assert_eq!(
+        mint_supply(lp_token_mint_acc).unwrap(),
+        LP_TOKEN_SUPPLY - LP_TOKENS_TO_REMOVE
+    );
+}
+
+#[tokio::test]
+async fn remove_liquidity_basket_missing_lst_fails() {
+    const LP_TOKEN_SUPPLY: u64 = 4_000_000_000;
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_SUPPLY / 2;
+    const JITOSOL_RESERVES_STARTING_BALANCE: u64 = 1_000_000_000;
+    const MSOL_RESERVES_STARTING_BALANCE: u64 = 2_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: JITOSOL_RESERVES_STARTING_BALANCE,
+        msol_sol_value: MSOL_RESERVES_STARTING_BALANCE,
+        jitosol_reserves: JITOSOL_RESERVES_STARTING_BALANCE,
+        msol_reserves: MSOL_RESERVES_STARTING_BALANCE,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+    })
+    .add_s_program();
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: LP_TOKENS_TO_REMOVE,
+        });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let jitosol_calculator_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+
+    let ix = remove_liquidity_basket_ix_by_mint_full(
+        RemoveLiquidityBasketByMintFreeArgs {
+            signer: liquidity_provider.pubkey(),
+            src_lp_acc: liquidity_provider_lp_token_acc_addr,
+            pool_state: &pool_state_account,
+            lst_state_list: &lst_state_list_account,
+        },
+        LP_TOKENS_TO_REMOVE,
+        vec![RemoveLiquidityBasketLstByMintArgs {
+            dst_lst_acc: liquidity_provider_jitosol_acc_addr,
+            lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            min_lst_out: 0,
+            lst_calculator_accounts: &jitosol_calculator_accounts,
+        }],
+        &[AccountMeta::new_readonly(jitosol::ID, false)],
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::IncompleteBasket);
+}
+
+#[tokio::test]
+async fn remove_liquidity_basket_extra_lst_fails() {
+    const LP_TOKEN_SUPPLY: u64 = 4_000_000_000;
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_SUPPLY / 2;
+    const JITOSOL_RESERVES_STARTING_BALANCE: u64 = 1_000_000_000;
+    const MSOL_RESERVES_STARTING_BALANCE: u64 = 2_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: JITOSOL_RESERVES_STARTING_BALANCE,
+        msol_sol_value: MSOL_RESERVES_STARTING_BALANCE,
+        jitosol_reserves: JITOSOL_RESERVES_STARTING_BALANCE,
+        msol_reserves: MSOL_RESERVES_STARTING_BALANCE,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+    })
+    .add_s_program();
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_msol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: msol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: LP_TOKENS_TO_REMOVE,
+        });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let jitosol_calculator_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let msol_calculator_accounts = marinade_sol_val_calc_account_metas();
+
+    let jitosol_args = || RemoveLiquidityBasketLstByMintArgs {
+        dst_lst_acc: liquidity_provider_jitosol_acc_addr,
+        lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        min_lst_out: 0,
+        lst_calculator_accounts: &jitosol_calculator_accounts,
+    };
+    // every LST is present but jitoSOL is paid out twice
+    let ix = remove_liquidity_basket_ix_by_mint_full(
+        RemoveLiquidityBasketByMintFreeArgs {
+            signer: liquidity_provider.pubkey(),
+            src_lp_acc: liquidity_provider_lp_token_acc_addr,
+            pool_state: &pool_state_account,
+            lst_state_list: &lst_state_list_account,
+        },
+        LP_TOKENS_TO_REMOVE,
+        vec![
+            jitosol_args(),
+            jitosol_args(),
+            RemoveLiquidityBasketLstByMintArgs {
+                dst_lst_acc: liquidity_provider_msol_acc_addr,
+                lst_mint: MintWithTokenProgram {
+                    pubkey: msol::ID,
+                    token_program: spl_token::ID,
+                },
+                min_lst_out: 0,
+                lst_calculator_accounts: &msol_calculator_accounts,
+            },
+        ],
+        &[AccountMeta::new_readonly(jitosol::ID, false)],
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::IncompleteBasket);
+}
+
+#[tokio::test]
+async fn remove_liquidity_basket_half_flat_fees() {
+    const LP_TOKEN_SUPPLY: u64 = 1_000_000_000;
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_SUPPLY / 2;
+    // 10x exchange rate
+    const WSOL_RESERVES_STARTING_BALANCE: u64 = 10_000_000_000;
+    const LP_WITHDRAWAL_FEE_BPS: u16 = 10;
+    const PROTOCOL_FEE_BPS: u16 = 5000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = lido_wsol_flat_fee_program_test(
+        LidoWsolProgramTestArgs {
+            wsol_reserves: WSOL_RESERVES_STARTING_BALANCE,
+            stsol_sol_value: 0,
+            stsol_reserves: 0,
+            wsol_protocol_fee_accumulator: 0,
+            stsol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: LP_TOKEN_SUPPLY,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: LP_WITHDRAWAL_FEE_BPS,
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: Default::default(),
+                lst_mint: native_mint::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: Default::default(),
+                lst_mint: stsol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: Default::default(),
+            lp: PROTOCOL_FEE_BPS,
+        },
+    )
+    .add_s_program();
+    let liquidity_provider_stsol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: stsol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_wsol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: native_mint::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: LP_TOKENS_TO_REMOVE,
+        });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: LIDO_STATE_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let stsol_calculator_accounts = lido_sol_val_calc_account_metas();
+
+    let ix = remove_liquidity_basket_ix_by_mint_full(
+        RemoveLiquidityBasketByMintFreeArgs {
+            signer: liquidity_provider.pubkey(),
+            src_lp_acc: liquidity_provider_lp_token_acc_addr,
+            pool_state: &pool_state_account,
+            lst_state_list: &lst_state_list_account,
+        },
+        LP_TOKENS_TO_REMOVE,
+        vec![
+            RemoveLiquidityBasketLstByMintArgs {
+                dst_lst_acc: liquidity_provider_stsol_acc_addr,
+                lst_mint: MintWithTokenProgram {
+                    pubkey: stsol::ID,
+                    token_program: spl_token::ID,
+                },
+                min_lst_out: 0,
+                lst_calculator_accounts: &stsol_calculator_accounts,
+            },
+            RemoveLiquidityBasketLstByMintArgs {
+                dst_lst_acc: liquidity_provider_wsol_acc_addr,
+                lst_mint: MintWithTokenProgram {
+                    pubkey: native_mint::ID,
+                    token_program: spl_token::ID,
+                },
+                min_lst_out: 0,
+                lst_calculator_accounts: &WSOL_LST_SOL_COMMON_METAS,
+            },
+        ],
+        // stSOL is first on lst_state_list
+        &PriceLpTokensToRedeemFreeArgs {
+            output_lst_mint: stsol::ID,
+        }
+        .resolve_to_account_metas(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // the LP withdrawal fee is charged on wSOL's pro-rata share at the same rate
+    // as on the LP tokens' SOL value, part of which goes to the protocol
+    let aaf = CeilDiv(U64BpsFee::new_unchecked(LP_WITHDRAWAL_FEE_BPS))
+        .apply(WSOL_RESERVES_STARTING_BALANCE / 2)
+        .unwrap();
+    let amt_after_fee = aaf.amt_after_fee();
+    let fee_charged = aaf.fee_charged();
+    let protocol_fees_charged = CeilDiv(U64BpsFee::new_unchecked(PROTOCOL_FEE_BPS))
+        .apply(fee_charged)
+        .unwrap()
+        .fee_charged();
+    assert!(protocol_fees_charged > 0);
+
+    let liquidity_provider_wsol_account = banks_client
+        .get_account_unwrapped(liquidity_provider_wsol_acc_addr)
+        .await;
+    assert_eq!(
+        token_account_balance(liquidity_provider_wsol_account).unwrap(),
+        amt_after_fee
+    );
+    let liquidity_provider_stsol_account = banks_client
+        .get_account_unwrapped(liquidity_provider_stsol_acc_addr)
+        .await;
+    assert_eq!(
+        token_account_balance(liquidity_provider_stsol_account).unwrap(),
+        0
+    );
+
+    let wsol_lst_state = banks_client.get_lst_state(native_mint::ID).await;
+    let pool_reserves = banks_client
+        .get_account_unwrapped(
+            create_pool_reserves_address(&wsol_lst_state, spl_token::ID).unwrap(),
+        )
+        .await;
+    assert_eq!(
+        token_account_balance(pool_reserves).unwrap(),
+        WSOL_RESERVES_STARTING_BALANCE - amt_after_fee - protocol_fees_charged
+    );
+    let protocol_fee_accumulator = banks_client
+        .get_account_unwrapped(
+            create_protocol_fee_accumulator_address(&wsol_lst_state, spl_token::ID).unwrap(),
+        )
+        .await;
+    assert_eq!(
+        token_account_balance(protocol_fee_accumulator).unwrap(),
+        protocol_fees_charged
+    );
+}
@@ -2,10 +2,10 @@ use flat_fee_lib::account_resolvers::PriceExactInFreeArgs;
 use flat_fee_test_utils::MockFeeAccountArgs;
 use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
 use marinade_keys::msol;
-use s_controller_interface::SControllerError;
+use s_controller_interface::{Deadline, SControllerError};
 use s_controller_lib::{
-    swap_exact_in_ix_by_mint_full, try_pool_state, SrcDstLstSolValueCalcAccountSuffixes,
-    SwapByMintsFreeArgs, SwapExactInAmounts,
+    ix_with_deadline, swap_exact_in_ix_by_mint_full, try_pool_state,
+    SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs, SwapExactInAmounts,
 };
 use s_controller_test_utils::{
     jito_marinade_flat_fee_program_test, jito_marinade_no_fee_program_test,
@@ -197,6 +197,10 @@ async fn basic_swap_exact_in_flat_fee() {
         flat_fee_interface::ProgramState {
             manager: Default::default(),
             lp_withdrawal_fee_bps: Default::default(),
+            pending_manager: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            epoch_surcharge_bps: Default::default(),
+            epoch_surcharge_slots: Default::default(),
         },
         [
             MockFeeAccountArgs {
@@ -443,3 +447,139 @@ async fn fail_swap_exact_in_same_mint() {
     let err = banks_client.process_transaction(tx).await.unwrap_err();
     assert_custom_err(err, SControllerError::SwapSameLst);
 }
+
+#[tokio::test]
+async fn swap_exact_in_with_deadline() {
+    const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+    const CURRENT_SLOT: u64 = 1_000;
+    const CURRENT_UNIX_TIMESTAMP: i64 = 1_700_000_000;
+
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+        // dont cares
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        slot: CURRENT_SLOT,
+        unix_timestamp: CURRENT_UNIX_TIMESTAMP,
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let swap_ix = swap_exact_in_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            lst_state_list: lst_state_list_account,
+        },
+        SwapExactInAmounts {
+            min_amount_out: 0,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+        &[
+            AccountMeta {
+                pubkey: msol::ID,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: jitosol::ID,
+                is_signer: false,
+                is_writable: false,
+            },
+        ],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+
+    for expired_deadline in [
+        Deadline {
+            expiry_slot: Some(CURRENT_SLOT - 1),
+            expiry_unix_timestamp: None,
+        },
+        Deadline {
+            expiry_slot: Some(CURRENT_SLOT),
+            expiry_unix_timestamp: Some(CURRENT_UNIX_TIMESTAMP - 1),
+        },
+    ] {
+        let ix = ix_with_deadline(swap_ix.clone(), &expired_deadline).unwrap();
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &swapper], last_blockhash);
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, SControllerError::DeadlineExceeded);
+    }
+
+    let ix = ix_with_deadline(
+        swap_ix,
+        &Deadline {
+            expiry_slot: Some(CURRENT_SLOT),
+            expiry_unix_timestamp: Some(CURRENT_UNIX_TIMESTAMP),
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let msol_account = banks_client
+        .get_account_unwrapped(swapper_msol_acc_addr)
+        .await;
+    assert_eq!(token_account_balance(msol_account).unwrap(), 0);
+}
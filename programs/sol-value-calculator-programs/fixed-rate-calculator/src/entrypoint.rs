@@ -0,0 +1,37 @@
+use fixed_rate_calculator_interface::{
+    FixedRateCalculatorProgramIx, LstToSolIxArgs, SolToLstIxArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::processor::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if *program_id != fixed_rate_calculator_lib::program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let ix = FixedRateCalculatorProgramIx::deserialize(instruction_data)?;
+    solana_program::msg!("{:?}", ix);
+
+    match ix {
+        FixedRateCalculatorProgramIx::LstToSol(LstToSolIxArgs { amount }) => {
+            process_lst_to_sol(accounts, amount)
+        }
+        FixedRateCalculatorProgramIx::SolToLst(SolToLstIxArgs { amount }) => {
+            process_sol_to_lst(accounts, amount)
+        }
+        FixedRateCalculatorProgramIx::SetLstRate(args) => process_set_lst_rate(accounts, args),
+        FixedRateCalculatorProgramIx::SetManager => process_set_manager(accounts),
+        FixedRateCalculatorProgramIx::Initialize => process_initialize(accounts),
+    }
+}
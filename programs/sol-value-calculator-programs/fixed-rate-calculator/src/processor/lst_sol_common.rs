@@ -0,0 +1,44 @@
+use fixed_rate_calculator_interface::{
+    lst_to_sol_verify_account_keys, LstToSolAccounts, LstToSolKeys,
+};
+use fixed_rate_calculator_lib::{
+    account_resolvers::{LstSolCommonFreeArgs, LstSolCommonWithBumpFreeArgs},
+    utils::try_lst_rate,
+    FixedRateCalc,
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sol_value_calculator_onchain::{process_lst_to_sol_unchecked, process_sol_to_lst_unchecked};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_lst_to_sol(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let calc = verify_lst_sol_common(accounts)?;
+    process_lst_to_sol_unchecked(&calc, amount)
+}
+
+pub fn process_sol_to_lst(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let calc = verify_lst_sol_common(accounts)?;
+    process_sol_to_lst_unchecked(&calc, amount)
+}
+
+/// Assumes account interfaces of LstToSol and SolToLst are the same
+fn verify_lst_sol_common(accounts: &[AccountInfo]) -> Result<FixedRateCalc, ProgramError> {
+    let actual: LstToSolAccounts = load_accounts(accounts)?;
+
+    let lst_rate_bytes = actual.lst_rate.try_borrow_data()?;
+    let lst_rate = try_lst_rate(&lst_rate_bytes)?;
+
+    let free_args = LstSolCommonWithBumpFreeArgs {
+        args: LstSolCommonFreeArgs {
+            lst_mint: *actual.lst_mint.key,
+        },
+        lst_rate_bump: lst_rate.bump,
+    };
+    let expected: LstToSolKeys = free_args.resolve()?;
+
+    lst_to_sol_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    // accounts should all be read-only, no need to verify_account_privileges
+
+    Ok((*lst_rate).into())
+}
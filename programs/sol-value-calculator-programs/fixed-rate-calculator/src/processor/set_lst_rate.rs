@@ -0,0 +1,90 @@
+use fixed_rate_calculator_interface::{
+    set_lst_rate_verify_account_keys, set_lst_rate_verify_account_privileges,
+    FixedRateCalculatorError, SetLstRateAccounts, SetLstRateIxArgs, SetLstRateKeys,
+};
+use fixed_rate_calculator_lib::{
+    account_resolvers::SetLstRateFreeArgs, pda::LstRateCreatePdaArgs, program,
+    utils::try_lst_rate_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_s_common::token::verify_tokenkeg_or_22_mint;
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use system_program_interface::CreateAccountAccounts;
+
+/// Creates the LstRate PDA if it does not exist yet
+pub fn process_set_lst_rate(accounts: &[AccountInfo], args: SetLstRateIxArgs) -> ProgramResult {
+    let (
+        SetLstRateAccounts {
+            payer, lst_rate, ..
+        },
+        SetLstRateIxArgs {
+            num_min,
+            num_max,
+            denom,
+        },
+        create_pda_args,
+    ) = verify_set_lst_rate(accounts, args)?;
+
+    if lst_rate.data_is_empty() {
+        init_rent_exempt_account_invoke_signed(
+            CreateAccountAccounts {
+                from: payer,
+                to: lst_rate,
+            },
+            InitRentExemptAccountArgs {
+                space: program::LST_RATE_SIZE,
+                owner: program::ID,
+            },
+            &[create_pda_args.to_signer_seeds().as_slice()],
+        )?;
+    }
+
+    let mut bytes = lst_rate.try_borrow_mut_data()?;
+    let lst_rate = try_lst_rate_mut(&mut bytes)?;
+
+    lst_rate.bump = create_pda_args.bump;
+    lst_rate.num_min = num_min;
+    lst_rate.num_max = num_max;
+    lst_rate.denom = denom;
+
+    Ok(())
+}
+
+fn verify_set_lst_rate<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    args: SetLstRateIxArgs,
+) -> Result<
+    (
+        SetLstRateAccounts<'me, 'info>,
+        SetLstRateIxArgs,
+        LstRateCreatePdaArgs,
+    ),
+    ProgramError,
+> {
+    let actual: SetLstRateAccounts = load_accounts(accounts)?;
+
+    let free_args = SetLstRateFreeArgs {
+        payer: *actual.payer.key,
+        state_acc: actual.state,
+        lst_mint: *actual.lst_mint.key,
+    };
+    let (expected, lst_rate_create_pda_args): (SetLstRateKeys, LstRateCreatePdaArgs) =
+        free_args.resolve()?;
+
+    set_lst_rate_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_lst_rate_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_tokenkeg_or_22_mint(actual.lst_mint)?;
+    if args.denom == 0 {
+        return Err(FixedRateCalculatorError::ZeroDenominator.into());
+    }
+
+    Ok((actual, args, lst_rate_create_pda_args))
+}
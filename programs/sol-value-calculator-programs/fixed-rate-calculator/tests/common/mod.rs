@@ -0,0 +1,18 @@
+use fixed_rate_calculator_interface::ProgramState;
+use fixed_rate_calculator_test_utils::{FixedRateCalculatorProgramTest, MockLstRateArgs};
+use solana_program_test::{processor, ProgramTest};
+
+pub fn normal_program_test(state: ProgramState, mock_lst_rates: &[MockLstRateArgs]) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    // name must match <name>.so filename
+    program_test.add_program(
+        "fixed_rate_calculator",
+        fixed_rate_calculator_lib::program::ID,
+        processor!(fixed_rate_calculator::entrypoint::process_instruction),
+    );
+    program_test = program_test.add_mock_fixed_rate_program_state(state);
+    for args in mock_lst_rates {
+        program_test = program_test.add_mock_lst_rate(*args);
+    }
+    program_test
+}
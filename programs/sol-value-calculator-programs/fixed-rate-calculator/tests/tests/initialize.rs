@@ -0,0 +1,36 @@
+use fixed_rate_calculator_interface::initialize_ix;
+use fixed_rate_calculator_lib::{account_resolvers::InitializeFreeArgs, utils::try_program_state};
+use fixed_rate_calculator_test_utils::{
+    FixedRateCalculatorProgramTestBanksClient, DEFAULT_PROGRAM_STATE,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+#[tokio::test]
+async fn initialize_basic() {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "fixed_rate_calculator",
+        fixed_rate_calculator_lib::program::ID,
+        processor!(fixed_rate_calculator::entrypoint::process_instruction),
+    );
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = initialize_ix(
+        InitializeFreeArgs {
+            payer: payer.pubkey(),
+        }
+        .resolve(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_fixed_rate_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+    assert_eq!(*state, DEFAULT_PROGRAM_STATE);
+}
@@ -0,0 +1,116 @@
+use fixed_rate_calculator_interface::{
+    lst_to_sol_ix, sol_to_lst_ix, FixedRateCalculatorError, LstToSolIxArgs, SolToLstIxArgs,
+};
+use fixed_rate_calculator_lib::account_resolvers::LstSolCommonFreeArgs;
+use fixed_rate_calculator_test_utils::{
+    FixedRateCalculatorProgramTestContext, MockLstRateArgs, DEFAULT_PROGRAM_STATE,
+};
+use sanctum_solana_test_utils::assert_custom_err;
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::normal_program_test;
+
+#[tokio::test]
+async fn lst_sol_basic() {
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let lst_mint = Pubkey::new_unique();
+    let program_test = normal_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &[MockLstRateArgs::single(lst_mint, 11, 10)],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = lst_to_sol_ix(
+        LstSolCommonFreeArgs { lst_mint }.resolve(),
+        LstToSolIxArgs { amount: AMOUNT },
+    )
+    .unwrap();
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            U64ValueRange::single(1_100_000_000),
+        )
+        .await;
+
+    let ix = sol_to_lst_ix(
+        LstSolCommonFreeArgs { lst_mint }.resolve_sol_to_lst(),
+        SolToLstIxArgs {
+            amount: 1_100_000_000,
+        },
+    )
+    .unwrap();
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            U64ValueRange::single(AMOUNT),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn lst_sol_value_jump_and_faulty_range() {
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let lst_mint = Pubkey::new_unique();
+    let program_test = normal_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &[MockLstRateArgs::single(lst_mint, 1, 1)],
+    );
+    let mut ctx = program_test.start_with_context().await;
+
+    ctx.set_mock_lst_rate(MockLstRateArgs::single(lst_mint, 2, 1));
+    let ix = lst_to_sol_ix(
+        LstSolCommonFreeArgs { lst_mint }.resolve(),
+        LstToSolIxArgs { amount: AMOUNT },
+    )
+    .unwrap();
+    ctx.banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &ctx.payer,
+            ctx.last_blockhash,
+            ix.clone(),
+            U64ValueRange::single(2 * AMOUNT),
+        )
+        .await;
+
+    ctx.set_mock_lst_rate(MockLstRateArgs {
+        lst_mint,
+        num_min: 3,
+        num_max: 1,
+        denom: 2,
+    });
+    let last_blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    ctx.banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &ctx.payer,
+            last_blockhash,
+            ix,
+            U64ValueRange::from_min_max_unchecked(1_500_000_000, 500_000_000),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn lst_sol_fail_uninitialized_lst_rate() {
+    let lst_mint = Pubkey::new_unique();
+    let program_test = normal_program_test(DEFAULT_PROGRAM_STATE, &[]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = lst_to_sol_ix(
+        LstSolCommonFreeArgs { lst_mint }.resolve(),
+        LstToSolIxArgs { amount: 1 },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FixedRateCalculatorError::UnsupportedLstMint);
+}
@@ -0,0 +1,3 @@
+mod initialize;
+mod lst_sol_common;
+mod set_lst_rate;
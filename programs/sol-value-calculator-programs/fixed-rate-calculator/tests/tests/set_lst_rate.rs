@@ -0,0 +1,162 @@
+use fixed_rate_calculator_interface::{
+    set_lst_rate_ix, FixedRateCalculatorError, ProgramState, SetLstRateIxArgs, SetLstRateKeys,
+};
+use fixed_rate_calculator_lib::{
+    account_resolvers::SetLstRateFreeArgs,
+    pda::LstRateFindPdaArgs,
+    program::{self, STATE_ID},
+    utils::try_lst_rate,
+};
+use fixed_rate_calculator_test_utils::FixedRateCalculatorProgramTestBanksClient;
+use sanctum_solana_test_utils::{
+    assert_custom_err, assert_program_error,
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs},
+    ExtendedBanksClient,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::normal_program_test;
+
+fn set_lst_rate_program_test() -> (ProgramTest, Keypair, Pubkey) {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+        },
+        &[],
+    )
+    .add_tokenkeg_mint_from_args(
+        lst_mint,
+        MockMintArgs {
+            mint_authority: None,
+            freeze_authority: None,
+            supply: 0,
+            decimals: 9,
+        },
+    );
+    (program_test, manager, lst_mint)
+}
+
+async fn verify_lst_rate(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    expected: SetLstRateIxArgs,
+) {
+    let (addr, bump) = LstRateFindPdaArgs {
+        program_id: program::ID,
+        lst_mint,
+    }
+    .get_lst_rate_address_and_bump_seed();
+    let actual_acc = banks_client.get_account_unwrapped(addr).await;
+    let actual = try_lst_rate(&actual_acc.data).unwrap();
+    assert_eq!(actual.bump, bump);
+    assert_eq!(actual.num_min, expected.num_min);
+    assert_eq!(actual.num_max, expected.num_max);
+    assert_eq!(actual.denom, expected.denom);
+}
+
+async fn set_lst_rate_keys(
+    banks_client: &mut BanksClient,
+    payer: Pubkey,
+    lst_mint: Pubkey,
+) -> SetLstRateKeys {
+    let state_acc = banks_client.get_fixed_rate_program_state().await;
+    let (keys, _pda) = SetLstRateFreeArgs {
+        payer,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+        lst_mint,
+    }
+    .resolve()
+    .unwrap();
+    keys
+}
+
+#[tokio::test]
+async fn set_lst_rate_create_and_update() {
+    const RATE_ARGS: [SetLstRateIxArgs; 2] = [
+        SetLstRateIxArgs {
+            num_min: 1,
+            num_max: 1,
+            denom: 1,
+        },
+        SetLstRateIxArgs {
+            num_min: 3,
+            num_max: 5,
+            denom: 4,
+        },
+    ];
+
+    let (program_test, manager, lst_mint) = set_lst_rate_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let keys = set_lst_rate_keys(&mut banks_client, payer.pubkey(), lst_mint).await;
+    assert!(banks_client
+        .get_account(keys.lst_rate)
+        .await
+        .unwrap()
+        .is_none());
+
+    for rate_args in RATE_ARGS {
+        let ix = set_lst_rate_ix(keys, rate_args.clone()).unwrap();
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+        banks_client.process_transaction(tx).await.unwrap();
+
+        verify_lst_rate(&mut banks_client, lst_mint, rate_args).await;
+    }
+}
+
+#[tokio::test]
+async fn set_lst_rate_fail_zero_denom() {
+    let (program_test, manager, lst_mint) = set_lst_rate_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let keys = set_lst_rate_keys(&mut banks_client, payer.pubkey(), lst_mint).await;
+    let ix = set_lst_rate_ix(
+        keys,
+        SetLstRateIxArgs {
+            num_min: 1,
+            num_max: 1,
+            denom: 0,
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FixedRateCalculatorError::ZeroDenominator);
+}
+
+#[tokio::test]
+async fn set_lst_rate_fail_unauthorized() {
+    let (program_test, _manager, lst_mint) = set_lst_rate_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let keys = set_lst_rate_keys(&mut banks_client, payer.pubkey(), lst_mint).await;
+    let ix = set_lst_rate_ix(
+        SetLstRateKeys {
+            manager: payer.pubkey(),
+            ..keys
+        },
+        SetLstRateIxArgs {
+            num_min: 1,
+            num_max: 1,
+            denom: 1,
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
@@ -0,0 +1,10 @@
+use generic_pool_calculator_onchain::processor::{
+    process_migrate_state_unchecked, verify_migrate_state,
+};
+use lido_calculator_lib::LidoSolValCalc;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+pub fn process_migrate_state(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let checked = verify_migrate_state::<LidoSolValCalc>(accounts)?;
+    process_migrate_state_unchecked(checked)
+}
@@ -1,12 +1,16 @@
+mod accept_manager;
 mod init;
 mod lst_sol_common;
 mod lst_to_sol;
+mod migrate_state;
 mod set_manager;
 mod sol_to_lst;
 mod update_last_upgrade_slot;
 
+pub use accept_manager::*;
 pub use init::*;
 pub use lst_to_sol::*;
+pub use migrate_state::*;
 pub use set_manager::*;
 pub use sol_to_lst::*;
 pub use update_last_upgrade_slot::*;
@@ -20,6 +20,7 @@ pub fn lido_normal_program_test() -> ProgramTest {
         .add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: LIDO_PROG_LAST_UPDATED_SLOT,
+            pending_manager: Pubkey::default(),
             owner: LidoSolValCalc::ID,
         })
         .add_test_fixtures_account("lido-state.json")
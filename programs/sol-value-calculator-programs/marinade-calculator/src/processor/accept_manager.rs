@@ -0,0 +1,10 @@
+use generic_pool_calculator_onchain::processor::{
+    process_accept_manager_unchecked, verify_accept_manager,
+};
+use marinade_calculator_lib::MarinadeSolValCalc;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+pub fn process_accept_manager(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let checked = verify_accept_manager::<MarinadeSolValCalc>(accounts)?;
+    process_accept_manager_unchecked(checked)
+}
@@ -20,6 +20,7 @@ pub fn marinade_normal_program_test() -> ProgramTest {
         .add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: MARINADE_PROG_LAST_UPDATED_SLOT,
+            pending_manager: Pubkey::default(),
             owner: MarinadeSolValCalc::ID,
         })
         .add_test_fixtures_account("marinade-state.json")
@@ -6,8 +6,8 @@ use solana_program::{
 use spl_calculator_lib::sanctum_spl_sol_val_calc_program;
 
 use crate::processor::{
-    process_init, process_lst_to_sol, process_set_manager, process_sol_to_lst,
-    process_update_last_upgrade_slot,
+    process_accept_manager, process_init, process_lst_to_sol, process_migrate_state,
+    process_set_manager, process_sol_to_lst, process_update_last_upgrade_slot,
 };
 
 #[cfg(not(feature = "no-entrypoint"))]
@@ -28,6 +28,8 @@ pub fn process_instruction(
     match ix {
         GenericPoolCalculatorProgramIx::LstToSol(args) => process_lst_to_sol(accounts, args),
         GenericPoolCalculatorProgramIx::SolToLst(args) => process_sol_to_lst(accounts, args),
+        GenericPoolCalculatorProgramIx::MigrateState => process_migrate_state(accounts),
+        GenericPoolCalculatorProgramIx::AcceptManager => process_accept_manager(accounts),
         GenericPoolCalculatorProgramIx::UpdateLastUpgradeSlot => {
             process_update_last_upgrade_slot(accounts)
         }
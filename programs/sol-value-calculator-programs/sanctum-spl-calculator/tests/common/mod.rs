@@ -35,6 +35,7 @@ pub fn pwr_normal_program_test() -> PwrNormalProgramTest {
         .add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: SANCTUM_SPL_STAKE_POOL_PROG_LAST_UDPATED_SLOT,
+            pending_manager: Pubkey::default(),
             owner: SanctumSplSolValCalc::ID,
         })
         .add_keyed_ui_account(sanctum_spl_stake_pool_prog_ui_acc)
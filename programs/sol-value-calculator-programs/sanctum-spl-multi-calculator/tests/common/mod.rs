@@ -35,6 +35,7 @@ pub fn jup_normal_program_test() -> JupNormalProgramTest {
         .add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: SANCTUM_SPL_MULTI_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
+            pending_manager: Pubkey::default(),
             owner: SanctumSplMultiSolValCalc::ID,
         })
         .add_keyed_ui_account(sanctum_spl_stake_pool_prog_ui_acc)
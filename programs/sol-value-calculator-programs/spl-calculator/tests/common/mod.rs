@@ -35,6 +35,7 @@ pub fn jito_normal_program_test() -> JitoNormalProgramTest {
         .add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
+            pending_manager: Pubkey::default(),
             owner: SplSolValCalc::ID,
         })
         .add_keyed_ui_account(spl_stake_pool_prog_ui_acc)